@@ -56,6 +56,9 @@ where
                 .await
                 .map_err(|_| ApiError::internal("database error"))?
                 .ok_or_else(|| ApiError::not_found("user"))?;
+            if user.is_suspended() {
+                return Err(ApiError::forbidden("account suspended"));
+            }
 
             let token = AuthToken::new(token_value.to_owned()).map_err(ApiError::from)?;
             Ok(Self { user, token })
@@ -90,6 +93,56 @@ where
     }
 }
 
+/// A signed-in user listed as an administrator in the accounts config
+#[derive(Clone)]
+pub struct AdminUser(pub CurrentUser);
+
+impl<U, A, C> FromRequestParts<AppState<U, A, C>> for AdminUser
+where
+    U: UsersRepository + Clone,
+    A: domain::repositories::ArticlesRepository + Clone,
+    C: domain::repositories::CommentsRepository + Clone,
+{
+    type Rejection = ApiError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState<U, A, C>,
+    ) -> Result<Self, Self::Rejection> {
+        let current = CurrentUser::from_request_parts(parts, state).await?;
+        if !state.admins.contains(current.user.username.as_str()) {
+            return Err(ApiError::forbidden("administrators only"));
+        }
+        Ok(Self(current))
+    }
+}
+
+/// A signed-in user listed as a moderator or an administrator in the
+/// accounts config
+#[derive(Clone)]
+pub struct ModeratorUser(pub CurrentUser);
+
+impl<U, A, C> FromRequestParts<AppState<U, A, C>> for ModeratorUser
+where
+    U: UsersRepository + Clone,
+    A: domain::repositories::ArticlesRepository + Clone,
+    C: domain::repositories::CommentsRepository + Clone,
+{
+    type Rejection = ApiError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState<U, A, C>,
+    ) -> Result<Self, Self::Rejection> {
+        let current = CurrentUser::from_request_parts(parts, state).await?;
+        let username = current.user.username.as_str();
+        if !state.moderators.contains(username) && !state.admins.contains(username) {
+            return Err(ApiError::forbidden("moderators only"));
+        }
+        Ok(Self(current))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -257,6 +310,28 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[tokio::test]
+    async fn test_current_user_from_request_parts_suspended() {
+        let state = AppState::default();
+        let user_id = UserId::random();
+        let user = create_test_user(user_id, "testuser", "test@example.com");
+
+        state.use_cases.users_repo.create_user(user).await.unwrap();
+        state.use_cases.users_repo.suspend_user(user_id, chrono::Utc::now()).await.unwrap();
+        state.sessions.write().await.insert("valid-token".to_owned(), user_id);
+
+        let req = Request::builder()
+            .uri("/")
+            .header("authorization", "Token valid-token")
+            .body(())
+            .unwrap();
+
+        let (mut parts, _) = req.into_parts();
+        let result = CurrentUser::from_request_parts(&mut parts, &state).await;
+
+        assert!(result.is_err());
+    }
+
     #[tokio::test]
     async fn test_optional_from_request_returns_none_on_error() {
         let state = AppState::default();
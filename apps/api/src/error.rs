@@ -25,26 +25,64 @@ pub struct AppError(anyhow::Error);
 
 pub type AppResult<T> = Result<T, AppError>;
 
+/// Raised by the `AppError` constructors; its status applies when the message
+/// matches none of the phrases `into_response` recognises
+#[derive(Debug)]
+struct StatusError {
+    status: StatusCode,
+    message: String,
+}
+
+impl std::fmt::Display for StatusError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for StatusError {}
+
 impl AppError {
+    fn with_status(status: StatusCode, message: impl Into<String>) -> Self {
+        Self(anyhow::Error::new(StatusError {
+            status,
+            message: message.into(),
+        }))
+    }
+
     pub fn validation(message: impl Into<String>) -> Self {
-        Self(anyhow::anyhow!(message.into()))
+        Self::with_status(StatusCode::UNPROCESSABLE_ENTITY, message)
     }
 
     pub fn conflict(message: impl Into<String>) -> Self {
-        Self(anyhow::anyhow!(message.into()))
+        Self::with_status(StatusCode::CONFLICT, message)
     }
 
     pub fn unauthorized(message: impl Into<String>) -> Self {
-        Self(anyhow::anyhow!(message.into()))
+        Self::with_status(StatusCode::UNAUTHORIZED, message)
+    }
+
+    pub fn forbidden(message: impl Into<String>) -> Self {
+        Self::with_status(StatusCode::FORBIDDEN, message)
     }
 
     pub fn not_found(message: impl Into<String>) -> Self {
-        Self(anyhow::anyhow!(message.into()))
+        Self::with_status(StatusCode::NOT_FOUND, message)
     }
 
     #[allow(dead_code)]
     pub fn internal(message: impl Into<String>) -> Self {
-        Self(anyhow::anyhow!(message.into()))
+        Self::with_status(StatusCode::INTERNAL_SERVER_ERROR, message)
+    }
+}
+
+fn title_for(status: StatusCode) -> &'static str {
+    match status {
+        StatusCode::CONFLICT => "Conflict",
+        StatusCode::NOT_FOUND => "Not Found",
+        StatusCode::UNAUTHORIZED => "Unauthorized",
+        StatusCode::FORBIDDEN => "Forbidden",
+        StatusCode::UNPROCESSABLE_ENTITY => "Validation Error",
+        _ => "Internal Server Error",
     }
 }
 
@@ -66,9 +104,15 @@ impl IntoResponse for AppError {
         
         let (status, title) = if let Some(domain_err) = self.0.downcast_ref::<DomainError>() {
             match domain_err {
-                DomainError::Conflict { .. } => (StatusCode::CONFLICT, "Conflict"),
+                DomainError::Conflict { .. }
+                | DomainError::ArticleInAnotherSeries
+                | DomainError::ReportClosed
+                | DomainError::ReportClaimed => (StatusCode::CONFLICT, "Conflict"),
                 DomainError::NotFound { .. } => (StatusCode::NOT_FOUND, "Not Found"),
                 DomainError::UnauthorizedAction => (StatusCode::UNAUTHORIZED, "Unauthorized"),
+                DomainError::Blocked | DomainError::Suspended => (StatusCode::FORBIDDEN, "Forbidden"),
+                DomainError::UploadTooLarge { .. } => (StatusCode::PAYLOAD_TOO_LARGE, "Payload Too Large"),
+                DomainError::UnsupportedUpload => (StatusCode::UNSUPPORTED_MEDIA_TYPE, "Unsupported Media Type"),
                 DomainError::Database { .. } => (StatusCode::INTERNAL_SERVER_ERROR, "Internal Server Error"),
                 _ => (StatusCode::UNPROCESSABLE_ENTITY, "Validation Error"),
             }
@@ -78,8 +122,12 @@ impl IntoResponse for AppError {
             (StatusCode::NOT_FOUND, "Not Found")
         } else if error_msg.contains("invalid credentials") || error_msg.contains("unauthorized") || error_msg.contains("invalid token") || error_msg.contains("missing authorization") {
             (StatusCode::UNAUTHORIZED, "Unauthorized")
-        } else if error_msg.contains("cannot follow") || error_msg.contains("validation") {
+        } else if error_msg.contains("blocked you") {
+            (StatusCode::FORBIDDEN, "Forbidden")
+        } else if error_msg.contains("cannot follow") || error_msg.contains("yourself") || error_msg.contains("validation") {
             (StatusCode::UNPROCESSABLE_ENTITY, "Validation Error")
+        } else if let Some(err) = self.0.downcast_ref::<StatusError>() {
+            (err.status, title_for(err.status))
         } else {
             (StatusCode::INTERNAL_SERVER_ERROR, "Internal Server Error")
         };
@@ -213,4 +261,32 @@ mod tests {
         let response = err.into_response();
         assert_eq!(response.status(), StatusCode::CONFLICT);
     }
+
+    #[test]
+    fn test_app_error_from_domain_blocked() {
+        let app_err: AppError = DomainError::Blocked.into();
+        let response = app_err.into_response();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[test]
+    fn test_app_error_from_domain_upload_errors() {
+        let too_large: AppError = DomainError::UploadTooLarge { max: 1 }.into();
+        assert_eq!(too_large.into_response().status(), StatusCode::PAYLOAD_TOO_LARGE);
+        let unsupported: AppError = DomainError::UnsupportedUpload.into();
+        assert_eq!(unsupported.into_response().status(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+    }
+
+    #[test]
+    fn test_app_error_constructor_status_without_known_phrase() {
+        assert_eq!(AppError::not_found("article").into_response().status(), StatusCode::NOT_FOUND);
+        assert_eq!(AppError::forbidden("not yours").into_response().status(), StatusCode::FORBIDDEN);
+    }
+
+    #[test]
+    fn test_app_error_message_contains_yourself() {
+        let err = AppError::validation("cannot mute yourself");
+        let response = err.into_response();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
 }
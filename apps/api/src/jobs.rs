@@ -0,0 +1,189 @@
+//! Background jobs that run alongside the HTTP server
+
+use std::time::Duration;
+
+use chrono::Utc;
+use domain::{
+    DomainEvent,
+    repositories::{ArticlesRepository, CommentsRepository, UsersRepository},
+    use_cases::{publish_due_articles, purge_deactivated_accounts, purge_trash},
+};
+use tokio::task::JoinHandle;
+use tracing::{error, info};
+
+use crate::state::AppState;
+
+/// Periodically erases accounts whose deletion grace period has elapsed.
+pub fn spawn_account_purge<U, A, C>(state: AppState<U, A, C>, every: Duration) -> JoinHandle<()>
+where
+    U: UsersRepository + Clone + 'static,
+    A: ArticlesRepository + Clone + 'static,
+    C: CommentsRepository + Clone + 'static,
+{
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(every);
+        loop {
+            interval.tick().await;
+            run_account_purge(&state).await;
+        }
+    })
+}
+
+async fn run_account_purge<U, A, C>(state: &AppState<U, A, C>)
+where
+    U: UsersRepository + Clone,
+    A: ArticlesRepository + Clone,
+    C: CommentsRepository + Clone,
+{
+    let events = match purge_deactivated_accounts(
+        &state.use_cases.users_repo,
+        &state.use_cases.articles_repo,
+        &state.account_deletion,
+        Utc::now(),
+    )
+    .await
+    {
+        Ok(events) => events,
+        Err(err) => {
+            error!(?err, "account purge failed");
+            return;
+        }
+    };
+
+    let erased: Vec<_> = events
+        .iter()
+        .filter_map(|event| match event {
+            DomainEvent::AccountDeleted { user_id, .. } => Some(*user_id),
+            _ => None,
+        })
+        .collect();
+    state.forget_sessions(&erased).await;
+    if !erased.is_empty() {
+        state.related_articles.invalidate_all();
+    }
+
+    for event in events {
+        match event {
+            DomainEvent::AccountErasureFailed { user_id, message } => {
+                error!(?user_id, %message, "account erasure failed")
+            }
+            event => info!(?event, "account erased"),
+        }
+    }
+}
+
+/// Periodically erases trashed articles and comments past their retention period.
+pub fn spawn_trash_purge<U, A, C>(state: AppState<U, A, C>, every: Duration) -> JoinHandle<()>
+where
+    U: UsersRepository + Clone + 'static,
+    A: ArticlesRepository + Clone + 'static,
+    C: CommentsRepository + Clone + 'static,
+{
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(every);
+        loop {
+            interval.tick().await;
+            run_trash_purge(&state).await;
+        }
+    })
+}
+
+async fn run_trash_purge<U, A, C>(state: &AppState<U, A, C>)
+where
+    U: UsersRepository + Clone,
+    A: ArticlesRepository + Clone,
+    C: CommentsRepository + Clone,
+{
+    match purge_trash(
+        &state.use_cases.articles_repo,
+        &state.use_cases.comments_repo,
+        &state.trash_policy,
+        Utc::now(),
+    )
+    .await
+    {
+        Ok(events) => {
+            for event in events {
+                info!(?event, "trashed item erased");
+            }
+        }
+        Err(err) => error!(?err, "trash purge failed"),
+    }
+}
+
+/// Periodically publishes scheduled articles whose time has come.
+pub fn spawn_article_scheduler<U, A, C>(state: AppState<U, A, C>, every: Duration) -> JoinHandle<()>
+where
+    U: UsersRepository + Clone + 'static,
+    A: ArticlesRepository + Clone + 'static,
+    C: CommentsRepository + Clone + 'static,
+{
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(every);
+        loop {
+            interval.tick().await;
+            run_article_scheduler(&state).await;
+        }
+    })
+}
+
+async fn run_article_scheduler<U, A, C>(state: &AppState<U, A, C>)
+where
+    U: UsersRepository + Clone,
+    A: ArticlesRepository + Clone,
+    C: CommentsRepository + Clone,
+{
+    match publish_due_articles(&state.use_cases.articles_repo, Utc::now()).await {
+        Ok(events) => {
+            if !events.is_empty() {
+                state.related_articles.invalidate_all();
+            }
+            for event in events {
+                info!(?event, "scheduled article published");
+            }
+        }
+        Err(err) => error!(?err, "publishing scheduled articles failed"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::{Article, ArticleDraft, ArticleId, Email, PasswordHash, TagList, User, UserId, Username};
+
+    #[tokio::test]
+    async fn test_run_account_purge_drops_sessions() {
+        let state = AppState::default();
+        let user = User::new(
+            UserId::random(),
+            Email::parse("gone@example.com").unwrap(),
+            Username::new("gone").unwrap(),
+            PasswordHash::new("hash").unwrap(),
+            Utc::now(),
+        );
+        state.use_cases.users_repo.create_user(user.clone()).await.unwrap();
+        let long_ago = Utc::now() - state.account_deletion.grace_period - chrono::Duration::days(1);
+        state.use_cases.users_repo.deactivate_user(user.id, long_ago).await.unwrap();
+        state.sessions.write().await.insert("stale-token".to_owned(), user.id);
+
+        run_account_purge(&state).await;
+
+        assert!(state.use_cases.users_repo.get_user_by_id(user.id).await.unwrap().is_none());
+        assert!(state.sessions.read().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_run_trash_purge_erases_expired_articles() {
+        let state = AppState::default();
+        let draft = ArticleDraft::new("Long gone", "Description", "Body", TagList::default()).unwrap();
+        let article = Article::publish(ArticleId::random(), UserId::random(), draft, Utc::now()).unwrap();
+        let articles_repo = &state.use_cases.articles_repo;
+        articles_repo.create_article(article.clone()).await.unwrap();
+        let long_ago = Utc::now() - state.trash_policy.retention - chrono::Duration::days(1);
+        articles_repo.delete_article(article.id, long_ago).await.unwrap();
+
+        run_trash_purge(&state).await;
+
+        assert!(articles_repo.get_trashed_article_by_slug("long-gone").await.unwrap().is_none());
+    }
+}
@@ -1,4 +1,5 @@
 pub mod auth;
 pub mod error;
+pub mod jobs;
 pub mod routes;
 pub mod state;
@@ -1,6 +1,6 @@
 use anyhow::Context;
-use api::{routes::router, state::AppState};
-use common_config::AppConfig;
+use api::{jobs, routes::router, state::AppState};
+use common_config::{AppConfig, DeletedContentMode, UploadBackend};
 use deadpool_postgres::{Config as PoolConfig, ManagerConfig, RecyclingMethod, Runtime};
 use tokio::net::TcpListener;
 use tokio_postgres::NoTls;
@@ -33,12 +33,81 @@ async fn main() -> anyhow::Result<()> {
     let users_repo = data::PostgresUsersRepository::new(pool.clone());
     let articles_repo = data::PostgresArticlesRepository::new(pool.clone());
     let comments_repo = data::PostgresCommentsRepository::new(pool.clone());
+    let revisions_repo = std::sync::Arc::new(data::PostgresRevisionsRepository::new(pool.clone()));
+    let tags_repo = std::sync::Arc::new(data::PostgresTagsRepository::new(pool.clone()));
+    let series_repo = std::sync::Arc::new(data::PostgresSeriesRepository::new(pool.clone()));
+    let reports_repo = std::sync::Arc::new(data::PostgresReportsRepository::new(pool.clone()));
+
+    let uploads = &config.uploads;
+    let blob_store: std::sync::Arc<dyn domain::BlobStore> = match uploads.backend {
+        UploadBackend::Local => std::sync::Arc::new(data::LocalBlobStore::new(&uploads.local_dir)),
+        UploadBackend::S3 => {
+            let s3 = uploads.s3.as_ref().context("uploads.s3 is required by the s3 backend")?;
+            std::sync::Arc::new(data::S3BlobStore::new(s3)?)
+        }
+    };
+    info!(backend = ?uploads.backend, "upload storage initialized");
     
     // Initialize use cases with repositories
-    let use_cases = domain::use_cases::UseCases::new(users_repo, articles_repo, comments_repo);
+    let use_cases = domain::use_cases::UseCases {
+        users_repo,
+        articles_repo,
+        comments_repo,
+        revisions_repo,
+        tags_repo,
+        series_repo,
+        blob_store,
+        reports_repo,
+    };
+
+    let account_deletion = domain::AccountDeletionPolicy::new(
+        chrono::Duration::days(config.accounts.deletion_grace_days.into()),
+        match config.accounts.deleted_content {
+            DeletedContentMode::Delete => domain::AuthoredContentPolicy::Delete,
+            DeletedContentMode::Anonymise => domain::AuthoredContentPolicy::Anonymise,
+        },
+    );
+
+    let export_link_ttl = chrono::Duration::from_std(config.accounts.export_link_ttl)
+        .context("invalid export link ttl")?;
+    let trending_window = chrono::Duration::from_std(config.articles.trending_window)
+        .context("invalid trending window")?;
+    let reactions = config
+        .reactions
+        .kinds
+        .iter()
+        .map(|reaction| {
+            let kind = domain::ReactionKind::new(reaction.kind.clone())
+                .with_context(|| format!("invalid reaction kind {:?}", reaction.kind))?;
+            Ok(domain::Reaction { kind, emoji: reaction.emoji.clone() })
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
 
     // Create app state with use cases
-    let state = AppState::new(use_cases);
+    let state = AppState::new(use_cases)
+        .with_account_deletion(account_deletion)
+        .with_reaction_policy(domain::ReactionPolicy::new(reactions))
+        .with_trash_policy(domain::TrashPolicy::new(chrono::Duration::days(config.trash.retention_days.into())))
+        .with_admins(config.accounts.admins.clone())
+        .with_moderators(config.accounts.moderators.clone())
+        .with_export_link_ttl(export_link_ttl)
+        .with_slug_policy(domain::SlugPolicy::new(config.articles.slug_max_len))
+        .with_render_cache_capacity(config.articles.render_cache_capacity)
+        .with_trending_window(trending_window)
+        .with_related_cache_ttl(config.articles.related_cache_ttl)
+        .with_tag_cache_ttl(config.tags.cache_ttl)
+        .with_upload_policy(domain::UploadPolicy::new(
+            uploads.max_bytes,
+            uploads.thumbnail_size,
+            uploads.public_url.clone(),
+        ))
+        .with_site_urls(domain::SiteUrls::new(config.site.url.clone()))
+        .with_sitemap_page_size(config.site.sitemap_page_size)
+        .with_feed_policy(domain::FeedPolicy::new(config.feeds.title.clone(), config.feeds.entries));
+
+    jobs::spawn_account_purge(state.clone(), config.accounts.purge_interval);
+    jobs::spawn_trash_purge(state.clone(), config.trash.purge_interval);
+    jobs::spawn_article_scheduler(state.clone(), config.articles.publish_interval);
 
     let app = router(state.clone(), telemetry.meter.clone());
 
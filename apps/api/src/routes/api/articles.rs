@@ -1,20 +1,29 @@
+use std::sync::Arc;
+
 use axum::{
     Json, Router,
     extract::{Path, Query, State},
-    http::StatusCode,
-    routing::{delete, get, post},
+    http::{HeaderMap, StatusCode, header},
+    response::{IntoResponse, Response},
+    routing::{delete, get, post, put},
 };
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use domain::{
-    ArticleEnvelope, ArticlesEnvelope, CommentEnvelope, CommentId, CommentsEnvelope,
+    ArticleEnvelope, ArticleSort, ArticlesEnvelope, CommentEnvelope, CommentId, CommentsEnvelope,
+    DiffFormat, Publication, RevisionDiffEnvelope, RevisionId, RevisionsEnvelope,
+    SearchResultsEnvelope, TagMatch,
     use_cases::{
-        create_article, create_comment, delete_article, delete_comment, favorite_article,
-        feed_articles, get_article, list_articles, list_comments, unfavorite_article,
-        update_article, CreateArticleInput, CreateCommentInput, FeedArticlesInput,
-        ListArticlesInput, UpdateArticleInput,
+        accept_coauthor_invitation, add_article_reaction, add_comment_reaction, bookmark_article, create_article, create_comment, delete_article, delete_comment,
+        diff_revisions, favorite_article, feed_articles, get_article, invite_coauthor, list_articles,
+        list_comments, list_revisions, publish_article, related_articles, remove_article_reaction, remove_coauthor,
+        remove_comment_reaction, restore_revision,
+        search_articles, series_navigation, unbookmark_article, unfavorite_article, unpublish_article, update_article,
+        BookmarkArticleInput, CommentReactionInput, CreateArticleInput, CreateCommentInput, FeedArticlesInput,
+        ListArticlesInput, RestoreRevisionInput, SearchArticlesInput, UpdateArticleInput,
     },
 };
 use serde::Deserialize;
+use uuid::Uuid;
 
 use crate::{
     auth::CurrentUser,
@@ -31,37 +40,78 @@ where
     Router::<AppState<U, A, C>>::new()
         .route("/", get(list_articles_handler).post(create_article_handler))
         .route("/feed", get(feed_articles_handler))
+        .route("/search", get(search_articles_handler))
         .route(
             "/{slug}",
             get(get_article_handler).put(update_article_handler).delete(delete_article_handler),
         )
+        .route("/{slug}/related", get(related_articles_handler))
+        .route("/{slug}/publish", post(publish_article_handler))
+        .route("/{slug}/unpublish", post(unpublish_article_handler))
+        .route("/{slug}/revisions", get(list_revisions_handler))
+        .route("/{slug}/revisions/diff", get(diff_revisions_handler))
+        .route("/{slug}/revisions/{id}/restore", post(restore_revision_handler))
         .route(
             "/{slug}/favorite",
             post(favorite_article_handler).delete(unfavorite_article_handler),
         )
+        .route(
+            "/{slug}/bookmark",
+            post(bookmark_article_handler).delete(unbookmark_article_handler),
+        )
+        .route(
+            "/{slug}/reactions/{kind}",
+            put(add_article_reaction_handler).delete(remove_article_reaction_handler),
+        )
+        .route("/{slug}/coauthors", post(invite_coauthor_handler))
+        .route(
+            "/{slug}/coauthors/{username}",
+            put(accept_coauthor_handler).delete(remove_coauthor_handler),
+        )
         .route("/{slug}/comments", get(list_comments_handler).post(create_comment_handler))
         .route("/{slug}/comments/{id}", delete(delete_comment_handler))
+        .route(
+            "/{slug}/comments/{id}/reactions/{kind}",
+            put(add_comment_reaction_handler).delete(remove_comment_reaction_handler),
+        )
 }
 
+/// `tag`, `excludeTag` and `author` take comma-separated lists
 #[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
 struct ListQuery {
     tag: Option<String>,
+    #[serde(default)]
+    tag_match: TagMatch,
+    exclude_tag: Option<String>,
     author: Option<String>,
     favorited: Option<String>,
+    since: Option<DateTime<Utc>>,
+    until: Option<DateTime<Utc>>,
+    #[serde(default)]
+    sort: ArticleSort,
     limit: Option<u32>,
     offset: Option<u32>,
+    cursor: Option<String>,
+}
+
+fn comma_list(value: Option<String>) -> Vec<String> {
+    value
+        .map(|list| list.split(',').map(|item| item.trim().to_owned()).filter(|item| !item.is_empty()).collect())
+        .unwrap_or_default()
 }
 
 #[derive(Debug, Default, Deserialize)]
 struct FeedQuery {
     limit: Option<u32>,
     offset: Option<u32>,
+    cursor: Option<String>,
 }
 
 async fn list_articles_handler<U, A, C>(
     State(state): State<AppState<U, A, C>>,
     Query(query): Query<ListQuery>,
-    _current_user: Option<CurrentUser>,
+    current_user: Option<CurrentUser>,
 ) -> ApiResult<Json<ArticlesEnvelope>>
 where
     U: domain::repositories::UsersRepository + Clone,
@@ -69,14 +119,22 @@ where
     C: domain::repositories::CommentsRepository + Clone,
 {
     let input = ListArticlesInput {
-        tag: query.tag,
-        author: query.author,
+        tags: comma_list(query.tag),
+        tag_match: query.tag_match,
+        excluded_tags: comma_list(query.exclude_tag),
+        authors: comma_list(query.author),
         favorited: query.favorited,
+        since: query.since,
+        until: query.until,
+        sort: query.sort,
+        trending_since: Some(Utc::now() - state.trending_window),
         limit: query.limit,
         offset: query.offset,
+        cursor: query.cursor,
+        viewer_id: current_user.map(|current| current.user.id),
     };
 
-    let envelope = list_articles(&state.use_cases.articles_repo, input).await?;
+    let envelope = list_articles(&state.use_cases.articles_repo, state.use_cases.tags_repo.as_ref(), input).await?;
     Ok(Json(envelope))
 }
 
@@ -93,12 +151,79 @@ where
     let input = FeedArticlesInput {
         limit: query.limit,
         offset: query.offset,
+        cursor: query.cursor,
     };
 
     let envelope = feed_articles(&state.use_cases.articles_repo, user.id, input).await?;
     Ok(Json(envelope))
 }
 
+/// `Accept` media type asking for the rendered body along with the Markdown
+const HTML_BODY_MEDIA_TYPE: &str = "application/vnd.realworld.html+json";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum BodyFormat {
+    Markdown,
+    Html,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct ArticleQuery {
+    render: Option<BodyFormat>,
+}
+
+impl ArticleQuery {
+    /// The query parameter wins over the `Accept` header
+    fn wants_html(&self, headers: &HeaderMap) -> bool {
+        match self.render {
+            Some(format) => format == BodyFormat::Html,
+            None => headers
+                .get_all(header::ACCEPT)
+                .iter()
+                .filter_map(|value| value.to_str().ok())
+                .any(|value| value.contains(HTML_BODY_MEDIA_TYPE)),
+        }
+    }
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct SearchParams {
+    #[serde(default)]
+    q: String,
+    limit: Option<u32>,
+    offset: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DiffQuery {
+    from: Uuid,
+    to: Uuid,
+    #[serde(default)]
+    format: DiffFormat,
+}
+
+async fn search_articles_handler<U, A, C>(
+    State(state): State<AppState<U, A, C>>,
+    Query(params): Query<SearchParams>,
+    current_user: Option<CurrentUser>,
+) -> ApiResult<Json<SearchResultsEnvelope>>
+where
+    U: domain::repositories::UsersRepository + Clone,
+    A: domain::repositories::ArticlesRepository + Clone,
+    C: domain::repositories::CommentsRepository + Clone,
+{
+    let input = SearchArticlesInput {
+        query: params.q,
+        limit: params.limit,
+        offset: params.offset,
+        viewer_id: current_user.map(|current| current.user.id),
+    };
+
+    let envelope = search_articles(&state.use_cases.articles_repo, input).await?;
+    Ok(Json(envelope))
+}
+
 #[derive(Debug, Deserialize)]
 struct CreateArticleRequest {
     article: ArticlePayload,
@@ -111,6 +236,12 @@ struct ArticlePayload {
     body: String,
     #[serde(default, rename = "tagList")]
     tag_list: Vec<String>,
+    /// Keep the article as a draft instead of publishing it
+    #[serde(default)]
+    draft: bool,
+    /// Schedule publication; takes precedence over `draft`
+    #[serde(rename = "publishAt")]
+    publish_at: Option<DateTime<Utc>>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -125,6 +256,27 @@ struct UpdateArticlePayload {
     body: Option<String>,
     #[serde(rename = "tagList")]
     tag_list: Option<Vec<String>>,
+    // SEO fields are cleared with `null`, and left alone when absent
+    #[serde(rename = "canonicalUrl", default, deserialize_with = "nullable")]
+    canonical_url: Option<Option<String>>,
+    #[serde(rename = "metaDescription", default, deserialize_with = "nullable")]
+    meta_description: Option<Option<String>>,
+    #[serde(rename = "socialImage", default, deserialize_with = "nullable")]
+    social_image: Option<Option<String>>,
+}
+
+/// Tells a field set to `null`, `Some(None)`, from one left out, `None`
+fn nullable<'de, D>(deserializer: D) -> Result<Option<Option<String>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    Option::<String>::deserialize(deserializer).map(Some)
+}
+
+#[derive(Debug, Deserialize)]
+struct PublishArticleRequest {
+    #[serde(rename = "publishAt")]
+    publish_at: Option<DateTime<Utc>>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -147,11 +299,17 @@ where
     A: domain::repositories::ArticlesRepository + Clone,
     C: domain::repositories::CommentsRepository + Clone,
 {
+    let publication = match (req.article.publish_at, req.article.draft) {
+        (Some(at), _) => Publication::At(at),
+        (None, true) => Publication::Draft,
+        (None, false) => Publication::Now,
+    };
     let input = CreateArticleInput {
         title: req.article.title,
         description: req.article.description,
         body: req.article.body,
         tag_list: req.article.tag_list,
+        publication,
     };
 
     let author_profile = user.to_profile(false);
@@ -160,18 +318,22 @@ where
         user.id,
         author_profile,
         input,
+        &state.slug_policy,
         Utc::now(),
     )
     .await?;
 
+    state.related_articles.invalidate_all();
     Ok((StatusCode::CREATED, Json(ArticleEnvelope::from(view))))
 }
 
 async fn get_article_handler<U, A, C>(
     State(state): State<AppState<U, A, C>>,
     Path(slug): Path<String>,
+    Query(query): Query<ArticleQuery>,
+    headers: HeaderMap,
     current_user: Option<CurrentUser>,
-) -> ApiResult<Json<ArticleEnvelope>>
+) -> ApiResult<Response>
 where
     U: domain::repositories::UsersRepository + Clone,
     A: domain::repositories::ArticlesRepository + Clone,
@@ -179,7 +341,7 @@ where
 {
     let viewer_id = current_user.as_ref().map(|current| current.user.id);
 
-    let view = get_article(
+    let mut view = get_article(
         &state.use_cases.users_repo,
         &state.use_cases.articles_repo,
         &slug,
@@ -187,8 +349,75 @@ where
     )
     .await
     .map_err(|_| ApiError::not_found("article"))?;
+    let series = series_navigation(
+        &state.use_cases.articles_repo,
+        state.use_cases.series_repo.as_ref(),
+        view.slug.as_str(),
+        viewer_id,
+    )
+    .await?;
+    view = view.with_series(series);
 
-    Ok(Json(ArticleEnvelope::from(view)))
+    if query.wants_html(&headers) {
+        let rendered = state.render_body(&view).await;
+        view = view.with_rendered_body(&rendered);
+    }
+    let vary = [(header::VARY, header::ACCEPT.as_str())];
+
+    // Requested through a slug the article had before a rename: serve it, but
+    // tell the client where it lives now
+    if view.slug.as_str() != slug {
+        let location = format!("/api/articles/{}", view.slug.as_str());
+        return Ok((
+            StatusCode::MOVED_PERMANENTLY,
+            [(header::LOCATION, location)],
+            vary,
+            Json(ArticleEnvelope::from(view)),
+        )
+            .into_response());
+    }
+
+    Ok((vary, Json(ArticleEnvelope::from(view))).into_response())
+}
+
+#[derive(Debug, Deserialize)]
+struct RelatedQuery {
+    limit: Option<u32>,
+}
+
+async fn related_articles_handler<U, A, C>(
+    State(state): State<AppState<U, A, C>>,
+    Path(slug): Path<String>,
+    Query(query): Query<RelatedQuery>,
+    current_user: Option<CurrentUser>,
+) -> ApiResult<Json<ArticlesEnvelope>>
+where
+    U: domain::repositories::UsersRepository + Clone,
+    A: domain::repositories::ArticlesRepository + Clone,
+    C: domain::repositories::CommentsRepository + Clone,
+{
+    let viewer_id = current_user.as_ref().map(|current| current.user.id);
+    let key = (slug.clone(), query.limit);
+    if viewer_id.is_none()
+        && let Some(cached) = state.related_articles.get(&key).await
+    {
+        return Ok(Json(cached.as_ref().clone()));
+    }
+
+    let envelope = related_articles(
+        &state.use_cases.users_repo,
+        &state.use_cases.articles_repo,
+        &slug,
+        viewer_id,
+        query.limit,
+        Utc::now(),
+    )
+    .await?;
+    if viewer_id.is_none() {
+        state.related_articles.insert(key, Arc::new(envelope.clone())).await;
+    }
+
+    Ok(Json(envelope))
 }
 
 async fn update_article_handler<U, A, C>(
@@ -203,18 +432,23 @@ where
     C: domain::repositories::CommentsRepository + Clone,
 {
     let input = UpdateArticleInput {
+        slug,
         title: req.article.title,
         description: req.article.description,
         body: req.article.body,
         tag_list: req.article.tag_list,
+        canonical_url: req.article.canonical_url,
+        meta_description: req.article.meta_description,
+        social_image: req.article.social_image,
     };
 
     let view = update_article(
         &state.use_cases.users_repo,
         &state.use_cases.articles_repo,
-        &slug,
+        state.use_cases.revisions_repo.as_ref(),
         user.id,
         input,
+        &state.slug_policy,
         Utc::now(),
     )
     .await
@@ -226,6 +460,7 @@ where
         _ => ApiError::from(e),
     })?;
 
+    state.related_articles.invalidate_all();
     Ok(Json(ArticleEnvelope::from(view)))
 }
 
@@ -239,13 +474,164 @@ where
     A: domain::repositories::ArticlesRepository + Clone,
     C: domain::repositories::CommentsRepository + Clone,
 {
-    delete_article(&state.use_cases.articles_repo, &slug, user.id)
+    delete_article(&state.use_cases.articles_repo, &slug, user.id, Utc::now())
         .await
         .map_err(|_| ApiError::not_found("article"))?;
 
+    state.related_articles.invalidate_all();
     Ok(())
 }
 
+async fn publish_article_handler<U, A, C>(
+    State(state): State<AppState<U, A, C>>,
+    CurrentUser { user, .. }: CurrentUser,
+    Path(slug): Path<String>,
+    req: Option<Json<PublishArticleRequest>>,
+) -> ApiResult<Json<ArticleEnvelope>>
+where
+    U: domain::repositories::UsersRepository + Clone,
+    A: domain::repositories::ArticlesRepository + Clone,
+    C: domain::repositories::CommentsRepository + Clone,
+{
+    let publish_at = req.and_then(|Json(req)| req.publish_at);
+
+    let view = publish_article(
+        &state.use_cases.users_repo,
+        &state.use_cases.articles_repo,
+        &slug,
+        user.id,
+        publish_at,
+        Utc::now(),
+    )
+    .await
+    .map_err(|e| match e {
+        domain::DomainError::UnauthorizedAction => {
+            ApiError::forbidden("cannot publish another user's article")
+        }
+        _ => ApiError::not_found("article"),
+    })?;
+
+    state.related_articles.invalidate_all();
+    Ok(Json(ArticleEnvelope::from(view)))
+}
+
+async fn unpublish_article_handler<U, A, C>(
+    State(state): State<AppState<U, A, C>>,
+    CurrentUser { user, .. }: CurrentUser,
+    Path(slug): Path<String>,
+) -> ApiResult<Json<ArticleEnvelope>>
+where
+    U: domain::repositories::UsersRepository + Clone,
+    A: domain::repositories::ArticlesRepository + Clone,
+    C: domain::repositories::CommentsRepository + Clone,
+{
+    let view = unpublish_article(
+        &state.use_cases.users_repo,
+        &state.use_cases.articles_repo,
+        &slug,
+        user.id,
+        Utc::now(),
+    )
+    .await
+    .map_err(|e| match e {
+        domain::DomainError::UnauthorizedAction => {
+            ApiError::forbidden("cannot unpublish another user's article")
+        }
+        _ => ApiError::not_found("article"),
+    })?;
+
+    state.related_articles.invalidate_all();
+    Ok(Json(ArticleEnvelope::from(view)))
+}
+
+async fn list_revisions_handler<U, A, C>(
+    State(state): State<AppState<U, A, C>>,
+    CurrentUser { user, .. }: CurrentUser,
+    Path(slug): Path<String>,
+) -> ApiResult<Json<RevisionsEnvelope>>
+where
+    U: domain::repositories::UsersRepository + Clone,
+    A: domain::repositories::ArticlesRepository + Clone,
+    C: domain::repositories::CommentsRepository + Clone,
+{
+    let revisions = list_revisions(
+        &state.use_cases.users_repo,
+        &state.use_cases.articles_repo,
+        state.use_cases.revisions_repo.as_ref(),
+        &slug,
+        user.id,
+    )
+    .await
+    .map_err(revision_error)?;
+
+    Ok(Json(revisions))
+}
+
+async fn diff_revisions_handler<U, A, C>(
+    State(state): State<AppState<U, A, C>>,
+    CurrentUser { user, .. }: CurrentUser,
+    Path(slug): Path<String>,
+    Query(query): Query<DiffQuery>,
+) -> ApiResult<Json<RevisionDiffEnvelope>>
+where
+    U: domain::repositories::UsersRepository + Clone,
+    A: domain::repositories::ArticlesRepository + Clone,
+    C: domain::repositories::CommentsRepository + Clone,
+{
+    let diff = diff_revisions(
+        &state.use_cases.articles_repo,
+        state.use_cases.revisions_repo.as_ref(),
+        &slug,
+        user.id,
+        RevisionId::new(query.from),
+        RevisionId::new(query.to),
+        query.format,
+    )
+    .await
+    .map_err(revision_error)?;
+
+    Ok(Json(RevisionDiffEnvelope::from(diff)))
+}
+
+async fn restore_revision_handler<U, A, C>(
+    State(state): State<AppState<U, A, C>>,
+    CurrentUser { user, .. }: CurrentUser,
+    Path((slug, id)): Path<(String, Uuid)>,
+) -> ApiResult<Json<ArticleEnvelope>>
+where
+    U: domain::repositories::UsersRepository + Clone,
+    A: domain::repositories::ArticlesRepository + Clone,
+    C: domain::repositories::CommentsRepository + Clone,
+{
+    let view = restore_revision(
+        &state.use_cases.users_repo,
+        &state.use_cases.articles_repo,
+        state.use_cases.revisions_repo.as_ref(),
+        RestoreRevisionInput {
+            slug,
+            revision_id: RevisionId::new(id),
+        },
+        user.id,
+        &state.slug_policy,
+        Utc::now(),
+    )
+    .await
+    .map_err(revision_error)?;
+
+    state.related_articles.invalidate_all();
+    Ok(Json(ArticleEnvelope::from(view)))
+}
+
+fn revision_error(e: domain::DomainError) -> ApiError {
+    match e {
+        domain::DomainError::NotFound { entity } => ApiError::not_found(entity),
+        domain::DomainError::UnauthorizedAction => {
+            ApiError::forbidden("only the author can see the history of an article")
+        }
+        _ => ApiError::from(e),
+    }
+}
+
 async fn favorite_article_handler<U, A, C>(
     State(state): State<AppState<U, A, C>>,
     CurrentUser { user, .. }: CurrentUser,
@@ -261,10 +647,15 @@ where
         &state.use_cases.articles_repo,
         &slug,
         user.id,
+        Utc::now(),
     )
     .await
-    .map_err(|_| ApiError::not_found("article"))?;
+    .map_err(|e| match e {
+        domain::DomainError::Blocked => ApiError::forbidden("the author has blocked you"),
+        _ => ApiError::not_found("article"),
+    })?;
 
+    state.related_articles.invalidate_all();
     Ok(Json(ArticleEnvelope::from(view)))
 }
 
@@ -287,9 +678,222 @@ where
     .await
     .map_err(|_| ApiError::not_found("article"))?;
 
+    state.related_articles.invalidate_all();
     Ok(Json(ArticleEnvelope::from(view)))
 }
 
+async fn add_article_reaction_handler<U, A, C>(
+    State(state): State<AppState<U, A, C>>,
+    CurrentUser { user, .. }: CurrentUser,
+    Path((slug, kind)): Path<(String, String)>,
+) -> ApiResult<Json<ArticleEnvelope>>
+where
+    U: domain::repositories::UsersRepository + Clone,
+    A: domain::repositories::ArticlesRepository + Clone,
+    C: domain::repositories::CommentsRepository + Clone,
+{
+    let view = add_article_reaction(
+        &state.use_cases.users_repo,
+        &state.use_cases.articles_repo,
+        &slug,
+        &kind,
+        user.id,
+        &state.reaction_policy,
+    )
+    .await
+    .map_err(|e| match e {
+        domain::DomainError::UnknownReaction => ApiError::from(e),
+        domain::DomainError::Blocked => ApiError::forbidden("the author has blocked you"),
+        _ => ApiError::not_found("article"),
+    })?;
+
+    Ok(Json(ArticleEnvelope::from(view)))
+}
+
+async fn remove_article_reaction_handler<U, A, C>(
+    State(state): State<AppState<U, A, C>>,
+    CurrentUser { user, .. }: CurrentUser,
+    Path((slug, kind)): Path<(String, String)>,
+) -> ApiResult<Json<ArticleEnvelope>>
+where
+    U: domain::repositories::UsersRepository + Clone,
+    A: domain::repositories::ArticlesRepository + Clone,
+    C: domain::repositories::CommentsRepository + Clone,
+{
+    let view = remove_article_reaction(
+        &state.use_cases.users_repo,
+        &state.use_cases.articles_repo,
+        &slug,
+        &kind,
+        user.id,
+    )
+    .await
+    .map_err(|e| match e {
+        domain::DomainError::UnknownReaction => ApiError::from(e),
+        _ => ApiError::not_found("article"),
+    })?;
+
+    Ok(Json(ArticleEnvelope::from(view)))
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct BookmarkRequest {
+    #[serde(default)]
+    bookmark: BookmarkPayload,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct BookmarkPayload {
+    note: Option<String>,
+    folder: Option<String>,
+}
+
+/// Saves the article to the current user's reading list; the body, with an
+/// optional note and folder, may be left out
+async fn bookmark_article_handler<U, A, C>(
+    State(state): State<AppState<U, A, C>>,
+    CurrentUser { user, .. }: CurrentUser,
+    Path(slug): Path<String>,
+    req: Option<Json<BookmarkRequest>>,
+) -> ApiResult<Json<ArticleEnvelope>>
+where
+    U: domain::repositories::UsersRepository + Clone,
+    A: domain::repositories::ArticlesRepository + Clone,
+    C: domain::repositories::CommentsRepository + Clone,
+{
+    let payload = req.map(|Json(req)| req.bookmark).unwrap_or_default();
+    let input = BookmarkArticleInput {
+        note: payload.note,
+        folder: payload.folder,
+    };
+    let view = bookmark_article(
+        &state.use_cases.users_repo,
+        &state.use_cases.articles_repo,
+        &slug,
+        user.id,
+        input,
+    )
+    .await?;
+
+    Ok(Json(ArticleEnvelope::from(view)))
+}
+
+async fn unbookmark_article_handler<U, A, C>(
+    State(state): State<AppState<U, A, C>>,
+    CurrentUser { user, .. }: CurrentUser,
+    Path(slug): Path<String>,
+) -> ApiResult<Json<ArticleEnvelope>>
+where
+    U: domain::repositories::UsersRepository + Clone,
+    A: domain::repositories::ArticlesRepository + Clone,
+    C: domain::repositories::CommentsRepository + Clone,
+{
+    let view = unbookmark_article(
+        &state.use_cases.users_repo,
+        &state.use_cases.articles_repo,
+        &slug,
+        user.id,
+    )
+    .await?;
+
+    Ok(Json(ArticleEnvelope::from(view)))
+}
+
+#[derive(Debug, Deserialize)]
+struct InviteCoauthorRequest {
+    coauthor: InviteCoauthorPayload,
+}
+
+#[derive(Debug, Deserialize)]
+struct InviteCoauthorPayload {
+    username: String,
+}
+
+async fn invite_coauthor_handler<U, A, C>(
+    State(state): State<AppState<U, A, C>>,
+    CurrentUser { user, .. }: CurrentUser,
+    Path(slug): Path<String>,
+    Json(req): Json<InviteCoauthorRequest>,
+) -> ApiResult<Json<ArticleEnvelope>>
+where
+    U: domain::repositories::UsersRepository + Clone,
+    A: domain::repositories::ArticlesRepository + Clone,
+    C: domain::repositories::CommentsRepository + Clone,
+{
+    let view = invite_coauthor(
+        &state.use_cases.users_repo,
+        &state.use_cases.articles_repo,
+        &slug,
+        user.id,
+        &req.coauthor.username,
+        Utc::now(),
+    )
+    .await
+    .map_err(|e| match e {
+        domain::DomainError::UnauthorizedAction => {
+            ApiError::unauthorized("only the owner can invite co-authors")
+        }
+        _ => ApiError::from(e),
+    })?;
+
+    Ok(Json(ArticleEnvelope::from(view)))
+}
+
+/// Invitees accept by putting themselves on the article
+async fn accept_coauthor_handler<U, A, C>(
+    State(state): State<AppState<U, A, C>>,
+    CurrentUser { user, .. }: CurrentUser,
+    Path((slug, username)): Path<(String, String)>,
+) -> ApiResult<Json<ArticleEnvelope>>
+where
+    U: domain::repositories::UsersRepository + Clone,
+    A: domain::repositories::ArticlesRepository + Clone,
+    C: domain::repositories::CommentsRepository + Clone,
+{
+    if user.username.as_str() != username {
+        return Err(ApiError::unauthorized("cannot accept another user's invitation"));
+    }
+
+    let view = accept_coauthor_invitation(
+        &state.use_cases.users_repo,
+        &state.use_cases.articles_repo,
+        &slug,
+        user.id,
+        Utc::now(),
+    )
+    .await?;
+
+    Ok(Json(ArticleEnvelope::from(view)))
+}
+
+async fn remove_coauthor_handler<U, A, C>(
+    State(state): State<AppState<U, A, C>>,
+    CurrentUser { user, .. }: CurrentUser,
+    Path((slug, username)): Path<(String, String)>,
+) -> ApiResult<()>
+where
+    U: domain::repositories::UsersRepository + Clone,
+    A: domain::repositories::ArticlesRepository + Clone,
+    C: domain::repositories::CommentsRepository + Clone,
+{
+    remove_coauthor(
+        &state.use_cases.users_repo,
+        &state.use_cases.articles_repo,
+        &slug,
+        user.id,
+        &username,
+    )
+    .await
+    .map_err(|e| match e {
+        domain::DomainError::UnauthorizedAction => {
+            ApiError::unauthorized("cannot remove another co-author")
+        }
+        _ => ApiError::from(e),
+    })?;
+
+    Ok(())
+}
+
 async fn list_comments_handler<U, A, C>(
     State(state): State<AppState<U, A, C>>,
     Path(slug): Path<String>,
@@ -340,7 +944,10 @@ where
         Utc::now(),
     )
     .await
-    .map_err(|_| ApiError::not_found("article"))?;
+    .map_err(|e| match e {
+        domain::DomainError::Blocked => ApiError::forbidden("the author has blocked you"),
+        _ => ApiError::not_found("article"),
+    })?;
 
     Ok((StatusCode::CREATED, Json(CommentEnvelope::from(view))))
 }
@@ -363,6 +970,7 @@ where
         &slug,
         comment_id,
         user.id,
+        Utc::now(),
     )
     .await
     .map_err(|_| ApiError::not_found("comment"))?;
@@ -370,9 +978,75 @@ where
     Ok(())
 }
 
+async fn add_comment_reaction_handler<U, A, C>(
+    State(state): State<AppState<U, A, C>>,
+    CurrentUser { user, .. }: CurrentUser,
+    Path((slug, id, kind)): Path<(String, i64, String)>,
+) -> ApiResult<Json<CommentEnvelope>>
+where
+    U: domain::repositories::UsersRepository + Clone,
+    A: domain::repositories::ArticlesRepository + Clone,
+    C: domain::repositories::CommentsRepository + Clone,
+{
+    let view = add_comment_reaction(
+        &state.use_cases.users_repo,
+        &state.use_cases.articles_repo,
+        &state.use_cases.comments_repo,
+        CommentReactionInput {
+            slug,
+            comment_id: CommentId::new(id),
+            kind,
+        },
+        user.id,
+        &state.reaction_policy,
+    )
+    .await
+    .map_err(|e| match e {
+        domain::DomainError::UnknownReaction => ApiError::from(e),
+        domain::DomainError::Blocked => ApiError::forbidden("the author has blocked you"),
+        _ => ApiError::not_found("comment"),
+    })?;
+
+    Ok(Json(CommentEnvelope::from(view)))
+}
+
+async fn remove_comment_reaction_handler<U, A, C>(
+    State(state): State<AppState<U, A, C>>,
+    CurrentUser { user, .. }: CurrentUser,
+    Path((slug, id, kind)): Path<(String, i64, String)>,
+) -> ApiResult<Json<CommentEnvelope>>
+where
+    U: domain::repositories::UsersRepository + Clone,
+    A: domain::repositories::ArticlesRepository + Clone,
+    C: domain::repositories::CommentsRepository + Clone,
+{
+    let view = remove_comment_reaction(
+        &state.use_cases.users_repo,
+        &state.use_cases.articles_repo,
+        &state.use_cases.comments_repo,
+        CommentReactionInput {
+            slug,
+            comment_id: CommentId::new(id),
+            kind,
+        },
+        user.id,
+    )
+    .await
+    .map_err(|e| match e {
+        domain::DomainError::UnknownReaction => ApiError::from(e),
+        _ => ApiError::not_found("comment"),
+    })?;
+
+    Ok(Json(CommentEnvelope::from(view)))
+}
+
 #[cfg(test)]
 mod tests {
-    use domain::{Email, PasswordHash, User, Username, UserId};
+    use super::*;
+    use axum::{body::Body, http::Request};
+    use domain::repositories::{ArticlesRepository, UsersRepository};
+    use domain::{Article, ArticleDraft, ArticleId, Email, PasswordHash, Slug, TagList, User, UserId, Username};
+    use tower::ServiceExt;
 
     fn create_test_user(id: UserId, username: &str, email: &str) -> User {
         User::new(
@@ -389,4 +1063,660 @@ mod tests {
         let user = create_test_user(UserId::random(), "test", "test@example.com");
         assert_eq!(user.username.as_str(), "test");
     }
+
+    #[tokio::test]
+    async fn test_get_article_by_previous_slug_redirects() {
+        let state = AppState::default();
+        let author = create_test_user(UserId::random(), "author", "author@example.com");
+        state.use_cases.users_repo.create_user(author.clone()).await.unwrap();
+        let draft = ArticleDraft::new("New Title", "desc", "body", TagList::default()).unwrap();
+        let article = Article::publish(ArticleId::random(), author.id, draft, Utc::now()).unwrap();
+        let articles_repo = &state.use_cases.articles_repo;
+        articles_repo.create_article(article.clone()).await.unwrap();
+        articles_repo
+            .record_slug_change(article.id, "old-title", article.slug.as_str())
+            .await
+            .unwrap();
+
+        let app = router().with_state(state);
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/old-title")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::MOVED_PERMANENTLY);
+        assert_eq!(
+            response.headers().get(header::LOCATION).unwrap(),
+            "/api/articles/new-title"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_article_links_series_neighbours() {
+        let state = AppState::default();
+        let author = create_test_user(UserId::random(), "author", "author@example.com");
+        state.use_cases.users_repo.create_user(author.clone()).await.unwrap();
+        for title in ["Part One", "Part Two"] {
+            let draft = ArticleDraft::new(title, "desc", "body", TagList::default()).unwrap();
+            let article = Article::publish(ArticleId::random(), author.id, draft, Utc::now()).unwrap();
+            state.use_cases.articles_repo.create_article(article).await.unwrap();
+        }
+        let input = domain::use_cases::CreateSeriesInput {
+            title: "Rust in Production".to_owned(),
+            articles: vec!["part-one".to_owned(), "part-two".to_owned()],
+            ..Default::default()
+        };
+        domain::use_cases::create_series(
+            &state.use_cases.users_repo,
+            &state.use_cases.articles_repo,
+            state.use_cases.series_repo.as_ref(),
+            author.id,
+            input,
+            &state.slug_policy,
+            Utc::now(),
+        )
+        .await
+        .unwrap();
+        let app = router().with_state(state);
+        let fetch = |uri: &'static str| {
+            let app = app.clone();
+            async move {
+                let response = app
+                    .oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap())
+                    .await
+                    .unwrap();
+                let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+                serde_json::from_slice::<serde_json::Value>(&bytes).unwrap()
+            }
+        };
+
+        let body = fetch("/part-two").await;
+        let series = &body["article"]["series"];
+        assert_eq!(series["slug"], "rust-in-production");
+        assert_eq!((series["part"].as_u64(), series["total"].as_u64()), (Some(2), Some(2)));
+        assert_eq!(series["previous"]["slug"], "part-one");
+        assert!(series["next"].is_null());
+
+        let body = fetch("/part-one").await;
+        assert_eq!(body["article"]["series"]["next"]["title"], "Part Two");
+    }
+
+    #[tokio::test]
+    async fn test_draft_hidden_until_published() {
+        let state = AppState::default();
+        let author = create_test_user(UserId::random(), "author", "author@example.com");
+        state.use_cases.users_repo.create_user(author.clone()).await.unwrap();
+        state.sessions.write().await.insert("author-token".to_owned(), author.id);
+        let app = router().with_state(state);
+
+        let create = serde_json::json!({
+            "article": {"title": "Draft", "description": "desc", "body": "body", "draft": true}
+        });
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/")
+                    .header("authorization", "Token author-token")
+                    .header("content-type", "application/json")
+                    .body(Body::from(create.to_string()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let anonymous = |app: Router| async move {
+            app.oneshot(Request::builder().uri("/draft").body(Body::empty()).unwrap())
+                .await
+                .unwrap()
+                .status()
+        };
+        assert_eq!(anonymous(app.clone()).await, StatusCode::NOT_FOUND);
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/draft/publish")
+                    .header("authorization", "Token author-token")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(anonymous(app).await, StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_revisions_diff_and_restore() {
+        let state = AppState::default();
+        let author = create_test_user(UserId::random(), "author", "author@example.com");
+        let other = create_test_user(UserId::random(), "other", "other@example.com");
+        for user in [&author, &other] {
+            state.use_cases.users_repo.create_user(user.clone()).await.unwrap();
+        }
+        state.sessions.write().await.insert("author-token".to_owned(), author.id);
+        state.sessions.write().await.insert("other-token".to_owned(), other.id);
+        let draft = ArticleDraft::new("Title", "desc", "first body", TagList::default()).unwrap();
+        let article = Article::publish(ArticleId::random(), author.id, draft, Utc::now()).unwrap();
+        state.use_cases.articles_repo.create_article(article).await.unwrap();
+        let app = router().with_state(state);
+
+        let call = |method: &str, uri: &str, token: &str, body: Option<serde_json::Value>| {
+            let request = Request::builder()
+                .method(method)
+                .uri(uri)
+                .header("authorization", format!("Token {token}"))
+                .header("content-type", "application/json")
+                .body(body.map_or_else(Body::empty, |body| Body::from(body.to_string())))
+                .unwrap();
+            let app = app.clone();
+            async move {
+                let response = app.oneshot(request).await.unwrap();
+                let status = response.status();
+                let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+                (status, serde_json::from_slice::<serde_json::Value>(&bytes).unwrap_or_default())
+            }
+        };
+
+        let update = serde_json::json!({"article": {"body": "second body"}});
+        let (status, _) = call("PUT", "/title", "author-token", Some(update)).await;
+        assert_eq!(status, StatusCode::OK);
+
+        let (status, _) = call("GET", "/title/revisions", "other-token", None).await;
+        assert_eq!(status, StatusCode::FORBIDDEN);
+        let (status, listed) = call("GET", "/title/revisions", "author-token", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(listed["revisionsCount"], 2);
+        let newest = listed["revisions"][0]["id"].as_str().unwrap().to_owned();
+        let oldest = listed["revisions"][1]["id"].as_str().unwrap().to_owned();
+
+        let uri = format!("/title/revisions/diff?from={oldest}&to={newest}&format=word");
+        let (status, diff) = call("GET", &uri, "author-token", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(
+            diff["diff"]["body"],
+            serde_json::json!([
+                {"op": "delete", "text": "first"},
+                {"op": "insert", "text": "second"},
+                {"op": "equal", "text": " body"},
+            ])
+        );
+
+        let (status, restored) = call("POST", &format!("/title/revisions/{oldest}/restore"), "author-token", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(restored["article"]["body"], "first body");
+    }
+
+    #[tokio::test]
+    async fn test_update_article_seo_fields() {
+        let state = AppState::default();
+        let author = create_test_user(UserId::random(), "author", "author@example.com");
+        state.use_cases.users_repo.create_user(author.clone()).await.unwrap();
+        state.sessions.write().await.insert("author-token".to_owned(), author.id);
+        let draft = ArticleDraft::new("Title", "desc", "body", TagList::default()).unwrap();
+        let article = Article::publish(ArticleId::random(), author.id, draft, Utc::now()).unwrap();
+        state.use_cases.articles_repo.create_article(article).await.unwrap();
+        let app = router().with_state(state);
+
+        let update = |article: serde_json::Value| {
+            let request = Request::builder()
+                .method("PUT")
+                .uri("/title")
+                .header("authorization", "Token author-token")
+                .header("content-type", "application/json")
+                .body(Body::from(serde_json::json!({ "article": article }).to_string()))
+                .unwrap();
+            let app = app.clone();
+            async move {
+                let response = app.oneshot(request).await.unwrap();
+                let status = response.status();
+                let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+                (status, serde_json::from_slice::<serde_json::Value>(&bytes).unwrap())
+            }
+        };
+
+        let (status, body) = update(serde_json::json!({
+            "canonicalUrl": "https://blog.example.com/title",
+            "metaDescription": "For search engines",
+            "socialImage": "/api/uploads/cover.png",
+        }))
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["article"]["canonicalUrl"], "https://blog.example.com/title");
+        assert_eq!(body["article"]["metaDescription"], "For search engines");
+        assert_eq!(body["article"]["socialImage"], "/api/uploads/cover.png");
+
+        // `null` clears a field; leaving it out keeps it
+        let (status, body) = update(serde_json::json!({ "canonicalUrl": null, "body": "new body" })).await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(body["article"]["canonicalUrl"].is_null());
+        assert_eq!(body["article"]["metaDescription"], "For search engines");
+
+        let (status, _) = update(serde_json::json!({ "canonicalUrl": "not a url" })).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[tokio::test]
+    async fn test_search_articles_endpoint() {
+        let state = AppState::default();
+        let author = create_test_user(UserId::random(), "author", "author@example.com");
+        state.use_cases.users_repo.create_user(author.clone()).await.unwrap();
+        for (title, body) in [("Async Rust", "Futures and executors"), ("Gardening", "Tomatoes")] {
+            let draft = ArticleDraft::new(title, "desc", body, TagList::default()).unwrap();
+            let article = Article::publish(ArticleId::random(), author.id, draft, Utc::now()).unwrap();
+            state.use_cases.articles_repo.create_article(article).await.unwrap();
+        }
+        let app = router().with_state(state);
+
+        let response = app
+            .clone()
+            .oneshot(Request::builder().uri("/search?q=execut*").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(body["articlesCount"], 1);
+        assert_eq!(body["articles"][0]["slug"], "async-rust");
+        assert_eq!(body["articles"][0]["highlight"], "Futures and <mark>executors</mark>");
+
+        let response = app
+            .oneshot(Request::builder().uri("/search?q=%22%22").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[tokio::test]
+    async fn test_get_article_renders_body_html_on_request() {
+        let state = AppState::default();
+        let author = create_test_user(UserId::random(), "author", "author@example.com");
+        state.use_cases.users_repo.create_user(author.clone()).await.unwrap();
+        let body = "## Getting started\n\nHello <script>alert(1)</script>";
+        let draft = ArticleDraft::new("Rendered", "desc", body, TagList::default()).unwrap();
+        let article = Article::publish(ArticleId::random(), author.id, draft, Utc::now()).unwrap();
+        let article = state.use_cases.articles_repo.create_article(article).await.unwrap();
+        let app = router().with_state(state.clone());
+
+        let fetch = |request: Request<Body>| {
+            let app = app.clone();
+            async move {
+                let response = app.oneshot(request).await.unwrap();
+                assert_eq!(response.status(), StatusCode::OK);
+                assert_eq!(response.headers().get(header::VARY).unwrap(), "accept");
+                let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+                serde_json::from_slice::<serde_json::Value>(&bytes).unwrap()
+            }
+        };
+
+        let plain = fetch(Request::builder().uri("/rendered").body(Body::empty()).unwrap()).await;
+        assert!(plain["article"].get("bodyHtml").is_none());
+
+        let by_query = fetch(Request::builder().uri("/rendered?render=html").body(Body::empty()).unwrap()).await;
+        let html = by_query["article"]["bodyHtml"].as_str().unwrap();
+        assert!(html.contains(r#"<h2 id="user-content-getting-started">Getting started</h2>"#));
+        assert!(!html.contains("<script"));
+        assert_eq!(by_query["article"]["toc"][0]["id"], "user-content-getting-started");
+
+        let by_accept = fetch(
+            Request::builder()
+                .uri("/rendered")
+                .header(header::ACCEPT, HTML_BODY_MEDIA_TYPE)
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await;
+        assert_eq!(by_accept["article"]["bodyHtml"], by_query["article"]["bodyHtml"]);
+        state.rendered_bodies.run_pending_tasks().await;
+        assert_eq!(state.rendered_bodies.entry_count(), 1);
+
+        // A rename reuses the rendering; a new body gets its own
+        let mut renamed = article;
+        renamed.slug = Slug::from_title("Renamed").unwrap();
+        let renamed = state.use_cases.articles_repo.update_article(renamed).await.unwrap();
+        fetch(Request::builder().uri("/renamed?render=html").body(Body::empty()).unwrap()).await;
+        state.rendered_bodies.run_pending_tasks().await;
+        assert_eq!(state.rendered_bodies.entry_count(), 1);
+
+        let mut edited = renamed;
+        edited.body = "## Getting started\n\nHello again".to_owned();
+        state.use_cases.articles_repo.update_article(edited).await.unwrap();
+        let again = fetch(Request::builder().uri("/renamed?render=html").body(Body::empty()).unwrap()).await;
+        assert!(again["article"]["bodyHtml"].as_str().unwrap().contains("Hello again"));
+        state.rendered_bodies.run_pending_tasks().await;
+        assert_eq!(state.rendered_bodies.entry_count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_list_articles_follows_cursors() {
+        let state = AppState::default();
+        let author = create_test_user(UserId::random(), "author", "author@example.com");
+        state.use_cases.users_repo.create_user(author.clone()).await.unwrap();
+        let now = Utc::now();
+        for i in 0..3 {
+            let draft = ArticleDraft::new(format!("Article {i}"), "desc", "body", TagList::default()).unwrap();
+            let created_at = now + chrono::Duration::seconds(i);
+            let article = Article::publish(ArticleId::random(), author.id, draft, created_at).unwrap();
+            state.use_cases.articles_repo.create_article(article).await.unwrap();
+        }
+        let app = router().with_state(state);
+
+        let fetch = |uri: String| {
+            let app = app.clone();
+            async move {
+                let response = app.oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap()).await.unwrap();
+                let status = response.status();
+                let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+                (status, serde_json::from_slice::<serde_json::Value>(&bytes).unwrap())
+            }
+        };
+
+        let (_, first) = fetch("/?limit=2".to_owned()).await;
+        assert_eq!(first["articles"][0]["slug"], "article-2");
+        assert!(first.get("prevCursor").is_none());
+        let next = first["nextCursor"].as_str().unwrap();
+
+        let (_, second) = fetch(format!("/?limit=2&cursor={next}")).await;
+        assert_eq!(second["articlesCount"], 3);
+        assert_eq!(second["articles"].as_array().unwrap().len(), 1);
+        assert_eq!(second["articles"][0]["slug"], "article-0");
+        assert!(second.get("nextCursor").is_none());
+        let prev = second["prevCursor"].as_str().unwrap();
+
+        let (_, back) = fetch(format!("/?limit=2&cursor={prev}")).await;
+        assert_eq!(back["articles"], first["articles"]);
+
+        let (status, _) = fetch("/?cursor=garbage".to_owned()).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[tokio::test]
+    async fn test_list_articles_sort_and_filters() {
+        let state = AppState::default();
+        let author = create_test_user(UserId::random(), "author", "author@example.com");
+        state.use_cases.users_repo.create_user(author.clone()).await.unwrap();
+        let now = Utc::now();
+        for (i, tags) in [vec!["rust", "web"], vec!["rust"], vec!["go"]].into_iter().enumerate() {
+            let draft = ArticleDraft::new(format!("Article {i}"), "desc", "body", TagList::new(tags).unwrap()).unwrap();
+            let created_at = now + chrono::Duration::seconds(i as i64);
+            let article = Article::publish(ArticleId::random(), author.id, draft, created_at).unwrap();
+            state.use_cases.articles_repo.create_article(article).await.unwrap();
+        }
+        let app = router().with_state(state);
+
+        let fetch = |uri: String| {
+            let app = app.clone();
+            async move {
+                let response = app.oneshot(Request::builder().uri(uri).body(Body::empty()).unwrap()).await.unwrap();
+                let status = response.status();
+                let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+                (status, serde_json::from_slice::<serde_json::Value>(&bytes).unwrap_or_default())
+            }
+        };
+
+        let (_, any) = fetch("/?tag=web,go&author=author,nobody".to_owned()).await;
+        assert_eq!(any["articlesCount"], 2);
+        let (_, all) = fetch("/?tag=rust,web&tagMatch=all".to_owned()).await;
+        assert_eq!(all["articles"][0]["slug"], "article-0");
+        let (_, excluded) = fetch("/?tag=rust&excludeTag=web&sort=popular".to_owned()).await;
+        assert_eq!(excluded["articlesCount"], 1);
+        assert!(excluded.get("nextCursor").is_none());
+
+        let cursor = domain::Cursor::older_than(now, ArticleId::random()).encode();
+        let (status, body) = fetch(format!("/?sort=trending&cursor={cursor}")).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert!(body.to_string().contains("sorted by recent"));
+        let (status, _) = fetch("/?sort=sideways".to_owned()).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_coauthor_invitation_flow() {
+        let state = AppState::default();
+        let owner = create_test_user(UserId::random(), "owner", "owner@example.com");
+        let coauthor = create_test_user(UserId::random(), "coauthor", "coauthor@example.com");
+        let reader = create_test_user(UserId::random(), "reader", "reader@example.com");
+        for (user, token) in [
+            (&owner, "owner-token"),
+            (&coauthor, "coauthor-token"),
+            (&reader, "reader-token"),
+        ] {
+            state.use_cases.users_repo.create_user(user.clone()).await.unwrap();
+            state.sessions.write().await.insert(token.to_owned(), user.id);
+        }
+        state.use_cases.users_repo.follow_user(reader.id, coauthor.id).await.unwrap();
+        let draft = ArticleDraft::new("Joint Work", "desc", "body", TagList::default()).unwrap();
+        let article = Article::publish(ArticleId::random(), owner.id, draft, Utc::now()).unwrap();
+        state.use_cases.articles_repo.create_article(article).await.unwrap();
+        let app = router().with_state(state.clone());
+        let send = |method: &str, uri: &str, token: &str, body: Option<serde_json::Value>| {
+            let builder = Request::builder()
+                .method(method)
+                .uri(uri)
+                .header("authorization", format!("Token {token}"));
+            let request = match body {
+                Some(body) => builder
+                    .header("content-type", "application/json")
+                    .body(Body::from(body.to_string())),
+                None => builder.body(Body::empty()),
+            };
+            let app = app.clone();
+            async move {
+                let response = app.oneshot(request.unwrap()).await.unwrap();
+                let status = response.status();
+                let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+                (status, serde_json::from_slice::<serde_json::Value>(&bytes).unwrap_or_default())
+            }
+        };
+        let invite = serde_json::json!({"coauthor": {"username": "coauthor"}});
+        let edit = serde_json::json!({"article": {"body": "co-written body"}});
+
+        let (status, _) =
+            send("POST", "/joint-work/coauthors", "reader-token", Some(invite.clone())).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        let (status, _) = send("POST", "/joint-work/coauthors", "owner-token", Some(invite)).await;
+        assert_eq!(status, StatusCode::OK);
+
+        // Pending invitees cannot edit yet, nor accept for someone else
+        let (status, _) = send("PUT", "/joint-work", "coauthor-token", Some(edit.clone())).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        let (status, _) = send("PUT", "/joint-work/coauthors/coauthor", "reader-token", None).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+        let (status, body) =
+            send("PUT", "/joint-work/coauthors/coauthor", "coauthor-token", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["article"]["authors"][0]["username"], "owner");
+        assert_eq!(body["article"]["authors"][1]["username"], "coauthor");
+
+        let (status, body) = send("PUT", "/joint-work", "coauthor-token", Some(edit)).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["article"]["body"], "co-written body");
+        assert_eq!(body["article"]["author"]["username"], "owner");
+
+        // Followers of a co-author get the article in their feed
+        let (_, feed) = send("GET", "/feed", "reader-token", None).await;
+        assert_eq!(feed["articlesCount"], 1);
+
+        // Only the owner deletes
+        let (status, _) = send("DELETE", "/joint-work", "coauthor-token", None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        let (status, _) =
+            send("DELETE", "/joint-work/coauthors/coauthor", "coauthor-token", None).await;
+        assert_eq!(status, StatusCode::OK);
+        let (_, body) = send("GET", "/joint-work", "reader-token", None).await;
+        assert_eq!(body["article"]["authors"].as_array().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_react_to_article() {
+        let state = AppState::default();
+        let author = create_test_user(UserId::random(), "author", "author@example.com");
+        let reader = create_test_user(UserId::random(), "reader", "reader@example.com");
+        state.use_cases.users_repo.create_user(author.clone()).await.unwrap();
+        state.use_cases.users_repo.create_user(reader.clone()).await.unwrap();
+        state.sessions.write().await.insert("reader-token".to_owned(), reader.id);
+        let draft = ArticleDraft::new("Cheered", "desc", "body", TagList::default()).unwrap();
+        let article = Article::publish(ArticleId::random(), author.id, draft, Utc::now()).unwrap();
+        state.use_cases.articles_repo.create_article(article).await.unwrap();
+        let app = router().with_state(state);
+        let send = |method: &str, uri: &str| {
+            let request = Request::builder()
+                .method(method)
+                .uri(uri)
+                .header("authorization", "Token reader-token")
+                .body(Body::empty())
+                .unwrap();
+            let app = app.clone();
+            async move {
+                let response = app.oneshot(request).await.unwrap();
+                let status = response.status();
+                let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+                (status, serde_json::from_slice::<serde_json::Value>(&bytes).unwrap_or_default())
+            }
+        };
+
+        let (status, body) = send("PUT", "/cheered/reactions/tada").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["article"]["reactions"][0]["kind"], "tada");
+        assert_eq!(body["article"]["reactions"][0]["count"], 1);
+        assert_eq!(body["article"]["reactions"][0]["reacted"], true);
+
+        let (status, _) = send("PUT", "/cheered/reactions/rocket").await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+        let (status, _) = send("PUT", "/missing/reactions/tada").await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+
+        let (status, body) = send("DELETE", "/cheered/reactions/tada").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["article"]["reactions"], serde_json::json!([]));
+    }
+
+    #[tokio::test]
+    async fn test_bookmark_article_is_private() {
+        let state = AppState::default();
+        let author = create_test_user(UserId::random(), "author", "author@example.com");
+        let reader = create_test_user(UserId::random(), "reader", "reader@example.com");
+        state.use_cases.users_repo.create_user(author.clone()).await.unwrap();
+        state.use_cases.users_repo.create_user(reader.clone()).await.unwrap();
+        state.sessions.write().await.insert("reader-token".to_owned(), reader.id);
+        let draft = ArticleDraft::new("Saved", "desc", "body", TagList::default()).unwrap();
+        let article = Article::publish(ArticleId::random(), author.id, draft, Utc::now()).unwrap();
+        state.use_cases.articles_repo.create_article(article.clone()).await.unwrap();
+        let app = router().with_state(state.clone());
+        let send = |method: &str, token: Option<&str>, body: Option<&str>| {
+            let mut builder = Request::builder().method(method).uri("/saved/bookmark");
+            if let Some(token) = token {
+                builder = builder.header("authorization", format!("Token {token}"));
+            }
+            let request = match body {
+                Some(body) => builder
+                    .header("content-type", "application/json")
+                    .body(Body::from(body.to_owned())),
+                None => builder.body(Body::empty()),
+            };
+            let app = app.clone();
+            async move {
+                let response = app.oneshot(request.unwrap()).await.unwrap();
+                let status = response.status();
+                let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+                (status, serde_json::from_slice::<serde_json::Value>(&bytes).unwrap_or_default())
+            }
+        };
+
+        let (status, _) = send("POST", None, None).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+        let (status, body) = send("POST", Some("reader-token"), None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["article"]["bookmarked"], true);
+        assert_eq!(body["article"]["favorited"], false);
+        assert_eq!(body["article"]["favoritesCount"], 0);
+
+        let note = r#"{"bookmark":{"note":"for the weekend","folder":"later"}}"#;
+        let (status, _) = send("POST", Some("reader-token"), Some(note)).await;
+        assert_eq!(status, StatusCode::OK);
+        let bookmark = state
+            .use_cases
+            .articles_repo
+            .get_bookmark(reader.id, article.id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(bookmark.folder.as_deref(), Some("later"));
+
+        let long = format!(r#"{{"bookmark":{{"folder":"{}"}}}}"#, "a".repeat(65));
+        let (status, _) = send("POST", Some("reader-token"), Some(&long)).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+        // Nobody else sees it
+        let response = app
+            .clone()
+            .oneshot(Request::builder().uri("/saved").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(body["article"]["bookmarked"], false);
+
+        let (status, body) = send("DELETE", Some("reader-token"), None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["article"]["bookmarked"], false);
+    }
+
+    #[tokio::test]
+    async fn test_related_articles_cached_until_an_article_changes() {
+        let state = AppState::default();
+        let author = create_test_user(UserId::random(), "author", "author@example.com");
+        state.use_cases.users_repo.create_user(author.clone()).await.unwrap();
+        state.sessions.write().await.insert("author-token".to_owned(), author.id);
+        for title in ["First", "Second", "Third"] {
+            let draft = ArticleDraft::new(title, "desc", "body", TagList::new(["rust"]).unwrap()).unwrap();
+            let article = Article::publish(ArticleId::random(), author.id, draft, Utc::now()).unwrap();
+            state.use_cases.articles_repo.create_article(article).await.unwrap();
+        }
+        let app = router().with_state(state.clone());
+        let request = |method: &str, uri: &str, token: Option<&str>| {
+            let mut builder = Request::builder().method(method).uri(uri);
+            if let Some(token) = token {
+                builder = builder.header("authorization", format!("Token {token}"));
+            }
+            app.clone().oneshot(builder.body(Body::empty()).unwrap())
+        };
+        let related = |token: Option<&'static str>| {
+            let response = request("GET", "/first/related", token);
+            async move {
+                let response = response.await.unwrap();
+                assert_eq!(response.status(), StatusCode::OK);
+                let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+                let envelope: ArticlesEnvelope = serde_json::from_slice(&bytes).unwrap();
+                envelope.articles.into_iter().map(|a| a.title).collect::<Vec<_>>()
+            }
+        };
+
+        assert_eq!(related(None).await, ["Third", "Second"]);
+        state.related_articles.run_pending_tasks().await;
+        assert_eq!(state.related_articles.entry_count(), 1);
+
+        // Nobody is recommended their own articles, and that isn't cached
+        assert!(related(Some("author-token")).await.is_empty());
+
+        let response = request("DELETE", "/third", Some("author-token")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(related(None).await, ["Second"]);
+
+        let response = request("GET", "/missing/related", None).await.unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
 }
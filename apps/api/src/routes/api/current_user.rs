@@ -1,14 +1,19 @@
 use axum::{
-    extract::State,
+    extract::{Query, State},
+    http::StatusCode,
+    response::IntoResponse,
     routing::get,
     Json, Router,
 };
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use domain::{
-    PlainPassword, UserEnvelope,
-    use_cases::{update_user, UpdateUserInput},
+    ArticlesEnvelope, BookmarksEnvelope, FollowedTagsEnvelope, PlainPassword, UserEnvelope,
+    use_cases::{
+        delete_account, list_bookmarks, list_drafts, list_followed_tags, update_user,
+        DeleteAccountInput, ListBookmarksInput, UpdateUserInput,
+    },
 };
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::{
     auth::CurrentUser,
@@ -24,7 +29,16 @@ where
     A: domain::repositories::ArticlesRepository + Clone + 'static,
     C: domain::repositories::CommentsRepository + Clone + 'static,
 {
-    Router::<AppState<U, A, C>>::new().route("/user", get(get_current_user_handler).put(update_current_user_handler))
+    Router::<AppState<U, A, C>>::new()
+        .route(
+            "/user",
+            get(get_current_user_handler)
+                .put(update_current_user_handler)
+                .delete(delete_current_user_handler),
+        )
+        .route("/user/drafts", get(list_drafts_handler))
+        .route("/user/followed-tags", get(list_followed_tags_handler))
+        .route("/user/bookmarks", get(list_bookmarks_handler))
 }
 
 async fn get_current_user_handler(
@@ -34,6 +48,68 @@ async fn get_current_user_handler(
     Ok(Json(UserEnvelope::from(view)))
 }
 
+/// Tags the current user follows
+async fn list_followed_tags_handler<U, A, C>(
+    State(state): State<AppState<U, A, C>>,
+    CurrentUser { user, .. }: CurrentUser,
+) -> ApiResult<Json<FollowedTagsEnvelope>>
+where
+    U: domain::repositories::UsersRepository + Clone,
+    A: domain::repositories::ArticlesRepository + Clone,
+    C: domain::repositories::CommentsRepository + Clone,
+{
+    let envelope = list_followed_tags(state.use_cases.tags_repo.as_ref(), user.id).await?;
+    Ok(Json(envelope))
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct DraftsQuery {
+    limit: Option<u32>,
+    offset: Option<u32>,
+}
+
+/// The current user's drafts and scheduled articles
+async fn list_drafts_handler<U, A, C>(
+    State(state): State<AppState<U, A, C>>,
+    Query(query): Query<DraftsQuery>,
+    CurrentUser { user, .. }: CurrentUser,
+) -> ApiResult<Json<ArticlesEnvelope>>
+where
+    U: domain::repositories::UsersRepository + Clone,
+    A: domain::repositories::ArticlesRepository + Clone,
+    C: domain::repositories::CommentsRepository + Clone,
+{
+    let envelope = list_drafts(&state.use_cases.articles_repo, user.id, query.limit, query.offset).await?;
+    Ok(Json(envelope))
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct BookmarksQuery {
+    folder: Option<String>,
+    limit: Option<u32>,
+    offset: Option<u32>,
+}
+
+/// The current user's reading list, most recently saved first
+async fn list_bookmarks_handler<U, A, C>(
+    State(state): State<AppState<U, A, C>>,
+    Query(query): Query<BookmarksQuery>,
+    CurrentUser { user, .. }: CurrentUser,
+) -> ApiResult<Json<BookmarksEnvelope>>
+where
+    U: domain::repositories::UsersRepository + Clone,
+    A: domain::repositories::ArticlesRepository + Clone,
+    C: domain::repositories::CommentsRepository + Clone,
+{
+    let input = ListBookmarksInput {
+        folder: query.folder,
+        limit: query.limit,
+        offset: query.offset,
+    };
+    let envelope = list_bookmarks(&state.use_cases.articles_repo, user.id, input).await?;
+    Ok(Json(envelope))
+}
+
 #[derive(Debug, Deserialize, Default)]
 struct UpdateUserRequest {
     user: UpdateUserPayload,
@@ -93,10 +169,79 @@ where
     Ok(Json(UserEnvelope::from(view)))
 }
 
+#[derive(Debug, Deserialize)]
+struct DeleteUserRequest {
+    user: DeleteUserPayload,
+}
+
+#[derive(Debug, Deserialize)]
+struct DeleteUserPayload {
+    password: String,
+}
+
+#[derive(Debug, Serialize)]
+struct AccountDeletionEnvelope {
+    deletion: AccountDeletionView,
+}
+
+#[derive(Debug, Serialize)]
+struct AccountDeletionView {
+    #[serde(rename = "purgeAfter")]
+    purge_after: DateTime<Utc>,
+}
+
+/// Deactivates the account; it is erased once the grace period is over
+/// unless the user logs in again before then.
+async fn delete_current_user_handler<U, A, C>(
+    State(state): State<AppState<U, A, C>>,
+    CurrentUser { user, .. }: CurrentUser,
+    Json(req): Json<DeleteUserRequest>,
+) -> ApiResult<impl IntoResponse>
+where
+    U: domain::repositories::UsersRepository + Clone,
+    A: domain::repositories::ArticlesRepository + Clone,
+    C: domain::repositories::CommentsRepository + Clone,
+{
+    let password = PlainPassword::new(req.user.password)
+        .map_err(|_| ApiError::unauthorized("invalid credentials"))?;
+    let input = DeleteAccountInput {
+        password_hash: hash_password(&password)?,
+    };
+
+    let output = delete_account(
+        &state.use_cases.users_repo,
+        user.id,
+        input,
+        &state.account_deletion,
+        Utc::now(),
+    )
+    .await
+    .map_err(|e| match e {
+        domain::DomainError::UnauthorizedAction => {
+            ApiError::unauthorized("invalid credentials")
+        }
+        _ => ApiError::from(e),
+    })?;
+
+    state.forget_sessions(&[user.id]).await;
+    // Their articles drop out of related lists along with the account
+    state.related_articles.invalidate_all();
+    tracing::info!(event = ?output.event, "account scheduled for deletion");
+
+    Ok((
+        StatusCode::ACCEPTED,
+        Json(AccountDeletionEnvelope {
+            deletion: AccountDeletionView {
+                purge_after: output.purge_after,
+            },
+        }),
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use domain::repositories::UsersRepository;
+    use domain::repositories::{ArticlesRepository, UsersRepository};
     use axum::{body::Body, http::{Request, StatusCode}};
     use domain::{AuthToken, Email, PasswordHash, User, UserId, Username};
     use tower::ServiceExt;
@@ -139,6 +284,86 @@ mod tests {
         assert_eq!(response.status(), StatusCode::OK);
     }
 
+    #[tokio::test]
+    async fn test_list_followed_tags() {
+        let state = AppState::default();
+        let user_id = UserId::random();
+        let user = create_test_user(user_id, "testuser", "test@example.com");
+        state.use_cases.users_repo.create_user(user).await.unwrap();
+        state.sessions.write().await.insert("test-token".to_owned(), user_id);
+        for name in ["web", "rust"] {
+            domain::use_cases::follow_tag(state.use_cases.tags_repo.as_ref(), name, user_id)
+                .await
+                .unwrap();
+        }
+        let app = router().with_state(state);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri("/user/followed-tags")
+                    .header("authorization", "Token test-token")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(body["tags"], serde_json::json!(["rust", "web"]));
+    }
+
+    #[tokio::test]
+    async fn test_list_bookmarks() {
+        let state = AppState::default();
+        let user_id = UserId::random();
+        let user = create_test_user(user_id, "testuser", "test@example.com");
+        state.use_cases.users_repo.create_user(user).await.unwrap();
+        state.sessions.write().await.insert("test-token".to_owned(), user_id);
+        for (title, folder) in [("First", Some("later")), ("Second", None)] {
+            let draft = domain::ArticleDraft::new(title, "desc", "body", domain::TagList::default()).unwrap();
+            let article = domain::Article::publish(domain::ArticleId::random(), user_id, draft, chrono::Utc::now()).unwrap();
+            state.use_cases.articles_repo.create_article(article.clone()).await.unwrap();
+            let input = domain::use_cases::BookmarkArticleInput {
+                note: None,
+                folder: folder.map(str::to_owned),
+            };
+            domain::use_cases::bookmark_article(
+                &state.use_cases.users_repo,
+                &state.use_cases.articles_repo,
+                article.slug.as_str(),
+                user_id,
+                input,
+            )
+            .await
+            .unwrap();
+        }
+        let app = router().with_state(state);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("GET")
+                    .uri("/user/bookmarks?folder=later")
+                    .header("authorization", "Token test-token")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(body["bookmarksCount"], 1);
+        assert_eq!(body["bookmarks"][0]["folder"], "later");
+        assert_eq!(body["bookmarks"][0]["article"]["title"], "First");
+        assert_eq!(body["bookmarks"][0]["article"]["bookmarked"], true);
+    }
+
     #[tokio::test]
     async fn test_get_current_user_without_auth() {
         let state = AppState::default();
@@ -376,4 +601,63 @@ mod tests {
         
         assert_eq!(response.status(), StatusCode::OK);
     }
+
+    async fn delete_request(app: Router, token: &AuthToken, password: &str) -> StatusCode {
+        let payload = serde_json::json!({
+            "user": {
+                "password": password
+            }
+        });
+
+        app.oneshot(
+                Request::builder()
+                    .method("DELETE")
+                    .uri("/user")
+                    .header("authorization", format!("Token {}", token.as_str()))
+                    .header("content-type", "application/json")
+                    .body(Body::from(serde_json::to_string(&payload).unwrap()))
+                    .unwrap(),
+            )
+            .await
+            .unwrap()
+            .status()
+    }
+
+    #[tokio::test]
+    async fn test_delete_current_user_deactivates_account() {
+        let state = AppState::default();
+        let user_id = UserId::random();
+        let mut user = create_test_user(user_id, "testuser", "test@example.com");
+        user.password_hash = hash_password(&PlainPassword::new("password123").unwrap()).unwrap();
+
+        state.use_cases.users_repo.create_user(user).await.unwrap();
+
+        let token = AuthToken::new("test-token".to_string()).unwrap();
+        state.sessions.write().await.insert(token.as_str().to_owned(), user_id);
+
+        let status = delete_request(router().with_state(state.clone()), &token, "password123").await;
+
+        assert_eq!(status, StatusCode::ACCEPTED);
+        assert!(state.sessions.read().await.is_empty());
+        let stored = state.use_cases.users_repo.get_user_by_id(user_id).await.unwrap().unwrap();
+        assert!(stored.is_deactivated());
+    }
+
+    #[tokio::test]
+    async fn test_delete_current_user_wrong_password() {
+        let state = AppState::default();
+        let user_id = UserId::random();
+        let mut user = create_test_user(user_id, "testuser", "test@example.com");
+        user.password_hash = hash_password(&PlainPassword::new("password123").unwrap()).unwrap();
+
+        state.use_cases.users_repo.create_user(user).await.unwrap();
+
+        let token = AuthToken::new("test-token".to_string()).unwrap();
+        state.sessions.write().await.insert(token.as_str().to_owned(), user_id);
+
+        let status = delete_request(router().with_state(state.clone()), &token, "not-my-password").await;
+
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        assert_eq!(state.sessions.read().await.len(), 1);
+    }
 }
@@ -0,0 +1,427 @@
+use std::io::{Cursor, Write};
+
+use axum::{
+    extract::{Path, Query, State},
+    http::{StatusCode, header},
+    response::{IntoResponse, Response},
+    routing::{get, post},
+    Json, Router,
+};
+use chrono::{DateTime, Utc};
+use domain::{
+    UserId,
+    use_cases::{export_user_data, ExportUserDataInput, UserDataExport},
+};
+use http_problem::ProblemDetails;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use zip::{write::SimpleFileOptions, CompressionMethod, ZipWriter};
+
+use crate::{
+    auth::CurrentUser,
+    error::{ApiError, ApiResult},
+    state::{AppState, ExportJob, ExportStatus},
+};
+
+pub fn router<U, A, C>() -> Router<AppState<U, A, C>>
+where
+    U: domain::repositories::UsersRepository + Clone + 'static,
+    A: domain::repositories::ArticlesRepository + Clone + 'static,
+    C: domain::repositories::CommentsRepository + Clone + 'static,
+{
+    Router::<AppState<U, A, C>>::new()
+        .route("/user/export", post(request_export_handler))
+        .route("/user/export/{id}", get(export_status_handler))
+        .route("/user/export/{id}/download", get(download_export_handler))
+}
+
+#[derive(Debug, Serialize)]
+struct ExportEnvelope {
+    export: ExportView,
+}
+
+#[derive(Debug, Serialize)]
+struct ExportView {
+    id: Uuid,
+    status: &'static str,
+    #[serde(rename = "requestedAt")]
+    requested_at: DateTime<Utc>,
+    #[serde(rename = "expiresAt", skip_serializing_if = "Option::is_none")]
+    expires_at: Option<DateTime<Utc>>,
+    #[serde(rename = "downloadUrl", skip_serializing_if = "Option::is_none")]
+    download_url: Option<String>,
+}
+
+impl ExportView {
+    fn new(id: Uuid, job: &ExportJob) -> Self {
+        let download_url = (job.status == ExportStatus::Ready).then(|| {
+            format!("/api/user/export/{id}/download?token={}", job.download_token)
+        });
+        Self {
+            id,
+            status: job.status.as_str(),
+            requested_at: job.requested_at,
+            expires_at: job.expires_at,
+            download_url,
+        }
+    }
+}
+
+/// Queues an archive of everything held on the current user; poll the
+/// status endpoint for the download link.
+async fn request_export_handler<U, A, C>(
+    State(state): State<AppState<U, A, C>>,
+    CurrentUser { user, .. }: CurrentUser,
+) -> ApiResult<impl IntoResponse>
+where
+    U: domain::repositories::UsersRepository + Clone + 'static,
+    A: domain::repositories::ArticlesRepository + Clone + 'static,
+    C: domain::repositories::CommentsRepository + Clone + 'static,
+{
+    let now = Utc::now();
+    let id = Uuid::new_v4();
+    let job = ExportJob::pending(user.id, Uuid::new_v4().simple().to_string(), now);
+    let view = ExportView::new(id, &job);
+
+    {
+        let mut exports = state.exports.write().await;
+        exports.retain(|_, job| !job.is_stale(now, state.export_link_ttl));
+        exports.insert(id, job);
+    }
+
+    tokio::spawn(build_export(state, id, user.id));
+
+    Ok((StatusCode::ACCEPTED, Json(ExportEnvelope { export: view })))
+}
+
+async fn export_status_handler<U, A, C>(
+    State(state): State<AppState<U, A, C>>,
+    CurrentUser { user, .. }: CurrentUser,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<ExportEnvelope>>
+where
+    U: domain::repositories::UsersRepository + Clone,
+    A: domain::repositories::ArticlesRepository + Clone,
+    C: domain::repositories::CommentsRepository + Clone,
+{
+    let exports = state.exports.read().await;
+    let job = exports
+        .get(&id)
+        .filter(|job| job.user_id == user.id)
+        .ok_or_else(|| ApiError::not_found("export not found"))?;
+
+    Ok(Json(ExportEnvelope {
+        export: ExportView::new(id, job),
+    }))
+}
+
+#[derive(Debug, Deserialize)]
+struct DownloadQuery {
+    token: String,
+}
+
+/// Serves the archive to whoever holds the link, until it expires
+async fn download_export_handler<U, A, C>(
+    State(state): State<AppState<U, A, C>>,
+    Path(id): Path<Uuid>,
+    Query(query): Query<DownloadQuery>,
+) -> ApiResult<Response>
+where
+    U: domain::repositories::UsersRepository + Clone,
+    A: domain::repositories::ArticlesRepository + Clone,
+    C: domain::repositories::CommentsRepository + Clone,
+{
+    let exports = state.exports.read().await;
+    let job = exports
+        .get(&id)
+        .filter(|job| job.token_matches(&query.token))
+        .ok_or_else(|| ApiError::not_found("export not found"))?;
+
+    if job.is_expired(Utc::now()) {
+        return Ok(ProblemDetails::new(StatusCode::GONE)
+            .with_title("Gone")
+            .with_detail("export link has expired")
+            .into_response());
+    }
+    let archive = job
+        .archive
+        .clone()
+        .ok_or_else(|| ApiError::not_found("export not found"))?;
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, "application/zip".to_owned()),
+            (
+                header::CONTENT_DISPOSITION,
+                format!("attachment; filename=\"export-{id}.zip\""),
+            ),
+        ],
+        archive,
+    )
+        .into_response())
+}
+
+async fn build_export<U, A, C>(state: AppState<U, A, C>, id: Uuid, user_id: UserId)
+where
+    U: domain::repositories::UsersRepository + Clone,
+    A: domain::repositories::ArticlesRepository + Clone,
+    C: domain::repositories::CommentsRepository + Clone,
+{
+    let active_sessions = state
+        .sessions
+        .read()
+        .await
+        .values()
+        .filter(|owner| **owner == user_id)
+        .count();
+
+    let archive = export_user_data(
+        &state.use_cases,
+        user_id,
+        ExportUserDataInput { active_sessions },
+        Utc::now(),
+    )
+    .await
+    .map_err(anyhow::Error::from)
+    .and_then(|export| write_archive(&export));
+
+    let mut exports = state.exports.write().await;
+    let Some(job) = exports.get_mut(&id) else {
+        return;
+    };
+    match archive {
+        Ok(bytes) => {
+            job.status = ExportStatus::Ready;
+            job.expires_at = Some(Utc::now() + state.export_link_ttl);
+            job.archive = Some(bytes);
+            tracing::info!(export_id = %id, user_id = ?user_id, "personal data export ready");
+        }
+        Err(err) => {
+            job.status = ExportStatus::Failed;
+            tracing::error!(?err, export_id = %id, "personal data export failed");
+        }
+    }
+}
+
+/// Packs the export into a zip: one Markdown file per article plus JSON for the rest
+fn write_archive(export: &UserDataExport) -> anyhow::Result<Vec<u8>> {
+    let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+    let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+
+    let json_files = [
+        ("profile.json", serde_json::to_vec_pretty(&export.profile)?),
+        ("comments.json", serde_json::to_vec_pretty(&export.comments)?),
+        ("favorites.json", serde_json::to_vec_pretty(&export.favorites)?),
+        ("bookmarks.json", serde_json::to_vec_pretty(&export.bookmarks)?),
+        ("follows.json", serde_json::to_vec_pretty(&export.follows)?),
+        ("blocks.json", serde_json::to_vec_pretty(&export.blocks)?),
+        ("mutes.json", serde_json::to_vec_pretty(&export.mutes)?),
+        ("followed_tags.json", serde_json::to_vec_pretty(&export.followed_tags)?),
+        ("reactions.json", serde_json::to_vec_pretty(&export.reactions)?),
+        ("reports.json", serde_json::to_vec_pretty(&export.reports)?),
+        ("series.json", serde_json::to_vec_pretty(&export.series)?),
+        ("coauthorships.json", serde_json::to_vec_pretty(&export.coauthorships)?),
+        ("sessions.json", serde_json::to_vec_pretty(&export.sessions)?),
+        ("audit.json", serde_json::to_vec_pretty(&export.audit)?),
+    ];
+    for (name, contents) in json_files {
+        zip.start_file(name, options)?;
+        zip.write_all(&contents)?;
+    }
+    for article in &export.articles {
+        zip.start_file(format!("articles/{}.md", article.slug), options)?;
+        zip.write_all(article.markdown.as_bytes())?;
+    }
+
+    Ok(zip.finish()?.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use axum::http::Request;
+    use domain::repositories::UsersRepository;
+    use domain::{Email, PasswordHash, User, Username};
+    use std::io::Read;
+    use tower::ServiceExt;
+
+    type TestState = AppState<
+        domain::repositories::InMemoryUsersRepository,
+        domain::repositories::InMemoryArticlesRepository,
+        domain::repositories::InMemoryCommentsRepository,
+    >;
+
+    async fn signed_in(state: &TestState) -> UserId {
+        let user = User::new(
+            UserId::random(),
+            Email::parse("me@example.com").unwrap(),
+            Username::new("me").unwrap(),
+            PasswordHash::new("hash").unwrap(),
+            Utc::now(),
+        );
+        state.use_cases.users_repo.create_user(user.clone()).await.unwrap();
+        state.sessions.write().await.insert("test-token".to_owned(), user.id);
+        user.id
+    }
+
+    async fn json_body(response: Response) -> serde_json::Value {
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        serde_json::from_slice(&bytes).unwrap()
+    }
+
+    async fn wait_until_finished(state: &TestState) {
+        for _ in 0..100 {
+            let pending = state
+                .exports
+                .read()
+                .await
+                .values()
+                .any(|job| job.status == ExportStatus::Pending);
+            if !pending {
+                return;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+        panic!("export never finished");
+    }
+
+    fn get(uri: &str) -> Request<Body> {
+        Request::builder()
+            .method("GET")
+            .uri(uri)
+            .header("authorization", "Token test-token")
+            .body(Body::empty())
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_export_round_trip() {
+        let state = AppState::default();
+        signed_in(&state).await;
+        let app = router().with_state(state.clone());
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/user/export")
+                    .header("authorization", "Token test-token")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::ACCEPTED);
+        let id = json_body(response).await["export"]["id"].as_str().unwrap().to_owned();
+
+        wait_until_finished(&state).await;
+
+        let response = app.clone().oneshot(get(&format!("/user/export/{id}"))).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = json_body(response).await;
+        assert_eq!(body["export"]["status"], "ready");
+        let url = body["export"]["downloadUrl"].as_str().unwrap();
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri(url.trim_start_matches("/api"))
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let mut archive = zip::ZipArchive::new(Cursor::new(bytes.to_vec())).unwrap();
+        let mut profile = String::new();
+        archive.by_name("profile.json").unwrap().read_to_string(&mut profile).unwrap();
+        assert!(profile.contains("me@example.com"));
+        let mut sessions = String::new();
+        archive.by_name("sessions.json").unwrap().read_to_string(&mut sessions).unwrap();
+        assert!(sessions.contains("\"active\": 1"));
+    }
+
+    #[tokio::test]
+    async fn test_export_link_expires() {
+        let state = AppState::default().with_export_link_ttl(chrono::Duration::zero());
+        let user_id = signed_in(&state).await;
+        let id = Uuid::new_v4();
+        state
+            .exports
+            .write()
+            .await
+            .insert(id, ExportJob::pending(user_id, "secret".to_owned(), Utc::now()));
+        build_export(state.clone(), id, user_id).await;
+
+        let app = router().with_state(state);
+        let response = app
+            .clone()
+            .oneshot(get(&format!("/user/export/{id}/download?token=secret")))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::GONE);
+
+        let response = app
+            .oneshot(get(&format!("/user/export/{id}/download?token=wrong")))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_export_request_sweeps_stale_jobs() {
+        let state = AppState::default();
+        let user_id = signed_in(&state).await;
+        let long_ago = Utc::now() - state.export_link_ttl - chrono::Duration::minutes(1);
+        let stuck = Uuid::new_v4();
+        let failed = Uuid::new_v4();
+        let recent = Uuid::new_v4();
+        {
+            let mut exports = state.exports.write().await;
+            exports.insert(stuck, ExportJob::pending(user_id, "stuck".to_owned(), long_ago));
+            let mut job = ExportJob::pending(user_id, "failed".to_owned(), long_ago);
+            job.status = ExportStatus::Failed;
+            exports.insert(failed, job);
+            exports.insert(recent, ExportJob::pending(user_id, "recent".to_owned(), Utc::now()));
+        }
+
+        let app = router().with_state(state.clone());
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/user/export")
+                    .header("authorization", "Token test-token")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::ACCEPTED);
+
+        let exports = state.exports.read().await;
+        assert!(!exports.contains_key(&stuck));
+        assert!(!exports.contains_key(&failed));
+        assert!(exports.contains_key(&recent));
+        assert_eq!(exports.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_export_status_hidden_from_other_users() {
+        let state = AppState::default();
+        signed_in(&state).await;
+        let id = Uuid::new_v4();
+        state
+            .exports
+            .write()
+            .await
+            .insert(id, ExportJob::pending(UserId::random(), "secret".to_owned(), Utc::now()));
+
+        let app = router().with_state(state);
+        let response = app.oneshot(get(&format!("/user/export/{id}"))).await.unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+}
@@ -1,7 +1,13 @@
 mod articles;
 mod current_user;
+mod exports;
+mod moderation;
 mod profiles;
+mod reactions;
+mod series;
 mod tags;
+mod trash;
+mod uploads;
 mod users;
 
 use crate::state::AppState;
@@ -16,9 +22,15 @@ where
     Router::<AppState<U, A, C>>::new()
         .nest("/articles", articles::router())
         .nest("/tags", tags::router())
+        .nest("/series", series::router())
         .nest("/profiles", profiles::router())
+        .nest("/reactions", reactions::router())
+        .nest("/uploads", uploads::router())
         .nest("/users", users::router())
         .merge(current_user::router())
+        .merge(exports::router())
+        .merge(moderation::router())
+        .merge(trash::router())
 }
 
 #[cfg(test)]
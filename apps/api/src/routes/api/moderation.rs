@@ -0,0 +1,368 @@
+use axum::{
+    extract::{Path, Query, State},
+    routing::{get, post},
+    Json, Router,
+};
+use chrono::Utc;
+use domain::{
+    CommentId, DomainError, DomainEvent, ModerationAction, ModerationQueueEnvelope, ModerationReportEnvelope,
+    ReportEnvelope, ReportId, ReportReason, ReportStatus, ReportsEnvelope,
+    use_cases::{
+        ModerateReportInput, ReportInput, ReportedContent, get_moderation_report, list_moderation_queue,
+        list_my_reports, moderate_report, report_content,
+    },
+};
+use serde::Deserialize;
+use uuid::Uuid;
+
+use crate::{
+    auth::{CurrentUser, ModeratorUser},
+    error::{ApiError, ApiResult},
+    state::AppState,
+};
+
+pub fn router<U, A, C>() -> Router<AppState<U, A, C>>
+where
+    U: domain::repositories::UsersRepository + Clone + 'static,
+    A: domain::repositories::ArticlesRepository + Clone + 'static,
+    C: domain::repositories::CommentsRepository + Clone + 'static,
+{
+    Router::<AppState<U, A, C>>::new()
+        .route("/reports", post(create_report_handler))
+        .route("/user/reports", get(list_my_reports_handler))
+        .route("/moderation/reports", get(list_queue_handler))
+        .route("/moderation/reports/{id}", get(get_report_handler))
+        .route("/moderation/reports/{id}/{action}", post(moderate_report_handler))
+}
+
+#[derive(Debug, Deserialize)]
+struct CreateReportRequest {
+    report: ReportPayload,
+}
+
+/// Names exactly one of `article` (a slug), `comment` (an id) or `profile`
+/// (a username)
+#[derive(Debug, Deserialize)]
+struct ReportPayload {
+    article: Option<String>,
+    comment: Option<i64>,
+    profile: Option<String>,
+    reason: ReportReason,
+    details: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct QueueQuery {
+    status: Option<ReportStatus>,
+    limit: Option<u32>,
+    offset: Option<u32>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct ModerateRequest {
+    note: Option<String>,
+}
+
+/// Report an article, comment or profile to the moderators
+async fn create_report_handler<U, A, C>(
+    State(state): State<AppState<U, A, C>>,
+    CurrentUser { user, .. }: CurrentUser,
+    Json(req): Json<CreateReportRequest>,
+) -> ApiResult<Json<ReportEnvelope>>
+where
+    U: domain::repositories::UsersRepository + Clone,
+    A: domain::repositories::ArticlesRepository + Clone,
+    C: domain::repositories::CommentsRepository + Clone,
+{
+    let payload = req.report;
+    let content = match (payload.article, payload.comment, payload.profile) {
+        (Some(slug), None, None) => ReportedContent::Article { slug },
+        (None, Some(id), None) => ReportedContent::Comment { id: CommentId::new(id) },
+        (None, None, Some(username)) => ReportedContent::Profile { username },
+        _ => {
+            return Err(ApiError::validation(
+                "report exactly one of an article, a comment or a profile",
+            ));
+        }
+    };
+    let input = ReportInput {
+        content,
+        reason: payload.reason,
+        details: payload.details,
+    };
+
+    let view = report_content(
+        &state.use_cases.users_repo,
+        &state.use_cases.articles_repo,
+        &state.use_cases.comments_repo,
+        state.use_cases.reports_repo.as_ref(),
+        user.id,
+        input,
+        Utc::now(),
+    )
+    .await?;
+    Ok(Json(ReportEnvelope::from(view)))
+}
+
+/// The current user's reports and how each was handled; reporters are not
+/// notified otherwise, this is where they follow their reports
+async fn list_my_reports_handler<U, A, C>(
+    State(state): State<AppState<U, A, C>>,
+    CurrentUser { user, .. }: CurrentUser,
+) -> ApiResult<Json<ReportsEnvelope>>
+where
+    U: domain::repositories::UsersRepository + Clone,
+    A: domain::repositories::ArticlesRepository + Clone,
+    C: domain::repositories::CommentsRepository + Clone,
+{
+    let envelope = list_my_reports(
+        &state.use_cases.users_repo,
+        &state.use_cases.articles_repo,
+        &state.use_cases.comments_repo,
+        state.use_cases.reports_repo.as_ref(),
+        user.id,
+    )
+    .await?;
+    Ok(Json(envelope))
+}
+
+async fn list_queue_handler<U, A, C>(
+    State(state): State<AppState<U, A, C>>,
+    _moderator: ModeratorUser,
+    Query(params): Query<QueueQuery>,
+) -> ApiResult<Json<ModerationQueueEnvelope>>
+where
+    U: domain::repositories::UsersRepository + Clone,
+    A: domain::repositories::ArticlesRepository + Clone,
+    C: domain::repositories::CommentsRepository + Clone,
+{
+    let envelope = list_moderation_queue(
+        &state.use_cases.users_repo,
+        &state.use_cases.articles_repo,
+        &state.use_cases.comments_repo,
+        state.use_cases.reports_repo.as_ref(),
+        params.status,
+        params.limit,
+        params.offset,
+    )
+    .await?;
+    Ok(Json(envelope))
+}
+
+async fn get_report_handler<U, A, C>(
+    State(state): State<AppState<U, A, C>>,
+    _moderator: ModeratorUser,
+    Path(id): Path<Uuid>,
+) -> ApiResult<Json<ModerationReportEnvelope>>
+where
+    U: domain::repositories::UsersRepository + Clone,
+    A: domain::repositories::ArticlesRepository + Clone,
+    C: domain::repositories::CommentsRepository + Clone,
+{
+    let envelope = get_moderation_report(
+        &state.use_cases.users_repo,
+        &state.use_cases.articles_repo,
+        &state.use_cases.comments_repo,
+        state.use_cases.reports_repo.as_ref(),
+        ReportId::new(id),
+    )
+    .await?;
+    Ok(Json(envelope))
+}
+
+/// Claim, hide, suspend, resolve or dismiss a report, with an optional note
+async fn moderate_report_handler<U, A, C>(
+    State(state): State<AppState<U, A, C>>,
+    ModeratorUser(CurrentUser { user, .. }): ModeratorUser,
+    Path((id, action)): Path<(Uuid, String)>,
+    req: Option<Json<ModerateRequest>>,
+) -> ApiResult<Json<ModerationReportEnvelope>>
+where
+    U: domain::repositories::UsersRepository + Clone,
+    A: domain::repositories::ArticlesRepository + Clone,
+    C: domain::repositories::CommentsRepository + Clone,
+{
+    let action = ModerationAction::parse(&action).ok_or_else(|| ApiError::not_found("moderation action"))?;
+    let note = req.and_then(|Json(req)| req.note);
+
+    let output = moderate_report(
+        &state.use_cases.users_repo,
+        &state.use_cases.articles_repo,
+        &state.use_cases.comments_repo,
+        state.use_cases.reports_repo.as_ref(),
+        ModerateReportInput {
+            report_id: ReportId::new(id),
+            action,
+            note,
+        },
+        user.id,
+        Utc::now(),
+    )
+    .await
+    .map_err(|e| match e {
+        DomainError::UnauthorizedAction => ApiError::forbidden("you cannot moderate reports about yourself"),
+        e => ApiError::from(e),
+    })?;
+
+    if action == ModerationAction::Hide {
+        state.related_articles.invalidate_all();
+    }
+    for event in &output.events {
+        match event {
+            DomainEvent::AccountSuspended { user_id } => {
+                state.forget_sessions(&[*user_id]).await;
+                tracing::info!(?event, "account suspended");
+            }
+            _ => tracing::info!(?event, "report moderated"),
+        }
+    }
+
+    Ok(Json(ModerationReportEnvelope::from(output.view)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{body::Body, http::{Request, StatusCode}};
+    use domain::repositories::{ArticlesRepository, UsersRepository};
+    use domain::{Article, ArticleDraft, ArticleId, Email, PasswordHash, TagList, User, UserId, Username};
+    use tower::ServiceExt;
+
+    async fn sign_up(state: &AppState<
+        domain::repositories::InMemoryUsersRepository,
+        domain::repositories::InMemoryArticlesRepository,
+        domain::repositories::InMemoryCommentsRepository,
+    >, name: &str) -> User {
+        let user = User::new(
+            UserId::random(),
+            Email::parse(format!("{name}@example.com")).unwrap(),
+            Username::new(name).unwrap(),
+            PasswordHash::new("hash").unwrap(),
+            Utc::now(),
+        );
+        state.use_cases.users_repo.create_user(user.clone()).await.unwrap();
+        state.sessions.write().await.insert(name.to_owned(), user.id);
+        user
+    }
+
+    fn request(method: &str, uri: &str, token: &str, body: serde_json::Value) -> Request<Body> {
+        Request::builder()
+            .method(method)
+            .uri(uri)
+            .header("authorization", format!("Token {token}"))
+            .header("content-type", "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap()
+    }
+
+    async fn json(response: axum::response::Response) -> serde_json::Value {
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        serde_json::from_slice(&body).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_report_hide_and_resolve_article() {
+        let state = AppState::default().with_moderators(["moderator".to_owned()]);
+        let author = sign_up(&state, "author").await;
+        sign_up(&state, "reporter").await;
+        sign_up(&state, "moderator").await;
+        let draft = ArticleDraft::new("Abusive", "Description", "Body", TagList::default()).unwrap();
+        let article = Article::publish(ArticleId::random(), author.id, draft, Utc::now()).unwrap();
+        state.use_cases.articles_repo.create_article(article).await.unwrap();
+        let app = router().with_state(state.clone());
+
+        let body = serde_json::json!({"report": {"article": "abusive", "reason": "harassment"}});
+        let response = app.clone().oneshot(request("POST", "/reports", "reporter", body)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let report = json(response).await;
+        assert_eq!(report["report"]["status"], "open");
+        assert_eq!(report["report"]["target"]["type"], "article");
+        let id = report["report"]["id"].as_str().unwrap().to_owned();
+
+        let response = app
+            .clone()
+            .oneshot(request("GET", "/moderation/reports", "reporter", serde_json::Value::Null))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+        let response = app
+            .clone()
+            .oneshot(request("GET", "/moderation/reports?status=open", "moderator", serde_json::Value::Null))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let queue = json(response).await;
+        assert_eq!(queue["reportsCount"], 1);
+        assert_eq!(queue["reports"][0]["reporter"], "reporter");
+        assert_eq!(queue["reports"][0]["targetAuthor"], "author");
+
+        let uri = format!("/moderation/reports/{id}/hide");
+        let body = serde_json::json!({"note": "clear harassment"});
+        let response = app.clone().oneshot(request("POST", &uri, "moderator", body)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let stored = state.use_cases.articles_repo.get_article_by_slug("abusive").await.unwrap().unwrap();
+        assert!(stored.is_hidden());
+
+        let uri = format!("/moderation/reports/{id}/resolve");
+        let response = app
+            .clone()
+            .oneshot(request("POST", &uri, "moderator", serde_json::json!({})))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let report = json(response).await;
+        assert_eq!(report["report"]["status"], "resolved");
+        assert_eq!(report["report"]["moderator"], "moderator");
+        assert_eq!(report["report"]["actions"][0]["note"], "clear harassment");
+
+        let response = app
+            .clone()
+            .oneshot(request("POST", &uri, "moderator", serde_json::json!({})))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+
+        let response = app
+            .oneshot(request("GET", "/user/reports", "reporter", serde_json::Value::Null))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let mine = json(response).await;
+        assert_eq!(mine["reports"][0]["status"], "resolved");
+        assert!(mine["reports"][0]["closedAt"].is_string());
+    }
+
+    #[tokio::test]
+    async fn test_suspend_reported_profile() {
+        let state = AppState::default().with_admins(["admin".to_owned()]);
+        let troll = sign_up(&state, "troll").await;
+        sign_up(&state, "reporter").await;
+        sign_up(&state, "admin").await;
+        let app = router().with_state(state.clone());
+
+        let body = serde_json::json!({"report": {"profile": "troll", "reason": "spam", "details": "bots"}});
+        let response = app.clone().oneshot(request("POST", "/reports", "reporter", body)).await.unwrap();
+        let id = json(response).await["report"]["id"].as_str().unwrap().to_owned();
+
+        let response = app
+            .clone()
+            .oneshot(request("POST", &format!("/moderation/reports/{id}/ban"), "admin", serde_json::json!({})))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        let response = app
+            .clone()
+            .oneshot(request("POST", &format!("/moderation/reports/{id}/suspend"), "admin", serde_json::json!({})))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(state.use_cases.users_repo.get_user_by_id(troll.id).await.unwrap().unwrap().is_suspended());
+        assert!(!state.sessions.read().await.contains_key("troll"));
+
+        let body = serde_json::json!({"report": {"article": "x", "profile": "troll", "reason": "spam"}});
+        let response = app.oneshot(request("POST", "/reports", "reporter", body)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+}
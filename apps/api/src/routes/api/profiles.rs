@@ -1,12 +1,17 @@
 use axum::{
     extract::{Path, State},
+    http::{HeaderMap, StatusCode, header},
+    response::{IntoResponse, Response},
     routing::get,
     Json, Router,
     routing::post,
 };
 use domain::{
-    ProfileEnvelope,
-    use_cases::{follow_user, get_profile, unfollow_user},
+    AvatarFormat, ProfileEnvelope,
+    use_cases::{
+        block_user, follow_user, get_avatar, get_profile, mute_user, unblock_user, unfollow_user,
+        unmute_user,
+    },
 };
 
 use crate::{
@@ -21,10 +26,22 @@ where
     A: domain::repositories::ArticlesRepository + Clone + 'static,
     C: domain::repositories::CommentsRepository + Clone + 'static,
 {
-    Router::<AppState<U, A, C>>::new().route("/{username}", get(get_profile_handler)).route(
-        "/{username}/follow",
-        post(follow_profile_handler).delete(unfollow_profile_handler),
-    )
+    Router::<AppState<U, A, C>>::new()
+        .route("/{username}", get(get_profile_handler))
+        // avatar.svg or avatar.png
+        .route("/{username}/{file}", get(get_avatar_handler))
+        .route(
+            "/{username}/follow",
+            post(follow_profile_handler).delete(unfollow_profile_handler),
+        )
+        .route(
+            "/{username}/block",
+            post(block_profile_handler).delete(unblock_profile_handler),
+        )
+        .route(
+            "/{username}/mute",
+            post(mute_profile_handler).delete(unmute_profile_handler),
+        )
 }
 
 async fn get_profile_handler<U, A, C>(
@@ -46,6 +63,49 @@ where
     Ok(Json(ProfileEnvelope::from(profile)))
 }
 
+/// Avatars only change with the username, so clients revalidate daily
+const AVATAR_CACHE_CONTROL: &str = "public, max-age=86400";
+
+async fn get_avatar_handler<U, A, C>(
+    State(state): State<AppState<U, A, C>>,
+    Path((username, file)): Path<(String, String)>,
+    headers: HeaderMap,
+) -> ApiResult<Response>
+where
+    U: domain::repositories::UsersRepository + Clone,
+    A: domain::repositories::ArticlesRepository + Clone,
+    C: domain::repositories::CommentsRepository + Clone,
+{
+    let format = AvatarFormat::from_file_name(&file).ok_or_else(|| ApiError::not_found("avatar"))?;
+    let avatar = get_avatar(&state.use_cases.users_repo, &username).await?;
+    let etag = avatar.etag();
+
+    let cache_headers = [
+        (header::ETAG, etag.clone()),
+        (header::CACHE_CONTROL, AVATAR_CACHE_CONTROL.to_owned()),
+    ];
+    let matches = headers
+        .get_all(header::IF_NONE_MATCH)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(|tag| tag.trim().trim_start_matches("W/"))
+        .any(|tag| tag == etag || tag == "*");
+    if matches {
+        return Ok((StatusCode::NOT_MODIFIED, cache_headers).into_response());
+    }
+
+    Ok((
+        cache_headers,
+        [
+            (header::CONTENT_TYPE, format.content_type()),
+            (header::X_CONTENT_TYPE_OPTIONS, "nosniff"),
+        ],
+        avatar.render(format),
+    )
+        .into_response())
+}
+
 async fn follow_profile_handler<U, A, C>(
     State(state): State<AppState<U, A, C>>,
     Path(username): Path<String>,
@@ -61,6 +121,7 @@ where
         .map_err(|e| match e {
             domain::DomainError::NotFound { .. } => ApiError::not_found("profile"),
             domain::DomainError::UnauthorizedAction => ApiError::validation("cannot follow yourself"),
+            domain::DomainError::Blocked => ApiError::forbidden("cannot follow a user who blocked you"),
             _ => ApiError::from(e),
         })?;
 
@@ -88,6 +149,90 @@ where
     Ok(Json(ProfileEnvelope::from(profile)))
 }
 
+async fn block_profile_handler<U, A, C>(
+    State(state): State<AppState<U, A, C>>,
+    Path(username): Path<String>,
+    CurrentUser { user, .. }: CurrentUser,
+) -> ApiResult<Json<ProfileEnvelope>>
+where
+    U: domain::repositories::UsersRepository + Clone,
+    A: domain::repositories::ArticlesRepository + Clone,
+    C: domain::repositories::CommentsRepository + Clone,
+{
+    let profile = block_user(&state.use_cases.users_repo, &username, user.id)
+        .await
+        .map_err(|e| match e {
+            domain::DomainError::NotFound { .. } => ApiError::not_found("profile"),
+            domain::DomainError::UnauthorizedAction => ApiError::validation("cannot block yourself"),
+            _ => ApiError::from(e),
+        })?;
+
+    Ok(Json(ProfileEnvelope::from(profile)))
+}
+
+async fn unblock_profile_handler<U, A, C>(
+    State(state): State<AppState<U, A, C>>,
+    Path(username): Path<String>,
+    CurrentUser { user, .. }: CurrentUser,
+) -> ApiResult<Json<ProfileEnvelope>>
+where
+    U: domain::repositories::UsersRepository + Clone,
+    A: domain::repositories::ArticlesRepository + Clone,
+    C: domain::repositories::CommentsRepository + Clone,
+{
+    let profile = unblock_user(&state.use_cases.users_repo, &username, user.id)
+        .await
+        .map_err(|e| match e {
+            domain::DomainError::NotFound { .. } => ApiError::not_found("profile"),
+            domain::DomainError::UnauthorizedAction => ApiError::validation("cannot unblock yourself"),
+            _ => ApiError::from(e),
+        })?;
+
+    Ok(Json(ProfileEnvelope::from(profile)))
+}
+
+async fn mute_profile_handler<U, A, C>(
+    State(state): State<AppState<U, A, C>>,
+    Path(username): Path<String>,
+    CurrentUser { user, .. }: CurrentUser,
+) -> ApiResult<Json<ProfileEnvelope>>
+where
+    U: domain::repositories::UsersRepository + Clone,
+    A: domain::repositories::ArticlesRepository + Clone,
+    C: domain::repositories::CommentsRepository + Clone,
+{
+    let profile = mute_user(&state.use_cases.users_repo, &username, user.id)
+        .await
+        .map_err(|e| match e {
+            domain::DomainError::NotFound { .. } => ApiError::not_found("profile"),
+            domain::DomainError::UnauthorizedAction => ApiError::validation("cannot mute yourself"),
+            _ => ApiError::from(e),
+        })?;
+
+    Ok(Json(ProfileEnvelope::from(profile)))
+}
+
+async fn unmute_profile_handler<U, A, C>(
+    State(state): State<AppState<U, A, C>>,
+    Path(username): Path<String>,
+    CurrentUser { user, .. }: CurrentUser,
+) -> ApiResult<Json<ProfileEnvelope>>
+where
+    U: domain::repositories::UsersRepository + Clone,
+    A: domain::repositories::ArticlesRepository + Clone,
+    C: domain::repositories::CommentsRepository + Clone,
+{
+    let profile = unmute_user(&state.use_cases.users_repo, &username, user.id)
+        .await
+        .map_err(|e| match e {
+            domain::DomainError::NotFound { .. } => ApiError::not_found("profile"),
+            domain::DomainError::UnauthorizedAction => ApiError::validation("cannot unmute yourself"),
+            _ => ApiError::from(e),
+        })?;
+
+    Ok(Json(ProfileEnvelope::from(profile)))
+}
+
 
 #[cfg(test)]
 mod tests {
@@ -133,6 +278,43 @@ mod tests {
         assert_eq!(response.status(), StatusCode::OK);
     }
 
+    #[tokio::test]
+    async fn test_get_generated_avatar_with_etag() {
+        let state = AppState::default();
+        let user = create_test_user(UserId::random(), "testuser", "test@example.com");
+        state.use_cases.users_repo.create_user(user).await.unwrap();
+        let app = router().with_state(state);
+        let get = |uri: &str, etag: Option<&str>| {
+            let mut request = Request::builder().method("GET").uri(uri);
+            if let Some(etag) = etag {
+                request = request.header("if-none-match", etag);
+            }
+            app.clone().oneshot(request.body(Body::empty()).unwrap())
+        };
+
+        let response = get("/testuser", None).await.unwrap();
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let envelope: ProfileEnvelope = serde_json::from_slice(&body).unwrap();
+        assert_eq!(envelope.profile.image.unwrap().as_str(), "/api/profiles/testuser/avatar.svg");
+
+        let svg = get("/testuser/avatar.svg", None).await.unwrap();
+        assert_eq!(svg.status(), StatusCode::OK);
+        assert_eq!(svg.headers()["content-type"], "image/svg+xml");
+        let etag = svg.headers()["etag"].to_str().unwrap().to_owned();
+
+        let png = get("/testuser/avatar.png", None).await.unwrap();
+        assert_eq!(png.headers()["content-type"], "image/png");
+        assert_eq!(png.headers()["etag"], etag.as_str());
+
+        let revalidated = get("/testuser/avatar.svg", Some(&etag)).await.unwrap();
+        assert_eq!(revalidated.status(), StatusCode::NOT_MODIFIED);
+        let stale = get("/testuser/avatar.svg", Some("\"other\"")).await.unwrap();
+        assert_eq!(stale.status(), StatusCode::OK);
+
+        assert_eq!(get("/testuser/avatar.gif", None).await.unwrap().status(), StatusCode::NOT_FOUND);
+        assert_eq!(get("/nobody/avatar.svg", None).await.unwrap().status(), StatusCode::NOT_FOUND);
+    }
+
     #[tokio::test]
     async fn test_get_profile_not_found() {
         let state = AppState::default();
@@ -230,6 +412,83 @@ mod tests {
         assert_eq!(response.status(), StatusCode::OK);
     }
 
+    #[tokio::test]
+    async fn test_blocked_user_cannot_follow() {
+        let state = AppState::default();
+        let blocker_id = UserId::random();
+        let blocked_id = UserId::random();
+
+        let blocker = create_test_user(blocker_id, "blocker", "blocker@example.com");
+        let blocked = create_test_user(blocked_id, "blocked", "blocked@example.com");
+
+        state.use_cases.users_repo.create_user(blocker).await.unwrap();
+        state.use_cases.users_repo.create_user(blocked).await.unwrap();
+
+        {
+            let mut sessions = state.sessions.write().await;
+            sessions.insert("blocker-token".to_owned(), blocker_id);
+            sessions.insert("blocked-token".to_owned(), blocked_id);
+        }
+
+        let app = router().with_state(state);
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/blocked/block")
+                    .header("authorization", "Token blocker-token")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/blocker/follow")
+                    .header("authorization", "Token blocked-token")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn test_mute_self_rejected() {
+        let state = AppState::default();
+        let user_id = UserId::random();
+        let user = create_test_user(user_id, "loner", "loner@example.com");
+        state.use_cases.users_repo.create_user(user).await.unwrap();
+        state
+            .sessions
+            .write()
+            .await
+            .insert("test-token".to_owned(), user_id);
+
+        let app = router().with_state(state);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/loner/mute")
+                    .header("authorization", "Token test-token")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
     #[test]
     fn test_validate_follow_action_success() {
         let target_id = UserId::random();
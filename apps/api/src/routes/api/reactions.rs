@@ -0,0 +1,25 @@
+use axum::{Json, Router, extract::State, routing::get};
+use domain::ReactionsEnvelope;
+
+use crate::state::AppState;
+
+pub fn router<U, A, C>() -> Router<AppState<U, A, C>>
+where
+    U: domain::repositories::UsersRepository + Clone + 'static,
+    A: domain::repositories::ArticlesRepository + Clone + 'static,
+    C: domain::repositories::CommentsRepository + Clone + 'static,
+{
+    Router::<AppState<U, A, C>>::new().route("/", get(list_reactions_handler))
+}
+
+/// The reactions users may leave, in the order clients should offer them
+async fn list_reactions_handler<U, A, C>(
+    State(state): State<AppState<U, A, C>>,
+) -> Json<ReactionsEnvelope>
+where
+    U: domain::repositories::UsersRepository + Clone,
+    A: domain::repositories::ArticlesRepository + Clone,
+    C: domain::repositories::CommentsRepository + Clone,
+{
+    Json(ReactionsEnvelope::from(&state.reaction_policy))
+}
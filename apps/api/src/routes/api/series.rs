@@ -0,0 +1,354 @@
+use axum::{
+    Json, Router,
+    extract::{Path, State},
+    http::StatusCode,
+    routing::{get, post, put},
+};
+use chrono::Utc;
+use domain::{
+    SeriesEnvelope, SeriesKind,
+    use_cases::{
+        create_series, delete_series, get_series, set_series_articles, update_series,
+        CreateSeriesInput, UpdateSeriesInput,
+    },
+};
+use serde::Deserialize;
+
+use crate::{
+    auth::CurrentUser,
+    error::{ApiError, ApiResult},
+    state::AppState,
+};
+
+pub fn router<U, A, C>() -> Router<AppState<U, A, C>>
+where
+    U: domain::repositories::UsersRepository + Clone + 'static,
+    A: domain::repositories::ArticlesRepository + Clone + 'static,
+    C: domain::repositories::CommentsRepository + Clone + 'static,
+{
+    Router::<AppState<U, A, C>>::new()
+        .route("/", post(create_series_handler))
+        .route(
+            "/{slug}",
+            get(get_series_handler).put(update_series_handler).delete(delete_series_handler),
+        )
+        .route("/{slug}/articles", put(set_series_articles_handler))
+}
+
+#[derive(Debug, Deserialize)]
+struct CreateSeriesRequest {
+    series: CreateSeriesPayload,
+}
+
+#[derive(Debug, Deserialize)]
+struct CreateSeriesPayload {
+    title: String,
+    #[serde(default)]
+    description: String,
+    #[serde(default)]
+    kind: SeriesKind,
+    /// Slugs of the first parts, in reading order
+    #[serde(default)]
+    articles: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct UpdateSeriesRequest {
+    series: UpdateSeriesPayload,
+}
+
+#[derive(Debug, Deserialize)]
+struct UpdateSeriesPayload {
+    title: Option<String>,
+    description: Option<String>,
+}
+
+/// The complete new list of parts; anything left out is removed
+#[derive(Debug, Deserialize)]
+struct SeriesArticlesRequest {
+    articles: Vec<String>,
+}
+
+/// Ownership failures read the same on every series endpoint
+fn owner_only(e: domain::DomainError) -> ApiError {
+    match e {
+        domain::DomainError::UnauthorizedAction => {
+            ApiError::unauthorized("cannot edit another user's series")
+        }
+        _ => ApiError::from(e),
+    }
+}
+
+async fn create_series_handler<U, A, C>(
+    State(state): State<AppState<U, A, C>>,
+    CurrentUser { user, .. }: CurrentUser,
+    Json(req): Json<CreateSeriesRequest>,
+) -> ApiResult<(StatusCode, Json<SeriesEnvelope>)>
+where
+    U: domain::repositories::UsersRepository + Clone,
+    A: domain::repositories::ArticlesRepository + Clone,
+    C: domain::repositories::CommentsRepository + Clone,
+{
+    let input = CreateSeriesInput {
+        title: req.series.title,
+        description: req.series.description,
+        kind: req.series.kind,
+        articles: req.series.articles,
+    };
+
+    let envelope = create_series(
+        &state.use_cases.users_repo,
+        &state.use_cases.articles_repo,
+        state.use_cases.series_repo.as_ref(),
+        user.id,
+        input,
+        &state.slug_policy,
+        Utc::now(),
+    )
+    .await?;
+
+    Ok((StatusCode::CREATED, Json(envelope)))
+}
+
+async fn get_series_handler<U, A, C>(
+    State(state): State<AppState<U, A, C>>,
+    Path(slug): Path<String>,
+    current_user: Option<CurrentUser>,
+) -> ApiResult<Json<SeriesEnvelope>>
+where
+    U: domain::repositories::UsersRepository + Clone,
+    A: domain::repositories::ArticlesRepository + Clone,
+    C: domain::repositories::CommentsRepository + Clone,
+{
+    let envelope = get_series(
+        &state.use_cases.users_repo,
+        state.use_cases.series_repo.as_ref(),
+        &slug,
+        current_user.map(|current| current.user.id),
+    )
+    .await?;
+
+    Ok(Json(envelope))
+}
+
+async fn update_series_handler<U, A, C>(
+    State(state): State<AppState<U, A, C>>,
+    CurrentUser { user, .. }: CurrentUser,
+    Path(slug): Path<String>,
+    Json(req): Json<UpdateSeriesRequest>,
+) -> ApiResult<Json<SeriesEnvelope>>
+where
+    U: domain::repositories::UsersRepository + Clone,
+    A: domain::repositories::ArticlesRepository + Clone,
+    C: domain::repositories::CommentsRepository + Clone,
+{
+    let input = UpdateSeriesInput {
+        title: req.series.title,
+        description: req.series.description,
+    };
+
+    let envelope = update_series(
+        &state.use_cases.users_repo,
+        state.use_cases.series_repo.as_ref(),
+        &slug,
+        user.id,
+        input,
+        Utc::now(),
+    )
+    .await
+    .map_err(owner_only)?;
+
+    Ok(Json(envelope))
+}
+
+async fn delete_series_handler<U, A, C>(
+    State(state): State<AppState<U, A, C>>,
+    CurrentUser { user, .. }: CurrentUser,
+    Path(slug): Path<String>,
+) -> ApiResult<()>
+where
+    U: domain::repositories::UsersRepository + Clone,
+    A: domain::repositories::ArticlesRepository + Clone,
+    C: domain::repositories::CommentsRepository + Clone,
+{
+    delete_series(state.use_cases.series_repo.as_ref(), &slug, user.id)
+        .await
+        .map_err(owner_only)?;
+
+    Ok(())
+}
+
+async fn set_series_articles_handler<U, A, C>(
+    State(state): State<AppState<U, A, C>>,
+    CurrentUser { user, .. }: CurrentUser,
+    Path(slug): Path<String>,
+    Json(req): Json<SeriesArticlesRequest>,
+) -> ApiResult<Json<SeriesEnvelope>>
+where
+    U: domain::repositories::UsersRepository + Clone,
+    A: domain::repositories::ArticlesRepository + Clone,
+    C: domain::repositories::CommentsRepository + Clone,
+{
+    let envelope = set_series_articles(
+        &state.use_cases.users_repo,
+        &state.use_cases.articles_repo,
+        state.use_cases.series_repo.as_ref(),
+        &slug,
+        user.id,
+        req.articles,
+    )
+    .await
+    .map_err(owner_only)?;
+
+    Ok(Json(envelope))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{body::Body, http::Request};
+    use domain::repositories::UsersRepository;
+    use domain::{Article, ArticleDraft, ArticleId, Email, PasswordHash, Publication, TagList, User, UserId, Username};
+    use tower::ServiceExt;
+
+    fn create_test_user(username: &str) -> User {
+        User::new(
+            UserId::random(),
+            Email::parse(format!("{username}@example.com")).unwrap(),
+            Username::new(username).unwrap(),
+            PasswordHash::new("hash".to_string()).unwrap(),
+            Utc::now(),
+        )
+    }
+
+    async fn create_test_article<U, A, C>(
+        state: &AppState<U, A, C>,
+        author: &User,
+        title: &str,
+        publication: Publication,
+    ) where
+        U: domain::repositories::UsersRepository + Clone,
+        A: domain::repositories::ArticlesRepository + Clone,
+        C: domain::repositories::CommentsRepository + Clone,
+    {
+        let draft = ArticleDraft::new(title, "desc", "body", TagList::default()).unwrap();
+        let mut article = Article::publish(ArticleId::random(), author.id, draft, Utc::now()).unwrap();
+        article.set_publication(publication, Utc::now());
+        state.use_cases.articles_repo.create_article(article).await.unwrap();
+    }
+
+    async fn send(
+        app: &Router,
+        method: &str,
+        uri: &str,
+        token: Option<&str>,
+        body: Option<serde_json::Value>,
+    ) -> (StatusCode, serde_json::Value) {
+        let mut builder = Request::builder().method(method).uri(uri);
+        if let Some(token) = token {
+            builder = builder.header("authorization", format!("Token {token}"));
+        }
+        let request = match body {
+            Some(body) => builder
+                .header("content-type", "application/json")
+                .body(Body::from(body.to_string())),
+            None => builder.body(Body::empty()),
+        };
+        let response = app.clone().oneshot(request.unwrap()).await.unwrap();
+        let status = response.status();
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, serde_json::from_slice(&bytes).unwrap_or_default())
+    }
+
+    #[tokio::test]
+    async fn test_series_lifecycle() {
+        let state = AppState::default();
+        let author = create_test_user("author");
+        let other = create_test_user("other");
+        state.use_cases.users_repo.create_user(author.clone()).await.unwrap();
+        state.use_cases.users_repo.create_user(other.clone()).await.unwrap();
+        state.sessions.write().await.insert("author-token".to_owned(), author.id);
+        state.sessions.write().await.insert("other-token".to_owned(), other.id);
+        create_test_article(&state, &author, "Part One", Publication::Now).await;
+        create_test_article(&state, &author, "Part Two", Publication::Draft).await;
+        let app = router().with_state(state.clone());
+
+        let create = serde_json::json!({
+            "series": {"title": "Rust in Production", "articles": ["part-one", "part-two"]}
+        });
+        let (status, _) = send(&app, "POST", "/", None, Some(create.clone())).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        let (status, body) = send(&app, "POST", "/", Some("author-token"), Some(create)).await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(body["series"]["slug"], "rust-in-production");
+        assert_eq!(body["series"]["kind"], "series");
+        assert_eq!(body["series"]["articlesCount"], 2);
+
+        // The draft part is only listed for its author
+        let (status, body) = send(&app, "GET", "/rust-in-production", None, None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["series"]["articles"][0]["slug"], "part-one");
+        assert_eq!(body["series"]["articlesCount"], 1);
+
+        let reorder = serde_json::json!({"articles": ["part-two", "part-one"]});
+        let (status, _) =
+            send(&app, "PUT", "/rust-in-production/articles", Some("other-token"), Some(reorder.clone())).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        let (status, body) =
+            send(&app, "PUT", "/rust-in-production/articles", Some("author-token"), Some(reorder)).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["series"]["articles"][0]["slug"], "part-two");
+
+        let retitle = serde_json::json!({"series": {"title": "Rust at Scale"}});
+        let (status, body) =
+            send(&app, "PUT", "/rust-in-production", Some("author-token"), Some(retitle)).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["series"]["title"], "Rust at Scale");
+        assert_eq!(body["series"]["slug"], "rust-in-production");
+
+        let (status, _) = send(&app, "DELETE", "/rust-in-production", Some("other-token"), None).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        let (status, _) = send(&app, "DELETE", "/rust-in-production", Some("author-token"), None).await;
+        assert_eq!(status, StatusCode::OK);
+        let (status, _) = send(&app, "GET", "/rust-in-production", None, None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_series_part_rules() {
+        let state = AppState::default();
+        let author = create_test_user("author");
+        let curator = create_test_user("curator");
+        state.use_cases.users_repo.create_user(author.clone()).await.unwrap();
+        state.use_cases.users_repo.create_user(curator.clone()).await.unwrap();
+        state.sessions.write().await.insert("author-token".to_owned(), author.id);
+        state.sessions.write().await.insert("curator-token".to_owned(), curator.id);
+        create_test_article(&state, &author, "Part One", Publication::Now).await;
+        let app = router().with_state(state);
+
+        let first = serde_json::json!({"series": {"title": "First", "articles": ["part-one"]}});
+        let (status, _) = send(&app, "POST", "/", Some("author-token"), Some(first)).await;
+        assert_eq!(status, StatusCode::CREATED);
+
+        // A part belongs to at most one series...
+        let second = serde_json::json!({"series": {"title": "Second", "articles": ["part-one"]}});
+        let (status, _) = send(&app, "POST", "/", Some("author-token"), Some(second)).await;
+        assert_eq!(status, StatusCode::CONFLICT);
+        let (status, _) = send(&app, "GET", "/second", None, None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+
+        // ...and only to its author's
+        let borrowed = serde_json::json!({"series": {"title": "Borrowed", "articles": ["part-one"]}});
+        let (status, _) = send(&app, "POST", "/", Some("curator-token"), Some(borrowed)).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+        // Collections take anyone's published articles, even ones in a series
+        let collection = serde_json::json!({
+            "series": {"title": "Reading List", "kind": "collection", "articles": ["part-one"]}
+        });
+        let (status, body) = send(&app, "POST", "/", Some("curator-token"), Some(collection)).await;
+        assert_eq!(status, StatusCode::CREATED);
+        assert_eq!(body["series"]["kind"], "collection");
+        assert_eq!(body["series"]["author"]["username"], "curator");
+    }
+}
@@ -1,7 +1,17 @@
-use axum::{Json, Router, extract::State, response::IntoResponse, routing::get};
-use serde::Serialize;
+use std::sync::Arc;
 
-use crate::state::AppState;
+use axum::{Json, Router, extract::{Path, Query, State}, routing::{get, post}};
+use domain::{
+    TagEnvelope, TagMergeEnvelope, TagsEnvelope,
+    use_cases::{follow_tag, list_tags, merge_tags, unfollow_tag, ListTagsInput, MergeTagsInput},
+};
+use serde::Deserialize;
+
+use crate::{
+    auth::{AdminUser, CurrentUser},
+    error::ApiResult,
+    state::AppState,
+};
 
 pub fn router<U, A, C>() -> Router<AppState<U, A, C>>
 where
@@ -9,54 +19,247 @@ where
     A: domain::repositories::ArticlesRepository + Clone + 'static,
     C: domain::repositories::CommentsRepository + Clone + 'static,
 {
-    Router::<AppState<U, A, C>>::new().route("/", get(get_tags))
+    Router::<AppState<U, A, C>>::new()
+        .route("/", get(get_tags))
+        .route("/merge", post(merge_tags_handler))
+        .route("/{tag}/follow", post(follow_tag_handler).delete(unfollow_tag_handler))
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct TagsQuery {
+    prefix: Option<String>,
+    limit: Option<u32>,
+}
+
+async fn get_tags<U, A, C>(
+    State(state): State<AppState<U, A, C>>,
+    Query(query): Query<TagsQuery>,
+) -> ApiResult<Json<TagsEnvelope>>
+where
+    U: domain::repositories::UsersRepository + Clone,
+    A: domain::repositories::ArticlesRepository + Clone,
+    C: domain::repositories::CommentsRepository + Clone,
+{
+    let key = (query.prefix.clone(), query.limit);
+    if let Some(cached) = state.tag_lists.get(&key).await {
+        return Ok(Json(cached.as_ref().clone()));
+    }
+
+    let input = ListTagsInput {
+        prefix: query.prefix,
+        limit: query.limit,
+    };
+    let envelope = list_tags(state.use_cases.tags_repo.as_ref(), input).await?;
+    state.tag_lists.insert(key, Arc::new(envelope.clone())).await;
+
+    Ok(Json(envelope))
+}
+
+#[derive(Debug, Deserialize)]
+struct MergeTagsRequest {
+    #[serde(rename = "tagMerge")]
+    tag_merge: MergeTagsBody,
+}
+
+#[derive(Debug, Deserialize)]
+struct MergeTagsBody {
+    from: String,
+    into: String,
 }
 
-#[derive(Debug, Serialize)]
-struct TagsResponse {
-    tags: Vec<String>,
+async fn merge_tags_handler<U, A, C>(
+    State(state): State<AppState<U, A, C>>,
+    _admin: AdminUser,
+    Json(req): Json<MergeTagsRequest>,
+) -> ApiResult<Json<TagMergeEnvelope>>
+where
+    U: domain::repositories::UsersRepository + Clone,
+    A: domain::repositories::ArticlesRepository + Clone,
+    C: domain::repositories::CommentsRepository + Clone,
+{
+    let input = MergeTagsInput {
+        from: req.tag_merge.from,
+        into: req.tag_merge.into,
+    };
+    let envelope = merge_tags(state.use_cases.tags_repo.as_ref(), input).await?;
+    state.tag_lists.invalidate_all();
+    state.related_articles.invalidate_all();
+
+    Ok(Json(envelope))
 }
 
-async fn get_tags<U, A, C>(State(state): State<AppState<U, A, C>>) -> impl IntoResponse
+async fn follow_tag_handler<U, A, C>(
+    State(state): State<AppState<U, A, C>>,
+    CurrentUser { user, .. }: CurrentUser,
+    Path(tag): Path<String>,
+) -> ApiResult<Json<TagEnvelope>>
 where
     U: domain::repositories::UsersRepository + Clone,
     A: domain::repositories::ArticlesRepository + Clone,
     C: domain::repositories::CommentsRepository + Clone,
 {
-    let tags = state
-        .tags
-        .read()
-        .await
-        .as_slice()
-        .iter()
-        .map(|tag| tag.as_str().to_owned())
-        .collect();
+    let envelope = follow_tag(state.use_cases.tags_repo.as_ref(), &tag, user.id).await?;
+    Ok(Json(envelope))
+}
 
-    Json(TagsResponse { tags })
+async fn unfollow_tag_handler<U, A, C>(
+    State(state): State<AppState<U, A, C>>,
+    CurrentUser { user, .. }: CurrentUser,
+    Path(tag): Path<String>,
+) -> ApiResult<Json<TagEnvelope>>
+where
+    U: domain::repositories::UsersRepository + Clone,
+    A: domain::repositories::ArticlesRepository + Clone,
+    C: domain::repositories::CommentsRepository + Clone,
+{
+    let envelope = unfollow_tag(state.use_cases.tags_repo.as_ref(), &tag, user.id).await?;
+    Ok(Json(envelope))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use axum::{body::Body, http::{Request, StatusCode}};
+    use chrono::Utc;
+    use domain::{Article, ArticleDraft, ArticleId, Email, PasswordHash, TagList, User, UserId, Username};
+    use domain::repositories::{ArticlesRepository, UsersRepository};
     use tower::ServiceExt;
 
+    async fn fetch(app: Router, uri: &str) -> (StatusCode, serde_json::Value) {
+        let response = app
+            .oneshot(Request::builder().method("GET").uri(uri).body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+        let status = response.status();
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        (status, serde_json::from_slice(&bytes).unwrap_or_default())
+    }
+
     #[tokio::test]
     async fn test_get_tags() {
         let state = AppState::default();
         let app = router().with_state(state);
-        
-        let response = app
-            .oneshot(
-                Request::builder()
-                    .method("GET")
-                    .uri("/")
-                    .body(Body::empty())
-                    .unwrap(),
-            )
-            .await
-            .unwrap();
-        
+
+        let (status, body) = fetch(app, "/").await;
+
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["tags"], serde_json::json!([]));
+    }
+
+    #[tokio::test]
+    async fn test_get_tags_counts_and_prefix() {
+        let state = AppState::default();
+        let author = User::new(
+            UserId::random(),
+            Email::parse("author@example.com").unwrap(),
+            Username::new("author").unwrap(),
+            PasswordHash::new("hash").unwrap(),
+            Utc::now(),
+        );
+        state.use_cases.users_repo.create_user(author.clone()).await.unwrap();
+        for (title, tags) in [("Both", vec!["rust", "web"]), ("Rust only", vec!["rust"])] {
+            let draft = ArticleDraft::new(title, "desc", "body", TagList::new(tags).unwrap()).unwrap();
+            let article = Article::publish(ArticleId::random(), author.id, draft, Utc::now()).unwrap();
+            state.use_cases.articles_repo.create_article(article).await.unwrap();
+        }
+        let app = router().with_state(state.clone());
+
+        let (_, popular) = fetch(app.clone(), "/").await;
+        assert_eq!(popular["tags"], serde_json::json!(["rust", "web"]));
+        assert_eq!(popular["tagCounts"][0]["articlesCount"], 2);
+
+        let (_, prefixed) = fetch(app.clone(), "/?prefix=W").await;
+        assert_eq!(prefixed["tags"], serde_json::json!(["web"]));
+
+        let (status, _) = fetch(app, "/?limit=0").await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+        state.tag_lists.run_pending_tasks().await;
+        assert_eq!(state.tag_lists.entry_count(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_merge_tags_requires_admin() {
+        let state = AppState::default().with_admins(["admin".to_owned()]);
+        for (name, token) in [("admin", "admin-token"), ("author", "author-token")] {
+            let user = User::new(
+                UserId::random(),
+                Email::parse(format!("{name}@example.com")).unwrap(),
+                Username::new(name).unwrap(),
+                PasswordHash::new("hash").unwrap(),
+                Utc::now(),
+            );
+            state.use_cases.users_repo.create_user(user.clone()).await.unwrap();
+            state.sessions.write().await.insert(token.to_owned(), user.id);
+            let draft = ArticleDraft::new(format!("Title by {name}"), "desc", "body", TagList::new(["JS"]).unwrap()).unwrap();
+            let article = Article::publish(ArticleId::random(), user.id, draft, Utc::now()).unwrap();
+            state.use_cases.articles_repo.create_article(article).await.unwrap();
+        }
+        let app = router().with_state(state.clone());
+        let merge = |token: &str| {
+            Request::builder()
+                .method("POST")
+                .uri("/merge")
+                .header("authorization", format!("Token {token}"))
+                .header("content-type", "application/json")
+                .body(Body::from(r#"{"tagMerge":{"from":"js","into":"JavaScript"}}"#))
+                .unwrap()
+        };
+
+        let (_, before) = fetch(app.clone(), "/").await;
+        assert_eq!(before["tags"], serde_json::json!(["js"]));
+
+        let response = app.clone().oneshot(merge("author-token")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+        let response = app.clone().oneshot(merge("admin-token")).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(body["tagMerge"]["into"], "javascript");
+        assert_eq!(body["tagMerge"]["articlesCount"], 2);
+
+        // The cached listing is dropped by the merge
+        let (_, after) = fetch(app, "/").await;
+        assert_eq!(after["tags"], serde_json::json!(["javascript"]));
+        assert_eq!(after["tagCounts"][0]["articlesCount"], 2);
+    }
+
+    #[tokio::test]
+    async fn test_follow_and_unfollow_tag() {
+        let state = AppState::default();
+        let user = User::new(
+            UserId::random(),
+            Email::parse("reader@example.com").unwrap(),
+            Username::new("reader").unwrap(),
+            PasswordHash::new("hash").unwrap(),
+            Utc::now(),
+        );
+        state.use_cases.users_repo.create_user(user.clone()).await.unwrap();
+        state.sessions.write().await.insert("reader-token".to_owned(), user.id);
+        let app = router().with_state(state.clone());
+        let request = |method: &str, token: Option<&str>| {
+            let mut builder = Request::builder().method(method).uri("/Rust/follow");
+            if let Some(token) = token {
+                builder = builder.header("authorization", format!("Token {token}"));
+            }
+            builder.body(Body::empty()).unwrap()
+        };
+
+        let response = app.clone().oneshot(request("POST", None)).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+        let response = app.clone().oneshot(request("POST", Some("reader-token"))).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(body["tag"], serde_json::json!({"name": "rust", "following": true}));
+        let followed = state.use_cases.tags_repo.followed_tags(user.id).await.unwrap();
+        assert_eq!(followed.len(), 1);
+
+        let response = app.oneshot(request("DELETE", Some("reader-token"))).await.unwrap();
         assert_eq!(response.status(), StatusCode::OK);
+        let followed = state.use_cases.tags_repo.followed_tags(user.id).await.unwrap();
+        assert!(followed.is_empty());
     }
 }
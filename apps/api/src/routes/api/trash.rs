@@ -0,0 +1,156 @@
+use axum::{
+    extract::{Path, State},
+    routing::{get, post},
+    Json, Router,
+};
+use chrono::Utc;
+use domain::{
+    ArticleEnvelope, CommentEnvelope, CommentId, TrashEnvelope,
+    use_cases::{list_trash, restore_article, restore_comment},
+};
+
+use crate::{
+    auth::CurrentUser,
+    error::{ApiError, ApiResult},
+    state::AppState,
+};
+
+pub fn router<U, A, C>() -> Router<AppState<U, A, C>>
+where
+    U: domain::repositories::UsersRepository + Clone + 'static,
+    A: domain::repositories::ArticlesRepository + Clone + 'static,
+    C: domain::repositories::CommentsRepository + Clone + 'static,
+{
+    Router::<AppState<U, A, C>>::new()
+        .route("/user/trash", get(list_trash_handler))
+        .route("/user/trash/articles/{slug}/restore", post(restore_article_handler))
+        .route("/user/trash/comments/{id}/restore", post(restore_comment_handler))
+}
+
+/// Articles and comments the current user deleted, with when each is erased
+async fn list_trash_handler<U, A, C>(
+    State(state): State<AppState<U, A, C>>,
+    CurrentUser { user, .. }: CurrentUser,
+) -> ApiResult<Json<TrashEnvelope>>
+where
+    U: domain::repositories::UsersRepository + Clone,
+    A: domain::repositories::ArticlesRepository + Clone,
+    C: domain::repositories::CommentsRepository + Clone,
+{
+    let envelope = list_trash(
+        &state.use_cases.articles_repo,
+        &state.use_cases.comments_repo,
+        user.id,
+        &state.trash_policy,
+    )
+    .await?;
+    Ok(Json(envelope))
+}
+
+async fn restore_article_handler<U, A, C>(
+    State(state): State<AppState<U, A, C>>,
+    CurrentUser { user, .. }: CurrentUser,
+    Path(slug): Path<String>,
+) -> ApiResult<Json<ArticleEnvelope>>
+where
+    U: domain::repositories::UsersRepository + Clone,
+    A: domain::repositories::ArticlesRepository + Clone,
+    C: domain::repositories::CommentsRepository + Clone,
+{
+    let view = restore_article(
+        &state.use_cases.users_repo,
+        &state.use_cases.articles_repo,
+        &slug,
+        user.id,
+        &state.trash_policy,
+        Utc::now(),
+    )
+    .await
+    .map_err(|_| ApiError::not_found("article"))?;
+
+    state.related_articles.invalidate_all();
+    Ok(Json(ArticleEnvelope::from(view)))
+}
+
+async fn restore_comment_handler<U, A, C>(
+    State(state): State<AppState<U, A, C>>,
+    CurrentUser { user, .. }: CurrentUser,
+    Path(id): Path<i64>,
+) -> ApiResult<Json<CommentEnvelope>>
+where
+    U: domain::repositories::UsersRepository + Clone,
+    A: domain::repositories::ArticlesRepository + Clone,
+    C: domain::repositories::CommentsRepository + Clone,
+{
+    let view = restore_comment(
+        &state.use_cases.users_repo,
+        &state.use_cases.comments_repo,
+        CommentId::new(id),
+        user.id,
+        &state.trash_policy,
+        Utc::now(),
+    )
+    .await
+    .map_err(|_| ApiError::not_found("comment"))?;
+
+    Ok(Json(CommentEnvelope::from(view)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{body::Body, http::{Request, StatusCode}};
+    use domain::repositories::{ArticlesRepository, UsersRepository};
+    use domain::{Article, ArticleDraft, ArticleId, Email, PasswordHash, TagList, User, UserId, Username};
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn test_trash_lists_and_restores_deleted_article() {
+        let state = AppState::default();
+        let user = User::new(
+            UserId::random(),
+            Email::parse("author@example.com").unwrap(),
+            Username::new("author").unwrap(),
+            PasswordHash::new("hash").unwrap(),
+            Utc::now(),
+        );
+        state.use_cases.users_repo.create_user(user.clone()).await.unwrap();
+        state.sessions.write().await.insert("tok".to_owned(), user.id);
+        let draft = ArticleDraft::new("Second thoughts", "Description", "Body", TagList::default()).unwrap();
+        let article = Article::publish(ArticleId::random(), user.id, draft, Utc::now()).unwrap();
+        state.use_cases.articles_repo.create_article(article.clone()).await.unwrap();
+        state.use_cases.articles_repo.delete_article(article.id, Utc::now()).await.unwrap();
+        let app = router().with_state(state.clone());
+
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .uri("/user/trash")
+                    .header("authorization", "Token tok")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let trash: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(trash["articles"][0]["slug"], "second-thoughts");
+        assert!(trash["articles"][0]["purgeAfter"].is_string());
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/user/trash/articles/second-thoughts/restore")
+                    .header("authorization", "Token tok")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(state.use_cases.articles_repo.get_article_by_slug("second-thoughts").await.unwrap().is_some());
+    }
+}
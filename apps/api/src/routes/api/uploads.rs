@@ -0,0 +1,228 @@
+use axum::{
+    Json, Router,
+    extract::{DefaultBodyLimit, Multipart, Path, State},
+    http::{StatusCode, header},
+    response::IntoResponse,
+    routing::{get, post},
+};
+use domain::{
+    DomainError, UploadEnvelope,
+    use_cases::{get_upload, upload_file},
+};
+
+use crate::{
+    auth::CurrentUser,
+    error::{ApiError, ApiResult},
+    state::AppState,
+};
+
+/// Form field holding the file
+const FILE_FIELD: &str = "file";
+/// Room for multipart headers and small extra fields on top of the file
+const FORM_OVERHEAD_BYTES: usize = 64 * 1024;
+/// Upload keys are never reused, so their content never changes
+const IMMUTABLE: &str = "public, max-age=31536000, immutable";
+
+pub fn router<U, A, C>() -> Router<AppState<U, A, C>>
+where
+    U: domain::repositories::UsersRepository + Clone + 'static,
+    A: domain::repositories::ArticlesRepository + Clone + 'static,
+    C: domain::repositories::CommentsRepository + Clone + 'static,
+{
+    Router::<AppState<U, A, C>>::new()
+        // The handler enforces the upload policy's limit while reading
+        .route("/", post(upload_handler).layer(DefaultBodyLimit::disable()))
+        .route("/{key}", get(get_upload_handler))
+}
+
+async fn upload_handler<U, A, C>(
+    State(state): State<AppState<U, A, C>>,
+    _current_user: CurrentUser,
+    mut multipart: Multipart,
+) -> ApiResult<(StatusCode, Json<UploadEnvelope>)>
+where
+    U: domain::repositories::UsersRepository + Clone,
+    A: domain::repositories::ArticlesRepository + Clone,
+    C: domain::repositories::CommentsRepository + Clone,
+{
+    let max = state.upload_policy.max_bytes;
+    let too_large = || ApiError::from(DomainError::UploadTooLarge { max });
+    let malformed = |e: axum::extract::multipart::MultipartError| {
+        ApiError::validation(format!("malformed multipart body: {}", e.body_text()))
+    };
+
+    let mut file = None;
+    let mut read = 0;
+    while let Some(mut field) = multipart.next_field().await.map_err(malformed)? {
+        let is_file = field.name() == Some(FILE_FIELD) && file.is_none();
+        let mut bytes = Vec::new();
+        while let Some(chunk) = field.chunk().await.map_err(malformed)? {
+            read += chunk.len();
+            if read > max + FORM_OVERHEAD_BYTES || (is_file && bytes.len() + chunk.len() > max) {
+                return Err(too_large());
+            }
+            if is_file {
+                bytes.extend_from_slice(&chunk);
+            }
+        }
+        if is_file {
+            file = Some(bytes);
+        }
+    }
+    let file = file.ok_or_else(|| ApiError::validation(format!("missing {FILE_FIELD} field")))?;
+
+    // Decoding and re-encoding images would hold up the runtime's threads
+    let policy = state.upload_policy.clone();
+    let processed = tokio::task::spawn_blocking(move || policy.process(&file))
+        .await
+        .map_err(|e| ApiError::internal(format!("processing the upload failed: {e}")))??;
+
+    let envelope = upload_file(
+        state.use_cases.blob_store.as_ref(),
+        &state.upload_policy,
+        processed,
+    )
+    .await?;
+
+    Ok((StatusCode::CREATED, Json(envelope)))
+}
+
+async fn get_upload_handler<U, A, C>(
+    State(state): State<AppState<U, A, C>>,
+    Path(key): Path<String>,
+) -> ApiResult<impl IntoResponse>
+where
+    U: domain::repositories::UsersRepository + Clone,
+    A: domain::repositories::ArticlesRepository + Clone,
+    C: domain::repositories::CommentsRepository + Clone,
+{
+    let blob = get_upload(state.use_cases.blob_store.as_ref(), &key).await?;
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, blob.content_type),
+            (header::CACHE_CONTROL, IMMUTABLE.to_owned()),
+            (header::X_CONTENT_TYPE_OPTIONS, "nosniff".to_owned()),
+        ],
+        blob.bytes,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{body::Body, http::Request};
+    use domain::{Email, PasswordHash, UploadPolicy, User, UserId, Username, repositories::UsersRepository};
+    use tower::ServiceExt;
+
+    const BOUNDARY: &str = "upload-boundary";
+
+    fn multipart_body(field: &str, bytes: &[u8]) -> Vec<u8> {
+        let mut body = format!(
+            "--{BOUNDARY}\r\nContent-Disposition: form-data; name=\"{field}\"; filename=\"photo.png\"\r\nContent-Type: application/octet-stream\r\n\r\n"
+        )
+        .into_bytes();
+        body.extend_from_slice(bytes);
+        body.extend_from_slice(format!("\r\n--{BOUNDARY}--\r\n").as_bytes());
+        body
+    }
+
+    fn png(width: u32, height: u32) -> Vec<u8> {
+        let mut bytes = std::io::Cursor::new(Vec::new());
+        image::RgbImage::new(width, height)
+            .write_to(&mut bytes, image::ImageFormat::Png)
+            .unwrap();
+        bytes.into_inner()
+    }
+
+    async fn signed_in_state() -> AppState<
+        domain::repositories::InMemoryUsersRepository,
+        domain::repositories::InMemoryArticlesRepository,
+        domain::repositories::InMemoryCommentsRepository,
+    > {
+        let state = AppState::default();
+        let user = User::new(
+            UserId::random(),
+            Email::parse("uploader@example.com").unwrap(),
+            Username::new("uploader").unwrap(),
+            PasswordHash::new("hash").unwrap(),
+            chrono::Utc::now(),
+        );
+        state.use_cases.users_repo.create_user(user.clone()).await.unwrap();
+        state.sessions.write().await.insert("tok".to_owned(), user.id);
+        state
+    }
+
+    fn upload_request(token: Option<&str>, body: Vec<u8>) -> Request<Body> {
+        let mut builder = Request::builder()
+            .method("POST")
+            .uri("/")
+            .header("content-type", format!("multipart/form-data; boundary={BOUNDARY}"));
+        if let Some(token) = token {
+            builder = builder.header("authorization", format!("Token {token}"));
+        }
+        builder.body(Body::from(body)).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_upload_image_and_fetch_it_back() {
+        let state = signed_in_state().await;
+        let app = router().with_state(state);
+
+        let response = app
+            .clone()
+            .oneshot(upload_request(Some("tok"), multipart_body("file", &png(800, 400))))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let envelope: UploadEnvelope = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(envelope.upload.content_type, "image/png");
+        assert_eq!(envelope.upload.width, Some(800));
+
+        for url in [envelope.upload.url, envelope.upload.thumbnail_url.unwrap()] {
+            let key = url.strip_prefix("/api/uploads").unwrap();
+            let response = app
+                .clone()
+                .oneshot(Request::builder().uri(key).body(Body::empty()).unwrap())
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::OK);
+            assert_eq!(response.headers()[header::CONTENT_TYPE], "image/png");
+            assert_eq!(response.headers()[header::CACHE_CONTROL], IMMUTABLE);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_upload_rejections() {
+        let state = signed_in_state()
+            .await
+            .with_upload_policy(UploadPolicy::new(1024, 64, "/api/uploads"));
+        let app = router().with_state(state);
+        let status = |request: Request<Body>| {
+            let app = app.clone();
+            async move { app.oneshot(request).await.unwrap().status() }
+        };
+
+        assert_eq!(
+            status(upload_request(None, multipart_body("file", &png(4, 4)))).await,
+            StatusCode::UNAUTHORIZED
+        );
+        assert_eq!(
+            status(upload_request(Some("tok"), multipart_body("file", b"<?php echo 1;"))).await,
+            StatusCode::UNSUPPORTED_MEDIA_TYPE
+        );
+        assert_eq!(
+            status(upload_request(Some("tok"), multipart_body("file", &[0; 2048]))).await,
+            StatusCode::PAYLOAD_TOO_LARGE
+        );
+        assert_eq!(
+            status(upload_request(Some("tok"), multipart_body("other", &png(4, 4)))).await,
+            StatusCode::UNPROCESSABLE_ENTITY
+        );
+        assert_eq!(
+            status(Request::builder().uri("/..%2F..%2Fetc%2Fpasswd").body(Body::empty()).unwrap()).await,
+            StatusCode::NOT_FOUND
+        );
+    }
+}
@@ -105,7 +105,15 @@ where
 
     let output = login_user(&state.use_cases.users_repo, input)
         .await
-        .map_err(|_| ApiError::unauthorized("invalid credentials"))?;
+        .map_err(|e| match e {
+            domain::DomainError::Suspended => ApiError::forbidden("account suspended"),
+            _ => ApiError::unauthorized("invalid credentials"),
+        })?;
+
+    if output.restored {
+        let event = domain::DomainEvent::AccountRestored { user_id: output.user.id };
+        tracing::info!(?event, "pending account deletion cancelled");
+    }
 
     let token = issue_token()?;
     remember_session(&state, &token, output.user.id).await;
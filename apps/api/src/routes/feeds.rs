@@ -0,0 +1,264 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode, header},
+    response::{IntoResponse, Response},
+    routing::get,
+    Router,
+};
+use domain::{
+    FeedFormat, FeedScope,
+    use_cases::{ArticleFeedInput, article_feed},
+};
+use serde::Deserialize;
+
+use crate::{
+    error::{ApiError, ApiResult},
+    state::AppState,
+};
+
+pub fn router<U, A, C>() -> Router<AppState<U, A, C>>
+where
+    U: domain::repositories::UsersRepository + Clone + 'static,
+    A: domain::repositories::ArticlesRepository + Clone + 'static,
+    C: domain::repositories::CommentsRepository + Clone + 'static,
+{
+    Router::<AppState<U, A, C>>::new()
+        .route("/articles.atom", get(global_atom_handler))
+        .route("/articles.rss", get(global_rss_handler))
+        // {tag}.atom or {tag}.rss
+        .route("/tags/{file}", get(tag_feed_handler))
+        // {username}.atom or {username}.rss
+        .route("/authors/{file}", get(author_feed_handler))
+}
+
+/// Feeds are polled, so readers revalidate rather than refetch
+const FEED_CACHE_CONTROL: &str = "public, max-age=300";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum FeedBody {
+    Summary,
+    Html,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct FeedQuery {
+    /// `html` adds each article's rendered body to its entry
+    render: Option<FeedBody>,
+    limit: Option<u32>,
+}
+
+async fn global_atom_handler<U, A, C>(
+    State(state): State<AppState<U, A, C>>,
+    Query(query): Query<FeedQuery>,
+    headers: HeaderMap,
+) -> ApiResult<Response>
+where
+    U: domain::repositories::UsersRepository + Clone,
+    A: domain::repositories::ArticlesRepository + Clone,
+    C: domain::repositories::CommentsRepository + Clone,
+{
+    feed_response(&state, FeedScope::All, FeedFormat::Atom, query, &headers).await
+}
+
+async fn global_rss_handler<U, A, C>(
+    State(state): State<AppState<U, A, C>>,
+    Query(query): Query<FeedQuery>,
+    headers: HeaderMap,
+) -> ApiResult<Response>
+where
+    U: domain::repositories::UsersRepository + Clone,
+    A: domain::repositories::ArticlesRepository + Clone,
+    C: domain::repositories::CommentsRepository + Clone,
+{
+    feed_response(&state, FeedScope::All, FeedFormat::Rss, query, &headers).await
+}
+
+async fn tag_feed_handler<U, A, C>(
+    State(state): State<AppState<U, A, C>>,
+    Path(file): Path<String>,
+    Query(query): Query<FeedQuery>,
+    headers: HeaderMap,
+) -> ApiResult<Response>
+where
+    U: domain::repositories::UsersRepository + Clone,
+    A: domain::repositories::ArticlesRepository + Clone,
+    C: domain::repositories::CommentsRepository + Clone,
+{
+    let (tag, format) = FeedFormat::split_file_name(&file).ok_or_else(|| ApiError::not_found("feed"))?;
+    feed_response(&state, FeedScope::Tag(tag.to_owned()), format, query, &headers).await
+}
+
+async fn author_feed_handler<U, A, C>(
+    State(state): State<AppState<U, A, C>>,
+    Path(file): Path<String>,
+    Query(query): Query<FeedQuery>,
+    headers: HeaderMap,
+) -> ApiResult<Response>
+where
+    U: domain::repositories::UsersRepository + Clone,
+    A: domain::repositories::ArticlesRepository + Clone,
+    C: domain::repositories::CommentsRepository + Clone,
+{
+    let (username, format) = FeedFormat::split_file_name(&file).ok_or_else(|| ApiError::not_found("feed"))?;
+    feed_response(&state, FeedScope::Author(username.to_owned()), format, query, &headers).await
+}
+
+async fn feed_response<U, A, C>(
+    state: &AppState<U, A, C>,
+    scope: FeedScope,
+    format: FeedFormat,
+    query: FeedQuery,
+    headers: &HeaderMap,
+) -> ApiResult<Response>
+where
+    U: domain::repositories::UsersRepository + Clone,
+    A: domain::repositories::ArticlesRepository + Clone,
+    C: domain::repositories::CommentsRepository + Clone,
+{
+    let input = ArticleFeedInput {
+        scope,
+        with_content: query.render == Some(FeedBody::Html),
+        limit: Some(query.limit.unwrap_or(state.feed_policy.entries)),
+    };
+    let mut feed = article_feed(
+        &state.use_cases.users_repo,
+        &state.use_cases.articles_repo,
+        state.use_cases.tags_repo.as_ref(),
+        input,
+    )
+    .await?;
+    for entry in &mut feed.entries {
+        if let Some((id, body)) = &entry.body {
+            entry.content_html = Some(state.render_markdown(*id, body).await.html.clone());
+        }
+    }
+    let rendered = feed.render(format, &state.site_urls, &state.feed_policy);
+
+    let cache_headers = [
+        (header::ETAG, rendered.etag.clone()),
+        (header::CACHE_CONTROL, FEED_CACHE_CONTROL.to_owned()),
+    ];
+    if not_modified(headers, &rendered.etag) {
+        return Ok((StatusCode::NOT_MODIFIED, cache_headers).into_response());
+    }
+
+    Ok((
+        cache_headers,
+        [(header::CONTENT_TYPE, format.content_type())],
+        rendered.xml,
+    )
+        .into_response())
+}
+
+/// Only `If-None-Match` is honored: a feed has no date that moves when an
+/// entry drops out of it, so `If-Modified-Since` could answer with a stale
+/// feed
+fn not_modified(headers: &HeaderMap, etag: &str) -> bool {
+    headers
+        .get_all(header::IF_NONE_MATCH)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(|tag| tag.trim().trim_start_matches("W/"))
+        .any(|tag| tag == etag || tag == "*")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{body::Body, http::Request};
+    use chrono::Utc;
+    use domain::repositories::{ArticlesRepository, UsersRepository};
+    use domain::{Article, ArticleDraft, ArticleId, Email, PasswordHash, TagList, User, UserId, Username};
+    use tower::ServiceExt;
+
+    async fn seeded_state() -> AppState<
+        domain::repositories::InMemoryUsersRepository,
+        domain::repositories::InMemoryArticlesRepository,
+        domain::repositories::InMemoryCommentsRepository,
+    > {
+        let state = AppState::default();
+        let author = User::new(
+            UserId::random(),
+            Email::parse("author@example.com").unwrap(),
+            Username::new("author").unwrap(),
+            PasswordHash::new("hash".to_string()).unwrap(),
+            Utc::now(),
+        );
+        state.use_cases.users_repo.create_user(author.clone()).await.unwrap();
+        for (title, tag) in [("Rusty", "rust"), ("Webby", "web")] {
+            let draft = ArticleDraft::new(title, "desc", "**bold** body", TagList::new([tag]).unwrap()).unwrap();
+            let article = Article::publish(ArticleId::random(), author.id, draft, Utc::now()).unwrap();
+            state.use_cases.articles_repo.create_article(article).await.unwrap();
+        }
+        state
+    }
+
+    async fn body_text(response: Response) -> String {
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        String::from_utf8(bytes.to_vec()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_feeds_in_both_formats() {
+        let state = seeded_state().await;
+        let app = router().with_state(state.clone());
+        let get = |uri: &str| {
+            app.clone()
+                .oneshot(Request::builder().method("GET").uri(uri).body(Body::empty()).unwrap())
+        };
+
+        let atom = get("/articles.atom").await.unwrap();
+        assert_eq!(atom.status(), StatusCode::OK);
+        assert_eq!(atom.headers()["content-type"], "application/atom+xml; charset=utf-8");
+        let xml = body_text(atom).await;
+        assert!(xml.contains("Rusty") && xml.contains("Webby"));
+        assert!(!xml.contains("<strong>"));
+
+        let rss = get("/tags/rust.rss?render=html").await.unwrap();
+        assert_eq!(rss.status(), StatusCode::OK);
+        assert_eq!(rss.headers()["content-type"], "application/rss+xml; charset=utf-8");
+        let xml = body_text(rss).await;
+        assert!(xml.contains("Rusty") && !xml.contains("Webby"));
+        assert!(xml.contains("<content:encoded><![CDATA[<p><strong>bold</strong> body</p>"));
+        // Rendered through the same cache as the article endpoint
+        let _ = get("/tags/rust.atom?render=html").await.unwrap();
+        state.rendered_bodies.run_pending_tasks().await;
+        assert_eq!(state.rendered_bodies.entry_count(), 1);
+
+        let author = get("/authors/author.atom?limit=1").await.unwrap();
+        assert_eq!(author.status(), StatusCode::OK);
+        assert_eq!(body_text(author).await.matches("<entry>").count(), 1);
+
+        assert_eq!(get("/authors/nobody.atom").await.unwrap().status(), StatusCode::NOT_FOUND);
+        assert_eq!(get("/tags/rust.json").await.unwrap().status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_feed_revalidation() {
+        let app = router().with_state(seeded_state().await);
+        let get = |headers: &[(&str, &str)]| {
+            let mut request = Request::builder().method("GET").uri("/articles.rss");
+            for (name, value) in headers {
+                request = request.header(*name, *value);
+            }
+            app.clone().oneshot(request.body(Body::empty()).unwrap())
+        };
+
+        let response = get(&[]).await.unwrap();
+        let etag = response.headers()["etag"].to_str().unwrap().to_owned();
+        assert!(response.headers().get("last-modified").is_none());
+
+        let revalidated = get(&[("if-none-match", &etag)]).await.unwrap();
+        assert_eq!(revalidated.status(), StatusCode::NOT_MODIFIED);
+        assert_eq!(revalidated.headers()["etag"], etag.as_str());
+
+        let stale = get(&[("if-none-match", "\"other\"")]).await.unwrap();
+        assert_eq!(stale.status(), StatusCode::OK);
+
+        // Dates are not validators for feeds
+        let since = get(&[("if-modified-since", "Fri, 01 Jan 2100 00:00:00 GMT")]).await.unwrap();
+        assert_eq!(since.status(), StatusCode::OK);
+    }
+}
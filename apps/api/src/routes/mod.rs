@@ -1,4 +1,6 @@
 pub mod api;
+mod feeds;
+mod sitemap;
 
 use crate::state::AppState;
 use axum::{extract::{MatchedPath, State}, middleware, routing::get, Router};
@@ -18,6 +20,8 @@ where
     Router::<AppState<U, A, C>>::new()
         .route("/health", get(health))
         .nest("/api", api::router())
+        .nest("/feeds", feeds::router())
+        .merge(sitemap::router())
         // Route-layer runs after matching, so MatchedPath is available.
         .route_layer(middleware::from_fn_with_state(http_metrics, record_http_metrics))
         .layer(
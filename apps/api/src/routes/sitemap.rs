@@ -0,0 +1,129 @@
+use axum::{
+    extract::{Path, State},
+    http::header,
+    response::{IntoResponse, Response},
+    routing::get,
+    Router,
+};
+use domain::{Sitemap, use_cases::get_sitemap};
+
+use crate::{
+    error::{ApiError, ApiResult},
+    state::AppState,
+};
+
+pub fn router<U, A, C>() -> Router<AppState<U, A, C>>
+where
+    U: domain::repositories::UsersRepository + Clone + 'static,
+    A: domain::repositories::ArticlesRepository + Clone + 'static,
+    C: domain::repositories::CommentsRepository + Clone + 'static,
+{
+    Router::<AppState<U, A, C>>::new()
+        .route("/sitemap.xml", get(sitemap_handler))
+        // {page}.xml, numbered from 1, when /sitemap.xml is an index
+        .route("/sitemaps/{file}", get(sitemap_page_handler))
+}
+
+/// Crawlers come back daily at most, so an hour old sitemap is fresh enough
+const SITEMAP_CACHE_CONTROL: &str = "public, max-age=3600";
+
+async fn sitemap_handler<U, A, C>(State(state): State<AppState<U, A, C>>) -> ApiResult<Response>
+where
+    U: domain::repositories::UsersRepository + Clone,
+    A: domain::repositories::ArticlesRepository + Clone,
+    C: domain::repositories::CommentsRepository + Clone,
+{
+    sitemap_response(&state, None).await
+}
+
+async fn sitemap_page_handler<U, A, C>(
+    State(state): State<AppState<U, A, C>>,
+    Path(file): Path<String>,
+) -> ApiResult<Response>
+where
+    U: domain::repositories::UsersRepository + Clone,
+    A: domain::repositories::ArticlesRepository + Clone,
+    C: domain::repositories::CommentsRepository + Clone,
+{
+    let page = Sitemap::parse_page(&file).ok_or_else(|| ApiError::not_found("sitemap"))?;
+    sitemap_response(&state, Some(page)).await
+}
+
+async fn sitemap_response<U, A, C>(state: &AppState<U, A, C>, page: Option<u32>) -> ApiResult<Response>
+where
+    U: domain::repositories::UsersRepository + Clone,
+    A: domain::repositories::ArticlesRepository + Clone,
+    C: domain::repositories::CommentsRepository + Clone,
+{
+    let sitemap = get_sitemap(&state.use_cases.articles_repo, page, state.sitemap_page_size).await?;
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, "application/xml; charset=utf-8"),
+            (header::CACHE_CONTROL, SITEMAP_CACHE_CONTROL),
+        ],
+        sitemap.render(&state.site_urls),
+    )
+        .into_response())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{
+        body::Body,
+        http::{Request, StatusCode},
+    };
+    use chrono::Utc;
+    use domain::repositories::{ArticlesRepository, UsersRepository};
+    use domain::{Article, ArticleDraft, ArticleId, Email, PasswordHash, SiteUrls, TagList, User, UserId, Username};
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn test_sitemap_turns_into_an_index() {
+        let state = AppState::default().with_site_urls(SiteUrls::new("https://conduit.example"));
+        let author = User::new(
+            UserId::random(),
+            Email::parse("author@example.com").unwrap(),
+            Username::new("author").unwrap(),
+            PasswordHash::new("hash".to_string()).unwrap(),
+            Utc::now(),
+        );
+        state.use_cases.users_repo.create_user(author.clone()).await.unwrap();
+        for title in ["First", "Second"] {
+            let draft = ArticleDraft::new(title, "desc", "body", TagList::default()).unwrap();
+            let article = Article::publish(ArticleId::random(), author.id, draft, Utc::now()).unwrap();
+            state.use_cases.articles_repo.create_article(article).await.unwrap();
+        }
+        let get = |app: Router, uri: &str| {
+            let request = Request::builder().method("GET").uri(uri).body(Body::empty()).unwrap();
+            async move {
+                let response = app.oneshot(request).await.unwrap();
+                let status = response.status();
+                let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+                (status, String::from_utf8(bytes.to_vec()).unwrap())
+            }
+        };
+
+        let app = router().with_state(state.clone());
+        let (status, xml) = get(app, "/sitemap.xml").await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(xml.contains("<urlset"));
+        assert!(xml.contains("<loc>https://conduit.example/article/first</loc>"));
+        assert!(xml.contains("<loc>https://conduit.example/profile/author</loc>"));
+
+        // Three URLs don't fit in sitemaps of two
+        let app = router().with_state(state.with_sitemap_page_size(2));
+        let (status, xml) = get(app.clone(), "/sitemap.xml").await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(xml.contains("<sitemapindex"));
+        assert!(xml.contains("<loc>https://conduit.example/sitemaps/2.xml</loc>"));
+        let (status, xml) = get(app.clone(), "/sitemaps/2.xml").await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(xml.matches("<url>").count(), 1);
+        assert!(xml.contains("/profile/author</loc>"));
+
+        assert_eq!(get(app.clone(), "/sitemaps/3.xml").await.0, StatusCode::NOT_FOUND);
+        assert_eq!(get(app, "/sitemaps/first.xml").await.0, StatusCode::NOT_FOUND);
+    }
+}
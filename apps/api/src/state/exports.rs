@@ -0,0 +1,72 @@
+use chrono::{DateTime, Utc};
+use domain::UserId;
+use subtle::ConstantTimeEq;
+
+/// How long a download link stays valid once the archive is ready
+pub const DEFAULT_EXPORT_LINK_TTL_HOURS: i64 = 24;
+
+pub(super) fn default_link_ttl() -> chrono::Duration {
+    chrono::Duration::hours(DEFAULT_EXPORT_LINK_TTL_HOURS)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportStatus {
+    Pending,
+    Ready,
+    Failed,
+}
+
+impl ExportStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Ready => "ready",
+            Self::Failed => "failed",
+        }
+    }
+}
+
+/// A personal data export requested by a user
+#[derive(Debug, Clone)]
+pub struct ExportJob {
+    pub user_id: UserId,
+    pub status: ExportStatus,
+    pub requested_at: DateTime<Utc>,
+    /// Secret carried by the download link, so it can be fetched without a session
+    pub download_token: String,
+    /// Set once the archive is ready; the link stops working afterwards
+    pub expires_at: Option<DateTime<Utc>>,
+    pub archive: Option<Vec<u8>>,
+}
+
+impl ExportJob {
+    pub fn pending(user_id: UserId, download_token: String, now: DateTime<Utc>) -> Self {
+        Self {
+            user_id,
+            status: ExportStatus::Pending,
+            requested_at: now,
+            download_token,
+            expires_at: None,
+            archive: None,
+        }
+    }
+
+    /// Compares in constant time, so response timings don't leak how much
+    /// of a guessed token is right
+    pub fn token_matches(&self, token: &str) -> bool {
+        self.download_token.as_bytes().ct_eq(token.as_bytes()).into()
+    }
+
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at.is_some_and(|at| at <= now)
+    }
+
+    /// Whether the job can be dropped: its link expired, or it failed or
+    /// never finished within `ttl` of being requested
+    pub fn is_stale(&self, now: DateTime<Utc>, ttl: chrono::Duration) -> bool {
+        match self.status {
+            ExportStatus::Ready => self.is_expired(now),
+            ExportStatus::Pending | ExportStatus::Failed => self.requested_at + ttl <= now,
+        }
+    }
+}
@@ -1,15 +1,28 @@
+mod exports;
+
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
+    hash::{DefaultHasher, Hash, Hasher},
     sync::Arc,
 };
 
 use domain::{
-    Tag, TagList,
+    AccountDeletionPolicy, ArticleId, ArticleView, ArticlesEnvelope, FeedPolicy, MAX_SITEMAP_URLS, RenderedBody, SiteUrls, SlugPolicy,
+    ReactionPolicy, TagsEnvelope, TrashPolicy, UploadPolicy,
     use_cases::UseCases,
     UserId,
     repositories::{UsersRepository, ArticlesRepository, CommentsRepository},
 };
+use moka::future::Cache;
 use tokio::sync::RwLock;
+use uuid::Uuid;
+
+pub use exports::{ExportJob, ExportStatus};
+
+const DEFAULT_RENDER_CACHE_CAPACITY: u64 = 1_000;
+const DEFAULT_TRENDING_WINDOW_DAYS: i64 = 7;
+const DEFAULT_TAG_CACHE_TTL: std::time::Duration = std::time::Duration::from_secs(60);
+const DEFAULT_RELATED_CACHE_TTL: std::time::Duration = std::time::Duration::from_secs(600);
 
 #[derive(Clone)]
 pub struct AppState<U = data::PostgresUsersRepository, A = data::PostgresArticlesRepository, C = data::PostgresCommentsRepository>
@@ -21,23 +34,170 @@ where
     // TODO: Replace with proper JWT-based auth or session store (Redis)
     pub sessions: Arc<RwLock<HashMap<String, UserId>>>,
     pub use_cases: Arc<UseCases<U, A, C>>,
-    // TODO: Extract tags from database or maintain as cache
-    pub tags: Arc<RwLock<TagList>>,
+    pub account_deletion: AccountDeletionPolicy,
+    pub trash_policy: TrashPolicy,
+    pub reaction_policy: ReactionPolicy,
+    /// Usernames allowed to run administrative operations
+    pub admins: Arc<HashSet<String>>,
+    /// Usernames allowed to work through the moderation queue, besides admins
+    pub moderators: Arc<HashSet<String>>,
+    // Finished archives are kept in memory until their download link expires
+    pub exports: Arc<RwLock<HashMap<Uuid, ExportJob>>>,
+    pub export_link_ttl: chrono::Duration,
+    pub slug_policy: SlugPolicy,
+    /// Rendered bodies keyed by article and a hash of the body, so a rename
+    /// still hits and any edit to the body misses
+    pub rendered_bodies: Cache<(ArticleId, u64), Arc<RenderedBody>>,
+    /// How far back favorites count when sorting by trending
+    pub trending_window: chrono::Duration,
+    /// Related articles shown to anonymous readers, keyed by slug and limit;
+    /// emptied whenever an article changes and otherwise kept for the TTL.
+    /// Signed-in readers differ in mutes and flags, so theirs aren't cached
+    pub related_articles: Cache<(String, Option<u32>), Arc<ArticlesEnvelope>>,
+    /// Tag listings keyed by prefix and limit; counts may lag by the TTL
+    pub tag_lists: Cache<(Option<String>, Option<u32>), Arc<TagsEnvelope>>,
+    pub upload_policy: UploadPolicy,
+    /// Public address of the web app that feeds and sitemaps link to
+    pub site_urls: SiteUrls,
+    pub sitemap_page_size: u32,
+    pub feed_policy: FeedPolicy,
+}
+
+impl<U, A, C> AppState<U, A, C>
+where
+    U: UsersRepository + Clone,
+    A: ArticlesRepository + Clone,
+    C: CommentsRepository + Clone,
+{
+    pub fn with_account_deletion(mut self, policy: AccountDeletionPolicy) -> Self {
+        self.account_deletion = policy;
+        self
+    }
+
+    pub fn with_trash_policy(mut self, policy: TrashPolicy) -> Self {
+        self.trash_policy = policy;
+        self
+    }
+
+    pub fn with_reaction_policy(mut self, policy: ReactionPolicy) -> Self {
+        self.reaction_policy = policy;
+        self
+    }
+
+    pub fn with_admins(mut self, usernames: impl IntoIterator<Item = String>) -> Self {
+        self.admins = Arc::new(usernames.into_iter().collect());
+        self
+    }
+
+    pub fn with_moderators(mut self, usernames: impl IntoIterator<Item = String>) -> Self {
+        self.moderators = Arc::new(usernames.into_iter().collect());
+        self
+    }
+
+    pub fn with_export_link_ttl(mut self, ttl: chrono::Duration) -> Self {
+        self.export_link_ttl = ttl;
+        self
+    }
+
+    pub fn with_slug_policy(mut self, policy: SlugPolicy) -> Self {
+        self.slug_policy = policy;
+        self
+    }
+
+    pub fn with_render_cache_capacity(mut self, capacity: u64) -> Self {
+        self.rendered_bodies = Cache::new(capacity);
+        self
+    }
+
+    pub fn with_trending_window(mut self, window: chrono::Duration) -> Self {
+        self.trending_window = window;
+        self
+    }
+
+    pub fn with_related_cache_ttl(mut self, ttl: std::time::Duration) -> Self {
+        self.related_articles = related_cache(ttl);
+        self
+    }
+
+    pub fn with_tag_cache_ttl(mut self, ttl: std::time::Duration) -> Self {
+        self.tag_lists = tag_cache(ttl);
+        self
+    }
+
+    pub fn with_upload_policy(mut self, policy: UploadPolicy) -> Self {
+        self.upload_policy = policy;
+        self
+    }
+
+    pub fn with_site_urls(mut self, site_urls: SiteUrls) -> Self {
+        self.site_urls = site_urls;
+        self
+    }
+
+    pub fn with_sitemap_page_size(mut self, page_size: u32) -> Self {
+        self.sitemap_page_size = page_size;
+        self
+    }
+
+    pub fn with_feed_policy(mut self, policy: FeedPolicy) -> Self {
+        self.feed_policy = policy;
+        self
+    }
+
+    /// Markdown body of the article rendered to sanitized HTML, from the cache
+    /// when this version was rendered before
+    pub async fn render_body(&self, view: &ArticleView) -> Arc<RenderedBody> {
+        self.render_markdown(view.id, &view.body).await
+    }
+
+    /// `body` of the article rendered as `render_body` does, sharing its cache
+    pub async fn render_markdown(&self, id: ArticleId, body: &str) -> Arc<RenderedBody> {
+        let mut hasher = DefaultHasher::new();
+        body.hash(&mut hasher);
+        let key = (id, hasher.finish());
+        self.rendered_bodies
+            .get_with(key, async { Arc::new(domain::render_markdown(body)) })
+            .await
+    }
+
+    /// Drops every session token issued to the given users
+    pub async fn forget_sessions(&self, user_ids: &[UserId]) {
+        self.sessions
+            .write()
+            .await
+            .retain(|_, user_id| !user_ids.contains(user_id));
+    }
+}
+
+fn related_cache(ttl: std::time::Duration) -> Cache<(String, Option<u32>), Arc<ArticlesEnvelope>> {
+    Cache::builder().max_capacity(10_000).time_to_live(ttl).build()
+}
+
+fn tag_cache(ttl: std::time::Duration) -> Cache<(Option<String>, Option<u32>), Arc<TagsEnvelope>> {
+    Cache::builder().max_capacity(1_000).time_to_live(ttl).build()
 }
 
 impl AppState {
     pub fn new(use_cases: UseCases<data::PostgresUsersRepository, data::PostgresArticlesRepository, data::PostgresCommentsRepository>) -> Self {
-        let mut tags = TagList::default();
-        for label in ["rust", "axum", "realworld"] {
-            if let Ok(tag) = Tag::new(label) {
-                tags.push(tag);
-            }
-        }
-        
         Self {
             sessions: Arc::new(RwLock::new(HashMap::new())),
             use_cases: Arc::new(use_cases),
-            tags: Arc::new(RwLock::new(tags)),
+            account_deletion: AccountDeletionPolicy::default(),
+            trash_policy: TrashPolicy::default(),
+            reaction_policy: ReactionPolicy::default(),
+            admins: Arc::new(HashSet::new()),
+            moderators: Arc::new(HashSet::new()),
+            exports: Arc::new(RwLock::new(HashMap::new())),
+            export_link_ttl: exports::default_link_ttl(),
+            slug_policy: SlugPolicy::default(),
+            rendered_bodies: Cache::new(DEFAULT_RENDER_CACHE_CAPACITY),
+            trending_window: chrono::Duration::days(DEFAULT_TRENDING_WINDOW_DAYS),
+            related_articles: related_cache(DEFAULT_RELATED_CACHE_TTL),
+            tag_lists: tag_cache(DEFAULT_TAG_CACHE_TTL),
+            upload_policy: UploadPolicy::default(),
+            site_urls: SiteUrls::default(),
+            sitemap_page_size: MAX_SITEMAP_URLS,
+            feed_policy: FeedPolicy::default(),
         }
     }
 }
@@ -46,25 +206,51 @@ impl AppState {
 impl AppState<domain::repositories::InMemoryUsersRepository, domain::repositories::InMemoryArticlesRepository, domain::repositories::InMemoryCommentsRepository> {
     #[allow(clippy::should_implement_trait)]
     pub fn default() -> Self {
-        use domain::repositories::{InMemoryUsersRepository, InMemoryArticlesRepository, InMemoryCommentsRepository};
+        use domain::repositories::{
+            InMemoryUsersRepository, InMemoryArticlesRepository, InMemoryBlobStore,
+            InMemoryCommentsRepository, InMemoryReportsRepository, InMemoryRevisionsRepository,
+            InMemorySeriesRepository, InMemoryTagsRepository,
+        };
         
         let users_repo = InMemoryUsersRepository::new();
-        let articles_repo = InMemoryArticlesRepository::new(users_repo.clone());
         let comments_repo = InMemoryCommentsRepository::new();
+        let articles_repo = InMemoryArticlesRepository::new(users_repo.clone()).with_comments(comments_repo.clone());
+        let revisions_repo = Arc::new(InMemoryRevisionsRepository::new());
+        let tags_repo = Arc::new(InMemoryTagsRepository::new(articles_repo.clone()));
+        let series_repo = Arc::new(InMemorySeriesRepository::new(articles_repo.clone()));
+        let blob_store = Arc::new(InMemoryBlobStore::new());
+        let reports_repo = Arc::new(InMemoryReportsRepository::new());
         
-        let use_cases = UseCases::new(users_repo, articles_repo, comments_repo);
-        
-        let mut tags = TagList::default();
-        for label in ["rust", "axum", "realworld"] {
-            if let Ok(tag) = Tag::new(label) {
-                tags.push(tag);
-            }
-        }
+        let use_cases = UseCases {
+            users_repo,
+            articles_repo,
+            comments_repo,
+            revisions_repo,
+            tags_repo,
+            series_repo,
+            blob_store,
+            reports_repo,
+        };
         
         Self {
             sessions: Arc::new(RwLock::new(HashMap::new())),
             use_cases: Arc::new(use_cases),
-            tags: Arc::new(RwLock::new(tags)),
+            account_deletion: AccountDeletionPolicy::default(),
+            trash_policy: TrashPolicy::default(),
+            reaction_policy: ReactionPolicy::default(),
+            admins: Arc::new(HashSet::new()),
+            moderators: Arc::new(HashSet::new()),
+            exports: Arc::new(RwLock::new(HashMap::new())),
+            export_link_ttl: exports::default_link_ttl(),
+            slug_policy: SlugPolicy::default(),
+            rendered_bodies: Cache::new(DEFAULT_RENDER_CACHE_CAPACITY),
+            trending_window: chrono::Duration::days(DEFAULT_TRENDING_WINDOW_DAYS),
+            related_articles: related_cache(DEFAULT_RELATED_CACHE_TTL),
+            tag_lists: tag_cache(DEFAULT_TAG_CACHE_TTL),
+            upload_policy: UploadPolicy::default(),
+            site_urls: SiteUrls::default(),
+            sitemap_page_size: MAX_SITEMAP_URLS,
+            feed_policy: FeedPolicy::default(),
         }
     }
 }
@@ -31,6 +31,22 @@ pub struct AppConfig {
     // pub restate: RestateConfig,
     pub telemetry: TelemetryConfig,
     // pub security: SecurityConfig,
+    #[serde(default)]
+    pub accounts: AccountsConfig,
+    #[serde(default)]
+    pub articles: ArticlesConfig,
+    #[serde(default)]
+    pub trash: TrashConfig,
+    #[serde(default)]
+    pub reactions: ReactionsConfig,
+    #[serde(default)]
+    pub tags: TagsConfig,
+    #[serde(default)]
+    pub uploads: UploadsConfig,
+    #[serde(default)]
+    pub site: SiteConfig,
+    #[serde(default)]
+    pub feeds: FeedsConfig,
 }
 
 impl AppConfig {
@@ -102,6 +118,335 @@ impl DatabaseConfig {
     }
 }
 
+/// What to do with an erased account's articles and comments
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DeletedContentMode {
+    #[default]
+    Delete,
+    Anonymise,
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Deserialize)]
+pub struct AccountsConfig {
+    #[serde(default = "AccountsConfig::default_deletion_grace_days")]
+    pub deletion_grace_days: u32,
+    #[serde(default)]
+    pub deleted_content: DeletedContentMode,
+    #[serde(default = "AccountsConfig::default_purge_interval")]
+    #[serde_as(as = "DurationSeconds<u64>")]
+    pub purge_interval: Duration,
+    #[serde(default = "AccountsConfig::default_export_link_ttl")]
+    #[serde_as(as = "DurationSeconds<u64>")]
+    pub export_link_ttl: Duration,
+    /// Usernames allowed to run administrative operations
+    #[serde(default)]
+    pub admins: Vec<String>,
+    /// Usernames allowed to work through the moderation queue, besides admins
+    #[serde(default)]
+    pub moderators: Vec<String>,
+}
+
+impl AccountsConfig {
+    const fn default_deletion_grace_days() -> u32 {
+        30
+    }
+
+    fn default_purge_interval() -> Duration {
+        Duration::from_secs(60 * 60)
+    }
+
+    fn default_export_link_ttl() -> Duration {
+        Duration::from_secs(24 * 60 * 60)
+    }
+}
+
+impl Default for AccountsConfig {
+    fn default() -> Self {
+        Self {
+            deletion_grace_days: Self::default_deletion_grace_days(),
+            deleted_content: DeletedContentMode::default(),
+            purge_interval: Self::default_purge_interval(),
+            export_link_ttl: Self::default_export_link_ttl(),
+            admins: Vec::new(),
+            moderators: Vec::new(),
+        }
+    }
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Deserialize)]
+pub struct ArticlesConfig {
+    #[serde(default = "ArticlesConfig::default_slug_max_len")]
+    pub slug_max_len: usize,
+    #[serde(default = "ArticlesConfig::default_publish_interval")]
+    #[serde_as(as = "DurationSeconds<u64>")]
+    pub publish_interval: Duration,
+    #[serde(default = "ArticlesConfig::default_render_cache_capacity")]
+    pub render_cache_capacity: u64,
+    #[serde(default = "ArticlesConfig::default_trending_window")]
+    #[serde_as(as = "DurationSeconds<u64>")]
+    pub trending_window: Duration,
+    /// Related articles are recomputed after any article change, or this long
+    #[serde(default = "ArticlesConfig::default_related_cache_ttl")]
+    #[serde_as(as = "DurationSeconds<u64>")]
+    pub related_cache_ttl: Duration,
+}
+
+impl ArticlesConfig {
+    const fn default_slug_max_len() -> usize {
+        80
+    }
+
+    fn default_publish_interval() -> Duration {
+        Duration::from_secs(60)
+    }
+
+    const fn default_render_cache_capacity() -> u64 {
+        1_000
+    }
+
+    fn default_trending_window() -> Duration {
+        Duration::from_secs(7 * 24 * 60 * 60)
+    }
+
+    fn default_related_cache_ttl() -> Duration {
+        Duration::from_secs(10 * 60)
+    }
+}
+
+impl Default for ArticlesConfig {
+    fn default() -> Self {
+        Self {
+            slug_max_len: Self::default_slug_max_len(),
+            publish_interval: Self::default_publish_interval(),
+            render_cache_capacity: Self::default_render_cache_capacity(),
+            trending_window: Self::default_trending_window(),
+            related_cache_ttl: Self::default_related_cache_ttl(),
+        }
+    }
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Deserialize)]
+pub struct TrashConfig {
+    #[serde(default = "TrashConfig::default_retention_days")]
+    pub retention_days: u32,
+    #[serde(default = "TrashConfig::default_purge_interval")]
+    #[serde_as(as = "DurationSeconds<u64>")]
+    pub purge_interval: Duration,
+}
+
+impl TrashConfig {
+    const fn default_retention_days() -> u32 {
+        30
+    }
+
+    fn default_purge_interval() -> Duration {
+        Duration::from_secs(60 * 60)
+    }
+}
+
+impl Default for TrashConfig {
+    fn default() -> Self {
+        Self {
+            retention_days: Self::default_retention_days(),
+            purge_interval: Self::default_purge_interval(),
+        }
+    }
+}
+
+/// A reaction users may leave: its name in URLs and the emoji it stands for
+#[derive(Debug, Clone, Deserialize)]
+pub struct ReactionConfig {
+    pub kind: String,
+    pub emoji: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct ReactionsConfig {
+    /// Offered in this order
+    #[serde(default = "ReactionsConfig::default_kinds")]
+    pub kinds: Vec<ReactionConfig>,
+}
+
+impl ReactionsConfig {
+    fn default_kinds() -> Vec<ReactionConfig> {
+        [("thumbs_up", "👍"), ("heart", "❤️"), ("tada", "🎉"), ("thinking", "🤔")]
+            .into_iter()
+            .map(|(kind, emoji)| ReactionConfig {
+                kind: kind.to_owned(),
+                emoji: emoji.to_owned(),
+            })
+            .collect()
+    }
+}
+
+impl Default for ReactionsConfig {
+    fn default() -> Self {
+        Self {
+            kinds: Self::default_kinds(),
+        }
+    }
+}
+
+#[serde_as]
+#[derive(Debug, Clone, Deserialize)]
+pub struct TagsConfig {
+    #[serde(default = "TagsConfig::default_cache_ttl")]
+    #[serde_as(as = "DurationSeconds<u64>")]
+    pub cache_ttl: Duration,
+}
+
+impl TagsConfig {
+    fn default_cache_ttl() -> Duration {
+        Duration::from_secs(60)
+    }
+}
+
+impl Default for TagsConfig {
+    fn default() -> Self {
+        Self {
+            cache_ttl: Self::default_cache_ttl(),
+        }
+    }
+}
+
+/// Where uploaded files are kept
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UploadBackend {
+    #[default]
+    Local,
+    S3,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct UploadsConfig {
+    #[serde(default)]
+    pub backend: UploadBackend,
+    /// Directory used by the local backend
+    #[serde(default = "UploadsConfig::default_local_dir")]
+    pub local_dir: PathBuf,
+    #[serde(default = "UploadsConfig::default_max_bytes")]
+    pub max_bytes: usize,
+    #[serde(default = "UploadsConfig::default_thumbnail_size")]
+    pub thumbnail_size: u32,
+    /// Base URL upload links are built on
+    #[serde(default = "UploadsConfig::default_public_url")]
+    pub public_url: String,
+    /// Required by the S3 backend
+    #[serde(default)]
+    pub s3: Option<S3Config>,
+}
+
+impl UploadsConfig {
+    fn default_local_dir() -> PathBuf {
+        "uploads".into()
+    }
+
+    const fn default_max_bytes() -> usize {
+        5 * 1024 * 1024
+    }
+
+    const fn default_thumbnail_size() -> u32 {
+        320
+    }
+
+    fn default_public_url() -> String {
+        "/api/uploads".into()
+    }
+}
+
+impl Default for UploadsConfig {
+    fn default() -> Self {
+        Self {
+            backend: UploadBackend::default(),
+            local_dir: Self::default_local_dir(),
+            max_bytes: Self::default_max_bytes(),
+            thumbnail_size: Self::default_thumbnail_size(),
+            public_url: Self::default_public_url(),
+            s3: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SiteConfig {
+    /// Public address of the web app, which feed and sitemap links point into
+    #[serde(default = "SiteConfig::default_url")]
+    pub url: String,
+    /// Most URLs in one sitemap before `/sitemap.xml` becomes an index
+    #[serde(default = "SiteConfig::default_sitemap_page_size")]
+    pub sitemap_page_size: u32,
+}
+
+impl SiteConfig {
+    fn default_url() -> String {
+        "http://localhost:3000".into()
+    }
+
+    const fn default_sitemap_page_size() -> u32 {
+        50_000
+    }
+}
+
+impl Default for SiteConfig {
+    fn default() -> Self {
+        Self {
+            url: Self::default_url(),
+            sitemap_page_size: Self::default_sitemap_page_size(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct FeedsConfig {
+    #[serde(default = "FeedsConfig::default_title")]
+    pub title: String,
+    #[serde(default = "FeedsConfig::default_entries")]
+    pub entries: u32,
+}
+
+impl FeedsConfig {
+    fn default_title() -> String {
+        "Conduit".into()
+    }
+
+    const fn default_entries() -> u32 {
+        20
+    }
+}
+
+impl Default for FeedsConfig {
+    fn default() -> Self {
+        Self {
+            title: Self::default_title(),
+            entries: Self::default_entries(),
+        }
+    }
+}
+
+/// An S3 bucket, or a bucket on any service speaking the S3 API
+#[derive(Debug, Clone, Deserialize)]
+pub struct S3Config {
+    pub bucket: String,
+    #[serde(default = "S3Config::default_region")]
+    pub region: String,
+    /// Set for S3-compatible services such as MinIO; AWS is used otherwise
+    #[serde(default)]
+    pub endpoint: Option<String>,
+    pub access_key_id: String,
+    pub secret_access_key: String,
+}
+
+impl S3Config {
+    fn default_region() -> String {
+        "us-east-1".into()
+    }
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct OpaConfig {
     pub base_url: String,
@@ -0,0 +1,19 @@
+-- migrate:up
+
+ALTER TABLE appuser ADD COLUMN deactivated_at timestamptz;
+
+-- purge job looks up accounts whose grace period has elapsed
+CREATE INDEX appuser_deactivated_at_idx ON appuser(deactivated_at) WHERE deactivated_at IS NOT NULL;
+
+-- placeholder author that anonymised articles and comments are reassigned to
+INSERT INTO appuser (id, email, username, pwd, img, bio)
+VALUES ('00000000-0000-0000-0000-000000000000', 'deleted-user@invalid', 'deleted-user', '!', '', '')
+ON CONFLICT (id) DO NOTHING;
+
+-- migrate:down
+
+DELETE FROM appuser WHERE id = '00000000-0000-0000-0000-000000000000';
+
+DROP INDEX IF EXISTS appuser_deactivated_at_idx;
+
+ALTER TABLE appuser DROP COLUMN IF EXISTS deactivated_at;
@@ -0,0 +1,28 @@
+-- migrate:up
+
+CREATE TABLE appuser_blocks(
+    blocker_id uuid NOT NULL,
+    blocked_id uuid NOT NULL,
+    created_at timestamptz NOT NULL DEFAULT (now()),
+    PRIMARY KEY (blocker_id, blocked_id),
+    FOREIGN KEY (blocker_id) REFERENCES appuser(id) ON DELETE CASCADE ON UPDATE CASCADE,
+    FOREIGN KEY (blocked_id) REFERENCES appuser(id) ON DELETE CASCADE ON UPDATE CASCADE
+);
+
+-- "who blocked me" is checked on every listing
+CREATE INDEX appuser_blocks_blocked_id_idx ON appuser_blocks(blocked_id);
+
+CREATE TABLE appuser_mutes(
+    muter_id uuid NOT NULL,
+    muted_id uuid NOT NULL,
+    created_at timestamptz NOT NULL DEFAULT (now()),
+    PRIMARY KEY (muter_id, muted_id),
+    FOREIGN KEY (muter_id) REFERENCES appuser(id) ON DELETE CASCADE ON UPDATE CASCADE,
+    FOREIGN KEY (muted_id) REFERENCES appuser(id) ON DELETE CASCADE ON UPDATE CASCADE
+);
+
+-- migrate:down
+
+DROP TABLE IF EXISTS appuser_mutes;
+
+DROP TABLE IF EXISTS appuser_blocks;
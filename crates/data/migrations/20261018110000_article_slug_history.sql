@@ -0,0 +1,15 @@
+-- migrate:up
+
+-- slugs an article had before its title was edited, so shared links keep working
+CREATE TABLE article_slug_history(
+    slug varchar PRIMARY KEY,
+    article_id uuid NOT NULL,
+    created_at timestamptz NOT NULL DEFAULT (now()),
+    FOREIGN KEY (article_id) REFERENCES article(id) ON DELETE CASCADE ON UPDATE CASCADE
+);
+
+CREATE INDEX article_slug_history_article_id_idx ON article_slug_history(article_id);
+
+-- migrate:down
+
+DROP TABLE IF EXISTS article_slug_history;
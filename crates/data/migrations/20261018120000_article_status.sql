@@ -0,0 +1,21 @@
+-- migrate:up
+
+-- draft -> scheduled -> published lifecycle; published_at is the go-live time,
+-- in the future for scheduled articles and NULL for drafts
+ALTER TABLE article
+    ADD COLUMN status text NOT NULL DEFAULT 'published'
+        CHECK (status IN ('draft', 'scheduled', 'published')),
+    ADD COLUMN published_at timestamptz;
+
+UPDATE article SET published_at = created_at;
+
+-- the scheduler only ever looks for scheduled articles that are due
+CREATE INDEX article_scheduled_idx ON article(published_at) WHERE status = 'scheduled';
+
+-- migrate:down
+
+DROP INDEX IF EXISTS article_scheduled_idx;
+
+ALTER TABLE article
+    DROP COLUMN IF EXISTS published_at,
+    DROP COLUMN IF EXISTS status;
@@ -0,0 +1,20 @@
+-- migrate:up
+
+-- immutable snapshots of an article's content, one per edit
+CREATE TABLE article_revision(
+    id uuid PRIMARY KEY,
+    article_id uuid NOT NULL,
+    version int NOT NULL,
+    author_id uuid NOT NULL,
+    title varchar NOT NULL,
+    description varchar NOT NULL,
+    body text NOT NULL,
+    created_at timestamptz NOT NULL DEFAULT (now()),
+    UNIQUE (article_id, version),
+    FOREIGN KEY (article_id) REFERENCES article(id) ON DELETE CASCADE ON UPDATE CASCADE,
+    FOREIGN KEY (author_id) REFERENCES appuser(id) ON DELETE CASCADE ON UPDATE CASCADE
+);
+
+-- migrate:down
+
+DROP TABLE IF EXISTS article_revision;
@@ -0,0 +1,20 @@
+-- migrate:up
+
+-- weighted document for full-text search: title (A), description (B), body (C);
+-- an indexed expression rather than a stored column so `article.*` keeps its shape
+CREATE FUNCTION article_search_vector(title text, description text, body text)
+RETURNS tsvector
+LANGUAGE sql IMMUTABLE PARALLEL SAFE
+AS $$
+    SELECT setweight(to_tsvector('english', title), 'A')
+        || setweight(to_tsvector('english', description), 'B')
+        || setweight(to_tsvector('english', body), 'C')
+$$;
+
+CREATE INDEX article_search_idx ON article
+    USING GIN (article_search_vector(title, description, body));
+
+-- migrate:down
+
+DROP INDEX IF EXISTS article_search_idx;
+DROP FUNCTION IF EXISTS article_search_vector(text, text, text);
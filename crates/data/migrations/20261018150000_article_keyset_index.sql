@@ -0,0 +1,6 @@
+-- migrate:up
+-- Serves both offset and cursor pages of newest-first article lists
+CREATE INDEX article_created_at_id_idx ON article (created_at DESC, id DESC);
+
+-- migrate:down
+DROP INDEX IF EXISTS article_created_at_id_idx;
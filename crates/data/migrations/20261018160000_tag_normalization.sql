@@ -0,0 +1,44 @@
+-- migrate:up
+
+-- Tags are now stored NFKC-normalized, lowercase, with whitespace runs as
+-- single hyphens and at most 32 characters. Existing spellings of the same
+-- tag fold into the oldest row. Lowercasing goes through the ICU root
+-- collation so non-ASCII letters fold as they do in the application, whatever
+-- the database locale.
+CREATE TEMPORARY TABLE tag_canonical AS
+SELECT id,
+       canonical,
+       first_value(id) OVER (PARTITION BY canonical ORDER BY created_at, id) AS keep_id
+FROM (
+    SELECT id, created_at,
+           left(regexp_replace(lower(normalize(btrim(name), NFKC) COLLATE "und-x-icu"), '\s+', '-', 'g'), 32) AS canonical
+    FROM tag
+) normalized;
+
+INSERT INTO article_tag (article_id, tag_id, created_at)
+SELECT at.article_id, c.keep_id, at.created_at
+FROM article_tag at
+JOIN tag_canonical c ON c.id = at.tag_id
+WHERE c.id <> c.keep_id
+ON CONFLICT DO NOTHING;
+
+DELETE FROM tag WHERE id IN (SELECT id FROM tag_canonical WHERE id <> keep_id);
+
+UPDATE tag SET name = c.canonical FROM tag_canonical c WHERE tag.id = c.id;
+
+DROP TABLE tag_canonical;
+
+-- other spellings of a tag, e.g. `js` for `javascript`; articles tagged with
+-- an alias are stored under the tag it points to
+CREATE TABLE tag_alias(
+    alias varchar PRIMARY KEY,
+    tag_id uuid NOT NULL,
+    created_at timestamptz NOT NULL DEFAULT (now()),
+    FOREIGN KEY (tag_id) REFERENCES tag(id) ON DELETE CASCADE ON UPDATE CASCADE
+);
+
+CREATE INDEX tag_alias_tag_id_idx ON tag_alias(tag_id);
+
+-- migrate:down
+
+DROP TABLE IF EXISTS tag_alias;
@@ -0,0 +1,17 @@
+-- migrate:up
+
+-- tags a user follows; articles carrying them join the user's feed
+CREATE TABLE tag_follow(
+    appuser_id uuid NOT NULL,
+    tag_id uuid NOT NULL,
+    created_at timestamptz NOT NULL DEFAULT (now()),
+    PRIMARY KEY (appuser_id, tag_id),
+    FOREIGN KEY (appuser_id) REFERENCES appuser(id) ON DELETE CASCADE ON UPDATE CASCADE,
+    FOREIGN KEY (tag_id) REFERENCES tag(id) ON DELETE CASCADE ON UPDATE CASCADE
+);
+
+CREATE INDEX tag_follow_tag_id_idx ON tag_follow(tag_id);
+
+-- migrate:down
+
+DROP TABLE IF EXISTS tag_follow;
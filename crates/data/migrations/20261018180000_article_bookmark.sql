@@ -0,0 +1,19 @@
+-- migrate:up
+
+-- private reading list; never counted or shown to anyone but its owner
+CREATE TABLE article_bookmark(
+    appuser_id uuid NOT NULL,
+    article_id uuid NOT NULL,
+    note text NULL,
+    folder varchar(64) NULL,
+    created_at timestamptz NOT NULL DEFAULT (now()),
+    PRIMARY KEY (appuser_id, article_id),
+    FOREIGN KEY (appuser_id) REFERENCES appuser(id) ON DELETE CASCADE ON UPDATE CASCADE,
+    FOREIGN KEY (article_id) REFERENCES article(id) ON DELETE CASCADE ON UPDATE CASCADE
+);
+
+CREATE INDEX article_bookmark_appuser_id_created_at_idx ON article_bookmark(appuser_id, created_at DESC);
+
+-- migrate:down
+
+DROP TABLE IF EXISTS article_bookmark;
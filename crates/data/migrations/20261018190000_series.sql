@@ -0,0 +1,34 @@
+-- migrate:up
+
+-- ordered groups of articles: one author's multi-part series, or a curated
+-- collection of anyone's published articles
+CREATE TABLE series(
+    id uuid PRIMARY KEY,
+    slug text NOT NULL UNIQUE,
+    title text NOT NULL,
+    description text NOT NULL DEFAULT '',
+    kind text NOT NULL DEFAULT 'series' CHECK (kind IN ('series', 'collection')),
+    owner_id uuid NOT NULL,
+    created_at timestamptz NOT NULL DEFAULT (now()),
+    updated_at timestamptz NOT NULL DEFAULT (now()),
+    FOREIGN KEY (owner_id) REFERENCES appuser(id) ON DELETE CASCADE ON UPDATE CASCADE
+);
+
+CREATE INDEX series_owner_id_idx ON series(owner_id);
+
+CREATE TABLE series_article(
+    series_id uuid NOT NULL,
+    article_id uuid NOT NULL,
+    position int NOT NULL,
+    PRIMARY KEY (series_id, article_id),
+    UNIQUE (series_id, position),
+    FOREIGN KEY (series_id) REFERENCES series(id) ON DELETE CASCADE ON UPDATE CASCADE,
+    FOREIGN KEY (article_id) REFERENCES article(id) ON DELETE CASCADE ON UPDATE CASCADE
+);
+
+CREATE INDEX series_article_article_id_idx ON series_article(article_id);
+
+-- migrate:down
+
+DROP TABLE IF EXISTS series_article;
+DROP TABLE IF EXISTS series;
@@ -0,0 +1,19 @@
+-- migrate:up
+
+-- users invited to write an article with its owner; they co-author it once
+-- accepted_at is set
+CREATE TABLE article_coauthor(
+    article_id uuid NOT NULL,
+    appuser_id uuid NOT NULL,
+    invited_at timestamptz NOT NULL DEFAULT (now()),
+    accepted_at timestamptz,
+    PRIMARY KEY (article_id, appuser_id),
+    FOREIGN KEY (article_id) REFERENCES article(id) ON DELETE CASCADE ON UPDATE CASCADE,
+    FOREIGN KEY (appuser_id) REFERENCES appuser(id) ON DELETE CASCADE ON UPDATE CASCADE
+);
+
+CREATE INDEX article_coauthor_appuser_id_idx ON article_coauthor(appuser_id);
+
+-- migrate:down
+
+DROP TABLE IF EXISTS article_coauthor;
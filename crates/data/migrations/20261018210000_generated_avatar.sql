@@ -0,0 +1,10 @@
+-- migrate:up
+
+-- an empty img means the user has not set one and gets a generated avatar
+ALTER TABLE appuser ALTER COLUMN img SET DEFAULT '';
+
+UPDATE appuser SET img = '' WHERE img = 'https://avatars.githubusercontent.com/u/32737308?v=4';
+
+-- migrate:down
+
+ALTER TABLE appuser ALTER COLUMN img SET DEFAULT 'https://avatars.githubusercontent.com/u/32737308?v=4';
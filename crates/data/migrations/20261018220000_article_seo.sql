@@ -0,0 +1,14 @@
+-- migrate:up
+
+-- optional overrides for search engines and link previews; NULL when unset
+ALTER TABLE article
+    ADD COLUMN canonical_url text,
+    ADD COLUMN meta_description text,
+    ADD COLUMN social_image text;
+
+-- migrate:down
+
+ALTER TABLE article
+    DROP COLUMN IF EXISTS social_image,
+    DROP COLUMN IF EXISTS meta_description,
+    DROP COLUMN IF EXISTS canonical_url;
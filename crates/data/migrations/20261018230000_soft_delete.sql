@@ -0,0 +1,20 @@
+-- migrate:up
+
+-- deleted articles and comments stay in the trash until purged
+ALTER TABLE article ADD COLUMN deleted_at timestamptz;
+ALTER TABLE comment ADD COLUMN deleted_at timestamptz;
+
+-- purge job and trash listings look up deleted rows only
+CREATE INDEX article_deleted_at_idx ON article(deleted_at) WHERE deleted_at IS NOT NULL;
+CREATE INDEX comment_deleted_at_idx ON comment(deleted_at) WHERE deleted_at IS NOT NULL;
+
+-- migrate:down
+
+DELETE FROM comment WHERE deleted_at IS NOT NULL;
+DELETE FROM article WHERE deleted_at IS NOT NULL;
+
+DROP INDEX IF EXISTS comment_deleted_at_idx;
+DROP INDEX IF EXISTS article_deleted_at_idx;
+
+ALTER TABLE comment DROP COLUMN IF EXISTS deleted_at;
+ALTER TABLE article DROP COLUMN IF EXISTS deleted_at;
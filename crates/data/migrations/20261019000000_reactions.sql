@@ -0,0 +1,97 @@
+-- migrate:up
+
+-- one row per user and kind they reacted with
+CREATE TABLE article_reaction(
+    article_id uuid NOT NULL,
+    appuser_id uuid NOT NULL,
+    kind varchar(32) NOT NULL,
+    created_at timestamptz NOT NULL DEFAULT (now()),
+    PRIMARY KEY (article_id, appuser_id, kind),
+    FOREIGN KEY (article_id) REFERENCES article(id) ON DELETE CASCADE ON UPDATE CASCADE,
+    FOREIGN KEY (appuser_id) REFERENCES appuser(id) ON DELETE CASCADE ON UPDATE CASCADE
+);
+
+CREATE INDEX article_reaction_appuser_id_idx ON article_reaction(appuser_id);
+
+CREATE TABLE comment_reaction(
+    comment_id int NOT NULL,
+    appuser_id uuid NOT NULL,
+    kind varchar(32) NOT NULL,
+    created_at timestamptz NOT NULL DEFAULT (now()),
+    PRIMARY KEY (comment_id, appuser_id, kind),
+    FOREIGN KEY (comment_id) REFERENCES comment(id) ON DELETE CASCADE ON UPDATE CASCADE,
+    FOREIGN KEY (appuser_id) REFERENCES appuser(id) ON DELETE CASCADE ON UPDATE CASCADE
+);
+
+CREATE INDEX comment_reaction_appuser_id_idx ON comment_reaction(appuser_id);
+
+-- counts per kind, kept by the triggers below so that reading them never
+-- counts rows; kinds nobody reacted with have no row
+CREATE TABLE article_reaction_count(
+    article_id uuid NOT NULL,
+    kind varchar(32) NOT NULL,
+    count int NOT NULL,
+    PRIMARY KEY (article_id, kind),
+    FOREIGN KEY (article_id) REFERENCES article(id) ON DELETE CASCADE ON UPDATE CASCADE
+);
+
+CREATE TABLE comment_reaction_count(
+    comment_id int NOT NULL,
+    kind varchar(32) NOT NULL,
+    count int NOT NULL,
+    PRIMARY KEY (comment_id, kind),
+    FOREIGN KEY (comment_id) REFERENCES comment(id) ON DELETE CASCADE ON UPDATE CASCADE
+);
+
+CREATE FUNCTION count_article_reaction() RETURNS trigger
+LANGUAGE plpgsql
+AS $$
+BEGIN
+    IF TG_OP = 'INSERT' THEN
+        INSERT INTO article_reaction_count (article_id, kind, count)
+        VALUES (NEW.article_id, NEW.kind, 1)
+        ON CONFLICT (article_id, kind) DO UPDATE SET count = article_reaction_count.count + 1;
+    ELSE
+        UPDATE article_reaction_count SET count = count - 1
+        WHERE article_id = OLD.article_id AND kind = OLD.kind;
+        DELETE FROM article_reaction_count
+        WHERE article_id = OLD.article_id AND kind = OLD.kind AND count <= 0;
+    END IF;
+    RETURN NULL;
+END
+$$;
+
+CREATE FUNCTION count_comment_reaction() RETURNS trigger
+LANGUAGE plpgsql
+AS $$
+BEGIN
+    IF TG_OP = 'INSERT' THEN
+        INSERT INTO comment_reaction_count (comment_id, kind, count)
+        VALUES (NEW.comment_id, NEW.kind, 1)
+        ON CONFLICT (comment_id, kind) DO UPDATE SET count = comment_reaction_count.count + 1;
+    ELSE
+        UPDATE comment_reaction_count SET count = count - 1
+        WHERE comment_id = OLD.comment_id AND kind = OLD.kind;
+        DELETE FROM comment_reaction_count
+        WHERE comment_id = OLD.comment_id AND kind = OLD.kind AND count <= 0;
+    END IF;
+    RETURN NULL;
+END
+$$;
+
+CREATE TRIGGER article_reaction_count_trigger
+    AFTER INSERT OR DELETE ON article_reaction
+    FOR EACH ROW EXECUTE FUNCTION count_article_reaction();
+
+CREATE TRIGGER comment_reaction_count_trigger
+    AFTER INSERT OR DELETE ON comment_reaction
+    FOR EACH ROW EXECUTE FUNCTION count_comment_reaction();
+
+-- migrate:down
+
+DROP TABLE IF EXISTS comment_reaction;
+DROP TABLE IF EXISTS article_reaction;
+DROP FUNCTION IF EXISTS count_comment_reaction();
+DROP FUNCTION IF EXISTS count_article_reaction();
+DROP TABLE IF EXISTS comment_reaction_count;
+DROP TABLE IF EXISTS article_reaction_count;
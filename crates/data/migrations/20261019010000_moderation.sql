@@ -0,0 +1,59 @@
+-- migrate:up
+
+-- hidden content stays visible to its authors only
+ALTER TABLE article ADD COLUMN hidden_at timestamptz;
+ALTER TABLE comment ADD COLUMN hidden_at timestamptz;
+ALTER TABLE appuser ADD COLUMN suspended_at timestamptz;
+
+-- a report targets exactly one article, comment or profile; target_author_id
+-- is whoever wrote the article or comment, or owns the profile
+CREATE TABLE report(
+    id uuid PRIMARY KEY,
+    reporter_id uuid NOT NULL,
+    article_id uuid,
+    comment_id int,
+    profile_id uuid,
+    target_author_id uuid NOT NULL,
+    reason text NOT NULL,
+    details text,
+    status text NOT NULL DEFAULT 'open',
+    moderator_id uuid,
+    created_at timestamptz NOT NULL DEFAULT (now()),
+    updated_at timestamptz NOT NULL DEFAULT (now()),
+    closed_at timestamptz,
+    CHECK (num_nonnulls(article_id, comment_id, profile_id) = 1),
+    FOREIGN KEY (reporter_id) REFERENCES appuser(id) ON DELETE CASCADE ON UPDATE CASCADE,
+    FOREIGN KEY (article_id) REFERENCES article(id) ON DELETE CASCADE ON UPDATE CASCADE,
+    FOREIGN KEY (comment_id) REFERENCES comment(id) ON DELETE CASCADE ON UPDATE CASCADE,
+    FOREIGN KEY (profile_id) REFERENCES appuser(id) ON DELETE CASCADE ON UPDATE CASCADE,
+    FOREIGN KEY (target_author_id) REFERENCES appuser(id) ON DELETE CASCADE ON UPDATE CASCADE,
+    FOREIGN KEY (moderator_id) REFERENCES appuser(id) ON DELETE SET NULL ON UPDATE CASCADE
+);
+
+-- the queue is worked through oldest first, per status
+CREATE INDEX report_status_created_at_idx ON report(status, created_at, id);
+CREATE INDEX report_reporter_id_idx ON report(reporter_id);
+
+-- every moderator action, kept after the moderator's account is gone
+CREATE TABLE moderation_action(
+    id bigint PRIMARY KEY GENERATED ALWAYS AS IDENTITY,
+    report_id uuid NOT NULL,
+    moderator_id uuid,
+    action text NOT NULL,
+    note text,
+    created_at timestamptz NOT NULL DEFAULT (now()),
+    FOREIGN KEY (report_id) REFERENCES report(id) ON DELETE CASCADE ON UPDATE CASCADE,
+    FOREIGN KEY (moderator_id) REFERENCES appuser(id) ON DELETE SET NULL ON UPDATE CASCADE
+);
+
+CREATE INDEX moderation_action_report_id_idx ON moderation_action(report_id, id);
+
+-- migrate:down
+
+DROP TABLE IF EXISTS moderation_action;
+
+DROP TABLE IF EXISTS report;
+
+ALTER TABLE appuser DROP COLUMN IF EXISTS suspended_at;
+ALTER TABLE comment DROP COLUMN IF EXISTS hidden_at;
+ALTER TABLE article DROP COLUMN IF EXISTS hidden_at;
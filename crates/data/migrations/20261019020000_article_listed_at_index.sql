@@ -0,0 +1,9 @@
+-- migrate:up
+-- Article lists run newest first by when each article went live, drafts by
+-- when they were written
+DROP INDEX IF EXISTS article_created_at_id_idx;
+CREATE INDEX article_listed_at_id_idx ON article ((COALESCE(published_at, created_at)) DESC, id DESC);
+
+-- migrate:down
+DROP INDEX IF EXISTS article_listed_at_id_idx;
+CREATE INDEX article_created_at_id_idx ON article (created_at DESC, id DESC);
@@ -0,0 +1,34 @@
+-- migrate:up
+-- An article belongs to one series at most, any number of collections. Each
+-- part carries its series' kind, kept in step by the foreign key, so a
+-- partial unique index can hold that rule.
+ALTER TABLE series ADD CONSTRAINT series_id_kind_key UNIQUE (id, kind);
+
+ALTER TABLE series_article ADD COLUMN kind text;
+UPDATE series_article sa SET kind = s.kind FROM series s WHERE s.id = sa.series_id;
+ALTER TABLE series_article ALTER COLUMN kind SET NOT NULL;
+ALTER TABLE series_article
+    ADD CONSTRAINT series_article_series_id_kind_fkey FOREIGN KEY (series_id, kind)
+    REFERENCES series(id, kind) ON DELETE CASCADE ON UPDATE CASCADE;
+
+-- Keep the oldest series of any article that slipped into several
+DELETE FROM series_article sa
+USING series s
+WHERE s.id = sa.series_id
+  AND sa.kind = 'series'
+  AND EXISTS (
+      SELECT 1
+      FROM series_article other
+      JOIN series os ON os.id = other.series_id
+      WHERE other.article_id = sa.article_id
+        AND other.kind = 'series'
+        AND (os.created_at, os.id) < (s.created_at, s.id)
+  );
+
+CREATE UNIQUE INDEX series_article_one_series_idx ON series_article(article_id) WHERE kind = 'series';
+
+-- migrate:down
+DROP INDEX IF EXISTS series_article_one_series_idx;
+ALTER TABLE series_article DROP CONSTRAINT IF EXISTS series_article_series_id_kind_fkey;
+ALTER TABLE series_article DROP COLUMN IF EXISTS kind;
+ALTER TABLE series DROP CONSTRAINT IF EXISTS series_id_kind_key;
@@ -1,19 +1,55 @@
 --! create_article
-INSERT INTO article (id, slug, title, description, body, author_id, created_at, updated_at)
-VALUES (:id, :slug, :title, :description, :body, :author_id, :created_at, :created_at)
+INSERT INTO article (id, slug, title, description, body, author_id, status, published_at,
+                     canonical_url, meta_description, social_image, created_at, updated_at)
+VALUES (:id, :slug, :title, :description, :body, :author_id, :status, :published_at,
+        :canonical_url, :meta_description, :social_image, :created_at, :created_at)
 RETURNING *;
 
 --! get_article_by_slug
 SELECT a.*,
        (SELECT COUNT(*) FROM article_favorite WHERE article_id = a.id) as favorites_count,
-       ARRAY(SELECT t.name FROM tag t JOIN article_tag at ON t.id = at.tag_id WHERE at.article_id = a.id ORDER BY t.name) as tag_list
-FROM article a WHERE a.slug = :slug;
+       ARRAY(SELECT t.name FROM tag t JOIN article_tag at ON t.id = at.tag_id WHERE at.article_id = a.id ORDER BY t.name) as tag_list,
+       ARRAY(SELECT ac.appuser_id FROM article_coauthor ac
+             WHERE ac.article_id = a.id AND ac.accepted_at IS NOT NULL
+             ORDER BY ac.accepted_at, ac.appuser_id) as coauthor_ids
+FROM article a WHERE a.slug = :slug AND a.deleted_at IS NULL;
 
 --! get_article_by_id
 SELECT a.*,
        (SELECT COUNT(*) FROM article_favorite WHERE article_id = a.id) as favorites_count,
-       ARRAY(SELECT t.name FROM tag t JOIN article_tag at ON t.id = at.tag_id WHERE at.article_id = a.id ORDER BY t.name) as tag_list
-FROM article a WHERE a.id = :id;
+       ARRAY(SELECT t.name FROM tag t JOIN article_tag at ON t.id = at.tag_id WHERE at.article_id = a.id ORDER BY t.name) as tag_list,
+       ARRAY(SELECT ac.appuser_id FROM article_coauthor ac
+             WHERE ac.article_id = a.id AND ac.accepted_at IS NOT NULL
+             ORDER BY ac.accepted_at, ac.appuser_id) as coauthor_ids
+FROM article a WHERE a.id = :id AND a.deleted_at IS NULL;
+
+--! get_articles_by_slugs
+SELECT a.*,
+       (SELECT COUNT(*) FROM article_favorite WHERE article_id = a.id) as favorites_count,
+       ARRAY(SELECT t.name FROM tag t JOIN article_tag at ON t.id = at.tag_id WHERE at.article_id = a.id ORDER BY t.name) as tag_list,
+       ARRAY(SELECT ac.appuser_id FROM article_coauthor ac
+             WHERE ac.article_id = a.id AND ac.accepted_at IS NOT NULL
+             ORDER BY ac.accepted_at, ac.appuser_id) as coauthor_ids
+FROM article a WHERE a.slug = ANY(:slugs) AND a.deleted_at IS NULL;
+
+--! get_article_by_previous_slug
+SELECT a.*,
+       (SELECT COUNT(*) FROM article_favorite WHERE article_id = a.id) as favorites_count,
+       ARRAY(SELECT t.name FROM tag t JOIN article_tag at ON t.id = at.tag_id WHERE at.article_id = a.id ORDER BY t.name) as tag_list,
+       ARRAY(SELECT ac.appuser_id FROM article_coauthor ac
+             WHERE ac.article_id = a.id AND ac.accepted_at IS NOT NULL
+             ORDER BY ac.accepted_at, ac.appuser_id) as coauthor_ids
+FROM article a
+JOIN article_slug_history h ON h.article_id = a.id
+WHERE h.slug = :slug AND a.deleted_at IS NULL;
+
+--! record_previous_slug
+INSERT INTO article_slug_history (slug, article_id)
+VALUES (:slug, :article_id)
+ON CONFLICT (slug) DO UPDATE SET article_id = EXCLUDED.article_id, created_at = now();
+
+--! forget_previous_slug
+DELETE FROM article_slug_history WHERE slug = :slug;
 
 --! update_article
 UPDATE article
@@ -21,16 +57,26 @@ SET slug = COALESCE(:slug, slug),
     title = COALESCE(:title, title),
     description = COALESCE(:description, description),
     body = COALESCE(:body, body),
+    status = :status,
+    published_at = :published_at,
+    canonical_url = :canonical_url,
+    meta_description = :meta_description,
+    social_image = :social_image,
     updated_at = :updated_at
-WHERE id = :id
+WHERE id = :id AND deleted_at IS NULL
 RETURNING *;
 
 --! delete_article
-DELETE FROM article WHERE id = :id;
+-- only moves it to the trash; purge_trashed_articles erases it
+UPDATE article SET deleted_at = :deleted_at WHERE id = :id AND deleted_at IS NULL;
+
+--! hide_article
+-- only its authors still see it
+UPDATE article SET hidden_at = :hidden_at WHERE id = :id;
 
 --! favorite_article
-INSERT INTO article_favorite (appuser_id, article_id)
-VALUES (:user_id, :article_id)
+INSERT INTO article_favorite (appuser_id, article_id, created_at)
+VALUES (:user_id, :article_id, :favorited_at)
 ON CONFLICT DO NOTHING;
 
 --! unfavorite_article
@@ -43,6 +89,18 @@ SELECT EXISTS(
     WHERE appuser_id = :user_id AND article_id = :article_id
 );
 
+--! list_favorited_articles
+SELECT a.*,
+       (SELECT COUNT(*) FROM article_favorite WHERE article_id = a.id) as favorites_count,
+       ARRAY(SELECT t.name FROM tag t JOIN article_tag at ON t.id = at.tag_id WHERE at.article_id = a.id ORDER BY t.name) as tag_list,
+       ARRAY(SELECT ac.appuser_id FROM article_coauthor ac
+             WHERE ac.article_id = a.id AND ac.accepted_at IS NOT NULL
+             ORDER BY ac.accepted_at, ac.appuser_id) as coauthor_ids
+FROM article_favorite f
+JOIN article a ON a.id = f.article_id
+WHERE f.appuser_id = :user_id AND a.deleted_at IS NULL
+ORDER BY f.created_at, a.id;
+
 --! get_favorites_count
 SELECT COUNT(*) as count FROM article_favorite WHERE article_id = :article_id;
 
@@ -68,47 +126,526 @@ JOIN article_tag at ON t.id = at.tag_id
 WHERE at.article_id = :article_id;
 
 --! list_articles
-SELECT a.id, a.slug, a.title, a.description, a.body, a.author_id, a.created_at, a.updated_at,
+-- the sort-specific keys are NULL for every row unless chosen, so newest
+-- first decides ties and the default order. Articles date from when they went
+-- live, or were written for drafts; the keyset index covers the same expression
+SELECT a.id, a.slug, a.title, a.description, a.body, a.author_id, a.created_at, a.updated_at, a.status, a.published_at,
        u.username as author_username, 
        u.bio as author_bio, 
        u.img as author_image,
        EXISTS(SELECT 1 FROM appuser_follows WHERE follower_id = :viewer_id AND followee_id = a.author_id) as following_author,
        EXISTS(SELECT 1 FROM article_favorite WHERE appuser_id = :viewer_id AND article_id = a.id) as favorited,
+       EXISTS(SELECT 1 FROM article_bookmark WHERE appuser_id = :viewer_id AND article_id = a.id) as bookmarked,
        (SELECT COUNT(*) FROM article_favorite WHERE article_id = a.id) as favorites_count,
-       ARRAY(SELECT t.name FROM tag t JOIN article_tag at ON t.id = at.tag_id WHERE at.article_id = a.id ORDER BY t.name) as tag_list
+       ARRAY(SELECT t.name FROM tag t JOIN article_tag at ON t.id = at.tag_id WHERE at.article_id = a.id ORDER BY t.name) as tag_list,
+       ARRAY(SELECT rc.kind FROM article_reaction_count rc WHERE rc.article_id = a.id
+             ORDER BY rc.count DESC, rc.kind COLLATE ucs_basic) as reaction_kinds,
+       ARRAY(SELECT rc.count FROM article_reaction_count rc WHERE rc.article_id = a.id
+             ORDER BY rc.count DESC, rc.kind COLLATE ucs_basic) as reaction_counts,
+       ARRAY(SELECT ar.kind FROM article_reaction ar WHERE ar.article_id = a.id AND ar.appuser_id = :viewer_id) as viewer_reactions
 FROM article a
 JOIN appuser u ON a.author_id = u.id
-WHERE (:author::text IS NULL OR a.author_id = (SELECT id FROM appuser WHERE username = :author))
-  AND (:tag::text IS NULL OR EXISTS(SELECT 1 FROM article_tag at JOIN tag t ON at.tag_id = t.id WHERE at.article_id = a.id AND t.name = :tag))
+WHERE u.deactivated_at IS NULL
+  AND a.deleted_at IS NULL
+  AND ((a.status = 'published' AND a.hidden_at IS NULL) OR a.author_id = :viewer_id)
+  AND NOT EXISTS(SELECT 1 FROM appuser_mutes WHERE muter_id = :viewer_id AND muted_id = a.author_id)
+  AND NOT EXISTS(SELECT 1 FROM appuser_blocks WHERE blocker_id = a.author_id AND blocked_id = :viewer_id)
+  AND (cardinality(:authors::text[]) = 0 OR u.username = ANY(:authors))
+  AND (cardinality(:tags::text[]) = 0 OR (
+        SELECT COUNT(DISTINCT t.name) FROM article_tag at JOIN tag t ON at.tag_id = t.id
+        WHERE at.article_id = a.id AND t.name = ANY(:tags)
+      ) >= CASE WHEN :match_all_tags THEN cardinality(:tags) ELSE 1 END)
+  AND NOT EXISTS(SELECT 1 FROM article_tag at JOIN tag t ON at.tag_id = t.id WHERE at.article_id = a.id AND t.name = ANY(:excluded_tags::text[]))
   AND (:favorited::text IS NULL OR EXISTS(SELECT 1 FROM article_favorite af JOIN appuser u2 ON af.appuser_id = u2.id WHERE af.article_id = a.id AND u2.username = :favorited))
-ORDER BY a.created_at DESC
+  AND (:since::timestamptz IS NULL OR a.created_at >= :since)
+  AND (:until::timestamptz IS NULL OR a.created_at < :until)
+ORDER BY CASE WHEN :sort = 'updated' THEN a.updated_at END DESC,
+         CASE WHEN :sort = 'popular' THEN (SELECT COUNT(*) FROM article_favorite WHERE article_id = a.id) END DESC,
+         CASE WHEN :sort = 'trending' THEN (
+             SELECT COUNT(*) FROM article_favorite
+             WHERE article_id = a.id AND (:trending_since::timestamptz IS NULL OR created_at >= :trending_since)
+         ) END DESC,
+         COALESCE(a.published_at, a.created_at) DESC, a.id DESC
 LIMIT :limit OFFSET :offset;
 
+--! list_articles_older
+-- keyset page after the cursor; :limit is one more than the page size
+SELECT a.id, a.slug, a.title, a.description, a.body, a.author_id, a.created_at, a.updated_at, a.status, a.published_at,
+       u.username as author_username, 
+       u.bio as author_bio, 
+       u.img as author_image,
+       EXISTS(SELECT 1 FROM appuser_follows WHERE follower_id = :viewer_id AND followee_id = a.author_id) as following_author,
+       EXISTS(SELECT 1 FROM article_favorite WHERE appuser_id = :viewer_id AND article_id = a.id) as favorited,
+       EXISTS(SELECT 1 FROM article_bookmark WHERE appuser_id = :viewer_id AND article_id = a.id) as bookmarked,
+       (SELECT COUNT(*) FROM article_favorite WHERE article_id = a.id) as favorites_count,
+       ARRAY(SELECT t.name FROM tag t JOIN article_tag at ON t.id = at.tag_id WHERE at.article_id = a.id ORDER BY t.name) as tag_list,
+       ARRAY(SELECT rc.kind FROM article_reaction_count rc WHERE rc.article_id = a.id
+             ORDER BY rc.count DESC, rc.kind COLLATE ucs_basic) as reaction_kinds,
+       ARRAY(SELECT rc.count FROM article_reaction_count rc WHERE rc.article_id = a.id
+             ORDER BY rc.count DESC, rc.kind COLLATE ucs_basic) as reaction_counts,
+       ARRAY(SELECT ar.kind FROM article_reaction ar WHERE ar.article_id = a.id AND ar.appuser_id = :viewer_id) as viewer_reactions
+FROM article a
+JOIN appuser u ON a.author_id = u.id
+WHERE u.deactivated_at IS NULL
+  AND a.deleted_at IS NULL
+  AND ((a.status = 'published' AND a.hidden_at IS NULL) OR a.author_id = :viewer_id)
+  AND NOT EXISTS(SELECT 1 FROM appuser_mutes WHERE muter_id = :viewer_id AND muted_id = a.author_id)
+  AND NOT EXISTS(SELECT 1 FROM appuser_blocks WHERE blocker_id = a.author_id AND blocked_id = :viewer_id)
+  AND (cardinality(:authors::text[]) = 0 OR u.username = ANY(:authors))
+  AND (cardinality(:tags::text[]) = 0 OR (
+        SELECT COUNT(DISTINCT t.name) FROM article_tag at JOIN tag t ON at.tag_id = t.id
+        WHERE at.article_id = a.id AND t.name = ANY(:tags)
+      ) >= CASE WHEN :match_all_tags THEN cardinality(:tags) ELSE 1 END)
+  AND NOT EXISTS(SELECT 1 FROM article_tag at JOIN tag t ON at.tag_id = t.id WHERE at.article_id = a.id AND t.name = ANY(:excluded_tags::text[]))
+  AND (:favorited::text IS NULL OR EXISTS(SELECT 1 FROM article_favorite af JOIN appuser u2 ON af.appuser_id = u2.id WHERE af.article_id = a.id AND u2.username = :favorited))
+  AND (:since::timestamptz IS NULL OR a.created_at >= :since)
+  AND (:until::timestamptz IS NULL OR a.created_at < :until)
+  AND (COALESCE(a.published_at, a.created_at), a.id) < (:cursor_listed_at, :cursor_id)
+ORDER BY COALESCE(a.published_at, a.created_at) DESC, a.id DESC
+LIMIT :limit;
+
+--! list_articles_newer
+-- keyset page before the cursor, nearest first
+SELECT a.id, a.slug, a.title, a.description, a.body, a.author_id, a.created_at, a.updated_at, a.status, a.published_at,
+       u.username as author_username, 
+       u.bio as author_bio, 
+       u.img as author_image,
+       EXISTS(SELECT 1 FROM appuser_follows WHERE follower_id = :viewer_id AND followee_id = a.author_id) as following_author,
+       EXISTS(SELECT 1 FROM article_favorite WHERE appuser_id = :viewer_id AND article_id = a.id) as favorited,
+       EXISTS(SELECT 1 FROM article_bookmark WHERE appuser_id = :viewer_id AND article_id = a.id) as bookmarked,
+       (SELECT COUNT(*) FROM article_favorite WHERE article_id = a.id) as favorites_count,
+       ARRAY(SELECT t.name FROM tag t JOIN article_tag at ON t.id = at.tag_id WHERE at.article_id = a.id ORDER BY t.name) as tag_list,
+       ARRAY(SELECT rc.kind FROM article_reaction_count rc WHERE rc.article_id = a.id
+             ORDER BY rc.count DESC, rc.kind COLLATE ucs_basic) as reaction_kinds,
+       ARRAY(SELECT rc.count FROM article_reaction_count rc WHERE rc.article_id = a.id
+             ORDER BY rc.count DESC, rc.kind COLLATE ucs_basic) as reaction_counts,
+       ARRAY(SELECT ar.kind FROM article_reaction ar WHERE ar.article_id = a.id AND ar.appuser_id = :viewer_id) as viewer_reactions
+FROM article a
+JOIN appuser u ON a.author_id = u.id
+WHERE u.deactivated_at IS NULL
+  AND a.deleted_at IS NULL
+  AND ((a.status = 'published' AND a.hidden_at IS NULL) OR a.author_id = :viewer_id)
+  AND NOT EXISTS(SELECT 1 FROM appuser_mutes WHERE muter_id = :viewer_id AND muted_id = a.author_id)
+  AND NOT EXISTS(SELECT 1 FROM appuser_blocks WHERE blocker_id = a.author_id AND blocked_id = :viewer_id)
+  AND (cardinality(:authors::text[]) = 0 OR u.username = ANY(:authors))
+  AND (cardinality(:tags::text[]) = 0 OR (
+        SELECT COUNT(DISTINCT t.name) FROM article_tag at JOIN tag t ON at.tag_id = t.id
+        WHERE at.article_id = a.id AND t.name = ANY(:tags)
+      ) >= CASE WHEN :match_all_tags THEN cardinality(:tags) ELSE 1 END)
+  AND NOT EXISTS(SELECT 1 FROM article_tag at JOIN tag t ON at.tag_id = t.id WHERE at.article_id = a.id AND t.name = ANY(:excluded_tags::text[]))
+  AND (:favorited::text IS NULL OR EXISTS(SELECT 1 FROM article_favorite af JOIN appuser u2 ON af.appuser_id = u2.id WHERE af.article_id = a.id AND u2.username = :favorited))
+  AND (:since::timestamptz IS NULL OR a.created_at >= :since)
+  AND (:until::timestamptz IS NULL OR a.created_at < :until)
+  AND (COALESCE(a.published_at, a.created_at), a.id) > (:cursor_listed_at, :cursor_id)
+ORDER BY COALESCE(a.published_at, a.created_at) ASC, a.id ASC
+LIMIT :limit;
+
 --! count_articles
 SELECT COUNT(*)
 FROM article a
-WHERE (:author::text IS NULL OR a.author_id = (SELECT id FROM appuser WHERE username = :author))
-  AND (:tag::text IS NULL OR EXISTS(SELECT 1 FROM article_tag at JOIN tag t ON at.tag_id = t.id WHERE at.article_id = a.id AND t.name = :tag))
-  AND (:favorited::text IS NULL OR EXISTS(SELECT 1 FROM article_favorite af JOIN appuser u2 ON af.appuser_id = u2.id WHERE af.article_id = a.id AND u2.username = :favorited));
+JOIN appuser u ON a.author_id = u.id
+WHERE u.deactivated_at IS NULL
+  AND a.deleted_at IS NULL
+  AND ((a.status = 'published' AND a.hidden_at IS NULL) OR a.author_id = :viewer_id)
+  AND NOT EXISTS(SELECT 1 FROM appuser_mutes WHERE muter_id = :viewer_id AND muted_id = a.author_id)
+  AND NOT EXISTS(SELECT 1 FROM appuser_blocks WHERE blocker_id = a.author_id AND blocked_id = :viewer_id)
+  AND (cardinality(:authors::text[]) = 0 OR u.username = ANY(:authors))
+  AND (cardinality(:tags::text[]) = 0 OR (
+        SELECT COUNT(DISTINCT t.name) FROM article_tag at JOIN tag t ON at.tag_id = t.id
+        WHERE at.article_id = a.id AND t.name = ANY(:tags)
+      ) >= CASE WHEN :match_all_tags THEN cardinality(:tags) ELSE 1 END)
+  AND NOT EXISTS(SELECT 1 FROM article_tag at JOIN tag t ON at.tag_id = t.id WHERE at.article_id = a.id AND t.name = ANY(:excluded_tags::text[]))
+  AND (:favorited::text IS NULL OR EXISTS(SELECT 1 FROM article_favorite af JOIN appuser u2 ON af.appuser_id = u2.id WHERE af.article_id = a.id AND u2.username = :favorited))
+  AND (:since::timestamptz IS NULL OR a.created_at >= :since)
+  AND (:until::timestamptz IS NULL OR a.created_at < :until);
 
 --! feed_articles
-SELECT a.id, a.slug, a.title, a.description, a.body, a.author_id, a.created_at, a.updated_at,
+SELECT a.id, a.slug, a.title, a.description, a.body, a.author_id, a.created_at, a.updated_at, a.status, a.published_at,
        u.username as author_username, 
        u.bio as author_bio, 
        u.img as author_image,
-       true as following_author,
+       EXISTS(SELECT 1 FROM appuser_follows WHERE follower_id = :viewer_id AND followee_id = a.author_id) as following_author,
        EXISTS(SELECT 1 FROM article_favorite WHERE appuser_id = :viewer_id AND article_id = a.id) as favorited,
+       EXISTS(SELECT 1 FROM article_bookmark WHERE appuser_id = :viewer_id AND article_id = a.id) as bookmarked,
        (SELECT COUNT(*) FROM article_favorite WHERE article_id = a.id) as favorites_count,
-       ARRAY(SELECT t.name FROM tag t JOIN article_tag at ON t.id = at.tag_id WHERE at.article_id = a.id ORDER BY t.name) as tag_list
+       ARRAY(SELECT t.name FROM tag t JOIN article_tag at ON t.id = at.tag_id WHERE at.article_id = a.id ORDER BY t.name) as tag_list,
+       ARRAY(SELECT rc.kind FROM article_reaction_count rc WHERE rc.article_id = a.id
+             ORDER BY rc.count DESC, rc.kind COLLATE ucs_basic) as reaction_kinds,
+       ARRAY(SELECT rc.count FROM article_reaction_count rc WHERE rc.article_id = a.id
+             ORDER BY rc.count DESC, rc.kind COLLATE ucs_basic) as reaction_counts,
+       ARRAY(SELECT ar.kind FROM article_reaction ar WHERE ar.article_id = a.id AND ar.appuser_id = :viewer_id) as viewer_reactions,
+       ARRAY(SELECT t.name FROM tag t JOIN article_tag at ON t.id = at.tag_id
+             JOIN tag_follow tf ON tf.tag_id = at.tag_id AND tf.appuser_id = :viewer_id
+             WHERE at.article_id = a.id ORDER BY t.name) as followed_tags,
+       EXISTS(SELECT 1 FROM article_coauthor ac
+              JOIN appuser_follows f ON f.followee_id = ac.appuser_id AND f.follower_id = :viewer_id
+              WHERE ac.article_id = a.id AND ac.accepted_at IS NOT NULL) as following_coauthor
 FROM article a
 JOIN appuser u ON a.author_id = u.id
-JOIN appuser_follows af ON af.followee_id = a.author_id
-WHERE af.follower_id = :viewer_id
-ORDER BY a.created_at DESC
+WHERE (EXISTS(SELECT 1 FROM appuser_follows WHERE follower_id = :viewer_id AND followee_id = a.author_id)
+       OR EXISTS(SELECT 1 FROM article_coauthor ac
+                 JOIN appuser_follows f ON f.followee_id = ac.appuser_id AND f.follower_id = :viewer_id
+                 WHERE ac.article_id = a.id AND ac.accepted_at IS NOT NULL)
+       OR EXISTS(SELECT 1 FROM article_tag at JOIN tag_follow tf ON tf.tag_id = at.tag_id
+                 WHERE at.article_id = a.id AND tf.appuser_id = :viewer_id))
+  AND a.status = 'published'
+  AND a.hidden_at IS NULL
+  AND u.deactivated_at IS NULL
+  AND a.deleted_at IS NULL
+  AND NOT EXISTS(SELECT 1 FROM appuser_mutes WHERE muter_id = :viewer_id AND muted_id = a.author_id)
+  AND NOT EXISTS(SELECT 1 FROM appuser_blocks WHERE blocker_id = a.author_id AND blocked_id = :viewer_id)
+ORDER BY COALESCE(a.published_at, a.created_at) DESC, a.id DESC
 LIMIT :limit OFFSET :offset;
 
+--! feed_articles_older
+SELECT a.id, a.slug, a.title, a.description, a.body, a.author_id, a.created_at, a.updated_at, a.status, a.published_at,
+       u.username as author_username, 
+       u.bio as author_bio, 
+       u.img as author_image,
+       EXISTS(SELECT 1 FROM appuser_follows WHERE follower_id = :viewer_id AND followee_id = a.author_id) as following_author,
+       EXISTS(SELECT 1 FROM article_favorite WHERE appuser_id = :viewer_id AND article_id = a.id) as favorited,
+       EXISTS(SELECT 1 FROM article_bookmark WHERE appuser_id = :viewer_id AND article_id = a.id) as bookmarked,
+       (SELECT COUNT(*) FROM article_favorite WHERE article_id = a.id) as favorites_count,
+       ARRAY(SELECT t.name FROM tag t JOIN article_tag at ON t.id = at.tag_id WHERE at.article_id = a.id ORDER BY t.name) as tag_list,
+       ARRAY(SELECT rc.kind FROM article_reaction_count rc WHERE rc.article_id = a.id
+             ORDER BY rc.count DESC, rc.kind COLLATE ucs_basic) as reaction_kinds,
+       ARRAY(SELECT rc.count FROM article_reaction_count rc WHERE rc.article_id = a.id
+             ORDER BY rc.count DESC, rc.kind COLLATE ucs_basic) as reaction_counts,
+       ARRAY(SELECT ar.kind FROM article_reaction ar WHERE ar.article_id = a.id AND ar.appuser_id = :viewer_id) as viewer_reactions,
+       ARRAY(SELECT t.name FROM tag t JOIN article_tag at ON t.id = at.tag_id
+             JOIN tag_follow tf ON tf.tag_id = at.tag_id AND tf.appuser_id = :viewer_id
+             WHERE at.article_id = a.id ORDER BY t.name) as followed_tags,
+       EXISTS(SELECT 1 FROM article_coauthor ac
+              JOIN appuser_follows f ON f.followee_id = ac.appuser_id AND f.follower_id = :viewer_id
+              WHERE ac.article_id = a.id AND ac.accepted_at IS NOT NULL) as following_coauthor
+FROM article a
+JOIN appuser u ON a.author_id = u.id
+WHERE (EXISTS(SELECT 1 FROM appuser_follows WHERE follower_id = :viewer_id AND followee_id = a.author_id)
+       OR EXISTS(SELECT 1 FROM article_coauthor ac
+                 JOIN appuser_follows f ON f.followee_id = ac.appuser_id AND f.follower_id = :viewer_id
+                 WHERE ac.article_id = a.id AND ac.accepted_at IS NOT NULL)
+       OR EXISTS(SELECT 1 FROM article_tag at JOIN tag_follow tf ON tf.tag_id = at.tag_id
+                 WHERE at.article_id = a.id AND tf.appuser_id = :viewer_id))
+  AND a.status = 'published'
+  AND a.hidden_at IS NULL
+  AND u.deactivated_at IS NULL
+  AND a.deleted_at IS NULL
+  AND NOT EXISTS(SELECT 1 FROM appuser_mutes WHERE muter_id = :viewer_id AND muted_id = a.author_id)
+  AND NOT EXISTS(SELECT 1 FROM appuser_blocks WHERE blocker_id = a.author_id AND blocked_id = :viewer_id)
+  AND (COALESCE(a.published_at, a.created_at), a.id) < (:cursor_listed_at, :cursor_id)
+ORDER BY COALESCE(a.published_at, a.created_at) DESC, a.id DESC
+LIMIT :limit;
+
+--! feed_articles_newer
+SELECT a.id, a.slug, a.title, a.description, a.body, a.author_id, a.created_at, a.updated_at, a.status, a.published_at,
+       u.username as author_username, 
+       u.bio as author_bio, 
+       u.img as author_image,
+       EXISTS(SELECT 1 FROM appuser_follows WHERE follower_id = :viewer_id AND followee_id = a.author_id) as following_author,
+       EXISTS(SELECT 1 FROM article_favorite WHERE appuser_id = :viewer_id AND article_id = a.id) as favorited,
+       EXISTS(SELECT 1 FROM article_bookmark WHERE appuser_id = :viewer_id AND article_id = a.id) as bookmarked,
+       (SELECT COUNT(*) FROM article_favorite WHERE article_id = a.id) as favorites_count,
+       ARRAY(SELECT t.name FROM tag t JOIN article_tag at ON t.id = at.tag_id WHERE at.article_id = a.id ORDER BY t.name) as tag_list,
+       ARRAY(SELECT rc.kind FROM article_reaction_count rc WHERE rc.article_id = a.id
+             ORDER BY rc.count DESC, rc.kind COLLATE ucs_basic) as reaction_kinds,
+       ARRAY(SELECT rc.count FROM article_reaction_count rc WHERE rc.article_id = a.id
+             ORDER BY rc.count DESC, rc.kind COLLATE ucs_basic) as reaction_counts,
+       ARRAY(SELECT ar.kind FROM article_reaction ar WHERE ar.article_id = a.id AND ar.appuser_id = :viewer_id) as viewer_reactions,
+       ARRAY(SELECT t.name FROM tag t JOIN article_tag at ON t.id = at.tag_id
+             JOIN tag_follow tf ON tf.tag_id = at.tag_id AND tf.appuser_id = :viewer_id
+             WHERE at.article_id = a.id ORDER BY t.name) as followed_tags,
+       EXISTS(SELECT 1 FROM article_coauthor ac
+              JOIN appuser_follows f ON f.followee_id = ac.appuser_id AND f.follower_id = :viewer_id
+              WHERE ac.article_id = a.id AND ac.accepted_at IS NOT NULL) as following_coauthor
+FROM article a
+JOIN appuser u ON a.author_id = u.id
+WHERE (EXISTS(SELECT 1 FROM appuser_follows WHERE follower_id = :viewer_id AND followee_id = a.author_id)
+       OR EXISTS(SELECT 1 FROM article_coauthor ac
+                 JOIN appuser_follows f ON f.followee_id = ac.appuser_id AND f.follower_id = :viewer_id
+                 WHERE ac.article_id = a.id AND ac.accepted_at IS NOT NULL)
+       OR EXISTS(SELECT 1 FROM article_tag at JOIN tag_follow tf ON tf.tag_id = at.tag_id
+                 WHERE at.article_id = a.id AND tf.appuser_id = :viewer_id))
+  AND a.status = 'published'
+  AND a.hidden_at IS NULL
+  AND u.deactivated_at IS NULL
+  AND a.deleted_at IS NULL
+  AND NOT EXISTS(SELECT 1 FROM appuser_mutes WHERE muter_id = :viewer_id AND muted_id = a.author_id)
+  AND NOT EXISTS(SELECT 1 FROM appuser_blocks WHERE blocker_id = a.author_id AND blocked_id = :viewer_id)
+  AND (COALESCE(a.published_at, a.created_at), a.id) > (:cursor_listed_at, :cursor_id)
+ORDER BY COALESCE(a.published_at, a.created_at) ASC, a.id ASC
+LIMIT :limit;
+
 --! count_feed_articles
 SELECT COUNT(*)
 FROM article a
-JOIN appuser_follows af ON af.followee_id = a.author_id
-WHERE af.follower_id = :viewer_id;
+JOIN appuser u ON a.author_id = u.id
+WHERE (EXISTS(SELECT 1 FROM appuser_follows WHERE follower_id = :viewer_id AND followee_id = a.author_id)
+       OR EXISTS(SELECT 1 FROM article_coauthor ac
+                 JOIN appuser_follows f ON f.followee_id = ac.appuser_id AND f.follower_id = :viewer_id
+                 WHERE ac.article_id = a.id AND ac.accepted_at IS NOT NULL)
+       OR EXISTS(SELECT 1 FROM article_tag at JOIN tag_follow tf ON tf.tag_id = at.tag_id
+                 WHERE at.article_id = a.id AND tf.appuser_id = :viewer_id))
+  AND a.status = 'published'
+  AND a.hidden_at IS NULL
+  AND u.deactivated_at IS NULL
+  AND a.deleted_at IS NULL
+  AND NOT EXISTS(SELECT 1 FROM appuser_mutes WHERE muter_id = :viewer_id AND muted_id = a.author_id)
+  AND NOT EXISTS(SELECT 1 FROM appuser_blocks WHERE blocker_id = a.author_id AND blocked_id = :viewer_id);
+
+--! delete_articles_by_author
+DELETE FROM article WHERE author_id = :author_id;
+
+--! reassign_articles
+UPDATE article SET author_id = :to_author_id WHERE author_id = :from_author_id;
+
+--! remove_favorites_by_user
+DELETE FROM article_favorite WHERE appuser_id = :user_id;
+
+--! list_articles_by_author
+SELECT a.*,
+       (SELECT COUNT(*) FROM article_favorite WHERE article_id = a.id) as favorites_count,
+       ARRAY(SELECT t.name FROM tag t JOIN article_tag at ON t.id = at.tag_id WHERE at.article_id = a.id ORDER BY t.name) as tag_list,
+       ARRAY(SELECT ac.appuser_id FROM article_coauthor ac
+             WHERE ac.article_id = a.id AND ac.accepted_at IS NOT NULL
+             ORDER BY ac.accepted_at, ac.appuser_id) as coauthor_ids
+FROM article a
+WHERE a.author_id = :author_id
+ORDER BY a.created_at, a.id;
+
+--! list_drafts
+SELECT a.id, a.slug, a.title, a.description, a.body, a.author_id, a.created_at, a.updated_at, a.status, a.published_at,
+       u.username as author_username,
+       u.bio as author_bio,
+       u.img as author_image,
+       false as following_author,
+       EXISTS(SELECT 1 FROM article_favorite WHERE appuser_id = :author_id AND article_id = a.id) as favorited,
+       EXISTS(SELECT 1 FROM article_bookmark WHERE appuser_id = :author_id AND article_id = a.id) as bookmarked,
+       (SELECT COUNT(*) FROM article_favorite WHERE article_id = a.id) as favorites_count,
+       ARRAY(SELECT t.name FROM tag t JOIN article_tag at ON t.id = at.tag_id WHERE at.article_id = a.id ORDER BY t.name) as tag_list,
+       ARRAY(SELECT rc.kind FROM article_reaction_count rc WHERE rc.article_id = a.id
+             ORDER BY rc.count DESC, rc.kind COLLATE ucs_basic) as reaction_kinds,
+       ARRAY(SELECT rc.count FROM article_reaction_count rc WHERE rc.article_id = a.id
+             ORDER BY rc.count DESC, rc.kind COLLATE ucs_basic) as reaction_counts,
+       ARRAY(SELECT ar.kind FROM article_reaction ar WHERE ar.article_id = a.id AND ar.appuser_id = :author_id) as viewer_reactions
+FROM article a
+JOIN appuser u ON a.author_id = u.id
+WHERE a.author_id = :author_id
+  AND a.status <> 'published'
+  AND a.deleted_at IS NULL
+ORDER BY a.updated_at DESC
+LIMIT :limit OFFSET :offset;
+
+--! count_drafts
+SELECT COUNT(*) FROM article WHERE author_id = :author_id AND status <> 'published' AND deleted_at IS NULL;
+
+--! publish_due_articles
+WITH due AS (
+    UPDATE article SET status = 'published', updated_at = :now
+    WHERE status = 'scheduled' AND published_at <= :now AND deleted_at IS NULL
+    RETURNING *
+)
+SELECT d.*,
+       (SELECT COUNT(*) FROM article_favorite WHERE article_id = d.id) as favorites_count,
+       ARRAY(SELECT t.name FROM tag t JOIN article_tag at ON t.id = at.tag_id WHERE at.article_id = d.id ORDER BY t.name) as tag_list,
+       ARRAY(SELECT ac.appuser_id FROM article_coauthor ac
+             WHERE ac.article_id = d.id AND ac.accepted_at IS NOT NULL
+             ORDER BY ac.accepted_at, ac.appuser_id) as coauthor_ids
+FROM due d;
+
+--! search_articles
+-- the headline is only built for the page being returned, from the escaped body so the
+-- only markup in it is the marks
+SELECT r.id, r.slug, r.title, r.description, r.body, r.author_id, r.created_at, r.updated_at, r.status, r.published_at,
+       r.author_username, r.author_bio, r.author_image, r.following_author, r.favorited, r.bookmarked, r.favorites_count, r.tag_list,
+       ARRAY(SELECT rc.kind FROM article_reaction_count rc WHERE rc.article_id = r.id
+             ORDER BY rc.count DESC, rc.kind COLLATE ucs_basic) as reaction_kinds,
+       ARRAY(SELECT rc.count FROM article_reaction_count rc WHERE rc.article_id = r.id
+             ORDER BY rc.count DESC, rc.kind COLLATE ucs_basic) as reaction_counts,
+       ARRAY(SELECT ar.kind FROM article_reaction ar WHERE ar.article_id = r.id AND ar.appuser_id = :viewer_id) as viewer_reactions,
+       r.rank,
+       ts_headline('english',
+                   replace(replace(replace(replace(replace(r.body, '&', '&amp;'), '<', '&lt;'), '>', '&gt;'),
+                                   '"', '&quot;'), '''', '&#39;'),
+                   q.query, 'StartSel=<mark>, StopSel=</mark>, MaxWords=35, MinWords=15') as highlight
+FROM (
+    SELECT a.id, a.slug, a.title, a.description, a.body, a.author_id, a.created_at, a.updated_at, a.status, a.published_at,
+           u.username as author_username,
+           u.bio as author_bio,
+           u.img as author_image,
+           EXISTS(SELECT 1 FROM appuser_follows WHERE follower_id = :viewer_id AND followee_id = a.author_id) as following_author,
+           EXISTS(SELECT 1 FROM article_favorite WHERE appuser_id = :viewer_id AND article_id = a.id) as favorited,
+           EXISTS(SELECT 1 FROM article_bookmark WHERE appuser_id = :viewer_id AND article_id = a.id) as bookmarked,
+           (SELECT COUNT(*) FROM article_favorite WHERE article_id = a.id) as favorites_count,
+           ARRAY(SELECT t.name FROM tag t JOIN article_tag at ON t.id = at.tag_id WHERE at.article_id = a.id ORDER BY t.name) as tag_list,
+           ts_rank_cd(article_search_vector(a.title, a.description, a.body), q.query)::float8 as rank
+    FROM article a
+    JOIN appuser u ON a.author_id = u.id
+    CROSS JOIN to_tsquery('english', :query) q(query)
+    WHERE u.deactivated_at IS NULL
+      AND a.deleted_at IS NULL
+      AND article_search_vector(a.title, a.description, a.body) @@ q.query
+      AND ((a.status = 'published' AND a.hidden_at IS NULL) OR a.author_id = :viewer_id)
+      AND NOT EXISTS(SELECT 1 FROM appuser_mutes WHERE muter_id = :viewer_id AND muted_id = a.author_id)
+      AND NOT EXISTS(SELECT 1 FROM appuser_blocks WHERE blocker_id = a.author_id AND blocked_id = :viewer_id)
+    ORDER BY rank DESC, a.created_at DESC
+    LIMIT :limit OFFSET :offset
+) r
+CROSS JOIN to_tsquery('english', :query) q(query)
+ORDER BY r.rank DESC, r.created_at DESC;
+
+--! count_search_articles
+SELECT COUNT(*)
+FROM article a
+JOIN appuser u ON a.author_id = u.id
+WHERE u.deactivated_at IS NULL
+  AND a.deleted_at IS NULL
+  AND article_search_vector(a.title, a.description, a.body) @@ to_tsquery('english', :query)
+  AND ((a.status = 'published' AND a.hidden_at IS NULL) OR a.author_id = :viewer_id)
+  AND NOT EXISTS(SELECT 1 FROM appuser_mutes WHERE muter_id = :viewer_id AND muted_id = a.author_id)
+  AND NOT EXISTS(SELECT 1 FROM appuser_blocks WHERE blocker_id = a.author_id AND blocked_id = :viewer_id);
+
+--! related_articles
+-- scores follow domain::RelatedScoring, whose weights are passed in: shared
+-- tags weighted by rarity, same owner, shared fans and recency
+WITH source AS (
+    SELECT id, author_id FROM article WHERE id = :article_id AND deleted_at IS NULL
+),
+tag_rarity AS (
+    SELECT at.tag_id, 1.0::float8 / ln(1.0 + COUNT(*)::float8) as rarity
+    FROM article_tag at
+    JOIN article a ON a.id = at.article_id
+    WHERE a.status = 'published' AND a.deleted_at IS NULL AND a.hidden_at IS NULL
+      AND at.tag_id IN (SELECT tag_id FROM article_tag WHERE article_id = :article_id)
+    GROUP BY at.tag_id
+),
+shared_tags AS (
+    SELECT at.article_id, SUM(tr.rarity) as rarity
+    FROM article_tag at
+    JOIN tag_rarity tr ON tr.tag_id = at.tag_id
+    GROUP BY at.article_id
+),
+shared_fans AS (
+    SELECT theirs.article_id, COUNT(*) as fans
+    FROM article_favorite mine
+    JOIN article_favorite theirs ON theirs.appuser_id = mine.appuser_id
+    WHERE mine.article_id = :article_id
+    GROUP BY theirs.article_id
+),
+scored AS (
+    SELECT a.id,
+           :tag_weight::float8 * COALESCE(st.rarity, 0)
+           + CASE WHEN a.author_id = s.author_id THEN :author_weight::float8 ELSE 0 END
+           + :cofavorite_weight::float8 * ln(1.0 + COALESCE(sf.fans, 0)::float8)
+           + :recency_weight::float8 / (1.0 + GREATEST(EXTRACT(EPOCH FROM (:now::timestamptz - a.created_at))::float8 / 86400.0, 0) / :recency_days::float8)
+           as score
+    FROM article a
+    CROSS JOIN source s
+    LEFT JOIN shared_tags st ON st.article_id = a.id
+    LEFT JOIN shared_fans sf ON sf.article_id = a.id
+    WHERE a.id <> s.id
+      AND a.status = 'published'
+      AND a.hidden_at IS NULL
+      AND a.deleted_at IS NULL
+      AND (st.rarity IS NOT NULL OR a.author_id = s.author_id OR sf.fans IS NOT NULL)
+)
+SELECT a.id, a.slug, a.title, a.description, a.body, a.author_id, a.created_at, a.updated_at, a.status, a.published_at,
+       u.username as author_username,
+       u.bio as author_bio,
+       u.img as author_image,
+       EXISTS(SELECT 1 FROM appuser_follows WHERE follower_id = :viewer_id AND followee_id = a.author_id) as following_author,
+       EXISTS(SELECT 1 FROM article_favorite WHERE appuser_id = :viewer_id AND article_id = a.id) as favorited,
+       EXISTS(SELECT 1 FROM article_bookmark WHERE appuser_id = :viewer_id AND article_id = a.id) as bookmarked,
+       (SELECT COUNT(*) FROM article_favorite WHERE article_id = a.id) as favorites_count,
+       ARRAY(SELECT t.name FROM tag t JOIN article_tag at ON t.id = at.tag_id WHERE at.article_id = a.id ORDER BY t.name) as tag_list,
+       ARRAY(SELECT rc.kind FROM article_reaction_count rc WHERE rc.article_id = a.id
+             ORDER BY rc.count DESC, rc.kind COLLATE ucs_basic) as reaction_kinds,
+       ARRAY(SELECT rc.count FROM article_reaction_count rc WHERE rc.article_id = a.id
+             ORDER BY rc.count DESC, rc.kind COLLATE ucs_basic) as reaction_counts,
+       ARRAY(SELECT ar.kind FROM article_reaction ar WHERE ar.article_id = a.id AND ar.appuser_id = :viewer_id) as viewer_reactions
+FROM scored sc
+JOIN article a ON a.id = sc.id
+JOIN appuser u ON a.author_id = u.id
+WHERE u.deactivated_at IS NULL
+  AND a.author_id <> :viewer_id
+  AND NOT EXISTS(SELECT 1 FROM article_coauthor WHERE article_id = a.id AND appuser_id = :viewer_id AND accepted_at IS NOT NULL)
+  AND NOT EXISTS(SELECT 1 FROM appuser_mutes WHERE muter_id = :viewer_id AND muted_id = a.author_id)
+  AND NOT EXISTS(SELECT 1 FROM appuser_blocks WHERE blocker_id = a.author_id AND blocked_id = :viewer_id)
+ORDER BY sc.score DESC, a.created_at DESC, a.id DESC
+LIMIT :limit;
+
+--! count_sitemap_entries
+SELECT COUNT(*) + COUNT(DISTINCT a.author_id)
+FROM article a
+JOIN appuser u ON a.author_id = u.id
+WHERE a.status = 'published' AND a.deleted_at IS NULL AND a.hidden_at IS NULL AND u.deactivated_at IS NULL;
+
+--! sitemap_entries
+SELECT kind, key, last_modified
+FROM (
+    SELECT 'article' AS kind, a.slug AS key, a.updated_at AS last_modified
+    FROM article a
+    JOIN appuser u ON a.author_id = u.id
+    WHERE a.status = 'published' AND a.deleted_at IS NULL AND a.hidden_at IS NULL AND u.deactivated_at IS NULL
+    UNION ALL
+    SELECT 'profile', u.username, GREATEST(MAX(a.updated_at), u.updated_at)
+    FROM article a
+    JOIN appuser u ON a.author_id = u.id
+    WHERE a.status = 'published' AND a.deleted_at IS NULL AND a.hidden_at IS NULL AND u.deactivated_at IS NULL
+    GROUP BY u.id
+) entries
+-- code point order, as the in-memory repository sorts
+ORDER BY kind, key COLLATE ucs_basic
+LIMIT :limit OFFSET :offset;
+
+--! get_trashed_article_by_slug
+SELECT a.*,
+       (SELECT COUNT(*) FROM article_favorite WHERE article_id = a.id) as favorites_count,
+       ARRAY(SELECT t.name FROM tag t JOIN article_tag at ON t.id = at.tag_id WHERE at.article_id = a.id ORDER BY t.name) as tag_list,
+       ARRAY(SELECT ac.appuser_id FROM article_coauthor ac
+             WHERE ac.article_id = a.id AND ac.accepted_at IS NOT NULL
+             ORDER BY ac.accepted_at, ac.appuser_id) as coauthor_ids
+FROM article a WHERE a.slug = :slug AND a.deleted_at IS NOT NULL;
+
+--! list_trashed_articles
+SELECT a.*,
+       (SELECT COUNT(*) FROM article_favorite WHERE article_id = a.id) as favorites_count,
+       ARRAY(SELECT t.name FROM tag t JOIN article_tag at ON t.id = at.tag_id WHERE at.article_id = a.id ORDER BY t.name) as tag_list,
+       ARRAY(SELECT ac.appuser_id FROM article_coauthor ac
+             WHERE ac.article_id = a.id AND ac.accepted_at IS NOT NULL
+             ORDER BY ac.accepted_at, ac.appuser_id) as coauthor_ids
+FROM article a
+WHERE a.author_id = :author_id AND a.deleted_at IS NOT NULL
+ORDER BY a.deleted_at DESC, a.id;
+
+--! restore_article
+UPDATE article SET deleted_at = NULL WHERE id = :id;
+
+--! purge_trashed_articles
+-- comments, favorites, bookmarks and the rest go with them by cascade
+DELETE FROM article WHERE deleted_at <= :cutoff RETURNING id;
+
+--! add_article_reaction
+-- article_reaction_count follows by trigger
+INSERT INTO article_reaction (article_id, appuser_id, kind)
+VALUES (:article_id, :user_id, :kind)
+ON CONFLICT DO NOTHING;
+
+--! remove_article_reaction
+DELETE FROM article_reaction WHERE article_id = :article_id AND appuser_id = :user_id AND kind = :kind;
+
+--! article_reactions
+SELECT c.kind,
+       c.count,
+       EXISTS(SELECT 1 FROM article_reaction r
+              WHERE r.article_id = c.article_id AND r.kind = c.kind AND r.appuser_id = :viewer_id) as reacted
+FROM article_reaction_count c
+WHERE c.article_id = :article_id
+ORDER BY c.count DESC, c.kind COLLATE ucs_basic;
+
+--! remove_article_reactions_by_user
+DELETE FROM article_reaction WHERE appuser_id = :user_id;
+
+--! list_article_reactions_by_user
+SELECT article_id, kind FROM article_reaction
+WHERE appuser_id = :user_id
+ORDER BY created_at, article_id, kind COLLATE ucs_basic;
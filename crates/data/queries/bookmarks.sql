@@ -0,0 +1,59 @@
+--! upsert_bookmark
+-- saving again replaces the note and folder but keeps the original time
+INSERT INTO article_bookmark (appuser_id, article_id, note, folder, created_at)
+VALUES (:user_id, :article_id, :note, :folder, :created_at)
+ON CONFLICT (appuser_id, article_id) DO UPDATE SET note = EXCLUDED.note, folder = EXCLUDED.folder
+RETURNING appuser_id, article_id, note, folder, created_at;
+
+--! delete_bookmark
+DELETE FROM article_bookmark WHERE appuser_id = :user_id AND article_id = :article_id;
+
+--! get_bookmark
+SELECT appuser_id, article_id, note, folder, created_at
+FROM article_bookmark
+WHERE appuser_id = :user_id AND article_id = :article_id;
+
+--! list_bookmarks
+SELECT a.id, a.slug, a.title, a.description, a.body, a.author_id, a.created_at, a.updated_at, a.status, a.published_at,
+       u.username as author_username,
+       u.bio as author_bio,
+       u.img as author_image,
+       EXISTS(SELECT 1 FROM appuser_follows WHERE follower_id = :user_id AND followee_id = a.author_id) as following_author,
+       EXISTS(SELECT 1 FROM article_favorite WHERE appuser_id = :user_id AND article_id = a.id) as favorited,
+       true as bookmarked,
+       (SELECT COUNT(*) FROM article_favorite WHERE article_id = a.id) as favorites_count,
+       ARRAY(SELECT t.name FROM tag t JOIN article_tag at ON t.id = at.tag_id WHERE at.article_id = a.id ORDER BY t.name) as tag_list,
+       ARRAY(SELECT rc.kind FROM article_reaction_count rc WHERE rc.article_id = a.id
+             ORDER BY rc.count DESC, rc.kind COLLATE ucs_basic) as reaction_kinds,
+       ARRAY(SELECT rc.count FROM article_reaction_count rc WHERE rc.article_id = a.id
+             ORDER BY rc.count DESC, rc.kind COLLATE ucs_basic) as reaction_counts,
+       ARRAY(SELECT ar.kind FROM article_reaction ar WHERE ar.article_id = a.id AND ar.appuser_id = :user_id) as viewer_reactions,
+       b.note, b.folder, b.created_at as bookmarked_at
+FROM article_bookmark b
+JOIN article a ON a.id = b.article_id
+JOIN appuser u ON a.author_id = u.id
+WHERE b.appuser_id = :user_id
+  AND u.deactivated_at IS NULL
+  AND a.deleted_at IS NULL
+  AND ((a.status = 'published' AND a.hidden_at IS NULL) OR a.author_id = :user_id)
+  AND (:folder::text IS NULL OR b.folder = :folder)
+ORDER BY b.created_at DESC, a.id DESC
+LIMIT :limit OFFSET :offset;
+
+--! count_bookmarks
+SELECT COUNT(*)
+FROM article_bookmark b
+JOIN article a ON a.id = b.article_id
+JOIN appuser u ON a.author_id = u.id
+WHERE b.appuser_id = :user_id
+  AND u.deactivated_at IS NULL
+  AND a.deleted_at IS NULL
+  AND ((a.status = 'published' AND a.hidden_at IS NULL) OR a.author_id = :user_id)
+  AND (:folder::text IS NULL OR b.folder = :folder);
+
+--! list_bookmarks_by_user
+-- whether or not the user can still see the article
+SELECT appuser_id, article_id, note, folder, created_at
+FROM article_bookmark
+WHERE appuser_id = :user_id
+ORDER BY created_at DESC, article_id;
@@ -0,0 +1,22 @@
+--! invite_coauthor
+INSERT INTO article_coauthor (article_id, appuser_id, invited_at)
+VALUES (:article_id, :appuser_id, :invited_at)
+ON CONFLICT (article_id, appuser_id) DO NOTHING;
+
+--! get_coauthor
+SELECT article_id, appuser_id, invited_at, accepted_at
+FROM article_coauthor
+WHERE article_id = :article_id AND appuser_id = :appuser_id;
+
+--! accept_coauthor
+UPDATE article_coauthor SET accepted_at = COALESCE(accepted_at, :accepted_at)
+WHERE article_id = :article_id AND appuser_id = :appuser_id;
+
+--! remove_coauthor
+DELETE FROM article_coauthor WHERE article_id = :article_id AND appuser_id = :appuser_id;
+
+--! list_coauthorships
+SELECT article_id, appuser_id, invited_at, accepted_at
+FROM article_coauthor
+WHERE appuser_id = :appuser_id
+ORDER BY invited_at, article_id;
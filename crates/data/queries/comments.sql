@@ -4,10 +4,64 @@ VALUES (:body, :article_id, :author_id, :created_at, :created_at)
 RETURNING *;
 
 --! get_comments_by_article
-SELECT * FROM comment WHERE article_id = :article_id ORDER BY created_at DESC;
+SELECT * FROM comment WHERE article_id = :article_id AND deleted_at IS NULL ORDER BY created_at DESC;
 
 --! delete_comment
-DELETE FROM comment WHERE id = :id;
+-- only moves it to the trash; purge_trashed_comments erases it
+UPDATE comment SET deleted_at = :deleted_at WHERE id = :id AND deleted_at IS NULL;
 
 --! get_comment_by_id
-SELECT * FROM comment WHERE id = :id;
+SELECT * FROM comment WHERE id = :id AND deleted_at IS NULL;
+
+--! hide_comment
+UPDATE comment SET hidden_at = :hidden_at WHERE id = :id;
+
+--! get_comments_by_author
+SELECT * FROM comment WHERE author_id = :author_id AND deleted_at IS NULL ORDER BY created_at DESC;
+
+--! delete_comments_by_author
+DELETE FROM comment WHERE author_id = :author_id;
+
+--! reassign_comments
+UPDATE comment SET author_id = :to_author_id WHERE author_id = :from_author_id;
+
+--! get_trashed_comment
+SELECT * FROM comment WHERE id = :id AND deleted_at IS NOT NULL;
+
+--! list_trashed_comments
+SELECT * FROM comment
+WHERE author_id = :author_id AND deleted_at IS NOT NULL
+ORDER BY deleted_at DESC, id;
+
+--! restore_comment
+UPDATE comment SET deleted_at = NULL WHERE id = :id;
+
+--! purge_trashed_comments
+DELETE FROM comment WHERE deleted_at <= :cutoff RETURNING id;
+
+--! add_comment_reaction
+-- comment_reaction_count follows by trigger
+INSERT INTO comment_reaction (comment_id, appuser_id, kind)
+VALUES (:comment_id, :user_id, :kind)
+ON CONFLICT DO NOTHING;
+
+--! remove_comment_reaction
+DELETE FROM comment_reaction WHERE comment_id = :comment_id AND appuser_id = :user_id AND kind = :kind;
+
+--! comment_reactions
+SELECT c.comment_id,
+       c.kind,
+       c.count,
+       EXISTS(SELECT 1 FROM comment_reaction r
+              WHERE r.comment_id = c.comment_id AND r.kind = c.kind AND r.appuser_id = :viewer_id) as reacted
+FROM comment_reaction_count c
+WHERE c.comment_id = ANY(:comment_ids)
+ORDER BY c.comment_id, c.count DESC, c.kind COLLATE ucs_basic;
+
+--! remove_comment_reactions_by_user
+DELETE FROM comment_reaction WHERE appuser_id = :user_id;
+
+--! list_comment_reactions_by_user
+SELECT comment_id, kind FROM comment_reaction
+WHERE appuser_id = :user_id
+ORDER BY created_at, comment_id, kind COLLATE ucs_basic;
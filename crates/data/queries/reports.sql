@@ -0,0 +1,50 @@
+--! create_report : (article_id?, comment_id?, profile_id?, details?, moderator_id?, closed_at?)
+INSERT INTO report (id, reporter_id, article_id, comment_id, profile_id, target_author_id, reason, details, created_at, updated_at)
+VALUES (:id, :reporter_id, :article_id, :comment_id, :profile_id, :target_author_id, :reason, :details, :created_at, :created_at)
+RETURNING *;
+
+--! get_report : (article_id?, comment_id?, profile_id?, details?, moderator_id?, closed_at?)
+SELECT * FROM report WHERE id = :id;
+
+--! find_open_report : (article_id?, comment_id?, profile_id?, details?, moderator_id?, closed_at?)
+-- at most one target id is set, the others match nothing
+SELECT * FROM report
+WHERE reporter_id = :reporter_id
+  AND (article_id = :article_id OR comment_id = :comment_id OR profile_id = :profile_id)
+  AND status IN ('open', 'claimed')
+LIMIT 1;
+
+--! list_reports : (article_id?, comment_id?, profile_id?, details?, moderator_id?, closed_at?)
+SELECT * FROM report
+WHERE (:status::text IS NULL OR status = :status)
+ORDER BY created_at, id
+LIMIT :limit OFFSET :offset;
+
+--! count_reports
+SELECT COUNT(*) FROM report WHERE (:status::text IS NULL OR status = :status);
+
+--! list_reports_by_reporter : (article_id?, comment_id?, profile_id?, details?, moderator_id?, closed_at?)
+SELECT * FROM report
+WHERE reporter_id = :reporter_id
+ORDER BY created_at DESC, id;
+
+--! update_report
+-- only while the report is open and unclaimed or claimed by the same moderator
+UPDATE report
+SET status = :status,
+    moderator_id = :moderator_id,
+    updated_at = :updated_at,
+    closed_at = :closed_at
+WHERE id = :id
+  AND (moderator_id IS NULL OR moderator_id = :moderator_id)
+  AND closed_at IS NULL;
+
+--! record_moderation_action
+INSERT INTO moderation_action (report_id, moderator_id, action, note, created_at)
+VALUES (:report_id, :moderator_id, :action, :note, :created_at);
+
+--! list_moderation_actions : (moderator_id?, note?)
+SELECT report_id, moderator_id, action, note, created_at
+FROM moderation_action
+WHERE report_id = :report_id
+ORDER BY id;
@@ -0,0 +1,13 @@
+--! create_revision
+INSERT INTO article_revision (id, article_id, version, author_id, title, description, body, created_at)
+VALUES (:id, :article_id, :version, :author_id, :title, :description, :body, :created_at)
+RETURNING *;
+
+--! list_revisions
+SELECT * FROM article_revision WHERE article_id = :article_id ORDER BY version DESC;
+
+--! get_revision
+SELECT * FROM article_revision WHERE id = :id;
+
+--! latest_revision
+SELECT * FROM article_revision WHERE article_id = :article_id ORDER BY version DESC LIMIT 1;
@@ -0,0 +1,64 @@
+--! create_series
+INSERT INTO series (id, slug, title, description, kind, owner_id, created_at, updated_at)
+VALUES (:id, :slug, :title, :description, :kind, :owner_id, :created_at, :created_at)
+RETURNING id, slug, title, description, kind, owner_id, created_at, updated_at;
+
+--! get_series_by_slug
+SELECT id, slug, title, description, kind, owner_id, created_at, updated_at
+FROM series
+WHERE slug = :slug;
+
+--! list_series_by_owner
+SELECT id, slug, title, description, kind, owner_id, created_at, updated_at
+FROM series
+WHERE owner_id = :owner_id
+ORDER BY created_at, id;
+
+--! update_series
+UPDATE series SET title = :title, description = :description, updated_at = :updated_at
+WHERE id = :id
+RETURNING id, slug, title, description, kind, owner_id, created_at, updated_at;
+
+--! delete_series
+DELETE FROM series WHERE id = :id;
+
+--! clear_series_parts
+DELETE FROM series_article WHERE series_id = :series_id;
+
+--! add_series_part
+INSERT INTO series_article (series_id, article_id, position, kind)
+SELECT id, :article_id, :position, kind
+FROM series
+WHERE id = :series_id;
+
+--! list_series_parts
+SELECT a.id, a.slug, a.title, a.description, a.body, a.author_id, a.created_at, a.updated_at, a.status, a.published_at,
+       u.username as author_username,
+       u.bio as author_bio,
+       u.img as author_image,
+       EXISTS(SELECT 1 FROM appuser_follows WHERE follower_id = :viewer_id AND followee_id = a.author_id) as following_author,
+       EXISTS(SELECT 1 FROM article_favorite WHERE appuser_id = :viewer_id AND article_id = a.id) as favorited,
+       EXISTS(SELECT 1 FROM article_bookmark WHERE appuser_id = :viewer_id AND article_id = a.id) as bookmarked,
+       (SELECT COUNT(*) FROM article_favorite WHERE article_id = a.id) as favorites_count,
+       ARRAY(SELECT t.name FROM tag t JOIN article_tag at ON t.id = at.tag_id WHERE at.article_id = a.id ORDER BY t.name) as tag_list,
+       ARRAY(SELECT rc.kind FROM article_reaction_count rc WHERE rc.article_id = a.id
+             ORDER BY rc.count DESC, rc.kind COLLATE ucs_basic) as reaction_kinds,
+       ARRAY(SELECT rc.count FROM article_reaction_count rc WHERE rc.article_id = a.id
+             ORDER BY rc.count DESC, rc.kind COLLATE ucs_basic) as reaction_counts,
+       ARRAY(SELECT ar.kind FROM article_reaction ar WHERE ar.article_id = a.id AND ar.appuser_id = :viewer_id) as viewer_reactions
+FROM series_article sa
+JOIN article a ON a.id = sa.article_id
+JOIN appuser u ON a.author_id = u.id
+WHERE sa.series_id = :series_id
+  AND u.deactivated_at IS NULL
+  AND a.deleted_at IS NULL
+  AND ((a.status = 'published' AND a.hidden_at IS NULL) OR a.author_id = :viewer_id)
+ORDER BY sa.position;
+
+--! series_of_article
+SELECT s.id, s.slug, s.title, s.description, s.kind, s.owner_id, s.created_at, s.updated_at
+FROM series s
+JOIN series_article sa ON sa.series_id = s.id
+JOIN article a ON a.id = sa.article_id
+WHERE sa.article_id = :article_id AND s.kind = 'series' AND a.deleted_at IS NULL
+LIMIT 1;
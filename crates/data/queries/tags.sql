@@ -0,0 +1,63 @@
+--! popular_tags
+SELECT t.name, COUNT(*) AS articles_count
+FROM tag t
+JOIN article_tag at ON t.id = at.tag_id
+JOIN article a ON a.id = at.article_id
+JOIN appuser u ON u.id = a.author_id
+WHERE a.status = 'published' AND a.deleted_at IS NULL AND a.hidden_at IS NULL AND u.deactivated_at IS NULL
+GROUP BY t.id, t.name
+ORDER BY articles_count DESC, t.name
+LIMIT :limit;
+
+--! search_tags
+SELECT t.name, COUNT(*) AS articles_count
+FROM tag t
+JOIN article_tag at ON t.id = at.tag_id
+JOIN article a ON a.id = at.article_id
+JOIN appuser u ON u.id = a.author_id
+WHERE a.status = 'published' AND a.deleted_at IS NULL AND a.hidden_at IS NULL AND u.deactivated_at IS NULL
+  AND left(lower(t.name), length(:prefix)) = lower(:prefix)
+GROUP BY t.id, t.name
+ORDER BY articles_count DESC, t.name
+LIMIT :limit;
+
+--! canonical_tag_name
+SELECT COALESCE(
+    (SELECT t.name FROM tag_alias ta JOIN tag t ON t.id = ta.tag_id WHERE ta.alias = :name),
+    :name
+);
+
+--! relink_tag
+INSERT INTO article_tag (article_id, tag_id, created_at)
+SELECT article_id, :target_id, created_at FROM article_tag WHERE tag_id = :source_id
+ON CONFLICT DO NOTHING;
+
+--! unlink_tag
+DELETE FROM article_tag WHERE tag_id = :tag_id;
+
+--! repoint_aliases
+UPDATE tag_alias SET tag_id = :target_id WHERE tag_id = :source_id;
+
+--! delete_tag
+DELETE FROM tag WHERE id = :id;
+
+--! upsert_alias
+INSERT INTO tag_alias (alias, tag_id) VALUES (:alias, :tag_id)
+ON CONFLICT (alias) DO UPDATE SET tag_id = EXCLUDED.tag_id;
+
+--! relink_follows
+INSERT INTO tag_follow (appuser_id, tag_id, created_at)
+SELECT appuser_id, :target_id, created_at FROM tag_follow WHERE tag_id = :source_id
+ON CONFLICT DO NOTHING;
+
+--! follow_tag
+INSERT INTO tag_follow (appuser_id, tag_id) VALUES (:user_id, :tag_id) ON CONFLICT DO NOTHING;
+
+--! unfollow_tag
+DELETE FROM tag_follow
+WHERE appuser_id = :user_id AND tag_id = (SELECT id FROM tag WHERE name = :name);
+
+--! followed_tags
+SELECT t.name FROM tag_follow tf JOIN tag t ON t.id = tf.tag_id
+WHERE tf.appuser_id = :user_id
+ORDER BY t.name;
@@ -1,23 +1,23 @@
---! create_user
+--! create_user : (deactivated_at?, suspended_at?)
 INSERT INTO appuser (id, email, username, pwd, created_at, updated_at)
 VALUES (:id, :email, :username, :pwd, :created_at, :created_at)
 RETURNING *;
 
---! get_user_by_email
+--! get_user_by_email : (deactivated_at?, suspended_at?)
 SELECT * FROM appuser WHERE email = :email;
 
---! get_user_by_username
+--! get_user_by_username : (deactivated_at?, suspended_at?)
 SELECT * FROM appuser WHERE username = :username;
 
---! get_user_by_id
+--! get_user_by_id : (deactivated_at?, suspended_at?)
 SELECT * FROM appuser WHERE id = :id;
 
---! update_user
+--! update_user : (deactivated_at?, suspended_at?)
 UPDATE appuser
 SET email = COALESCE(:email, email),
     username = COALESCE(:username, username),
     pwd = COALESCE(:pwd, pwd),
-    img = COALESCE(:img, img),
+    img = COALESCE(:img, ''),
     bio = COALESCE(:bio, bio),
     updated_at = :updated_at
 WHERE id = :id
@@ -37,3 +37,78 @@ SELECT EXISTS(
     SELECT 1 FROM appuser_follows
     WHERE follower_id = :follower_id AND followee_id = :followee_id
 );
+
+--! list_followed_users : (deactivated_at?, suspended_at?)
+SELECT u.* FROM appuser u
+JOIN appuser_follows f ON f.followee_id = u.id
+WHERE f.follower_id = :follower_id
+ORDER BY u.username;
+
+--! list_blocked_users : (deactivated_at?, suspended_at?)
+SELECT u.* FROM appuser u
+JOIN appuser_blocks b ON b.blocked_id = u.id
+WHERE b.blocker_id = :blocker_id
+ORDER BY u.username;
+
+--! list_muted_users : (deactivated_at?, suspended_at?)
+SELECT u.* FROM appuser u
+JOIN appuser_mutes m ON m.muted_id = u.id
+WHERE m.muter_id = :muter_id
+ORDER BY u.username;
+
+--! deactivate_user
+UPDATE appuser SET deactivated_at = :deactivated_at WHERE id = :id;
+
+--! reactivate_user
+UPDATE appuser SET deactivated_at = NULL WHERE id = :id;
+
+--! suspend_user
+UPDATE appuser SET suspended_at = :suspended_at WHERE id = :id;
+
+--! list_deactivated_users : (deactivated_at?, suspended_at?)
+SELECT * FROM appuser
+WHERE deactivated_at IS NOT NULL AND deactivated_at <= :cutoff
+ORDER BY deactivated_at;
+
+--! delete_user
+DELETE FROM appuser WHERE id = :id;
+
+--! block_user
+INSERT INTO appuser_blocks (blocker_id, blocked_id)
+VALUES (:blocker_id, :blocked_id)
+ON CONFLICT DO NOTHING;
+
+--! unfollow_both_ways
+DELETE FROM appuser_follows
+WHERE (follower_id = :user_id AND followee_id = :other_id)
+   OR (follower_id = :other_id AND followee_id = :user_id);
+
+--! unblock_user
+DELETE FROM appuser_blocks
+WHERE blocker_id = :blocker_id AND blocked_id = :blocked_id;
+
+--! is_blocking
+SELECT EXISTS(
+    SELECT 1 FROM appuser_blocks
+    WHERE blocker_id = :blocker_id AND blocked_id = :blocked_id
+);
+
+--! mute_user
+INSERT INTO appuser_mutes (muter_id, muted_id)
+VALUES (:muter_id, :muted_id)
+ON CONFLICT DO NOTHING;
+
+--! unmute_user
+DELETE FROM appuser_mutes
+WHERE muter_id = :muter_id AND muted_id = :muted_id;
+
+--! is_muting
+SELECT EXISTS(
+    SELECT 1 FROM appuser_mutes
+    WHERE muter_id = :muter_id AND muted_id = :muted_id
+);
+
+--! list_hidden_authors
+SELECT muted_id FROM appuser_mutes WHERE muter_id = :viewer_id
+UNION
+SELECT blocker_id FROM appuser_blocks WHERE blocked_id = :viewer_id;
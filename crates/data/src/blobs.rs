@@ -0,0 +1,148 @@
+use std::io::ErrorKind;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use anyhow::{Context, bail};
+use async_trait::async_trait;
+use common_config::S3Config;
+use domain::{Blob, UploadKind, repositories::BlobStore};
+use object_store::{
+    Attribute, Attributes, ObjectStore, PutOptions, PutPayload,
+    aws::{AmazonS3, AmazonS3Builder},
+    path::Path,
+};
+
+const FALLBACK_CONTENT_TYPE: &str = "application/octet-stream";
+
+/// Keeps each blob as a file named after its key in one directory
+///
+/// Nothing but the bytes is written, so the content type is read off the
+/// key's extension.
+#[derive(Debug, Clone)]
+pub struct LocalBlobStore {
+    root: PathBuf,
+}
+
+impl LocalBlobStore {
+    /// The directory is created on the first write
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    fn path(&self, key: &str) -> anyhow::Result<PathBuf> {
+        if key.is_empty() || key.starts_with('.') || key.contains(['/', '\\']) {
+            bail!("invalid blob key {key:?}");
+        }
+        Ok(self.root.join(key))
+    }
+}
+
+#[async_trait]
+impl BlobStore for LocalBlobStore {
+    #[tracing::instrument(skip(self, blob), err)]
+    async fn put_blob(&self, key: &str, blob: Blob) -> anyhow::Result<()> {
+        let path = self.path(key)?;
+        tokio::fs::create_dir_all(&self.root)
+            .await
+            .with_context(|| format!("failed to create {}", self.root.display()))?;
+        // Readers never see a half-written file
+        let partial = self.root.join(format!(".{key}.partial"));
+        tokio::fs::write(&partial, &blob.bytes).await?;
+        tokio::fs::rename(&partial, &path).await?;
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn get_blob(&self, key: &str) -> anyhow::Result<Option<Blob>> {
+        match tokio::fs::read(self.path(key)?).await {
+            Ok(bytes) => Ok(Some(Blob {
+                content_type: UploadKind::from_key(key)
+                    .map_or(FALLBACK_CONTENT_TYPE, |kind| kind.content_type())
+                    .to_owned(),
+                bytes,
+            })),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn delete_blob(&self, key: &str) -> anyhow::Result<()> {
+        match tokio::fs::remove_file(self.path(key)?).await {
+            Err(e) if e.kind() != ErrorKind::NotFound => Err(e.into()),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Keeps blobs as objects in an S3 bucket, or one on any S3-compatible
+/// service such as MinIO
+#[derive(Debug, Clone)]
+pub struct S3BlobStore {
+    store: Arc<AmazonS3>,
+}
+
+impl S3BlobStore {
+    pub fn new(config: &S3Config) -> anyhow::Result<Self> {
+        let mut builder = AmazonS3Builder::new()
+            .with_bucket_name(&config.bucket)
+            .with_region(&config.region)
+            .with_access_key_id(&config.access_key_id)
+            .with_secret_access_key(&config.secret_access_key);
+        if let Some(endpoint) = &config.endpoint {
+            // Self-hosted services usually live on one host without TLS
+            builder = builder
+                .with_endpoint(endpoint)
+                .with_allow_http(endpoint.starts_with("http://"))
+                .with_virtual_hosted_style_request(false);
+        }
+        let store = builder.build().context("invalid S3 configuration")?;
+        Ok(Self {
+            store: Arc::new(store),
+        })
+    }
+}
+
+#[async_trait]
+impl BlobStore for S3BlobStore {
+    #[tracing::instrument(skip(self, blob), err)]
+    async fn put_blob(&self, key: &str, blob: Blob) -> anyhow::Result<()> {
+        let mut attributes = Attributes::new();
+        attributes.insert(Attribute::ContentType, blob.content_type.into());
+        self.store
+            .put_opts(
+                &Path::from(key),
+                PutPayload::from(blob.bytes),
+                PutOptions::from(attributes),
+            )
+            .await?;
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn get_blob(&self, key: &str) -> anyhow::Result<Option<Blob>> {
+        let result = match self.store.get(&Path::from(key)).await {
+            Ok(result) => result,
+            Err(object_store::Error::NotFound { .. }) => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+        let content_type = result
+            .attributes
+            .get(&Attribute::ContentType)
+            .map_or(FALLBACK_CONTENT_TYPE, |value| value.as_ref())
+            .to_owned();
+        let bytes = result.bytes().await?;
+        Ok(Some(Blob {
+            content_type,
+            bytes: bytes.to_vec(),
+        }))
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn delete_blob(&self, key: &str) -> anyhow::Result<()> {
+        match self.store.delete(&Path::from(key)).await {
+            Err(object_store::Error::NotFound { .. }) | Ok(()) => Ok(()),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
@@ -1,5 +1,11 @@
 // This file was generated with `clorinde`. Do not modify.
 
 pub mod articles;
+pub mod bookmarks;
+pub mod coauthors;
 pub mod comments;
+pub mod reports;
+pub mod revisions;
+pub mod series;
+pub mod tags;
 pub mod users;
@@ -6,6 +6,10 @@ pub struct CreateArticleParams<
     T2: crate::clorinde::StringSql,
     T3: crate::clorinde::StringSql,
     T4: crate::clorinde::StringSql,
+    T5: crate::clorinde::StringSql,
+    T6: crate::clorinde::StringSql,
+    T7: crate::clorinde::StringSql,
+    T8: crate::clorinde::StringSql,
 > {
     pub id: uuid::Uuid,
     pub slug: T1,
@@ -13,26 +17,56 @@ pub struct CreateArticleParams<
     pub description: T3,
     pub body: T4,
     pub author_id: uuid::Uuid,
+    pub status: T5,
+    pub published_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub canonical_url: Option<T6>,
+    pub meta_description: Option<T7>,
+    pub social_image: Option<T8>,
     pub created_at: chrono::DateTime<chrono::FixedOffset>,
 }
 #[derive(Debug)]
+pub struct RecordPreviousSlugParams<T1: crate::clorinde::StringSql> {
+    pub slug: T1,
+    pub article_id: uuid::Uuid,
+}
+#[derive(Debug)]
 pub struct UpdateArticleParams<
     T1: crate::clorinde::StringSql,
     T2: crate::clorinde::StringSql,
     T3: crate::clorinde::StringSql,
     T4: crate::clorinde::StringSql,
+    T5: crate::clorinde::StringSql,
+    T6: crate::clorinde::StringSql,
+    T7: crate::clorinde::StringSql,
+    T8: crate::clorinde::StringSql,
 > {
     pub slug: T1,
     pub title: T2,
     pub description: T3,
     pub body: T4,
+    pub status: T5,
+    pub published_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub canonical_url: Option<T6>,
+    pub meta_description: Option<T7>,
+    pub social_image: Option<T8>,
     pub updated_at: chrono::DateTime<chrono::FixedOffset>,
     pub id: uuid::Uuid,
 }
 #[derive(Clone, Copy, Debug)]
+pub struct DeleteArticleParams {
+    pub deleted_at: chrono::DateTime<chrono::FixedOffset>,
+    pub id: uuid::Uuid,
+}
+#[derive(Clone, Copy, Debug)]
+pub struct HideArticleParams {
+    pub hidden_at: chrono::DateTime<chrono::FixedOffset>,
+    pub id: uuid::Uuid,
+}
+#[derive(Clone, Copy, Debug)]
 pub struct FavoriteArticleParams {
     pub user_id: uuid::Uuid,
     pub article_id: uuid::Uuid,
+    pub favorited_at: chrono::DateTime<chrono::FixedOffset>,
 }
 #[derive(Clone, Copy, Debug)]
 pub struct UnfavoriteArticleParams {
@@ -55,19 +89,91 @@ pub struct AddTagToArticleParams {
     pub tag_id: uuid::Uuid,
 }
 #[derive(Debug)]
-pub struct ListArticlesParams<T1: crate::clorinde::StringSql, T2: crate::clorinde::StringSql, T3: crate::clorinde::StringSql> {
+pub struct ListArticlesParams<
+    T1: crate::clorinde::StringSql,
+    T2: crate::clorinde::ArraySql<Item = T1>,
+    T3: crate::clorinde::StringSql,
+    T4: crate::clorinde::ArraySql<Item = T3>,
+    T5: crate::clorinde::StringSql,
+    T6: crate::clorinde::ArraySql<Item = T5>,
+    T7: crate::clorinde::StringSql,
+    T8: crate::clorinde::StringSql,
+> {
     pub viewer_id: uuid::Uuid,
-    pub author: T1,
-    pub tag: T2,
-    pub favorited: T3,
+    pub authors: T2,
+    pub tags: T4,
+    pub match_all_tags: bool,
+    pub excluded_tags: T6,
+    pub favorited: T7,
+    pub since: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub until: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub sort: T8,
+    pub trending_since: Option<chrono::DateTime<chrono::FixedOffset>>,
     pub limit: i64,
     pub offset: i64,
 }
 #[derive(Debug)]
-pub struct CountArticlesParams<T1: crate::clorinde::StringSql, T2: crate::clorinde::StringSql, T3: crate::clorinde::StringSql> {
-    pub author: T1,
-    pub tag: T2,
-    pub favorited: T3,
+pub struct ListArticlesOlderParams<
+    T1: crate::clorinde::StringSql,
+    T2: crate::clorinde::ArraySql<Item = T1>,
+    T3: crate::clorinde::StringSql,
+    T4: crate::clorinde::ArraySql<Item = T3>,
+    T5: crate::clorinde::StringSql,
+    T6: crate::clorinde::ArraySql<Item = T5>,
+    T7: crate::clorinde::StringSql,
+> {
+    pub viewer_id: uuid::Uuid,
+    pub authors: T2,
+    pub tags: T4,
+    pub match_all_tags: bool,
+    pub excluded_tags: T6,
+    pub favorited: T7,
+    pub since: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub until: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub cursor_listed_at: chrono::DateTime<chrono::FixedOffset>,
+    pub cursor_id: uuid::Uuid,
+    pub limit: i64,
+}
+#[derive(Debug)]
+pub struct ListArticlesNewerParams<
+    T1: crate::clorinde::StringSql,
+    T2: crate::clorinde::ArraySql<Item = T1>,
+    T3: crate::clorinde::StringSql,
+    T4: crate::clorinde::ArraySql<Item = T3>,
+    T5: crate::clorinde::StringSql,
+    T6: crate::clorinde::ArraySql<Item = T5>,
+    T7: crate::clorinde::StringSql,
+> {
+    pub viewer_id: uuid::Uuid,
+    pub authors: T2,
+    pub tags: T4,
+    pub match_all_tags: bool,
+    pub excluded_tags: T6,
+    pub favorited: T7,
+    pub since: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub until: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub cursor_listed_at: chrono::DateTime<chrono::FixedOffset>,
+    pub cursor_id: uuid::Uuid,
+    pub limit: i64,
+}
+#[derive(Debug)]
+pub struct CountArticlesParams<
+    T1: crate::clorinde::StringSql,
+    T2: crate::clorinde::ArraySql<Item = T1>,
+    T3: crate::clorinde::StringSql,
+    T4: crate::clorinde::ArraySql<Item = T3>,
+    T5: crate::clorinde::StringSql,
+    T6: crate::clorinde::ArraySql<Item = T5>,
+    T7: crate::clorinde::StringSql,
+> {
+    pub viewer_id: uuid::Uuid,
+    pub authors: T2,
+    pub tags: T4,
+    pub match_all_tags: bool,
+    pub excluded_tags: T6,
+    pub favorited: T7,
+    pub since: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub until: Option<chrono::DateTime<chrono::FixedOffset>>,
 }
 #[derive(Clone, Copy, Debug)]
 pub struct FeedArticlesParams {
@@ -75,6 +181,77 @@ pub struct FeedArticlesParams {
     pub limit: i64,
     pub offset: i64,
 }
+#[derive(Clone, Copy, Debug)]
+pub struct FeedArticlesOlderParams {
+    pub viewer_id: uuid::Uuid,
+    pub cursor_listed_at: chrono::DateTime<chrono::FixedOffset>,
+    pub cursor_id: uuid::Uuid,
+    pub limit: i64,
+}
+#[derive(Clone, Copy, Debug)]
+pub struct FeedArticlesNewerParams {
+    pub viewer_id: uuid::Uuid,
+    pub cursor_listed_at: chrono::DateTime<chrono::FixedOffset>,
+    pub cursor_id: uuid::Uuid,
+    pub limit: i64,
+}
+#[derive(Clone, Copy, Debug)]
+pub struct ReassignArticlesParams {
+    pub to_author_id: uuid::Uuid,
+    pub from_author_id: uuid::Uuid,
+}
+#[derive(Clone, Copy, Debug)]
+pub struct ListDraftsParams {
+    pub author_id: uuid::Uuid,
+    pub limit: i64,
+    pub offset: i64,
+}
+#[derive(Debug)]
+pub struct SearchArticlesParams<T1: crate::clorinde::StringSql> {
+    pub viewer_id: uuid::Uuid,
+    pub query: T1,
+    pub limit: i64,
+    pub offset: i64,
+}
+#[derive(Debug)]
+pub struct CountSearchArticlesParams<T1: crate::clorinde::StringSql> {
+    pub query: T1,
+    pub viewer_id: uuid::Uuid,
+}
+#[derive(Clone, Copy, Debug)]
+pub struct RelatedArticlesParams {
+    pub article_id: uuid::Uuid,
+    pub tag_weight: f64,
+    pub author_weight: f64,
+    pub cofavorite_weight: f64,
+    pub recency_weight: f64,
+    pub now: chrono::DateTime<chrono::FixedOffset>,
+    pub recency_days: f64,
+    pub viewer_id: uuid::Uuid,
+    pub limit: i64,
+}
+#[derive(Clone, Copy, Debug)]
+pub struct SitemapEntriesParams {
+    pub limit: i64,
+    pub offset: i64,
+}
+#[derive(Debug)]
+pub struct AddArticleReactionParams<T1: crate::clorinde::StringSql> {
+    pub article_id: uuid::Uuid,
+    pub user_id: uuid::Uuid,
+    pub kind: T1,
+}
+#[derive(Debug)]
+pub struct RemoveArticleReactionParams<T1: crate::clorinde::StringSql> {
+    pub article_id: uuid::Uuid,
+    pub user_id: uuid::Uuid,
+    pub kind: T1,
+}
+#[derive(Clone, Copy, Debug)]
+pub struct ArticleReactionsParams {
+    pub viewer_id: uuid::Uuid,
+    pub article_id: uuid::Uuid,
+}
 #[derive(Debug, Clone, PartialEq)]
 pub struct CreateArticle {
     pub id: uuid::Uuid,
@@ -85,6 +262,13 @@ pub struct CreateArticle {
     pub author_id: uuid::Uuid,
     pub created_at: chrono::DateTime<chrono::FixedOffset>,
     pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub status: String,
+    pub published_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub canonical_url: Option<String>,
+    pub meta_description: Option<String>,
+    pub social_image: Option<String>,
+    pub deleted_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub hidden_at: Option<chrono::DateTime<chrono::FixedOffset>>,
 }
 pub struct CreateArticleBorrowed<'a> {
     pub id: uuid::Uuid,
@@ -95,6 +279,13 @@ pub struct CreateArticleBorrowed<'a> {
     pub author_id: uuid::Uuid,
     pub created_at: chrono::DateTime<chrono::FixedOffset>,
     pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub status: &'a str,
+    pub published_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub canonical_url: Option<&'a str>,
+    pub meta_description: Option<&'a str>,
+    pub social_image: Option<&'a str>,
+    pub deleted_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub hidden_at: Option<chrono::DateTime<chrono::FixedOffset>>,
 }
 impl<'a> From<CreateArticleBorrowed<'a>> for CreateArticle {
     fn from(
@@ -107,6 +298,13 @@ impl<'a> From<CreateArticleBorrowed<'a>> for CreateArticle {
             author_id,
             created_at,
             updated_at,
+            status,
+            published_at,
+            canonical_url,
+            meta_description,
+            social_image,
+            deleted_at,
+            hidden_at,
         }: CreateArticleBorrowed<'a>,
     ) -> Self {
         Self {
@@ -118,6 +316,13 @@ impl<'a> From<CreateArticleBorrowed<'a>> for CreateArticle {
             author_id,
             created_at,
             updated_at,
+            status: status.into(),
+            published_at,
+            canonical_url: canonical_url.map(|v| v.into()),
+            meta_description: meta_description.map(|v| v.into()),
+            social_image: social_image.map(|v| v.into()),
+            deleted_at,
+            hidden_at,
         }
     }
 }
@@ -131,8 +336,16 @@ pub struct GetArticleBySlug {
     pub author_id: uuid::Uuid,
     pub created_at: chrono::DateTime<chrono::FixedOffset>,
     pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub status: String,
+    pub published_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub canonical_url: Option<String>,
+    pub meta_description: Option<String>,
+    pub social_image: Option<String>,
+    pub deleted_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub hidden_at: Option<chrono::DateTime<chrono::FixedOffset>>,
     pub favorites_count: i64,
     pub tag_list: Vec<String>,
+    pub coauthor_ids: Vec<uuid::Uuid>,
 }
 pub struct GetArticleBySlugBorrowed<'a> {
     pub id: uuid::Uuid,
@@ -143,8 +356,16 @@ pub struct GetArticleBySlugBorrowed<'a> {
     pub author_id: uuid::Uuid,
     pub created_at: chrono::DateTime<chrono::FixedOffset>,
     pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub status: &'a str,
+    pub published_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub canonical_url: Option<&'a str>,
+    pub meta_description: Option<&'a str>,
+    pub social_image: Option<&'a str>,
+    pub deleted_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub hidden_at: Option<chrono::DateTime<chrono::FixedOffset>>,
     pub favorites_count: i64,
     pub tag_list: crate::clorinde::ArrayIterator<'a, &'a str>,
+    pub coauthor_ids: crate::clorinde::ArrayIterator<'a, uuid::Uuid>,
 }
 impl<'a> From<GetArticleBySlugBorrowed<'a>> for GetArticleBySlug {
     fn from(
@@ -157,8 +378,16 @@ impl<'a> From<GetArticleBySlugBorrowed<'a>> for GetArticleBySlug {
             author_id,
             created_at,
             updated_at,
+            status,
+            published_at,
+            canonical_url,
+            meta_description,
+            social_image,
+            deleted_at,
+            hidden_at,
             favorites_count,
             tag_list,
+            coauthor_ids,
         }: GetArticleBySlugBorrowed<'a>,
     ) -> Self {
         Self {
@@ -170,8 +399,16 @@ impl<'a> From<GetArticleBySlugBorrowed<'a>> for GetArticleBySlug {
             author_id,
             created_at,
             updated_at,
+            status: status.into(),
+            published_at,
+            canonical_url: canonical_url.map(|v| v.into()),
+            meta_description: meta_description.map(|v| v.into()),
+            social_image: social_image.map(|v| v.into()),
+            deleted_at,
+            hidden_at,
             favorites_count,
             tag_list: tag_list.map(|v| v.into()).collect(),
+            coauthor_ids: coauthor_ids.map(|v| v).collect(),
         }
     }
 }
@@ -185,8 +422,16 @@ pub struct GetArticleById {
     pub author_id: uuid::Uuid,
     pub created_at: chrono::DateTime<chrono::FixedOffset>,
     pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub status: String,
+    pub published_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub canonical_url: Option<String>,
+    pub meta_description: Option<String>,
+    pub social_image: Option<String>,
+    pub deleted_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub hidden_at: Option<chrono::DateTime<chrono::FixedOffset>>,
     pub favorites_count: i64,
     pub tag_list: Vec<String>,
+    pub coauthor_ids: Vec<uuid::Uuid>,
 }
 pub struct GetArticleByIdBorrowed<'a> {
     pub id: uuid::Uuid,
@@ -197,8 +442,16 @@ pub struct GetArticleByIdBorrowed<'a> {
     pub author_id: uuid::Uuid,
     pub created_at: chrono::DateTime<chrono::FixedOffset>,
     pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub status: &'a str,
+    pub published_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub canonical_url: Option<&'a str>,
+    pub meta_description: Option<&'a str>,
+    pub social_image: Option<&'a str>,
+    pub deleted_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub hidden_at: Option<chrono::DateTime<chrono::FixedOffset>>,
     pub favorites_count: i64,
     pub tag_list: crate::clorinde::ArrayIterator<'a, &'a str>,
+    pub coauthor_ids: crate::clorinde::ArrayIterator<'a, uuid::Uuid>,
 }
 impl<'a> From<GetArticleByIdBorrowed<'a>> for GetArticleById {
     fn from(
@@ -211,8 +464,16 @@ impl<'a> From<GetArticleByIdBorrowed<'a>> for GetArticleById {
             author_id,
             created_at,
             updated_at,
+            status,
+            published_at,
+            canonical_url,
+            meta_description,
+            social_image,
+            deleted_at,
+            hidden_at,
             favorites_count,
             tag_list,
+            coauthor_ids,
         }: GetArticleByIdBorrowed<'a>,
     ) -> Self {
         Self {
@@ -224,8 +485,188 @@ impl<'a> From<GetArticleByIdBorrowed<'a>> for GetArticleById {
             author_id,
             created_at,
             updated_at,
+            status: status.into(),
+            published_at,
+            canonical_url: canonical_url.map(|v| v.into()),
+            meta_description: meta_description.map(|v| v.into()),
+            social_image: social_image.map(|v| v.into()),
+            deleted_at,
+            hidden_at,
+            favorites_count,
+            tag_list: tag_list.map(|v| v.into()).collect(),
+            coauthor_ids: coauthor_ids.map(|v| v).collect(),
+        }
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct GetArticlesBySlugs {
+    pub id: uuid::Uuid,
+    pub slug: String,
+    pub title: String,
+    pub description: String,
+    pub body: String,
+    pub author_id: uuid::Uuid,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub status: String,
+    pub published_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub canonical_url: Option<String>,
+    pub meta_description: Option<String>,
+    pub social_image: Option<String>,
+    pub deleted_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub hidden_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub favorites_count: i64,
+    pub tag_list: Vec<String>,
+    pub coauthor_ids: Vec<uuid::Uuid>,
+}
+pub struct GetArticlesBySlugsBorrowed<'a> {
+    pub id: uuid::Uuid,
+    pub slug: &'a str,
+    pub title: &'a str,
+    pub description: &'a str,
+    pub body: &'a str,
+    pub author_id: uuid::Uuid,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub status: &'a str,
+    pub published_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub canonical_url: Option<&'a str>,
+    pub meta_description: Option<&'a str>,
+    pub social_image: Option<&'a str>,
+    pub deleted_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub hidden_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub favorites_count: i64,
+    pub tag_list: crate::clorinde::ArrayIterator<'a, &'a str>,
+    pub coauthor_ids: crate::clorinde::ArrayIterator<'a, uuid::Uuid>,
+}
+impl<'a> From<GetArticlesBySlugsBorrowed<'a>> for GetArticlesBySlugs {
+    fn from(
+        GetArticlesBySlugsBorrowed {
+            id,
+            slug,
+            title,
+            description,
+            body,
+            author_id,
+            created_at,
+            updated_at,
+            status,
+            published_at,
+            canonical_url,
+            meta_description,
+            social_image,
+            deleted_at,
+            hidden_at,
+            favorites_count,
+            tag_list,
+            coauthor_ids,
+        }: GetArticlesBySlugsBorrowed<'a>,
+    ) -> Self {
+        Self {
+            id,
+            slug: slug.into(),
+            title: title.into(),
+            description: description.into(),
+            body: body.into(),
+            author_id,
+            created_at,
+            updated_at,
+            status: status.into(),
+            published_at,
+            canonical_url: canonical_url.map(|v| v.into()),
+            meta_description: meta_description.map(|v| v.into()),
+            social_image: social_image.map(|v| v.into()),
+            deleted_at,
+            hidden_at,
+            favorites_count,
+            tag_list: tag_list.map(|v| v.into()).collect(),
+            coauthor_ids: coauthor_ids.map(|v| v).collect(),
+        }
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct GetArticleByPreviousSlug {
+    pub id: uuid::Uuid,
+    pub slug: String,
+    pub title: String,
+    pub description: String,
+    pub body: String,
+    pub author_id: uuid::Uuid,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub status: String,
+    pub published_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub canonical_url: Option<String>,
+    pub meta_description: Option<String>,
+    pub social_image: Option<String>,
+    pub deleted_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub hidden_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub favorites_count: i64,
+    pub tag_list: Vec<String>,
+    pub coauthor_ids: Vec<uuid::Uuid>,
+}
+pub struct GetArticleByPreviousSlugBorrowed<'a> {
+    pub id: uuid::Uuid,
+    pub slug: &'a str,
+    pub title: &'a str,
+    pub description: &'a str,
+    pub body: &'a str,
+    pub author_id: uuid::Uuid,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub status: &'a str,
+    pub published_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub canonical_url: Option<&'a str>,
+    pub meta_description: Option<&'a str>,
+    pub social_image: Option<&'a str>,
+    pub deleted_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub hidden_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub favorites_count: i64,
+    pub tag_list: crate::clorinde::ArrayIterator<'a, &'a str>,
+    pub coauthor_ids: crate::clorinde::ArrayIterator<'a, uuid::Uuid>,
+}
+impl<'a> From<GetArticleByPreviousSlugBorrowed<'a>> for GetArticleByPreviousSlug {
+    fn from(
+        GetArticleByPreviousSlugBorrowed {
+            id,
+            slug,
+            title,
+            description,
+            body,
+            author_id,
+            created_at,
+            updated_at,
+            status,
+            published_at,
+            canonical_url,
+            meta_description,
+            social_image,
+            deleted_at,
+            hidden_at,
+            favorites_count,
+            tag_list,
+            coauthor_ids,
+        }: GetArticleByPreviousSlugBorrowed<'a>,
+    ) -> Self {
+        Self {
+            id,
+            slug: slug.into(),
+            title: title.into(),
+            description: description.into(),
+            body: body.into(),
+            author_id,
+            created_at,
+            updated_at,
+            status: status.into(),
+            published_at,
+            canonical_url: canonical_url.map(|v| v.into()),
+            meta_description: meta_description.map(|v| v.into()),
+            social_image: social_image.map(|v| v.into()),
+            deleted_at,
+            hidden_at,
             favorites_count,
             tag_list: tag_list.map(|v| v.into()).collect(),
+            coauthor_ids: coauthor_ids.map(|v| v).collect(),
         }
     }
 }
@@ -239,6 +680,13 @@ pub struct UpdateArticle {
     pub author_id: uuid::Uuid,
     pub created_at: chrono::DateTime<chrono::FixedOffset>,
     pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub status: String,
+    pub published_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub canonical_url: Option<String>,
+    pub meta_description: Option<String>,
+    pub social_image: Option<String>,
+    pub deleted_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub hidden_at: Option<chrono::DateTime<chrono::FixedOffset>>,
 }
 pub struct UpdateArticleBorrowed<'a> {
     pub id: uuid::Uuid,
@@ -249,6 +697,13 @@ pub struct UpdateArticleBorrowed<'a> {
     pub author_id: uuid::Uuid,
     pub created_at: chrono::DateTime<chrono::FixedOffset>,
     pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub status: &'a str,
+    pub published_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub canonical_url: Option<&'a str>,
+    pub meta_description: Option<&'a str>,
+    pub social_image: Option<&'a str>,
+    pub deleted_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub hidden_at: Option<chrono::DateTime<chrono::FixedOffset>>,
 }
 impl<'a> From<UpdateArticleBorrowed<'a>> for UpdateArticle {
     fn from(
@@ -261,6 +716,13 @@ impl<'a> From<UpdateArticleBorrowed<'a>> for UpdateArticle {
             author_id,
             created_at,
             updated_at,
+            status,
+            published_at,
+            canonical_url,
+            meta_description,
+            social_image,
+            deleted_at,
+            hidden_at,
         }: UpdateArticleBorrowed<'a>,
     ) -> Self {
         Self {
@@ -272,6 +734,99 @@ impl<'a> From<UpdateArticleBorrowed<'a>> for UpdateArticle {
             author_id,
             created_at,
             updated_at,
+            status: status.into(),
+            published_at,
+            canonical_url: canonical_url.map(|v| v.into()),
+            meta_description: meta_description.map(|v| v.into()),
+            social_image: social_image.map(|v| v.into()),
+            deleted_at,
+            hidden_at,
+        }
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListFavoritedArticles {
+    pub id: uuid::Uuid,
+    pub slug: String,
+    pub title: String,
+    pub description: String,
+    pub body: String,
+    pub author_id: uuid::Uuid,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub status: String,
+    pub published_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub canonical_url: Option<String>,
+    pub meta_description: Option<String>,
+    pub social_image: Option<String>,
+    pub deleted_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub hidden_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub favorites_count: i64,
+    pub tag_list: Vec<String>,
+    pub coauthor_ids: Vec<uuid::Uuid>,
+}
+pub struct ListFavoritedArticlesBorrowed<'a> {
+    pub id: uuid::Uuid,
+    pub slug: &'a str,
+    pub title: &'a str,
+    pub description: &'a str,
+    pub body: &'a str,
+    pub author_id: uuid::Uuid,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub status: &'a str,
+    pub published_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub canonical_url: Option<&'a str>,
+    pub meta_description: Option<&'a str>,
+    pub social_image: Option<&'a str>,
+    pub deleted_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub hidden_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub favorites_count: i64,
+    pub tag_list: crate::clorinde::ArrayIterator<'a, &'a str>,
+    pub coauthor_ids: crate::clorinde::ArrayIterator<'a, uuid::Uuid>,
+}
+impl<'a> From<ListFavoritedArticlesBorrowed<'a>> for ListFavoritedArticles {
+    fn from(
+        ListFavoritedArticlesBorrowed {
+            id,
+            slug,
+            title,
+            description,
+            body,
+            author_id,
+            created_at,
+            updated_at,
+            status,
+            published_at,
+            canonical_url,
+            meta_description,
+            social_image,
+            deleted_at,
+            hidden_at,
+            favorites_count,
+            tag_list,
+            coauthor_ids,
+        }: ListFavoritedArticlesBorrowed<'a>,
+    ) -> Self {
+        Self {
+            id,
+            slug: slug.into(),
+            title: title.into(),
+            description: description.into(),
+            body: body.into(),
+            author_id,
+            created_at,
+            updated_at,
+            status: status.into(),
+            published_at,
+            canonical_url: canonical_url.map(|v| v.into()),
+            meta_description: meta_description.map(|v| v.into()),
+            social_image: social_image.map(|v| v.into()),
+            deleted_at,
+            hidden_at,
+            favorites_count,
+            tag_list: tag_list.map(|v| v.into()).collect(),
+            coauthor_ids: coauthor_ids.map(|v| v).collect(),
         }
     }
 }
@@ -311,13 +866,19 @@ pub struct ListArticles {
     pub author_id: uuid::Uuid,
     pub created_at: chrono::DateTime<chrono::FixedOffset>,
     pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub status: String,
+    pub published_at: Option<chrono::DateTime<chrono::FixedOffset>>,
     pub author_username: String,
     pub author_bio: String,
     pub author_image: String,
     pub following_author: bool,
     pub favorited: bool,
+    pub bookmarked: bool,
     pub favorites_count: i64,
     pub tag_list: Vec<String>,
+    pub reaction_kinds: Vec<String>,
+    pub reaction_counts: Vec<i32>,
+    pub viewer_reactions: Vec<String>,
 }
 pub struct ListArticlesBorrowed<'a> {
     pub id: uuid::Uuid,
@@ -328,13 +889,19 @@ pub struct ListArticlesBorrowed<'a> {
     pub author_id: uuid::Uuid,
     pub created_at: chrono::DateTime<chrono::FixedOffset>,
     pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub status: &'a str,
+    pub published_at: Option<chrono::DateTime<chrono::FixedOffset>>,
     pub author_username: &'a str,
     pub author_bio: &'a str,
     pub author_image: &'a str,
     pub following_author: bool,
     pub favorited: bool,
+    pub bookmarked: bool,
     pub favorites_count: i64,
     pub tag_list: crate::clorinde::ArrayIterator<'a, &'a str>,
+    pub reaction_kinds: crate::clorinde::ArrayIterator<'a, &'a str>,
+    pub reaction_counts: crate::clorinde::ArrayIterator<'a, i32>,
+    pub viewer_reactions: crate::clorinde::ArrayIterator<'a, &'a str>,
 }
 impl<'a> From<ListArticlesBorrowed<'a>> for ListArticles {
     fn from(
@@ -347,13 +914,19 @@ impl<'a> From<ListArticlesBorrowed<'a>> for ListArticles {
             author_id,
             created_at,
             updated_at,
+            status,
+            published_at,
             author_username,
             author_bio,
             author_image,
             following_author,
             favorited,
+            bookmarked,
             favorites_count,
             tag_list,
+            reaction_kinds,
+            reaction_counts,
+            viewer_reactions,
         }: ListArticlesBorrowed<'a>,
     ) -> Self {
         Self {
@@ -365,18 +938,24 @@ impl<'a> From<ListArticlesBorrowed<'a>> for ListArticles {
             author_id,
             created_at,
             updated_at,
+            status: status.into(),
+            published_at,
             author_username: author_username.into(),
             author_bio: author_bio.into(),
             author_image: author_image.into(),
             following_author,
             favorited,
+            bookmarked,
             favorites_count,
             tag_list: tag_list.map(|v| v.into()).collect(),
+            reaction_kinds: reaction_kinds.map(|v| v.into()).collect(),
+            reaction_counts: reaction_counts.map(|v| v).collect(),
+            viewer_reactions: viewer_reactions.map(|v| v.into()).collect(),
         }
     }
 }
 #[derive(Debug, Clone, PartialEq)]
-pub struct FeedArticles {
+pub struct ListArticlesOlder {
     pub id: uuid::Uuid,
     pub slug: String,
     pub title: String,
@@ -385,15 +964,21 @@ pub struct FeedArticles {
     pub author_id: uuid::Uuid,
     pub created_at: chrono::DateTime<chrono::FixedOffset>,
     pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub status: String,
+    pub published_at: Option<chrono::DateTime<chrono::FixedOffset>>,
     pub author_username: String,
     pub author_bio: String,
     pub author_image: String,
     pub following_author: bool,
     pub favorited: bool,
+    pub bookmarked: bool,
     pub favorites_count: i64,
     pub tag_list: Vec<String>,
+    pub reaction_kinds: Vec<String>,
+    pub reaction_counts: Vec<i32>,
+    pub viewer_reactions: Vec<String>,
 }
-pub struct FeedArticlesBorrowed<'a> {
+pub struct ListArticlesOlderBorrowed<'a> {
     pub id: uuid::Uuid,
     pub slug: &'a str,
     pub title: &'a str,
@@ -402,17 +987,23 @@ pub struct FeedArticlesBorrowed<'a> {
     pub author_id: uuid::Uuid,
     pub created_at: chrono::DateTime<chrono::FixedOffset>,
     pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub status: &'a str,
+    pub published_at: Option<chrono::DateTime<chrono::FixedOffset>>,
     pub author_username: &'a str,
     pub author_bio: &'a str,
     pub author_image: &'a str,
     pub following_author: bool,
     pub favorited: bool,
+    pub bookmarked: bool,
     pub favorites_count: i64,
     pub tag_list: crate::clorinde::ArrayIterator<'a, &'a str>,
+    pub reaction_kinds: crate::clorinde::ArrayIterator<'a, &'a str>,
+    pub reaction_counts: crate::clorinde::ArrayIterator<'a, i32>,
+    pub viewer_reactions: crate::clorinde::ArrayIterator<'a, &'a str>,
 }
-impl<'a> From<FeedArticlesBorrowed<'a>> for FeedArticles {
+impl<'a> From<ListArticlesOlderBorrowed<'a>> for ListArticlesOlder {
     fn from(
-        FeedArticlesBorrowed {
+        ListArticlesOlderBorrowed {
             id,
             slug,
             title,
@@ -421,14 +1012,20 @@ impl<'a> From<FeedArticlesBorrowed<'a>> for FeedArticles {
             author_id,
             created_at,
             updated_at,
+            status,
+            published_at,
             author_username,
             author_bio,
             author_image,
             following_author,
             favorited,
+            bookmarked,
             favorites_count,
             tag_list,
-        }: FeedArticlesBorrowed<'a>,
+            reaction_kinds,
+            reaction_counts,
+            viewer_reactions,
+        }: ListArticlesOlderBorrowed<'a>,
     ) -> Self {
         Self {
             id,
@@ -439,35 +1036,2982 @@ impl<'a> From<FeedArticlesBorrowed<'a>> for FeedArticles {
             author_id,
             created_at,
             updated_at,
+            status: status.into(),
+            published_at,
             author_username: author_username.into(),
             author_bio: author_bio.into(),
             author_image: author_image.into(),
             following_author,
             favorited,
+            bookmarked,
             favorites_count,
             tag_list: tag_list.map(|v| v.into()).collect(),
+            reaction_kinds: reaction_kinds.map(|v| v.into()).collect(),
+            reaction_counts: reaction_counts.map(|v| v).collect(),
+            viewer_reactions: viewer_reactions.map(|v| v.into()).collect(),
         }
     }
 }
-use crate::clorinde::client::async_::GenericClient;
-use futures::{self, StreamExt, TryStreamExt};
-pub struct CreateArticleQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
-    client: &'c C,
-    params: [&'a (dyn postgres_types::ToSql + Sync); N],
-    query: &'static str,
-    cached: Option<&'s tokio_postgres::Statement>,
-    extractor: fn(&tokio_postgres::Row) -> Result<CreateArticleBorrowed, tokio_postgres::Error>,
-    mapper: fn(CreateArticleBorrowed) -> T,
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListArticlesNewer {
+    pub id: uuid::Uuid,
+    pub slug: String,
+    pub title: String,
+    pub description: String,
+    pub body: String,
+    pub author_id: uuid::Uuid,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub status: String,
+    pub published_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub author_username: String,
+    pub author_bio: String,
+    pub author_image: String,
+    pub following_author: bool,
+    pub favorited: bool,
+    pub bookmarked: bool,
+    pub favorites_count: i64,
+    pub tag_list: Vec<String>,
+    pub reaction_kinds: Vec<String>,
+    pub reaction_counts: Vec<i32>,
+    pub viewer_reactions: Vec<String>,
 }
-impl<'c, 'a, 's, C, T: 'c, const N: usize> CreateArticleQuery<'c, 'a, 's, C, T, N>
-where
-    C: GenericClient,
-{
+pub struct ListArticlesNewerBorrowed<'a> {
+    pub id: uuid::Uuid,
+    pub slug: &'a str,
+    pub title: &'a str,
+    pub description: &'a str,
+    pub body: &'a str,
+    pub author_id: uuid::Uuid,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub status: &'a str,
+    pub published_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub author_username: &'a str,
+    pub author_bio: &'a str,
+    pub author_image: &'a str,
+    pub following_author: bool,
+    pub favorited: bool,
+    pub bookmarked: bool,
+    pub favorites_count: i64,
+    pub tag_list: crate::clorinde::ArrayIterator<'a, &'a str>,
+    pub reaction_kinds: crate::clorinde::ArrayIterator<'a, &'a str>,
+    pub reaction_counts: crate::clorinde::ArrayIterator<'a, i32>,
+    pub viewer_reactions: crate::clorinde::ArrayIterator<'a, &'a str>,
+}
+impl<'a> From<ListArticlesNewerBorrowed<'a>> for ListArticlesNewer {
+    fn from(
+        ListArticlesNewerBorrowed {
+            id,
+            slug,
+            title,
+            description,
+            body,
+            author_id,
+            created_at,
+            updated_at,
+            status,
+            published_at,
+            author_username,
+            author_bio,
+            author_image,
+            following_author,
+            favorited,
+            bookmarked,
+            favorites_count,
+            tag_list,
+            reaction_kinds,
+            reaction_counts,
+            viewer_reactions,
+        }: ListArticlesNewerBorrowed<'a>,
+    ) -> Self {
+        Self {
+            id,
+            slug: slug.into(),
+            title: title.into(),
+            description: description.into(),
+            body: body.into(),
+            author_id,
+            created_at,
+            updated_at,
+            status: status.into(),
+            published_at,
+            author_username: author_username.into(),
+            author_bio: author_bio.into(),
+            author_image: author_image.into(),
+            following_author,
+            favorited,
+            bookmarked,
+            favorites_count,
+            tag_list: tag_list.map(|v| v.into()).collect(),
+            reaction_kinds: reaction_kinds.map(|v| v.into()).collect(),
+            reaction_counts: reaction_counts.map(|v| v).collect(),
+            viewer_reactions: viewer_reactions.map(|v| v.into()).collect(),
+        }
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeedArticles {
+    pub id: uuid::Uuid,
+    pub slug: String,
+    pub title: String,
+    pub description: String,
+    pub body: String,
+    pub author_id: uuid::Uuid,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub status: String,
+    pub published_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub author_username: String,
+    pub author_bio: String,
+    pub author_image: String,
+    pub following_author: bool,
+    pub favorited: bool,
+    pub bookmarked: bool,
+    pub favorites_count: i64,
+    pub tag_list: Vec<String>,
+    pub reaction_kinds: Vec<String>,
+    pub reaction_counts: Vec<i32>,
+    pub viewer_reactions: Vec<String>,
+    pub followed_tags: Vec<String>,
+    pub following_coauthor: bool,
+}
+pub struct FeedArticlesBorrowed<'a> {
+    pub id: uuid::Uuid,
+    pub slug: &'a str,
+    pub title: &'a str,
+    pub description: &'a str,
+    pub body: &'a str,
+    pub author_id: uuid::Uuid,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub status: &'a str,
+    pub published_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub author_username: &'a str,
+    pub author_bio: &'a str,
+    pub author_image: &'a str,
+    pub following_author: bool,
+    pub favorited: bool,
+    pub bookmarked: bool,
+    pub favorites_count: i64,
+    pub tag_list: crate::clorinde::ArrayIterator<'a, &'a str>,
+    pub reaction_kinds: crate::clorinde::ArrayIterator<'a, &'a str>,
+    pub reaction_counts: crate::clorinde::ArrayIterator<'a, i32>,
+    pub viewer_reactions: crate::clorinde::ArrayIterator<'a, &'a str>,
+    pub followed_tags: crate::clorinde::ArrayIterator<'a, &'a str>,
+    pub following_coauthor: bool,
+}
+impl<'a> From<FeedArticlesBorrowed<'a>> for FeedArticles {
+    fn from(
+        FeedArticlesBorrowed {
+            id,
+            slug,
+            title,
+            description,
+            body,
+            author_id,
+            created_at,
+            updated_at,
+            status,
+            published_at,
+            author_username,
+            author_bio,
+            author_image,
+            following_author,
+            favorited,
+            bookmarked,
+            favorites_count,
+            tag_list,
+            reaction_kinds,
+            reaction_counts,
+            viewer_reactions,
+            followed_tags,
+            following_coauthor,
+        }: FeedArticlesBorrowed<'a>,
+    ) -> Self {
+        Self {
+            id,
+            slug: slug.into(),
+            title: title.into(),
+            description: description.into(),
+            body: body.into(),
+            author_id,
+            created_at,
+            updated_at,
+            status: status.into(),
+            published_at,
+            author_username: author_username.into(),
+            author_bio: author_bio.into(),
+            author_image: author_image.into(),
+            following_author,
+            favorited,
+            bookmarked,
+            favorites_count,
+            tag_list: tag_list.map(|v| v.into()).collect(),
+            reaction_kinds: reaction_kinds.map(|v| v.into()).collect(),
+            reaction_counts: reaction_counts.map(|v| v).collect(),
+            viewer_reactions: viewer_reactions.map(|v| v.into()).collect(),
+            followed_tags: followed_tags.map(|v| v.into()).collect(),
+            following_coauthor,
+        }
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeedArticlesOlder {
+    pub id: uuid::Uuid,
+    pub slug: String,
+    pub title: String,
+    pub description: String,
+    pub body: String,
+    pub author_id: uuid::Uuid,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub status: String,
+    pub published_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub author_username: String,
+    pub author_bio: String,
+    pub author_image: String,
+    pub following_author: bool,
+    pub favorited: bool,
+    pub bookmarked: bool,
+    pub favorites_count: i64,
+    pub tag_list: Vec<String>,
+    pub reaction_kinds: Vec<String>,
+    pub reaction_counts: Vec<i32>,
+    pub viewer_reactions: Vec<String>,
+    pub followed_tags: Vec<String>,
+    pub following_coauthor: bool,
+}
+pub struct FeedArticlesOlderBorrowed<'a> {
+    pub id: uuid::Uuid,
+    pub slug: &'a str,
+    pub title: &'a str,
+    pub description: &'a str,
+    pub body: &'a str,
+    pub author_id: uuid::Uuid,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub status: &'a str,
+    pub published_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub author_username: &'a str,
+    pub author_bio: &'a str,
+    pub author_image: &'a str,
+    pub following_author: bool,
+    pub favorited: bool,
+    pub bookmarked: bool,
+    pub favorites_count: i64,
+    pub tag_list: crate::clorinde::ArrayIterator<'a, &'a str>,
+    pub reaction_kinds: crate::clorinde::ArrayIterator<'a, &'a str>,
+    pub reaction_counts: crate::clorinde::ArrayIterator<'a, i32>,
+    pub viewer_reactions: crate::clorinde::ArrayIterator<'a, &'a str>,
+    pub followed_tags: crate::clorinde::ArrayIterator<'a, &'a str>,
+    pub following_coauthor: bool,
+}
+impl<'a> From<FeedArticlesOlderBorrowed<'a>> for FeedArticlesOlder {
+    fn from(
+        FeedArticlesOlderBorrowed {
+            id,
+            slug,
+            title,
+            description,
+            body,
+            author_id,
+            created_at,
+            updated_at,
+            status,
+            published_at,
+            author_username,
+            author_bio,
+            author_image,
+            following_author,
+            favorited,
+            bookmarked,
+            favorites_count,
+            tag_list,
+            reaction_kinds,
+            reaction_counts,
+            viewer_reactions,
+            followed_tags,
+            following_coauthor,
+        }: FeedArticlesOlderBorrowed<'a>,
+    ) -> Self {
+        Self {
+            id,
+            slug: slug.into(),
+            title: title.into(),
+            description: description.into(),
+            body: body.into(),
+            author_id,
+            created_at,
+            updated_at,
+            status: status.into(),
+            published_at,
+            author_username: author_username.into(),
+            author_bio: author_bio.into(),
+            author_image: author_image.into(),
+            following_author,
+            favorited,
+            bookmarked,
+            favorites_count,
+            tag_list: tag_list.map(|v| v.into()).collect(),
+            reaction_kinds: reaction_kinds.map(|v| v.into()).collect(),
+            reaction_counts: reaction_counts.map(|v| v).collect(),
+            viewer_reactions: viewer_reactions.map(|v| v.into()).collect(),
+            followed_tags: followed_tags.map(|v| v.into()).collect(),
+            following_coauthor,
+        }
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct FeedArticlesNewer {
+    pub id: uuid::Uuid,
+    pub slug: String,
+    pub title: String,
+    pub description: String,
+    pub body: String,
+    pub author_id: uuid::Uuid,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub status: String,
+    pub published_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub author_username: String,
+    pub author_bio: String,
+    pub author_image: String,
+    pub following_author: bool,
+    pub favorited: bool,
+    pub bookmarked: bool,
+    pub favorites_count: i64,
+    pub tag_list: Vec<String>,
+    pub reaction_kinds: Vec<String>,
+    pub reaction_counts: Vec<i32>,
+    pub viewer_reactions: Vec<String>,
+    pub followed_tags: Vec<String>,
+    pub following_coauthor: bool,
+}
+pub struct FeedArticlesNewerBorrowed<'a> {
+    pub id: uuid::Uuid,
+    pub slug: &'a str,
+    pub title: &'a str,
+    pub description: &'a str,
+    pub body: &'a str,
+    pub author_id: uuid::Uuid,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub status: &'a str,
+    pub published_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub author_username: &'a str,
+    pub author_bio: &'a str,
+    pub author_image: &'a str,
+    pub following_author: bool,
+    pub favorited: bool,
+    pub bookmarked: bool,
+    pub favorites_count: i64,
+    pub tag_list: crate::clorinde::ArrayIterator<'a, &'a str>,
+    pub reaction_kinds: crate::clorinde::ArrayIterator<'a, &'a str>,
+    pub reaction_counts: crate::clorinde::ArrayIterator<'a, i32>,
+    pub viewer_reactions: crate::clorinde::ArrayIterator<'a, &'a str>,
+    pub followed_tags: crate::clorinde::ArrayIterator<'a, &'a str>,
+    pub following_coauthor: bool,
+}
+impl<'a> From<FeedArticlesNewerBorrowed<'a>> for FeedArticlesNewer {
+    fn from(
+        FeedArticlesNewerBorrowed {
+            id,
+            slug,
+            title,
+            description,
+            body,
+            author_id,
+            created_at,
+            updated_at,
+            status,
+            published_at,
+            author_username,
+            author_bio,
+            author_image,
+            following_author,
+            favorited,
+            bookmarked,
+            favorites_count,
+            tag_list,
+            reaction_kinds,
+            reaction_counts,
+            viewer_reactions,
+            followed_tags,
+            following_coauthor,
+        }: FeedArticlesNewerBorrowed<'a>,
+    ) -> Self {
+        Self {
+            id,
+            slug: slug.into(),
+            title: title.into(),
+            description: description.into(),
+            body: body.into(),
+            author_id,
+            created_at,
+            updated_at,
+            status: status.into(),
+            published_at,
+            author_username: author_username.into(),
+            author_bio: author_bio.into(),
+            author_image: author_image.into(),
+            following_author,
+            favorited,
+            bookmarked,
+            favorites_count,
+            tag_list: tag_list.map(|v| v.into()).collect(),
+            reaction_kinds: reaction_kinds.map(|v| v.into()).collect(),
+            reaction_counts: reaction_counts.map(|v| v).collect(),
+            viewer_reactions: viewer_reactions.map(|v| v.into()).collect(),
+            followed_tags: followed_tags.map(|v| v.into()).collect(),
+            following_coauthor,
+        }
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListArticlesByAuthor {
+    pub id: uuid::Uuid,
+    pub slug: String,
+    pub title: String,
+    pub description: String,
+    pub body: String,
+    pub author_id: uuid::Uuid,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub status: String,
+    pub published_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub canonical_url: Option<String>,
+    pub meta_description: Option<String>,
+    pub social_image: Option<String>,
+    pub deleted_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub hidden_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub favorites_count: i64,
+    pub tag_list: Vec<String>,
+    pub coauthor_ids: Vec<uuid::Uuid>,
+}
+pub struct ListArticlesByAuthorBorrowed<'a> {
+    pub id: uuid::Uuid,
+    pub slug: &'a str,
+    pub title: &'a str,
+    pub description: &'a str,
+    pub body: &'a str,
+    pub author_id: uuid::Uuid,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub status: &'a str,
+    pub published_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub canonical_url: Option<&'a str>,
+    pub meta_description: Option<&'a str>,
+    pub social_image: Option<&'a str>,
+    pub deleted_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub hidden_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub favorites_count: i64,
+    pub tag_list: crate::clorinde::ArrayIterator<'a, &'a str>,
+    pub coauthor_ids: crate::clorinde::ArrayIterator<'a, uuid::Uuid>,
+}
+impl<'a> From<ListArticlesByAuthorBorrowed<'a>> for ListArticlesByAuthor {
+    fn from(
+        ListArticlesByAuthorBorrowed {
+            id,
+            slug,
+            title,
+            description,
+            body,
+            author_id,
+            created_at,
+            updated_at,
+            status,
+            published_at,
+            canonical_url,
+            meta_description,
+            social_image,
+            deleted_at,
+            hidden_at,
+            favorites_count,
+            tag_list,
+            coauthor_ids,
+        }: ListArticlesByAuthorBorrowed<'a>,
+    ) -> Self {
+        Self {
+            id,
+            slug: slug.into(),
+            title: title.into(),
+            description: description.into(),
+            body: body.into(),
+            author_id,
+            created_at,
+            updated_at,
+            status: status.into(),
+            published_at,
+            canonical_url: canonical_url.map(|v| v.into()),
+            meta_description: meta_description.map(|v| v.into()),
+            social_image: social_image.map(|v| v.into()),
+            deleted_at,
+            hidden_at,
+            favorites_count,
+            tag_list: tag_list.map(|v| v.into()).collect(),
+            coauthor_ids: coauthor_ids.map(|v| v).collect(),
+        }
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListDrafts {
+    pub id: uuid::Uuid,
+    pub slug: String,
+    pub title: String,
+    pub description: String,
+    pub body: String,
+    pub author_id: uuid::Uuid,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub status: String,
+    pub published_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub author_username: String,
+    pub author_bio: String,
+    pub author_image: String,
+    pub following_author: bool,
+    pub favorited: bool,
+    pub bookmarked: bool,
+    pub favorites_count: i64,
+    pub tag_list: Vec<String>,
+    pub reaction_kinds: Vec<String>,
+    pub reaction_counts: Vec<i32>,
+    pub viewer_reactions: Vec<String>,
+}
+pub struct ListDraftsBorrowed<'a> {
+    pub id: uuid::Uuid,
+    pub slug: &'a str,
+    pub title: &'a str,
+    pub description: &'a str,
+    pub body: &'a str,
+    pub author_id: uuid::Uuid,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub status: &'a str,
+    pub published_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub author_username: &'a str,
+    pub author_bio: &'a str,
+    pub author_image: &'a str,
+    pub following_author: bool,
+    pub favorited: bool,
+    pub bookmarked: bool,
+    pub favorites_count: i64,
+    pub tag_list: crate::clorinde::ArrayIterator<'a, &'a str>,
+    pub reaction_kinds: crate::clorinde::ArrayIterator<'a, &'a str>,
+    pub reaction_counts: crate::clorinde::ArrayIterator<'a, i32>,
+    pub viewer_reactions: crate::clorinde::ArrayIterator<'a, &'a str>,
+}
+impl<'a> From<ListDraftsBorrowed<'a>> for ListDrafts {
+    fn from(
+        ListDraftsBorrowed {
+            id,
+            slug,
+            title,
+            description,
+            body,
+            author_id,
+            created_at,
+            updated_at,
+            status,
+            published_at,
+            author_username,
+            author_bio,
+            author_image,
+            following_author,
+            favorited,
+            bookmarked,
+            favorites_count,
+            tag_list,
+            reaction_kinds,
+            reaction_counts,
+            viewer_reactions,
+        }: ListDraftsBorrowed<'a>,
+    ) -> Self {
+        Self {
+            id,
+            slug: slug.into(),
+            title: title.into(),
+            description: description.into(),
+            body: body.into(),
+            author_id,
+            created_at,
+            updated_at,
+            status: status.into(),
+            published_at,
+            author_username: author_username.into(),
+            author_bio: author_bio.into(),
+            author_image: author_image.into(),
+            following_author,
+            favorited,
+            bookmarked,
+            favorites_count,
+            tag_list: tag_list.map(|v| v.into()).collect(),
+            reaction_kinds: reaction_kinds.map(|v| v.into()).collect(),
+            reaction_counts: reaction_counts.map(|v| v).collect(),
+            viewer_reactions: viewer_reactions.map(|v| v.into()).collect(),
+        }
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct PublishDueArticles {
+    pub id: uuid::Uuid,
+    pub slug: String,
+    pub title: String,
+    pub description: String,
+    pub body: String,
+    pub author_id: uuid::Uuid,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub status: String,
+    pub published_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub canonical_url: Option<String>,
+    pub meta_description: Option<String>,
+    pub social_image: Option<String>,
+    pub deleted_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub hidden_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub favorites_count: i64,
+    pub tag_list: Vec<String>,
+    pub coauthor_ids: Vec<uuid::Uuid>,
+}
+pub struct PublishDueArticlesBorrowed<'a> {
+    pub id: uuid::Uuid,
+    pub slug: &'a str,
+    pub title: &'a str,
+    pub description: &'a str,
+    pub body: &'a str,
+    pub author_id: uuid::Uuid,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub status: &'a str,
+    pub published_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub canonical_url: Option<&'a str>,
+    pub meta_description: Option<&'a str>,
+    pub social_image: Option<&'a str>,
+    pub deleted_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub hidden_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub favorites_count: i64,
+    pub tag_list: crate::clorinde::ArrayIterator<'a, &'a str>,
+    pub coauthor_ids: crate::clorinde::ArrayIterator<'a, uuid::Uuid>,
+}
+impl<'a> From<PublishDueArticlesBorrowed<'a>> for PublishDueArticles {
+    fn from(
+        PublishDueArticlesBorrowed {
+            id,
+            slug,
+            title,
+            description,
+            body,
+            author_id,
+            created_at,
+            updated_at,
+            status,
+            published_at,
+            canonical_url,
+            meta_description,
+            social_image,
+            deleted_at,
+            hidden_at,
+            favorites_count,
+            tag_list,
+            coauthor_ids,
+        }: PublishDueArticlesBorrowed<'a>,
+    ) -> Self {
+        Self {
+            id,
+            slug: slug.into(),
+            title: title.into(),
+            description: description.into(),
+            body: body.into(),
+            author_id,
+            created_at,
+            updated_at,
+            status: status.into(),
+            published_at,
+            canonical_url: canonical_url.map(|v| v.into()),
+            meta_description: meta_description.map(|v| v.into()),
+            social_image: social_image.map(|v| v.into()),
+            deleted_at,
+            hidden_at,
+            favorites_count,
+            tag_list: tag_list.map(|v| v.into()).collect(),
+            coauthor_ids: coauthor_ids.map(|v| v).collect(),
+        }
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchArticles {
+    pub id: uuid::Uuid,
+    pub slug: String,
+    pub title: String,
+    pub description: String,
+    pub body: String,
+    pub author_id: uuid::Uuid,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub status: String,
+    pub published_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub author_username: String,
+    pub author_bio: String,
+    pub author_image: String,
+    pub following_author: bool,
+    pub favorited: bool,
+    pub bookmarked: bool,
+    pub favorites_count: i64,
+    pub tag_list: Vec<String>,
+    pub reaction_kinds: Vec<String>,
+    pub reaction_counts: Vec<i32>,
+    pub viewer_reactions: Vec<String>,
+    pub rank: f64,
+    pub highlight: String,
+}
+pub struct SearchArticlesBorrowed<'a> {
+    pub id: uuid::Uuid,
+    pub slug: &'a str,
+    pub title: &'a str,
+    pub description: &'a str,
+    pub body: &'a str,
+    pub author_id: uuid::Uuid,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub status: &'a str,
+    pub published_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub author_username: &'a str,
+    pub author_bio: &'a str,
+    pub author_image: &'a str,
+    pub following_author: bool,
+    pub favorited: bool,
+    pub bookmarked: bool,
+    pub favorites_count: i64,
+    pub tag_list: crate::clorinde::ArrayIterator<'a, &'a str>,
+    pub reaction_kinds: crate::clorinde::ArrayIterator<'a, &'a str>,
+    pub reaction_counts: crate::clorinde::ArrayIterator<'a, i32>,
+    pub viewer_reactions: crate::clorinde::ArrayIterator<'a, &'a str>,
+    pub rank: f64,
+    pub highlight: &'a str,
+}
+impl<'a> From<SearchArticlesBorrowed<'a>> for SearchArticles {
+    fn from(
+        SearchArticlesBorrowed {
+            id,
+            slug,
+            title,
+            description,
+            body,
+            author_id,
+            created_at,
+            updated_at,
+            status,
+            published_at,
+            author_username,
+            author_bio,
+            author_image,
+            following_author,
+            favorited,
+            bookmarked,
+            favorites_count,
+            tag_list,
+            reaction_kinds,
+            reaction_counts,
+            viewer_reactions,
+            rank,
+            highlight,
+        }: SearchArticlesBorrowed<'a>,
+    ) -> Self {
+        Self {
+            id,
+            slug: slug.into(),
+            title: title.into(),
+            description: description.into(),
+            body: body.into(),
+            author_id,
+            created_at,
+            updated_at,
+            status: status.into(),
+            published_at,
+            author_username: author_username.into(),
+            author_bio: author_bio.into(),
+            author_image: author_image.into(),
+            following_author,
+            favorited,
+            bookmarked,
+            favorites_count,
+            tag_list: tag_list.map(|v| v.into()).collect(),
+            reaction_kinds: reaction_kinds.map(|v| v.into()).collect(),
+            reaction_counts: reaction_counts.map(|v| v).collect(),
+            viewer_reactions: viewer_reactions.map(|v| v.into()).collect(),
+            rank,
+            highlight: highlight.into(),
+        }
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct RelatedArticles {
+    pub id: uuid::Uuid,
+    pub slug: String,
+    pub title: String,
+    pub description: String,
+    pub body: String,
+    pub author_id: uuid::Uuid,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub status: String,
+    pub published_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub author_username: String,
+    pub author_bio: String,
+    pub author_image: String,
+    pub following_author: bool,
+    pub favorited: bool,
+    pub bookmarked: bool,
+    pub favorites_count: i64,
+    pub tag_list: Vec<String>,
+    pub reaction_kinds: Vec<String>,
+    pub reaction_counts: Vec<i32>,
+    pub viewer_reactions: Vec<String>,
+}
+pub struct RelatedArticlesBorrowed<'a> {
+    pub id: uuid::Uuid,
+    pub slug: &'a str,
+    pub title: &'a str,
+    pub description: &'a str,
+    pub body: &'a str,
+    pub author_id: uuid::Uuid,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub status: &'a str,
+    pub published_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub author_username: &'a str,
+    pub author_bio: &'a str,
+    pub author_image: &'a str,
+    pub following_author: bool,
+    pub favorited: bool,
+    pub bookmarked: bool,
+    pub favorites_count: i64,
+    pub tag_list: crate::clorinde::ArrayIterator<'a, &'a str>,
+    pub reaction_kinds: crate::clorinde::ArrayIterator<'a, &'a str>,
+    pub reaction_counts: crate::clorinde::ArrayIterator<'a, i32>,
+    pub viewer_reactions: crate::clorinde::ArrayIterator<'a, &'a str>,
+}
+impl<'a> From<RelatedArticlesBorrowed<'a>> for RelatedArticles {
+    fn from(
+        RelatedArticlesBorrowed {
+            id,
+            slug,
+            title,
+            description,
+            body,
+            author_id,
+            created_at,
+            updated_at,
+            status,
+            published_at,
+            author_username,
+            author_bio,
+            author_image,
+            following_author,
+            favorited,
+            bookmarked,
+            favorites_count,
+            tag_list,
+            reaction_kinds,
+            reaction_counts,
+            viewer_reactions,
+        }: RelatedArticlesBorrowed<'a>,
+    ) -> Self {
+        Self {
+            id,
+            slug: slug.into(),
+            title: title.into(),
+            description: description.into(),
+            body: body.into(),
+            author_id,
+            created_at,
+            updated_at,
+            status: status.into(),
+            published_at,
+            author_username: author_username.into(),
+            author_bio: author_bio.into(),
+            author_image: author_image.into(),
+            following_author,
+            favorited,
+            bookmarked,
+            favorites_count,
+            tag_list: tag_list.map(|v| v.into()).collect(),
+            reaction_kinds: reaction_kinds.map(|v| v.into()).collect(),
+            reaction_counts: reaction_counts.map(|v| v).collect(),
+            viewer_reactions: viewer_reactions.map(|v| v.into()).collect(),
+        }
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct SitemapEntries {
+    pub kind: String,
+    pub key: String,
+    pub last_modified: chrono::DateTime<chrono::FixedOffset>,
+}
+pub struct SitemapEntriesBorrowed<'a> {
+    pub kind: &'a str,
+    pub key: &'a str,
+    pub last_modified: chrono::DateTime<chrono::FixedOffset>,
+}
+impl<'a> From<SitemapEntriesBorrowed<'a>> for SitemapEntries {
+    fn from(
+        SitemapEntriesBorrowed {
+            kind,
+            key,
+            last_modified,
+        }: SitemapEntriesBorrowed<'a>,
+    ) -> Self {
+        Self {
+            kind: kind.into(),
+            key: key.into(),
+            last_modified,
+        }
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct GetTrashedArticleBySlug {
+    pub id: uuid::Uuid,
+    pub slug: String,
+    pub title: String,
+    pub description: String,
+    pub body: String,
+    pub author_id: uuid::Uuid,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub status: String,
+    pub published_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub canonical_url: Option<String>,
+    pub meta_description: Option<String>,
+    pub social_image: Option<String>,
+    pub deleted_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub hidden_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub favorites_count: i64,
+    pub tag_list: Vec<String>,
+    pub coauthor_ids: Vec<uuid::Uuid>,
+}
+pub struct GetTrashedArticleBySlugBorrowed<'a> {
+    pub id: uuid::Uuid,
+    pub slug: &'a str,
+    pub title: &'a str,
+    pub description: &'a str,
+    pub body: &'a str,
+    pub author_id: uuid::Uuid,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub status: &'a str,
+    pub published_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub canonical_url: Option<&'a str>,
+    pub meta_description: Option<&'a str>,
+    pub social_image: Option<&'a str>,
+    pub deleted_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub hidden_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub favorites_count: i64,
+    pub tag_list: crate::clorinde::ArrayIterator<'a, &'a str>,
+    pub coauthor_ids: crate::clorinde::ArrayIterator<'a, uuid::Uuid>,
+}
+impl<'a> From<GetTrashedArticleBySlugBorrowed<'a>> for GetTrashedArticleBySlug {
+    fn from(
+        GetTrashedArticleBySlugBorrowed {
+            id,
+            slug,
+            title,
+            description,
+            body,
+            author_id,
+            created_at,
+            updated_at,
+            status,
+            published_at,
+            canonical_url,
+            meta_description,
+            social_image,
+            deleted_at,
+            hidden_at,
+            favorites_count,
+            tag_list,
+            coauthor_ids,
+        }: GetTrashedArticleBySlugBorrowed<'a>,
+    ) -> Self {
+        Self {
+            id,
+            slug: slug.into(),
+            title: title.into(),
+            description: description.into(),
+            body: body.into(),
+            author_id,
+            created_at,
+            updated_at,
+            status: status.into(),
+            published_at,
+            canonical_url: canonical_url.map(|v| v.into()),
+            meta_description: meta_description.map(|v| v.into()),
+            social_image: social_image.map(|v| v.into()),
+            deleted_at,
+            hidden_at,
+            favorites_count,
+            tag_list: tag_list.map(|v| v.into()).collect(),
+            coauthor_ids: coauthor_ids.map(|v| v).collect(),
+        }
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListTrashedArticles {
+    pub id: uuid::Uuid,
+    pub slug: String,
+    pub title: String,
+    pub description: String,
+    pub body: String,
+    pub author_id: uuid::Uuid,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub status: String,
+    pub published_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub canonical_url: Option<String>,
+    pub meta_description: Option<String>,
+    pub social_image: Option<String>,
+    pub deleted_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub hidden_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub favorites_count: i64,
+    pub tag_list: Vec<String>,
+    pub coauthor_ids: Vec<uuid::Uuid>,
+}
+pub struct ListTrashedArticlesBorrowed<'a> {
+    pub id: uuid::Uuid,
+    pub slug: &'a str,
+    pub title: &'a str,
+    pub description: &'a str,
+    pub body: &'a str,
+    pub author_id: uuid::Uuid,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub status: &'a str,
+    pub published_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub canonical_url: Option<&'a str>,
+    pub meta_description: Option<&'a str>,
+    pub social_image: Option<&'a str>,
+    pub deleted_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub hidden_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub favorites_count: i64,
+    pub tag_list: crate::clorinde::ArrayIterator<'a, &'a str>,
+    pub coauthor_ids: crate::clorinde::ArrayIterator<'a, uuid::Uuid>,
+}
+impl<'a> From<ListTrashedArticlesBorrowed<'a>> for ListTrashedArticles {
+    fn from(
+        ListTrashedArticlesBorrowed {
+            id,
+            slug,
+            title,
+            description,
+            body,
+            author_id,
+            created_at,
+            updated_at,
+            status,
+            published_at,
+            canonical_url,
+            meta_description,
+            social_image,
+            deleted_at,
+            hidden_at,
+            favorites_count,
+            tag_list,
+            coauthor_ids,
+        }: ListTrashedArticlesBorrowed<'a>,
+    ) -> Self {
+        Self {
+            id,
+            slug: slug.into(),
+            title: title.into(),
+            description: description.into(),
+            body: body.into(),
+            author_id,
+            created_at,
+            updated_at,
+            status: status.into(),
+            published_at,
+            canonical_url: canonical_url.map(|v| v.into()),
+            meta_description: meta_description.map(|v| v.into()),
+            social_image: social_image.map(|v| v.into()),
+            deleted_at,
+            hidden_at,
+            favorites_count,
+            tag_list: tag_list.map(|v| v.into()).collect(),
+            coauthor_ids: coauthor_ids.map(|v| v).collect(),
+        }
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArticleReactions {
+    pub kind: String,
+    pub count: i32,
+    pub reacted: bool,
+}
+pub struct ArticleReactionsBorrowed<'a> {
+    pub kind: &'a str,
+    pub count: i32,
+    pub reacted: bool,
+}
+impl<'a> From<ArticleReactionsBorrowed<'a>> for ArticleReactions {
+    fn from(
+        ArticleReactionsBorrowed {
+            kind,
+            count,
+            reacted,
+        }: ArticleReactionsBorrowed<'a>,
+    ) -> Self {
+        Self {
+            kind: kind.into(),
+            count,
+            reacted,
+        }
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListArticleReactionsByUser {
+    pub article_id: uuid::Uuid,
+    pub kind: String,
+}
+pub struct ListArticleReactionsByUserBorrowed<'a> {
+    pub article_id: uuid::Uuid,
+    pub kind: &'a str,
+}
+impl<'a> From<ListArticleReactionsByUserBorrowed<'a>> for ListArticleReactionsByUser {
+    fn from(
+        ListArticleReactionsByUserBorrowed { article_id, kind }: ListArticleReactionsByUserBorrowed<
+            'a,
+        >,
+    ) -> Self {
+        Self {
+            article_id,
+            kind: kind.into(),
+        }
+    }
+}
+use crate::clorinde::client::async_::GenericClient;
+use futures::{self, StreamExt, TryStreamExt};
+pub struct CreateArticleQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor: fn(&tokio_postgres::Row) -> Result<CreateArticleBorrowed, tokio_postgres::Error>,
+    mapper: fn(CreateArticleBorrowed) -> T,
+}
+impl<'c, 'a, 's, C, T: 'c, const N: usize> CreateArticleQuery<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(
+        self,
+        mapper: fn(CreateArticleBorrowed) -> R,
+    ) -> CreateArticleQuery<'c, 'a, 's, C, R, N> {
+        CreateArticleQuery {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
+        }
+    }
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct GetArticleBySlugQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor: fn(&tokio_postgres::Row) -> Result<GetArticleBySlugBorrowed, tokio_postgres::Error>,
+    mapper: fn(GetArticleBySlugBorrowed) -> T,
+}
+impl<'c, 'a, 's, C, T: 'c, const N: usize> GetArticleBySlugQuery<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(
+        self,
+        mapper: fn(GetArticleBySlugBorrowed) -> R,
+    ) -> GetArticleBySlugQuery<'c, 'a, 's, C, R, N> {
+        GetArticleBySlugQuery {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
+        }
+    }
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct GetArticleByIdQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor: fn(&tokio_postgres::Row) -> Result<GetArticleByIdBorrowed, tokio_postgres::Error>,
+    mapper: fn(GetArticleByIdBorrowed) -> T,
+}
+impl<'c, 'a, 's, C, T: 'c, const N: usize> GetArticleByIdQuery<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(
+        self,
+        mapper: fn(GetArticleByIdBorrowed) -> R,
+    ) -> GetArticleByIdQuery<'c, 'a, 's, C, R, N> {
+        GetArticleByIdQuery {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
+        }
+    }
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct UpdateArticleQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor: fn(&tokio_postgres::Row) -> Result<UpdateArticleBorrowed, tokio_postgres::Error>,
+    mapper: fn(UpdateArticleBorrowed) -> T,
+}
+impl<'c, 'a, 's, C, T: 'c, const N: usize> UpdateArticleQuery<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(
+        self,
+        mapper: fn(UpdateArticleBorrowed) -> R,
+    ) -> UpdateArticleQuery<'c, 'a, 's, C, R, N> {
+        UpdateArticleQuery {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
+        }
+    }
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct BoolQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor: fn(&tokio_postgres::Row) -> Result<bool, tokio_postgres::Error>,
+    mapper: fn(bool) -> T,
+}
+impl<'c, 'a, 's, C, T: 'c, const N: usize> BoolQuery<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(self, mapper: fn(bool) -> R) -> BoolQuery<'c, 'a, 's, C, R, N> {
+        BoolQuery {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
+        }
+    }
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct I64Query<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor: fn(&tokio_postgres::Row) -> Result<i64, tokio_postgres::Error>,
+    mapper: fn(i64) -> T,
+}
+impl<'c, 'a, 's, C, T: 'c, const N: usize> I64Query<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(self, mapper: fn(i64) -> R) -> I64Query<'c, 'a, 's, C, R, N> {
+        I64Query {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
+        }
+    }
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct StringQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor: fn(&tokio_postgres::Row) -> Result<&str, tokio_postgres::Error>,
+    mapper: fn(&str) -> T,
+}
+impl<'c, 'a, 's, C, T: 'c, const N: usize> StringQuery<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(self, mapper: fn(&str) -> R) -> StringQuery<'c, 'a, 's, C, R, N> {
+        StringQuery {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
+        }
+    }
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct GetTagByNameQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor: fn(&tokio_postgres::Row) -> Result<GetTagByNameBorrowed, tokio_postgres::Error>,
+    mapper: fn(GetTagByNameBorrowed) -> T,
+}
+impl<'c, 'a, 's, C, T: 'c, const N: usize> GetTagByNameQuery<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(
+        self,
+        mapper: fn(GetTagByNameBorrowed) -> R,
+    ) -> GetTagByNameQuery<'c, 'a, 's, C, R, N> {
+        GetTagByNameQuery {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
+        }
+    }
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct ListArticlesQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor: fn(&tokio_postgres::Row) -> Result<ListArticlesBorrowed, tokio_postgres::Error>,
+    mapper: fn(ListArticlesBorrowed) -> T,
+}
+impl<'c, 'a, 's, C, T: 'c, const N: usize> ListArticlesQuery<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(
+        self,
+        mapper: fn(ListArticlesBorrowed) -> R,
+    ) -> ListArticlesQuery<'c, 'a, 's, C, R, N> {
+        ListArticlesQuery {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
+        }
+    }
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct FeedArticlesQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor: fn(&tokio_postgres::Row) -> Result<FeedArticlesBorrowed, tokio_postgres::Error>,
+    mapper: fn(FeedArticlesBorrowed) -> T,
+}
+impl<'c, 'a, 's, C, T: 'c, const N: usize> FeedArticlesQuery<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(
+        self,
+        mapper: fn(FeedArticlesBorrowed) -> R,
+    ) -> FeedArticlesQuery<'c, 'a, 's, C, R, N> {
+        FeedArticlesQuery {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
+        }
+    }
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct GetArticleByPreviousSlugQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor:
+        fn(&tokio_postgres::Row) -> Result<GetArticleByPreviousSlugBorrowed, tokio_postgres::Error>,
+    mapper: fn(GetArticleByPreviousSlugBorrowed) -> T,
+}
+impl<'c, 'a, 's, C, T: 'c, const N: usize> GetArticleByPreviousSlugQuery<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(
+        self,
+        mapper: fn(GetArticleByPreviousSlugBorrowed) -> R,
+    ) -> GetArticleByPreviousSlugQuery<'c, 'a, 's, C, R, N> {
+        GetArticleByPreviousSlugQuery {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
+        }
+    }
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct ListDraftsQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor: fn(&tokio_postgres::Row) -> Result<ListDraftsBorrowed, tokio_postgres::Error>,
+    mapper: fn(ListDraftsBorrowed) -> T,
+}
+impl<'c, 'a, 's, C, T: 'c, const N: usize> ListDraftsQuery<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(
+        self,
+        mapper: fn(ListDraftsBorrowed) -> R,
+    ) -> ListDraftsQuery<'c, 'a, 's, C, R, N> {
+        ListDraftsQuery {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
+        }
+    }
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct PublishDueArticlesQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor:
+        fn(&tokio_postgres::Row) -> Result<PublishDueArticlesBorrowed, tokio_postgres::Error>,
+    mapper: fn(PublishDueArticlesBorrowed) -> T,
+}
+impl<'c, 'a, 's, C, T: 'c, const N: usize> PublishDueArticlesQuery<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(
+        self,
+        mapper: fn(PublishDueArticlesBorrowed) -> R,
+    ) -> PublishDueArticlesQuery<'c, 'a, 's, C, R, N> {
+        PublishDueArticlesQuery {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
+        }
+    }
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct SearchArticlesQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor: fn(&tokio_postgres::Row) -> Result<SearchArticlesBorrowed, tokio_postgres::Error>,
+    mapper: fn(SearchArticlesBorrowed) -> T,
+}
+impl<'c, 'a, 's, C, T: 'c, const N: usize> SearchArticlesQuery<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(
+        self,
+        mapper: fn(SearchArticlesBorrowed) -> R,
+    ) -> SearchArticlesQuery<'c, 'a, 's, C, R, N> {
+        SearchArticlesQuery {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
+        }
+    }
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct ListArticlesOlderQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor: fn(&tokio_postgres::Row) -> Result<ListArticlesOlderBorrowed, tokio_postgres::Error>,
+    mapper: fn(ListArticlesOlderBorrowed) -> T,
+}
+impl<'c, 'a, 's, C, T: 'c, const N: usize> ListArticlesOlderQuery<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(
+        self,
+        mapper: fn(ListArticlesOlderBorrowed) -> R,
+    ) -> ListArticlesOlderQuery<'c, 'a, 's, C, R, N> {
+        ListArticlesOlderQuery {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
+        }
+    }
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct ListArticlesNewerQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor: fn(&tokio_postgres::Row) -> Result<ListArticlesNewerBorrowed, tokio_postgres::Error>,
+    mapper: fn(ListArticlesNewerBorrowed) -> T,
+}
+impl<'c, 'a, 's, C, T: 'c, const N: usize> ListArticlesNewerQuery<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(
+        self,
+        mapper: fn(ListArticlesNewerBorrowed) -> R,
+    ) -> ListArticlesNewerQuery<'c, 'a, 's, C, R, N> {
+        ListArticlesNewerQuery {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
+        }
+    }
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct FeedArticlesOlderQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor: fn(&tokio_postgres::Row) -> Result<FeedArticlesOlderBorrowed, tokio_postgres::Error>,
+    mapper: fn(FeedArticlesOlderBorrowed) -> T,
+}
+impl<'c, 'a, 's, C, T: 'c, const N: usize> FeedArticlesOlderQuery<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(
+        self,
+        mapper: fn(FeedArticlesOlderBorrowed) -> R,
+    ) -> FeedArticlesOlderQuery<'c, 'a, 's, C, R, N> {
+        FeedArticlesOlderQuery {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
+        }
+    }
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct FeedArticlesNewerQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor: fn(&tokio_postgres::Row) -> Result<FeedArticlesNewerBorrowed, tokio_postgres::Error>,
+    mapper: fn(FeedArticlesNewerBorrowed) -> T,
+}
+impl<'c, 'a, 's, C, T: 'c, const N: usize> FeedArticlesNewerQuery<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(
+        self,
+        mapper: fn(FeedArticlesNewerBorrowed) -> R,
+    ) -> FeedArticlesNewerQuery<'c, 'a, 's, C, R, N> {
+        FeedArticlesNewerQuery {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
+        }
+    }
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct RelatedArticlesQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor: fn(&tokio_postgres::Row) -> Result<RelatedArticlesBorrowed, tokio_postgres::Error>,
+    mapper: fn(RelatedArticlesBorrowed) -> T,
+}
+impl<'c, 'a, 's, C, T: 'c, const N: usize> RelatedArticlesQuery<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(
+        self,
+        mapper: fn(RelatedArticlesBorrowed) -> R,
+    ) -> RelatedArticlesQuery<'c, 'a, 's, C, R, N> {
+        RelatedArticlesQuery {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
+        }
+    }
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct SitemapEntriesQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor: fn(&tokio_postgres::Row) -> Result<SitemapEntriesBorrowed, tokio_postgres::Error>,
+    mapper: fn(SitemapEntriesBorrowed) -> T,
+}
+impl<'c, 'a, 's, C, T: 'c, const N: usize> SitemapEntriesQuery<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(
+        self,
+        mapper: fn(SitemapEntriesBorrowed) -> R,
+    ) -> SitemapEntriesQuery<'c, 'a, 's, C, R, N> {
+        SitemapEntriesQuery {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
+        }
+    }
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct GetTrashedArticleBySlugQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor:
+        fn(&tokio_postgres::Row) -> Result<GetTrashedArticleBySlugBorrowed, tokio_postgres::Error>,
+    mapper: fn(GetTrashedArticleBySlugBorrowed) -> T,
+}
+impl<'c, 'a, 's, C, T: 'c, const N: usize> GetTrashedArticleBySlugQuery<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(
+        self,
+        mapper: fn(GetTrashedArticleBySlugBorrowed) -> R,
+    ) -> GetTrashedArticleBySlugQuery<'c, 'a, 's, C, R, N> {
+        GetTrashedArticleBySlugQuery {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
+        }
+    }
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct ListTrashedArticlesQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor:
+        fn(&tokio_postgres::Row) -> Result<ListTrashedArticlesBorrowed, tokio_postgres::Error>,
+    mapper: fn(ListTrashedArticlesBorrowed) -> T,
+}
+impl<'c, 'a, 's, C, T: 'c, const N: usize> ListTrashedArticlesQuery<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(
+        self,
+        mapper: fn(ListTrashedArticlesBorrowed) -> R,
+    ) -> ListTrashedArticlesQuery<'c, 'a, 's, C, R, N> {
+        ListTrashedArticlesQuery {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
+        }
+    }
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct UuidUuidQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor: fn(&tokio_postgres::Row) -> Result<uuid::Uuid, tokio_postgres::Error>,
+    mapper: fn(uuid::Uuid) -> T,
+}
+impl<'c, 'a, 's, C, T: 'c, const N: usize> UuidUuidQuery<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(self, mapper: fn(uuid::Uuid) -> R) -> UuidUuidQuery<'c, 'a, 's, C, R, N> {
+        UuidUuidQuery {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
+        }
+    }
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct ArticleReactionsQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor: fn(&tokio_postgres::Row) -> Result<ArticleReactionsBorrowed, tokio_postgres::Error>,
+    mapper: fn(ArticleReactionsBorrowed) -> T,
+}
+impl<'c, 'a, 's, C, T: 'c, const N: usize> ArticleReactionsQuery<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(
+        self,
+        mapper: fn(ArticleReactionsBorrowed) -> R,
+    ) -> ArticleReactionsQuery<'c, 'a, 's, C, R, N> {
+        ArticleReactionsQuery {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
+        }
+    }
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct ListArticlesByAuthorQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor:
+        fn(&tokio_postgres::Row) -> Result<ListArticlesByAuthorBorrowed, tokio_postgres::Error>,
+    mapper: fn(ListArticlesByAuthorBorrowed) -> T,
+}
+impl<'c, 'a, 's, C, T: 'c, const N: usize> ListArticlesByAuthorQuery<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(
+        self,
+        mapper: fn(ListArticlesByAuthorBorrowed) -> R,
+    ) -> ListArticlesByAuthorQuery<'c, 'a, 's, C, R, N> {
+        ListArticlesByAuthorQuery {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
+        }
+    }
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct ListFavoritedArticlesQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor:
+        fn(&tokio_postgres::Row) -> Result<ListFavoritedArticlesBorrowed, tokio_postgres::Error>,
+    mapper: fn(ListFavoritedArticlesBorrowed) -> T,
+}
+impl<'c, 'a, 's, C, T: 'c, const N: usize> ListFavoritedArticlesQuery<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(
+        self,
+        mapper: fn(ListFavoritedArticlesBorrowed) -> R,
+    ) -> ListFavoritedArticlesQuery<'c, 'a, 's, C, R, N> {
+        ListFavoritedArticlesQuery {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
+        }
+    }
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct ListArticleReactionsByUserQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor: fn(
+        &tokio_postgres::Row,
+    ) -> Result<ListArticleReactionsByUserBorrowed, tokio_postgres::Error>,
+    mapper: fn(ListArticleReactionsByUserBorrowed) -> T,
+}
+impl<'c, 'a, 's, C, T: 'c, const N: usize> ListArticleReactionsByUserQuery<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
     pub fn map<R>(
         self,
-        mapper: fn(CreateArticleBorrowed) -> R,
-    ) -> CreateArticleQuery<'c, 'a, 's, C, R, N> {
-        CreateArticleQuery {
+        mapper: fn(ListArticleReactionsByUserBorrowed) -> R,
+    ) -> ListArticleReactionsByUserQuery<'c, 'a, 's, C, R, N> {
+        ListArticleReactionsByUserQuery {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
+        }
+    }
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct GetArticlesBySlugsQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor:
+        fn(&tokio_postgres::Row) -> Result<GetArticlesBySlugsBorrowed, tokio_postgres::Error>,
+    mapper: fn(GetArticlesBySlugsBorrowed) -> T,
+}
+impl<'c, 'a, 's, C, T: 'c, const N: usize> GetArticlesBySlugsQuery<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(
+        self,
+        mapper: fn(GetArticlesBySlugsBorrowed) -> R,
+    ) -> GetArticlesBySlugsQuery<'c, 'a, 's, C, R, N> {
+        GetArticlesBySlugsQuery {
             client: self.client,
             params: self.params,
             query: self.query,
@@ -476,650 +4020,1418 @@ where
             mapper,
         }
     }
-    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
-        let row =
-            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
-        Ok((self.mapper)((self.extractor)(&row)?))
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct CreateArticleStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn create_article() -> CreateArticleStmt {
+    CreateArticleStmt(
+        "INSERT INTO article (id, slug, title, description, body, author_id, status, published_at, canonical_url, meta_description, social_image, created_at, updated_at) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $12) RETURNING *",
+        None,
+    )
+}
+impl CreateArticleStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub fn bind<
+        'c,
+        'a,
+        's,
+        C: GenericClient,
+        T1: crate::clorinde::StringSql,
+        T2: crate::clorinde::StringSql,
+        T3: crate::clorinde::StringSql,
+        T4: crate::clorinde::StringSql,
+        T5: crate::clorinde::StringSql,
+        T6: crate::clorinde::StringSql,
+        T7: crate::clorinde::StringSql,
+        T8: crate::clorinde::StringSql,
+    >(
+        &'s self,
+        client: &'c C,
+        id: &'a uuid::Uuid,
+        slug: &'a T1,
+        title: &'a T2,
+        description: &'a T3,
+        body: &'a T4,
+        author_id: &'a uuid::Uuid,
+        status: &'a T5,
+        published_at: &'a Option<chrono::DateTime<chrono::FixedOffset>>,
+        canonical_url: &'a Option<T6>,
+        meta_description: &'a Option<T7>,
+        social_image: &'a Option<T8>,
+        created_at: &'a chrono::DateTime<chrono::FixedOffset>,
+    ) -> CreateArticleQuery<'c, 'a, 's, C, CreateArticle, 12> {
+        CreateArticleQuery {
+            client,
+            params: [
+                id,
+                slug,
+                title,
+                description,
+                body,
+                author_id,
+                status,
+                published_at,
+                canonical_url,
+                meta_description,
+                social_image,
+                created_at,
+            ],
+            query: self.0,
+            cached: self.1.as_ref(),
+            extractor:
+                |row: &tokio_postgres::Row| -> Result<CreateArticleBorrowed, tokio_postgres::Error> {
+                    Ok(CreateArticleBorrowed {
+                        id: row.try_get(0)?,
+                        slug: row.try_get(1)?,
+                        title: row.try_get(2)?,
+                        description: row.try_get(3)?,
+                        body: row.try_get(4)?,
+                        author_id: row.try_get(5)?,
+                        created_at: row.try_get(6)?,
+                        updated_at: row.try_get(7)?,
+                        status: row.try_get(8)?,
+                        published_at: row.try_get(9)?,
+                        canonical_url: row.try_get(10)?,
+                        meta_description: row.try_get(11)?,
+                        social_image: row.try_get(12)?,
+                        deleted_at: row.try_get(13)?,
+                        hidden_at: row.try_get(14)?,
+                    })
+                },
+            mapper: |it| CreateArticle::from(it),
+        }
+    }
+}
+impl<
+    'c,
+    'a,
+    's,
+    C: GenericClient,
+    T1: crate::clorinde::StringSql,
+    T2: crate::clorinde::StringSql,
+    T3: crate::clorinde::StringSql,
+    T4: crate::clorinde::StringSql,
+    T5: crate::clorinde::StringSql,
+    T6: crate::clorinde::StringSql,
+    T7: crate::clorinde::StringSql,
+    T8: crate::clorinde::StringSql,
+>
+    crate::clorinde::client::async_::Params<
+        'c,
+        'a,
+        's,
+        CreateArticleParams<T1, T2, T3, T4, T5, T6, T7, T8>,
+        CreateArticleQuery<'c, 'a, 's, C, CreateArticle, 12>,
+        C,
+    > for CreateArticleStmt
+{
+    fn params(
+        &'s self,
+        client: &'c C,
+        params: &'a CreateArticleParams<T1, T2, T3, T4, T5, T6, T7, T8>,
+    ) -> CreateArticleQuery<'c, 'a, 's, C, CreateArticle, 12> {
+        self.bind(
+            client,
+            &params.id,
+            &params.slug,
+            &params.title,
+            &params.description,
+            &params.body,
+            &params.author_id,
+            &params.status,
+            &params.published_at,
+            &params.canonical_url,
+            &params.meta_description,
+            &params.social_image,
+            &params.created_at,
+        )
+    }
+}
+pub struct GetArticleBySlugStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn get_article_by_slug() -> GetArticleBySlugStmt {
+    GetArticleBySlugStmt(
+        "SELECT a.*, (SELECT COUNT(*) FROM article_favorite WHERE article_id = a.id) as favorites_count, ARRAY(SELECT t.name FROM tag t JOIN article_tag at ON t.id = at.tag_id WHERE at.article_id = a.id ORDER BY t.name) as tag_list, ARRAY(SELECT ac.appuser_id FROM article_coauthor ac WHERE ac.article_id = a.id AND ac.accepted_at IS NOT NULL ORDER BY ac.accepted_at, ac.appuser_id) as coauthor_ids FROM article a WHERE a.slug = $1 AND a.deleted_at IS NULL",
+        None,
+    )
+}
+impl GetArticleBySlugStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub fn bind<'c, 'a, 's, C: GenericClient, T1: crate::clorinde::StringSql>(
+        &'s self,
+        client: &'c C,
+        slug: &'a T1,
+    ) -> GetArticleBySlugQuery<'c, 'a, 's, C, GetArticleBySlug, 1> {
+        GetArticleBySlugQuery { client, params: [slug], query: self.0, cached: self.1.as_ref(), extractor: |row: &tokio_postgres::Row| -> Result<GetArticleBySlugBorrowed, tokio_postgres::Error> { Ok(GetArticleBySlugBorrowed { id: row.try_get(0)?,
+slug: row.try_get(1)?,
+title: row.try_get(2)?,
+description: row.try_get(3)?,
+body: row.try_get(4)?,
+author_id: row.try_get(5)?,
+created_at: row.try_get(6)?,
+updated_at: row.try_get(7)?,
+status: row.try_get(8)?,
+published_at: row.try_get(9)?,
+canonical_url: row.try_get(10)?,
+meta_description: row.try_get(11)?,
+social_image: row.try_get(12)?,
+deleted_at: row.try_get(13)?,
+hidden_at: row.try_get(14)?,
+favorites_count: row.try_get(15)?,
+tag_list: row.try_get(16)?,
+coauthor_ids: row.try_get(17)?,
+ }) }, mapper: |it| GetArticleBySlug::from(it), }
+    }
+}
+pub struct GetArticleByIdStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn get_article_by_id() -> GetArticleByIdStmt {
+    GetArticleByIdStmt(
+        "SELECT a.*, (SELECT COUNT(*) FROM article_favorite WHERE article_id = a.id) as favorites_count, ARRAY(SELECT t.name FROM tag t JOIN article_tag at ON t.id = at.tag_id WHERE at.article_id = a.id ORDER BY t.name) as tag_list, ARRAY(SELECT ac.appuser_id FROM article_coauthor ac WHERE ac.article_id = a.id AND ac.accepted_at IS NOT NULL ORDER BY ac.accepted_at, ac.appuser_id) as coauthor_ids FROM article a WHERE a.id = $1 AND a.deleted_at IS NULL",
+        None,
+    )
+}
+impl GetArticleByIdStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        id: &'a uuid::Uuid,
+    ) -> GetArticleByIdQuery<'c, 'a, 's, C, GetArticleById, 1> {
+        GetArticleByIdQuery { client, params: [id], query: self.0, cached: self.1.as_ref(), extractor: |row: &tokio_postgres::Row| -> Result<GetArticleByIdBorrowed, tokio_postgres::Error> { Ok(GetArticleByIdBorrowed { id: row.try_get(0)?,
+slug: row.try_get(1)?,
+title: row.try_get(2)?,
+description: row.try_get(3)?,
+body: row.try_get(4)?,
+author_id: row.try_get(5)?,
+created_at: row.try_get(6)?,
+updated_at: row.try_get(7)?,
+status: row.try_get(8)?,
+published_at: row.try_get(9)?,
+canonical_url: row.try_get(10)?,
+meta_description: row.try_get(11)?,
+social_image: row.try_get(12)?,
+deleted_at: row.try_get(13)?,
+hidden_at: row.try_get(14)?,
+favorites_count: row.try_get(15)?,
+tag_list: row.try_get(16)?,
+coauthor_ids: row.try_get(17)?,
+ }) }, mapper: |it| GetArticleById::from(it), }
+    }
+}
+pub struct GetArticlesBySlugsStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn get_articles_by_slugs() -> GetArticlesBySlugsStmt {
+    GetArticlesBySlugsStmt(
+        "SELECT a.*, (SELECT COUNT(*) FROM article_favorite WHERE article_id = a.id) as favorites_count, ARRAY(SELECT t.name FROM tag t JOIN article_tag at ON t.id = at.tag_id WHERE at.article_id = a.id ORDER BY t.name) as tag_list, ARRAY(SELECT ac.appuser_id FROM article_coauthor ac WHERE ac.article_id = a.id AND ac.accepted_at IS NOT NULL ORDER BY ac.accepted_at, ac.appuser_id) as coauthor_ids FROM article a WHERE a.slug = ANY($1) AND a.deleted_at IS NULL",
+        None,
+    )
+}
+impl GetArticlesBySlugsStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub fn bind<
+        'c,
+        'a,
+        's,
+        C: GenericClient,
+        T1: crate::clorinde::StringSql,
+        T2: crate::clorinde::ArraySql<Item = T1>,
+    >(
+        &'s self,
+        client: &'c C,
+        slugs: &'a T2,
+    ) -> GetArticlesBySlugsQuery<'c, 'a, 's, C, GetArticlesBySlugs, 1> {
+        GetArticlesBySlugsQuery { client, params: [slugs], query: self.0, cached: self.1.as_ref(), extractor: |row: &tokio_postgres::Row| -> Result<GetArticlesBySlugsBorrowed, tokio_postgres::Error> { Ok(GetArticlesBySlugsBorrowed { id: row.try_get(0)?,
+slug: row.try_get(1)?,
+title: row.try_get(2)?,
+description: row.try_get(3)?,
+body: row.try_get(4)?,
+author_id: row.try_get(5)?,
+created_at: row.try_get(6)?,
+updated_at: row.try_get(7)?,
+status: row.try_get(8)?,
+published_at: row.try_get(9)?,
+canonical_url: row.try_get(10)?,
+meta_description: row.try_get(11)?,
+social_image: row.try_get(12)?,
+deleted_at: row.try_get(13)?,
+hidden_at: row.try_get(14)?,
+favorites_count: row.try_get(15)?,
+tag_list: row.try_get(16)?,
+coauthor_ids: row.try_get(17)?,
+ }) }, mapper: |it| GetArticlesBySlugs::from(it), }
     }
-    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
-        self.iter().await?.try_collect().await
+}
+pub struct GetArticleByPreviousSlugStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn get_article_by_previous_slug() -> GetArticleByPreviousSlugStmt {
+    GetArticleByPreviousSlugStmt(
+        "SELECT a.*, (SELECT COUNT(*) FROM article_favorite WHERE article_id = a.id) as favorites_count, ARRAY(SELECT t.name FROM tag t JOIN article_tag at ON t.id = at.tag_id WHERE at.article_id = a.id ORDER BY t.name) as tag_list, ARRAY(SELECT ac.appuser_id FROM article_coauthor ac WHERE ac.article_id = a.id AND ac.accepted_at IS NOT NULL ORDER BY ac.accepted_at, ac.appuser_id) as coauthor_ids FROM article a JOIN article_slug_history h ON h.article_id = a.id WHERE h.slug = $1 AND a.deleted_at IS NULL",
+        None,
+    )
+}
+impl GetArticleByPreviousSlugStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
     }
-    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
-        let opt_row =
-            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
-        Ok(opt_row
-            .map(|row| {
-                let extracted = (self.extractor)(&row)?;
-                Ok((self.mapper)(extracted))
-            })
-            .transpose()?)
+    pub fn bind<'c, 'a, 's, C: GenericClient, T1: crate::clorinde::StringSql>(
+        &'s self,
+        client: &'c C,
+        slug: &'a T1,
+    ) -> GetArticleByPreviousSlugQuery<'c, 'a, 's, C, GetArticleByPreviousSlug, 1> {
+        GetArticleByPreviousSlugQuery { client, params: [slug], query: self.0, cached: self.1.as_ref(), extractor: |row: &tokio_postgres::Row| -> Result<GetArticleByPreviousSlugBorrowed, tokio_postgres::Error> { Ok(GetArticleByPreviousSlugBorrowed { id: row.try_get(0)?,
+slug: row.try_get(1)?,
+title: row.try_get(2)?,
+description: row.try_get(3)?,
+body: row.try_get(4)?,
+author_id: row.try_get(5)?,
+created_at: row.try_get(6)?,
+updated_at: row.try_get(7)?,
+status: row.try_get(8)?,
+published_at: row.try_get(9)?,
+canonical_url: row.try_get(10)?,
+meta_description: row.try_get(11)?,
+social_image: row.try_get(12)?,
+deleted_at: row.try_get(13)?,
+hidden_at: row.try_get(14)?,
+favorites_count: row.try_get(15)?,
+tag_list: row.try_get(16)?,
+coauthor_ids: row.try_get(17)?,
+ }) }, mapper: |it| GetArticleByPreviousSlug::from(it), }
     }
-    pub async fn iter(
-        self,
-    ) -> Result<
-        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
-        tokio_postgres::Error,
+}
+pub struct RecordPreviousSlugStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn record_previous_slug() -> RecordPreviousSlugStmt {
+    RecordPreviousSlugStmt(
+        "INSERT INTO article_slug_history (slug, article_id) VALUES ($1, $2) ON CONFLICT (slug) DO UPDATE SET article_id = EXCLUDED.article_id, created_at = now()",
+        None,
+    )
+}
+impl RecordPreviousSlugStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub async fn bind<'c, 'a, 's, C: GenericClient, T1: crate::clorinde::StringSql>(
+        &'s self,
+        client: &'c C,
+        slug: &'a T1,
+        article_id: &'a uuid::Uuid,
+    ) -> Result<u64, tokio_postgres::Error> {
+        client.execute(self.0, &[slug, article_id]).await
+    }
+}
+impl<'a, C: GenericClient + Send + Sync, T1: crate::clorinde::StringSql>
+    crate::clorinde::client::async_::Params<
+        'a,
+        'a,
+        'a,
+        RecordPreviousSlugParams<T1>,
+        std::pin::Pin<
+            Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+        >,
+        C,
+    > for RecordPreviousSlugStmt
+{
+    fn params(
+        &'a self,
+        client: &'a C,
+        params: &'a RecordPreviousSlugParams<T1>,
+    ) -> std::pin::Pin<
+        Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
     > {
-        let stream = crate::clorinde::client::async_::raw(
-            self.client,
-            self.query,
-            crate::clorinde::slice_iter(&self.params),
-            self.cached,
-        )
-        .await?;
-        let mapped = stream
-            .map(move |res| {
-                res.and_then(|row| {
-                    let extracted = (self.extractor)(&row)?;
-                    Ok((self.mapper)(extracted))
-                })
-            })
-            .into_stream();
-        Ok(mapped)
+        Box::pin(self.bind(client, &params.slug, &params.article_id))
     }
 }
-pub struct GetArticleBySlugQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
-    client: &'c C,
-    params: [&'a (dyn postgres_types::ToSql + Sync); N],
-    query: &'static str,
-    cached: Option<&'s tokio_postgres::Statement>,
-    extractor: fn(&tokio_postgres::Row) -> Result<GetArticleBySlugBorrowed, tokio_postgres::Error>,
-    mapper: fn(GetArticleBySlugBorrowed) -> T,
+pub struct ForgetPreviousSlugStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn forget_previous_slug() -> ForgetPreviousSlugStmt {
+    ForgetPreviousSlugStmt("DELETE FROM article_slug_history WHERE slug = $1", None)
 }
-impl<'c, 'a, 's, C, T: 'c, const N: usize> GetArticleBySlugQuery<'c, 'a, 's, C, T, N>
-where
-    C: GenericClient,
-{
-    pub fn map<R>(
-        self,
-        mapper: fn(GetArticleBySlugBorrowed) -> R,
-    ) -> GetArticleBySlugQuery<'c, 'a, 's, C, R, N> {
-        GetArticleBySlugQuery {
-            client: self.client,
-            params: self.params,
-            query: self.query,
-            cached: self.cached,
-            extractor: self.extractor,
-            mapper,
-        }
+impl ForgetPreviousSlugStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
     }
-    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
-        let row =
-            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
-        Ok((self.mapper)((self.extractor)(&row)?))
+    pub async fn bind<'c, 'a, 's, C: GenericClient, T1: crate::clorinde::StringSql>(
+        &'s self,
+        client: &'c C,
+        slug: &'a T1,
+    ) -> Result<u64, tokio_postgres::Error> {
+        client.execute(self.0, &[slug]).await
     }
-    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
-        self.iter().await?.try_collect().await
+}
+pub struct UpdateArticleStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn update_article() -> UpdateArticleStmt {
+    UpdateArticleStmt(
+        "UPDATE article SET slug = COALESCE($1, slug), title = COALESCE($2, title), description = COALESCE($3, description), body = COALESCE($4, body), status = $5, published_at = $6, canonical_url = $7, meta_description = $8, social_image = $9, updated_at = $10 WHERE id = $11 AND deleted_at IS NULL RETURNING *",
+        None,
+    )
+}
+impl UpdateArticleStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
     }
-    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
-        let opt_row =
-            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
-        Ok(opt_row
-            .map(|row| {
-                let extracted = (self.extractor)(&row)?;
-                Ok((self.mapper)(extracted))
-            })
-            .transpose()?)
+    pub fn bind<
+        'c,
+        'a,
+        's,
+        C: GenericClient,
+        T1: crate::clorinde::StringSql,
+        T2: crate::clorinde::StringSql,
+        T3: crate::clorinde::StringSql,
+        T4: crate::clorinde::StringSql,
+        T5: crate::clorinde::StringSql,
+        T6: crate::clorinde::StringSql,
+        T7: crate::clorinde::StringSql,
+        T8: crate::clorinde::StringSql,
+    >(
+        &'s self,
+        client: &'c C,
+        slug: &'a T1,
+        title: &'a T2,
+        description: &'a T3,
+        body: &'a T4,
+        status: &'a T5,
+        published_at: &'a Option<chrono::DateTime<chrono::FixedOffset>>,
+        canonical_url: &'a Option<T6>,
+        meta_description: &'a Option<T7>,
+        social_image: &'a Option<T8>,
+        updated_at: &'a chrono::DateTime<chrono::FixedOffset>,
+        id: &'a uuid::Uuid,
+    ) -> UpdateArticleQuery<'c, 'a, 's, C, UpdateArticle, 11> {
+        UpdateArticleQuery {
+            client,
+            params: [
+                slug,
+                title,
+                description,
+                body,
+                status,
+                published_at,
+                canonical_url,
+                meta_description,
+                social_image,
+                updated_at,
+                id,
+            ],
+            query: self.0,
+            cached: self.1.as_ref(),
+            extractor:
+                |row: &tokio_postgres::Row| -> Result<UpdateArticleBorrowed, tokio_postgres::Error> {
+                    Ok(UpdateArticleBorrowed {
+                        id: row.try_get(0)?,
+                        slug: row.try_get(1)?,
+                        title: row.try_get(2)?,
+                        description: row.try_get(3)?,
+                        body: row.try_get(4)?,
+                        author_id: row.try_get(5)?,
+                        created_at: row.try_get(6)?,
+                        updated_at: row.try_get(7)?,
+                        status: row.try_get(8)?,
+                        published_at: row.try_get(9)?,
+                        canonical_url: row.try_get(10)?,
+                        meta_description: row.try_get(11)?,
+                        social_image: row.try_get(12)?,
+                        deleted_at: row.try_get(13)?,
+                        hidden_at: row.try_get(14)?,
+                    })
+                },
+            mapper: |it| UpdateArticle::from(it),
+        }
     }
-    pub async fn iter(
-        self,
-    ) -> Result<
-        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
-        tokio_postgres::Error,
-    > {
-        let stream = crate::clorinde::client::async_::raw(
-            self.client,
-            self.query,
-            crate::clorinde::slice_iter(&self.params),
-            self.cached,
+}
+impl<
+    'c,
+    'a,
+    's,
+    C: GenericClient,
+    T1: crate::clorinde::StringSql,
+    T2: crate::clorinde::StringSql,
+    T3: crate::clorinde::StringSql,
+    T4: crate::clorinde::StringSql,
+    T5: crate::clorinde::StringSql,
+    T6: crate::clorinde::StringSql,
+    T7: crate::clorinde::StringSql,
+    T8: crate::clorinde::StringSql,
+>
+    crate::clorinde::client::async_::Params<
+        'c,
+        'a,
+        's,
+        UpdateArticleParams<T1, T2, T3, T4, T5, T6, T7, T8>,
+        UpdateArticleQuery<'c, 'a, 's, C, UpdateArticle, 11>,
+        C,
+    > for UpdateArticleStmt
+{
+    fn params(
+        &'s self,
+        client: &'c C,
+        params: &'a UpdateArticleParams<T1, T2, T3, T4, T5, T6, T7, T8>,
+    ) -> UpdateArticleQuery<'c, 'a, 's, C, UpdateArticle, 11> {
+        self.bind(
+            client,
+            &params.slug,
+            &params.title,
+            &params.description,
+            &params.body,
+            &params.status,
+            &params.published_at,
+            &params.canonical_url,
+            &params.meta_description,
+            &params.social_image,
+            &params.updated_at,
+            &params.id,
         )
-        .await?;
-        let mapped = stream
-            .map(move |res| {
-                res.and_then(|row| {
-                    let extracted = (self.extractor)(&row)?;
-                    Ok((self.mapper)(extracted))
-                })
-            })
-            .into_stream();
-        Ok(mapped)
     }
 }
-pub struct GetArticleByIdQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
-    client: &'c C,
-    params: [&'a (dyn postgres_types::ToSql + Sync); N],
-    query: &'static str,
-    cached: Option<&'s tokio_postgres::Statement>,
-    extractor: fn(&tokio_postgres::Row) -> Result<GetArticleByIdBorrowed, tokio_postgres::Error>,
-    mapper: fn(GetArticleByIdBorrowed) -> T,
+pub struct DeleteArticleStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn delete_article() -> DeleteArticleStmt {
+    DeleteArticleStmt(
+        "UPDATE article SET deleted_at = $1 WHERE id = $2 AND deleted_at IS NULL",
+        None,
+    )
+}
+impl DeleteArticleStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub async fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        deleted_at: &'a chrono::DateTime<chrono::FixedOffset>,
+        id: &'a uuid::Uuid,
+    ) -> Result<u64, tokio_postgres::Error> {
+        client.execute(self.0, &[deleted_at, id]).await
+    }
 }
-impl<'c, 'a, 's, C, T: 'c, const N: usize> GetArticleByIdQuery<'c, 'a, 's, C, T, N>
-where
-    C: GenericClient,
+impl<'a, C: GenericClient + Send + Sync>
+    crate::clorinde::client::async_::Params<
+        'a,
+        'a,
+        'a,
+        DeleteArticleParams,
+        std::pin::Pin<
+            Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+        >,
+        C,
+    > for DeleteArticleStmt
 {
-    pub fn map<R>(
-        self,
-        mapper: fn(GetArticleByIdBorrowed) -> R,
-    ) -> GetArticleByIdQuery<'c, 'a, 's, C, R, N> {
-        GetArticleByIdQuery {
-            client: self.client,
-            params: self.params,
-            query: self.query,
-            cached: self.cached,
-            extractor: self.extractor,
-            mapper,
-        }
-    }
-    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
-        let row =
-            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
-        Ok((self.mapper)((self.extractor)(&row)?))
+    fn params(
+        &'a self,
+        client: &'a C,
+        params: &'a DeleteArticleParams,
+    ) -> std::pin::Pin<
+        Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+    > {
+        Box::pin(self.bind(client, &params.deleted_at, &params.id))
     }
-    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
-        self.iter().await?.try_collect().await
+}
+pub struct HideArticleStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn hide_article() -> HideArticleStmt {
+    HideArticleStmt("UPDATE article SET hidden_at = $1 WHERE id = $2", None)
+}
+impl HideArticleStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
     }
-    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
-        let opt_row =
-            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
-        Ok(opt_row
-            .map(|row| {
-                let extracted = (self.extractor)(&row)?;
-                Ok((self.mapper)(extracted))
-            })
-            .transpose()?)
+    pub async fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        hidden_at: &'a chrono::DateTime<chrono::FixedOffset>,
+        id: &'a uuid::Uuid,
+    ) -> Result<u64, tokio_postgres::Error> {
+        client.execute(self.0, &[hidden_at, id]).await
     }
-    pub async fn iter(
-        self,
-    ) -> Result<
-        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
-        tokio_postgres::Error,
+}
+impl<'a, C: GenericClient + Send + Sync>
+    crate::clorinde::client::async_::Params<
+        'a,
+        'a,
+        'a,
+        HideArticleParams,
+        std::pin::Pin<
+            Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+        >,
+        C,
+    > for HideArticleStmt
+{
+    fn params(
+        &'a self,
+        client: &'a C,
+        params: &'a HideArticleParams,
+    ) -> std::pin::Pin<
+        Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
     > {
-        let stream = crate::clorinde::client::async_::raw(
-            self.client,
-            self.query,
-            crate::clorinde::slice_iter(&self.params),
-            self.cached,
-        )
-        .await?;
-        let mapped = stream
-            .map(move |res| {
-                res.and_then(|row| {
-                    let extracted = (self.extractor)(&row)?;
-                    Ok((self.mapper)(extracted))
-                })
-            })
-            .into_stream();
-        Ok(mapped)
+        Box::pin(self.bind(client, &params.hidden_at, &params.id))
     }
 }
-pub struct UpdateArticleQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
-    client: &'c C,
-    params: [&'a (dyn postgres_types::ToSql + Sync); N],
-    query: &'static str,
-    cached: Option<&'s tokio_postgres::Statement>,
-    extractor: fn(&tokio_postgres::Row) -> Result<UpdateArticleBorrowed, tokio_postgres::Error>,
-    mapper: fn(UpdateArticleBorrowed) -> T,
+pub struct FavoriteArticleStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn favorite_article() -> FavoriteArticleStmt {
+    FavoriteArticleStmt(
+        "INSERT INTO article_favorite (appuser_id, article_id, created_at) VALUES ($1, $2, $3) ON CONFLICT DO NOTHING",
+        None,
+    )
 }
-impl<'c, 'a, 's, C, T: 'c, const N: usize> UpdateArticleQuery<'c, 'a, 's, C, T, N>
-where
-    C: GenericClient,
-{
-    pub fn map<R>(
-        self,
-        mapper: fn(UpdateArticleBorrowed) -> R,
-    ) -> UpdateArticleQuery<'c, 'a, 's, C, R, N> {
-        UpdateArticleQuery {
-            client: self.client,
-            params: self.params,
-            query: self.query,
-            cached: self.cached,
-            extractor: self.extractor,
-            mapper,
-        }
+impl FavoriteArticleStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
     }
-    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
-        let row =
-            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
-        Ok((self.mapper)((self.extractor)(&row)?))
+    pub async fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        user_id: &'a uuid::Uuid,
+        article_id: &'a uuid::Uuid,
+        favorited_at: &'a chrono::DateTime<chrono::FixedOffset>,
+    ) -> Result<u64, tokio_postgres::Error> {
+        client
+            .execute(self.0, &[user_id, article_id, favorited_at])
+            .await
     }
-    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
-        self.iter().await?.try_collect().await
+}
+impl<'a, C: GenericClient + Send + Sync>
+    crate::clorinde::client::async_::Params<
+        'a,
+        'a,
+        'a,
+        FavoriteArticleParams,
+        std::pin::Pin<
+            Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+        >,
+        C,
+    > for FavoriteArticleStmt
+{
+    fn params(
+        &'a self,
+        client: &'a C,
+        params: &'a FavoriteArticleParams,
+    ) -> std::pin::Pin<
+        Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+    > {
+        Box::pin(self.bind(
+            client,
+            &params.user_id,
+            &params.article_id,
+            &params.favorited_at,
+        ))
     }
-    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
-        let opt_row =
-            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
-        Ok(opt_row
-            .map(|row| {
-                let extracted = (self.extractor)(&row)?;
-                Ok((self.mapper)(extracted))
-            })
-            .transpose()?)
+}
+pub struct UnfavoriteArticleStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn unfavorite_article() -> UnfavoriteArticleStmt {
+    UnfavoriteArticleStmt(
+        "DELETE FROM article_favorite WHERE appuser_id = $1 AND article_id = $2",
+        None,
+    )
+}
+impl UnfavoriteArticleStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
     }
-    pub async fn iter(
-        self,
-    ) -> Result<
-        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
-        tokio_postgres::Error,
-    > {
-        let stream = crate::clorinde::client::async_::raw(
-            self.client,
-            self.query,
-            crate::clorinde::slice_iter(&self.params),
-            self.cached,
-        )
-        .await?;
-        let mapped = stream
-            .map(move |res| {
-                res.and_then(|row| {
-                    let extracted = (self.extractor)(&row)?;
-                    Ok((self.mapper)(extracted))
-                })
-            })
-            .into_stream();
-        Ok(mapped)
+    pub async fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        user_id: &'a uuid::Uuid,
+        article_id: &'a uuid::Uuid,
+    ) -> Result<u64, tokio_postgres::Error> {
+        client.execute(self.0, &[user_id, article_id]).await
+    }
+}
+impl<'a, C: GenericClient + Send + Sync>
+    crate::clorinde::client::async_::Params<
+        'a,
+        'a,
+        'a,
+        UnfavoriteArticleParams,
+        std::pin::Pin<
+            Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+        >,
+        C,
+    > for UnfavoriteArticleStmt
+{
+    fn params(
+        &'a self,
+        client: &'a C,
+        params: &'a UnfavoriteArticleParams,
+    ) -> std::pin::Pin<
+        Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+    > {
+        Box::pin(self.bind(client, &params.user_id, &params.article_id))
     }
 }
-pub struct BoolQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
-    client: &'c C,
-    params: [&'a (dyn postgres_types::ToSql + Sync); N],
-    query: &'static str,
-    cached: Option<&'s tokio_postgres::Statement>,
-    extractor: fn(&tokio_postgres::Row) -> Result<bool, tokio_postgres::Error>,
-    mapper: fn(bool) -> T,
+pub struct IsFavoritedStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn is_favorited() -> IsFavoritedStmt {
+    IsFavoritedStmt(
+        "SELECT EXISTS( SELECT 1 FROM article_favorite WHERE appuser_id = $1 AND article_id = $2 )",
+        None,
+    )
 }
-impl<'c, 'a, 's, C, T: 'c, const N: usize> BoolQuery<'c, 'a, 's, C, T, N>
-where
-    C: GenericClient,
-{
-    pub fn map<R>(self, mapper: fn(bool) -> R) -> BoolQuery<'c, 'a, 's, C, R, N> {
+impl IsFavoritedStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        user_id: &'a uuid::Uuid,
+        article_id: &'a uuid::Uuid,
+    ) -> BoolQuery<'c, 'a, 's, C, bool, 2> {
         BoolQuery {
-            client: self.client,
-            params: self.params,
-            query: self.query,
-            cached: self.cached,
-            extractor: self.extractor,
-            mapper,
+            client,
+            params: [user_id, article_id],
+            query: self.0,
+            cached: self.1.as_ref(),
+            extractor: |row| Ok(row.try_get(0)?),
+            mapper: |it| it,
         }
     }
-    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
-        let row =
-            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
-        Ok((self.mapper)((self.extractor)(&row)?))
-    }
-    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
-        self.iter().await?.try_collect().await
+}
+impl<'c, 'a, 's, C: GenericClient>
+    crate::clorinde::client::async_::Params<
+        'c,
+        'a,
+        's,
+        IsFavoritedParams,
+        BoolQuery<'c, 'a, 's, C, bool, 2>,
+        C,
+    > for IsFavoritedStmt
+{
+    fn params(
+        &'s self,
+        client: &'c C,
+        params: &'a IsFavoritedParams,
+    ) -> BoolQuery<'c, 'a, 's, C, bool, 2> {
+        self.bind(client, &params.user_id, &params.article_id)
     }
-    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
-        let opt_row =
-            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
-        Ok(opt_row
-            .map(|row| {
-                let extracted = (self.extractor)(&row)?;
-                Ok((self.mapper)(extracted))
-            })
-            .transpose()?)
+}
+pub struct ListFavoritedArticlesStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn list_favorited_articles() -> ListFavoritedArticlesStmt {
+    ListFavoritedArticlesStmt(
+        "SELECT a.*, (SELECT COUNT(*) FROM article_favorite WHERE article_id = a.id) as favorites_count, ARRAY(SELECT t.name FROM tag t JOIN article_tag at ON t.id = at.tag_id WHERE at.article_id = a.id ORDER BY t.name) as tag_list, ARRAY(SELECT ac.appuser_id FROM article_coauthor ac WHERE ac.article_id = a.id AND ac.accepted_at IS NOT NULL ORDER BY ac.accepted_at, ac.appuser_id) as coauthor_ids FROM article_favorite f JOIN article a ON a.id = f.article_id WHERE f.appuser_id = $1 AND a.deleted_at IS NULL ORDER BY f.created_at, a.id",
+        None,
+    )
+}
+impl ListFavoritedArticlesStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
     }
-    pub async fn iter(
-        self,
-    ) -> Result<
-        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
-        tokio_postgres::Error,
-    > {
-        let stream = crate::clorinde::client::async_::raw(
-            self.client,
-            self.query,
-            crate::clorinde::slice_iter(&self.params),
-            self.cached,
-        )
-        .await?;
-        let mapped = stream
-            .map(move |res| {
-                res.and_then(|row| {
-                    let extracted = (self.extractor)(&row)?;
-                    Ok((self.mapper)(extracted))
-                })
-            })
-            .into_stream();
-        Ok(mapped)
+    pub fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        user_id: &'a uuid::Uuid,
+    ) -> ListFavoritedArticlesQuery<'c, 'a, 's, C, ListFavoritedArticles, 1> {
+        ListFavoritedArticlesQuery { client, params: [user_id], query: self.0, cached: self.1.as_ref(), extractor: |row: &tokio_postgres::Row| -> Result<ListFavoritedArticlesBorrowed, tokio_postgres::Error> { Ok(ListFavoritedArticlesBorrowed { id: row.try_get(0)?,
+slug: row.try_get(1)?,
+title: row.try_get(2)?,
+description: row.try_get(3)?,
+body: row.try_get(4)?,
+author_id: row.try_get(5)?,
+created_at: row.try_get(6)?,
+updated_at: row.try_get(7)?,
+status: row.try_get(8)?,
+published_at: row.try_get(9)?,
+canonical_url: row.try_get(10)?,
+meta_description: row.try_get(11)?,
+social_image: row.try_get(12)?,
+deleted_at: row.try_get(13)?,
+hidden_at: row.try_get(14)?,
+favorites_count: row.try_get(15)?,
+tag_list: row.try_get(16)?,
+coauthor_ids: row.try_get(17)?,
+ }) }, mapper: |it| ListFavoritedArticles::from(it), }
     }
 }
-pub struct I64Query<'c, 'a, 's, C: GenericClient, T, const N: usize> {
-    client: &'c C,
-    params: [&'a (dyn postgres_types::ToSql + Sync); N],
-    query: &'static str,
-    cached: Option<&'s tokio_postgres::Statement>,
-    extractor: fn(&tokio_postgres::Row) -> Result<i64, tokio_postgres::Error>,
-    mapper: fn(i64) -> T,
+pub struct GetFavoritesCountStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn get_favorites_count() -> GetFavoritesCountStmt {
+    GetFavoritesCountStmt(
+        "SELECT COUNT(*) as count FROM article_favorite WHERE article_id = $1",
+        None,
+    )
 }
-impl<'c, 'a, 's, C, T: 'c, const N: usize> I64Query<'c, 'a, 's, C, T, N>
-where
-    C: GenericClient,
-{
-    pub fn map<R>(self, mapper: fn(i64) -> R) -> I64Query<'c, 'a, 's, C, R, N> {
+impl GetFavoritesCountStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        article_id: &'a uuid::Uuid,
+    ) -> I64Query<'c, 'a, 's, C, i64, 1> {
         I64Query {
-            client: self.client,
-            params: self.params,
-            query: self.query,
-            cached: self.cached,
-            extractor: self.extractor,
-            mapper,
+            client,
+            params: [article_id],
+            query: self.0,
+            cached: self.1.as_ref(),
+            extractor: |row| Ok(row.try_get(0)?),
+            mapper: |it| it,
         }
     }
-    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
-        let row =
-            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
-        Ok((self.mapper)((self.extractor)(&row)?))
-    }
-    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
-        self.iter().await?.try_collect().await
-    }
-    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
-        let opt_row =
-            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
-        Ok(opt_row
-            .map(|row| {
-                let extracted = (self.extractor)(&row)?;
-                Ok((self.mapper)(extracted))
-            })
-            .transpose()?)
-    }
-    pub async fn iter(
-        self,
-    ) -> Result<
-        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
-        tokio_postgres::Error,
-    > {
-        let stream = crate::clorinde::client::async_::raw(
-            self.client,
-            self.query,
-            crate::clorinde::slice_iter(&self.params),
-            self.cached,
-        )
-        .await?;
-        let mapped = stream
-            .map(move |res| {
-                res.and_then(|row| {
-                    let extracted = (self.extractor)(&row)?;
-                    Ok((self.mapper)(extracted))
-                })
-            })
-            .into_stream();
-        Ok(mapped)
-    }
 }
-pub struct StringQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
-    client: &'c C,
-    params: [&'a (dyn postgres_types::ToSql + Sync); N],
-    query: &'static str,
-    cached: Option<&'s tokio_postgres::Statement>,
-    extractor: fn(&tokio_postgres::Row) -> Result<&str, tokio_postgres::Error>,
-    mapper: fn(&str) -> T,
+pub struct GetTagsStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn get_tags() -> GetTagsStmt {
+    GetTagsStmt("SELECT name FROM tag", None)
 }
-impl<'c, 'a, 's, C, T: 'c, const N: usize> StringQuery<'c, 'a, 's, C, T, N>
-where
-    C: GenericClient,
-{
-    pub fn map<R>(self, mapper: fn(&str) -> R) -> StringQuery<'c, 'a, 's, C, R, N> {
+impl GetTagsStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+    ) -> StringQuery<'c, 'a, 's, C, String, 0> {
         StringQuery {
-            client: self.client,
-            params: self.params,
-            query: self.query,
-            cached: self.cached,
-            extractor: self.extractor,
-            mapper,
+            client,
+            params: [],
+            query: self.0,
+            cached: self.1.as_ref(),
+            extractor: |row| Ok(row.try_get(0)?),
+            mapper: |it| it.into(),
         }
     }
-    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
-        let row =
-            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
-        Ok((self.mapper)((self.extractor)(&row)?))
-    }
-    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
-        self.iter().await?.try_collect().await
+}
+pub struct CreateTagStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn create_tag() -> CreateTagStmt {
+    CreateTagStmt(
+        "INSERT INTO tag (id, name) VALUES ($1, $2) ON CONFLICT (name) DO NOTHING",
+        None,
+    )
+}
+impl CreateTagStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
     }
-    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
-        let opt_row =
-            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
-        Ok(opt_row
-            .map(|row| {
-                let extracted = (self.extractor)(&row)?;
-                Ok((self.mapper)(extracted))
-            })
-            .transpose()?)
+    pub async fn bind<'c, 'a, 's, C: GenericClient, T1: crate::clorinde::StringSql>(
+        &'s self,
+        client: &'c C,
+        id: &'a uuid::Uuid,
+        name: &'a T1,
+    ) -> Result<u64, tokio_postgres::Error> {
+        client.execute(self.0, &[id, name]).await
     }
-    pub async fn iter(
-        self,
-    ) -> Result<
-        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
-        tokio_postgres::Error,
+}
+impl<'a, C: GenericClient + Send + Sync, T1: crate::clorinde::StringSql>
+    crate::clorinde::client::async_::Params<
+        'a,
+        'a,
+        'a,
+        CreateTagParams<T1>,
+        std::pin::Pin<
+            Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+        >,
+        C,
+    > for CreateTagStmt
+{
+    fn params(
+        &'a self,
+        client: &'a C,
+        params: &'a CreateTagParams<T1>,
+    ) -> std::pin::Pin<
+        Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
     > {
-        let stream = crate::clorinde::client::async_::raw(
-            self.client,
-            self.query,
-            crate::clorinde::slice_iter(&self.params),
-            self.cached,
-        )
-        .await?;
-        let mapped = stream
-            .map(move |res| {
-                res.and_then(|row| {
-                    let extracted = (self.extractor)(&row)?;
-                    Ok((self.mapper)(extracted))
-                })
-            })
-            .into_stream();
-        Ok(mapped)
+        Box::pin(self.bind(client, &params.id, &params.name))
     }
 }
-pub struct GetTagByNameQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
-    client: &'c C,
-    params: [&'a (dyn postgres_types::ToSql + Sync); N],
-    query: &'static str,
-    cached: Option<&'s tokio_postgres::Statement>,
-    extractor: fn(&tokio_postgres::Row) -> Result<GetTagByNameBorrowed, tokio_postgres::Error>,
-    mapper: fn(GetTagByNameBorrowed) -> T,
+pub struct GetTagByNameStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn get_tag_by_name() -> GetTagByNameStmt {
+    GetTagByNameStmt("SELECT * FROM tag WHERE name = $1", None)
 }
-impl<'c, 'a, 's, C, T: 'c, const N: usize> GetTagByNameQuery<'c, 'a, 's, C, T, N>
-where
-    C: GenericClient,
-{
-    pub fn map<R>(
-        self,
-        mapper: fn(GetTagByNameBorrowed) -> R,
-    ) -> GetTagByNameQuery<'c, 'a, 's, C, R, N> {
+impl GetTagByNameStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub fn bind<'c, 'a, 's, C: GenericClient, T1: crate::clorinde::StringSql>(
+        &'s self,
+        client: &'c C,
+        name: &'a T1,
+    ) -> GetTagByNameQuery<'c, 'a, 's, C, GetTagByName, 1> {
         GetTagByNameQuery {
-            client: self.client,
-            params: self.params,
-            query: self.query,
-            cached: self.cached,
-            extractor: self.extractor,
-            mapper,
+            client,
+            params: [name],
+            query: self.0,
+            cached: self.1.as_ref(),
+            extractor:
+                |row: &tokio_postgres::Row| -> Result<GetTagByNameBorrowed, tokio_postgres::Error> {
+                    Ok(GetTagByNameBorrowed {
+                        id: row.try_get(0)?,
+                        name: row.try_get(1)?,
+                        created_at: row.try_get(2)?,
+                    })
+                },
+            mapper: |it| GetTagByName::from(it),
         }
     }
-    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
-        let row =
-            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
-        Ok((self.mapper)((self.extractor)(&row)?))
-    }
-    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
-        self.iter().await?.try_collect().await
+}
+pub struct AddTagToArticleStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn add_tag_to_article() -> AddTagToArticleStmt {
+    AddTagToArticleStmt(
+        "INSERT INTO article_tag (article_id, tag_id) VALUES ($1, $2) ON CONFLICT DO NOTHING",
+        None,
+    )
+}
+impl AddTagToArticleStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
     }
-    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
-        let opt_row =
-            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
-        Ok(opt_row
-            .map(|row| {
-                let extracted = (self.extractor)(&row)?;
-                Ok((self.mapper)(extracted))
-            })
-            .transpose()?)
+    pub async fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        article_id: &'a uuid::Uuid,
+        tag_id: &'a uuid::Uuid,
+    ) -> Result<u64, tokio_postgres::Error> {
+        client.execute(self.0, &[article_id, tag_id]).await
     }
-    pub async fn iter(
-        self,
-    ) -> Result<
-        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
-        tokio_postgres::Error,
+}
+impl<'a, C: GenericClient + Send + Sync>
+    crate::clorinde::client::async_::Params<
+        'a,
+        'a,
+        'a,
+        AddTagToArticleParams,
+        std::pin::Pin<
+            Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+        >,
+        C,
+    > for AddTagToArticleStmt
+{
+    fn params(
+        &'a self,
+        client: &'a C,
+        params: &'a AddTagToArticleParams,
+    ) -> std::pin::Pin<
+        Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
     > {
-        let stream = crate::clorinde::client::async_::raw(
-            self.client,
-            self.query,
-            crate::clorinde::slice_iter(&self.params),
-            self.cached,
-        )
-        .await?;
-        let mapped = stream
-            .map(move |res| {
-                res.and_then(|row| {
-                    let extracted = (self.extractor)(&row)?;
-                    Ok((self.mapper)(extracted))
-                })
-            })
-            .into_stream();
-        Ok(mapped)
+        Box::pin(self.bind(client, &params.article_id, &params.tag_id))
     }
 }
-pub struct ListArticlesQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
-    client: &'c C,
-    params: [&'a (dyn postgres_types::ToSql + Sync); N],
-    query: &'static str,
-    cached: Option<&'s tokio_postgres::Statement>,
-    extractor: fn(&tokio_postgres::Row) -> Result<ListArticlesBorrowed, tokio_postgres::Error>,
-    mapper: fn(ListArticlesBorrowed) -> T,
+pub struct RemoveTagsFromArticleStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn remove_tags_from_article() -> RemoveTagsFromArticleStmt {
+    RemoveTagsFromArticleStmt("DELETE FROM article_tag WHERE article_id = $1", None)
 }
-impl<'c, 'a, 's, C, T: 'c, const N: usize> ListArticlesQuery<'c, 'a, 's, C, T, N>
-where
-    C: GenericClient,
-{
-    pub fn map<R>(
-        self,
-        mapper: fn(ListArticlesBorrowed) -> R,
-    ) -> ListArticlesQuery<'c, 'a, 's, C, R, N> {
-        ListArticlesQuery {
-            client: self.client,
-            params: self.params,
-            query: self.query,
-            cached: self.cached,
-            extractor: self.extractor,
-            mapper,
+impl RemoveTagsFromArticleStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub async fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        article_id: &'a uuid::Uuid,
+    ) -> Result<u64, tokio_postgres::Error> {
+        client.execute(self.0, &[article_id]).await
+    }
+}
+pub struct GetArticleTagsStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn get_article_tags() -> GetArticleTagsStmt {
+    GetArticleTagsStmt(
+        "SELECT t.name FROM tag t JOIN article_tag at ON t.id = at.tag_id WHERE at.article_id = $1",
+        None,
+    )
+}
+impl GetArticleTagsStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        article_id: &'a uuid::Uuid,
+    ) -> StringQuery<'c, 'a, 's, C, String, 1> {
+        StringQuery {
+            client,
+            params: [article_id],
+            query: self.0,
+            cached: self.1.as_ref(),
+            extractor: |row| Ok(row.try_get(0)?),
+            mapper: |it| it.into(),
         }
     }
-    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
-        let row =
-            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
-        Ok((self.mapper)((self.extractor)(&row)?))
-    }
-    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
-        self.iter().await?.try_collect().await
+}
+pub struct ListArticlesStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn list_articles() -> ListArticlesStmt {
+    ListArticlesStmt(
+        "SELECT a.id, a.slug, a.title, a.description, a.body, a.author_id, a.created_at, a.updated_at, a.status, a.published_at, u.username as author_username, u.bio as author_bio, u.img as author_image, EXISTS(SELECT 1 FROM appuser_follows WHERE follower_id = $1 AND followee_id = a.author_id) as following_author, EXISTS(SELECT 1 FROM article_favorite WHERE appuser_id = $1 AND article_id = a.id) as favorited, EXISTS(SELECT 1 FROM article_bookmark WHERE appuser_id = $1 AND article_id = a.id) as bookmarked, (SELECT COUNT(*) FROM article_favorite WHERE article_id = a.id) as favorites_count, ARRAY(SELECT t.name FROM tag t JOIN article_tag at ON t.id = at.tag_id WHERE at.article_id = a.id ORDER BY t.name) as tag_list, ARRAY(SELECT rc.kind FROM article_reaction_count rc WHERE rc.article_id = a.id ORDER BY rc.count DESC, rc.kind COLLATE ucs_basic) as reaction_kinds, ARRAY(SELECT rc.count FROM article_reaction_count rc WHERE rc.article_id = a.id ORDER BY rc.count DESC, rc.kind COLLATE ucs_basic) as reaction_counts, ARRAY(SELECT ar.kind FROM article_reaction ar WHERE ar.article_id = a.id AND ar.appuser_id = $1) as viewer_reactions FROM article a JOIN appuser u ON a.author_id = u.id WHERE u.deactivated_at IS NULL AND a.deleted_at IS NULL AND ((a.status = 'published' AND a.hidden_at IS NULL) OR a.author_id = $1) AND NOT EXISTS(SELECT 1 FROM appuser_mutes WHERE muter_id = $1 AND muted_id = a.author_id) AND NOT EXISTS(SELECT 1 FROM appuser_blocks WHERE blocker_id = a.author_id AND blocked_id = $1) AND (cardinality($2::text[]) = 0 OR u.username = ANY($2)) AND (cardinality($3::text[]) = 0 OR ( SELECT COUNT(DISTINCT t.name) FROM article_tag at JOIN tag t ON at.tag_id = t.id WHERE at.article_id = a.id AND t.name = ANY($3) ) >= CASE WHEN $4 THEN cardinality($3) ELSE 1 END) AND NOT EXISTS(SELECT 1 FROM article_tag at JOIN tag t ON at.tag_id = t.id WHERE at.article_id = a.id AND t.name = ANY($5::text[])) AND ($6::text IS NULL OR EXISTS(SELECT 1 FROM article_favorite af JOIN appuser u2 ON af.appuser_id = u2.id WHERE af.article_id = a.id AND u2.username = $6)) AND ($7::timestamptz IS NULL OR a.created_at >= $7) AND ($8::timestamptz IS NULL OR a.created_at < $8) ORDER BY CASE WHEN $9 = 'updated' THEN a.updated_at END DESC, CASE WHEN $9 = 'popular' THEN (SELECT COUNT(*) FROM article_favorite WHERE article_id = a.id) END DESC, CASE WHEN $9 = 'trending' THEN ( SELECT COUNT(*) FROM article_favorite WHERE article_id = a.id AND ($10::timestamptz IS NULL OR created_at >= $10) ) END DESC, COALESCE(a.published_at, a.created_at) DESC, a.id DESC LIMIT $11 OFFSET $12",
+        None,
+    )
+}
+impl ListArticlesStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
     }
-    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
-        let opt_row =
-            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
-        Ok(opt_row
-            .map(|row| {
-                let extracted = (self.extractor)(&row)?;
-                Ok((self.mapper)(extracted))
-            })
-            .transpose()?)
+    pub fn bind<
+        'c,
+        'a,
+        's,
+        C: GenericClient,
+        T1: crate::clorinde::StringSql,
+        T2: crate::clorinde::ArraySql<Item = T1>,
+        T3: crate::clorinde::StringSql,
+        T4: crate::clorinde::ArraySql<Item = T3>,
+        T5: crate::clorinde::StringSql,
+        T6: crate::clorinde::ArraySql<Item = T5>,
+        T7: crate::clorinde::StringSql,
+        T8: crate::clorinde::StringSql,
+    >(
+        &'s self,
+        client: &'c C,
+        viewer_id: &'a uuid::Uuid,
+        authors: &'a T2,
+        tags: &'a T4,
+        match_all_tags: &'a bool,
+        excluded_tags: &'a T6,
+        favorited: &'a T7,
+        since: &'a Option<chrono::DateTime<chrono::FixedOffset>>,
+        until: &'a Option<chrono::DateTime<chrono::FixedOffset>>,
+        sort: &'a T8,
+        trending_since: &'a Option<chrono::DateTime<chrono::FixedOffset>>,
+        limit: &'a i64,
+        offset: &'a i64,
+    ) -> ListArticlesQuery<'c, 'a, 's, C, ListArticles, 12> {
+        ListArticlesQuery {
+            client,
+            params: [
+                viewer_id,
+                authors,
+                tags,
+                match_all_tags,
+                excluded_tags,
+                favorited,
+                since,
+                until,
+                sort,
+                trending_since,
+                limit,
+                offset,
+            ],
+            query: self.0,
+            cached: self.1.as_ref(),
+            extractor:
+                |row: &tokio_postgres::Row| -> Result<ListArticlesBorrowed, tokio_postgres::Error> {
+                    Ok(ListArticlesBorrowed {
+                        id: row.try_get(0)?,
+                        slug: row.try_get(1)?,
+                        title: row.try_get(2)?,
+                        description: row.try_get(3)?,
+                        body: row.try_get(4)?,
+                        author_id: row.try_get(5)?,
+                        created_at: row.try_get(6)?,
+                        updated_at: row.try_get(7)?,
+                        status: row.try_get(8)?,
+                        published_at: row.try_get(9)?,
+                        author_username: row.try_get(10)?,
+                        author_bio: row.try_get(11)?,
+                        author_image: row.try_get(12)?,
+                        following_author: row.try_get(13)?,
+                        favorited: row.try_get(14)?,
+                        bookmarked: row.try_get(15)?,
+                        favorites_count: row.try_get(16)?,
+                        tag_list: row.try_get(17)?,
+                        reaction_kinds: row.try_get(18)?,
+                        reaction_counts: row.try_get(19)?,
+                        viewer_reactions: row.try_get(20)?,
+                    })
+                },
+            mapper: |it| ListArticles::from(it),
+        }
     }
-    pub async fn iter(
-        self,
-    ) -> Result<
-        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
-        tokio_postgres::Error,
-    > {
-        let stream = crate::clorinde::client::async_::raw(
-            self.client,
-            self.query,
-            crate::clorinde::slice_iter(&self.params),
-            self.cached,
+}
+impl<
+    'c,
+    'a,
+    's,
+    C: GenericClient,
+    T1: crate::clorinde::StringSql,
+    T2: crate::clorinde::ArraySql<Item = T1>,
+    T3: crate::clorinde::StringSql,
+    T4: crate::clorinde::ArraySql<Item = T3>,
+    T5: crate::clorinde::StringSql,
+    T6: crate::clorinde::ArraySql<Item = T5>,
+    T7: crate::clorinde::StringSql,
+    T8: crate::clorinde::StringSql,
+>
+    crate::clorinde::client::async_::Params<
+        'c,
+        'a,
+        's,
+        ListArticlesParams<T1, T2, T3, T4, T5, T6, T7, T8>,
+        ListArticlesQuery<'c, 'a, 's, C, ListArticles, 12>,
+        C,
+    > for ListArticlesStmt
+{
+    fn params(
+        &'s self,
+        client: &'c C,
+        params: &'a ListArticlesParams<T1, T2, T3, T4, T5, T6, T7, T8>,
+    ) -> ListArticlesQuery<'c, 'a, 's, C, ListArticles, 12> {
+        self.bind(
+            client,
+            &params.viewer_id,
+            &params.authors,
+            &params.tags,
+            &params.match_all_tags,
+            &params.excluded_tags,
+            &params.favorited,
+            &params.since,
+            &params.until,
+            &params.sort,
+            &params.trending_since,
+            &params.limit,
+            &params.offset,
         )
-        .await?;
-        let mapped = stream
-            .map(move |res| {
-                res.and_then(|row| {
-                    let extracted = (self.extractor)(&row)?;
-                    Ok((self.mapper)(extracted))
-                })
-            })
-            .into_stream();
-        Ok(mapped)
     }
 }
-pub struct FeedArticlesQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
-    client: &'c C,
-    params: [&'a (dyn postgres_types::ToSql + Sync); N],
-    query: &'static str,
-    cached: Option<&'s tokio_postgres::Statement>,
-    extractor: fn(&tokio_postgres::Row) -> Result<FeedArticlesBorrowed, tokio_postgres::Error>,
-    mapper: fn(FeedArticlesBorrowed) -> T,
+pub struct ListArticlesOlderStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn list_articles_older() -> ListArticlesOlderStmt {
+    ListArticlesOlderStmt(
+        "SELECT a.id, a.slug, a.title, a.description, a.body, a.author_id, a.created_at, a.updated_at, a.status, a.published_at, u.username as author_username, u.bio as author_bio, u.img as author_image, EXISTS(SELECT 1 FROM appuser_follows WHERE follower_id = $1 AND followee_id = a.author_id) as following_author, EXISTS(SELECT 1 FROM article_favorite WHERE appuser_id = $1 AND article_id = a.id) as favorited, EXISTS(SELECT 1 FROM article_bookmark WHERE appuser_id = $1 AND article_id = a.id) as bookmarked, (SELECT COUNT(*) FROM article_favorite WHERE article_id = a.id) as favorites_count, ARRAY(SELECT t.name FROM tag t JOIN article_tag at ON t.id = at.tag_id WHERE at.article_id = a.id ORDER BY t.name) as tag_list, ARRAY(SELECT rc.kind FROM article_reaction_count rc WHERE rc.article_id = a.id ORDER BY rc.count DESC, rc.kind COLLATE ucs_basic) as reaction_kinds, ARRAY(SELECT rc.count FROM article_reaction_count rc WHERE rc.article_id = a.id ORDER BY rc.count DESC, rc.kind COLLATE ucs_basic) as reaction_counts, ARRAY(SELECT ar.kind FROM article_reaction ar WHERE ar.article_id = a.id AND ar.appuser_id = $1) as viewer_reactions FROM article a JOIN appuser u ON a.author_id = u.id WHERE u.deactivated_at IS NULL AND a.deleted_at IS NULL AND ((a.status = 'published' AND a.hidden_at IS NULL) OR a.author_id = $1) AND NOT EXISTS(SELECT 1 FROM appuser_mutes WHERE muter_id = $1 AND muted_id = a.author_id) AND NOT EXISTS(SELECT 1 FROM appuser_blocks WHERE blocker_id = a.author_id AND blocked_id = $1) AND (cardinality($2::text[]) = 0 OR u.username = ANY($2)) AND (cardinality($3::text[]) = 0 OR ( SELECT COUNT(DISTINCT t.name) FROM article_tag at JOIN tag t ON at.tag_id = t.id WHERE at.article_id = a.id AND t.name = ANY($3) ) >= CASE WHEN $4 THEN cardinality($3) ELSE 1 END) AND NOT EXISTS(SELECT 1 FROM article_tag at JOIN tag t ON at.tag_id = t.id WHERE at.article_id = a.id AND t.name = ANY($5::text[])) AND ($6::text IS NULL OR EXISTS(SELECT 1 FROM article_favorite af JOIN appuser u2 ON af.appuser_id = u2.id WHERE af.article_id = a.id AND u2.username = $6)) AND ($7::timestamptz IS NULL OR a.created_at >= $7) AND ($8::timestamptz IS NULL OR a.created_at < $8) AND (COALESCE(a.published_at, a.created_at), a.id) < ($9, $10) ORDER BY COALESCE(a.published_at, a.created_at) DESC, a.id DESC LIMIT $11",
+        None,
+    )
 }
-impl<'c, 'a, 's, C, T: 'c, const N: usize> FeedArticlesQuery<'c, 'a, 's, C, T, N>
-where
+impl ListArticlesOlderStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub fn bind<
+        'c,
+        'a,
+        's,
+        C: GenericClient,
+        T1: crate::clorinde::StringSql,
+        T2: crate::clorinde::ArraySql<Item = T1>,
+        T3: crate::clorinde::StringSql,
+        T4: crate::clorinde::ArraySql<Item = T3>,
+        T5: crate::clorinde::StringSql,
+        T6: crate::clorinde::ArraySql<Item = T5>,
+        T7: crate::clorinde::StringSql,
+    >(
+        &'s self,
+        client: &'c C,
+        viewer_id: &'a uuid::Uuid,
+        authors: &'a T2,
+        tags: &'a T4,
+        match_all_tags: &'a bool,
+        excluded_tags: &'a T6,
+        favorited: &'a T7,
+        since: &'a Option<chrono::DateTime<chrono::FixedOffset>>,
+        until: &'a Option<chrono::DateTime<chrono::FixedOffset>>,
+        cursor_listed_at: &'a chrono::DateTime<chrono::FixedOffset>,
+        cursor_id: &'a uuid::Uuid,
+        limit: &'a i64,
+    ) -> ListArticlesOlderQuery<'c, 'a, 's, C, ListArticlesOlder, 11> {
+        ListArticlesOlderQuery { client, params: [viewer_id, authors, tags, match_all_tags, excluded_tags, favorited, since, until, cursor_listed_at, cursor_id, limit], query: self.0, cached: self.1.as_ref(), extractor: |row: &tokio_postgres::Row| -> Result<ListArticlesOlderBorrowed, tokio_postgres::Error> { Ok(ListArticlesOlderBorrowed { id: row.try_get(0)?,
+slug: row.try_get(1)?,
+title: row.try_get(2)?,
+description: row.try_get(3)?,
+body: row.try_get(4)?,
+author_id: row.try_get(5)?,
+created_at: row.try_get(6)?,
+updated_at: row.try_get(7)?,
+status: row.try_get(8)?,
+published_at: row.try_get(9)?,
+author_username: row.try_get(10)?,
+author_bio: row.try_get(11)?,
+author_image: row.try_get(12)?,
+following_author: row.try_get(13)?,
+favorited: row.try_get(14)?,
+bookmarked: row.try_get(15)?,
+favorites_count: row.try_get(16)?,
+tag_list: row.try_get(17)?,
+reaction_kinds: row.try_get(18)?,
+reaction_counts: row.try_get(19)?,
+viewer_reactions: row.try_get(20)?,
+ }) }, mapper: |it| ListArticlesOlder::from(it), }
+    }
+}
+impl<
+    'c,
+    'a,
+    's,
     C: GenericClient,
+    T1: crate::clorinde::StringSql,
+    T2: crate::clorinde::ArraySql<Item = T1>,
+    T3: crate::clorinde::StringSql,
+    T4: crate::clorinde::ArraySql<Item = T3>,
+    T5: crate::clorinde::StringSql,
+    T6: crate::clorinde::ArraySql<Item = T5>,
+    T7: crate::clorinde::StringSql,
+>
+    crate::clorinde::client::async_::Params<
+        'c,
+        'a,
+        's,
+        ListArticlesOlderParams<T1, T2, T3, T4, T5, T6, T7>,
+        ListArticlesOlderQuery<'c, 'a, 's, C, ListArticlesOlder, 11>,
+        C,
+    > for ListArticlesOlderStmt
 {
-    pub fn map<R>(
-        self,
-        mapper: fn(FeedArticlesBorrowed) -> R,
-    ) -> FeedArticlesQuery<'c, 'a, 's, C, R, N> {
-        FeedArticlesQuery {
-            client: self.client,
-            params: self.params,
-            query: self.query,
-            cached: self.cached,
-            extractor: self.extractor,
-            mapper,
-        }
-    }
-    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
-        let row =
-            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
-        Ok((self.mapper)((self.extractor)(&row)?))
+    fn params(
+        &'s self,
+        client: &'c C,
+        params: &'a ListArticlesOlderParams<T1, T2, T3, T4, T5, T6, T7>,
+    ) -> ListArticlesOlderQuery<'c, 'a, 's, C, ListArticlesOlder, 11> {
+        self.bind(
+            client,
+            &params.viewer_id,
+            &params.authors,
+            &params.tags,
+            &params.match_all_tags,
+            &params.excluded_tags,
+            &params.favorited,
+            &params.since,
+            &params.until,
+            &params.cursor_listed_at,
+            &params.cursor_id,
+            &params.limit,
+        )
     }
-    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
-        self.iter().await?.try_collect().await
+}
+pub struct ListArticlesNewerStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn list_articles_newer() -> ListArticlesNewerStmt {
+    ListArticlesNewerStmt(
+        "SELECT a.id, a.slug, a.title, a.description, a.body, a.author_id, a.created_at, a.updated_at, a.status, a.published_at, u.username as author_username, u.bio as author_bio, u.img as author_image, EXISTS(SELECT 1 FROM appuser_follows WHERE follower_id = $1 AND followee_id = a.author_id) as following_author, EXISTS(SELECT 1 FROM article_favorite WHERE appuser_id = $1 AND article_id = a.id) as favorited, EXISTS(SELECT 1 FROM article_bookmark WHERE appuser_id = $1 AND article_id = a.id) as bookmarked, (SELECT COUNT(*) FROM article_favorite WHERE article_id = a.id) as favorites_count, ARRAY(SELECT t.name FROM tag t JOIN article_tag at ON t.id = at.tag_id WHERE at.article_id = a.id ORDER BY t.name) as tag_list, ARRAY(SELECT rc.kind FROM article_reaction_count rc WHERE rc.article_id = a.id ORDER BY rc.count DESC, rc.kind COLLATE ucs_basic) as reaction_kinds, ARRAY(SELECT rc.count FROM article_reaction_count rc WHERE rc.article_id = a.id ORDER BY rc.count DESC, rc.kind COLLATE ucs_basic) as reaction_counts, ARRAY(SELECT ar.kind FROM article_reaction ar WHERE ar.article_id = a.id AND ar.appuser_id = $1) as viewer_reactions FROM article a JOIN appuser u ON a.author_id = u.id WHERE u.deactivated_at IS NULL AND a.deleted_at IS NULL AND ((a.status = 'published' AND a.hidden_at IS NULL) OR a.author_id = $1) AND NOT EXISTS(SELECT 1 FROM appuser_mutes WHERE muter_id = $1 AND muted_id = a.author_id) AND NOT EXISTS(SELECT 1 FROM appuser_blocks WHERE blocker_id = a.author_id AND blocked_id = $1) AND (cardinality($2::text[]) = 0 OR u.username = ANY($2)) AND (cardinality($3::text[]) = 0 OR ( SELECT COUNT(DISTINCT t.name) FROM article_tag at JOIN tag t ON at.tag_id = t.id WHERE at.article_id = a.id AND t.name = ANY($3) ) >= CASE WHEN $4 THEN cardinality($3) ELSE 1 END) AND NOT EXISTS(SELECT 1 FROM article_tag at JOIN tag t ON at.tag_id = t.id WHERE at.article_id = a.id AND t.name = ANY($5::text[])) AND ($6::text IS NULL OR EXISTS(SELECT 1 FROM article_favorite af JOIN appuser u2 ON af.appuser_id = u2.id WHERE af.article_id = a.id AND u2.username = $6)) AND ($7::timestamptz IS NULL OR a.created_at >= $7) AND ($8::timestamptz IS NULL OR a.created_at < $8) AND (COALESCE(a.published_at, a.created_at), a.id) > ($9, $10) ORDER BY COALESCE(a.published_at, a.created_at) ASC, a.id ASC LIMIT $11",
+        None,
+    )
+}
+impl ListArticlesNewerStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
     }
-    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
-        let opt_row =
-            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
-        Ok(opt_row
-            .map(|row| {
-                let extracted = (self.extractor)(&row)?;
-                Ok((self.mapper)(extracted))
-            })
-            .transpose()?)
+    pub fn bind<
+        'c,
+        'a,
+        's,
+        C: GenericClient,
+        T1: crate::clorinde::StringSql,
+        T2: crate::clorinde::ArraySql<Item = T1>,
+        T3: crate::clorinde::StringSql,
+        T4: crate::clorinde::ArraySql<Item = T3>,
+        T5: crate::clorinde::StringSql,
+        T6: crate::clorinde::ArraySql<Item = T5>,
+        T7: crate::clorinde::StringSql,
+    >(
+        &'s self,
+        client: &'c C,
+        viewer_id: &'a uuid::Uuid,
+        authors: &'a T2,
+        tags: &'a T4,
+        match_all_tags: &'a bool,
+        excluded_tags: &'a T6,
+        favorited: &'a T7,
+        since: &'a Option<chrono::DateTime<chrono::FixedOffset>>,
+        until: &'a Option<chrono::DateTime<chrono::FixedOffset>>,
+        cursor_listed_at: &'a chrono::DateTime<chrono::FixedOffset>,
+        cursor_id: &'a uuid::Uuid,
+        limit: &'a i64,
+    ) -> ListArticlesNewerQuery<'c, 'a, 's, C, ListArticlesNewer, 11> {
+        ListArticlesNewerQuery { client, params: [viewer_id, authors, tags, match_all_tags, excluded_tags, favorited, since, until, cursor_listed_at, cursor_id, limit], query: self.0, cached: self.1.as_ref(), extractor: |row: &tokio_postgres::Row| -> Result<ListArticlesNewerBorrowed, tokio_postgres::Error> { Ok(ListArticlesNewerBorrowed { id: row.try_get(0)?,
+slug: row.try_get(1)?,
+title: row.try_get(2)?,
+description: row.try_get(3)?,
+body: row.try_get(4)?,
+author_id: row.try_get(5)?,
+created_at: row.try_get(6)?,
+updated_at: row.try_get(7)?,
+status: row.try_get(8)?,
+published_at: row.try_get(9)?,
+author_username: row.try_get(10)?,
+author_bio: row.try_get(11)?,
+author_image: row.try_get(12)?,
+following_author: row.try_get(13)?,
+favorited: row.try_get(14)?,
+bookmarked: row.try_get(15)?,
+favorites_count: row.try_get(16)?,
+tag_list: row.try_get(17)?,
+reaction_kinds: row.try_get(18)?,
+reaction_counts: row.try_get(19)?,
+viewer_reactions: row.try_get(20)?,
+ }) }, mapper: |it| ListArticlesNewer::from(it), }
     }
-    pub async fn iter(
-        self,
-    ) -> Result<
-        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
-        tokio_postgres::Error,
-    > {
-        let stream = crate::clorinde::client::async_::raw(
-            self.client,
-            self.query,
-            crate::clorinde::slice_iter(&self.params),
-            self.cached,
-        )
-        .await?;
-        let mapped = stream
-            .map(move |res| {
-                res.and_then(|row| {
-                    let extracted = (self.extractor)(&row)?;
-                    Ok((self.mapper)(extracted))
-                })
-            })
-            .into_stream();
-        Ok(mapped)
+}
+impl<
+    'c,
+    'a,
+    's,
+    C: GenericClient,
+    T1: crate::clorinde::StringSql,
+    T2: crate::clorinde::ArraySql<Item = T1>,
+    T3: crate::clorinde::StringSql,
+    T4: crate::clorinde::ArraySql<Item = T3>,
+    T5: crate::clorinde::StringSql,
+    T6: crate::clorinde::ArraySql<Item = T5>,
+    T7: crate::clorinde::StringSql,
+>
+    crate::clorinde::client::async_::Params<
+        'c,
+        'a,
+        's,
+        ListArticlesNewerParams<T1, T2, T3, T4, T5, T6, T7>,
+        ListArticlesNewerQuery<'c, 'a, 's, C, ListArticlesNewer, 11>,
+        C,
+    > for ListArticlesNewerStmt
+{
+    fn params(
+        &'s self,
+        client: &'c C,
+        params: &'a ListArticlesNewerParams<T1, T2, T3, T4, T5, T6, T7>,
+    ) -> ListArticlesNewerQuery<'c, 'a, 's, C, ListArticlesNewer, 11> {
+        self.bind(
+            client,
+            &params.viewer_id,
+            &params.authors,
+            &params.tags,
+            &params.match_all_tags,
+            &params.excluded_tags,
+            &params.favorited,
+            &params.since,
+            &params.until,
+            &params.cursor_listed_at,
+            &params.cursor_id,
+            &params.limit,
+        )
     }
 }
-pub struct CreateArticleStmt(&'static str, Option<tokio_postgres::Statement>);
-pub fn create_article() -> CreateArticleStmt {
-    CreateArticleStmt(
-        "INSERT INTO article (id, slug, title, description, body, author_id, created_at, updated_at) VALUES ($1, $2, $3, $4, $5, $6, $7, $7) RETURNING *",
+pub struct CountArticlesStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn count_articles() -> CountArticlesStmt {
+    CountArticlesStmt(
+        "SELECT COUNT(*) FROM article a JOIN appuser u ON a.author_id = u.id WHERE u.deactivated_at IS NULL AND a.deleted_at IS NULL AND ((a.status = 'published' AND a.hidden_at IS NULL) OR a.author_id = $1) AND NOT EXISTS(SELECT 1 FROM appuser_mutes WHERE muter_id = $1 AND muted_id = a.author_id) AND NOT EXISTS(SELECT 1 FROM appuser_blocks WHERE blocker_id = a.author_id AND blocked_id = $1) AND (cardinality($2::text[]) = 0 OR u.username = ANY($2)) AND (cardinality($3::text[]) = 0 OR ( SELECT COUNT(DISTINCT t.name) FROM article_tag at JOIN tag t ON at.tag_id = t.id WHERE at.article_id = a.id AND t.name = ANY($3) ) >= CASE WHEN $4 THEN cardinality($3) ELSE 1 END) AND NOT EXISTS(SELECT 1 FROM article_tag at JOIN tag t ON at.tag_id = t.id WHERE at.article_id = a.id AND t.name = ANY($5::text[])) AND ($6::text IS NULL OR EXISTS(SELECT 1 FROM article_favorite af JOIN appuser u2 ON af.appuser_id = u2.id WHERE af.article_id = a.id AND u2.username = $6)) AND ($7::timestamptz IS NULL OR a.created_at >= $7) AND ($8::timestamptz IS NULL OR a.created_at < $8)",
         None,
     )
 }
-impl CreateArticleStmt {
+impl CountArticlesStmt {
     pub async fn prepare<'a, C: GenericClient>(
         mut self,
         client: &'a C,
@@ -1133,28 +5445,113 @@ impl CreateArticleStmt {
         's,
         C: GenericClient,
         T1: crate::clorinde::StringSql,
-        T2: crate::clorinde::StringSql,
+        T2: crate::clorinde::ArraySql<Item = T1>,
         T3: crate::clorinde::StringSql,
-        T4: crate::clorinde::StringSql,
+        T4: crate::clorinde::ArraySql<Item = T3>,
+        T5: crate::clorinde::StringSql,
+        T6: crate::clorinde::ArraySql<Item = T5>,
+        T7: crate::clorinde::StringSql,
     >(
         &'s self,
         client: &'c C,
-        id: &'a uuid::Uuid,
-        slug: &'a T1,
-        title: &'a T2,
-        description: &'a T3,
-        body: &'a T4,
-        author_id: &'a uuid::Uuid,
-        created_at: &'a chrono::DateTime<chrono::FixedOffset>,
-    ) -> CreateArticleQuery<'c, 'a, 's, C, CreateArticle, 7> {
-        CreateArticleQuery {
+        viewer_id: &'a uuid::Uuid,
+        authors: &'a T2,
+        tags: &'a T4,
+        match_all_tags: &'a bool,
+        excluded_tags: &'a T6,
+        favorited: &'a T7,
+        since: &'a Option<chrono::DateTime<chrono::FixedOffset>>,
+        until: &'a Option<chrono::DateTime<chrono::FixedOffset>>,
+    ) -> I64Query<'c, 'a, 's, C, i64, 8> {
+        I64Query {
+            client,
+            params: [
+                viewer_id,
+                authors,
+                tags,
+                match_all_tags,
+                excluded_tags,
+                favorited,
+                since,
+                until,
+            ],
+            query: self.0,
+            cached: self.1.as_ref(),
+            extractor: |row| Ok(row.try_get(0)?),
+            mapper: |it| it,
+        }
+    }
+}
+impl<
+    'c,
+    'a,
+    's,
+    C: GenericClient,
+    T1: crate::clorinde::StringSql,
+    T2: crate::clorinde::ArraySql<Item = T1>,
+    T3: crate::clorinde::StringSql,
+    T4: crate::clorinde::ArraySql<Item = T3>,
+    T5: crate::clorinde::StringSql,
+    T6: crate::clorinde::ArraySql<Item = T5>,
+    T7: crate::clorinde::StringSql,
+>
+    crate::clorinde::client::async_::Params<
+        'c,
+        'a,
+        's,
+        CountArticlesParams<T1, T2, T3, T4, T5, T6, T7>,
+        I64Query<'c, 'a, 's, C, i64, 8>,
+        C,
+    > for CountArticlesStmt
+{
+    fn params(
+        &'s self,
+        client: &'c C,
+        params: &'a CountArticlesParams<T1, T2, T3, T4, T5, T6, T7>,
+    ) -> I64Query<'c, 'a, 's, C, i64, 8> {
+        self.bind(
+            client,
+            &params.viewer_id,
+            &params.authors,
+            &params.tags,
+            &params.match_all_tags,
+            &params.excluded_tags,
+            &params.favorited,
+            &params.since,
+            &params.until,
+        )
+    }
+}
+pub struct FeedArticlesStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn feed_articles() -> FeedArticlesStmt {
+    FeedArticlesStmt(
+        "SELECT a.id, a.slug, a.title, a.description, a.body, a.author_id, a.created_at, a.updated_at, a.status, a.published_at, u.username as author_username, u.bio as author_bio, u.img as author_image, EXISTS(SELECT 1 FROM appuser_follows WHERE follower_id = $1 AND followee_id = a.author_id) as following_author, EXISTS(SELECT 1 FROM article_favorite WHERE appuser_id = $1 AND article_id = a.id) as favorited, EXISTS(SELECT 1 FROM article_bookmark WHERE appuser_id = $1 AND article_id = a.id) as bookmarked, (SELECT COUNT(*) FROM article_favorite WHERE article_id = a.id) as favorites_count, ARRAY(SELECT t.name FROM tag t JOIN article_tag at ON t.id = at.tag_id WHERE at.article_id = a.id ORDER BY t.name) as tag_list, ARRAY(SELECT rc.kind FROM article_reaction_count rc WHERE rc.article_id = a.id ORDER BY rc.count DESC, rc.kind COLLATE ucs_basic) as reaction_kinds, ARRAY(SELECT rc.count FROM article_reaction_count rc WHERE rc.article_id = a.id ORDER BY rc.count DESC, rc.kind COLLATE ucs_basic) as reaction_counts, ARRAY(SELECT ar.kind FROM article_reaction ar WHERE ar.article_id = a.id AND ar.appuser_id = $1) as viewer_reactions, ARRAY(SELECT t.name FROM tag t JOIN article_tag at ON t.id = at.tag_id JOIN tag_follow tf ON tf.tag_id = at.tag_id AND tf.appuser_id = $1 WHERE at.article_id = a.id ORDER BY t.name) as followed_tags, EXISTS(SELECT 1 FROM article_coauthor ac JOIN appuser_follows f ON f.followee_id = ac.appuser_id AND f.follower_id = $1 WHERE ac.article_id = a.id AND ac.accepted_at IS NOT NULL) as following_coauthor FROM article a JOIN appuser u ON a.author_id = u.id WHERE (EXISTS(SELECT 1 FROM appuser_follows WHERE follower_id = $1 AND followee_id = a.author_id) OR EXISTS(SELECT 1 FROM article_coauthor ac JOIN appuser_follows f ON f.followee_id = ac.appuser_id AND f.follower_id = $1 WHERE ac.article_id = a.id AND ac.accepted_at IS NOT NULL) OR EXISTS(SELECT 1 FROM article_tag at JOIN tag_follow tf ON tf.tag_id = at.tag_id WHERE at.article_id = a.id AND tf.appuser_id = $1)) AND a.status = 'published' AND a.hidden_at IS NULL AND u.deactivated_at IS NULL AND a.deleted_at IS NULL AND NOT EXISTS(SELECT 1 FROM appuser_mutes WHERE muter_id = $1 AND muted_id = a.author_id) AND NOT EXISTS(SELECT 1 FROM appuser_blocks WHERE blocker_id = a.author_id AND blocked_id = $1) ORDER BY COALESCE(a.published_at, a.created_at) DESC, a.id DESC LIMIT $2 OFFSET $3",
+        None,
+    )
+}
+impl FeedArticlesStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        viewer_id: &'a uuid::Uuid,
+        limit: &'a i64,
+        offset: &'a i64,
+    ) -> FeedArticlesQuery<'c, 'a, 's, C, FeedArticles, 3> {
+        FeedArticlesQuery {
             client,
-            params: [id, slug, title, description, body, author_id, created_at],
+            params: [viewer_id, limit, offset],
             query: self.0,
             cached: self.1.as_ref(),
             extractor:
-                |row: &tokio_postgres::Row| -> Result<CreateArticleBorrowed, tokio_postgres::Error> {
-                    Ok(CreateArticleBorrowed {
+                |row: &tokio_postgres::Row| -> Result<FeedArticlesBorrowed, tokio_postgres::Error> {
+                    Ok(FeedArticlesBorrowed {
                         id: row.try_get(0)?,
                         slug: row.try_get(1)?,
                         title: row.try_get(2)?,
@@ -1163,56 +5560,294 @@ impl CreateArticleStmt {
                         author_id: row.try_get(5)?,
                         created_at: row.try_get(6)?,
                         updated_at: row.try_get(7)?,
+                        status: row.try_get(8)?,
+                        published_at: row.try_get(9)?,
+                        author_username: row.try_get(10)?,
+                        author_bio: row.try_get(11)?,
+                        author_image: row.try_get(12)?,
+                        following_author: row.try_get(13)?,
+                        favorited: row.try_get(14)?,
+                        bookmarked: row.try_get(15)?,
+                        favorites_count: row.try_get(16)?,
+                        tag_list: row.try_get(17)?,
+                        reaction_kinds: row.try_get(18)?,
+                        reaction_counts: row.try_get(19)?,
+                        viewer_reactions: row.try_get(20)?,
+                        followed_tags: row.try_get(21)?,
+                        following_coauthor: row.try_get(22)?,
                     })
                 },
-            mapper: |it| CreateArticle::from(it),
+            mapper: |it| FeedArticles::from(it),
         }
     }
 }
-impl<
-    'c,
-    'a,
-    's,
-    C: GenericClient,
-    T1: crate::clorinde::StringSql,
-    T2: crate::clorinde::StringSql,
-    T3: crate::clorinde::StringSql,
-    T4: crate::clorinde::StringSql,
->
+impl<'c, 'a, 's, C: GenericClient>
     crate::clorinde::client::async_::Params<
         'c,
         'a,
         's,
-        CreateArticleParams<T1, T2, T3, T4>,
-        CreateArticleQuery<'c, 'a, 's, C, CreateArticle, 7>,
+        FeedArticlesParams,
+        FeedArticlesQuery<'c, 'a, 's, C, FeedArticles, 3>,
         C,
-    > for CreateArticleStmt
+    > for FeedArticlesStmt
+{
+    fn params(
+        &'s self,
+        client: &'c C,
+        params: &'a FeedArticlesParams,
+    ) -> FeedArticlesQuery<'c, 'a, 's, C, FeedArticles, 3> {
+        self.bind(client, &params.viewer_id, &params.limit, &params.offset)
+    }
+}
+pub struct FeedArticlesOlderStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn feed_articles_older() -> FeedArticlesOlderStmt {
+    FeedArticlesOlderStmt(
+        "SELECT a.id, a.slug, a.title, a.description, a.body, a.author_id, a.created_at, a.updated_at, a.status, a.published_at, u.username as author_username, u.bio as author_bio, u.img as author_image, EXISTS(SELECT 1 FROM appuser_follows WHERE follower_id = $1 AND followee_id = a.author_id) as following_author, EXISTS(SELECT 1 FROM article_favorite WHERE appuser_id = $1 AND article_id = a.id) as favorited, EXISTS(SELECT 1 FROM article_bookmark WHERE appuser_id = $1 AND article_id = a.id) as bookmarked, (SELECT COUNT(*) FROM article_favorite WHERE article_id = a.id) as favorites_count, ARRAY(SELECT t.name FROM tag t JOIN article_tag at ON t.id = at.tag_id WHERE at.article_id = a.id ORDER BY t.name) as tag_list, ARRAY(SELECT rc.kind FROM article_reaction_count rc WHERE rc.article_id = a.id ORDER BY rc.count DESC, rc.kind COLLATE ucs_basic) as reaction_kinds, ARRAY(SELECT rc.count FROM article_reaction_count rc WHERE rc.article_id = a.id ORDER BY rc.count DESC, rc.kind COLLATE ucs_basic) as reaction_counts, ARRAY(SELECT ar.kind FROM article_reaction ar WHERE ar.article_id = a.id AND ar.appuser_id = $1) as viewer_reactions, ARRAY(SELECT t.name FROM tag t JOIN article_tag at ON t.id = at.tag_id JOIN tag_follow tf ON tf.tag_id = at.tag_id AND tf.appuser_id = $1 WHERE at.article_id = a.id ORDER BY t.name) as followed_tags, EXISTS(SELECT 1 FROM article_coauthor ac JOIN appuser_follows f ON f.followee_id = ac.appuser_id AND f.follower_id = $1 WHERE ac.article_id = a.id AND ac.accepted_at IS NOT NULL) as following_coauthor FROM article a JOIN appuser u ON a.author_id = u.id WHERE (EXISTS(SELECT 1 FROM appuser_follows WHERE follower_id = $1 AND followee_id = a.author_id) OR EXISTS(SELECT 1 FROM article_coauthor ac JOIN appuser_follows f ON f.followee_id = ac.appuser_id AND f.follower_id = $1 WHERE ac.article_id = a.id AND ac.accepted_at IS NOT NULL) OR EXISTS(SELECT 1 FROM article_tag at JOIN tag_follow tf ON tf.tag_id = at.tag_id WHERE at.article_id = a.id AND tf.appuser_id = $1)) AND a.status = 'published' AND a.hidden_at IS NULL AND u.deactivated_at IS NULL AND a.deleted_at IS NULL AND NOT EXISTS(SELECT 1 FROM appuser_mutes WHERE muter_id = $1 AND muted_id = a.author_id) AND NOT EXISTS(SELECT 1 FROM appuser_blocks WHERE blocker_id = a.author_id AND blocked_id = $1) AND (COALESCE(a.published_at, a.created_at), a.id) < ($2, $3) ORDER BY COALESCE(a.published_at, a.created_at) DESC, a.id DESC LIMIT $4",
+        None,
+    )
+}
+impl FeedArticlesOlderStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        viewer_id: &'a uuid::Uuid,
+        cursor_listed_at: &'a chrono::DateTime<chrono::FixedOffset>,
+        cursor_id: &'a uuid::Uuid,
+        limit: &'a i64,
+    ) -> FeedArticlesOlderQuery<'c, 'a, 's, C, FeedArticlesOlder, 4> {
+        FeedArticlesOlderQuery { client, params: [viewer_id, cursor_listed_at, cursor_id, limit], query: self.0, cached: self.1.as_ref(), extractor: |row: &tokio_postgres::Row| -> Result<FeedArticlesOlderBorrowed, tokio_postgres::Error> { Ok(FeedArticlesOlderBorrowed { id: row.try_get(0)?,
+slug: row.try_get(1)?,
+title: row.try_get(2)?,
+description: row.try_get(3)?,
+body: row.try_get(4)?,
+author_id: row.try_get(5)?,
+created_at: row.try_get(6)?,
+updated_at: row.try_get(7)?,
+status: row.try_get(8)?,
+published_at: row.try_get(9)?,
+author_username: row.try_get(10)?,
+author_bio: row.try_get(11)?,
+author_image: row.try_get(12)?,
+following_author: row.try_get(13)?,
+favorited: row.try_get(14)?,
+bookmarked: row.try_get(15)?,
+favorites_count: row.try_get(16)?,
+tag_list: row.try_get(17)?,
+reaction_kinds: row.try_get(18)?,
+reaction_counts: row.try_get(19)?,
+viewer_reactions: row.try_get(20)?,
+followed_tags: row.try_get(21)?,
+following_coauthor: row.try_get(22)?,
+ }) }, mapper: |it| FeedArticlesOlder::from(it), }
+    }
+}
+impl<'c, 'a, 's, C: GenericClient>
+    crate::clorinde::client::async_::Params<
+        'c,
+        'a,
+        's,
+        FeedArticlesOlderParams,
+        FeedArticlesOlderQuery<'c, 'a, 's, C, FeedArticlesOlder, 4>,
+        C,
+    > for FeedArticlesOlderStmt
 {
     fn params(
         &'s self,
         client: &'c C,
-        params: &'a CreateArticleParams<T1, T2, T3, T4>,
-    ) -> CreateArticleQuery<'c, 'a, 's, C, CreateArticle, 7> {
+        params: &'a FeedArticlesOlderParams,
+    ) -> FeedArticlesOlderQuery<'c, 'a, 's, C, FeedArticlesOlder, 4> {
         self.bind(
             client,
-            &params.id,
-            &params.slug,
-            &params.title,
-            &params.description,
-            &params.body,
-            &params.author_id,
-            &params.created_at,
+            &params.viewer_id,
+            &params.cursor_listed_at,
+            &params.cursor_id,
+            &params.limit,
+        )
+    }
+}
+pub struct FeedArticlesNewerStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn feed_articles_newer() -> FeedArticlesNewerStmt {
+    FeedArticlesNewerStmt(
+        "SELECT a.id, a.slug, a.title, a.description, a.body, a.author_id, a.created_at, a.updated_at, a.status, a.published_at, u.username as author_username, u.bio as author_bio, u.img as author_image, EXISTS(SELECT 1 FROM appuser_follows WHERE follower_id = $1 AND followee_id = a.author_id) as following_author, EXISTS(SELECT 1 FROM article_favorite WHERE appuser_id = $1 AND article_id = a.id) as favorited, EXISTS(SELECT 1 FROM article_bookmark WHERE appuser_id = $1 AND article_id = a.id) as bookmarked, (SELECT COUNT(*) FROM article_favorite WHERE article_id = a.id) as favorites_count, ARRAY(SELECT t.name FROM tag t JOIN article_tag at ON t.id = at.tag_id WHERE at.article_id = a.id ORDER BY t.name) as tag_list, ARRAY(SELECT rc.kind FROM article_reaction_count rc WHERE rc.article_id = a.id ORDER BY rc.count DESC, rc.kind COLLATE ucs_basic) as reaction_kinds, ARRAY(SELECT rc.count FROM article_reaction_count rc WHERE rc.article_id = a.id ORDER BY rc.count DESC, rc.kind COLLATE ucs_basic) as reaction_counts, ARRAY(SELECT ar.kind FROM article_reaction ar WHERE ar.article_id = a.id AND ar.appuser_id = $1) as viewer_reactions, ARRAY(SELECT t.name FROM tag t JOIN article_tag at ON t.id = at.tag_id JOIN tag_follow tf ON tf.tag_id = at.tag_id AND tf.appuser_id = $1 WHERE at.article_id = a.id ORDER BY t.name) as followed_tags, EXISTS(SELECT 1 FROM article_coauthor ac JOIN appuser_follows f ON f.followee_id = ac.appuser_id AND f.follower_id = $1 WHERE ac.article_id = a.id AND ac.accepted_at IS NOT NULL) as following_coauthor FROM article a JOIN appuser u ON a.author_id = u.id WHERE (EXISTS(SELECT 1 FROM appuser_follows WHERE follower_id = $1 AND followee_id = a.author_id) OR EXISTS(SELECT 1 FROM article_coauthor ac JOIN appuser_follows f ON f.followee_id = ac.appuser_id AND f.follower_id = $1 WHERE ac.article_id = a.id AND ac.accepted_at IS NOT NULL) OR EXISTS(SELECT 1 FROM article_tag at JOIN tag_follow tf ON tf.tag_id = at.tag_id WHERE at.article_id = a.id AND tf.appuser_id = $1)) AND a.status = 'published' AND a.hidden_at IS NULL AND u.deactivated_at IS NULL AND a.deleted_at IS NULL AND NOT EXISTS(SELECT 1 FROM appuser_mutes WHERE muter_id = $1 AND muted_id = a.author_id) AND NOT EXISTS(SELECT 1 FROM appuser_blocks WHERE blocker_id = a.author_id AND blocked_id = $1) AND (COALESCE(a.published_at, a.created_at), a.id) > ($2, $3) ORDER BY COALESCE(a.published_at, a.created_at) ASC, a.id ASC LIMIT $4",
+        None,
+    )
+}
+impl FeedArticlesNewerStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        viewer_id: &'a uuid::Uuid,
+        cursor_listed_at: &'a chrono::DateTime<chrono::FixedOffset>,
+        cursor_id: &'a uuid::Uuid,
+        limit: &'a i64,
+    ) -> FeedArticlesNewerQuery<'c, 'a, 's, C, FeedArticlesNewer, 4> {
+        FeedArticlesNewerQuery { client, params: [viewer_id, cursor_listed_at, cursor_id, limit], query: self.0, cached: self.1.as_ref(), extractor: |row: &tokio_postgres::Row| -> Result<FeedArticlesNewerBorrowed, tokio_postgres::Error> { Ok(FeedArticlesNewerBorrowed { id: row.try_get(0)?,
+slug: row.try_get(1)?,
+title: row.try_get(2)?,
+description: row.try_get(3)?,
+body: row.try_get(4)?,
+author_id: row.try_get(5)?,
+created_at: row.try_get(6)?,
+updated_at: row.try_get(7)?,
+status: row.try_get(8)?,
+published_at: row.try_get(9)?,
+author_username: row.try_get(10)?,
+author_bio: row.try_get(11)?,
+author_image: row.try_get(12)?,
+following_author: row.try_get(13)?,
+favorited: row.try_get(14)?,
+bookmarked: row.try_get(15)?,
+favorites_count: row.try_get(16)?,
+tag_list: row.try_get(17)?,
+reaction_kinds: row.try_get(18)?,
+reaction_counts: row.try_get(19)?,
+viewer_reactions: row.try_get(20)?,
+followed_tags: row.try_get(21)?,
+following_coauthor: row.try_get(22)?,
+ }) }, mapper: |it| FeedArticlesNewer::from(it), }
+    }
+}
+impl<'c, 'a, 's, C: GenericClient>
+    crate::clorinde::client::async_::Params<
+        'c,
+        'a,
+        's,
+        FeedArticlesNewerParams,
+        FeedArticlesNewerQuery<'c, 'a, 's, C, FeedArticlesNewer, 4>,
+        C,
+    > for FeedArticlesNewerStmt
+{
+    fn params(
+        &'s self,
+        client: &'c C,
+        params: &'a FeedArticlesNewerParams,
+    ) -> FeedArticlesNewerQuery<'c, 'a, 's, C, FeedArticlesNewer, 4> {
+        self.bind(
+            client,
+            &params.viewer_id,
+            &params.cursor_listed_at,
+            &params.cursor_id,
+            &params.limit,
         )
     }
 }
-pub struct GetArticleBySlugStmt(&'static str, Option<tokio_postgres::Statement>);
-pub fn get_article_by_slug() -> GetArticleBySlugStmt {
-    GetArticleBySlugStmt(
-        "SELECT a.*, (SELECT COUNT(*) FROM article_favorite WHERE article_id = a.id) as favorites_count, ARRAY(SELECT t.name FROM tag t JOIN article_tag at ON t.id = at.tag_id WHERE at.article_id = a.id ORDER BY t.name) as tag_list FROM article a WHERE a.slug = $1",
-        None,
-    )
+pub struct CountFeedArticlesStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn count_feed_articles() -> CountFeedArticlesStmt {
+    CountFeedArticlesStmt(
+        "SELECT COUNT(*) FROM article a JOIN appuser u ON a.author_id = u.id WHERE (EXISTS(SELECT 1 FROM appuser_follows WHERE follower_id = $1 AND followee_id = a.author_id) OR EXISTS(SELECT 1 FROM article_coauthor ac JOIN appuser_follows f ON f.followee_id = ac.appuser_id AND f.follower_id = $1 WHERE ac.article_id = a.id AND ac.accepted_at IS NOT NULL) OR EXISTS(SELECT 1 FROM article_tag at JOIN tag_follow tf ON tf.tag_id = at.tag_id WHERE at.article_id = a.id AND tf.appuser_id = $1)) AND a.status = 'published' AND a.hidden_at IS NULL AND u.deactivated_at IS NULL AND a.deleted_at IS NULL AND NOT EXISTS(SELECT 1 FROM appuser_mutes WHERE muter_id = $1 AND muted_id = a.author_id) AND NOT EXISTS(SELECT 1 FROM appuser_blocks WHERE blocker_id = a.author_id AND blocked_id = $1)",
+        None,
+    )
+}
+impl CountFeedArticlesStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        viewer_id: &'a uuid::Uuid,
+    ) -> I64Query<'c, 'a, 's, C, i64, 1> {
+        I64Query {
+            client,
+            params: [viewer_id],
+            query: self.0,
+            cached: self.1.as_ref(),
+            extractor: |row| Ok(row.try_get(0)?),
+            mapper: |it| it,
+        }
+    }
+}
+pub struct DeleteArticlesByAuthorStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn delete_articles_by_author() -> DeleteArticlesByAuthorStmt {
+    DeleteArticlesByAuthorStmt("DELETE FROM article WHERE author_id = $1", None)
+}
+impl DeleteArticlesByAuthorStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub async fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        author_id: &'a uuid::Uuid,
+    ) -> Result<u64, tokio_postgres::Error> {
+        client.execute(self.0, &[author_id]).await
+    }
+}
+pub struct ReassignArticlesStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn reassign_articles() -> ReassignArticlesStmt {
+    ReassignArticlesStmt(
+        "UPDATE article SET author_id = $1 WHERE author_id = $2",
+        None,
+    )
+}
+impl ReassignArticlesStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub async fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        to_author_id: &'a uuid::Uuid,
+        from_author_id: &'a uuid::Uuid,
+    ) -> Result<u64, tokio_postgres::Error> {
+        client
+            .execute(self.0, &[to_author_id, from_author_id])
+            .await
+    }
+}
+impl<'a, C: GenericClient + Send + Sync>
+    crate::clorinde::client::async_::Params<
+        'a,
+        'a,
+        'a,
+        ReassignArticlesParams,
+        std::pin::Pin<
+            Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+        >,
+        C,
+    > for ReassignArticlesStmt
+{
+    fn params(
+        &'a self,
+        client: &'a C,
+        params: &'a ReassignArticlesParams,
+    ) -> std::pin::Pin<
+        Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+    > {
+        Box::pin(self.bind(client, &params.to_author_id, &params.from_author_id))
+    }
+}
+pub struct RemoveFavoritesByUserStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn remove_favorites_by_user() -> RemoveFavoritesByUserStmt {
+    RemoveFavoritesByUserStmt("DELETE FROM article_favorite WHERE appuser_id = $1", None)
 }
-impl GetArticleBySlugStmt {
+impl RemoveFavoritesByUserStmt {
     pub async fn prepare<'a, C: GenericClient>(
         mut self,
         client: &'a C,
@@ -1220,44 +5855,22 @@ impl GetArticleBySlugStmt {
         self.1 = Some(client.prepare(self.0).await?);
         Ok(self)
     }
-    pub fn bind<'c, 'a, 's, C: GenericClient, T1: crate::clorinde::StringSql>(
+    pub async fn bind<'c, 'a, 's, C: GenericClient>(
         &'s self,
         client: &'c C,
-        slug: &'a T1,
-    ) -> GetArticleBySlugQuery<'c, 'a, 's, C, GetArticleBySlug, 1> {
-        GetArticleBySlugQuery {
-            client,
-            params: [slug],
-            query: self.0,
-            cached: self.1.as_ref(),
-            extractor: |
-                row: &tokio_postgres::Row,
-            | -> Result<GetArticleBySlugBorrowed, tokio_postgres::Error> {
-                Ok(GetArticleBySlugBorrowed {
-                    id: row.try_get(0)?,
-                    slug: row.try_get(1)?,
-                    title: row.try_get(2)?,
-                    description: row.try_get(3)?,
-                    body: row.try_get(4)?,
-                    author_id: row.try_get(5)?,
-                    created_at: row.try_get(6)?,
-                    updated_at: row.try_get(7)?,
-                    favorites_count: row.try_get(8)?,
-                    tag_list: row.try_get(9)?,
-                })
-            },
-            mapper: |it| GetArticleBySlug::from(it),
-        }
+        user_id: &'a uuid::Uuid,
+    ) -> Result<u64, tokio_postgres::Error> {
+        client.execute(self.0, &[user_id]).await
     }
 }
-pub struct GetArticleByIdStmt(&'static str, Option<tokio_postgres::Statement>);
-pub fn get_article_by_id() -> GetArticleByIdStmt {
-    GetArticleByIdStmt(
-        "SELECT a.*, (SELECT COUNT(*) FROM article_favorite WHERE article_id = a.id) as favorites_count, ARRAY(SELECT t.name FROM tag t JOIN article_tag at ON t.id = at.tag_id WHERE at.article_id = a.id ORDER BY t.name) as tag_list FROM article a WHERE a.id = $1",
+pub struct ListArticlesByAuthorStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn list_articles_by_author() -> ListArticlesByAuthorStmt {
+    ListArticlesByAuthorStmt(
+        "SELECT a.*, (SELECT COUNT(*) FROM article_favorite WHERE article_id = a.id) as favorites_count, ARRAY(SELECT t.name FROM tag t JOIN article_tag at ON t.id = at.tag_id WHERE at.article_id = a.id ORDER BY t.name) as tag_list, ARRAY(SELECT ac.appuser_id FROM article_coauthor ac WHERE ac.article_id = a.id AND ac.accepted_at IS NOT NULL ORDER BY ac.accepted_at, ac.appuser_id) as coauthor_ids FROM article a WHERE a.author_id = $1 ORDER BY a.created_at, a.id",
         None,
     )
 }
-impl GetArticleByIdStmt {
+impl ListArticlesByAuthorStmt {
     pub async fn prepare<'a, C: GenericClient>(
         mut self,
         client: &'a C,
@@ -1268,41 +5881,37 @@ impl GetArticleByIdStmt {
     pub fn bind<'c, 'a, 's, C: GenericClient>(
         &'s self,
         client: &'c C,
-        id: &'a uuid::Uuid,
-    ) -> GetArticleByIdQuery<'c, 'a, 's, C, GetArticleById, 1> {
-        GetArticleByIdQuery {
-            client,
-            params: [id],
-            query: self.0,
-            cached: self.1.as_ref(),
-            extractor: |
-                row: &tokio_postgres::Row,
-            | -> Result<GetArticleByIdBorrowed, tokio_postgres::Error> {
-                Ok(GetArticleByIdBorrowed {
-                    id: row.try_get(0)?,
-                    slug: row.try_get(1)?,
-                    title: row.try_get(2)?,
-                    description: row.try_get(3)?,
-                    body: row.try_get(4)?,
-                    author_id: row.try_get(5)?,
-                    created_at: row.try_get(6)?,
-                    updated_at: row.try_get(7)?,
-                    favorites_count: row.try_get(8)?,
-                    tag_list: row.try_get(9)?,
-                })
-            },
-            mapper: |it| GetArticleById::from(it),
-        }
+        author_id: &'a uuid::Uuid,
+    ) -> ListArticlesByAuthorQuery<'c, 'a, 's, C, ListArticlesByAuthor, 1> {
+        ListArticlesByAuthorQuery { client, params: [author_id], query: self.0, cached: self.1.as_ref(), extractor: |row: &tokio_postgres::Row| -> Result<ListArticlesByAuthorBorrowed, tokio_postgres::Error> { Ok(ListArticlesByAuthorBorrowed { id: row.try_get(0)?,
+slug: row.try_get(1)?,
+title: row.try_get(2)?,
+description: row.try_get(3)?,
+body: row.try_get(4)?,
+author_id: row.try_get(5)?,
+created_at: row.try_get(6)?,
+updated_at: row.try_get(7)?,
+status: row.try_get(8)?,
+published_at: row.try_get(9)?,
+canonical_url: row.try_get(10)?,
+meta_description: row.try_get(11)?,
+social_image: row.try_get(12)?,
+deleted_at: row.try_get(13)?,
+hidden_at: row.try_get(14)?,
+favorites_count: row.try_get(15)?,
+tag_list: row.try_get(16)?,
+coauthor_ids: row.try_get(17)?,
+ }) }, mapper: |it| ListArticlesByAuthor::from(it), }
     }
 }
-pub struct UpdateArticleStmt(&'static str, Option<tokio_postgres::Statement>);
-pub fn update_article() -> UpdateArticleStmt {
-    UpdateArticleStmt(
-        "UPDATE article SET slug = COALESCE($1, slug), title = COALESCE($2, title), description = COALESCE($3, description), body = COALESCE($4, body), updated_at = $5 WHERE id = $6 RETURNING *",
+pub struct ListDraftsStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn list_drafts() -> ListDraftsStmt {
+    ListDraftsStmt(
+        "SELECT a.id, a.slug, a.title, a.description, a.body, a.author_id, a.created_at, a.updated_at, a.status, a.published_at, u.username as author_username, u.bio as author_bio, u.img as author_image, false as following_author, EXISTS(SELECT 1 FROM article_favorite WHERE appuser_id = $1 AND article_id = a.id) as favorited, EXISTS(SELECT 1 FROM article_bookmark WHERE appuser_id = $1 AND article_id = a.id) as bookmarked, (SELECT COUNT(*) FROM article_favorite WHERE article_id = a.id) as favorites_count, ARRAY(SELECT t.name FROM tag t JOIN article_tag at ON t.id = at.tag_id WHERE at.article_id = a.id ORDER BY t.name) as tag_list, ARRAY(SELECT rc.kind FROM article_reaction_count rc WHERE rc.article_id = a.id ORDER BY rc.count DESC, rc.kind COLLATE ucs_basic) as reaction_kinds, ARRAY(SELECT rc.count FROM article_reaction_count rc WHERE rc.article_id = a.id ORDER BY rc.count DESC, rc.kind COLLATE ucs_basic) as reaction_counts, ARRAY(SELECT ar.kind FROM article_reaction ar WHERE ar.article_id = a.id AND ar.appuser_id = $1) as viewer_reactions FROM article a JOIN appuser u ON a.author_id = u.id WHERE a.author_id = $1 AND a.status <> 'published' AND a.deleted_at IS NULL ORDER BY a.updated_at DESC LIMIT $2 OFFSET $3",
         None,
     )
 }
-impl UpdateArticleStmt {
+impl ListDraftsStmt {
     pub async fn prepare<'a, C: GenericClient>(
         mut self,
         client: &'a C,
@@ -1310,33 +5919,21 @@ impl UpdateArticleStmt {
         self.1 = Some(client.prepare(self.0).await?);
         Ok(self)
     }
-    pub fn bind<
-        'c,
-        'a,
-        's,
-        C: GenericClient,
-        T1: crate::clorinde::StringSql,
-        T2: crate::clorinde::StringSql,
-        T3: crate::clorinde::StringSql,
-        T4: crate::clorinde::StringSql,
-    >(
+    pub fn bind<'c, 'a, 's, C: GenericClient>(
         &'s self,
         client: &'c C,
-        slug: &'a T1,
-        title: &'a T2,
-        description: &'a T3,
-        body: &'a T4,
-        updated_at: &'a chrono::DateTime<chrono::FixedOffset>,
-        id: &'a uuid::Uuid,
-    ) -> UpdateArticleQuery<'c, 'a, 's, C, UpdateArticle, 6> {
-        UpdateArticleQuery {
+        author_id: &'a uuid::Uuid,
+        limit: &'a i64,
+        offset: &'a i64,
+    ) -> ListDraftsQuery<'c, 'a, 's, C, ListDrafts, 3> {
+        ListDraftsQuery {
             client,
-            params: [slug, title, description, body, updated_at, id],
+            params: [author_id, limit, offset],
             query: self.0,
             cached: self.1.as_ref(),
             extractor:
-                |row: &tokio_postgres::Row| -> Result<UpdateArticleBorrowed, tokio_postgres::Error> {
-                    Ok(UpdateArticleBorrowed {
+                |row: &tokio_postgres::Row| -> Result<ListDraftsBorrowed, tokio_postgres::Error> {
+                    Ok(ListDraftsBorrowed {
                         id: row.try_get(0)?,
                         slug: row.try_get(1)?,
                         title: row.try_get(2)?,
@@ -1345,52 +5942,51 @@ impl UpdateArticleStmt {
                         author_id: row.try_get(5)?,
                         created_at: row.try_get(6)?,
                         updated_at: row.try_get(7)?,
+                        status: row.try_get(8)?,
+                        published_at: row.try_get(9)?,
+                        author_username: row.try_get(10)?,
+                        author_bio: row.try_get(11)?,
+                        author_image: row.try_get(12)?,
+                        following_author: row.try_get(13)?,
+                        favorited: row.try_get(14)?,
+                        bookmarked: row.try_get(15)?,
+                        favorites_count: row.try_get(16)?,
+                        tag_list: row.try_get(17)?,
+                        reaction_kinds: row.try_get(18)?,
+                        reaction_counts: row.try_get(19)?,
+                        viewer_reactions: row.try_get(20)?,
                     })
                 },
-            mapper: |it| UpdateArticle::from(it),
+            mapper: |it| ListDrafts::from(it),
         }
     }
 }
-impl<
-    'c,
-    'a,
-    's,
-    C: GenericClient,
-    T1: crate::clorinde::StringSql,
-    T2: crate::clorinde::StringSql,
-    T3: crate::clorinde::StringSql,
-    T4: crate::clorinde::StringSql,
->
+impl<'c, 'a, 's, C: GenericClient>
     crate::clorinde::client::async_::Params<
         'c,
         'a,
         's,
-        UpdateArticleParams<T1, T2, T3, T4>,
-        UpdateArticleQuery<'c, 'a, 's, C, UpdateArticle, 6>,
+        ListDraftsParams,
+        ListDraftsQuery<'c, 'a, 's, C, ListDrafts, 3>,
         C,
-    > for UpdateArticleStmt
+    > for ListDraftsStmt
 {
     fn params(
         &'s self,
         client: &'c C,
-        params: &'a UpdateArticleParams<T1, T2, T3, T4>,
-    ) -> UpdateArticleQuery<'c, 'a, 's, C, UpdateArticle, 6> {
-        self.bind(
-            client,
-            &params.slug,
-            &params.title,
-            &params.description,
-            &params.body,
-            &params.updated_at,
-            &params.id,
-        )
+        params: &'a ListDraftsParams,
+    ) -> ListDraftsQuery<'c, 'a, 's, C, ListDrafts, 3> {
+        self.bind(client, &params.author_id, &params.limit, &params.offset)
     }
 }
-pub struct DeleteArticleStmt(&'static str, Option<tokio_postgres::Statement>);
-pub fn delete_article() -> DeleteArticleStmt {
-    DeleteArticleStmt("DELETE FROM article WHERE id = $1", None)
+pub struct CountDraftsStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn count_drafts() -> CountDraftsStmt {
+    CountDraftsStmt(
+        "SELECT COUNT(*) FROM article WHERE author_id = $1 AND status <> 'published' AND deleted_at IS NULL",
+        None,
+    )
 }
-impl DeleteArticleStmt {
+impl CountDraftsStmt {
     pub async fn prepare<'a, C: GenericClient>(
         mut self,
         client: &'a C,
@@ -1398,22 +5994,29 @@ impl DeleteArticleStmt {
         self.1 = Some(client.prepare(self.0).await?);
         Ok(self)
     }
-    pub async fn bind<'c, 'a, 's, C: GenericClient>(
+    pub fn bind<'c, 'a, 's, C: GenericClient>(
         &'s self,
         client: &'c C,
-        id: &'a uuid::Uuid,
-    ) -> Result<u64, tokio_postgres::Error> {
-        client.execute(self.0, &[id]).await
+        author_id: &'a uuid::Uuid,
+    ) -> I64Query<'c, 'a, 's, C, i64, 1> {
+        I64Query {
+            client,
+            params: [author_id],
+            query: self.0,
+            cached: self.1.as_ref(),
+            extractor: |row| Ok(row.try_get(0)?),
+            mapper: |it| it,
+        }
     }
 }
-pub struct FavoriteArticleStmt(&'static str, Option<tokio_postgres::Statement>);
-pub fn favorite_article() -> FavoriteArticleStmt {
-    FavoriteArticleStmt(
-        "INSERT INTO article_favorite (appuser_id, article_id) VALUES ($1, $2) ON CONFLICT DO NOTHING",
+pub struct PublishDueArticlesStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn publish_due_articles() -> PublishDueArticlesStmt {
+    PublishDueArticlesStmt(
+        "WITH due AS ( UPDATE article SET status = 'published', updated_at = $1 WHERE status = 'scheduled' AND published_at <= $1 AND deleted_at IS NULL RETURNING * ) SELECT d.*, (SELECT COUNT(*) FROM article_favorite WHERE article_id = d.id) as favorites_count, ARRAY(SELECT t.name FROM tag t JOIN article_tag at ON t.id = at.tag_id WHERE at.article_id = d.id ORDER BY t.name) as tag_list, ARRAY(SELECT ac.appuser_id FROM article_coauthor ac WHERE ac.article_id = d.id AND ac.accepted_at IS NOT NULL ORDER BY ac.accepted_at, ac.appuser_id) as coauthor_ids FROM due d",
         None,
     )
 }
-impl FavoriteArticleStmt {
+impl PublishDueArticlesStmt {
     pub async fn prepare<'a, C: GenericClient>(
         mut self,
         client: &'a C,
@@ -1421,45 +6024,40 @@ impl FavoriteArticleStmt {
         self.1 = Some(client.prepare(self.0).await?);
         Ok(self)
     }
-    pub async fn bind<'c, 'a, 's, C: GenericClient>(
+    pub fn bind<'c, 'a, 's, C: GenericClient>(
         &'s self,
         client: &'c C,
-        user_id: &'a uuid::Uuid,
-        article_id: &'a uuid::Uuid,
-    ) -> Result<u64, tokio_postgres::Error> {
-        client.execute(self.0, &[user_id, article_id]).await
-    }
-}
-impl<'a, C: GenericClient + Send + Sync>
-    crate::clorinde::client::async_::Params<
-        'a,
-        'a,
-        'a,
-        FavoriteArticleParams,
-        std::pin::Pin<
-            Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
-        >,
-        C,
-    > for FavoriteArticleStmt
-{
-    fn params(
-        &'a self,
-        client: &'a C,
-        params: &'a FavoriteArticleParams,
-    ) -> std::pin::Pin<
-        Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
-    > {
-        Box::pin(self.bind(client, &params.user_id, &params.article_id))
+        now: &'a chrono::DateTime<chrono::FixedOffset>,
+    ) -> PublishDueArticlesQuery<'c, 'a, 's, C, PublishDueArticles, 1> {
+        PublishDueArticlesQuery { client, params: [now], query: self.0, cached: self.1.as_ref(), extractor: |row: &tokio_postgres::Row| -> Result<PublishDueArticlesBorrowed, tokio_postgres::Error> { Ok(PublishDueArticlesBorrowed { id: row.try_get(0)?,
+slug: row.try_get(1)?,
+title: row.try_get(2)?,
+description: row.try_get(3)?,
+body: row.try_get(4)?,
+author_id: row.try_get(5)?,
+created_at: row.try_get(6)?,
+updated_at: row.try_get(7)?,
+status: row.try_get(8)?,
+published_at: row.try_get(9)?,
+canonical_url: row.try_get(10)?,
+meta_description: row.try_get(11)?,
+social_image: row.try_get(12)?,
+deleted_at: row.try_get(13)?,
+hidden_at: row.try_get(14)?,
+favorites_count: row.try_get(15)?,
+tag_list: row.try_get(16)?,
+coauthor_ids: row.try_get(17)?,
+ }) }, mapper: |it| PublishDueArticles::from(it), }
     }
 }
-pub struct UnfavoriteArticleStmt(&'static str, Option<tokio_postgres::Statement>);
-pub fn unfavorite_article() -> UnfavoriteArticleStmt {
-    UnfavoriteArticleStmt(
-        "DELETE FROM article_favorite WHERE appuser_id = $1 AND article_id = $2",
+pub struct SearchArticlesStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn search_articles() -> SearchArticlesStmt {
+    SearchArticlesStmt(
+        "SELECT r.id, r.slug, r.title, r.description, r.body, r.author_id, r.created_at, r.updated_at, r.status, r.published_at, r.author_username, r.author_bio, r.author_image, r.following_author, r.favorited, r.bookmarked, r.favorites_count, r.tag_list, ARRAY(SELECT rc.kind FROM article_reaction_count rc WHERE rc.article_id = r.id ORDER BY rc.count DESC, rc.kind COLLATE ucs_basic) as reaction_kinds, ARRAY(SELECT rc.count FROM article_reaction_count rc WHERE rc.article_id = r.id ORDER BY rc.count DESC, rc.kind COLLATE ucs_basic) as reaction_counts, ARRAY(SELECT ar.kind FROM article_reaction ar WHERE ar.article_id = r.id AND ar.appuser_id = $1) as viewer_reactions, r.rank, ts_headline('english', replace(replace(replace(replace(replace(r.body, '&', '&amp;'), '<', '&lt;'), '>', '&gt;'), '\"', '&quot;'), '''', '&#39;'), q.query, 'StartSel=<mark>, StopSel=</mark>, MaxWords=35, MinWords=15') as highlight FROM ( SELECT a.id, a.slug, a.title, a.description, a.body, a.author_id, a.created_at, a.updated_at, a.status, a.published_at, u.username as author_username, u.bio as author_bio, u.img as author_image, EXISTS(SELECT 1 FROM appuser_follows WHERE follower_id = $1 AND followee_id = a.author_id) as following_author, EXISTS(SELECT 1 FROM article_favorite WHERE appuser_id = $1 AND article_id = a.id) as favorited, EXISTS(SELECT 1 FROM article_bookmark WHERE appuser_id = $1 AND article_id = a.id) as bookmarked, (SELECT COUNT(*) FROM article_favorite WHERE article_id = a.id) as favorites_count, ARRAY(SELECT t.name FROM tag t JOIN article_tag at ON t.id = at.tag_id WHERE at.article_id = a.id ORDER BY t.name) as tag_list, ts_rank_cd(article_search_vector(a.title, a.description, a.body), q.query)::float8 as rank FROM article a JOIN appuser u ON a.author_id = u.id CROSS JOIN to_tsquery('english', $2) q(query) WHERE u.deactivated_at IS NULL AND a.deleted_at IS NULL AND article_search_vector(a.title, a.description, a.body) @@ q.query AND ((a.status = 'published' AND a.hidden_at IS NULL) OR a.author_id = $1) AND NOT EXISTS(SELECT 1 FROM appuser_mutes WHERE muter_id = $1 AND muted_id = a.author_id) AND NOT EXISTS(SELECT 1 FROM appuser_blocks WHERE blocker_id = a.author_id AND blocked_id = $1) ORDER BY rank DESC, a.created_at DESC LIMIT $3 OFFSET $4 ) r CROSS JOIN to_tsquery('english', $2) q(query) ORDER BY r.rank DESC, r.created_at DESC",
         None,
     )
 }
-impl UnfavoriteArticleStmt {
+impl SearchArticlesStmt {
     pub async fn prepare<'a, C: GenericClient>(
         mut self,
         client: &'a C,
@@ -1467,45 +6065,72 @@ impl UnfavoriteArticleStmt {
         self.1 = Some(client.prepare(self.0).await?);
         Ok(self)
     }
-    pub async fn bind<'c, 'a, 's, C: GenericClient>(
+    pub fn bind<'c, 'a, 's, C: GenericClient, T1: crate::clorinde::StringSql>(
         &'s self,
         client: &'c C,
-        user_id: &'a uuid::Uuid,
-        article_id: &'a uuid::Uuid,
-    ) -> Result<u64, tokio_postgres::Error> {
-        client.execute(self.0, &[user_id, article_id]).await
+        viewer_id: &'a uuid::Uuid,
+        query: &'a T1,
+        limit: &'a i64,
+        offset: &'a i64,
+    ) -> SearchArticlesQuery<'c, 'a, 's, C, SearchArticles, 4> {
+        SearchArticlesQuery { client, params: [viewer_id, query, limit, offset], query: self.0, cached: self.1.as_ref(), extractor: |row: &tokio_postgres::Row| -> Result<SearchArticlesBorrowed, tokio_postgres::Error> { Ok(SearchArticlesBorrowed { id: row.try_get(0)?,
+slug: row.try_get(1)?,
+title: row.try_get(2)?,
+description: row.try_get(3)?,
+body: row.try_get(4)?,
+author_id: row.try_get(5)?,
+created_at: row.try_get(6)?,
+updated_at: row.try_get(7)?,
+status: row.try_get(8)?,
+published_at: row.try_get(9)?,
+author_username: row.try_get(10)?,
+author_bio: row.try_get(11)?,
+author_image: row.try_get(12)?,
+following_author: row.try_get(13)?,
+favorited: row.try_get(14)?,
+bookmarked: row.try_get(15)?,
+favorites_count: row.try_get(16)?,
+tag_list: row.try_get(17)?,
+reaction_kinds: row.try_get(18)?,
+reaction_counts: row.try_get(19)?,
+viewer_reactions: row.try_get(20)?,
+rank: row.try_get(21)?,
+highlight: row.try_get(22)?,
+ }) }, mapper: |it| SearchArticles::from(it), }
     }
 }
-impl<'a, C: GenericClient + Send + Sync>
+impl<'c, 'a, 's, C: GenericClient, T1: crate::clorinde::StringSql>
     crate::clorinde::client::async_::Params<
+        'c,
         'a,
-        'a,
-        'a,
-        UnfavoriteArticleParams,
-        std::pin::Pin<
-            Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
-        >,
+        's,
+        SearchArticlesParams<T1>,
+        SearchArticlesQuery<'c, 'a, 's, C, SearchArticles, 4>,
         C,
-    > for UnfavoriteArticleStmt
+    > for SearchArticlesStmt
 {
     fn params(
-        &'a self,
-        client: &'a C,
-        params: &'a UnfavoriteArticleParams,
-    ) -> std::pin::Pin<
-        Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
-    > {
-        Box::pin(self.bind(client, &params.user_id, &params.article_id))
+        &'s self,
+        client: &'c C,
+        params: &'a SearchArticlesParams<T1>,
+    ) -> SearchArticlesQuery<'c, 'a, 's, C, SearchArticles, 4> {
+        self.bind(
+            client,
+            &params.viewer_id,
+            &params.query,
+            &params.limit,
+            &params.offset,
+        )
     }
 }
-pub struct IsFavoritedStmt(&'static str, Option<tokio_postgres::Statement>);
-pub fn is_favorited() -> IsFavoritedStmt {
-    IsFavoritedStmt(
-        "SELECT EXISTS( SELECT 1 FROM article_favorite WHERE appuser_id = $1 AND article_id = $2 )",
+pub struct CountSearchArticlesStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn count_search_articles() -> CountSearchArticlesStmt {
+    CountSearchArticlesStmt(
+        "SELECT COUNT(*) FROM article a JOIN appuser u ON a.author_id = u.id WHERE u.deactivated_at IS NULL AND a.deleted_at IS NULL AND article_search_vector(a.title, a.description, a.body) @@ to_tsquery('english', $1) AND ((a.status = 'published' AND a.hidden_at IS NULL) OR a.author_id = $2) AND NOT EXISTS(SELECT 1 FROM appuser_mutes WHERE muter_id = $2 AND muted_id = a.author_id) AND NOT EXISTS(SELECT 1 FROM appuser_blocks WHERE blocker_id = a.author_id AND blocked_id = $2)",
         None,
     )
 }
-impl IsFavoritedStmt {
+impl CountSearchArticlesStmt {
     pub async fn prepare<'a, C: GenericClient>(
         mut self,
         client: &'a C,
@@ -1513,15 +6138,15 @@ impl IsFavoritedStmt {
         self.1 = Some(client.prepare(self.0).await?);
         Ok(self)
     }
-    pub fn bind<'c, 'a, 's, C: GenericClient>(
+    pub fn bind<'c, 'a, 's, C: GenericClient, T1: crate::clorinde::StringSql>(
         &'s self,
         client: &'c C,
-        user_id: &'a uuid::Uuid,
-        article_id: &'a uuid::Uuid,
-    ) -> BoolQuery<'c, 'a, 's, C, bool, 2> {
-        BoolQuery {
+        query: &'a T1,
+        viewer_id: &'a uuid::Uuid,
+    ) -> I64Query<'c, 'a, 's, C, i64, 2> {
+        I64Query {
             client,
-            params: [user_id, article_id],
+            params: [query, viewer_id],
             query: self.0,
             cached: self.1.as_ref(),
             extractor: |row| Ok(row.try_get(0)?),
@@ -1529,32 +6154,32 @@ impl IsFavoritedStmt {
         }
     }
 }
-impl<'c, 'a, 's, C: GenericClient>
+impl<'c, 'a, 's, C: GenericClient, T1: crate::clorinde::StringSql>
     crate::clorinde::client::async_::Params<
         'c,
         'a,
         's,
-        IsFavoritedParams,
-        BoolQuery<'c, 'a, 's, C, bool, 2>,
+        CountSearchArticlesParams<T1>,
+        I64Query<'c, 'a, 's, C, i64, 2>,
         C,
-    > for IsFavoritedStmt
+    > for CountSearchArticlesStmt
 {
     fn params(
         &'s self,
         client: &'c C,
-        params: &'a IsFavoritedParams,
-    ) -> BoolQuery<'c, 'a, 's, C, bool, 2> {
-        self.bind(client, &params.user_id, &params.article_id)
+        params: &'a CountSearchArticlesParams<T1>,
+    ) -> I64Query<'c, 'a, 's, C, i64, 2> {
+        self.bind(client, &params.query, &params.viewer_id)
     }
 }
-pub struct GetFavoritesCountStmt(&'static str, Option<tokio_postgres::Statement>);
-pub fn get_favorites_count() -> GetFavoritesCountStmt {
-    GetFavoritesCountStmt(
-        "SELECT COUNT(*) as count FROM article_favorite WHERE article_id = $1",
+pub struct RelatedArticlesStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn related_articles() -> RelatedArticlesStmt {
+    RelatedArticlesStmt(
+        "WITH source AS ( SELECT id, author_id FROM article WHERE id = $1 AND deleted_at IS NULL ), tag_rarity AS ( SELECT at.tag_id, 1.0::float8 / ln(1.0 + COUNT(*)::float8) as rarity FROM article_tag at JOIN article a ON a.id = at.article_id WHERE a.status = 'published' AND a.deleted_at IS NULL AND a.hidden_at IS NULL AND at.tag_id IN (SELECT tag_id FROM article_tag WHERE article_id = $1) GROUP BY at.tag_id ), shared_tags AS ( SELECT at.article_id, SUM(tr.rarity) as rarity FROM article_tag at JOIN tag_rarity tr ON tr.tag_id = at.tag_id GROUP BY at.article_id ), shared_fans AS ( SELECT theirs.article_id, COUNT(*) as fans FROM article_favorite mine JOIN article_favorite theirs ON theirs.appuser_id = mine.appuser_id WHERE mine.article_id = $1 GROUP BY theirs.article_id ), scored AS ( SELECT a.id, $2::float8 * COALESCE(st.rarity, 0) + CASE WHEN a.author_id = s.author_id THEN $3::float8 ELSE 0 END + $4::float8 * ln(1.0 + COALESCE(sf.fans, 0)::float8) + $5::float8 / (1.0 + GREATEST(EXTRACT(EPOCH FROM ($6::timestamptz - a.created_at))::float8 / 86400.0, 0) / $7::float8) as score FROM article a CROSS JOIN source s LEFT JOIN shared_tags st ON st.article_id = a.id LEFT JOIN shared_fans sf ON sf.article_id = a.id WHERE a.id <> s.id AND a.status = 'published' AND a.hidden_at IS NULL AND a.deleted_at IS NULL AND (st.rarity IS NOT NULL OR a.author_id = s.author_id OR sf.fans IS NOT NULL) ) SELECT a.id, a.slug, a.title, a.description, a.body, a.author_id, a.created_at, a.updated_at, a.status, a.published_at, u.username as author_username, u.bio as author_bio, u.img as author_image, EXISTS(SELECT 1 FROM appuser_follows WHERE follower_id = $8 AND followee_id = a.author_id) as following_author, EXISTS(SELECT 1 FROM article_favorite WHERE appuser_id = $8 AND article_id = a.id) as favorited, EXISTS(SELECT 1 FROM article_bookmark WHERE appuser_id = $8 AND article_id = a.id) as bookmarked, (SELECT COUNT(*) FROM article_favorite WHERE article_id = a.id) as favorites_count, ARRAY(SELECT t.name FROM tag t JOIN article_tag at ON t.id = at.tag_id WHERE at.article_id = a.id ORDER BY t.name) as tag_list, ARRAY(SELECT rc.kind FROM article_reaction_count rc WHERE rc.article_id = a.id ORDER BY rc.count DESC, rc.kind COLLATE ucs_basic) as reaction_kinds, ARRAY(SELECT rc.count FROM article_reaction_count rc WHERE rc.article_id = a.id ORDER BY rc.count DESC, rc.kind COLLATE ucs_basic) as reaction_counts, ARRAY(SELECT ar.kind FROM article_reaction ar WHERE ar.article_id = a.id AND ar.appuser_id = $8) as viewer_reactions FROM scored sc JOIN article a ON a.id = sc.id JOIN appuser u ON a.author_id = u.id WHERE u.deactivated_at IS NULL AND a.author_id <> $8 AND NOT EXISTS(SELECT 1 FROM article_coauthor WHERE article_id = a.id AND appuser_id = $8 AND accepted_at IS NOT NULL) AND NOT EXISTS(SELECT 1 FROM appuser_mutes WHERE muter_id = $8 AND muted_id = a.author_id) AND NOT EXISTS(SELECT 1 FROM appuser_blocks WHERE blocker_id = a.author_id AND blocked_id = $8) ORDER BY sc.score DESC, a.created_at DESC, a.id DESC LIMIT $9",
         None,
     )
 }
-impl GetFavoritesCountStmt {
+impl RelatedArticlesStmt {
     pub async fn prepare<'a, C: GenericClient>(
         mut self,
         client: &'a C,
@@ -1566,22 +6191,76 @@ impl GetFavoritesCountStmt {
         &'s self,
         client: &'c C,
         article_id: &'a uuid::Uuid,
-    ) -> I64Query<'c, 'a, 's, C, i64, 1> {
-        I64Query {
+        tag_weight: &'a f64,
+        author_weight: &'a f64,
+        cofavorite_weight: &'a f64,
+        recency_weight: &'a f64,
+        now: &'a chrono::DateTime<chrono::FixedOffset>,
+        recency_days: &'a f64,
+        viewer_id: &'a uuid::Uuid,
+        limit: &'a i64,
+    ) -> RelatedArticlesQuery<'c, 'a, 's, C, RelatedArticles, 9> {
+        RelatedArticlesQuery { client, params: [article_id, tag_weight, author_weight, cofavorite_weight, recency_weight, now, recency_days, viewer_id, limit], query: self.0, cached: self.1.as_ref(), extractor: |row: &tokio_postgres::Row| -> Result<RelatedArticlesBorrowed, tokio_postgres::Error> { Ok(RelatedArticlesBorrowed { id: row.try_get(0)?,
+slug: row.try_get(1)?,
+title: row.try_get(2)?,
+description: row.try_get(3)?,
+body: row.try_get(4)?,
+author_id: row.try_get(5)?,
+created_at: row.try_get(6)?,
+updated_at: row.try_get(7)?,
+status: row.try_get(8)?,
+published_at: row.try_get(9)?,
+author_username: row.try_get(10)?,
+author_bio: row.try_get(11)?,
+author_image: row.try_get(12)?,
+following_author: row.try_get(13)?,
+favorited: row.try_get(14)?,
+bookmarked: row.try_get(15)?,
+favorites_count: row.try_get(16)?,
+tag_list: row.try_get(17)?,
+reaction_kinds: row.try_get(18)?,
+reaction_counts: row.try_get(19)?,
+viewer_reactions: row.try_get(20)?,
+ }) }, mapper: |it| RelatedArticles::from(it), }
+    }
+}
+impl<'c, 'a, 's, C: GenericClient>
+    crate::clorinde::client::async_::Params<
+        'c,
+        'a,
+        's,
+        RelatedArticlesParams,
+        RelatedArticlesQuery<'c, 'a, 's, C, RelatedArticles, 9>,
+        C,
+    > for RelatedArticlesStmt
+{
+    fn params(
+        &'s self,
+        client: &'c C,
+        params: &'a RelatedArticlesParams,
+    ) -> RelatedArticlesQuery<'c, 'a, 's, C, RelatedArticles, 9> {
+        self.bind(
             client,
-            params: [article_id],
-            query: self.0,
-            cached: self.1.as_ref(),
-            extractor: |row| Ok(row.try_get(0)?),
-            mapper: |it| it,
-        }
+            &params.article_id,
+            &params.tag_weight,
+            &params.author_weight,
+            &params.cofavorite_weight,
+            &params.recency_weight,
+            &params.now,
+            &params.recency_days,
+            &params.viewer_id,
+            &params.limit,
+        )
     }
 }
-pub struct GetTagsStmt(&'static str, Option<tokio_postgres::Statement>);
-pub fn get_tags() -> GetTagsStmt {
-    GetTagsStmt("SELECT name FROM tag", None)
+pub struct CountSitemapEntriesStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn count_sitemap_entries() -> CountSitemapEntriesStmt {
+    CountSitemapEntriesStmt(
+        "SELECT COUNT(*) + COUNT(DISTINCT a.author_id) FROM article a JOIN appuser u ON a.author_id = u.id WHERE a.status = 'published' AND a.deleted_at IS NULL AND a.hidden_at IS NULL AND u.deactivated_at IS NULL",
+        None,
+    )
 }
-impl GetTagsStmt {
+impl CountSitemapEntriesStmt {
     pub async fn prepare<'a, C: GenericClient>(
         mut self,
         client: &'a C,
@@ -1592,25 +6271,25 @@ impl GetTagsStmt {
     pub fn bind<'c, 'a, 's, C: GenericClient>(
         &'s self,
         client: &'c C,
-    ) -> StringQuery<'c, 'a, 's, C, String, 0> {
-        StringQuery {
+    ) -> I64Query<'c, 'a, 's, C, i64, 0> {
+        I64Query {
             client,
             params: [],
             query: self.0,
             cached: self.1.as_ref(),
             extractor: |row| Ok(row.try_get(0)?),
-            mapper: |it| it.into(),
+            mapper: |it| it,
         }
     }
 }
-pub struct CreateTagStmt(&'static str, Option<tokio_postgres::Statement>);
-pub fn create_tag() -> CreateTagStmt {
-    CreateTagStmt(
-        "INSERT INTO tag (id, name) VALUES ($1, $2) ON CONFLICT (name) DO NOTHING",
+pub struct SitemapEntriesStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn sitemap_entries() -> SitemapEntriesStmt {
+    SitemapEntriesStmt(
+        "SELECT kind, key, last_modified FROM ( SELECT 'article' AS kind, a.slug AS key, a.updated_at AS last_modified FROM article a JOIN appuser u ON a.author_id = u.id WHERE a.status = 'published' AND a.deleted_at IS NULL AND a.hidden_at IS NULL AND u.deactivated_at IS NULL UNION ALL SELECT 'profile', u.username, GREATEST(MAX(a.updated_at), u.updated_at) FROM article a JOIN appuser u ON a.author_id = u.id WHERE a.status = 'published' AND a.deleted_at IS NULL AND a.hidden_at IS NULL AND u.deactivated_at IS NULL GROUP BY u.id ) entries ORDER BY kind, key COLLATE ucs_basic LIMIT $1 OFFSET $2",
         None,
     )
 }
-impl CreateTagStmt {
+impl SitemapEntriesStmt {
     pub async fn prepare<'a, C: GenericClient>(
         mut self,
         client: &'a C,
@@ -1618,42 +6297,44 @@ impl CreateTagStmt {
         self.1 = Some(client.prepare(self.0).await?);
         Ok(self)
     }
-    pub async fn bind<'c, 'a, 's, C: GenericClient, T1: crate::clorinde::StringSql>(
+    pub fn bind<'c, 'a, 's, C: GenericClient>(
         &'s self,
         client: &'c C,
-        id: &'a uuid::Uuid,
-        name: &'a T1,
-    ) -> Result<u64, tokio_postgres::Error> {
-        client.execute(self.0, &[id, name]).await
+        limit: &'a i64,
+        offset: &'a i64,
+    ) -> SitemapEntriesQuery<'c, 'a, 's, C, SitemapEntries, 2> {
+        SitemapEntriesQuery { client, params: [limit, offset], query: self.0, cached: self.1.as_ref(), extractor: |row: &tokio_postgres::Row| -> Result<SitemapEntriesBorrowed, tokio_postgres::Error> { Ok(SitemapEntriesBorrowed { kind: row.try_get(0)?,
+key: row.try_get(1)?,
+last_modified: row.try_get(2)?,
+ }) }, mapper: |it| SitemapEntries::from(it), }
     }
 }
-impl<'a, C: GenericClient + Send + Sync, T1: crate::clorinde::StringSql>
+impl<'c, 'a, 's, C: GenericClient>
     crate::clorinde::client::async_::Params<
-        'a,
-        'a,
-        'a,
-        CreateTagParams<T1>,
-        std::pin::Pin<
-            Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
-        >,
+        'c,
+        'a,
+        's,
+        SitemapEntriesParams,
+        SitemapEntriesQuery<'c, 'a, 's, C, SitemapEntries, 2>,
         C,
-    > for CreateTagStmt
+    > for SitemapEntriesStmt
 {
     fn params(
-        &'a self,
-        client: &'a C,
-        params: &'a CreateTagParams<T1>,
-    ) -> std::pin::Pin<
-        Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
-    > {
-        Box::pin(self.bind(client, &params.id, &params.name))
+        &'s self,
+        client: &'c C,
+        params: &'a SitemapEntriesParams,
+    ) -> SitemapEntriesQuery<'c, 'a, 's, C, SitemapEntries, 2> {
+        self.bind(client, &params.limit, &params.offset)
     }
 }
-pub struct GetTagByNameStmt(&'static str, Option<tokio_postgres::Statement>);
-pub fn get_tag_by_name() -> GetTagByNameStmt {
-    GetTagByNameStmt("SELECT * FROM tag WHERE name = $1", None)
+pub struct GetTrashedArticleBySlugStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn get_trashed_article_by_slug() -> GetTrashedArticleBySlugStmt {
+    GetTrashedArticleBySlugStmt(
+        "SELECT a.*, (SELECT COUNT(*) FROM article_favorite WHERE article_id = a.id) as favorites_count, ARRAY(SELECT t.name FROM tag t JOIN article_tag at ON t.id = at.tag_id WHERE at.article_id = a.id ORDER BY t.name) as tag_list, ARRAY(SELECT ac.appuser_id FROM article_coauthor ac WHERE ac.article_id = a.id AND ac.accepted_at IS NOT NULL ORDER BY ac.accepted_at, ac.appuser_id) as coauthor_ids FROM article a WHERE a.slug = $1 AND a.deleted_at IS NOT NULL",
+        None,
+    )
 }
-impl GetTagByNameStmt {
+impl GetTrashedArticleBySlugStmt {
     pub async fn prepare<'a, C: GenericClient>(
         mut self,
         client: &'a C,
@@ -1664,33 +6345,37 @@ impl GetTagByNameStmt {
     pub fn bind<'c, 'a, 's, C: GenericClient, T1: crate::clorinde::StringSql>(
         &'s self,
         client: &'c C,
-        name: &'a T1,
-    ) -> GetTagByNameQuery<'c, 'a, 's, C, GetTagByName, 1> {
-        GetTagByNameQuery {
-            client,
-            params: [name],
-            query: self.0,
-            cached: self.1.as_ref(),
-            extractor:
-                |row: &tokio_postgres::Row| -> Result<GetTagByNameBorrowed, tokio_postgres::Error> {
-                    Ok(GetTagByNameBorrowed {
-                        id: row.try_get(0)?,
-                        name: row.try_get(1)?,
-                        created_at: row.try_get(2)?,
-                    })
-                },
-            mapper: |it| GetTagByName::from(it),
-        }
+        slug: &'a T1,
+    ) -> GetTrashedArticleBySlugQuery<'c, 'a, 's, C, GetTrashedArticleBySlug, 1> {
+        GetTrashedArticleBySlugQuery { client, params: [slug], query: self.0, cached: self.1.as_ref(), extractor: |row: &tokio_postgres::Row| -> Result<GetTrashedArticleBySlugBorrowed, tokio_postgres::Error> { Ok(GetTrashedArticleBySlugBorrowed { id: row.try_get(0)?,
+slug: row.try_get(1)?,
+title: row.try_get(2)?,
+description: row.try_get(3)?,
+body: row.try_get(4)?,
+author_id: row.try_get(5)?,
+created_at: row.try_get(6)?,
+updated_at: row.try_get(7)?,
+status: row.try_get(8)?,
+published_at: row.try_get(9)?,
+canonical_url: row.try_get(10)?,
+meta_description: row.try_get(11)?,
+social_image: row.try_get(12)?,
+deleted_at: row.try_get(13)?,
+hidden_at: row.try_get(14)?,
+favorites_count: row.try_get(15)?,
+tag_list: row.try_get(16)?,
+coauthor_ids: row.try_get(17)?,
+ }) }, mapper: |it| GetTrashedArticleBySlug::from(it), }
     }
 }
-pub struct AddTagToArticleStmt(&'static str, Option<tokio_postgres::Statement>);
-pub fn add_tag_to_article() -> AddTagToArticleStmt {
-    AddTagToArticleStmt(
-        "INSERT INTO article_tag (article_id, tag_id) VALUES ($1, $2) ON CONFLICT DO NOTHING",
+pub struct ListTrashedArticlesStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn list_trashed_articles() -> ListTrashedArticlesStmt {
+    ListTrashedArticlesStmt(
+        "SELECT a.*, (SELECT COUNT(*) FROM article_favorite WHERE article_id = a.id) as favorites_count, ARRAY(SELECT t.name FROM tag t JOIN article_tag at ON t.id = at.tag_id WHERE at.article_id = a.id ORDER BY t.name) as tag_list, ARRAY(SELECT ac.appuser_id FROM article_coauthor ac WHERE ac.article_id = a.id AND ac.accepted_at IS NOT NULL ORDER BY ac.accepted_at, ac.appuser_id) as coauthor_ids FROM article a WHERE a.author_id = $1 AND a.deleted_at IS NOT NULL ORDER BY a.deleted_at DESC, a.id",
         None,
     )
 }
-impl AddTagToArticleStmt {
+impl ListTrashedArticlesStmt {
     pub async fn prepare<'a, C: GenericClient>(
         mut self,
         client: &'a C,
@@ -1698,42 +6383,37 @@ impl AddTagToArticleStmt {
         self.1 = Some(client.prepare(self.0).await?);
         Ok(self)
     }
-    pub async fn bind<'c, 'a, 's, C: GenericClient>(
+    pub fn bind<'c, 'a, 's, C: GenericClient>(
         &'s self,
         client: &'c C,
-        article_id: &'a uuid::Uuid,
-        tag_id: &'a uuid::Uuid,
-    ) -> Result<u64, tokio_postgres::Error> {
-        client.execute(self.0, &[article_id, tag_id]).await
-    }
-}
-impl<'a, C: GenericClient + Send + Sync>
-    crate::clorinde::client::async_::Params<
-        'a,
-        'a,
-        'a,
-        AddTagToArticleParams,
-        std::pin::Pin<
-            Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
-        >,
-        C,
-    > for AddTagToArticleStmt
-{
-    fn params(
-        &'a self,
-        client: &'a C,
-        params: &'a AddTagToArticleParams,
-    ) -> std::pin::Pin<
-        Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
-    > {
-        Box::pin(self.bind(client, &params.article_id, &params.tag_id))
+        author_id: &'a uuid::Uuid,
+    ) -> ListTrashedArticlesQuery<'c, 'a, 's, C, ListTrashedArticles, 1> {
+        ListTrashedArticlesQuery { client, params: [author_id], query: self.0, cached: self.1.as_ref(), extractor: |row: &tokio_postgres::Row| -> Result<ListTrashedArticlesBorrowed, tokio_postgres::Error> { Ok(ListTrashedArticlesBorrowed { id: row.try_get(0)?,
+slug: row.try_get(1)?,
+title: row.try_get(2)?,
+description: row.try_get(3)?,
+body: row.try_get(4)?,
+author_id: row.try_get(5)?,
+created_at: row.try_get(6)?,
+updated_at: row.try_get(7)?,
+status: row.try_get(8)?,
+published_at: row.try_get(9)?,
+canonical_url: row.try_get(10)?,
+meta_description: row.try_get(11)?,
+social_image: row.try_get(12)?,
+deleted_at: row.try_get(13)?,
+hidden_at: row.try_get(14)?,
+favorites_count: row.try_get(15)?,
+tag_list: row.try_get(16)?,
+coauthor_ids: row.try_get(17)?,
+ }) }, mapper: |it| ListTrashedArticles::from(it), }
     }
 }
-pub struct RemoveTagsFromArticleStmt(&'static str, Option<tokio_postgres::Statement>);
-pub fn remove_tags_from_article() -> RemoveTagsFromArticleStmt {
-    RemoveTagsFromArticleStmt("DELETE FROM article_tag WHERE article_id = $1", None)
+pub struct RestoreArticleStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn restore_article() -> RestoreArticleStmt {
+    RestoreArticleStmt("UPDATE article SET deleted_at = NULL WHERE id = $1", None)
 }
-impl RemoveTagsFromArticleStmt {
+impl RestoreArticleStmt {
     pub async fn prepare<'a, C: GenericClient>(
         mut self,
         client: &'a C,
@@ -1744,19 +6424,19 @@ impl RemoveTagsFromArticleStmt {
     pub async fn bind<'c, 'a, 's, C: GenericClient>(
         &'s self,
         client: &'c C,
-        article_id: &'a uuid::Uuid,
+        id: &'a uuid::Uuid,
     ) -> Result<u64, tokio_postgres::Error> {
-        client.execute(self.0, &[article_id]).await
+        client.execute(self.0, &[id]).await
     }
 }
-pub struct GetArticleTagsStmt(&'static str, Option<tokio_postgres::Statement>);
-pub fn get_article_tags() -> GetArticleTagsStmt {
-    GetArticleTagsStmt(
-        "SELECT t.name FROM tag t JOIN article_tag at ON t.id = at.tag_id WHERE at.article_id = $1",
+pub struct PurgeTrashedArticlesStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn purge_trashed_articles() -> PurgeTrashedArticlesStmt {
+    PurgeTrashedArticlesStmt(
+        "DELETE FROM article WHERE deleted_at <= $1 RETURNING id",
         None,
     )
 }
-impl GetArticleTagsStmt {
+impl PurgeTrashedArticlesStmt {
     pub async fn prepare<'a, C: GenericClient>(
         mut self,
         client: &'a C,
@@ -1767,26 +6447,26 @@ impl GetArticleTagsStmt {
     pub fn bind<'c, 'a, 's, C: GenericClient>(
         &'s self,
         client: &'c C,
-        article_id: &'a uuid::Uuid,
-    ) -> StringQuery<'c, 'a, 's, C, String, 1> {
-        StringQuery {
+        cutoff: &'a chrono::DateTime<chrono::FixedOffset>,
+    ) -> UuidUuidQuery<'c, 'a, 's, C, uuid::Uuid, 1> {
+        UuidUuidQuery {
             client,
-            params: [article_id],
+            params: [cutoff],
             query: self.0,
             cached: self.1.as_ref(),
             extractor: |row| Ok(row.try_get(0)?),
-            mapper: |it| it.into(),
+            mapper: |it| it,
         }
     }
 }
-pub struct ListArticlesStmt(&'static str, Option<tokio_postgres::Statement>);
-pub fn list_articles() -> ListArticlesStmt {
-    ListArticlesStmt(
-        "SELECT a.id, a.slug, a.title, a.description, a.body, a.author_id, a.created_at, a.updated_at, u.username as author_username, u.bio as author_bio, u.img as author_image, EXISTS(SELECT 1 FROM appuser_follows WHERE follower_id = $1 AND followee_id = a.author_id) as following_author, EXISTS(SELECT 1 FROM article_favorite WHERE appuser_id = $1 AND article_id = a.id) as favorited, (SELECT COUNT(*) FROM article_favorite WHERE article_id = a.id) as favorites_count, ARRAY(SELECT t.name FROM tag t JOIN article_tag at ON t.id = at.tag_id WHERE at.article_id = a.id ORDER BY t.name) as tag_list FROM article a JOIN appuser u ON a.author_id = u.id WHERE ($2::text IS NULL OR a.author_id = (SELECT id FROM appuser WHERE username = $2)) AND ($3::text IS NULL OR EXISTS(SELECT 1 FROM article_tag at JOIN tag t ON at.tag_id = t.id WHERE at.article_id = a.id AND t.name = $3)) AND ($4::text IS NULL OR EXISTS(SELECT 1 FROM article_favorite af JOIN appuser u2 ON af.appuser_id = u2.id WHERE af.article_id = a.id AND u2.username = $4)) ORDER BY a.created_at DESC LIMIT $5 OFFSET $6",
+pub struct AddArticleReactionStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn add_article_reaction() -> AddArticleReactionStmt {
+    AddArticleReactionStmt(
+        "INSERT INTO article_reaction (article_id, appuser_id, kind) VALUES ($1, $2, $3) ON CONFLICT DO NOTHING",
         None,
     )
 }
-impl ListArticlesStmt {
+impl AddArticleReactionStmt {
     pub async fn prepare<'a, C: GenericClient>(
         mut self,
         client: &'a C,
@@ -1794,87 +6474,46 @@ impl ListArticlesStmt {
         self.1 = Some(client.prepare(self.0).await?);
         Ok(self)
     }
-    pub fn bind<
-        'c,
-        'a,
-        's,
-        C: GenericClient,
-        T1: crate::clorinde::StringSql,
-        T2: crate::clorinde::StringSql,
-        T3: crate::clorinde::StringSql,
-    >(
+    pub async fn bind<'c, 'a, 's, C: GenericClient, T1: crate::clorinde::StringSql>(
         &'s self,
         client: &'c C,
-        viewer_id: &'a uuid::Uuid,
-        author: &'a T1,
-        tag: &'a T2,
-        favorited: &'a T3,
-        limit: &'a i64,
-        offset: &'a i64,
-    ) -> ListArticlesQuery<'c, 'a, 's, C, ListArticles, 6> {
-        ListArticlesQuery {
-            client,
-            params: [viewer_id, author, tag, favorited, limit, offset],
-            query: self.0,
-            cached: self.1.as_ref(),
-            extractor:
-                |row: &tokio_postgres::Row| -> Result<ListArticlesBorrowed, tokio_postgres::Error> {
-                    Ok(ListArticlesBorrowed {
-                        id: row.try_get(0)?,
-                        slug: row.try_get(1)?,
-                        title: row.try_get(2)?,
-                        description: row.try_get(3)?,
-                        body: row.try_get(4)?,
-                        author_id: row.try_get(5)?,
-                        created_at: row.try_get(6)?,
-                        updated_at: row.try_get(7)?,
-                        author_username: row.try_get(8)?,
-                        author_bio: row.try_get(9)?,
-                        author_image: row.try_get(10)?,
-                        following_author: row.try_get(11)?,
-                        favorited: row.try_get(12)?,
-                        favorites_count: row.try_get(13)?,
-                        tag_list: row.try_get(14)?,
-                    })
-                },
-            mapper: |it| ListArticles::from(it),
-        }
+        article_id: &'a uuid::Uuid,
+        user_id: &'a uuid::Uuid,
+        kind: &'a T1,
+    ) -> Result<u64, tokio_postgres::Error> {
+        client.execute(self.0, &[article_id, user_id, kind]).await
     }
 }
-impl<'c, 'a, 's, C: GenericClient, T1: crate::clorinde::StringSql, T2: crate::clorinde::StringSql, T3: crate::clorinde::StringSql>
+impl<'a, C: GenericClient + Send + Sync, T1: crate::clorinde::StringSql>
     crate::clorinde::client::async_::Params<
-        'c,
         'a,
-        's,
-        ListArticlesParams<T1, T2, T3>,
-        ListArticlesQuery<'c, 'a, 's, C, ListArticles, 6>,
+        'a,
+        'a,
+        AddArticleReactionParams<T1>,
+        std::pin::Pin<
+            Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+        >,
         C,
-    > for ListArticlesStmt
+    > for AddArticleReactionStmt
 {
     fn params(
-        &'s self,
-        client: &'c C,
-        params: &'a ListArticlesParams<T1, T2, T3>,
-    ) -> ListArticlesQuery<'c, 'a, 's, C, ListArticles, 6> {
-        self.bind(
-            client,
-            &params.viewer_id,
-            &params.author,
-            &params.tag,
-            &params.favorited,
-            &params.limit,
-            &params.offset,
-        )
+        &'a self,
+        client: &'a C,
+        params: &'a AddArticleReactionParams<T1>,
+    ) -> std::pin::Pin<
+        Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+    > {
+        Box::pin(self.bind(client, &params.article_id, &params.user_id, &params.kind))
     }
 }
-pub struct CountArticlesStmt(&'static str, Option<tokio_postgres::Statement>);
-pub fn count_articles() -> CountArticlesStmt {
-    CountArticlesStmt(
-        "SELECT COUNT(*) FROM article a WHERE ($1::text IS NULL OR a.author_id = (SELECT id FROM appuser WHERE username = $1)) AND ($2::text IS NULL OR EXISTS(SELECT 1 FROM article_tag at JOIN tag t ON at.tag_id = t.id WHERE at.article_id = a.id AND t.name = $2)) AND ($3::text IS NULL OR EXISTS(SELECT 1 FROM article_favorite af JOIN appuser u2 ON af.appuser_id = u2.id WHERE af.article_id = a.id AND u2.username = $3))",
+pub struct RemoveArticleReactionStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn remove_article_reaction() -> RemoveArticleReactionStmt {
+    RemoveArticleReactionStmt(
+        "DELETE FROM article_reaction WHERE article_id = $1 AND appuser_id = $2 AND kind = $3",
         None,
     )
 }
-impl CountArticlesStmt {
+impl RemoveArticleReactionStmt {
     pub async fn prepare<'a, C: GenericClient>(
         mut self,
         client: &'a C,
@@ -1882,57 +6521,46 @@ impl CountArticlesStmt {
         self.1 = Some(client.prepare(self.0).await?);
         Ok(self)
     }
-    pub fn bind<
-        'c,
-        'a,
-        's,
-        C: GenericClient,
-        T1: crate::clorinde::StringSql,
-        T2: crate::clorinde::StringSql,
-        T3: crate::clorinde::StringSql,
-    >(
+    pub async fn bind<'c, 'a, 's, C: GenericClient, T1: crate::clorinde::StringSql>(
         &'s self,
         client: &'c C,
-        author: &'a T1,
-        tag: &'a T2,
-        favorited: &'a T3,
-    ) -> I64Query<'c, 'a, 's, C, i64, 3> {
-        I64Query {
-            client,
-            params: [author, tag, favorited],
-            query: self.0,
-            cached: self.1.as_ref(),
-            extractor: |row| Ok(row.try_get(0)?),
-            mapper: |it| it,
-        }
+        article_id: &'a uuid::Uuid,
+        user_id: &'a uuid::Uuid,
+        kind: &'a T1,
+    ) -> Result<u64, tokio_postgres::Error> {
+        client.execute(self.0, &[article_id, user_id, kind]).await
     }
 }
-impl<'c, 'a, 's, C: GenericClient, T1: crate::clorinde::StringSql, T2: crate::clorinde::StringSql, T3: crate::clorinde::StringSql>
+impl<'a, C: GenericClient + Send + Sync, T1: crate::clorinde::StringSql>
     crate::clorinde::client::async_::Params<
-        'c,
         'a,
-        's,
-        CountArticlesParams<T1, T2, T3>,
-        I64Query<'c, 'a, 's, C, i64, 3>,
+        'a,
+        'a,
+        RemoveArticleReactionParams<T1>,
+        std::pin::Pin<
+            Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+        >,
         C,
-    > for CountArticlesStmt
+    > for RemoveArticleReactionStmt
 {
     fn params(
-        &'s self,
-        client: &'c C,
-        params: &'a CountArticlesParams<T1, T2, T3>,
-    ) -> I64Query<'c, 'a, 's, C, i64, 3> {
-        self.bind(client, &params.author, &params.tag, &params.favorited)
+        &'a self,
+        client: &'a C,
+        params: &'a RemoveArticleReactionParams<T1>,
+    ) -> std::pin::Pin<
+        Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+    > {
+        Box::pin(self.bind(client, &params.article_id, &params.user_id, &params.kind))
     }
 }
-pub struct FeedArticlesStmt(&'static str, Option<tokio_postgres::Statement>);
-pub fn feed_articles() -> FeedArticlesStmt {
-    FeedArticlesStmt(
-        "SELECT a.id, a.slug, a.title, a.description, a.body, a.author_id, a.created_at, a.updated_at, u.username as author_username, u.bio as author_bio, u.img as author_image, true as following_author, EXISTS(SELECT 1 FROM article_favorite WHERE appuser_id = $1 AND article_id = a.id) as favorited, (SELECT COUNT(*) FROM article_favorite WHERE article_id = a.id) as favorites_count, ARRAY(SELECT t.name FROM tag t JOIN article_tag at ON t.id = at.tag_id WHERE at.article_id = a.id ORDER BY t.name) as tag_list FROM article a JOIN appuser u ON a.author_id = u.id JOIN appuser_follows af ON af.followee_id = a.author_id WHERE af.follower_id = $1 ORDER BY a.created_at DESC LIMIT $2 OFFSET $3",
+pub struct ArticleReactionsStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn article_reactions() -> ArticleReactionsStmt {
+    ArticleReactionsStmt(
+        "SELECT c.kind, c.count, EXISTS(SELECT 1 FROM article_reaction r WHERE r.article_id = c.article_id AND r.kind = c.kind AND r.appuser_id = $1) as reacted FROM article_reaction_count c WHERE c.article_id = $2 ORDER BY c.count DESC, c.kind COLLATE ucs_basic",
         None,
     )
 }
-impl FeedArticlesStmt {
+impl ArticleReactionsStmt {
     pub async fn prepare<'a, C: GenericClient>(
         mut self,
         client: &'a C,
@@ -1944,36 +6572,12 @@ impl FeedArticlesStmt {
         &'s self,
         client: &'c C,
         viewer_id: &'a uuid::Uuid,
-        limit: &'a i64,
-        offset: &'a i64,
-    ) -> FeedArticlesQuery<'c, 'a, 's, C, FeedArticles, 3> {
-        FeedArticlesQuery {
-            client,
-            params: [viewer_id, limit, offset],
-            query: self.0,
-            cached: self.1.as_ref(),
-            extractor:
-                |row: &tokio_postgres::Row| -> Result<FeedArticlesBorrowed, tokio_postgres::Error> {
-                    Ok(FeedArticlesBorrowed {
-                        id: row.try_get(0)?,
-                        slug: row.try_get(1)?,
-                        title: row.try_get(2)?,
-                        description: row.try_get(3)?,
-                        body: row.try_get(4)?,
-                        author_id: row.try_get(5)?,
-                        created_at: row.try_get(6)?,
-                        updated_at: row.try_get(7)?,
-                        author_username: row.try_get(8)?,
-                        author_bio: row.try_get(9)?,
-                        author_image: row.try_get(10)?,
-                        following_author: row.try_get(11)?,
-                        favorited: row.try_get(12)?,
-                        favorites_count: row.try_get(13)?,
-                        tag_list: row.try_get(14)?,
-                    })
-                },
-            mapper: |it| FeedArticles::from(it),
-        }
+        article_id: &'a uuid::Uuid,
+    ) -> ArticleReactionsQuery<'c, 'a, 's, C, ArticleReactions, 2> {
+        ArticleReactionsQuery { client, params: [viewer_id, article_id], query: self.0, cached: self.1.as_ref(), extractor: |row: &tokio_postgres::Row| -> Result<ArticleReactionsBorrowed, tokio_postgres::Error> { Ok(ArticleReactionsBorrowed { kind: row.try_get(0)?,
+count: row.try_get(1)?,
+reacted: row.try_get(2)?,
+ }) }, mapper: |it| ArticleReactions::from(it), }
     }
 }
 impl<'c, 'a, 's, C: GenericClient>
@@ -1981,27 +6585,47 @@ impl<'c, 'a, 's, C: GenericClient>
         'c,
         'a,
         's,
-        FeedArticlesParams,
-        FeedArticlesQuery<'c, 'a, 's, C, FeedArticles, 3>,
+        ArticleReactionsParams,
+        ArticleReactionsQuery<'c, 'a, 's, C, ArticleReactions, 2>,
         C,
-    > for FeedArticlesStmt
+    > for ArticleReactionsStmt
 {
     fn params(
         &'s self,
         client: &'c C,
-        params: &'a FeedArticlesParams,
-    ) -> FeedArticlesQuery<'c, 'a, 's, C, FeedArticles, 3> {
-        self.bind(client, &params.viewer_id, &params.limit, &params.offset)
+        params: &'a ArticleReactionsParams,
+    ) -> ArticleReactionsQuery<'c, 'a, 's, C, ArticleReactions, 2> {
+        self.bind(client, &params.viewer_id, &params.article_id)
     }
 }
-pub struct CountFeedArticlesStmt(&'static str, Option<tokio_postgres::Statement>);
-pub fn count_feed_articles() -> CountFeedArticlesStmt {
-    CountFeedArticlesStmt(
-        "SELECT COUNT(*) FROM article a JOIN appuser_follows af ON af.followee_id = a.author_id WHERE af.follower_id = $1",
+pub struct RemoveArticleReactionsByUserStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn remove_article_reactions_by_user() -> RemoveArticleReactionsByUserStmt {
+    RemoveArticleReactionsByUserStmt("DELETE FROM article_reaction WHERE appuser_id = $1", None)
+}
+impl RemoveArticleReactionsByUserStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub async fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        user_id: &'a uuid::Uuid,
+    ) -> Result<u64, tokio_postgres::Error> {
+        client.execute(self.0, &[user_id]).await
+    }
+}
+pub struct ListArticleReactionsByUserStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn list_article_reactions_by_user() -> ListArticleReactionsByUserStmt {
+    ListArticleReactionsByUserStmt(
+        "SELECT article_id, kind FROM article_reaction WHERE appuser_id = $1 ORDER BY created_at, article_id, kind COLLATE ucs_basic",
         None,
     )
 }
-impl CountFeedArticlesStmt {
+impl ListArticleReactionsByUserStmt {
     pub async fn prepare<'a, C: GenericClient>(
         mut self,
         client: &'a C,
@@ -2012,15 +6636,10 @@ impl CountFeedArticlesStmt {
     pub fn bind<'c, 'a, 's, C: GenericClient>(
         &'s self,
         client: &'c C,
-        viewer_id: &'a uuid::Uuid,
-    ) -> I64Query<'c, 'a, 's, C, i64, 1> {
-        I64Query {
-            client,
-            params: [viewer_id],
-            query: self.0,
-            cached: self.1.as_ref(),
-            extractor: |row| Ok(row.try_get(0)?),
-            mapper: |it| it,
-        }
+        user_id: &'a uuid::Uuid,
+    ) -> ListArticleReactionsByUserQuery<'c, 'a, 's, C, ListArticleReactionsByUser, 1> {
+        ListArticleReactionsByUserQuery { client, params: [user_id], query: self.0, cached: self.1.as_ref(), extractor: |row: &tokio_postgres::Row| -> Result<ListArticleReactionsByUserBorrowed, tokio_postgres::Error> { Ok(ListArticleReactionsByUserBorrowed { article_id: row.try_get(0)?,
+kind: row.try_get(1)?,
+ }) }, mapper: |it| ListArticleReactionsByUser::from(it), }
     }
 }
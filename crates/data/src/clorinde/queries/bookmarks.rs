@@ -0,0 +1,902 @@
+// This file was generated with `clorinde`. Do not modify.
+
+#[derive(Debug)]
+pub struct UpsertBookmarkParams<T1: crate::clorinde::StringSql, T2: crate::clorinde::StringSql> {
+    pub user_id: uuid::Uuid,
+    pub article_id: uuid::Uuid,
+    pub note: Option<T1>,
+    pub folder: Option<T2>,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+}
+#[derive(Clone, Copy, Debug)]
+pub struct DeleteBookmarkParams {
+    pub user_id: uuid::Uuid,
+    pub article_id: uuid::Uuid,
+}
+#[derive(Clone, Copy, Debug)]
+pub struct GetBookmarkParams {
+    pub user_id: uuid::Uuid,
+    pub article_id: uuid::Uuid,
+}
+#[derive(Debug)]
+pub struct ListBookmarksParams<T1: crate::clorinde::StringSql> {
+    pub user_id: uuid::Uuid,
+    pub folder: Option<T1>,
+    pub limit: i64,
+    pub offset: i64,
+}
+#[derive(Debug)]
+pub struct CountBookmarksParams<T1: crate::clorinde::StringSql> {
+    pub user_id: uuid::Uuid,
+    pub folder: Option<T1>,
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct UpsertBookmark {
+    pub appuser_id: uuid::Uuid,
+    pub article_id: uuid::Uuid,
+    pub note: Option<String>,
+    pub folder: Option<String>,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+}
+pub struct UpsertBookmarkBorrowed<'a> {
+    pub appuser_id: uuid::Uuid,
+    pub article_id: uuid::Uuid,
+    pub note: Option<&'a str>,
+    pub folder: Option<&'a str>,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+}
+impl<'a> From<UpsertBookmarkBorrowed<'a>> for UpsertBookmark {
+    fn from(
+        UpsertBookmarkBorrowed {
+            appuser_id,
+            article_id,
+            note,
+            folder,
+            created_at,
+        }: UpsertBookmarkBorrowed<'a>,
+    ) -> Self {
+        Self {
+            appuser_id,
+            article_id,
+            note: note.map(|v| v.into()),
+            folder: folder.map(|v| v.into()),
+            created_at,
+        }
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct GetBookmark {
+    pub appuser_id: uuid::Uuid,
+    pub article_id: uuid::Uuid,
+    pub note: Option<String>,
+    pub folder: Option<String>,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+}
+pub struct GetBookmarkBorrowed<'a> {
+    pub appuser_id: uuid::Uuid,
+    pub article_id: uuid::Uuid,
+    pub note: Option<&'a str>,
+    pub folder: Option<&'a str>,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+}
+impl<'a> From<GetBookmarkBorrowed<'a>> for GetBookmark {
+    fn from(
+        GetBookmarkBorrowed {
+            appuser_id,
+            article_id,
+            note,
+            folder,
+            created_at,
+        }: GetBookmarkBorrowed<'a>,
+    ) -> Self {
+        Self {
+            appuser_id,
+            article_id,
+            note: note.map(|v| v.into()),
+            folder: folder.map(|v| v.into()),
+            created_at,
+        }
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListBookmarks {
+    pub id: uuid::Uuid,
+    pub slug: String,
+    pub title: String,
+    pub description: String,
+    pub body: String,
+    pub author_id: uuid::Uuid,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub status: String,
+    pub published_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub author_username: String,
+    pub author_bio: String,
+    pub author_image: String,
+    pub following_author: bool,
+    pub favorited: bool,
+    pub bookmarked: bool,
+    pub favorites_count: i64,
+    pub tag_list: Vec<String>,
+    pub reaction_kinds: Vec<String>,
+    pub reaction_counts: Vec<i32>,
+    pub viewer_reactions: Vec<String>,
+    pub note: Option<String>,
+    pub folder: Option<String>,
+    pub bookmarked_at: chrono::DateTime<chrono::FixedOffset>,
+}
+pub struct ListBookmarksBorrowed<'a> {
+    pub id: uuid::Uuid,
+    pub slug: &'a str,
+    pub title: &'a str,
+    pub description: &'a str,
+    pub body: &'a str,
+    pub author_id: uuid::Uuid,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub status: &'a str,
+    pub published_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub author_username: &'a str,
+    pub author_bio: &'a str,
+    pub author_image: &'a str,
+    pub following_author: bool,
+    pub favorited: bool,
+    pub bookmarked: bool,
+    pub favorites_count: i64,
+    pub tag_list: crate::clorinde::ArrayIterator<'a, &'a str>,
+    pub reaction_kinds: crate::clorinde::ArrayIterator<'a, &'a str>,
+    pub reaction_counts: crate::clorinde::ArrayIterator<'a, i32>,
+    pub viewer_reactions: crate::clorinde::ArrayIterator<'a, &'a str>,
+    pub note: Option<&'a str>,
+    pub folder: Option<&'a str>,
+    pub bookmarked_at: chrono::DateTime<chrono::FixedOffset>,
+}
+impl<'a> From<ListBookmarksBorrowed<'a>> for ListBookmarks {
+    fn from(
+        ListBookmarksBorrowed {
+            id,
+            slug,
+            title,
+            description,
+            body,
+            author_id,
+            created_at,
+            updated_at,
+            status,
+            published_at,
+            author_username,
+            author_bio,
+            author_image,
+            following_author,
+            favorited,
+            bookmarked,
+            favorites_count,
+            tag_list,
+            reaction_kinds,
+            reaction_counts,
+            viewer_reactions,
+            note,
+            folder,
+            bookmarked_at,
+        }: ListBookmarksBorrowed<'a>,
+    ) -> Self {
+        Self {
+            id,
+            slug: slug.into(),
+            title: title.into(),
+            description: description.into(),
+            body: body.into(),
+            author_id,
+            created_at,
+            updated_at,
+            status: status.into(),
+            published_at,
+            author_username: author_username.into(),
+            author_bio: author_bio.into(),
+            author_image: author_image.into(),
+            following_author,
+            favorited,
+            bookmarked,
+            favorites_count,
+            tag_list: tag_list.map(|v| v.into()).collect(),
+            reaction_kinds: reaction_kinds.map(|v| v.into()).collect(),
+            reaction_counts: reaction_counts.map(|v| v).collect(),
+            viewer_reactions: viewer_reactions.map(|v| v.into()).collect(),
+            note: note.map(|v| v.into()),
+            folder: folder.map(|v| v.into()),
+            bookmarked_at,
+        }
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListBookmarksByUser {
+    pub appuser_id: uuid::Uuid,
+    pub article_id: uuid::Uuid,
+    pub note: Option<String>,
+    pub folder: Option<String>,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+}
+pub struct ListBookmarksByUserBorrowed<'a> {
+    pub appuser_id: uuid::Uuid,
+    pub article_id: uuid::Uuid,
+    pub note: Option<&'a str>,
+    pub folder: Option<&'a str>,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+}
+impl<'a> From<ListBookmarksByUserBorrowed<'a>> for ListBookmarksByUser {
+    fn from(
+        ListBookmarksByUserBorrowed {
+            appuser_id,
+            article_id,
+            note,
+            folder,
+            created_at,
+        }: ListBookmarksByUserBorrowed<'a>,
+    ) -> Self {
+        Self {
+            appuser_id,
+            article_id,
+            note: note.map(|v| v.into()),
+            folder: folder.map(|v| v.into()),
+            created_at,
+        }
+    }
+}
+use crate::clorinde::client::async_::GenericClient;
+use futures::{self, StreamExt, TryStreamExt};
+pub struct UpsertBookmarkQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor: fn(&tokio_postgres::Row) -> Result<UpsertBookmarkBorrowed, tokio_postgres::Error>,
+    mapper: fn(UpsertBookmarkBorrowed) -> T,
+}
+impl<'c, 'a, 's, C, T: 'c, const N: usize> UpsertBookmarkQuery<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(
+        self,
+        mapper: fn(UpsertBookmarkBorrowed) -> R,
+    ) -> UpsertBookmarkQuery<'c, 'a, 's, C, R, N> {
+        UpsertBookmarkQuery {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
+        }
+    }
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct GetBookmarkQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor: fn(&tokio_postgres::Row) -> Result<GetBookmarkBorrowed, tokio_postgres::Error>,
+    mapper: fn(GetBookmarkBorrowed) -> T,
+}
+impl<'c, 'a, 's, C, T: 'c, const N: usize> GetBookmarkQuery<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(
+        self,
+        mapper: fn(GetBookmarkBorrowed) -> R,
+    ) -> GetBookmarkQuery<'c, 'a, 's, C, R, N> {
+        GetBookmarkQuery {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
+        }
+    }
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct ListBookmarksQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor: fn(&tokio_postgres::Row) -> Result<ListBookmarksBorrowed, tokio_postgres::Error>,
+    mapper: fn(ListBookmarksBorrowed) -> T,
+}
+impl<'c, 'a, 's, C, T: 'c, const N: usize> ListBookmarksQuery<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(
+        self,
+        mapper: fn(ListBookmarksBorrowed) -> R,
+    ) -> ListBookmarksQuery<'c, 'a, 's, C, R, N> {
+        ListBookmarksQuery {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
+        }
+    }
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct I64Query<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor: fn(&tokio_postgres::Row) -> Result<i64, tokio_postgres::Error>,
+    mapper: fn(i64) -> T,
+}
+impl<'c, 'a, 's, C, T: 'c, const N: usize> I64Query<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(self, mapper: fn(i64) -> R) -> I64Query<'c, 'a, 's, C, R, N> {
+        I64Query {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
+        }
+    }
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct ListBookmarksByUserQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor:
+        fn(&tokio_postgres::Row) -> Result<ListBookmarksByUserBorrowed, tokio_postgres::Error>,
+    mapper: fn(ListBookmarksByUserBorrowed) -> T,
+}
+impl<'c, 'a, 's, C, T: 'c, const N: usize> ListBookmarksByUserQuery<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(
+        self,
+        mapper: fn(ListBookmarksByUserBorrowed) -> R,
+    ) -> ListBookmarksByUserQuery<'c, 'a, 's, C, R, N> {
+        ListBookmarksByUserQuery {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
+        }
+    }
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct UpsertBookmarkStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn upsert_bookmark() -> UpsertBookmarkStmt {
+    UpsertBookmarkStmt(
+        "INSERT INTO article_bookmark (appuser_id, article_id, note, folder, created_at) VALUES ($1, $2, $3, $4, $5) ON CONFLICT (appuser_id, article_id) DO UPDATE SET note = EXCLUDED.note, folder = EXCLUDED.folder RETURNING appuser_id, article_id, note, folder, created_at",
+        None,
+    )
+}
+impl UpsertBookmarkStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub fn bind<'c, 'a, 's, C: GenericClient, T1: crate::clorinde::StringSql, T2: crate::clorinde::StringSql>(
+        &'s self,
+        client: &'c C,
+        user_id: &'a uuid::Uuid,
+        article_id: &'a uuid::Uuid,
+        note: &'a Option<T1>,
+        folder: &'a Option<T2>,
+        created_at: &'a chrono::DateTime<chrono::FixedOffset>,
+    ) -> UpsertBookmarkQuery<'c, 'a, 's, C, UpsertBookmark, 5> {
+        UpsertBookmarkQuery { client, params: [user_id, article_id, note, folder, created_at], query: self.0, cached: self.1.as_ref(), extractor: |row: &tokio_postgres::Row| -> Result<UpsertBookmarkBorrowed, tokio_postgres::Error> { Ok(UpsertBookmarkBorrowed { appuser_id: row.try_get(0)?,
+article_id: row.try_get(1)?,
+note: row.try_get(2)?,
+folder: row.try_get(3)?,
+created_at: row.try_get(4)?,
+ }) }, mapper: |it| UpsertBookmark::from(it), }
+    }
+}
+impl<'c, 'a, 's, C: GenericClient, T1: crate::clorinde::StringSql, T2: crate::clorinde::StringSql>
+    crate::clorinde::client::async_::Params<
+        'c,
+        'a,
+        's,
+        UpsertBookmarkParams<T1, T2>,
+        UpsertBookmarkQuery<'c, 'a, 's, C, UpsertBookmark, 5>,
+        C,
+    > for UpsertBookmarkStmt
+{
+    fn params(
+        &'s self,
+        client: &'c C,
+        params: &'a UpsertBookmarkParams<T1, T2>,
+    ) -> UpsertBookmarkQuery<'c, 'a, 's, C, UpsertBookmark, 5> {
+        self.bind(
+            client,
+            &params.user_id,
+            &params.article_id,
+            &params.note,
+            &params.folder,
+            &params.created_at,
+        )
+    }
+}
+pub struct DeleteBookmarkStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn delete_bookmark() -> DeleteBookmarkStmt {
+    DeleteBookmarkStmt(
+        "DELETE FROM article_bookmark WHERE appuser_id = $1 AND article_id = $2",
+        None,
+    )
+}
+impl DeleteBookmarkStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub async fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        user_id: &'a uuid::Uuid,
+        article_id: &'a uuid::Uuid,
+    ) -> Result<u64, tokio_postgres::Error> {
+        client.execute(self.0, &[user_id, article_id]).await
+    }
+}
+impl<'a, C: GenericClient + Send + Sync>
+    crate::clorinde::client::async_::Params<
+        'a,
+        'a,
+        'a,
+        DeleteBookmarkParams,
+        std::pin::Pin<
+            Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+        >,
+        C,
+    > for DeleteBookmarkStmt
+{
+    fn params(
+        &'a self,
+        client: &'a C,
+        params: &'a DeleteBookmarkParams,
+    ) -> std::pin::Pin<
+        Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+    > {
+        Box::pin(self.bind(client, &params.user_id, &params.article_id))
+    }
+}
+pub struct GetBookmarkStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn get_bookmark() -> GetBookmarkStmt {
+    GetBookmarkStmt(
+        "SELECT appuser_id, article_id, note, folder, created_at FROM article_bookmark WHERE appuser_id = $1 AND article_id = $2",
+        None,
+    )
+}
+impl GetBookmarkStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        user_id: &'a uuid::Uuid,
+        article_id: &'a uuid::Uuid,
+    ) -> GetBookmarkQuery<'c, 'a, 's, C, GetBookmark, 2> {
+        GetBookmarkQuery {
+            client,
+            params: [user_id, article_id],
+            query: self.0,
+            cached: self.1.as_ref(),
+            extractor:
+                |row: &tokio_postgres::Row| -> Result<GetBookmarkBorrowed, tokio_postgres::Error> {
+                    Ok(GetBookmarkBorrowed {
+                        appuser_id: row.try_get(0)?,
+                        article_id: row.try_get(1)?,
+                        note: row.try_get(2)?,
+                        folder: row.try_get(3)?,
+                        created_at: row.try_get(4)?,
+                    })
+                },
+            mapper: |it| GetBookmark::from(it),
+        }
+    }
+}
+impl<'c, 'a, 's, C: GenericClient>
+    crate::clorinde::client::async_::Params<
+        'c,
+        'a,
+        's,
+        GetBookmarkParams,
+        GetBookmarkQuery<'c, 'a, 's, C, GetBookmark, 2>,
+        C,
+    > for GetBookmarkStmt
+{
+    fn params(
+        &'s self,
+        client: &'c C,
+        params: &'a GetBookmarkParams,
+    ) -> GetBookmarkQuery<'c, 'a, 's, C, GetBookmark, 2> {
+        self.bind(client, &params.user_id, &params.article_id)
+    }
+}
+pub struct ListBookmarksStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn list_bookmarks() -> ListBookmarksStmt {
+    ListBookmarksStmt(
+        "SELECT a.id, a.slug, a.title, a.description, a.body, a.author_id, a.created_at, a.updated_at, a.status, a.published_at, u.username as author_username, u.bio as author_bio, u.img as author_image, EXISTS(SELECT 1 FROM appuser_follows WHERE follower_id = $1 AND followee_id = a.author_id) as following_author, EXISTS(SELECT 1 FROM article_favorite WHERE appuser_id = $1 AND article_id = a.id) as favorited, true as bookmarked, (SELECT COUNT(*) FROM article_favorite WHERE article_id = a.id) as favorites_count, ARRAY(SELECT t.name FROM tag t JOIN article_tag at ON t.id = at.tag_id WHERE at.article_id = a.id ORDER BY t.name) as tag_list, ARRAY(SELECT rc.kind FROM article_reaction_count rc WHERE rc.article_id = a.id ORDER BY rc.count DESC, rc.kind COLLATE ucs_basic) as reaction_kinds, ARRAY(SELECT rc.count FROM article_reaction_count rc WHERE rc.article_id = a.id ORDER BY rc.count DESC, rc.kind COLLATE ucs_basic) as reaction_counts, ARRAY(SELECT ar.kind FROM article_reaction ar WHERE ar.article_id = a.id AND ar.appuser_id = $1) as viewer_reactions, b.note, b.folder, b.created_at as bookmarked_at FROM article_bookmark b JOIN article a ON a.id = b.article_id JOIN appuser u ON a.author_id = u.id WHERE b.appuser_id = $1 AND u.deactivated_at IS NULL AND a.deleted_at IS NULL AND ((a.status = 'published' AND a.hidden_at IS NULL) OR a.author_id = $1) AND ($2::text IS NULL OR b.folder = $2) ORDER BY b.created_at DESC, a.id DESC LIMIT $3 OFFSET $4",
+        None,
+    )
+}
+impl ListBookmarksStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub fn bind<'c, 'a, 's, C: GenericClient, T1: crate::clorinde::StringSql>(
+        &'s self,
+        client: &'c C,
+        user_id: &'a uuid::Uuid,
+        folder: &'a Option<T1>,
+        limit: &'a i64,
+        offset: &'a i64,
+    ) -> ListBookmarksQuery<'c, 'a, 's, C, ListBookmarks, 4> {
+        ListBookmarksQuery {
+            client,
+            params: [user_id, folder, limit, offset],
+            query: self.0,
+            cached: self.1.as_ref(),
+            extractor:
+                |row: &tokio_postgres::Row| -> Result<ListBookmarksBorrowed, tokio_postgres::Error> {
+                    Ok(ListBookmarksBorrowed {
+                        id: row.try_get(0)?,
+                        slug: row.try_get(1)?,
+                        title: row.try_get(2)?,
+                        description: row.try_get(3)?,
+                        body: row.try_get(4)?,
+                        author_id: row.try_get(5)?,
+                        created_at: row.try_get(6)?,
+                        updated_at: row.try_get(7)?,
+                        status: row.try_get(8)?,
+                        published_at: row.try_get(9)?,
+                        author_username: row.try_get(10)?,
+                        author_bio: row.try_get(11)?,
+                        author_image: row.try_get(12)?,
+                        following_author: row.try_get(13)?,
+                        favorited: row.try_get(14)?,
+                        bookmarked: row.try_get(15)?,
+                        favorites_count: row.try_get(16)?,
+                        tag_list: row.try_get(17)?,
+                        reaction_kinds: row.try_get(18)?,
+                        reaction_counts: row.try_get(19)?,
+                        viewer_reactions: row.try_get(20)?,
+                        note: row.try_get(21)?,
+                        folder: row.try_get(22)?,
+                        bookmarked_at: row.try_get(23)?,
+                    })
+                },
+            mapper: |it| ListBookmarks::from(it),
+        }
+    }
+}
+impl<'c, 'a, 's, C: GenericClient, T1: crate::clorinde::StringSql>
+    crate::clorinde::client::async_::Params<
+        'c,
+        'a,
+        's,
+        ListBookmarksParams<T1>,
+        ListBookmarksQuery<'c, 'a, 's, C, ListBookmarks, 4>,
+        C,
+    > for ListBookmarksStmt
+{
+    fn params(
+        &'s self,
+        client: &'c C,
+        params: &'a ListBookmarksParams<T1>,
+    ) -> ListBookmarksQuery<'c, 'a, 's, C, ListBookmarks, 4> {
+        self.bind(
+            client,
+            &params.user_id,
+            &params.folder,
+            &params.limit,
+            &params.offset,
+        )
+    }
+}
+pub struct CountBookmarksStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn count_bookmarks() -> CountBookmarksStmt {
+    CountBookmarksStmt(
+        "SELECT COUNT(*) FROM article_bookmark b JOIN article a ON a.id = b.article_id JOIN appuser u ON a.author_id = u.id WHERE b.appuser_id = $1 AND u.deactivated_at IS NULL AND a.deleted_at IS NULL AND (a.status = 'published' OR a.author_id = $1) AND ($2::text IS NULL OR b.folder = $2)",
+        None,
+    )
+}
+impl CountBookmarksStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub fn bind<'c, 'a, 's, C: GenericClient, T1: crate::clorinde::StringSql>(
+        &'s self,
+        client: &'c C,
+        user_id: &'a uuid::Uuid,
+        folder: &'a Option<T1>,
+    ) -> I64Query<'c, 'a, 's, C, i64, 2> {
+        I64Query {
+            client,
+            params: [user_id, folder],
+            query: self.0,
+            cached: self.1.as_ref(),
+            extractor: |row| Ok(row.try_get(0)?),
+            mapper: |it| it,
+        }
+    }
+}
+impl<'c, 'a, 's, C: GenericClient, T1: crate::clorinde::StringSql>
+    crate::clorinde::client::async_::Params<
+        'c,
+        'a,
+        's,
+        CountBookmarksParams<T1>,
+        I64Query<'c, 'a, 's, C, i64, 2>,
+        C,
+    > for CountBookmarksStmt
+{
+    fn params(
+        &'s self,
+        client: &'c C,
+        params: &'a CountBookmarksParams<T1>,
+    ) -> I64Query<'c, 'a, 's, C, i64, 2> {
+        self.bind(client, &params.user_id, &params.folder)
+    }
+}
+pub struct ListBookmarksByUserStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn list_bookmarks_by_user() -> ListBookmarksByUserStmt {
+    ListBookmarksByUserStmt(
+        "SELECT appuser_id, article_id, note, folder, created_at FROM article_bookmark WHERE appuser_id = $1 ORDER BY created_at DESC, article_id",
+        None,
+    )
+}
+impl ListBookmarksByUserStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        user_id: &'a uuid::Uuid,
+    ) -> ListBookmarksByUserQuery<'c, 'a, 's, C, ListBookmarksByUser, 1> {
+        ListBookmarksByUserQuery { client, params: [user_id], query: self.0, cached: self.1.as_ref(), extractor: |row: &tokio_postgres::Row| -> Result<ListBookmarksByUserBorrowed, tokio_postgres::Error> { Ok(ListBookmarksByUserBorrowed { appuser_id: row.try_get(0)?,
+article_id: row.try_get(1)?,
+note: row.try_get(2)?,
+folder: row.try_get(3)?,
+created_at: row.try_get(4)?,
+ }) }, mapper: |it| ListBookmarksByUser::from(it), }
+    }
+}
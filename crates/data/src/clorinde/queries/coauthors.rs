@@ -0,0 +1,419 @@
+// This file was generated with `clorinde`. Do not modify.
+
+#[derive(Clone, Copy, Debug)]
+pub struct InviteCoauthorParams {
+    pub article_id: uuid::Uuid,
+    pub appuser_id: uuid::Uuid,
+    pub invited_at: chrono::DateTime<chrono::FixedOffset>,
+}
+#[derive(Clone, Copy, Debug)]
+pub struct GetCoauthorParams {
+    pub article_id: uuid::Uuid,
+    pub appuser_id: uuid::Uuid,
+}
+#[derive(Clone, Copy, Debug)]
+pub struct AcceptCoauthorParams {
+    pub accepted_at: chrono::DateTime<chrono::FixedOffset>,
+    pub article_id: uuid::Uuid,
+    pub appuser_id: uuid::Uuid,
+}
+#[derive(Clone, Copy, Debug)]
+pub struct RemoveCoauthorParams {
+    pub article_id: uuid::Uuid,
+    pub appuser_id: uuid::Uuid,
+}
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub struct GetCoauthor {
+    pub article_id: uuid::Uuid,
+    pub appuser_id: uuid::Uuid,
+    pub invited_at: chrono::DateTime<chrono::FixedOffset>,
+    pub accepted_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+}
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub struct ListCoauthorships {
+    pub article_id: uuid::Uuid,
+    pub appuser_id: uuid::Uuid,
+    pub invited_at: chrono::DateTime<chrono::FixedOffset>,
+    pub accepted_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+}
+use crate::clorinde::client::async_::GenericClient;
+use futures::{self, StreamExt, TryStreamExt};
+pub struct GetCoauthorQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor: fn(&tokio_postgres::Row) -> Result<GetCoauthor, tokio_postgres::Error>,
+    mapper: fn(GetCoauthor) -> T,
+}
+impl<'c, 'a, 's, C, T: 'c, const N: usize> GetCoauthorQuery<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(self, mapper: fn(GetCoauthor) -> R) -> GetCoauthorQuery<'c, 'a, 's, C, R, N> {
+        GetCoauthorQuery {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
+        }
+    }
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct ListCoauthorshipsQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor: fn(&tokio_postgres::Row) -> Result<ListCoauthorships, tokio_postgres::Error>,
+    mapper: fn(ListCoauthorships) -> T,
+}
+impl<'c, 'a, 's, C, T: 'c, const N: usize> ListCoauthorshipsQuery<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(
+        self,
+        mapper: fn(ListCoauthorships) -> R,
+    ) -> ListCoauthorshipsQuery<'c, 'a, 's, C, R, N> {
+        ListCoauthorshipsQuery {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
+        }
+    }
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct InviteCoauthorStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn invite_coauthor() -> InviteCoauthorStmt {
+    InviteCoauthorStmt(
+        "INSERT INTO article_coauthor (article_id, appuser_id, invited_at) VALUES ($1, $2, $3) ON CONFLICT (article_id, appuser_id) DO NOTHING",
+        None,
+    )
+}
+impl InviteCoauthorStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub async fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        article_id: &'a uuid::Uuid,
+        appuser_id: &'a uuid::Uuid,
+        invited_at: &'a chrono::DateTime<chrono::FixedOffset>,
+    ) -> Result<u64, tokio_postgres::Error> {
+        client
+            .execute(self.0, &[article_id, appuser_id, invited_at])
+            .await
+    }
+}
+impl<'a, C: GenericClient + Send + Sync>
+    crate::clorinde::client::async_::Params<
+        'a,
+        'a,
+        'a,
+        InviteCoauthorParams,
+        std::pin::Pin<
+            Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+        >,
+        C,
+    > for InviteCoauthorStmt
+{
+    fn params(
+        &'a self,
+        client: &'a C,
+        params: &'a InviteCoauthorParams,
+    ) -> std::pin::Pin<
+        Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+    > {
+        Box::pin(self.bind(
+            client,
+            &params.article_id,
+            &params.appuser_id,
+            &params.invited_at,
+        ))
+    }
+}
+pub struct GetCoauthorStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn get_coauthor() -> GetCoauthorStmt {
+    GetCoauthorStmt(
+        "SELECT article_id, appuser_id, invited_at, accepted_at FROM article_coauthor WHERE article_id = $1 AND appuser_id = $2",
+        None,
+    )
+}
+impl GetCoauthorStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        article_id: &'a uuid::Uuid,
+        appuser_id: &'a uuid::Uuid,
+    ) -> GetCoauthorQuery<'c, 'a, 's, C, GetCoauthor, 2> {
+        GetCoauthorQuery {
+            client,
+            params: [article_id, appuser_id],
+            query: self.0,
+            cached: self.1.as_ref(),
+            extractor: |row: &tokio_postgres::Row| -> Result<GetCoauthor, tokio_postgres::Error> {
+                Ok(GetCoauthor {
+                    article_id: row.try_get(0)?,
+                    appuser_id: row.try_get(1)?,
+                    invited_at: row.try_get(2)?,
+                    accepted_at: row.try_get(3)?,
+                })
+            },
+            mapper: |it| it,
+        }
+    }
+}
+impl<'c, 'a, 's, C: GenericClient>
+    crate::clorinde::client::async_::Params<
+        'c,
+        'a,
+        's,
+        GetCoauthorParams,
+        GetCoauthorQuery<'c, 'a, 's, C, GetCoauthor, 2>,
+        C,
+    > for GetCoauthorStmt
+{
+    fn params(
+        &'s self,
+        client: &'c C,
+        params: &'a GetCoauthorParams,
+    ) -> GetCoauthorQuery<'c, 'a, 's, C, GetCoauthor, 2> {
+        self.bind(client, &params.article_id, &params.appuser_id)
+    }
+}
+pub struct AcceptCoauthorStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn accept_coauthor() -> AcceptCoauthorStmt {
+    AcceptCoauthorStmt(
+        "UPDATE article_coauthor SET accepted_at = COALESCE(accepted_at, $1) WHERE article_id = $2 AND appuser_id = $3",
+        None,
+    )
+}
+impl AcceptCoauthorStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub async fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        accepted_at: &'a chrono::DateTime<chrono::FixedOffset>,
+        article_id: &'a uuid::Uuid,
+        appuser_id: &'a uuid::Uuid,
+    ) -> Result<u64, tokio_postgres::Error> {
+        client
+            .execute(self.0, &[accepted_at, article_id, appuser_id])
+            .await
+    }
+}
+impl<'a, C: GenericClient + Send + Sync>
+    crate::clorinde::client::async_::Params<
+        'a,
+        'a,
+        'a,
+        AcceptCoauthorParams,
+        std::pin::Pin<
+            Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+        >,
+        C,
+    > for AcceptCoauthorStmt
+{
+    fn params(
+        &'a self,
+        client: &'a C,
+        params: &'a AcceptCoauthorParams,
+    ) -> std::pin::Pin<
+        Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+    > {
+        Box::pin(self.bind(
+            client,
+            &params.accepted_at,
+            &params.article_id,
+            &params.appuser_id,
+        ))
+    }
+}
+pub struct RemoveCoauthorStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn remove_coauthor() -> RemoveCoauthorStmt {
+    RemoveCoauthorStmt(
+        "DELETE FROM article_coauthor WHERE article_id = $1 AND appuser_id = $2",
+        None,
+    )
+}
+impl RemoveCoauthorStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub async fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        article_id: &'a uuid::Uuid,
+        appuser_id: &'a uuid::Uuid,
+    ) -> Result<u64, tokio_postgres::Error> {
+        client.execute(self.0, &[article_id, appuser_id]).await
+    }
+}
+impl<'a, C: GenericClient + Send + Sync>
+    crate::clorinde::client::async_::Params<
+        'a,
+        'a,
+        'a,
+        RemoveCoauthorParams,
+        std::pin::Pin<
+            Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+        >,
+        C,
+    > for RemoveCoauthorStmt
+{
+    fn params(
+        &'a self,
+        client: &'a C,
+        params: &'a RemoveCoauthorParams,
+    ) -> std::pin::Pin<
+        Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+    > {
+        Box::pin(self.bind(client, &params.article_id, &params.appuser_id))
+    }
+}
+pub struct ListCoauthorshipsStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn list_coauthorships() -> ListCoauthorshipsStmt {
+    ListCoauthorshipsStmt(
+        "SELECT article_id, appuser_id, invited_at, accepted_at FROM article_coauthor WHERE appuser_id = $1 ORDER BY invited_at, article_id",
+        None,
+    )
+}
+impl ListCoauthorshipsStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        appuser_id: &'a uuid::Uuid,
+    ) -> ListCoauthorshipsQuery<'c, 'a, 's, C, ListCoauthorships, 1> {
+        ListCoauthorshipsQuery {
+            client,
+            params: [appuser_id],
+            query: self.0,
+            cached: self.1.as_ref(),
+            extractor:
+                |row: &tokio_postgres::Row| -> Result<ListCoauthorships, tokio_postgres::Error> {
+                    Ok(ListCoauthorships {
+                        article_id: row.try_get(0)?,
+                        appuser_id: row.try_get(1)?,
+                        invited_at: row.try_get(2)?,
+                        accepted_at: row.try_get(3)?,
+                    })
+                },
+            mapper: |it| it,
+        }
+    }
+}
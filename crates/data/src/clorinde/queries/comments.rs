@@ -7,6 +7,38 @@ pub struct CreateCommentParams<T1: crate::clorinde::StringSql> {
     pub author_id: uuid::Uuid,
     pub created_at: chrono::DateTime<chrono::FixedOffset>,
 }
+#[derive(Clone, Copy, Debug)]
+pub struct DeleteCommentParams {
+    pub deleted_at: chrono::DateTime<chrono::FixedOffset>,
+    pub id: i32,
+}
+#[derive(Clone, Copy, Debug)]
+pub struct HideCommentParams {
+    pub hidden_at: chrono::DateTime<chrono::FixedOffset>,
+    pub id: i32,
+}
+#[derive(Clone, Copy, Debug)]
+pub struct ReassignCommentsParams {
+    pub to_author_id: uuid::Uuid,
+    pub from_author_id: uuid::Uuid,
+}
+#[derive(Debug)]
+pub struct AddCommentReactionParams<T1: crate::clorinde::StringSql> {
+    pub comment_id: i32,
+    pub user_id: uuid::Uuid,
+    pub kind: T1,
+}
+#[derive(Debug)]
+pub struct RemoveCommentReactionParams<T1: crate::clorinde::StringSql> {
+    pub comment_id: i32,
+    pub user_id: uuid::Uuid,
+    pub kind: T1,
+}
+#[derive(Debug)]
+pub struct CommentReactionsParams<T1: crate::clorinde::ArraySql<Item = i32>> {
+    pub viewer_id: uuid::Uuid,
+    pub comment_ids: T1,
+}
 #[derive(Debug, Clone, PartialEq)]
 pub struct CreateComment {
     pub id: i32,
@@ -15,6 +47,8 @@ pub struct CreateComment {
     pub author_id: uuid::Uuid,
     pub created_at: chrono::DateTime<chrono::FixedOffset>,
     pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub deleted_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub hidden_at: Option<chrono::DateTime<chrono::FixedOffset>>,
 }
 pub struct CreateCommentBorrowed<'a> {
     pub id: i32,
@@ -23,6 +57,8 @@ pub struct CreateCommentBorrowed<'a> {
     pub author_id: uuid::Uuid,
     pub created_at: chrono::DateTime<chrono::FixedOffset>,
     pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub deleted_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub hidden_at: Option<chrono::DateTime<chrono::FixedOffset>>,
 }
 impl<'a> From<CreateCommentBorrowed<'a>> for CreateComment {
     fn from(
@@ -33,6 +69,8 @@ impl<'a> From<CreateCommentBorrowed<'a>> for CreateComment {
             author_id,
             created_at,
             updated_at,
+            deleted_at,
+            hidden_at,
         }: CreateCommentBorrowed<'a>,
     ) -> Self {
         Self {
@@ -42,6 +80,8 @@ impl<'a> From<CreateCommentBorrowed<'a>> for CreateComment {
             author_id,
             created_at,
             updated_at,
+            deleted_at,
+            hidden_at,
         }
     }
 }
@@ -53,6 +93,8 @@ pub struct GetCommentsByArticle {
     pub author_id: uuid::Uuid,
     pub created_at: chrono::DateTime<chrono::FixedOffset>,
     pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub deleted_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub hidden_at: Option<chrono::DateTime<chrono::FixedOffset>>,
 }
 pub struct GetCommentsByArticleBorrowed<'a> {
     pub id: i32,
@@ -61,6 +103,8 @@ pub struct GetCommentsByArticleBorrowed<'a> {
     pub author_id: uuid::Uuid,
     pub created_at: chrono::DateTime<chrono::FixedOffset>,
     pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub deleted_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub hidden_at: Option<chrono::DateTime<chrono::FixedOffset>>,
 }
 impl<'a> From<GetCommentsByArticleBorrowed<'a>> for GetCommentsByArticle {
     fn from(
@@ -71,6 +115,8 @@ impl<'a> From<GetCommentsByArticleBorrowed<'a>> for GetCommentsByArticle {
             author_id,
             created_at,
             updated_at,
+            deleted_at,
+            hidden_at,
         }: GetCommentsByArticleBorrowed<'a>,
     ) -> Self {
         Self {
@@ -80,6 +126,8 @@ impl<'a> From<GetCommentsByArticleBorrowed<'a>> for GetCommentsByArticle {
             author_id,
             created_at,
             updated_at,
+            deleted_at,
+            hidden_at,
         }
     }
 }
@@ -91,6 +139,8 @@ pub struct GetCommentById {
     pub author_id: uuid::Uuid,
     pub created_at: chrono::DateTime<chrono::FixedOffset>,
     pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub deleted_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub hidden_at: Option<chrono::DateTime<chrono::FixedOffset>>,
 }
 pub struct GetCommentByIdBorrowed<'a> {
     pub id: i32,
@@ -99,6 +149,8 @@ pub struct GetCommentByIdBorrowed<'a> {
     pub author_id: uuid::Uuid,
     pub created_at: chrono::DateTime<chrono::FixedOffset>,
     pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub deleted_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub hidden_at: Option<chrono::DateTime<chrono::FixedOffset>>,
 }
 impl<'a> From<GetCommentByIdBorrowed<'a>> for GetCommentById {
     fn from(
@@ -109,6 +161,8 @@ impl<'a> From<GetCommentByIdBorrowed<'a>> for GetCommentById {
             author_id,
             created_at,
             updated_at,
+            deleted_at,
+            hidden_at,
         }: GetCommentByIdBorrowed<'a>,
     ) -> Self {
         Self {
@@ -118,6 +172,197 @@ impl<'a> From<GetCommentByIdBorrowed<'a>> for GetCommentById {
             author_id,
             created_at,
             updated_at,
+            deleted_at,
+            hidden_at,
+        }
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct GetCommentsByAuthor {
+    pub id: i32,
+    pub body: String,
+    pub article_id: uuid::Uuid,
+    pub author_id: uuid::Uuid,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub deleted_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub hidden_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+}
+pub struct GetCommentsByAuthorBorrowed<'a> {
+    pub id: i32,
+    pub body: &'a str,
+    pub article_id: uuid::Uuid,
+    pub author_id: uuid::Uuid,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub deleted_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub hidden_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+}
+impl<'a> From<GetCommentsByAuthorBorrowed<'a>> for GetCommentsByAuthor {
+    fn from(
+        GetCommentsByAuthorBorrowed {
+            id,
+            body,
+            article_id,
+            author_id,
+            created_at,
+            updated_at,
+            deleted_at,
+            hidden_at,
+        }: GetCommentsByAuthorBorrowed<'a>,
+    ) -> Self {
+        Self {
+            id,
+            body: body.into(),
+            article_id,
+            author_id,
+            created_at,
+            updated_at,
+            deleted_at,
+            hidden_at,
+        }
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct GetTrashedComment {
+    pub id: i32,
+    pub body: String,
+    pub article_id: uuid::Uuid,
+    pub author_id: uuid::Uuid,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub deleted_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub hidden_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+}
+pub struct GetTrashedCommentBorrowed<'a> {
+    pub id: i32,
+    pub body: &'a str,
+    pub article_id: uuid::Uuid,
+    pub author_id: uuid::Uuid,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub deleted_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub hidden_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+}
+impl<'a> From<GetTrashedCommentBorrowed<'a>> for GetTrashedComment {
+    fn from(
+        GetTrashedCommentBorrowed {
+            id,
+            body,
+            article_id,
+            author_id,
+            created_at,
+            updated_at,
+            deleted_at,
+            hidden_at,
+        }: GetTrashedCommentBorrowed<'a>,
+    ) -> Self {
+        Self {
+            id,
+            body: body.into(),
+            article_id,
+            author_id,
+            created_at,
+            updated_at,
+            deleted_at,
+            hidden_at,
+        }
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListTrashedComments {
+    pub id: i32,
+    pub body: String,
+    pub article_id: uuid::Uuid,
+    pub author_id: uuid::Uuid,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub deleted_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub hidden_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+}
+pub struct ListTrashedCommentsBorrowed<'a> {
+    pub id: i32,
+    pub body: &'a str,
+    pub article_id: uuid::Uuid,
+    pub author_id: uuid::Uuid,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub deleted_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub hidden_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+}
+impl<'a> From<ListTrashedCommentsBorrowed<'a>> for ListTrashedComments {
+    fn from(
+        ListTrashedCommentsBorrowed {
+            id,
+            body,
+            article_id,
+            author_id,
+            created_at,
+            updated_at,
+            deleted_at,
+            hidden_at,
+        }: ListTrashedCommentsBorrowed<'a>,
+    ) -> Self {
+        Self {
+            id,
+            body: body.into(),
+            article_id,
+            author_id,
+            created_at,
+            updated_at,
+            deleted_at,
+            hidden_at,
+        }
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommentReactions {
+    pub comment_id: i32,
+    pub kind: String,
+    pub count: i32,
+    pub reacted: bool,
+}
+pub struct CommentReactionsBorrowed<'a> {
+    pub comment_id: i32,
+    pub kind: &'a str,
+    pub count: i32,
+    pub reacted: bool,
+}
+impl<'a> From<CommentReactionsBorrowed<'a>> for CommentReactions {
+    fn from(
+        CommentReactionsBorrowed {
+            comment_id,
+            kind,
+            count,
+            reacted,
+        }: CommentReactionsBorrowed<'a>,
+    ) -> Self {
+        Self {
+            comment_id,
+            kind: kind.into(),
+            count,
+            reacted,
+        }
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListCommentReactionsByUser {
+    pub comment_id: i32,
+    pub kind: String,
+}
+pub struct ListCommentReactionsByUserBorrowed<'a> {
+    pub comment_id: i32,
+    pub kind: &'a str,
+}
+impl<'a> From<ListCommentReactionsByUserBorrowed<'a>> for ListCommentReactionsByUser {
+    fn from(
+        ListCommentReactionsByUserBorrowed { comment_id, kind }: ListCommentReactionsByUserBorrowed<
+            'a,
+        >,
+    ) -> Self {
+        Self {
+            comment_id,
+            kind: kind.into(),
         }
     }
 }
@@ -325,83 +570,565 @@ where
         Ok(mapped)
     }
 }
-pub struct CreateCommentStmt(&'static str, Option<tokio_postgres::Statement>);
-pub fn create_comment() -> CreateCommentStmt {
-    CreateCommentStmt(
-        "INSERT INTO comment (body, article_id, author_id, created_at, updated_at) VALUES ($1, $2, $3, $4, $4) RETURNING *",
-        None,
-    )
+pub struct GetCommentsByAuthorQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor:
+        fn(&tokio_postgres::Row) -> Result<GetCommentsByAuthorBorrowed, tokio_postgres::Error>,
+    mapper: fn(GetCommentsByAuthorBorrowed) -> T,
 }
-impl CreateCommentStmt {
-    pub async fn prepare<'a, C: GenericClient>(
-        mut self,
-        client: &'a C,
-    ) -> Result<Self, tokio_postgres::Error> {
-        self.1 = Some(client.prepare(self.0).await?);
-        Ok(self)
-    }
-    pub fn bind<'c, 'a, 's, C: GenericClient, T1: crate::clorinde::StringSql>(
-        &'s self,
-        client: &'c C,
-        body: &'a T1,
-        article_id: &'a uuid::Uuid,
-        author_id: &'a uuid::Uuid,
-        created_at: &'a chrono::DateTime<chrono::FixedOffset>,
-    ) -> CreateCommentQuery<'c, 'a, 's, C, CreateComment, 4> {
-        CreateCommentQuery {
-            client,
-            params: [body, article_id, author_id, created_at],
-            query: self.0,
-            cached: self.1.as_ref(),
-            extractor:
-                |row: &tokio_postgres::Row| -> Result<CreateCommentBorrowed, tokio_postgres::Error> {
-                    Ok(CreateCommentBorrowed {
-                        id: row.try_get(0)?,
-                        body: row.try_get(1)?,
-                        article_id: row.try_get(2)?,
-                        author_id: row.try_get(3)?,
-                        created_at: row.try_get(4)?,
-                        updated_at: row.try_get(5)?,
-                    })
-                },
-            mapper: |it| CreateComment::from(it),
+impl<'c, 'a, 's, C, T: 'c, const N: usize> GetCommentsByAuthorQuery<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(
+        self,
+        mapper: fn(GetCommentsByAuthorBorrowed) -> R,
+    ) -> GetCommentsByAuthorQuery<'c, 'a, 's, C, R, N> {
+        GetCommentsByAuthorQuery {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
         }
     }
-}
-impl<'c, 'a, 's, C: GenericClient, T1: crate::clorinde::StringSql>
-    crate::clorinde::client::async_::Params<
-        'c,
-        'a,
-        's,
-        CreateCommentParams<T1>,
-        CreateCommentQuery<'c, 'a, 's, C, CreateComment, 4>,
-        C,
-    > for CreateCommentStmt
-{
-    fn params(
-        &'s self,
-        client: &'c C,
-        params: &'a CreateCommentParams<T1>,
-    ) -> CreateCommentQuery<'c, 'a, 's, C, CreateComment, 4> {
-        self.bind(
-            client,
-            &params.body,
-            &params.article_id,
-            &params.author_id,
-            &params.created_at,
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
         )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
     }
 }
-pub struct GetCommentsByArticleStmt(&'static str, Option<tokio_postgres::Statement>);
-pub fn get_comments_by_article() -> GetCommentsByArticleStmt {
-    GetCommentsByArticleStmt(
-        "SELECT * FROM comment WHERE article_id = $1 ORDER BY created_at DESC",
-        None,
-    )
+pub struct GetTrashedCommentQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor: fn(&tokio_postgres::Row) -> Result<GetTrashedCommentBorrowed, tokio_postgres::Error>,
+    mapper: fn(GetTrashedCommentBorrowed) -> T,
 }
-impl GetCommentsByArticleStmt {
-    pub async fn prepare<'a, C: GenericClient>(
-        mut self,
+impl<'c, 'a, 's, C, T: 'c, const N: usize> GetTrashedCommentQuery<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(
+        self,
+        mapper: fn(GetTrashedCommentBorrowed) -> R,
+    ) -> GetTrashedCommentQuery<'c, 'a, 's, C, R, N> {
+        GetTrashedCommentQuery {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
+        }
+    }
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct ListTrashedCommentsQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor:
+        fn(&tokio_postgres::Row) -> Result<ListTrashedCommentsBorrowed, tokio_postgres::Error>,
+    mapper: fn(ListTrashedCommentsBorrowed) -> T,
+}
+impl<'c, 'a, 's, C, T: 'c, const N: usize> ListTrashedCommentsQuery<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(
+        self,
+        mapper: fn(ListTrashedCommentsBorrowed) -> R,
+    ) -> ListTrashedCommentsQuery<'c, 'a, 's, C, R, N> {
+        ListTrashedCommentsQuery {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
+        }
+    }
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct I32Query<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor: fn(&tokio_postgres::Row) -> Result<i32, tokio_postgres::Error>,
+    mapper: fn(i32) -> T,
+}
+impl<'c, 'a, 's, C, T: 'c, const N: usize> I32Query<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(self, mapper: fn(i32) -> R) -> I32Query<'c, 'a, 's, C, R, N> {
+        I32Query {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
+        }
+    }
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct CommentReactionsQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor: fn(&tokio_postgres::Row) -> Result<CommentReactionsBorrowed, tokio_postgres::Error>,
+    mapper: fn(CommentReactionsBorrowed) -> T,
+}
+impl<'c, 'a, 's, C, T: 'c, const N: usize> CommentReactionsQuery<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(
+        self,
+        mapper: fn(CommentReactionsBorrowed) -> R,
+    ) -> CommentReactionsQuery<'c, 'a, 's, C, R, N> {
+        CommentReactionsQuery {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
+        }
+    }
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct ListCommentReactionsByUserQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor: fn(
+        &tokio_postgres::Row,
+    ) -> Result<ListCommentReactionsByUserBorrowed, tokio_postgres::Error>,
+    mapper: fn(ListCommentReactionsByUserBorrowed) -> T,
+}
+impl<'c, 'a, 's, C, T: 'c, const N: usize> ListCommentReactionsByUserQuery<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(
+        self,
+        mapper: fn(ListCommentReactionsByUserBorrowed) -> R,
+    ) -> ListCommentReactionsByUserQuery<'c, 'a, 's, C, R, N> {
+        ListCommentReactionsByUserQuery {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
+        }
+    }
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct CreateCommentStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn create_comment() -> CreateCommentStmt {
+    CreateCommentStmt(
+        "INSERT INTO comment (body, article_id, author_id, created_at, updated_at) VALUES ($1, $2, $3, $4, $4) RETURNING *",
+        None,
+    )
+}
+impl CreateCommentStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub fn bind<'c, 'a, 's, C: GenericClient, T1: crate::clorinde::StringSql>(
+        &'s self,
+        client: &'c C,
+        body: &'a T1,
+        article_id: &'a uuid::Uuid,
+        author_id: &'a uuid::Uuid,
+        created_at: &'a chrono::DateTime<chrono::FixedOffset>,
+    ) -> CreateCommentQuery<'c, 'a, 's, C, CreateComment, 4> {
+        CreateCommentQuery {
+            client,
+            params: [body, article_id, author_id, created_at],
+            query: self.0,
+            cached: self.1.as_ref(),
+            extractor:
+                |row: &tokio_postgres::Row| -> Result<CreateCommentBorrowed, tokio_postgres::Error> {
+                    Ok(CreateCommentBorrowed {
+                        id: row.try_get(0)?,
+                        body: row.try_get(1)?,
+                        article_id: row.try_get(2)?,
+                        author_id: row.try_get(3)?,
+                        created_at: row.try_get(4)?,
+                        updated_at: row.try_get(5)?,
+                        deleted_at: row.try_get(6)?,
+                        hidden_at: row.try_get(7)?,
+                    })
+                },
+            mapper: |it| CreateComment::from(it),
+        }
+    }
+}
+impl<'c, 'a, 's, C: GenericClient, T1: crate::clorinde::StringSql>
+    crate::clorinde::client::async_::Params<
+        'c,
+        'a,
+        's,
+        CreateCommentParams<T1>,
+        CreateCommentQuery<'c, 'a, 's, C, CreateComment, 4>,
+        C,
+    > for CreateCommentStmt
+{
+    fn params(
+        &'s self,
+        client: &'c C,
+        params: &'a CreateCommentParams<T1>,
+    ) -> CreateCommentQuery<'c, 'a, 's, C, CreateComment, 4> {
+        self.bind(
+            client,
+            &params.body,
+            &params.article_id,
+            &params.author_id,
+            &params.created_at,
+        )
+    }
+}
+pub struct GetCommentsByArticleStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn get_comments_by_article() -> GetCommentsByArticleStmt {
+    GetCommentsByArticleStmt(
+        "SELECT * FROM comment WHERE article_id = $1 AND deleted_at IS NULL ORDER BY created_at DESC",
+        None,
+    )
+}
+impl GetCommentsByArticleStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        article_id: &'a uuid::Uuid,
+    ) -> GetCommentsByArticleQuery<'c, 'a, 's, C, GetCommentsByArticle, 1> {
+        GetCommentsByArticleQuery { client, params: [article_id], query: self.0, cached: self.1.as_ref(), extractor: |row: &tokio_postgres::Row| -> Result<GetCommentsByArticleBorrowed, tokio_postgres::Error> { Ok(GetCommentsByArticleBorrowed { id: row.try_get(0)?,
+body: row.try_get(1)?,
+article_id: row.try_get(2)?,
+author_id: row.try_get(3)?,
+created_at: row.try_get(4)?,
+updated_at: row.try_get(5)?,
+deleted_at: row.try_get(6)?,
+hidden_at: row.try_get(7)?,
+ }) }, mapper: |it| GetCommentsByArticle::from(it), }
+    }
+}
+pub struct DeleteCommentStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn delete_comment() -> DeleteCommentStmt {
+    DeleteCommentStmt(
+        "UPDATE comment SET deleted_at = $1 WHERE id = $2 AND deleted_at IS NULL",
+        None,
+    )
+}
+impl DeleteCommentStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub async fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        deleted_at: &'a chrono::DateTime<chrono::FixedOffset>,
+        id: &'a i32,
+    ) -> Result<u64, tokio_postgres::Error> {
+        client.execute(self.0, &[deleted_at, id]).await
+    }
+}
+impl<'a, C: GenericClient + Send + Sync>
+    crate::clorinde::client::async_::Params<
+        'a,
+        'a,
+        'a,
+        DeleteCommentParams,
+        std::pin::Pin<
+            Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+        >,
+        C,
+    > for DeleteCommentStmt
+{
+    fn params(
+        &'a self,
+        client: &'a C,
+        params: &'a DeleteCommentParams,
+    ) -> std::pin::Pin<
+        Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+    > {
+        Box::pin(self.bind(client, &params.deleted_at, &params.id))
+    }
+}
+pub struct GetCommentByIdStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn get_comment_by_id() -> GetCommentByIdStmt {
+    GetCommentByIdStmt(
+        "SELECT * FROM comment WHERE id = $1 AND deleted_at IS NULL",
+        None,
+    )
+}
+impl GetCommentByIdStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
         client: &'a C,
     ) -> Result<Self, tokio_postgres::Error> {
         self.1 = Some(client.prepare(self.0).await?);
@@ -410,34 +1137,24 @@ impl GetCommentsByArticleStmt {
     pub fn bind<'c, 'a, 's, C: GenericClient>(
         &'s self,
         client: &'c C,
-        article_id: &'a uuid::Uuid,
-    ) -> GetCommentsByArticleQuery<'c, 'a, 's, C, GetCommentsByArticle, 1> {
-        GetCommentsByArticleQuery {
-            client,
-            params: [article_id],
-            query: self.0,
-            cached: self.1.as_ref(),
-            extractor: |
-                row: &tokio_postgres::Row,
-            | -> Result<GetCommentsByArticleBorrowed, tokio_postgres::Error> {
-                Ok(GetCommentsByArticleBorrowed {
-                    id: row.try_get(0)?,
-                    body: row.try_get(1)?,
-                    article_id: row.try_get(2)?,
-                    author_id: row.try_get(3)?,
-                    created_at: row.try_get(4)?,
-                    updated_at: row.try_get(5)?,
-                })
-            },
-            mapper: |it| GetCommentsByArticle::from(it),
-        }
+        id: &'a i32,
+    ) -> GetCommentByIdQuery<'c, 'a, 's, C, GetCommentById, 1> {
+        GetCommentByIdQuery { client, params: [id], query: self.0, cached: self.1.as_ref(), extractor: |row: &tokio_postgres::Row| -> Result<GetCommentByIdBorrowed, tokio_postgres::Error> { Ok(GetCommentByIdBorrowed { id: row.try_get(0)?,
+body: row.try_get(1)?,
+article_id: row.try_get(2)?,
+author_id: row.try_get(3)?,
+created_at: row.try_get(4)?,
+updated_at: row.try_get(5)?,
+deleted_at: row.try_get(6)?,
+hidden_at: row.try_get(7)?,
+ }) }, mapper: |it| GetCommentById::from(it), }
     }
 }
-pub struct DeleteCommentStmt(&'static str, Option<tokio_postgres::Statement>);
-pub fn delete_comment() -> DeleteCommentStmt {
-    DeleteCommentStmt("DELETE FROM comment WHERE id = $1", None)
+pub struct HideCommentStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn hide_comment() -> HideCommentStmt {
+    HideCommentStmt("UPDATE comment SET hidden_at = $1 WHERE id = $2", None)
 }
-impl DeleteCommentStmt {
+impl HideCommentStmt {
     pub async fn prepare<'a, C: GenericClient>(
         mut self,
         client: &'a C,
@@ -448,16 +1165,141 @@ impl DeleteCommentStmt {
     pub async fn bind<'c, 'a, 's, C: GenericClient>(
         &'s self,
         client: &'c C,
+        hidden_at: &'a chrono::DateTime<chrono::FixedOffset>,
         id: &'a i32,
     ) -> Result<u64, tokio_postgres::Error> {
-        client.execute(self.0, &[id]).await
+        client.execute(self.0, &[hidden_at, id]).await
     }
 }
-pub struct GetCommentByIdStmt(&'static str, Option<tokio_postgres::Statement>);
-pub fn get_comment_by_id() -> GetCommentByIdStmt {
-    GetCommentByIdStmt("SELECT * FROM comment WHERE id = $1", None)
+impl<'a, C: GenericClient + Send + Sync>
+    crate::clorinde::client::async_::Params<
+        'a,
+        'a,
+        'a,
+        HideCommentParams,
+        std::pin::Pin<
+            Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+        >,
+        C,
+    > for HideCommentStmt
+{
+    fn params(
+        &'a self,
+        client: &'a C,
+        params: &'a HideCommentParams,
+    ) -> std::pin::Pin<
+        Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+    > {
+        Box::pin(self.bind(client, &params.hidden_at, &params.id))
+    }
 }
-impl GetCommentByIdStmt {
+pub struct GetCommentsByAuthorStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn get_comments_by_author() -> GetCommentsByAuthorStmt {
+    GetCommentsByAuthorStmt(
+        "SELECT * FROM comment WHERE author_id = $1 AND deleted_at IS NULL ORDER BY created_at DESC",
+        None,
+    )
+}
+impl GetCommentsByAuthorStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        author_id: &'a uuid::Uuid,
+    ) -> GetCommentsByAuthorQuery<'c, 'a, 's, C, GetCommentsByAuthor, 1> {
+        GetCommentsByAuthorQuery { client, params: [author_id], query: self.0, cached: self.1.as_ref(), extractor: |row: &tokio_postgres::Row| -> Result<GetCommentsByAuthorBorrowed, tokio_postgres::Error> { Ok(GetCommentsByAuthorBorrowed { id: row.try_get(0)?,
+body: row.try_get(1)?,
+article_id: row.try_get(2)?,
+author_id: row.try_get(3)?,
+created_at: row.try_get(4)?,
+updated_at: row.try_get(5)?,
+deleted_at: row.try_get(6)?,
+hidden_at: row.try_get(7)?,
+ }) }, mapper: |it| GetCommentsByAuthor::from(it), }
+    }
+}
+pub struct DeleteCommentsByAuthorStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn delete_comments_by_author() -> DeleteCommentsByAuthorStmt {
+    DeleteCommentsByAuthorStmt("DELETE FROM comment WHERE author_id = $1", None)
+}
+impl DeleteCommentsByAuthorStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub async fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        author_id: &'a uuid::Uuid,
+    ) -> Result<u64, tokio_postgres::Error> {
+        client.execute(self.0, &[author_id]).await
+    }
+}
+pub struct ReassignCommentsStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn reassign_comments() -> ReassignCommentsStmt {
+    ReassignCommentsStmt(
+        "UPDATE comment SET author_id = $1 WHERE author_id = $2",
+        None,
+    )
+}
+impl ReassignCommentsStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub async fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        to_author_id: &'a uuid::Uuid,
+        from_author_id: &'a uuid::Uuid,
+    ) -> Result<u64, tokio_postgres::Error> {
+        client
+            .execute(self.0, &[to_author_id, from_author_id])
+            .await
+    }
+}
+impl<'a, C: GenericClient + Send + Sync>
+    crate::clorinde::client::async_::Params<
+        'a,
+        'a,
+        'a,
+        ReassignCommentsParams,
+        std::pin::Pin<
+            Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+        >,
+        C,
+    > for ReassignCommentsStmt
+{
+    fn params(
+        &'a self,
+        client: &'a C,
+        params: &'a ReassignCommentsParams,
+    ) -> std::pin::Pin<
+        Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+    > {
+        Box::pin(self.bind(client, &params.to_author_id, &params.from_author_id))
+    }
+}
+pub struct GetTrashedCommentStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn get_trashed_comment() -> GetTrashedCommentStmt {
+    GetTrashedCommentStmt(
+        "SELECT * FROM comment WHERE id = $1 AND deleted_at IS NOT NULL",
+        None,
+    )
+}
+impl GetTrashedCommentStmt {
     pub async fn prepare<'a, C: GenericClient>(
         mut self,
         client: &'a C,
@@ -469,25 +1311,281 @@ impl GetCommentByIdStmt {
         &'s self,
         client: &'c C,
         id: &'a i32,
-    ) -> GetCommentByIdQuery<'c, 'a, 's, C, GetCommentById, 1> {
-        GetCommentByIdQuery {
+    ) -> GetTrashedCommentQuery<'c, 'a, 's, C, GetTrashedComment, 1> {
+        GetTrashedCommentQuery { client, params: [id], query: self.0, cached: self.1.as_ref(), extractor: |row: &tokio_postgres::Row| -> Result<GetTrashedCommentBorrowed, tokio_postgres::Error> { Ok(GetTrashedCommentBorrowed { id: row.try_get(0)?,
+body: row.try_get(1)?,
+article_id: row.try_get(2)?,
+author_id: row.try_get(3)?,
+created_at: row.try_get(4)?,
+updated_at: row.try_get(5)?,
+deleted_at: row.try_get(6)?,
+hidden_at: row.try_get(7)?,
+ }) }, mapper: |it| GetTrashedComment::from(it), }
+    }
+}
+pub struct ListTrashedCommentsStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn list_trashed_comments() -> ListTrashedCommentsStmt {
+    ListTrashedCommentsStmt(
+        "SELECT * FROM comment WHERE author_id = $1 AND deleted_at IS NOT NULL ORDER BY deleted_at DESC, id",
+        None,
+    )
+}
+impl ListTrashedCommentsStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        author_id: &'a uuid::Uuid,
+    ) -> ListTrashedCommentsQuery<'c, 'a, 's, C, ListTrashedComments, 1> {
+        ListTrashedCommentsQuery { client, params: [author_id], query: self.0, cached: self.1.as_ref(), extractor: |row: &tokio_postgres::Row| -> Result<ListTrashedCommentsBorrowed, tokio_postgres::Error> { Ok(ListTrashedCommentsBorrowed { id: row.try_get(0)?,
+body: row.try_get(1)?,
+article_id: row.try_get(2)?,
+author_id: row.try_get(3)?,
+created_at: row.try_get(4)?,
+updated_at: row.try_get(5)?,
+deleted_at: row.try_get(6)?,
+hidden_at: row.try_get(7)?,
+ }) }, mapper: |it| ListTrashedComments::from(it), }
+    }
+}
+pub struct RestoreCommentStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn restore_comment() -> RestoreCommentStmt {
+    RestoreCommentStmt("UPDATE comment SET deleted_at = NULL WHERE id = $1", None)
+}
+impl RestoreCommentStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub async fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        id: &'a i32,
+    ) -> Result<u64, tokio_postgres::Error> {
+        client.execute(self.0, &[id]).await
+    }
+}
+pub struct PurgeTrashedCommentsStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn purge_trashed_comments() -> PurgeTrashedCommentsStmt {
+    PurgeTrashedCommentsStmt(
+        "DELETE FROM comment WHERE deleted_at <= $1 RETURNING id",
+        None,
+    )
+}
+impl PurgeTrashedCommentsStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        cutoff: &'a chrono::DateTime<chrono::FixedOffset>,
+    ) -> I32Query<'c, 'a, 's, C, i32, 1> {
+        I32Query {
             client,
-            params: [id],
+            params: [cutoff],
             query: self.0,
             cached: self.1.as_ref(),
-            extractor: |
-                row: &tokio_postgres::Row,
-            | -> Result<GetCommentByIdBorrowed, tokio_postgres::Error> {
-                Ok(GetCommentByIdBorrowed {
-                    id: row.try_get(0)?,
-                    body: row.try_get(1)?,
-                    article_id: row.try_get(2)?,
-                    author_id: row.try_get(3)?,
-                    created_at: row.try_get(4)?,
-                    updated_at: row.try_get(5)?,
-                })
-            },
-            mapper: |it| GetCommentById::from(it),
+            extractor: |row| Ok(row.try_get(0)?),
+            mapper: |it| it,
         }
     }
 }
+pub struct AddCommentReactionStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn add_comment_reaction() -> AddCommentReactionStmt {
+    AddCommentReactionStmt(
+        "INSERT INTO comment_reaction (comment_id, appuser_id, kind) VALUES ($1, $2, $3) ON CONFLICT DO NOTHING",
+        None,
+    )
+}
+impl AddCommentReactionStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub async fn bind<'c, 'a, 's, C: GenericClient, T1: crate::clorinde::StringSql>(
+        &'s self,
+        client: &'c C,
+        comment_id: &'a i32,
+        user_id: &'a uuid::Uuid,
+        kind: &'a T1,
+    ) -> Result<u64, tokio_postgres::Error> {
+        client.execute(self.0, &[comment_id, user_id, kind]).await
+    }
+}
+impl<'a, C: GenericClient + Send + Sync, T1: crate::clorinde::StringSql>
+    crate::clorinde::client::async_::Params<
+        'a,
+        'a,
+        'a,
+        AddCommentReactionParams<T1>,
+        std::pin::Pin<
+            Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+        >,
+        C,
+    > for AddCommentReactionStmt
+{
+    fn params(
+        &'a self,
+        client: &'a C,
+        params: &'a AddCommentReactionParams<T1>,
+    ) -> std::pin::Pin<
+        Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+    > {
+        Box::pin(self.bind(client, &params.comment_id, &params.user_id, &params.kind))
+    }
+}
+pub struct RemoveCommentReactionStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn remove_comment_reaction() -> RemoveCommentReactionStmt {
+    RemoveCommentReactionStmt(
+        "DELETE FROM comment_reaction WHERE comment_id = $1 AND appuser_id = $2 AND kind = $3",
+        None,
+    )
+}
+impl RemoveCommentReactionStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub async fn bind<'c, 'a, 's, C: GenericClient, T1: crate::clorinde::StringSql>(
+        &'s self,
+        client: &'c C,
+        comment_id: &'a i32,
+        user_id: &'a uuid::Uuid,
+        kind: &'a T1,
+    ) -> Result<u64, tokio_postgres::Error> {
+        client.execute(self.0, &[comment_id, user_id, kind]).await
+    }
+}
+impl<'a, C: GenericClient + Send + Sync, T1: crate::clorinde::StringSql>
+    crate::clorinde::client::async_::Params<
+        'a,
+        'a,
+        'a,
+        RemoveCommentReactionParams<T1>,
+        std::pin::Pin<
+            Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+        >,
+        C,
+    > for RemoveCommentReactionStmt
+{
+    fn params(
+        &'a self,
+        client: &'a C,
+        params: &'a RemoveCommentReactionParams<T1>,
+    ) -> std::pin::Pin<
+        Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+    > {
+        Box::pin(self.bind(client, &params.comment_id, &params.user_id, &params.kind))
+    }
+}
+pub struct CommentReactionsStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn comment_reactions() -> CommentReactionsStmt {
+    CommentReactionsStmt(
+        "SELECT c.comment_id, c.kind, c.count, EXISTS(SELECT 1 FROM comment_reaction r WHERE r.comment_id = c.comment_id AND r.kind = c.kind AND r.appuser_id = $1) as reacted FROM comment_reaction_count c WHERE c.comment_id = ANY($2) ORDER BY c.comment_id, c.count DESC, c.kind COLLATE ucs_basic",
+        None,
+    )
+}
+impl CommentReactionsStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub fn bind<'c, 'a, 's, C: GenericClient, T1: crate::clorinde::ArraySql<Item = i32>>(
+        &'s self,
+        client: &'c C,
+        viewer_id: &'a uuid::Uuid,
+        comment_ids: &'a T1,
+    ) -> CommentReactionsQuery<'c, 'a, 's, C, CommentReactions, 2> {
+        CommentReactionsQuery { client, params: [viewer_id, comment_ids], query: self.0, cached: self.1.as_ref(), extractor: |row: &tokio_postgres::Row| -> Result<CommentReactionsBorrowed, tokio_postgres::Error> { Ok(CommentReactionsBorrowed { comment_id: row.try_get(0)?,
+kind: row.try_get(1)?,
+count: row.try_get(2)?,
+reacted: row.try_get(3)?,
+ }) }, mapper: |it| CommentReactions::from(it), }
+    }
+}
+impl<'c, 'a, 's, C: GenericClient, T1: crate::clorinde::ArraySql<Item = i32>>
+    crate::clorinde::client::async_::Params<
+        'c,
+        'a,
+        's,
+        CommentReactionsParams<T1>,
+        CommentReactionsQuery<'c, 'a, 's, C, CommentReactions, 2>,
+        C,
+    > for CommentReactionsStmt
+{
+    fn params(
+        &'s self,
+        client: &'c C,
+        params: &'a CommentReactionsParams<T1>,
+    ) -> CommentReactionsQuery<'c, 'a, 's, C, CommentReactions, 2> {
+        self.bind(client, &params.viewer_id, &params.comment_ids)
+    }
+}
+pub struct RemoveCommentReactionsByUserStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn remove_comment_reactions_by_user() -> RemoveCommentReactionsByUserStmt {
+    RemoveCommentReactionsByUserStmt("DELETE FROM comment_reaction WHERE appuser_id = $1", None)
+}
+impl RemoveCommentReactionsByUserStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub async fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        user_id: &'a uuid::Uuid,
+    ) -> Result<u64, tokio_postgres::Error> {
+        client.execute(self.0, &[user_id]).await
+    }
+}
+pub struct ListCommentReactionsByUserStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn list_comment_reactions_by_user() -> ListCommentReactionsByUserStmt {
+    ListCommentReactionsByUserStmt(
+        "SELECT comment_id, kind FROM comment_reaction WHERE appuser_id = $1 ORDER BY created_at, comment_id, kind COLLATE ucs_basic",
+        None,
+    )
+}
+impl ListCommentReactionsByUserStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        user_id: &'a uuid::Uuid,
+    ) -> ListCommentReactionsByUserQuery<'c, 'a, 's, C, ListCommentReactionsByUser, 1> {
+        ListCommentReactionsByUserQuery { client, params: [user_id], query: self.0, cached: self.1.as_ref(), extractor: |row: &tokio_postgres::Row| -> Result<ListCommentReactionsByUserBorrowed, tokio_postgres::Error> { Ok(ListCommentReactionsByUserBorrowed { comment_id: row.try_get(0)?,
+kind: row.try_get(1)?,
+ }) }, mapper: |it| ListCommentReactionsByUser::from(it), }
+    }
+}
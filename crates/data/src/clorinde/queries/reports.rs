@@ -0,0 +1,1352 @@
+// This file was generated with `clorinde`. Do not modify.
+
+#[derive(Debug)]
+pub struct CreateReportParams<T1: crate::clorinde::StringSql, T2: crate::clorinde::StringSql> {
+    pub id: uuid::Uuid,
+    pub reporter_id: uuid::Uuid,
+    pub article_id: Option<uuid::Uuid>,
+    pub comment_id: Option<i32>,
+    pub profile_id: Option<uuid::Uuid>,
+    pub target_author_id: uuid::Uuid,
+    pub reason: T1,
+    pub details: Option<T2>,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+}
+#[derive(Clone, Copy, Debug)]
+pub struct FindOpenReportParams {
+    pub reporter_id: uuid::Uuid,
+    pub article_id: Option<uuid::Uuid>,
+    pub comment_id: Option<i32>,
+    pub profile_id: Option<uuid::Uuid>,
+}
+#[derive(Debug)]
+pub struct ListReportsParams<T1: crate::clorinde::StringSql> {
+    pub status: Option<T1>,
+    pub limit: i64,
+    pub offset: i64,
+}
+#[derive(Debug)]
+pub struct UpdateReportParams<T1: crate::clorinde::StringSql> {
+    pub status: T1,
+    pub moderator_id: Option<uuid::Uuid>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub closed_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub id: uuid::Uuid,
+}
+#[derive(Debug)]
+pub struct RecordModerationActionParams<T1: crate::clorinde::StringSql, T2: crate::clorinde::StringSql> {
+    pub report_id: uuid::Uuid,
+    pub moderator_id: Option<uuid::Uuid>,
+    pub action: T1,
+    pub note: Option<T2>,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateReport {
+    pub id: uuid::Uuid,
+    pub reporter_id: uuid::Uuid,
+    pub article_id: Option<uuid::Uuid>,
+    pub comment_id: Option<i32>,
+    pub profile_id: Option<uuid::Uuid>,
+    pub target_author_id: uuid::Uuid,
+    pub reason: String,
+    pub details: Option<String>,
+    pub status: String,
+    pub moderator_id: Option<uuid::Uuid>,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub closed_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+}
+pub struct CreateReportBorrowed<'a> {
+    pub id: uuid::Uuid,
+    pub reporter_id: uuid::Uuid,
+    pub article_id: Option<uuid::Uuid>,
+    pub comment_id: Option<i32>,
+    pub profile_id: Option<uuid::Uuid>,
+    pub target_author_id: uuid::Uuid,
+    pub reason: &'a str,
+    pub details: Option<&'a str>,
+    pub status: &'a str,
+    pub moderator_id: Option<uuid::Uuid>,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub closed_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+}
+impl<'a> From<CreateReportBorrowed<'a>> for CreateReport {
+    fn from(
+        CreateReportBorrowed {
+            id,
+            reporter_id,
+            article_id,
+            comment_id,
+            profile_id,
+            target_author_id,
+            reason,
+            details,
+            status,
+            moderator_id,
+            created_at,
+            updated_at,
+            closed_at,
+        }: CreateReportBorrowed<'a>,
+    ) -> Self {
+        Self {
+            id,
+            reporter_id,
+            article_id,
+            comment_id,
+            profile_id,
+            target_author_id,
+            reason: reason.into(),
+            details: details.map(|v| v.into()),
+            status: status.into(),
+            moderator_id,
+            created_at,
+            updated_at,
+            closed_at,
+        }
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct GetReport {
+    pub id: uuid::Uuid,
+    pub reporter_id: uuid::Uuid,
+    pub article_id: Option<uuid::Uuid>,
+    pub comment_id: Option<i32>,
+    pub profile_id: Option<uuid::Uuid>,
+    pub target_author_id: uuid::Uuid,
+    pub reason: String,
+    pub details: Option<String>,
+    pub status: String,
+    pub moderator_id: Option<uuid::Uuid>,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub closed_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+}
+pub struct GetReportBorrowed<'a> {
+    pub id: uuid::Uuid,
+    pub reporter_id: uuid::Uuid,
+    pub article_id: Option<uuid::Uuid>,
+    pub comment_id: Option<i32>,
+    pub profile_id: Option<uuid::Uuid>,
+    pub target_author_id: uuid::Uuid,
+    pub reason: &'a str,
+    pub details: Option<&'a str>,
+    pub status: &'a str,
+    pub moderator_id: Option<uuid::Uuid>,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub closed_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+}
+impl<'a> From<GetReportBorrowed<'a>> for GetReport {
+    fn from(
+        GetReportBorrowed {
+            id,
+            reporter_id,
+            article_id,
+            comment_id,
+            profile_id,
+            target_author_id,
+            reason,
+            details,
+            status,
+            moderator_id,
+            created_at,
+            updated_at,
+            closed_at,
+        }: GetReportBorrowed<'a>,
+    ) -> Self {
+        Self {
+            id,
+            reporter_id,
+            article_id,
+            comment_id,
+            profile_id,
+            target_author_id,
+            reason: reason.into(),
+            details: details.map(|v| v.into()),
+            status: status.into(),
+            moderator_id,
+            created_at,
+            updated_at,
+            closed_at,
+        }
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct FindOpenReport {
+    pub id: uuid::Uuid,
+    pub reporter_id: uuid::Uuid,
+    pub article_id: Option<uuid::Uuid>,
+    pub comment_id: Option<i32>,
+    pub profile_id: Option<uuid::Uuid>,
+    pub target_author_id: uuid::Uuid,
+    pub reason: String,
+    pub details: Option<String>,
+    pub status: String,
+    pub moderator_id: Option<uuid::Uuid>,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub closed_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+}
+pub struct FindOpenReportBorrowed<'a> {
+    pub id: uuid::Uuid,
+    pub reporter_id: uuid::Uuid,
+    pub article_id: Option<uuid::Uuid>,
+    pub comment_id: Option<i32>,
+    pub profile_id: Option<uuid::Uuid>,
+    pub target_author_id: uuid::Uuid,
+    pub reason: &'a str,
+    pub details: Option<&'a str>,
+    pub status: &'a str,
+    pub moderator_id: Option<uuid::Uuid>,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub closed_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+}
+impl<'a> From<FindOpenReportBorrowed<'a>> for FindOpenReport {
+    fn from(
+        FindOpenReportBorrowed {
+            id,
+            reporter_id,
+            article_id,
+            comment_id,
+            profile_id,
+            target_author_id,
+            reason,
+            details,
+            status,
+            moderator_id,
+            created_at,
+            updated_at,
+            closed_at,
+        }: FindOpenReportBorrowed<'a>,
+    ) -> Self {
+        Self {
+            id,
+            reporter_id,
+            article_id,
+            comment_id,
+            profile_id,
+            target_author_id,
+            reason: reason.into(),
+            details: details.map(|v| v.into()),
+            status: status.into(),
+            moderator_id,
+            created_at,
+            updated_at,
+            closed_at,
+        }
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListReports {
+    pub id: uuid::Uuid,
+    pub reporter_id: uuid::Uuid,
+    pub article_id: Option<uuid::Uuid>,
+    pub comment_id: Option<i32>,
+    pub profile_id: Option<uuid::Uuid>,
+    pub target_author_id: uuid::Uuid,
+    pub reason: String,
+    pub details: Option<String>,
+    pub status: String,
+    pub moderator_id: Option<uuid::Uuid>,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub closed_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+}
+pub struct ListReportsBorrowed<'a> {
+    pub id: uuid::Uuid,
+    pub reporter_id: uuid::Uuid,
+    pub article_id: Option<uuid::Uuid>,
+    pub comment_id: Option<i32>,
+    pub profile_id: Option<uuid::Uuid>,
+    pub target_author_id: uuid::Uuid,
+    pub reason: &'a str,
+    pub details: Option<&'a str>,
+    pub status: &'a str,
+    pub moderator_id: Option<uuid::Uuid>,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub closed_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+}
+impl<'a> From<ListReportsBorrowed<'a>> for ListReports {
+    fn from(
+        ListReportsBorrowed {
+            id,
+            reporter_id,
+            article_id,
+            comment_id,
+            profile_id,
+            target_author_id,
+            reason,
+            details,
+            status,
+            moderator_id,
+            created_at,
+            updated_at,
+            closed_at,
+        }: ListReportsBorrowed<'a>,
+    ) -> Self {
+        Self {
+            id,
+            reporter_id,
+            article_id,
+            comment_id,
+            profile_id,
+            target_author_id,
+            reason: reason.into(),
+            details: details.map(|v| v.into()),
+            status: status.into(),
+            moderator_id,
+            created_at,
+            updated_at,
+            closed_at,
+        }
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListReportsByReporter {
+    pub id: uuid::Uuid,
+    pub reporter_id: uuid::Uuid,
+    pub article_id: Option<uuid::Uuid>,
+    pub comment_id: Option<i32>,
+    pub profile_id: Option<uuid::Uuid>,
+    pub target_author_id: uuid::Uuid,
+    pub reason: String,
+    pub details: Option<String>,
+    pub status: String,
+    pub moderator_id: Option<uuid::Uuid>,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub closed_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+}
+pub struct ListReportsByReporterBorrowed<'a> {
+    pub id: uuid::Uuid,
+    pub reporter_id: uuid::Uuid,
+    pub article_id: Option<uuid::Uuid>,
+    pub comment_id: Option<i32>,
+    pub profile_id: Option<uuid::Uuid>,
+    pub target_author_id: uuid::Uuid,
+    pub reason: &'a str,
+    pub details: Option<&'a str>,
+    pub status: &'a str,
+    pub moderator_id: Option<uuid::Uuid>,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub closed_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+}
+impl<'a> From<ListReportsByReporterBorrowed<'a>> for ListReportsByReporter {
+    fn from(
+        ListReportsByReporterBorrowed {
+            id,
+            reporter_id,
+            article_id,
+            comment_id,
+            profile_id,
+            target_author_id,
+            reason,
+            details,
+            status,
+            moderator_id,
+            created_at,
+            updated_at,
+            closed_at,
+        }: ListReportsByReporterBorrowed<'a>,
+    ) -> Self {
+        Self {
+            id,
+            reporter_id,
+            article_id,
+            comment_id,
+            profile_id,
+            target_author_id,
+            reason: reason.into(),
+            details: details.map(|v| v.into()),
+            status: status.into(),
+            moderator_id,
+            created_at,
+            updated_at,
+            closed_at,
+        }
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListModerationActions {
+    pub report_id: uuid::Uuid,
+    pub moderator_id: Option<uuid::Uuid>,
+    pub action: String,
+    pub note: Option<String>,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+}
+pub struct ListModerationActionsBorrowed<'a> {
+    pub report_id: uuid::Uuid,
+    pub moderator_id: Option<uuid::Uuid>,
+    pub action: &'a str,
+    pub note: Option<&'a str>,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+}
+impl<'a> From<ListModerationActionsBorrowed<'a>> for ListModerationActions {
+    fn from(
+        ListModerationActionsBorrowed {
+            report_id,
+            moderator_id,
+            action,
+            note,
+            created_at,
+        }: ListModerationActionsBorrowed<'a>,
+    ) -> Self {
+        Self {
+            report_id,
+            moderator_id,
+            action: action.into(),
+            note: note.map(|v| v.into()),
+            created_at,
+        }
+    }
+}
+use crate::clorinde::client::async_::GenericClient;
+use futures::{self, StreamExt, TryStreamExt};
+pub struct CreateReportQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor: fn(&tokio_postgres::Row) -> Result<CreateReportBorrowed, tokio_postgres::Error>,
+    mapper: fn(CreateReportBorrowed) -> T,
+}
+impl<'c, 'a, 's, C, T: 'c, const N: usize> CreateReportQuery<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(
+        self,
+        mapper: fn(CreateReportBorrowed) -> R,
+    ) -> CreateReportQuery<'c, 'a, 's, C, R, N> {
+        CreateReportQuery {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
+        }
+    }
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct GetReportQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor: fn(&tokio_postgres::Row) -> Result<GetReportBorrowed, tokio_postgres::Error>,
+    mapper: fn(GetReportBorrowed) -> T,
+}
+impl<'c, 'a, 's, C, T: 'c, const N: usize> GetReportQuery<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(self, mapper: fn(GetReportBorrowed) -> R) -> GetReportQuery<'c, 'a, 's, C, R, N> {
+        GetReportQuery {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
+        }
+    }
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct FindOpenReportQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor: fn(&tokio_postgres::Row) -> Result<FindOpenReportBorrowed, tokio_postgres::Error>,
+    mapper: fn(FindOpenReportBorrowed) -> T,
+}
+impl<'c, 'a, 's, C, T: 'c, const N: usize> FindOpenReportQuery<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(
+        self,
+        mapper: fn(FindOpenReportBorrowed) -> R,
+    ) -> FindOpenReportQuery<'c, 'a, 's, C, R, N> {
+        FindOpenReportQuery {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
+        }
+    }
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct ListReportsQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor: fn(&tokio_postgres::Row) -> Result<ListReportsBorrowed, tokio_postgres::Error>,
+    mapper: fn(ListReportsBorrowed) -> T,
+}
+impl<'c, 'a, 's, C, T: 'c, const N: usize> ListReportsQuery<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(
+        self,
+        mapper: fn(ListReportsBorrowed) -> R,
+    ) -> ListReportsQuery<'c, 'a, 's, C, R, N> {
+        ListReportsQuery {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
+        }
+    }
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct I64Query<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor: fn(&tokio_postgres::Row) -> Result<i64, tokio_postgres::Error>,
+    mapper: fn(i64) -> T,
+}
+impl<'c, 'a, 's, C, T: 'c, const N: usize> I64Query<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(self, mapper: fn(i64) -> R) -> I64Query<'c, 'a, 's, C, R, N> {
+        I64Query {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
+        }
+    }
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct ListReportsByReporterQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor:
+        fn(&tokio_postgres::Row) -> Result<ListReportsByReporterBorrowed, tokio_postgres::Error>,
+    mapper: fn(ListReportsByReporterBorrowed) -> T,
+}
+impl<'c, 'a, 's, C, T: 'c, const N: usize> ListReportsByReporterQuery<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(
+        self,
+        mapper: fn(ListReportsByReporterBorrowed) -> R,
+    ) -> ListReportsByReporterQuery<'c, 'a, 's, C, R, N> {
+        ListReportsByReporterQuery {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
+        }
+    }
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct ListModerationActionsQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor:
+        fn(&tokio_postgres::Row) -> Result<ListModerationActionsBorrowed, tokio_postgres::Error>,
+    mapper: fn(ListModerationActionsBorrowed) -> T,
+}
+impl<'c, 'a, 's, C, T: 'c, const N: usize> ListModerationActionsQuery<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(
+        self,
+        mapper: fn(ListModerationActionsBorrowed) -> R,
+    ) -> ListModerationActionsQuery<'c, 'a, 's, C, R, N> {
+        ListModerationActionsQuery {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
+        }
+    }
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct CreateReportStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn create_report() -> CreateReportStmt {
+    CreateReportStmt(
+        "INSERT INTO report (id, reporter_id, article_id, comment_id, profile_id, target_author_id, reason, details, created_at, updated_at) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $9) RETURNING *",
+        None,
+    )
+}
+impl CreateReportStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub fn bind<'c, 'a, 's, C: GenericClient, T1: crate::clorinde::StringSql, T2: crate::clorinde::StringSql>(
+        &'s self,
+        client: &'c C,
+        id: &'a uuid::Uuid,
+        reporter_id: &'a uuid::Uuid,
+        article_id: &'a Option<uuid::Uuid>,
+        comment_id: &'a Option<i32>,
+        profile_id: &'a Option<uuid::Uuid>,
+        target_author_id: &'a uuid::Uuid,
+        reason: &'a T1,
+        details: &'a Option<T2>,
+        created_at: &'a chrono::DateTime<chrono::FixedOffset>,
+    ) -> CreateReportQuery<'c, 'a, 's, C, CreateReport, 9> {
+        CreateReportQuery {
+            client,
+            params: [
+                id,
+                reporter_id,
+                article_id,
+                comment_id,
+                profile_id,
+                target_author_id,
+                reason,
+                details,
+                created_at,
+            ],
+            query: self.0,
+            cached: self.1.as_ref(),
+            extractor:
+                |row: &tokio_postgres::Row| -> Result<CreateReportBorrowed, tokio_postgres::Error> {
+                    Ok(CreateReportBorrowed {
+                        id: row.try_get(0)?,
+                        reporter_id: row.try_get(1)?,
+                        article_id: row.try_get(2)?,
+                        comment_id: row.try_get(3)?,
+                        profile_id: row.try_get(4)?,
+                        target_author_id: row.try_get(5)?,
+                        reason: row.try_get(6)?,
+                        details: row.try_get(7)?,
+                        status: row.try_get(8)?,
+                        moderator_id: row.try_get(9)?,
+                        created_at: row.try_get(10)?,
+                        updated_at: row.try_get(11)?,
+                        closed_at: row.try_get(12)?,
+                    })
+                },
+            mapper: |it| CreateReport::from(it),
+        }
+    }
+}
+impl<'c, 'a, 's, C: GenericClient, T1: crate::clorinde::StringSql, T2: crate::clorinde::StringSql>
+    crate::clorinde::client::async_::Params<
+        'c,
+        'a,
+        's,
+        CreateReportParams<T1, T2>,
+        CreateReportQuery<'c, 'a, 's, C, CreateReport, 9>,
+        C,
+    > for CreateReportStmt
+{
+    fn params(
+        &'s self,
+        client: &'c C,
+        params: &'a CreateReportParams<T1, T2>,
+    ) -> CreateReportQuery<'c, 'a, 's, C, CreateReport, 9> {
+        self.bind(
+            client,
+            &params.id,
+            &params.reporter_id,
+            &params.article_id,
+            &params.comment_id,
+            &params.profile_id,
+            &params.target_author_id,
+            &params.reason,
+            &params.details,
+            &params.created_at,
+        )
+    }
+}
+pub struct GetReportStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn get_report() -> GetReportStmt {
+    GetReportStmt("SELECT * FROM report WHERE id = $1", None)
+}
+impl GetReportStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        id: &'a uuid::Uuid,
+    ) -> GetReportQuery<'c, 'a, 's, C, GetReport, 1> {
+        GetReportQuery {
+            client,
+            params: [id],
+            query: self.0,
+            cached: self.1.as_ref(),
+            extractor:
+                |row: &tokio_postgres::Row| -> Result<GetReportBorrowed, tokio_postgres::Error> {
+                    Ok(GetReportBorrowed {
+                        id: row.try_get(0)?,
+                        reporter_id: row.try_get(1)?,
+                        article_id: row.try_get(2)?,
+                        comment_id: row.try_get(3)?,
+                        profile_id: row.try_get(4)?,
+                        target_author_id: row.try_get(5)?,
+                        reason: row.try_get(6)?,
+                        details: row.try_get(7)?,
+                        status: row.try_get(8)?,
+                        moderator_id: row.try_get(9)?,
+                        created_at: row.try_get(10)?,
+                        updated_at: row.try_get(11)?,
+                        closed_at: row.try_get(12)?,
+                    })
+                },
+            mapper: |it| GetReport::from(it),
+        }
+    }
+}
+pub struct FindOpenReportStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn find_open_report() -> FindOpenReportStmt {
+    FindOpenReportStmt(
+        "SELECT * FROM report WHERE reporter_id = $1 AND (article_id = $2 OR comment_id = $3 OR profile_id = $4) AND status IN ('open', 'claimed') LIMIT 1",
+        None,
+    )
+}
+impl FindOpenReportStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        reporter_id: &'a uuid::Uuid,
+        article_id: &'a Option<uuid::Uuid>,
+        comment_id: &'a Option<i32>,
+        profile_id: &'a Option<uuid::Uuid>,
+    ) -> FindOpenReportQuery<'c, 'a, 's, C, FindOpenReport, 4> {
+        FindOpenReportQuery { client, params: [reporter_id, article_id, comment_id, profile_id], query: self.0, cached: self.1.as_ref(), extractor: |row: &tokio_postgres::Row| -> Result<FindOpenReportBorrowed, tokio_postgres::Error> { Ok(FindOpenReportBorrowed { id: row.try_get(0)?,
+reporter_id: row.try_get(1)?,
+article_id: row.try_get(2)?,
+comment_id: row.try_get(3)?,
+profile_id: row.try_get(4)?,
+target_author_id: row.try_get(5)?,
+reason: row.try_get(6)?,
+details: row.try_get(7)?,
+status: row.try_get(8)?,
+moderator_id: row.try_get(9)?,
+created_at: row.try_get(10)?,
+updated_at: row.try_get(11)?,
+closed_at: row.try_get(12)?,
+ }) }, mapper: |it| FindOpenReport::from(it), }
+    }
+}
+impl<'c, 'a, 's, C: GenericClient>
+    crate::clorinde::client::async_::Params<
+        'c,
+        'a,
+        's,
+        FindOpenReportParams,
+        FindOpenReportQuery<'c, 'a, 's, C, FindOpenReport, 4>,
+        C,
+    > for FindOpenReportStmt
+{
+    fn params(
+        &'s self,
+        client: &'c C,
+        params: &'a FindOpenReportParams,
+    ) -> FindOpenReportQuery<'c, 'a, 's, C, FindOpenReport, 4> {
+        self.bind(
+            client,
+            &params.reporter_id,
+            &params.article_id,
+            &params.comment_id,
+            &params.profile_id,
+        )
+    }
+}
+pub struct ListReportsStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn list_reports() -> ListReportsStmt {
+    ListReportsStmt(
+        "SELECT * FROM report WHERE ($1::text IS NULL OR status = $1) ORDER BY created_at, id LIMIT $2 OFFSET $3",
+        None,
+    )
+}
+impl ListReportsStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub fn bind<'c, 'a, 's, C: GenericClient, T1: crate::clorinde::StringSql>(
+        &'s self,
+        client: &'c C,
+        status: &'a Option<T1>,
+        limit: &'a i64,
+        offset: &'a i64,
+    ) -> ListReportsQuery<'c, 'a, 's, C, ListReports, 3> {
+        ListReportsQuery {
+            client,
+            params: [status, limit, offset],
+            query: self.0,
+            cached: self.1.as_ref(),
+            extractor:
+                |row: &tokio_postgres::Row| -> Result<ListReportsBorrowed, tokio_postgres::Error> {
+                    Ok(ListReportsBorrowed {
+                        id: row.try_get(0)?,
+                        reporter_id: row.try_get(1)?,
+                        article_id: row.try_get(2)?,
+                        comment_id: row.try_get(3)?,
+                        profile_id: row.try_get(4)?,
+                        target_author_id: row.try_get(5)?,
+                        reason: row.try_get(6)?,
+                        details: row.try_get(7)?,
+                        status: row.try_get(8)?,
+                        moderator_id: row.try_get(9)?,
+                        created_at: row.try_get(10)?,
+                        updated_at: row.try_get(11)?,
+                        closed_at: row.try_get(12)?,
+                    })
+                },
+            mapper: |it| ListReports::from(it),
+        }
+    }
+}
+impl<'c, 'a, 's, C: GenericClient, T1: crate::clorinde::StringSql>
+    crate::clorinde::client::async_::Params<
+        'c,
+        'a,
+        's,
+        ListReportsParams<T1>,
+        ListReportsQuery<'c, 'a, 's, C, ListReports, 3>,
+        C,
+    > for ListReportsStmt
+{
+    fn params(
+        &'s self,
+        client: &'c C,
+        params: &'a ListReportsParams<T1>,
+    ) -> ListReportsQuery<'c, 'a, 's, C, ListReports, 3> {
+        self.bind(client, &params.status, &params.limit, &params.offset)
+    }
+}
+pub struct CountReportsStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn count_reports() -> CountReportsStmt {
+    CountReportsStmt(
+        "SELECT COUNT(*) FROM report WHERE ($1::text IS NULL OR status = $1)",
+        None,
+    )
+}
+impl CountReportsStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub fn bind<'c, 'a, 's, C: GenericClient, T1: crate::clorinde::StringSql>(
+        &'s self,
+        client: &'c C,
+        status: &'a Option<T1>,
+    ) -> I64Query<'c, 'a, 's, C, i64, 1> {
+        I64Query {
+            client,
+            params: [status],
+            query: self.0,
+            cached: self.1.as_ref(),
+            extractor: |row| Ok(row.try_get(0)?),
+            mapper: |it| it,
+        }
+    }
+}
+pub struct ListReportsByReporterStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn list_reports_by_reporter() -> ListReportsByReporterStmt {
+    ListReportsByReporterStmt(
+        "SELECT * FROM report WHERE reporter_id = $1 ORDER BY created_at DESC, id",
+        None,
+    )
+}
+impl ListReportsByReporterStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        reporter_id: &'a uuid::Uuid,
+    ) -> ListReportsByReporterQuery<'c, 'a, 's, C, ListReportsByReporter, 1> {
+        ListReportsByReporterQuery { client, params: [reporter_id], query: self.0, cached: self.1.as_ref(), extractor: |row: &tokio_postgres::Row| -> Result<ListReportsByReporterBorrowed, tokio_postgres::Error> { Ok(ListReportsByReporterBorrowed { id: row.try_get(0)?,
+reporter_id: row.try_get(1)?,
+article_id: row.try_get(2)?,
+comment_id: row.try_get(3)?,
+profile_id: row.try_get(4)?,
+target_author_id: row.try_get(5)?,
+reason: row.try_get(6)?,
+details: row.try_get(7)?,
+status: row.try_get(8)?,
+moderator_id: row.try_get(9)?,
+created_at: row.try_get(10)?,
+updated_at: row.try_get(11)?,
+closed_at: row.try_get(12)?,
+ }) }, mapper: |it| ListReportsByReporter::from(it), }
+    }
+}
+pub struct UpdateReportStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn update_report() -> UpdateReportStmt {
+    UpdateReportStmt(
+        "UPDATE report SET status = $1, moderator_id = $2, updated_at = $3, closed_at = $4 WHERE id = $5 AND (moderator_id IS NULL OR moderator_id = $2) AND closed_at IS NULL",
+        None,
+    )
+}
+impl UpdateReportStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub async fn bind<'c, 'a, 's, C: GenericClient, T1: crate::clorinde::StringSql>(
+        &'s self,
+        client: &'c C,
+        status: &'a T1,
+        moderator_id: &'a Option<uuid::Uuid>,
+        updated_at: &'a chrono::DateTime<chrono::FixedOffset>,
+        closed_at: &'a Option<chrono::DateTime<chrono::FixedOffset>>,
+        id: &'a uuid::Uuid,
+    ) -> Result<u64, tokio_postgres::Error> {
+        client
+            .execute(self.0, &[status, moderator_id, updated_at, closed_at, id])
+            .await
+    }
+}
+impl<'a, C: GenericClient + Send + Sync, T1: crate::clorinde::StringSql>
+    crate::clorinde::client::async_::Params<
+        'a,
+        'a,
+        'a,
+        UpdateReportParams<T1>,
+        std::pin::Pin<
+            Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+        >,
+        C,
+    > for UpdateReportStmt
+{
+    fn params(
+        &'a self,
+        client: &'a C,
+        params: &'a UpdateReportParams<T1>,
+    ) -> std::pin::Pin<
+        Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+    > {
+        Box::pin(self.bind(
+            client,
+            &params.status,
+            &params.moderator_id,
+            &params.updated_at,
+            &params.closed_at,
+            &params.id,
+        ))
+    }
+}
+pub struct RecordModerationActionStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn record_moderation_action() -> RecordModerationActionStmt {
+    RecordModerationActionStmt(
+        "INSERT INTO moderation_action (report_id, moderator_id, action, note, created_at) VALUES ($1, $2, $3, $4, $5)",
+        None,
+    )
+}
+impl RecordModerationActionStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub async fn bind<'c, 'a, 's, C: GenericClient, T1: crate::clorinde::StringSql, T2: crate::clorinde::StringSql>(
+        &'s self,
+        client: &'c C,
+        report_id: &'a uuid::Uuid,
+        moderator_id: &'a Option<uuid::Uuid>,
+        action: &'a T1,
+        note: &'a Option<T2>,
+        created_at: &'a chrono::DateTime<chrono::FixedOffset>,
+    ) -> Result<u64, tokio_postgres::Error> {
+        client
+            .execute(self.0, &[report_id, moderator_id, action, note, created_at])
+            .await
+    }
+}
+impl<'a, C: GenericClient + Send + Sync, T1: crate::clorinde::StringSql, T2: crate::clorinde::StringSql>
+    crate::clorinde::client::async_::Params<
+        'a,
+        'a,
+        'a,
+        RecordModerationActionParams<T1, T2>,
+        std::pin::Pin<
+            Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+        >,
+        C,
+    > for RecordModerationActionStmt
+{
+    fn params(
+        &'a self,
+        client: &'a C,
+        params: &'a RecordModerationActionParams<T1, T2>,
+    ) -> std::pin::Pin<
+        Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+    > {
+        Box::pin(self.bind(
+            client,
+            &params.report_id,
+            &params.moderator_id,
+            &params.action,
+            &params.note,
+            &params.created_at,
+        ))
+    }
+}
+pub struct ListModerationActionsStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn list_moderation_actions() -> ListModerationActionsStmt {
+    ListModerationActionsStmt(
+        "SELECT report_id, moderator_id, action, note, created_at FROM moderation_action WHERE report_id = $1 ORDER BY id",
+        None,
+    )
+}
+impl ListModerationActionsStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        report_id: &'a uuid::Uuid,
+    ) -> ListModerationActionsQuery<'c, 'a, 's, C, ListModerationActions, 1> {
+        ListModerationActionsQuery { client, params: [report_id], query: self.0, cached: self.1.as_ref(), extractor: |row: &tokio_postgres::Row| -> Result<ListModerationActionsBorrowed, tokio_postgres::Error> { Ok(ListModerationActionsBorrowed { report_id: row.try_get(0)?,
+moderator_id: row.try_get(1)?,
+action: row.try_get(2)?,
+note: row.try_get(3)?,
+created_at: row.try_get(4)?,
+ }) }, mapper: |it| ListModerationActions::from(it), }
+    }
+}
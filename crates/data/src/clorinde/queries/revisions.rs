@@ -0,0 +1,654 @@
+// This file was generated with `clorinde`. Do not modify.
+
+
+#[derive(Debug)]
+pub struct CreateRevisionParams<T1: crate::clorinde::StringSql, T2: crate::clorinde::StringSql, T3: crate::clorinde::StringSql> {
+    pub id: uuid::Uuid,
+    pub article_id: uuid::Uuid,
+    pub version: i32,
+    pub author_id: uuid::Uuid,
+    pub title: T1,
+    pub description: T2,
+    pub body: T3,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateRevision {
+    pub id: uuid::Uuid,
+    pub article_id: uuid::Uuid,
+    pub version: i32,
+    pub author_id: uuid::Uuid,
+    pub title: String,
+    pub description: String,
+    pub body: String,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+}
+pub struct CreateRevisionBorrowed<'a> {
+    pub id: uuid::Uuid,
+    pub article_id: uuid::Uuid,
+    pub version: i32,
+    pub author_id: uuid::Uuid,
+    pub title: &'a str,
+    pub description: &'a str,
+    pub body: &'a str,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+}
+impl<'a> From<CreateRevisionBorrowed<'a>> for CreateRevision {
+    fn from(
+        CreateRevisionBorrowed {
+            id,
+            article_id,
+            version,
+            author_id,
+            title,
+            description,
+            body,
+            created_at,
+        }: CreateRevisionBorrowed<'a>,
+    ) -> Self {
+        Self {
+            id,
+            article_id,
+            version,
+            author_id,
+            title: title.into(),
+            description: description.into(),
+            body: body.into(),
+            created_at,
+        }
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListRevisions {
+    pub id: uuid::Uuid,
+    pub article_id: uuid::Uuid,
+    pub version: i32,
+    pub author_id: uuid::Uuid,
+    pub title: String,
+    pub description: String,
+    pub body: String,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+}
+pub struct ListRevisionsBorrowed<'a> {
+    pub id: uuid::Uuid,
+    pub article_id: uuid::Uuid,
+    pub version: i32,
+    pub author_id: uuid::Uuid,
+    pub title: &'a str,
+    pub description: &'a str,
+    pub body: &'a str,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+}
+impl<'a> From<ListRevisionsBorrowed<'a>> for ListRevisions {
+    fn from(
+        ListRevisionsBorrowed {
+            id,
+            article_id,
+            version,
+            author_id,
+            title,
+            description,
+            body,
+            created_at,
+        }: ListRevisionsBorrowed<'a>,
+    ) -> Self {
+        Self {
+            id,
+            article_id,
+            version,
+            author_id,
+            title: title.into(),
+            description: description.into(),
+            body: body.into(),
+            created_at,
+        }
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct GetRevision {
+    pub id: uuid::Uuid,
+    pub article_id: uuid::Uuid,
+    pub version: i32,
+    pub author_id: uuid::Uuid,
+    pub title: String,
+    pub description: String,
+    pub body: String,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+}
+pub struct GetRevisionBorrowed<'a> {
+    pub id: uuid::Uuid,
+    pub article_id: uuid::Uuid,
+    pub version: i32,
+    pub author_id: uuid::Uuid,
+    pub title: &'a str,
+    pub description: &'a str,
+    pub body: &'a str,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+}
+impl<'a> From<GetRevisionBorrowed<'a>> for GetRevision {
+    fn from(
+        GetRevisionBorrowed {
+            id,
+            article_id,
+            version,
+            author_id,
+            title,
+            description,
+            body,
+            created_at,
+        }: GetRevisionBorrowed<'a>,
+    ) -> Self {
+        Self {
+            id,
+            article_id,
+            version,
+            author_id,
+            title: title.into(),
+            description: description.into(),
+            body: body.into(),
+            created_at,
+        }
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct LatestRevision {
+    pub id: uuid::Uuid,
+    pub article_id: uuid::Uuid,
+    pub version: i32,
+    pub author_id: uuid::Uuid,
+    pub title: String,
+    pub description: String,
+    pub body: String,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+}
+pub struct LatestRevisionBorrowed<'a> {
+    pub id: uuid::Uuid,
+    pub article_id: uuid::Uuid,
+    pub version: i32,
+    pub author_id: uuid::Uuid,
+    pub title: &'a str,
+    pub description: &'a str,
+    pub body: &'a str,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+}
+impl<'a> From<LatestRevisionBorrowed<'a>> for LatestRevision {
+    fn from(
+        LatestRevisionBorrowed {
+            id,
+            article_id,
+            version,
+            author_id,
+            title,
+            description,
+            body,
+            created_at,
+        }: LatestRevisionBorrowed<'a>,
+    ) -> Self {
+        Self {
+            id,
+            article_id,
+            version,
+            author_id,
+            title: title.into(),
+            description: description.into(),
+            body: body.into(),
+            created_at,
+        }
+    }
+}
+use crate::clorinde::client::async_::GenericClient;
+use futures::{self, StreamExt, TryStreamExt};
+pub struct CreateRevisionQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor: fn(&tokio_postgres::Row) -> Result<CreateRevisionBorrowed, tokio_postgres::Error>,
+    mapper: fn(CreateRevisionBorrowed) -> T,
+}
+impl<'c, 'a, 's, C, T: 'c, const N: usize> CreateRevisionQuery<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(
+        self,
+        mapper: fn(CreateRevisionBorrowed) -> R,
+    ) -> CreateRevisionQuery<'c, 'a, 's, C, R, N> {
+        CreateRevisionQuery {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
+        }
+    }
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct ListRevisionsQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor: fn(&tokio_postgres::Row) -> Result<ListRevisionsBorrowed, tokio_postgres::Error>,
+    mapper: fn(ListRevisionsBorrowed) -> T,
+}
+impl<'c, 'a, 's, C, T: 'c, const N: usize> ListRevisionsQuery<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(
+        self,
+        mapper: fn(ListRevisionsBorrowed) -> R,
+    ) -> ListRevisionsQuery<'c, 'a, 's, C, R, N> {
+        ListRevisionsQuery {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
+        }
+    }
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct GetRevisionQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor: fn(&tokio_postgres::Row) -> Result<GetRevisionBorrowed, tokio_postgres::Error>,
+    mapper: fn(GetRevisionBorrowed) -> T,
+}
+impl<'c, 'a, 's, C, T: 'c, const N: usize> GetRevisionQuery<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(
+        self,
+        mapper: fn(GetRevisionBorrowed) -> R,
+    ) -> GetRevisionQuery<'c, 'a, 's, C, R, N> {
+        GetRevisionQuery {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
+        }
+    }
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct LatestRevisionQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor: fn(&tokio_postgres::Row) -> Result<LatestRevisionBorrowed, tokio_postgres::Error>,
+    mapper: fn(LatestRevisionBorrowed) -> T,
+}
+impl<'c, 'a, 's, C, T: 'c, const N: usize> LatestRevisionQuery<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(
+        self,
+        mapper: fn(LatestRevisionBorrowed) -> R,
+    ) -> LatestRevisionQuery<'c, 'a, 's, C, R, N> {
+        LatestRevisionQuery {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
+        }
+    }
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct CreateRevisionStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn create_revision() -> CreateRevisionStmt {
+    CreateRevisionStmt(
+        "INSERT INTO article_revision (id, article_id, version, author_id, title, description, body, created_at) VALUES ($1, $2, $3, $4, $5, $6, $7, $8) RETURNING *",
+        None,
+    )
+}
+impl CreateRevisionStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub fn bind<
+        'c,
+        'a,
+        's,
+        C: GenericClient,
+        T1: crate::clorinde::StringSql,
+        T2: crate::clorinde::StringSql,
+        T3: crate::clorinde::StringSql,
+    >(
+        &'s self,
+        client: &'c C,
+        id: &'a uuid::Uuid,
+        article_id: &'a uuid::Uuid,
+        version: &'a i32,
+        author_id: &'a uuid::Uuid,
+        title: &'a T1,
+        description: &'a T2,
+        body: &'a T3,
+        created_at: &'a chrono::DateTime<chrono::FixedOffset>,
+    ) -> CreateRevisionQuery<'c, 'a, 's, C, CreateRevision, 8> {
+        CreateRevisionQuery { client, params: [id, article_id, version, author_id, title, description, body, created_at], query: self.0, cached: self.1.as_ref(), extractor: |row: &tokio_postgres::Row| -> Result<CreateRevisionBorrowed, tokio_postgres::Error> { Ok(CreateRevisionBorrowed { id: row.try_get(0)?,
+article_id: row.try_get(1)?,
+version: row.try_get(2)?,
+author_id: row.try_get(3)?,
+title: row.try_get(4)?,
+description: row.try_get(5)?,
+body: row.try_get(6)?,
+created_at: row.try_get(7)?,
+ }) }, mapper: |it| CreateRevision::from(it), }
+    }
+}
+impl<'c, 'a, 's, C: GenericClient, T1: crate::clorinde::StringSql, T2: crate::clorinde::StringSql, T3: crate::clorinde::StringSql>
+    crate::clorinde::client::async_::Params<
+        'c,
+        'a,
+        's,
+        CreateRevisionParams<T1, T2, T3>,
+        CreateRevisionQuery<'c, 'a, 's, C, CreateRevision, 8>,
+        C,
+    > for CreateRevisionStmt
+{
+    fn params(
+        &'s self,
+        client: &'c C,
+        params: &'a CreateRevisionParams<T1, T2, T3>,
+    ) -> CreateRevisionQuery<'c, 'a, 's, C, CreateRevision, 8> {
+        self.bind(
+            client,
+            &params.id,
+            &params.article_id,
+            &params.version,
+            &params.author_id,
+            &params.title,
+            &params.description,
+            &params.body,
+            &params.created_at,
+        )
+    }
+}
+pub struct ListRevisionsStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn list_revisions() -> ListRevisionsStmt {
+    ListRevisionsStmt(
+        "SELECT * FROM article_revision WHERE article_id = $1 ORDER BY version DESC",
+        None,
+    )
+}
+impl ListRevisionsStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        article_id: &'a uuid::Uuid,
+    ) -> ListRevisionsQuery<'c, 'a, 's, C, ListRevisions, 1> {
+        ListRevisionsQuery {
+            client,
+            params: [article_id],
+            query: self.0,
+            cached: self.1.as_ref(),
+            extractor:
+                |row: &tokio_postgres::Row| -> Result<ListRevisionsBorrowed, tokio_postgres::Error> {
+                    Ok(ListRevisionsBorrowed {
+                        id: row.try_get(0)?,
+                        article_id: row.try_get(1)?,
+                        version: row.try_get(2)?,
+                        author_id: row.try_get(3)?,
+                        title: row.try_get(4)?,
+                        description: row.try_get(5)?,
+                        body: row.try_get(6)?,
+                        created_at: row.try_get(7)?,
+                    })
+                },
+            mapper: |it| ListRevisions::from(it),
+        }
+    }
+}
+pub struct GetRevisionStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn get_revision() -> GetRevisionStmt {
+    GetRevisionStmt("SELECT * FROM article_revision WHERE id = $1", None)
+}
+impl GetRevisionStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        id: &'a uuid::Uuid,
+    ) -> GetRevisionQuery<'c, 'a, 's, C, GetRevision, 1> {
+        GetRevisionQuery {
+            client,
+            params: [id],
+            query: self.0,
+            cached: self.1.as_ref(),
+            extractor:
+                |row: &tokio_postgres::Row| -> Result<GetRevisionBorrowed, tokio_postgres::Error> {
+                    Ok(GetRevisionBorrowed {
+                        id: row.try_get(0)?,
+                        article_id: row.try_get(1)?,
+                        version: row.try_get(2)?,
+                        author_id: row.try_get(3)?,
+                        title: row.try_get(4)?,
+                        description: row.try_get(5)?,
+                        body: row.try_get(6)?,
+                        created_at: row.try_get(7)?,
+                    })
+                },
+            mapper: |it| GetRevision::from(it),
+        }
+    }
+}
+pub struct LatestRevisionStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn latest_revision() -> LatestRevisionStmt {
+    LatestRevisionStmt(
+        "SELECT * FROM article_revision WHERE article_id = $1 ORDER BY version DESC LIMIT 1",
+        None,
+    )
+}
+impl LatestRevisionStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        article_id: &'a uuid::Uuid,
+    ) -> LatestRevisionQuery<'c, 'a, 's, C, LatestRevision, 1> {
+        LatestRevisionQuery { client, params: [article_id], query: self.0, cached: self.1.as_ref(), extractor: |row: &tokio_postgres::Row| -> Result<LatestRevisionBorrowed, tokio_postgres::Error> { Ok(LatestRevisionBorrowed { id: row.try_get(0)?,
+article_id: row.try_get(1)?,
+version: row.try_get(2)?,
+author_id: row.try_get(3)?,
+title: row.try_get(4)?,
+description: row.try_get(5)?,
+body: row.try_get(6)?,
+created_at: row.try_get(7)?,
+ }) }, mapper: |it| LatestRevision::from(it), }
+    }
+}
@@ -0,0 +1,1179 @@
+// This file was generated with `clorinde`. Do not modify.
+
+#[derive(Debug)]
+pub struct CreateSeriesParams<
+    T1: crate::clorinde::StringSql,
+    T2: crate::clorinde::StringSql,
+    T3: crate::clorinde::StringSql,
+    T4: crate::clorinde::StringSql,
+> {
+    pub id: uuid::Uuid,
+    pub slug: T1,
+    pub title: T2,
+    pub description: T3,
+    pub kind: T4,
+    pub owner_id: uuid::Uuid,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+}
+#[derive(Debug)]
+pub struct UpdateSeriesParams<T1: crate::clorinde::StringSql, T2: crate::clorinde::StringSql> {
+    pub title: T1,
+    pub description: T2,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub id: uuid::Uuid,
+}
+#[derive(Clone, Copy, Debug)]
+pub struct AddSeriesPartParams {
+    pub article_id: uuid::Uuid,
+    pub position: i32,
+    pub series_id: uuid::Uuid,
+}
+#[derive(Clone, Copy, Debug)]
+pub struct ListSeriesPartsParams {
+    pub viewer_id: uuid::Uuid,
+    pub series_id: uuid::Uuid,
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateSeries {
+    pub id: uuid::Uuid,
+    pub slug: String,
+    pub title: String,
+    pub description: String,
+    pub kind: String,
+    pub owner_id: uuid::Uuid,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+}
+pub struct CreateSeriesBorrowed<'a> {
+    pub id: uuid::Uuid,
+    pub slug: &'a str,
+    pub title: &'a str,
+    pub description: &'a str,
+    pub kind: &'a str,
+    pub owner_id: uuid::Uuid,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+}
+impl<'a> From<CreateSeriesBorrowed<'a>> for CreateSeries {
+    fn from(
+        CreateSeriesBorrowed {
+            id,
+            slug,
+            title,
+            description,
+            kind,
+            owner_id,
+            created_at,
+            updated_at,
+        }: CreateSeriesBorrowed<'a>,
+    ) -> Self {
+        Self {
+            id,
+            slug: slug.into(),
+            title: title.into(),
+            description: description.into(),
+            kind: kind.into(),
+            owner_id,
+            created_at,
+            updated_at,
+        }
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct GetSeriesBySlug {
+    pub id: uuid::Uuid,
+    pub slug: String,
+    pub title: String,
+    pub description: String,
+    pub kind: String,
+    pub owner_id: uuid::Uuid,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+}
+pub struct GetSeriesBySlugBorrowed<'a> {
+    pub id: uuid::Uuid,
+    pub slug: &'a str,
+    pub title: &'a str,
+    pub description: &'a str,
+    pub kind: &'a str,
+    pub owner_id: uuid::Uuid,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+}
+impl<'a> From<GetSeriesBySlugBorrowed<'a>> for GetSeriesBySlug {
+    fn from(
+        GetSeriesBySlugBorrowed {
+            id,
+            slug,
+            title,
+            description,
+            kind,
+            owner_id,
+            created_at,
+            updated_at,
+        }: GetSeriesBySlugBorrowed<'a>,
+    ) -> Self {
+        Self {
+            id,
+            slug: slug.into(),
+            title: title.into(),
+            description: description.into(),
+            kind: kind.into(),
+            owner_id,
+            created_at,
+            updated_at,
+        }
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListSeriesByOwner {
+    pub id: uuid::Uuid,
+    pub slug: String,
+    pub title: String,
+    pub description: String,
+    pub kind: String,
+    pub owner_id: uuid::Uuid,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+}
+pub struct ListSeriesByOwnerBorrowed<'a> {
+    pub id: uuid::Uuid,
+    pub slug: &'a str,
+    pub title: &'a str,
+    pub description: &'a str,
+    pub kind: &'a str,
+    pub owner_id: uuid::Uuid,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+}
+impl<'a> From<ListSeriesByOwnerBorrowed<'a>> for ListSeriesByOwner {
+    fn from(
+        ListSeriesByOwnerBorrowed {
+            id,
+            slug,
+            title,
+            description,
+            kind,
+            owner_id,
+            created_at,
+            updated_at,
+        }: ListSeriesByOwnerBorrowed<'a>,
+    ) -> Self {
+        Self {
+            id,
+            slug: slug.into(),
+            title: title.into(),
+            description: description.into(),
+            kind: kind.into(),
+            owner_id,
+            created_at,
+            updated_at,
+        }
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct UpdateSeries {
+    pub id: uuid::Uuid,
+    pub slug: String,
+    pub title: String,
+    pub description: String,
+    pub kind: String,
+    pub owner_id: uuid::Uuid,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+}
+pub struct UpdateSeriesBorrowed<'a> {
+    pub id: uuid::Uuid,
+    pub slug: &'a str,
+    pub title: &'a str,
+    pub description: &'a str,
+    pub kind: &'a str,
+    pub owner_id: uuid::Uuid,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+}
+impl<'a> From<UpdateSeriesBorrowed<'a>> for UpdateSeries {
+    fn from(
+        UpdateSeriesBorrowed {
+            id,
+            slug,
+            title,
+            description,
+            kind,
+            owner_id,
+            created_at,
+            updated_at,
+        }: UpdateSeriesBorrowed<'a>,
+    ) -> Self {
+        Self {
+            id,
+            slug: slug.into(),
+            title: title.into(),
+            description: description.into(),
+            kind: kind.into(),
+            owner_id,
+            created_at,
+            updated_at,
+        }
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListSeriesParts {
+    pub id: uuid::Uuid,
+    pub slug: String,
+    pub title: String,
+    pub description: String,
+    pub body: String,
+    pub author_id: uuid::Uuid,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub status: String,
+    pub published_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub author_username: String,
+    pub author_bio: String,
+    pub author_image: String,
+    pub following_author: bool,
+    pub favorited: bool,
+    pub bookmarked: bool,
+    pub favorites_count: i64,
+    pub tag_list: Vec<String>,
+    pub reaction_kinds: Vec<String>,
+    pub reaction_counts: Vec<i32>,
+    pub viewer_reactions: Vec<String>,
+}
+pub struct ListSeriesPartsBorrowed<'a> {
+    pub id: uuid::Uuid,
+    pub slug: &'a str,
+    pub title: &'a str,
+    pub description: &'a str,
+    pub body: &'a str,
+    pub author_id: uuid::Uuid,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub status: &'a str,
+    pub published_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub author_username: &'a str,
+    pub author_bio: &'a str,
+    pub author_image: &'a str,
+    pub following_author: bool,
+    pub favorited: bool,
+    pub bookmarked: bool,
+    pub favorites_count: i64,
+    pub tag_list: crate::clorinde::ArrayIterator<'a, &'a str>,
+    pub reaction_kinds: crate::clorinde::ArrayIterator<'a, &'a str>,
+    pub reaction_counts: crate::clorinde::ArrayIterator<'a, i32>,
+    pub viewer_reactions: crate::clorinde::ArrayIterator<'a, &'a str>,
+}
+impl<'a> From<ListSeriesPartsBorrowed<'a>> for ListSeriesParts {
+    fn from(
+        ListSeriesPartsBorrowed {
+            id,
+            slug,
+            title,
+            description,
+            body,
+            author_id,
+            created_at,
+            updated_at,
+            status,
+            published_at,
+            author_username,
+            author_bio,
+            author_image,
+            following_author,
+            favorited,
+            bookmarked,
+            favorites_count,
+            tag_list,
+            reaction_kinds,
+            reaction_counts,
+            viewer_reactions,
+        }: ListSeriesPartsBorrowed<'a>,
+    ) -> Self {
+        Self {
+            id,
+            slug: slug.into(),
+            title: title.into(),
+            description: description.into(),
+            body: body.into(),
+            author_id,
+            created_at,
+            updated_at,
+            status: status.into(),
+            published_at,
+            author_username: author_username.into(),
+            author_bio: author_bio.into(),
+            author_image: author_image.into(),
+            following_author,
+            favorited,
+            bookmarked,
+            favorites_count,
+            tag_list: tag_list.map(|v| v.into()).collect(),
+            reaction_kinds: reaction_kinds.map(|v| v.into()).collect(),
+            reaction_counts: reaction_counts.map(|v| v).collect(),
+            viewer_reactions: viewer_reactions.map(|v| v.into()).collect(),
+        }
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct SeriesOfArticle {
+    pub id: uuid::Uuid,
+    pub slug: String,
+    pub title: String,
+    pub description: String,
+    pub kind: String,
+    pub owner_id: uuid::Uuid,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+}
+pub struct SeriesOfArticleBorrowed<'a> {
+    pub id: uuid::Uuid,
+    pub slug: &'a str,
+    pub title: &'a str,
+    pub description: &'a str,
+    pub kind: &'a str,
+    pub owner_id: uuid::Uuid,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+}
+impl<'a> From<SeriesOfArticleBorrowed<'a>> for SeriesOfArticle {
+    fn from(
+        SeriesOfArticleBorrowed {
+            id,
+            slug,
+            title,
+            description,
+            kind,
+            owner_id,
+            created_at,
+            updated_at,
+        }: SeriesOfArticleBorrowed<'a>,
+    ) -> Self {
+        Self {
+            id,
+            slug: slug.into(),
+            title: title.into(),
+            description: description.into(),
+            kind: kind.into(),
+            owner_id,
+            created_at,
+            updated_at,
+        }
+    }
+}
+use crate::clorinde::client::async_::GenericClient;
+use futures::{self, StreamExt, TryStreamExt};
+pub struct CreateSeriesQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor: fn(&tokio_postgres::Row) -> Result<CreateSeriesBorrowed, tokio_postgres::Error>,
+    mapper: fn(CreateSeriesBorrowed) -> T,
+}
+impl<'c, 'a, 's, C, T: 'c, const N: usize> CreateSeriesQuery<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(
+        self,
+        mapper: fn(CreateSeriesBorrowed) -> R,
+    ) -> CreateSeriesQuery<'c, 'a, 's, C, R, N> {
+        CreateSeriesQuery {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
+        }
+    }
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct GetSeriesBySlugQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor: fn(&tokio_postgres::Row) -> Result<GetSeriesBySlugBorrowed, tokio_postgres::Error>,
+    mapper: fn(GetSeriesBySlugBorrowed) -> T,
+}
+impl<'c, 'a, 's, C, T: 'c, const N: usize> GetSeriesBySlugQuery<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(
+        self,
+        mapper: fn(GetSeriesBySlugBorrowed) -> R,
+    ) -> GetSeriesBySlugQuery<'c, 'a, 's, C, R, N> {
+        GetSeriesBySlugQuery {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
+        }
+    }
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct UpdateSeriesQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor: fn(&tokio_postgres::Row) -> Result<UpdateSeriesBorrowed, tokio_postgres::Error>,
+    mapper: fn(UpdateSeriesBorrowed) -> T,
+}
+impl<'c, 'a, 's, C, T: 'c, const N: usize> UpdateSeriesQuery<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(
+        self,
+        mapper: fn(UpdateSeriesBorrowed) -> R,
+    ) -> UpdateSeriesQuery<'c, 'a, 's, C, R, N> {
+        UpdateSeriesQuery {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
+        }
+    }
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct ListSeriesPartsQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor: fn(&tokio_postgres::Row) -> Result<ListSeriesPartsBorrowed, tokio_postgres::Error>,
+    mapper: fn(ListSeriesPartsBorrowed) -> T,
+}
+impl<'c, 'a, 's, C, T: 'c, const N: usize> ListSeriesPartsQuery<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(
+        self,
+        mapper: fn(ListSeriesPartsBorrowed) -> R,
+    ) -> ListSeriesPartsQuery<'c, 'a, 's, C, R, N> {
+        ListSeriesPartsQuery {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
+        }
+    }
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct SeriesOfArticleQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor: fn(&tokio_postgres::Row) -> Result<SeriesOfArticleBorrowed, tokio_postgres::Error>,
+    mapper: fn(SeriesOfArticleBorrowed) -> T,
+}
+impl<'c, 'a, 's, C, T: 'c, const N: usize> SeriesOfArticleQuery<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(
+        self,
+        mapper: fn(SeriesOfArticleBorrowed) -> R,
+    ) -> SeriesOfArticleQuery<'c, 'a, 's, C, R, N> {
+        SeriesOfArticleQuery {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
+        }
+    }
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct ListSeriesByOwnerQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor: fn(&tokio_postgres::Row) -> Result<ListSeriesByOwnerBorrowed, tokio_postgres::Error>,
+    mapper: fn(ListSeriesByOwnerBorrowed) -> T,
+}
+impl<'c, 'a, 's, C, T: 'c, const N: usize> ListSeriesByOwnerQuery<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(
+        self,
+        mapper: fn(ListSeriesByOwnerBorrowed) -> R,
+    ) -> ListSeriesByOwnerQuery<'c, 'a, 's, C, R, N> {
+        ListSeriesByOwnerQuery {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
+        }
+    }
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct CreateSeriesStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn create_series() -> CreateSeriesStmt {
+    CreateSeriesStmt(
+        "INSERT INTO series (id, slug, title, description, kind, owner_id, created_at, updated_at) VALUES ($1, $2, $3, $4, $5, $6, $7, $7) RETURNING id, slug, title, description, kind, owner_id, created_at, updated_at",
+        None,
+    )
+}
+impl CreateSeriesStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub fn bind<
+        'c,
+        'a,
+        's,
+        C: GenericClient,
+        T1: crate::clorinde::StringSql,
+        T2: crate::clorinde::StringSql,
+        T3: crate::clorinde::StringSql,
+        T4: crate::clorinde::StringSql,
+    >(
+        &'s self,
+        client: &'c C,
+        id: &'a uuid::Uuid,
+        slug: &'a T1,
+        title: &'a T2,
+        description: &'a T3,
+        kind: &'a T4,
+        owner_id: &'a uuid::Uuid,
+        created_at: &'a chrono::DateTime<chrono::FixedOffset>,
+    ) -> CreateSeriesQuery<'c, 'a, 's, C, CreateSeries, 7> {
+        CreateSeriesQuery {
+            client,
+            params: [id, slug, title, description, kind, owner_id, created_at],
+            query: self.0,
+            cached: self.1.as_ref(),
+            extractor:
+                |row: &tokio_postgres::Row| -> Result<CreateSeriesBorrowed, tokio_postgres::Error> {
+                    Ok(CreateSeriesBorrowed {
+                        id: row.try_get(0)?,
+                        slug: row.try_get(1)?,
+                        title: row.try_get(2)?,
+                        description: row.try_get(3)?,
+                        kind: row.try_get(4)?,
+                        owner_id: row.try_get(5)?,
+                        created_at: row.try_get(6)?,
+                        updated_at: row.try_get(7)?,
+                    })
+                },
+            mapper: |it| CreateSeries::from(it),
+        }
+    }
+}
+impl<
+    'c,
+    'a,
+    's,
+    C: GenericClient,
+    T1: crate::clorinde::StringSql,
+    T2: crate::clorinde::StringSql,
+    T3: crate::clorinde::StringSql,
+    T4: crate::clorinde::StringSql,
+>
+    crate::clorinde::client::async_::Params<
+        'c,
+        'a,
+        's,
+        CreateSeriesParams<T1, T2, T3, T4>,
+        CreateSeriesQuery<'c, 'a, 's, C, CreateSeries, 7>,
+        C,
+    > for CreateSeriesStmt
+{
+    fn params(
+        &'s self,
+        client: &'c C,
+        params: &'a CreateSeriesParams<T1, T2, T3, T4>,
+    ) -> CreateSeriesQuery<'c, 'a, 's, C, CreateSeries, 7> {
+        self.bind(
+            client,
+            &params.id,
+            &params.slug,
+            &params.title,
+            &params.description,
+            &params.kind,
+            &params.owner_id,
+            &params.created_at,
+        )
+    }
+}
+pub struct GetSeriesBySlugStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn get_series_by_slug() -> GetSeriesBySlugStmt {
+    GetSeriesBySlugStmt(
+        "SELECT id, slug, title, description, kind, owner_id, created_at, updated_at FROM series WHERE slug = $1",
+        None,
+    )
+}
+impl GetSeriesBySlugStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub fn bind<'c, 'a, 's, C: GenericClient, T1: crate::clorinde::StringSql>(
+        &'s self,
+        client: &'c C,
+        slug: &'a T1,
+    ) -> GetSeriesBySlugQuery<'c, 'a, 's, C, GetSeriesBySlug, 1> {
+        GetSeriesBySlugQuery { client, params: [slug], query: self.0, cached: self.1.as_ref(), extractor: |row: &tokio_postgres::Row| -> Result<GetSeriesBySlugBorrowed, tokio_postgres::Error> { Ok(GetSeriesBySlugBorrowed { id: row.try_get(0)?,
+slug: row.try_get(1)?,
+title: row.try_get(2)?,
+description: row.try_get(3)?,
+kind: row.try_get(4)?,
+owner_id: row.try_get(5)?,
+created_at: row.try_get(6)?,
+updated_at: row.try_get(7)?,
+ }) }, mapper: |it| GetSeriesBySlug::from(it), }
+    }
+}
+pub struct ListSeriesByOwnerStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn list_series_by_owner() -> ListSeriesByOwnerStmt {
+    ListSeriesByOwnerStmt(
+        "SELECT id, slug, title, description, kind, owner_id, created_at, updated_at FROM series WHERE owner_id = $1 ORDER BY created_at, id",
+        None,
+    )
+}
+impl ListSeriesByOwnerStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        owner_id: &'a uuid::Uuid,
+    ) -> ListSeriesByOwnerQuery<'c, 'a, 's, C, ListSeriesByOwner, 1> {
+        ListSeriesByOwnerQuery { client, params: [owner_id], query: self.0, cached: self.1.as_ref(), extractor: |row: &tokio_postgres::Row| -> Result<ListSeriesByOwnerBorrowed, tokio_postgres::Error> { Ok(ListSeriesByOwnerBorrowed { id: row.try_get(0)?,
+slug: row.try_get(1)?,
+title: row.try_get(2)?,
+description: row.try_get(3)?,
+kind: row.try_get(4)?,
+owner_id: row.try_get(5)?,
+created_at: row.try_get(6)?,
+updated_at: row.try_get(7)?,
+ }) }, mapper: |it| ListSeriesByOwner::from(it), }
+    }
+}
+pub struct UpdateSeriesStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn update_series() -> UpdateSeriesStmt {
+    UpdateSeriesStmt(
+        "UPDATE series SET title = $1, description = $2, updated_at = $3 WHERE id = $4 RETURNING id, slug, title, description, kind, owner_id, created_at, updated_at",
+        None,
+    )
+}
+impl UpdateSeriesStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub fn bind<'c, 'a, 's, C: GenericClient, T1: crate::clorinde::StringSql, T2: crate::clorinde::StringSql>(
+        &'s self,
+        client: &'c C,
+        title: &'a T1,
+        description: &'a T2,
+        updated_at: &'a chrono::DateTime<chrono::FixedOffset>,
+        id: &'a uuid::Uuid,
+    ) -> UpdateSeriesQuery<'c, 'a, 's, C, UpdateSeries, 4> {
+        UpdateSeriesQuery {
+            client,
+            params: [title, description, updated_at, id],
+            query: self.0,
+            cached: self.1.as_ref(),
+            extractor:
+                |row: &tokio_postgres::Row| -> Result<UpdateSeriesBorrowed, tokio_postgres::Error> {
+                    Ok(UpdateSeriesBorrowed {
+                        id: row.try_get(0)?,
+                        slug: row.try_get(1)?,
+                        title: row.try_get(2)?,
+                        description: row.try_get(3)?,
+                        kind: row.try_get(4)?,
+                        owner_id: row.try_get(5)?,
+                        created_at: row.try_get(6)?,
+                        updated_at: row.try_get(7)?,
+                    })
+                },
+            mapper: |it| UpdateSeries::from(it),
+        }
+    }
+}
+impl<'c, 'a, 's, C: GenericClient, T1: crate::clorinde::StringSql, T2: crate::clorinde::StringSql>
+    crate::clorinde::client::async_::Params<
+        'c,
+        'a,
+        's,
+        UpdateSeriesParams<T1, T2>,
+        UpdateSeriesQuery<'c, 'a, 's, C, UpdateSeries, 4>,
+        C,
+    > for UpdateSeriesStmt
+{
+    fn params(
+        &'s self,
+        client: &'c C,
+        params: &'a UpdateSeriesParams<T1, T2>,
+    ) -> UpdateSeriesQuery<'c, 'a, 's, C, UpdateSeries, 4> {
+        self.bind(
+            client,
+            &params.title,
+            &params.description,
+            &params.updated_at,
+            &params.id,
+        )
+    }
+}
+pub struct DeleteSeriesStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn delete_series() -> DeleteSeriesStmt {
+    DeleteSeriesStmt("DELETE FROM series WHERE id = $1", None)
+}
+impl DeleteSeriesStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub async fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        id: &'a uuid::Uuid,
+    ) -> Result<u64, tokio_postgres::Error> {
+        client.execute(self.0, &[id]).await
+    }
+}
+pub struct ClearSeriesPartsStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn clear_series_parts() -> ClearSeriesPartsStmt {
+    ClearSeriesPartsStmt("DELETE FROM series_article WHERE series_id = $1", None)
+}
+impl ClearSeriesPartsStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub async fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        series_id: &'a uuid::Uuid,
+    ) -> Result<u64, tokio_postgres::Error> {
+        client.execute(self.0, &[series_id]).await
+    }
+}
+pub struct AddSeriesPartStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn add_series_part() -> AddSeriesPartStmt {
+    AddSeriesPartStmt(
+        "INSERT INTO series_article (series_id, article_id, position, kind) SELECT id, $1, $2, kind FROM series WHERE id = $3",
+        None,
+    )
+}
+impl AddSeriesPartStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub async fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        article_id: &'a uuid::Uuid,
+        position: &'a i32,
+        series_id: &'a uuid::Uuid,
+    ) -> Result<u64, tokio_postgres::Error> {
+        client
+            .execute(self.0, &[article_id, position, series_id])
+            .await
+    }
+}
+impl<'a, C: GenericClient + Send + Sync>
+    crate::clorinde::client::async_::Params<
+        'a,
+        'a,
+        'a,
+        AddSeriesPartParams,
+        std::pin::Pin<
+            Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+        >,
+        C,
+    > for AddSeriesPartStmt
+{
+    fn params(
+        &'a self,
+        client: &'a C,
+        params: &'a AddSeriesPartParams,
+    ) -> std::pin::Pin<
+        Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+    > {
+        Box::pin(self.bind(
+            client,
+            &params.article_id,
+            &params.position,
+            &params.series_id,
+        ))
+    }
+}
+pub struct ListSeriesPartsStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn list_series_parts() -> ListSeriesPartsStmt {
+    ListSeriesPartsStmt(
+        "SELECT a.id, a.slug, a.title, a.description, a.body, a.author_id, a.created_at, a.updated_at, a.status, a.published_at, u.username as author_username, u.bio as author_bio, u.img as author_image, EXISTS(SELECT 1 FROM appuser_follows WHERE follower_id = $1 AND followee_id = a.author_id) as following_author, EXISTS(SELECT 1 FROM article_favorite WHERE appuser_id = $1 AND article_id = a.id) as favorited, EXISTS(SELECT 1 FROM article_bookmark WHERE appuser_id = $1 AND article_id = a.id) as bookmarked, (SELECT COUNT(*) FROM article_favorite WHERE article_id = a.id) as favorites_count, ARRAY(SELECT t.name FROM tag t JOIN article_tag at ON t.id = at.tag_id WHERE at.article_id = a.id ORDER BY t.name) as tag_list, ARRAY(SELECT rc.kind FROM article_reaction_count rc WHERE rc.article_id = a.id ORDER BY rc.count DESC, rc.kind COLLATE ucs_basic) as reaction_kinds, ARRAY(SELECT rc.count FROM article_reaction_count rc WHERE rc.article_id = a.id ORDER BY rc.count DESC, rc.kind COLLATE ucs_basic) as reaction_counts, ARRAY(SELECT ar.kind FROM article_reaction ar WHERE ar.article_id = a.id AND ar.appuser_id = $1) as viewer_reactions FROM series_article sa JOIN article a ON a.id = sa.article_id JOIN appuser u ON a.author_id = u.id WHERE sa.series_id = $2 AND u.deactivated_at IS NULL AND a.deleted_at IS NULL AND ((a.status = 'published' AND a.hidden_at IS NULL) OR a.author_id = $1) ORDER BY sa.position",
+        None,
+    )
+}
+impl ListSeriesPartsStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        viewer_id: &'a uuid::Uuid,
+        series_id: &'a uuid::Uuid,
+    ) -> ListSeriesPartsQuery<'c, 'a, 's, C, ListSeriesParts, 2> {
+        ListSeriesPartsQuery { client, params: [viewer_id, series_id], query: self.0, cached: self.1.as_ref(), extractor: |row: &tokio_postgres::Row| -> Result<ListSeriesPartsBorrowed, tokio_postgres::Error> { Ok(ListSeriesPartsBorrowed { id: row.try_get(0)?,
+slug: row.try_get(1)?,
+title: row.try_get(2)?,
+description: row.try_get(3)?,
+body: row.try_get(4)?,
+author_id: row.try_get(5)?,
+created_at: row.try_get(6)?,
+updated_at: row.try_get(7)?,
+status: row.try_get(8)?,
+published_at: row.try_get(9)?,
+author_username: row.try_get(10)?,
+author_bio: row.try_get(11)?,
+author_image: row.try_get(12)?,
+following_author: row.try_get(13)?,
+favorited: row.try_get(14)?,
+bookmarked: row.try_get(15)?,
+favorites_count: row.try_get(16)?,
+tag_list: row.try_get(17)?,
+reaction_kinds: row.try_get(18)?,
+reaction_counts: row.try_get(19)?,
+viewer_reactions: row.try_get(20)?,
+ }) }, mapper: |it| ListSeriesParts::from(it), }
+    }
+}
+impl<'c, 'a, 's, C: GenericClient>
+    crate::clorinde::client::async_::Params<
+        'c,
+        'a,
+        's,
+        ListSeriesPartsParams,
+        ListSeriesPartsQuery<'c, 'a, 's, C, ListSeriesParts, 2>,
+        C,
+    > for ListSeriesPartsStmt
+{
+    fn params(
+        &'s self,
+        client: &'c C,
+        params: &'a ListSeriesPartsParams,
+    ) -> ListSeriesPartsQuery<'c, 'a, 's, C, ListSeriesParts, 2> {
+        self.bind(client, &params.viewer_id, &params.series_id)
+    }
+}
+pub struct SeriesOfArticleStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn series_of_article() -> SeriesOfArticleStmt {
+    SeriesOfArticleStmt(
+        "SELECT s.id, s.slug, s.title, s.description, s.kind, s.owner_id, s.created_at, s.updated_at FROM series s JOIN series_article sa ON sa.series_id = s.id JOIN article a ON a.id = sa.article_id WHERE sa.article_id = $1 AND s.kind = 'series' AND a.deleted_at IS NULL LIMIT 1",
+        None,
+    )
+}
+impl SeriesOfArticleStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        article_id: &'a uuid::Uuid,
+    ) -> SeriesOfArticleQuery<'c, 'a, 's, C, SeriesOfArticle, 1> {
+        SeriesOfArticleQuery { client, params: [article_id], query: self.0, cached: self.1.as_ref(), extractor: |row: &tokio_postgres::Row| -> Result<SeriesOfArticleBorrowed, tokio_postgres::Error> { Ok(SeriesOfArticleBorrowed { id: row.try_get(0)?,
+slug: row.try_get(1)?,
+title: row.try_get(2)?,
+description: row.try_get(3)?,
+kind: row.try_get(4)?,
+owner_id: row.try_get(5)?,
+created_at: row.try_get(6)?,
+updated_at: row.try_get(7)?,
+ }) }, mapper: |it| SeriesOfArticle::from(it), }
+    }
+}
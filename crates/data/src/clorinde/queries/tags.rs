@@ -0,0 +1,745 @@
+// This file was generated with `clorinde`. Do not modify.
+
+#[derive(Debug)]
+pub struct SearchTagsParams<T1: crate::clorinde::StringSql> {
+    pub prefix: T1,
+    pub limit: i64,
+}
+#[derive(Clone, Copy, Debug)]
+pub struct RelinkTagParams {
+    pub target_id: uuid::Uuid,
+    pub source_id: uuid::Uuid,
+}
+#[derive(Clone, Copy, Debug)]
+pub struct RepointAliasesParams {
+    pub target_id: uuid::Uuid,
+    pub source_id: uuid::Uuid,
+}
+#[derive(Debug)]
+pub struct UpsertAliasParams<T1: crate::clorinde::StringSql> {
+    pub alias: T1,
+    pub tag_id: uuid::Uuid,
+}
+#[derive(Clone, Copy, Debug)]
+pub struct RelinkFollowsParams {
+    pub target_id: uuid::Uuid,
+    pub source_id: uuid::Uuid,
+}
+#[derive(Clone, Copy, Debug)]
+pub struct FollowTagParams {
+    pub user_id: uuid::Uuid,
+    pub tag_id: uuid::Uuid,
+}
+#[derive(Debug)]
+pub struct UnfollowTagParams<T1: crate::clorinde::StringSql> {
+    pub user_id: uuid::Uuid,
+    pub name: T1,
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct PopularTags {
+    pub name: String,
+    pub articles_count: i64,
+}
+pub struct PopularTagsBorrowed<'a> {
+    pub name: &'a str,
+    pub articles_count: i64,
+}
+impl<'a> From<PopularTagsBorrowed<'a>> for PopularTags {
+    fn from(
+        PopularTagsBorrowed {
+            name,
+            articles_count,
+        }: PopularTagsBorrowed<'a>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            articles_count,
+        }
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchTags {
+    pub name: String,
+    pub articles_count: i64,
+}
+pub struct SearchTagsBorrowed<'a> {
+    pub name: &'a str,
+    pub articles_count: i64,
+}
+impl<'a> From<SearchTagsBorrowed<'a>> for SearchTags {
+    fn from(
+        SearchTagsBorrowed {
+            name,
+            articles_count,
+        }: SearchTagsBorrowed<'a>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            articles_count,
+        }
+    }
+}
+use crate::clorinde::client::async_::GenericClient;
+use futures::{self, StreamExt, TryStreamExt};
+pub struct PopularTagsQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor: fn(&tokio_postgres::Row) -> Result<PopularTagsBorrowed, tokio_postgres::Error>,
+    mapper: fn(PopularTagsBorrowed) -> T,
+}
+impl<'c, 'a, 's, C, T: 'c, const N: usize> PopularTagsQuery<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(
+        self,
+        mapper: fn(PopularTagsBorrowed) -> R,
+    ) -> PopularTagsQuery<'c, 'a, 's, C, R, N> {
+        PopularTagsQuery {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
+        }
+    }
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct SearchTagsQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor: fn(&tokio_postgres::Row) -> Result<SearchTagsBorrowed, tokio_postgres::Error>,
+    mapper: fn(SearchTagsBorrowed) -> T,
+}
+impl<'c, 'a, 's, C, T: 'c, const N: usize> SearchTagsQuery<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(
+        self,
+        mapper: fn(SearchTagsBorrowed) -> R,
+    ) -> SearchTagsQuery<'c, 'a, 's, C, R, N> {
+        SearchTagsQuery {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
+        }
+    }
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct StringQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor: fn(&tokio_postgres::Row) -> Result<&str, tokio_postgres::Error>,
+    mapper: fn(&str) -> T,
+}
+impl<'c, 'a, 's, C, T: 'c, const N: usize> StringQuery<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(self, mapper: fn(&str) -> R) -> StringQuery<'c, 'a, 's, C, R, N> {
+        StringQuery {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
+        }
+    }
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct PopularTagsStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn popular_tags() -> PopularTagsStmt {
+    PopularTagsStmt(
+        "SELECT t.name, COUNT(*) AS articles_count FROM tag t JOIN article_tag at ON t.id = at.tag_id JOIN article a ON a.id = at.article_id JOIN appuser u ON u.id = a.author_id WHERE a.status = 'published' AND a.deleted_at IS NULL AND u.deactivated_at IS NULL GROUP BY t.id, t.name ORDER BY articles_count DESC, t.name LIMIT $1",
+        None,
+    )
+}
+impl PopularTagsStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        limit: &'a i64,
+    ) -> PopularTagsQuery<'c, 'a, 's, C, PopularTags, 1> {
+        PopularTagsQuery {
+            client,
+            params: [limit],
+            query: self.0,
+            cached: self.1.as_ref(),
+            extractor:
+                |row: &tokio_postgres::Row| -> Result<PopularTagsBorrowed, tokio_postgres::Error> {
+                    Ok(PopularTagsBorrowed {
+                        name: row.try_get(0)?,
+                        articles_count: row.try_get(1)?,
+                    })
+                },
+            mapper: |it| PopularTags::from(it),
+        }
+    }
+}
+pub struct SearchTagsStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn search_tags() -> SearchTagsStmt {
+    SearchTagsStmt(
+        "SELECT t.name, COUNT(*) AS articles_count FROM tag t JOIN article_tag at ON t.id = at.tag_id JOIN article a ON a.id = at.article_id JOIN appuser u ON u.id = a.author_id WHERE a.status = 'published' AND a.deleted_at IS NULL AND u.deactivated_at IS NULL AND left(lower(t.name), length($1)) = lower($1) GROUP BY t.id, t.name ORDER BY articles_count DESC, t.name LIMIT $2",
+        None,
+    )
+}
+impl SearchTagsStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub fn bind<'c, 'a, 's, C: GenericClient, T1: crate::clorinde::StringSql>(
+        &'s self,
+        client: &'c C,
+        prefix: &'a T1,
+        limit: &'a i64,
+    ) -> SearchTagsQuery<'c, 'a, 's, C, SearchTags, 2> {
+        SearchTagsQuery {
+            client,
+            params: [prefix, limit],
+            query: self.0,
+            cached: self.1.as_ref(),
+            extractor:
+                |row: &tokio_postgres::Row| -> Result<SearchTagsBorrowed, tokio_postgres::Error> {
+                    Ok(SearchTagsBorrowed {
+                        name: row.try_get(0)?,
+                        articles_count: row.try_get(1)?,
+                    })
+                },
+            mapper: |it| SearchTags::from(it),
+        }
+    }
+}
+impl<'c, 'a, 's, C: GenericClient, T1: crate::clorinde::StringSql>
+    crate::clorinde::client::async_::Params<
+        'c,
+        'a,
+        's,
+        SearchTagsParams<T1>,
+        SearchTagsQuery<'c, 'a, 's, C, SearchTags, 2>,
+        C,
+    > for SearchTagsStmt
+{
+    fn params(
+        &'s self,
+        client: &'c C,
+        params: &'a SearchTagsParams<T1>,
+    ) -> SearchTagsQuery<'c, 'a, 's, C, SearchTags, 2> {
+        self.bind(client, &params.prefix, &params.limit)
+    }
+}
+pub struct CanonicalTagNameStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn canonical_tag_name() -> CanonicalTagNameStmt {
+    CanonicalTagNameStmt(
+        "SELECT COALESCE( (SELECT t.name FROM tag_alias ta JOIN tag t ON t.id = ta.tag_id WHERE ta.alias = $1), $1 )",
+        None,
+    )
+}
+impl CanonicalTagNameStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub fn bind<'c, 'a, 's, C: GenericClient, T1: crate::clorinde::StringSql>(
+        &'s self,
+        client: &'c C,
+        name: &'a T1,
+    ) -> StringQuery<'c, 'a, 's, C, String, 1> {
+        StringQuery {
+            client,
+            params: [name],
+            query: self.0,
+            cached: self.1.as_ref(),
+            extractor: |row| Ok(row.try_get(0)?),
+            mapper: |it| it.into(),
+        }
+    }
+}
+pub struct RelinkTagStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn relink_tag() -> RelinkTagStmt {
+    RelinkTagStmt(
+        "INSERT INTO article_tag (article_id, tag_id, created_at) SELECT article_id, $1, created_at FROM article_tag WHERE tag_id = $2 ON CONFLICT DO NOTHING",
+        None,
+    )
+}
+impl RelinkTagStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub async fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        target_id: &'a uuid::Uuid,
+        source_id: &'a uuid::Uuid,
+    ) -> Result<u64, tokio_postgres::Error> {
+        client.execute(self.0, &[target_id, source_id]).await
+    }
+}
+impl<'a, C: GenericClient + Send + Sync>
+    crate::clorinde::client::async_::Params<
+        'a,
+        'a,
+        'a,
+        RelinkTagParams,
+        std::pin::Pin<
+            Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+        >,
+        C,
+    > for RelinkTagStmt
+{
+    fn params(
+        &'a self,
+        client: &'a C,
+        params: &'a RelinkTagParams,
+    ) -> std::pin::Pin<
+        Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+    > {
+        Box::pin(self.bind(client, &params.target_id, &params.source_id))
+    }
+}
+pub struct UnlinkTagStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn unlink_tag() -> UnlinkTagStmt {
+    UnlinkTagStmt("DELETE FROM article_tag WHERE tag_id = $1", None)
+}
+impl UnlinkTagStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub async fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        tag_id: &'a uuid::Uuid,
+    ) -> Result<u64, tokio_postgres::Error> {
+        client.execute(self.0, &[tag_id]).await
+    }
+}
+pub struct RepointAliasesStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn repoint_aliases() -> RepointAliasesStmt {
+    RepointAliasesStmt("UPDATE tag_alias SET tag_id = $1 WHERE tag_id = $2", None)
+}
+impl RepointAliasesStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub async fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        target_id: &'a uuid::Uuid,
+        source_id: &'a uuid::Uuid,
+    ) -> Result<u64, tokio_postgres::Error> {
+        client.execute(self.0, &[target_id, source_id]).await
+    }
+}
+impl<'a, C: GenericClient + Send + Sync>
+    crate::clorinde::client::async_::Params<
+        'a,
+        'a,
+        'a,
+        RepointAliasesParams,
+        std::pin::Pin<
+            Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+        >,
+        C,
+    > for RepointAliasesStmt
+{
+    fn params(
+        &'a self,
+        client: &'a C,
+        params: &'a RepointAliasesParams,
+    ) -> std::pin::Pin<
+        Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+    > {
+        Box::pin(self.bind(client, &params.target_id, &params.source_id))
+    }
+}
+pub struct DeleteTagStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn delete_tag() -> DeleteTagStmt {
+    DeleteTagStmt("DELETE FROM tag WHERE id = $1", None)
+}
+impl DeleteTagStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub async fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        id: &'a uuid::Uuid,
+    ) -> Result<u64, tokio_postgres::Error> {
+        client.execute(self.0, &[id]).await
+    }
+}
+pub struct UpsertAliasStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn upsert_alias() -> UpsertAliasStmt {
+    UpsertAliasStmt(
+        "INSERT INTO tag_alias (alias, tag_id) VALUES ($1, $2) ON CONFLICT (alias) DO UPDATE SET tag_id = EXCLUDED.tag_id",
+        None,
+    )
+}
+impl UpsertAliasStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub async fn bind<'c, 'a, 's, C: GenericClient, T1: crate::clorinde::StringSql>(
+        &'s self,
+        client: &'c C,
+        alias: &'a T1,
+        tag_id: &'a uuid::Uuid,
+    ) -> Result<u64, tokio_postgres::Error> {
+        client.execute(self.0, &[alias, tag_id]).await
+    }
+}
+impl<'a, C: GenericClient + Send + Sync, T1: crate::clorinde::StringSql>
+    crate::clorinde::client::async_::Params<
+        'a,
+        'a,
+        'a,
+        UpsertAliasParams<T1>,
+        std::pin::Pin<
+            Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+        >,
+        C,
+    > for UpsertAliasStmt
+{
+    fn params(
+        &'a self,
+        client: &'a C,
+        params: &'a UpsertAliasParams<T1>,
+    ) -> std::pin::Pin<
+        Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+    > {
+        Box::pin(self.bind(client, &params.alias, &params.tag_id))
+    }
+}
+pub struct RelinkFollowsStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn relink_follows() -> RelinkFollowsStmt {
+    RelinkFollowsStmt(
+        "INSERT INTO tag_follow (appuser_id, tag_id, created_at) SELECT appuser_id, $1, created_at FROM tag_follow WHERE tag_id = $2 ON CONFLICT DO NOTHING",
+        None,
+    )
+}
+impl RelinkFollowsStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub async fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        target_id: &'a uuid::Uuid,
+        source_id: &'a uuid::Uuid,
+    ) -> Result<u64, tokio_postgres::Error> {
+        client.execute(self.0, &[target_id, source_id]).await
+    }
+}
+impl<'a, C: GenericClient + Send + Sync>
+    crate::clorinde::client::async_::Params<
+        'a,
+        'a,
+        'a,
+        RelinkFollowsParams,
+        std::pin::Pin<
+            Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+        >,
+        C,
+    > for RelinkFollowsStmt
+{
+    fn params(
+        &'a self,
+        client: &'a C,
+        params: &'a RelinkFollowsParams,
+    ) -> std::pin::Pin<
+        Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+    > {
+        Box::pin(self.bind(client, &params.target_id, &params.source_id))
+    }
+}
+pub struct FollowTagStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn follow_tag() -> FollowTagStmt {
+    FollowTagStmt(
+        "INSERT INTO tag_follow (appuser_id, tag_id) VALUES ($1, $2) ON CONFLICT DO NOTHING",
+        None,
+    )
+}
+impl FollowTagStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub async fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        user_id: &'a uuid::Uuid,
+        tag_id: &'a uuid::Uuid,
+    ) -> Result<u64, tokio_postgres::Error> {
+        client.execute(self.0, &[user_id, tag_id]).await
+    }
+}
+impl<'a, C: GenericClient + Send + Sync>
+    crate::clorinde::client::async_::Params<
+        'a,
+        'a,
+        'a,
+        FollowTagParams,
+        std::pin::Pin<
+            Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+        >,
+        C,
+    > for FollowTagStmt
+{
+    fn params(
+        &'a self,
+        client: &'a C,
+        params: &'a FollowTagParams,
+    ) -> std::pin::Pin<
+        Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+    > {
+        Box::pin(self.bind(client, &params.user_id, &params.tag_id))
+    }
+}
+pub struct UnfollowTagStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn unfollow_tag() -> UnfollowTagStmt {
+    UnfollowTagStmt(
+        "DELETE FROM tag_follow WHERE appuser_id = $1 AND tag_id = (SELECT id FROM tag WHERE name = $2)",
+        None,
+    )
+}
+impl UnfollowTagStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub async fn bind<'c, 'a, 's, C: GenericClient, T1: crate::clorinde::StringSql>(
+        &'s self,
+        client: &'c C,
+        user_id: &'a uuid::Uuid,
+        name: &'a T1,
+    ) -> Result<u64, tokio_postgres::Error> {
+        client.execute(self.0, &[user_id, name]).await
+    }
+}
+impl<'a, C: GenericClient + Send + Sync, T1: crate::clorinde::StringSql>
+    crate::clorinde::client::async_::Params<
+        'a,
+        'a,
+        'a,
+        UnfollowTagParams<T1>,
+        std::pin::Pin<
+            Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+        >,
+        C,
+    > for UnfollowTagStmt
+{
+    fn params(
+        &'a self,
+        client: &'a C,
+        params: &'a UnfollowTagParams<T1>,
+    ) -> std::pin::Pin<
+        Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+    > {
+        Box::pin(self.bind(client, &params.user_id, &params.name))
+    }
+}
+pub struct FollowedTagsStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn followed_tags() -> FollowedTagsStmt {
+    FollowedTagsStmt(
+        "SELECT t.name FROM tag_follow tf JOIN tag t ON t.id = tf.tag_id WHERE tf.appuser_id = $1 ORDER BY t.name",
+        None,
+    )
+}
+impl FollowedTagsStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        user_id: &'a uuid::Uuid,
+    ) -> StringQuery<'c, 'a, 's, C, String, 1> {
+        StringQuery {
+            client,
+            params: [user_id],
+            query: self.0,
+            cached: self.1.as_ref(),
+            extractor: |row| Ok(row.try_get(0)?),
+            mapper: |it| it.into(),
+        }
+    }
+}
@@ -39,6 +39,51 @@ pub struct IsFollowingParams {
     pub follower_id: uuid::Uuid,
     pub followee_id: uuid::Uuid,
 }
+#[derive(Clone, Copy, Debug)]
+pub struct DeactivateUserParams {
+    pub deactivated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub id: uuid::Uuid,
+}
+#[derive(Clone, Copy, Debug)]
+pub struct SuspendUserParams {
+    pub suspended_at: chrono::DateTime<chrono::FixedOffset>,
+    pub id: uuid::Uuid,
+}
+#[derive(Clone, Copy, Debug)]
+pub struct BlockUserParams {
+    pub blocker_id: uuid::Uuid,
+    pub blocked_id: uuid::Uuid,
+}
+#[derive(Clone, Copy, Debug)]
+pub struct UnfollowBothWaysParams {
+    pub user_id: uuid::Uuid,
+    pub other_id: uuid::Uuid,
+}
+#[derive(Clone, Copy, Debug)]
+pub struct UnblockUserParams {
+    pub blocker_id: uuid::Uuid,
+    pub blocked_id: uuid::Uuid,
+}
+#[derive(Clone, Copy, Debug)]
+pub struct IsBlockingParams {
+    pub blocker_id: uuid::Uuid,
+    pub blocked_id: uuid::Uuid,
+}
+#[derive(Clone, Copy, Debug)]
+pub struct MuteUserParams {
+    pub muter_id: uuid::Uuid,
+    pub muted_id: uuid::Uuid,
+}
+#[derive(Clone, Copy, Debug)]
+pub struct UnmuteUserParams {
+    pub muter_id: uuid::Uuid,
+    pub muted_id: uuid::Uuid,
+}
+#[derive(Clone, Copy, Debug)]
+pub struct IsMutingParams {
+    pub muter_id: uuid::Uuid,
+    pub muted_id: uuid::Uuid,
+}
 #[derive(Debug, Clone, PartialEq)]
 pub struct CreateUser {
     pub id: uuid::Uuid,
@@ -49,6 +94,8 @@ pub struct CreateUser {
     pub bio: String,
     pub created_at: chrono::DateTime<chrono::FixedOffset>,
     pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub deactivated_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub suspended_at: Option<chrono::DateTime<chrono::FixedOffset>>,
 }
 pub struct CreateUserBorrowed<'a> {
     pub id: uuid::Uuid,
@@ -59,6 +106,8 @@ pub struct CreateUserBorrowed<'a> {
     pub bio: &'a str,
     pub created_at: chrono::DateTime<chrono::FixedOffset>,
     pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub deactivated_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub suspended_at: Option<chrono::DateTime<chrono::FixedOffset>>,
 }
 impl<'a> From<CreateUserBorrowed<'a>> for CreateUser {
     fn from(
@@ -71,6 +120,8 @@ impl<'a> From<CreateUserBorrowed<'a>> for CreateUser {
             bio,
             created_at,
             updated_at,
+            deactivated_at,
+            suspended_at,
         }: CreateUserBorrowed<'a>,
     ) -> Self {
         Self {
@@ -82,6 +133,8 @@ impl<'a> From<CreateUserBorrowed<'a>> for CreateUser {
             bio: bio.into(),
             created_at,
             updated_at,
+            deactivated_at,
+            suspended_at,
         }
     }
 }
@@ -95,6 +148,8 @@ pub struct GetUserByEmail {
     pub bio: String,
     pub created_at: chrono::DateTime<chrono::FixedOffset>,
     pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub deactivated_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub suspended_at: Option<chrono::DateTime<chrono::FixedOffset>>,
 }
 pub struct GetUserByEmailBorrowed<'a> {
     pub id: uuid::Uuid,
@@ -105,6 +160,8 @@ pub struct GetUserByEmailBorrowed<'a> {
     pub bio: &'a str,
     pub created_at: chrono::DateTime<chrono::FixedOffset>,
     pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub deactivated_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub suspended_at: Option<chrono::DateTime<chrono::FixedOffset>>,
 }
 impl<'a> From<GetUserByEmailBorrowed<'a>> for GetUserByEmail {
     fn from(
@@ -117,6 +174,8 @@ impl<'a> From<GetUserByEmailBorrowed<'a>> for GetUserByEmail {
             bio,
             created_at,
             updated_at,
+            deactivated_at,
+            suspended_at,
         }: GetUserByEmailBorrowed<'a>,
     ) -> Self {
         Self {
@@ -128,6 +187,8 @@ impl<'a> From<GetUserByEmailBorrowed<'a>> for GetUserByEmail {
             bio: bio.into(),
             created_at,
             updated_at,
+            deactivated_at,
+            suspended_at,
         }
     }
 }
@@ -141,6 +202,8 @@ pub struct GetUserByUsername {
     pub bio: String,
     pub created_at: chrono::DateTime<chrono::FixedOffset>,
     pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub deactivated_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub suspended_at: Option<chrono::DateTime<chrono::FixedOffset>>,
 }
 pub struct GetUserByUsernameBorrowed<'a> {
     pub id: uuid::Uuid,
@@ -151,6 +214,8 @@ pub struct GetUserByUsernameBorrowed<'a> {
     pub bio: &'a str,
     pub created_at: chrono::DateTime<chrono::FixedOffset>,
     pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub deactivated_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub suspended_at: Option<chrono::DateTime<chrono::FixedOffset>>,
 }
 impl<'a> From<GetUserByUsernameBorrowed<'a>> for GetUserByUsername {
     fn from(
@@ -163,6 +228,8 @@ impl<'a> From<GetUserByUsernameBorrowed<'a>> for GetUserByUsername {
             bio,
             created_at,
             updated_at,
+            deactivated_at,
+            suspended_at,
         }: GetUserByUsernameBorrowed<'a>,
     ) -> Self {
         Self {
@@ -174,6 +241,8 @@ impl<'a> From<GetUserByUsernameBorrowed<'a>> for GetUserByUsername {
             bio: bio.into(),
             created_at,
             updated_at,
+            deactivated_at,
+            suspended_at,
         }
     }
 }
@@ -187,6 +256,8 @@ pub struct GetUserById {
     pub bio: String,
     pub created_at: chrono::DateTime<chrono::FixedOffset>,
     pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub deactivated_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub suspended_at: Option<chrono::DateTime<chrono::FixedOffset>>,
 }
 pub struct GetUserByIdBorrowed<'a> {
     pub id: uuid::Uuid,
@@ -197,6 +268,8 @@ pub struct GetUserByIdBorrowed<'a> {
     pub bio: &'a str,
     pub created_at: chrono::DateTime<chrono::FixedOffset>,
     pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub deactivated_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub suspended_at: Option<chrono::DateTime<chrono::FixedOffset>>,
 }
 impl<'a> From<GetUserByIdBorrowed<'a>> for GetUserById {
     fn from(
@@ -209,6 +282,8 @@ impl<'a> From<GetUserByIdBorrowed<'a>> for GetUserById {
             bio,
             created_at,
             updated_at,
+            deactivated_at,
+            suspended_at,
         }: GetUserByIdBorrowed<'a>,
     ) -> Self {
         Self {
@@ -220,6 +295,8 @@ impl<'a> From<GetUserByIdBorrowed<'a>> for GetUserById {
             bio: bio.into(),
             created_at,
             updated_at,
+            deactivated_at,
+            suspended_at,
         }
     }
 }
@@ -233,6 +310,8 @@ pub struct UpdateUser {
     pub bio: String,
     pub created_at: chrono::DateTime<chrono::FixedOffset>,
     pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub deactivated_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub suspended_at: Option<chrono::DateTime<chrono::FixedOffset>>,
 }
 pub struct UpdateUserBorrowed<'a> {
     pub id: uuid::Uuid,
@@ -243,6 +322,8 @@ pub struct UpdateUserBorrowed<'a> {
     pub bio: &'a str,
     pub created_at: chrono::DateTime<chrono::FixedOffset>,
     pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub deactivated_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub suspended_at: Option<chrono::DateTime<chrono::FixedOffset>>,
 }
 impl<'a> From<UpdateUserBorrowed<'a>> for UpdateUser {
     fn from(
@@ -255,6 +336,8 @@ impl<'a> From<UpdateUserBorrowed<'a>> for UpdateUser {
             bio,
             created_at,
             updated_at,
+            deactivated_at,
+            suspended_at,
         }: UpdateUserBorrowed<'a>,
     ) -> Self {
         Self {
@@ -266,6 +349,224 @@ impl<'a> From<UpdateUserBorrowed<'a>> for UpdateUser {
             bio: bio.into(),
             created_at,
             updated_at,
+            deactivated_at,
+            suspended_at,
+        }
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListFollowedUsers {
+    pub id: uuid::Uuid,
+    pub email: String,
+    pub username: String,
+    pub pwd: String,
+    pub img: String,
+    pub bio: String,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub deactivated_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub suspended_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+}
+pub struct ListFollowedUsersBorrowed<'a> {
+    pub id: uuid::Uuid,
+    pub email: &'a str,
+    pub username: &'a str,
+    pub pwd: &'a str,
+    pub img: &'a str,
+    pub bio: &'a str,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub deactivated_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub suspended_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+}
+impl<'a> From<ListFollowedUsersBorrowed<'a>> for ListFollowedUsers {
+    fn from(
+        ListFollowedUsersBorrowed {
+            id,
+            email,
+            username,
+            pwd,
+            img,
+            bio,
+            created_at,
+            updated_at,
+            deactivated_at,
+            suspended_at,
+        }: ListFollowedUsersBorrowed<'a>,
+    ) -> Self {
+        Self {
+            id,
+            email: email.into(),
+            username: username.into(),
+            pwd: pwd.into(),
+            img: img.into(),
+            bio: bio.into(),
+            created_at,
+            updated_at,
+            deactivated_at,
+            suspended_at,
+        }
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListBlockedUsers {
+    pub id: uuid::Uuid,
+    pub email: String,
+    pub username: String,
+    pub pwd: String,
+    pub img: String,
+    pub bio: String,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub deactivated_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub suspended_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+}
+pub struct ListBlockedUsersBorrowed<'a> {
+    pub id: uuid::Uuid,
+    pub email: &'a str,
+    pub username: &'a str,
+    pub pwd: &'a str,
+    pub img: &'a str,
+    pub bio: &'a str,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub deactivated_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub suspended_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+}
+impl<'a> From<ListBlockedUsersBorrowed<'a>> for ListBlockedUsers {
+    fn from(
+        ListBlockedUsersBorrowed {
+            id,
+            email,
+            username,
+            pwd,
+            img,
+            bio,
+            created_at,
+            updated_at,
+            deactivated_at,
+            suspended_at,
+        }: ListBlockedUsersBorrowed<'a>,
+    ) -> Self {
+        Self {
+            id,
+            email: email.into(),
+            username: username.into(),
+            pwd: pwd.into(),
+            img: img.into(),
+            bio: bio.into(),
+            created_at,
+            updated_at,
+            deactivated_at,
+            suspended_at,
+        }
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListMutedUsers {
+    pub id: uuid::Uuid,
+    pub email: String,
+    pub username: String,
+    pub pwd: String,
+    pub img: String,
+    pub bio: String,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub deactivated_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub suspended_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+}
+pub struct ListMutedUsersBorrowed<'a> {
+    pub id: uuid::Uuid,
+    pub email: &'a str,
+    pub username: &'a str,
+    pub pwd: &'a str,
+    pub img: &'a str,
+    pub bio: &'a str,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub deactivated_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub suspended_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+}
+impl<'a> From<ListMutedUsersBorrowed<'a>> for ListMutedUsers {
+    fn from(
+        ListMutedUsersBorrowed {
+            id,
+            email,
+            username,
+            pwd,
+            img,
+            bio,
+            created_at,
+            updated_at,
+            deactivated_at,
+            suspended_at,
+        }: ListMutedUsersBorrowed<'a>,
+    ) -> Self {
+        Self {
+            id,
+            email: email.into(),
+            username: username.into(),
+            pwd: pwd.into(),
+            img: img.into(),
+            bio: bio.into(),
+            created_at,
+            updated_at,
+            deactivated_at,
+            suspended_at,
+        }
+    }
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListDeactivatedUsers {
+    pub id: uuid::Uuid,
+    pub email: String,
+    pub username: String,
+    pub pwd: String,
+    pub img: String,
+    pub bio: String,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub deactivated_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub suspended_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+}
+pub struct ListDeactivatedUsersBorrowed<'a> {
+    pub id: uuid::Uuid,
+    pub email: &'a str,
+    pub username: &'a str,
+    pub pwd: &'a str,
+    pub img: &'a str,
+    pub bio: &'a str,
+    pub created_at: chrono::DateTime<chrono::FixedOffset>,
+    pub updated_at: chrono::DateTime<chrono::FixedOffset>,
+    pub deactivated_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+    pub suspended_at: Option<chrono::DateTime<chrono::FixedOffset>>,
+}
+impl<'a> From<ListDeactivatedUsersBorrowed<'a>> for ListDeactivatedUsers {
+    fn from(
+        ListDeactivatedUsersBorrowed {
+            id,
+            email,
+            username,
+            pwd,
+            img,
+            bio,
+            created_at,
+            updated_at,
+            deactivated_at,
+            suspended_at,
+        }: ListDeactivatedUsersBorrowed<'a>,
+    ) -> Self {
+        Self {
+            id,
+            email: email.into(),
+            username: username.into(),
+            pwd: pwd.into(),
+            img: img.into(),
+            bio: bio.into(),
+            created_at,
+            updated_at,
+            deactivated_at,
+            suspended_at,
         }
     }
 }
@@ -670,90 +971,1028 @@ where
         Ok(mapped)
     }
 }
-pub struct CreateUserStmt(&'static str, Option<tokio_postgres::Statement>);
-pub fn create_user() -> CreateUserStmt {
-    CreateUserStmt(
-        "INSERT INTO appuser (id, email, username, pwd, created_at, updated_at) VALUES ($1, $2, $3, $4, $5, $5) RETURNING *",
-        None,
-    )
+pub struct ListDeactivatedUsersQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor:
+        fn(&tokio_postgres::Row) -> Result<ListDeactivatedUsersBorrowed, tokio_postgres::Error>,
+    mapper: fn(ListDeactivatedUsersBorrowed) -> T,
 }
-impl CreateUserStmt {
-    pub async fn prepare<'a, C: GenericClient>(
-        mut self,
-        client: &'a C,
-    ) -> Result<Self, tokio_postgres::Error> {
-        self.1 = Some(client.prepare(self.0).await?);
-        Ok(self)
-    }
-    pub fn bind<
-        'c,
-        'a,
-        's,
-        C: GenericClient,
-        T1: crate::clorinde::StringSql,
-        T2: crate::clorinde::StringSql,
-        T3: crate::clorinde::StringSql,
-    >(
-        &'s self,
-        client: &'c C,
-        id: &'a uuid::Uuid,
-        email: &'a T1,
-        username: &'a T2,
-        pwd: &'a T3,
-        created_at: &'a chrono::DateTime<chrono::FixedOffset>,
-    ) -> CreateUserQuery<'c, 'a, 's, C, CreateUser, 5> {
-        CreateUserQuery {
-            client,
-            params: [id, email, username, pwd, created_at],
-            query: self.0,
-            cached: self.1.as_ref(),
-            extractor:
-                |row: &tokio_postgres::Row| -> Result<CreateUserBorrowed, tokio_postgres::Error> {
-                    Ok(CreateUserBorrowed {
-                        id: row.try_get(0)?,
-                        email: row.try_get(1)?,
-                        username: row.try_get(2)?,
-                        pwd: row.try_get(3)?,
-                        img: row.try_get(4)?,
-                        bio: row.try_get(5)?,
-                        created_at: row.try_get(6)?,
-                        updated_at: row.try_get(7)?,
-                    })
-                },
-            mapper: |it| CreateUser::from(it),
+impl<'c, 'a, 's, C, T: 'c, const N: usize> ListDeactivatedUsersQuery<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(
+        self,
+        mapper: fn(ListDeactivatedUsersBorrowed) -> R,
+    ) -> ListDeactivatedUsersQuery<'c, 'a, 's, C, R, N> {
+        ListDeactivatedUsersQuery {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
         }
     }
-}
-impl<'c, 'a, 's, C: GenericClient, T1: crate::clorinde::StringSql, T2: crate::clorinde::StringSql, T3: crate::clorinde::StringSql>
-    crate::clorinde::client::async_::Params<
-        'c,
-        'a,
-        's,
-        CreateUserParams<T1, T2, T3>,
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct ListFollowedUsersQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor: fn(&tokio_postgres::Row) -> Result<ListFollowedUsersBorrowed, tokio_postgres::Error>,
+    mapper: fn(ListFollowedUsersBorrowed) -> T,
+}
+impl<'c, 'a, 's, C, T: 'c, const N: usize> ListFollowedUsersQuery<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(
+        self,
+        mapper: fn(ListFollowedUsersBorrowed) -> R,
+    ) -> ListFollowedUsersQuery<'c, 'a, 's, C, R, N> {
+        ListFollowedUsersQuery {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
+        }
+    }
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct UuidUuidQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor: fn(&tokio_postgres::Row) -> Result<uuid::Uuid, tokio_postgres::Error>,
+    mapper: fn(uuid::Uuid) -> T,
+}
+impl<'c, 'a, 's, C, T: 'c, const N: usize> UuidUuidQuery<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(self, mapper: fn(uuid::Uuid) -> R) -> UuidUuidQuery<'c, 'a, 's, C, R, N> {
+        UuidUuidQuery {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
+        }
+    }
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct ListBlockedUsersQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor: fn(&tokio_postgres::Row) -> Result<ListBlockedUsersBorrowed, tokio_postgres::Error>,
+    mapper: fn(ListBlockedUsersBorrowed) -> T,
+}
+impl<'c, 'a, 's, C, T: 'c, const N: usize> ListBlockedUsersQuery<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(
+        self,
+        mapper: fn(ListBlockedUsersBorrowed) -> R,
+    ) -> ListBlockedUsersQuery<'c, 'a, 's, C, R, N> {
+        ListBlockedUsersQuery {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
+        }
+    }
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct ListMutedUsersQuery<'c, 'a, 's, C: GenericClient, T, const N: usize> {
+    client: &'c C,
+    params: [&'a (dyn postgres_types::ToSql + Sync); N],
+    query: &'static str,
+    cached: Option<&'s tokio_postgres::Statement>,
+    extractor: fn(&tokio_postgres::Row) -> Result<ListMutedUsersBorrowed, tokio_postgres::Error>,
+    mapper: fn(ListMutedUsersBorrowed) -> T,
+}
+impl<'c, 'a, 's, C, T: 'c, const N: usize> ListMutedUsersQuery<'c, 'a, 's, C, T, N>
+where
+    C: GenericClient,
+{
+    pub fn map<R>(
+        self,
+        mapper: fn(ListMutedUsersBorrowed) -> R,
+    ) -> ListMutedUsersQuery<'c, 'a, 's, C, R, N> {
+        ListMutedUsersQuery {
+            client: self.client,
+            params: self.params,
+            query: self.query,
+            cached: self.cached,
+            extractor: self.extractor,
+            mapper,
+        }
+    }
+    pub async fn one(self) -> Result<T, tokio_postgres::Error> {
+        let row =
+            crate::clorinde::client::async_::one(self.client, self.query, &self.params, self.cached).await?;
+        Ok((self.mapper)((self.extractor)(&row)?))
+    }
+    pub async fn all(self) -> Result<Vec<T>, tokio_postgres::Error> {
+        self.iter().await?.try_collect().await
+    }
+    pub async fn opt(self) -> Result<Option<T>, tokio_postgres::Error> {
+        let opt_row =
+            crate::clorinde::client::async_::opt(self.client, self.query, &self.params, self.cached).await?;
+        Ok(opt_row
+            .map(|row| {
+                let extracted = (self.extractor)(&row)?;
+                Ok((self.mapper)(extracted))
+            })
+            .transpose()?)
+    }
+    pub async fn iter(
+        self,
+    ) -> Result<
+        impl futures::Stream<Item = Result<T, tokio_postgres::Error>> + 'c,
+        tokio_postgres::Error,
+    > {
+        let stream = crate::clorinde::client::async_::raw(
+            self.client,
+            self.query,
+            crate::clorinde::slice_iter(&self.params),
+            self.cached,
+        )
+        .await?;
+        let mapped = stream
+            .map(move |res| {
+                res.and_then(|row| {
+                    let extracted = (self.extractor)(&row)?;
+                    Ok((self.mapper)(extracted))
+                })
+            })
+            .into_stream();
+        Ok(mapped)
+    }
+}
+pub struct CreateUserStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn create_user() -> CreateUserStmt {
+    CreateUserStmt(
+        "INSERT INTO appuser (id, email, username, pwd, created_at, updated_at) VALUES ($1, $2, $3, $4, $5, $5) RETURNING *",
+        None,
+    )
+}
+impl CreateUserStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub fn bind<
+        'c,
+        'a,
+        's,
+        C: GenericClient,
+        T1: crate::clorinde::StringSql,
+        T2: crate::clorinde::StringSql,
+        T3: crate::clorinde::StringSql,
+    >(
+        &'s self,
+        client: &'c C,
+        id: &'a uuid::Uuid,
+        email: &'a T1,
+        username: &'a T2,
+        pwd: &'a T3,
+        created_at: &'a chrono::DateTime<chrono::FixedOffset>,
+    ) -> CreateUserQuery<'c, 'a, 's, C, CreateUser, 5> {
+        CreateUserQuery {
+            client,
+            params: [id, email, username, pwd, created_at],
+            query: self.0,
+            cached: self.1.as_ref(),
+            extractor:
+                |row: &tokio_postgres::Row| -> Result<CreateUserBorrowed, tokio_postgres::Error> {
+                    Ok(CreateUserBorrowed {
+                        id: row.try_get(0)?,
+                        email: row.try_get(1)?,
+                        username: row.try_get(2)?,
+                        pwd: row.try_get(3)?,
+                        img: row.try_get(4)?,
+                        bio: row.try_get(5)?,
+                        created_at: row.try_get(6)?,
+                        updated_at: row.try_get(7)?,
+                        deactivated_at: row.try_get(8)?,
+                        suspended_at: row.try_get(9)?,
+                    })
+                },
+            mapper: |it| CreateUser::from(it),
+        }
+    }
+}
+impl<'c, 'a, 's, C: GenericClient, T1: crate::clorinde::StringSql, T2: crate::clorinde::StringSql, T3: crate::clorinde::StringSql>
+    crate::clorinde::client::async_::Params<
+        'c,
+        'a,
+        's,
+        CreateUserParams<T1, T2, T3>,
         CreateUserQuery<'c, 'a, 's, C, CreateUser, 5>,
         C,
-    > for CreateUserStmt
+    > for CreateUserStmt
+{
+    fn params(
+        &'s self,
+        client: &'c C,
+        params: &'a CreateUserParams<T1, T2, T3>,
+    ) -> CreateUserQuery<'c, 'a, 's, C, CreateUser, 5> {
+        self.bind(
+            client,
+            &params.id,
+            &params.email,
+            &params.username,
+            &params.pwd,
+            &params.created_at,
+        )
+    }
+}
+pub struct GetUserByEmailStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn get_user_by_email() -> GetUserByEmailStmt {
+    GetUserByEmailStmt("SELECT * FROM appuser WHERE email = $1", None)
+}
+impl GetUserByEmailStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub fn bind<'c, 'a, 's, C: GenericClient, T1: crate::clorinde::StringSql>(
+        &'s self,
+        client: &'c C,
+        email: &'a T1,
+    ) -> GetUserByEmailQuery<'c, 'a, 's, C, GetUserByEmail, 1> {
+        GetUserByEmailQuery { client, params: [email], query: self.0, cached: self.1.as_ref(), extractor: |row: &tokio_postgres::Row| -> Result<GetUserByEmailBorrowed, tokio_postgres::Error> { Ok(GetUserByEmailBorrowed { id: row.try_get(0)?,
+email: row.try_get(1)?,
+username: row.try_get(2)?,
+pwd: row.try_get(3)?,
+img: row.try_get(4)?,
+bio: row.try_get(5)?,
+created_at: row.try_get(6)?,
+updated_at: row.try_get(7)?,
+deactivated_at: row.try_get(8)?,
+suspended_at: row.try_get(9)?,
+ }) }, mapper: |it| GetUserByEmail::from(it), }
+    }
+}
+pub struct GetUserByUsernameStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn get_user_by_username() -> GetUserByUsernameStmt {
+    GetUserByUsernameStmt("SELECT * FROM appuser WHERE username = $1", None)
+}
+impl GetUserByUsernameStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub fn bind<'c, 'a, 's, C: GenericClient, T1: crate::clorinde::StringSql>(
+        &'s self,
+        client: &'c C,
+        username: &'a T1,
+    ) -> GetUserByUsernameQuery<'c, 'a, 's, C, GetUserByUsername, 1> {
+        GetUserByUsernameQuery { client, params: [username], query: self.0, cached: self.1.as_ref(), extractor: |row: &tokio_postgres::Row| -> Result<GetUserByUsernameBorrowed, tokio_postgres::Error> { Ok(GetUserByUsernameBorrowed { id: row.try_get(0)?,
+email: row.try_get(1)?,
+username: row.try_get(2)?,
+pwd: row.try_get(3)?,
+img: row.try_get(4)?,
+bio: row.try_get(5)?,
+created_at: row.try_get(6)?,
+updated_at: row.try_get(7)?,
+deactivated_at: row.try_get(8)?,
+suspended_at: row.try_get(9)?,
+ }) }, mapper: |it| GetUserByUsername::from(it), }
+    }
+}
+pub struct GetUserByIdStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn get_user_by_id() -> GetUserByIdStmt {
+    GetUserByIdStmt("SELECT * FROM appuser WHERE id = $1", None)
+}
+impl GetUserByIdStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        id: &'a uuid::Uuid,
+    ) -> GetUserByIdQuery<'c, 'a, 's, C, GetUserById, 1> {
+        GetUserByIdQuery {
+            client,
+            params: [id],
+            query: self.0,
+            cached: self.1.as_ref(),
+            extractor:
+                |row: &tokio_postgres::Row| -> Result<GetUserByIdBorrowed, tokio_postgres::Error> {
+                    Ok(GetUserByIdBorrowed {
+                        id: row.try_get(0)?,
+                        email: row.try_get(1)?,
+                        username: row.try_get(2)?,
+                        pwd: row.try_get(3)?,
+                        img: row.try_get(4)?,
+                        bio: row.try_get(5)?,
+                        created_at: row.try_get(6)?,
+                        updated_at: row.try_get(7)?,
+                        deactivated_at: row.try_get(8)?,
+                        suspended_at: row.try_get(9)?,
+                    })
+                },
+            mapper: |it| GetUserById::from(it),
+        }
+    }
+}
+pub struct UpdateUserStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn update_user() -> UpdateUserStmt {
+    UpdateUserStmt(
+        "UPDATE appuser SET email = COALESCE($1, email), username = COALESCE($2, username), pwd = COALESCE($3, pwd), img = COALESCE($4, ''), bio = COALESCE($5, bio), updated_at = $6 WHERE id = $7 RETURNING *",
+        None,
+    )
+}
+impl UpdateUserStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub fn bind<
+        'c,
+        'a,
+        's,
+        C: GenericClient,
+        T1: crate::clorinde::StringSql,
+        T2: crate::clorinde::StringSql,
+        T3: crate::clorinde::StringSql,
+        T4: crate::clorinde::StringSql,
+        T5: crate::clorinde::StringSql,
+    >(
+        &'s self,
+        client: &'c C,
+        email: &'a T1,
+        username: &'a T2,
+        pwd: &'a T3,
+        img: &'a T4,
+        bio: &'a T5,
+        updated_at: &'a chrono::DateTime<chrono::FixedOffset>,
+        id: &'a uuid::Uuid,
+    ) -> UpdateUserQuery<'c, 'a, 's, C, UpdateUser, 7> {
+        UpdateUserQuery {
+            client,
+            params: [email, username, pwd, img, bio, updated_at, id],
+            query: self.0,
+            cached: self.1.as_ref(),
+            extractor:
+                |row: &tokio_postgres::Row| -> Result<UpdateUserBorrowed, tokio_postgres::Error> {
+                    Ok(UpdateUserBorrowed {
+                        id: row.try_get(0)?,
+                        email: row.try_get(1)?,
+                        username: row.try_get(2)?,
+                        pwd: row.try_get(3)?,
+                        img: row.try_get(4)?,
+                        bio: row.try_get(5)?,
+                        created_at: row.try_get(6)?,
+                        updated_at: row.try_get(7)?,
+                        deactivated_at: row.try_get(8)?,
+                        suspended_at: row.try_get(9)?,
+                    })
+                },
+            mapper: |it| UpdateUser::from(it),
+        }
+    }
+}
+impl<
+    'c,
+    'a,
+    's,
+    C: GenericClient,
+    T1: crate::clorinde::StringSql,
+    T2: crate::clorinde::StringSql,
+    T3: crate::clorinde::StringSql,
+    T4: crate::clorinde::StringSql,
+    T5: crate::clorinde::StringSql,
+>
+    crate::clorinde::client::async_::Params<
+        'c,
+        'a,
+        's,
+        UpdateUserParams<T1, T2, T3, T4, T5>,
+        UpdateUserQuery<'c, 'a, 's, C, UpdateUser, 7>,
+        C,
+    > for UpdateUserStmt
+{
+    fn params(
+        &'s self,
+        client: &'c C,
+        params: &'a UpdateUserParams<T1, T2, T3, T4, T5>,
+    ) -> UpdateUserQuery<'c, 'a, 's, C, UpdateUser, 7> {
+        self.bind(
+            client,
+            &params.email,
+            &params.username,
+            &params.pwd,
+            &params.img,
+            &params.bio,
+            &params.updated_at,
+            &params.id,
+        )
+    }
+}
+pub struct FollowUserStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn follow_user() -> FollowUserStmt {
+    FollowUserStmt(
+        "INSERT INTO appuser_follows (follower_id, followee_id) VALUES ($1, $2) ON CONFLICT DO NOTHING",
+        None,
+    )
+}
+impl FollowUserStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub async fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        follower_id: &'a uuid::Uuid,
+        followee_id: &'a uuid::Uuid,
+    ) -> Result<u64, tokio_postgres::Error> {
+        client.execute(self.0, &[follower_id, followee_id]).await
+    }
+}
+impl<'a, C: GenericClient + Send + Sync>
+    crate::clorinde::client::async_::Params<
+        'a,
+        'a,
+        'a,
+        FollowUserParams,
+        std::pin::Pin<
+            Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+        >,
+        C,
+    > for FollowUserStmt
+{
+    fn params(
+        &'a self,
+        client: &'a C,
+        params: &'a FollowUserParams,
+    ) -> std::pin::Pin<
+        Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+    > {
+        Box::pin(self.bind(client, &params.follower_id, &params.followee_id))
+    }
+}
+pub struct UnfollowUserStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn unfollow_user() -> UnfollowUserStmt {
+    UnfollowUserStmt(
+        "DELETE FROM appuser_follows WHERE follower_id = $1 AND followee_id = $2",
+        None,
+    )
+}
+impl UnfollowUserStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub async fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        follower_id: &'a uuid::Uuid,
+        followee_id: &'a uuid::Uuid,
+    ) -> Result<u64, tokio_postgres::Error> {
+        client.execute(self.0, &[follower_id, followee_id]).await
+    }
+}
+impl<'a, C: GenericClient + Send + Sync>
+    crate::clorinde::client::async_::Params<
+        'a,
+        'a,
+        'a,
+        UnfollowUserParams,
+        std::pin::Pin<
+            Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+        >,
+        C,
+    > for UnfollowUserStmt
 {
     fn params(
+        &'a self,
+        client: &'a C,
+        params: &'a UnfollowUserParams,
+    ) -> std::pin::Pin<
+        Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+    > {
+        Box::pin(self.bind(client, &params.follower_id, &params.followee_id))
+    }
+}
+pub struct IsFollowingStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn is_following() -> IsFollowingStmt {
+    IsFollowingStmt(
+        "SELECT EXISTS( SELECT 1 FROM appuser_follows WHERE follower_id = $1 AND followee_id = $2 )",
+        None,
+    )
+}
+impl IsFollowingStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub fn bind<'c, 'a, 's, C: GenericClient>(
         &'s self,
         client: &'c C,
-        params: &'a CreateUserParams<T1, T2, T3>,
-    ) -> CreateUserQuery<'c, 'a, 's, C, CreateUser, 5> {
-        self.bind(
+        follower_id: &'a uuid::Uuid,
+        followee_id: &'a uuid::Uuid,
+    ) -> BoolQuery<'c, 'a, 's, C, bool, 2> {
+        BoolQuery {
             client,
-            &params.id,
-            &params.email,
-            &params.username,
-            &params.pwd,
-            &params.created_at,
-        )
+            params: [follower_id, followee_id],
+            query: self.0,
+            cached: self.1.as_ref(),
+            extractor: |row| Ok(row.try_get(0)?),
+            mapper: |it| it,
+        }
+    }
+}
+impl<'c, 'a, 's, C: GenericClient>
+    crate::clorinde::client::async_::Params<
+        'c,
+        'a,
+        's,
+        IsFollowingParams,
+        BoolQuery<'c, 'a, 's, C, bool, 2>,
+        C,
+    > for IsFollowingStmt
+{
+    fn params(
+        &'s self,
+        client: &'c C,
+        params: &'a IsFollowingParams,
+    ) -> BoolQuery<'c, 'a, 's, C, bool, 2> {
+        self.bind(client, &params.follower_id, &params.followee_id)
+    }
+}
+pub struct ListFollowedUsersStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn list_followed_users() -> ListFollowedUsersStmt {
+    ListFollowedUsersStmt(
+        "SELECT u.* FROM appuser u JOIN appuser_follows f ON f.followee_id = u.id WHERE f.follower_id = $1 ORDER BY u.username",
+        None,
+    )
+}
+impl ListFollowedUsersStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        follower_id: &'a uuid::Uuid,
+    ) -> ListFollowedUsersQuery<'c, 'a, 's, C, ListFollowedUsers, 1> {
+        ListFollowedUsersQuery { client, params: [follower_id], query: self.0, cached: self.1.as_ref(), extractor: |row: &tokio_postgres::Row| -> Result<ListFollowedUsersBorrowed, tokio_postgres::Error> { Ok(ListFollowedUsersBorrowed { id: row.try_get(0)?,
+email: row.try_get(1)?,
+username: row.try_get(2)?,
+pwd: row.try_get(3)?,
+img: row.try_get(4)?,
+bio: row.try_get(5)?,
+created_at: row.try_get(6)?,
+updated_at: row.try_get(7)?,
+deactivated_at: row.try_get(8)?,
+suspended_at: row.try_get(9)?,
+ }) }, mapper: |it| ListFollowedUsers::from(it), }
+    }
+}
+pub struct ListBlockedUsersStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn list_blocked_users() -> ListBlockedUsersStmt {
+    ListBlockedUsersStmt(
+        "SELECT u.* FROM appuser u JOIN appuser_blocks b ON b.blocked_id = u.id WHERE b.blocker_id = $1 ORDER BY u.username",
+        None,
+    )
+}
+impl ListBlockedUsersStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        blocker_id: &'a uuid::Uuid,
+    ) -> ListBlockedUsersQuery<'c, 'a, 's, C, ListBlockedUsers, 1> {
+        ListBlockedUsersQuery { client, params: [blocker_id], query: self.0, cached: self.1.as_ref(), extractor: |row: &tokio_postgres::Row| -> Result<ListBlockedUsersBorrowed, tokio_postgres::Error> { Ok(ListBlockedUsersBorrowed { id: row.try_get(0)?,
+email: row.try_get(1)?,
+username: row.try_get(2)?,
+pwd: row.try_get(3)?,
+img: row.try_get(4)?,
+bio: row.try_get(5)?,
+created_at: row.try_get(6)?,
+updated_at: row.try_get(7)?,
+deactivated_at: row.try_get(8)?,
+suspended_at: row.try_get(9)?,
+ }) }, mapper: |it| ListBlockedUsers::from(it), }
+    }
+}
+pub struct ListMutedUsersStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn list_muted_users() -> ListMutedUsersStmt {
+    ListMutedUsersStmt(
+        "SELECT u.* FROM appuser u JOIN appuser_mutes m ON m.muted_id = u.id WHERE m.muter_id = $1 ORDER BY u.username",
+        None,
+    )
+}
+impl ListMutedUsersStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        muter_id: &'a uuid::Uuid,
+    ) -> ListMutedUsersQuery<'c, 'a, 's, C, ListMutedUsers, 1> {
+        ListMutedUsersQuery { client, params: [muter_id], query: self.0, cached: self.1.as_ref(), extractor: |row: &tokio_postgres::Row| -> Result<ListMutedUsersBorrowed, tokio_postgres::Error> { Ok(ListMutedUsersBorrowed { id: row.try_get(0)?,
+email: row.try_get(1)?,
+username: row.try_get(2)?,
+pwd: row.try_get(3)?,
+img: row.try_get(4)?,
+bio: row.try_get(5)?,
+created_at: row.try_get(6)?,
+updated_at: row.try_get(7)?,
+deactivated_at: row.try_get(8)?,
+suspended_at: row.try_get(9)?,
+ }) }, mapper: |it| ListMutedUsers::from(it), }
+    }
+}
+pub struct DeactivateUserStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn deactivate_user() -> DeactivateUserStmt {
+    DeactivateUserStmt("UPDATE appuser SET deactivated_at = $1 WHERE id = $2", None)
+}
+impl DeactivateUserStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub async fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        deactivated_at: &'a chrono::DateTime<chrono::FixedOffset>,
+        id: &'a uuid::Uuid,
+    ) -> Result<u64, tokio_postgres::Error> {
+        client.execute(self.0, &[deactivated_at, id]).await
+    }
+}
+impl<'a, C: GenericClient + Send + Sync>
+    crate::clorinde::client::async_::Params<
+        'a,
+        'a,
+        'a,
+        DeactivateUserParams,
+        std::pin::Pin<
+            Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+        >,
+        C,
+    > for DeactivateUserStmt
+{
+    fn params(
+        &'a self,
+        client: &'a C,
+        params: &'a DeactivateUserParams,
+    ) -> std::pin::Pin<
+        Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+    > {
+        Box::pin(self.bind(client, &params.deactivated_at, &params.id))
+    }
+}
+pub struct ReactivateUserStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn reactivate_user() -> ReactivateUserStmt {
+    ReactivateUserStmt(
+        "UPDATE appuser SET deactivated_at = NULL WHERE id = $1",
+        None,
+    )
+}
+impl ReactivateUserStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub async fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        id: &'a uuid::Uuid,
+    ) -> Result<u64, tokio_postgres::Error> {
+        client.execute(self.0, &[id]).await
+    }
+}
+pub struct SuspendUserStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn suspend_user() -> SuspendUserStmt {
+    SuspendUserStmt("UPDATE appuser SET suspended_at = $1 WHERE id = $2", None)
+}
+impl SuspendUserStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub async fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        suspended_at: &'a chrono::DateTime<chrono::FixedOffset>,
+        id: &'a uuid::Uuid,
+    ) -> Result<u64, tokio_postgres::Error> {
+        client.execute(self.0, &[suspended_at, id]).await
+    }
+}
+impl<'a, C: GenericClient + Send + Sync>
+    crate::clorinde::client::async_::Params<
+        'a,
+        'a,
+        'a,
+        SuspendUserParams,
+        std::pin::Pin<
+            Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+        >,
+        C,
+    > for SuspendUserStmt
+{
+    fn params(
+        &'a self,
+        client: &'a C,
+        params: &'a SuspendUserParams,
+    ) -> std::pin::Pin<
+        Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+    > {
+        Box::pin(self.bind(client, &params.suspended_at, &params.id))
+    }
+}
+pub struct ListDeactivatedUsersStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn list_deactivated_users() -> ListDeactivatedUsersStmt {
+    ListDeactivatedUsersStmt(
+        "SELECT * FROM appuser WHERE deactivated_at IS NOT NULL AND deactivated_at <= $1 ORDER BY deactivated_at",
+        None,
+    )
+}
+impl ListDeactivatedUsersStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        cutoff: &'a chrono::DateTime<chrono::FixedOffset>,
+    ) -> ListDeactivatedUsersQuery<'c, 'a, 's, C, ListDeactivatedUsers, 1> {
+        ListDeactivatedUsersQuery { client, params: [cutoff], query: self.0, cached: self.1.as_ref(), extractor: |row: &tokio_postgres::Row| -> Result<ListDeactivatedUsersBorrowed, tokio_postgres::Error> { Ok(ListDeactivatedUsersBorrowed { id: row.try_get(0)?,
+email: row.try_get(1)?,
+username: row.try_get(2)?,
+pwd: row.try_get(3)?,
+img: row.try_get(4)?,
+bio: row.try_get(5)?,
+created_at: row.try_get(6)?,
+updated_at: row.try_get(7)?,
+deactivated_at: row.try_get(8)?,
+suspended_at: row.try_get(9)?,
+ }) }, mapper: |it| ListDeactivatedUsers::from(it), }
+    }
+}
+pub struct DeleteUserStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn delete_user() -> DeleteUserStmt {
+    DeleteUserStmt("DELETE FROM appuser WHERE id = $1", None)
+}
+impl DeleteUserStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub async fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        id: &'a uuid::Uuid,
+    ) -> Result<u64, tokio_postgres::Error> {
+        client.execute(self.0, &[id]).await
     }
 }
-pub struct GetUserByEmailStmt(&'static str, Option<tokio_postgres::Statement>);
-pub fn get_user_by_email() -> GetUserByEmailStmt {
-    GetUserByEmailStmt("SELECT * FROM appuser WHERE email = $1", None)
+pub struct BlockUserStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn block_user() -> BlockUserStmt {
+    BlockUserStmt(
+        "INSERT INTO appuser_blocks (blocker_id, blocked_id) VALUES ($1, $2) ON CONFLICT DO NOTHING",
+        None,
+    )
 }
-impl GetUserByEmailStmt {
+impl BlockUserStmt {
     pub async fn prepare<'a, C: GenericClient>(
         mut self,
         client: &'a C,
@@ -761,39 +2000,45 @@ impl GetUserByEmailStmt {
         self.1 = Some(client.prepare(self.0).await?);
         Ok(self)
     }
-    pub fn bind<'c, 'a, 's, C: GenericClient, T1: crate::clorinde::StringSql>(
+    pub async fn bind<'c, 'a, 's, C: GenericClient>(
         &'s self,
         client: &'c C,
-        email: &'a T1,
-    ) -> GetUserByEmailQuery<'c, 'a, 's, C, GetUserByEmail, 1> {
-        GetUserByEmailQuery {
-            client,
-            params: [email],
-            query: self.0,
-            cached: self.1.as_ref(),
-            extractor: |
-                row: &tokio_postgres::Row,
-            | -> Result<GetUserByEmailBorrowed, tokio_postgres::Error> {
-                Ok(GetUserByEmailBorrowed {
-                    id: row.try_get(0)?,
-                    email: row.try_get(1)?,
-                    username: row.try_get(2)?,
-                    pwd: row.try_get(3)?,
-                    img: row.try_get(4)?,
-                    bio: row.try_get(5)?,
-                    created_at: row.try_get(6)?,
-                    updated_at: row.try_get(7)?,
-                })
-            },
-            mapper: |it| GetUserByEmail::from(it),
-        }
+        blocker_id: &'a uuid::Uuid,
+        blocked_id: &'a uuid::Uuid,
+    ) -> Result<u64, tokio_postgres::Error> {
+        client.execute(self.0, &[blocker_id, blocked_id]).await
     }
 }
-pub struct GetUserByUsernameStmt(&'static str, Option<tokio_postgres::Statement>);
-pub fn get_user_by_username() -> GetUserByUsernameStmt {
-    GetUserByUsernameStmt("SELECT * FROM appuser WHERE username = $1", None)
+impl<'a, C: GenericClient + Send + Sync>
+    crate::clorinde::client::async_::Params<
+        'a,
+        'a,
+        'a,
+        BlockUserParams,
+        std::pin::Pin<
+            Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+        >,
+        C,
+    > for BlockUserStmt
+{
+    fn params(
+        &'a self,
+        client: &'a C,
+        params: &'a BlockUserParams,
+    ) -> std::pin::Pin<
+        Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+    > {
+        Box::pin(self.bind(client, &params.blocker_id, &params.blocked_id))
+    }
 }
-impl GetUserByUsernameStmt {
+pub struct UnfollowBothWaysStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn unfollow_both_ways() -> UnfollowBothWaysStmt {
+    UnfollowBothWaysStmt(
+        "DELETE FROM appuser_follows WHERE (follower_id = $1 AND followee_id = $2) OR (follower_id = $2 AND followee_id = $1)",
+        None,
+    )
+}
+impl UnfollowBothWaysStmt {
     pub async fn prepare<'a, C: GenericClient>(
         mut self,
         client: &'a C,
@@ -801,39 +2046,45 @@ impl GetUserByUsernameStmt {
         self.1 = Some(client.prepare(self.0).await?);
         Ok(self)
     }
-    pub fn bind<'c, 'a, 's, C: GenericClient, T1: crate::clorinde::StringSql>(
+    pub async fn bind<'c, 'a, 's, C: GenericClient>(
         &'s self,
         client: &'c C,
-        username: &'a T1,
-    ) -> GetUserByUsernameQuery<'c, 'a, 's, C, GetUserByUsername, 1> {
-        GetUserByUsernameQuery {
-            client,
-            params: [username],
-            query: self.0,
-            cached: self.1.as_ref(),
-            extractor: |
-                row: &tokio_postgres::Row,
-            | -> Result<GetUserByUsernameBorrowed, tokio_postgres::Error> {
-                Ok(GetUserByUsernameBorrowed {
-                    id: row.try_get(0)?,
-                    email: row.try_get(1)?,
-                    username: row.try_get(2)?,
-                    pwd: row.try_get(3)?,
-                    img: row.try_get(4)?,
-                    bio: row.try_get(5)?,
-                    created_at: row.try_get(6)?,
-                    updated_at: row.try_get(7)?,
-                })
-            },
-            mapper: |it| GetUserByUsername::from(it),
-        }
+        user_id: &'a uuid::Uuid,
+        other_id: &'a uuid::Uuid,
+    ) -> Result<u64, tokio_postgres::Error> {
+        client.execute(self.0, &[user_id, other_id]).await
     }
 }
-pub struct GetUserByIdStmt(&'static str, Option<tokio_postgres::Statement>);
-pub fn get_user_by_id() -> GetUserByIdStmt {
-    GetUserByIdStmt("SELECT * FROM appuser WHERE id = $1", None)
+impl<'a, C: GenericClient + Send + Sync>
+    crate::clorinde::client::async_::Params<
+        'a,
+        'a,
+        'a,
+        UnfollowBothWaysParams,
+        std::pin::Pin<
+            Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+        >,
+        C,
+    > for UnfollowBothWaysStmt
+{
+    fn params(
+        &'a self,
+        client: &'a C,
+        params: &'a UnfollowBothWaysParams,
+    ) -> std::pin::Pin<
+        Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+    > {
+        Box::pin(self.bind(client, &params.user_id, &params.other_id))
+    }
 }
-impl GetUserByIdStmt {
+pub struct UnblockUserStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn unblock_user() -> UnblockUserStmt {
+    UnblockUserStmt(
+        "DELETE FROM appuser_blocks WHERE blocker_id = $1 AND blocked_id = $2",
+        None,
+    )
+}
+impl UnblockUserStmt {
     pub async fn prepare<'a, C: GenericClient>(
         mut self,
         client: &'a C,
@@ -841,41 +2092,45 @@ impl GetUserByIdStmt {
         self.1 = Some(client.prepare(self.0).await?);
         Ok(self)
     }
-    pub fn bind<'c, 'a, 's, C: GenericClient>(
+    pub async fn bind<'c, 'a, 's, C: GenericClient>(
         &'s self,
         client: &'c C,
-        id: &'a uuid::Uuid,
-    ) -> GetUserByIdQuery<'c, 'a, 's, C, GetUserById, 1> {
-        GetUserByIdQuery {
-            client,
-            params: [id],
-            query: self.0,
-            cached: self.1.as_ref(),
-            extractor:
-                |row: &tokio_postgres::Row| -> Result<GetUserByIdBorrowed, tokio_postgres::Error> {
-                    Ok(GetUserByIdBorrowed {
-                        id: row.try_get(0)?,
-                        email: row.try_get(1)?,
-                        username: row.try_get(2)?,
-                        pwd: row.try_get(3)?,
-                        img: row.try_get(4)?,
-                        bio: row.try_get(5)?,
-                        created_at: row.try_get(6)?,
-                        updated_at: row.try_get(7)?,
-                    })
-                },
-            mapper: |it| GetUserById::from(it),
-        }
+        blocker_id: &'a uuid::Uuid,
+        blocked_id: &'a uuid::Uuid,
+    ) -> Result<u64, tokio_postgres::Error> {
+        client.execute(self.0, &[blocker_id, blocked_id]).await
     }
 }
-pub struct UpdateUserStmt(&'static str, Option<tokio_postgres::Statement>);
-pub fn update_user() -> UpdateUserStmt {
-    UpdateUserStmt(
-        "UPDATE appuser SET email = COALESCE($1, email), username = COALESCE($2, username), pwd = COALESCE($3, pwd), img = COALESCE($4, img), bio = COALESCE($5, bio), updated_at = $6 WHERE id = $7 RETURNING *",
+impl<'a, C: GenericClient + Send + Sync>
+    crate::clorinde::client::async_::Params<
+        'a,
+        'a,
+        'a,
+        UnblockUserParams,
+        std::pin::Pin<
+            Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+        >,
+        C,
+    > for UnblockUserStmt
+{
+    fn params(
+        &'a self,
+        client: &'a C,
+        params: &'a UnblockUserParams,
+    ) -> std::pin::Pin<
+        Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
+    > {
+        Box::pin(self.bind(client, &params.blocker_id, &params.blocked_id))
+    }
+}
+pub struct IsBlockingStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn is_blocking() -> IsBlockingStmt {
+    IsBlockingStmt(
+        "SELECT EXISTS( SELECT 1 FROM appuser_blocks WHERE blocker_id = $1 AND blocked_id = $2 )",
         None,
     )
 }
-impl UpdateUserStmt {
+impl IsBlockingStmt {
     pub async fn prepare<'a, C: GenericClient>(
         mut self,
         client: &'a C,
@@ -883,94 +2138,48 @@ impl UpdateUserStmt {
         self.1 = Some(client.prepare(self.0).await?);
         Ok(self)
     }
-    pub fn bind<
-        'c,
-        'a,
-        's,
-        C: GenericClient,
-        T1: crate::clorinde::StringSql,
-        T2: crate::clorinde::StringSql,
-        T3: crate::clorinde::StringSql,
-        T4: crate::clorinde::StringSql,
-        T5: crate::clorinde::StringSql,
-    >(
+    pub fn bind<'c, 'a, 's, C: GenericClient>(
         &'s self,
         client: &'c C,
-        email: &'a T1,
-        username: &'a T2,
-        pwd: &'a T3,
-        img: &'a T4,
-        bio: &'a T5,
-        updated_at: &'a chrono::DateTime<chrono::FixedOffset>,
-        id: &'a uuid::Uuid,
-    ) -> UpdateUserQuery<'c, 'a, 's, C, UpdateUser, 7> {
-        UpdateUserQuery {
+        blocker_id: &'a uuid::Uuid,
+        blocked_id: &'a uuid::Uuid,
+    ) -> BoolQuery<'c, 'a, 's, C, bool, 2> {
+        BoolQuery {
             client,
-            params: [email, username, pwd, img, bio, updated_at, id],
+            params: [blocker_id, blocked_id],
             query: self.0,
             cached: self.1.as_ref(),
-            extractor:
-                |row: &tokio_postgres::Row| -> Result<UpdateUserBorrowed, tokio_postgres::Error> {
-                    Ok(UpdateUserBorrowed {
-                        id: row.try_get(0)?,
-                        email: row.try_get(1)?,
-                        username: row.try_get(2)?,
-                        pwd: row.try_get(3)?,
-                        img: row.try_get(4)?,
-                        bio: row.try_get(5)?,
-                        created_at: row.try_get(6)?,
-                        updated_at: row.try_get(7)?,
-                    })
-                },
-            mapper: |it| UpdateUser::from(it),
+            extractor: |row| Ok(row.try_get(0)?),
+            mapper: |it| it,
         }
     }
 }
-impl<
-    'c,
-    'a,
-    's,
-    C: GenericClient,
-    T1: crate::clorinde::StringSql,
-    T2: crate::clorinde::StringSql,
-    T3: crate::clorinde::StringSql,
-    T4: crate::clorinde::StringSql,
-    T5: crate::clorinde::StringSql,
->
+impl<'c, 'a, 's, C: GenericClient>
     crate::clorinde::client::async_::Params<
         'c,
         'a,
         's,
-        UpdateUserParams<T1, T2, T3, T4, T5>,
-        UpdateUserQuery<'c, 'a, 's, C, UpdateUser, 7>,
+        IsBlockingParams,
+        BoolQuery<'c, 'a, 's, C, bool, 2>,
         C,
-    > for UpdateUserStmt
+    > for IsBlockingStmt
 {
     fn params(
         &'s self,
         client: &'c C,
-        params: &'a UpdateUserParams<T1, T2, T3, T4, T5>,
-    ) -> UpdateUserQuery<'c, 'a, 's, C, UpdateUser, 7> {
-        self.bind(
-            client,
-            &params.email,
-            &params.username,
-            &params.pwd,
-            &params.img,
-            &params.bio,
-            &params.updated_at,
-            &params.id,
-        )
+        params: &'a IsBlockingParams,
+    ) -> BoolQuery<'c, 'a, 's, C, bool, 2> {
+        self.bind(client, &params.blocker_id, &params.blocked_id)
     }
 }
-pub struct FollowUserStmt(&'static str, Option<tokio_postgres::Statement>);
-pub fn follow_user() -> FollowUserStmt {
-    FollowUserStmt(
-        "INSERT INTO appuser_follows (follower_id, followee_id) VALUES ($1, $2) ON CONFLICT DO NOTHING",
+pub struct MuteUserStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn mute_user() -> MuteUserStmt {
+    MuteUserStmt(
+        "INSERT INTO appuser_mutes (muter_id, muted_id) VALUES ($1, $2) ON CONFLICT DO NOTHING",
         None,
     )
 }
-impl FollowUserStmt {
+impl MuteUserStmt {
     pub async fn prepare<'a, C: GenericClient>(
         mut self,
         client: &'a C,
@@ -981,10 +2190,10 @@ impl FollowUserStmt {
     pub async fn bind<'c, 'a, 's, C: GenericClient>(
         &'s self,
         client: &'c C,
-        follower_id: &'a uuid::Uuid,
-        followee_id: &'a uuid::Uuid,
+        muter_id: &'a uuid::Uuid,
+        muted_id: &'a uuid::Uuid,
     ) -> Result<u64, tokio_postgres::Error> {
-        client.execute(self.0, &[follower_id, followee_id]).await
+        client.execute(self.0, &[muter_id, muted_id]).await
     }
 }
 impl<'a, C: GenericClient + Send + Sync>
@@ -992,31 +2201,31 @@ impl<'a, C: GenericClient + Send + Sync>
         'a,
         'a,
         'a,
-        FollowUserParams,
+        MuteUserParams,
         std::pin::Pin<
             Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
         >,
         C,
-    > for FollowUserStmt
+    > for MuteUserStmt
 {
     fn params(
         &'a self,
         client: &'a C,
-        params: &'a FollowUserParams,
+        params: &'a MuteUserParams,
     ) -> std::pin::Pin<
         Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
     > {
-        Box::pin(self.bind(client, &params.follower_id, &params.followee_id))
+        Box::pin(self.bind(client, &params.muter_id, &params.muted_id))
     }
 }
-pub struct UnfollowUserStmt(&'static str, Option<tokio_postgres::Statement>);
-pub fn unfollow_user() -> UnfollowUserStmt {
-    UnfollowUserStmt(
-        "DELETE FROM appuser_follows WHERE follower_id = $1 AND followee_id = $2",
+pub struct UnmuteUserStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn unmute_user() -> UnmuteUserStmt {
+    UnmuteUserStmt(
+        "DELETE FROM appuser_mutes WHERE muter_id = $1 AND muted_id = $2",
         None,
     )
 }
-impl UnfollowUserStmt {
+impl UnmuteUserStmt {
     pub async fn prepare<'a, C: GenericClient>(
         mut self,
         client: &'a C,
@@ -1027,10 +2236,10 @@ impl UnfollowUserStmt {
     pub async fn bind<'c, 'a, 's, C: GenericClient>(
         &'s self,
         client: &'c C,
-        follower_id: &'a uuid::Uuid,
-        followee_id: &'a uuid::Uuid,
+        muter_id: &'a uuid::Uuid,
+        muted_id: &'a uuid::Uuid,
     ) -> Result<u64, tokio_postgres::Error> {
-        client.execute(self.0, &[follower_id, followee_id]).await
+        client.execute(self.0, &[muter_id, muted_id]).await
     }
 }
 impl<'a, C: GenericClient + Send + Sync>
@@ -1038,31 +2247,31 @@ impl<'a, C: GenericClient + Send + Sync>
         'a,
         'a,
         'a,
-        UnfollowUserParams,
+        UnmuteUserParams,
         std::pin::Pin<
             Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
         >,
         C,
-    > for UnfollowUserStmt
+    > for UnmuteUserStmt
 {
     fn params(
         &'a self,
         client: &'a C,
-        params: &'a UnfollowUserParams,
+        params: &'a UnmuteUserParams,
     ) -> std::pin::Pin<
         Box<dyn futures::Future<Output = Result<u64, tokio_postgres::Error>> + Send + 'a>,
     > {
-        Box::pin(self.bind(client, &params.follower_id, &params.followee_id))
+        Box::pin(self.bind(client, &params.muter_id, &params.muted_id))
     }
 }
-pub struct IsFollowingStmt(&'static str, Option<tokio_postgres::Statement>);
-pub fn is_following() -> IsFollowingStmt {
-    IsFollowingStmt(
-        "SELECT EXISTS( SELECT 1 FROM appuser_follows WHERE follower_id = $1 AND followee_id = $2 )",
+pub struct IsMutingStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn is_muting() -> IsMutingStmt {
+    IsMutingStmt(
+        "SELECT EXISTS( SELECT 1 FROM appuser_mutes WHERE muter_id = $1 AND muted_id = $2 )",
         None,
     )
 }
-impl IsFollowingStmt {
+impl IsMutingStmt {
     pub async fn prepare<'a, C: GenericClient>(
         mut self,
         client: &'a C,
@@ -1073,12 +2282,12 @@ impl IsFollowingStmt {
     pub fn bind<'c, 'a, 's, C: GenericClient>(
         &'s self,
         client: &'c C,
-        follower_id: &'a uuid::Uuid,
-        followee_id: &'a uuid::Uuid,
+        muter_id: &'a uuid::Uuid,
+        muted_id: &'a uuid::Uuid,
     ) -> BoolQuery<'c, 'a, 's, C, bool, 2> {
         BoolQuery {
             client,
-            params: [follower_id, followee_id],
+            params: [muter_id, muted_id],
             query: self.0,
             cached: self.1.as_ref(),
             extractor: |row| Ok(row.try_get(0)?),
@@ -1087,20 +2296,44 @@ impl IsFollowingStmt {
     }
 }
 impl<'c, 'a, 's, C: GenericClient>
-    crate::clorinde::client::async_::Params<
-        'c,
-        'a,
-        's,
-        IsFollowingParams,
-        BoolQuery<'c, 'a, 's, C, bool, 2>,
-        C,
-    > for IsFollowingStmt
+    crate::clorinde::client::async_::Params<'c, 'a, 's, IsMutingParams, BoolQuery<'c, 'a, 's, C, bool, 2>, C>
+    for IsMutingStmt
 {
     fn params(
         &'s self,
         client: &'c C,
-        params: &'a IsFollowingParams,
+        params: &'a IsMutingParams,
     ) -> BoolQuery<'c, 'a, 's, C, bool, 2> {
-        self.bind(client, &params.follower_id, &params.followee_id)
+        self.bind(client, &params.muter_id, &params.muted_id)
+    }
+}
+pub struct ListHiddenAuthorsStmt(&'static str, Option<tokio_postgres::Statement>);
+pub fn list_hidden_authors() -> ListHiddenAuthorsStmt {
+    ListHiddenAuthorsStmt(
+        "SELECT muted_id FROM appuser_mutes WHERE muter_id = $1 UNION SELECT blocker_id FROM appuser_blocks WHERE blocked_id = $1",
+        None,
+    )
+}
+impl ListHiddenAuthorsStmt {
+    pub async fn prepare<'a, C: GenericClient>(
+        mut self,
+        client: &'a C,
+    ) -> Result<Self, tokio_postgres::Error> {
+        self.1 = Some(client.prepare(self.0).await?);
+        Ok(self)
+    }
+    pub fn bind<'c, 'a, 's, C: GenericClient>(
+        &'s self,
+        client: &'c C,
+        viewer_id: &'a uuid::Uuid,
+    ) -> UuidUuidQuery<'c, 'a, 's, C, uuid::Uuid, 1> {
+        UuidUuidQuery {
+            client,
+            params: [viewer_id],
+            query: self.0,
+            cached: self.1.as_ref(),
+            extractor: |row| Ok(row.try_get(0)?),
+            mapper: |it| it,
+        }
     }
 }
@@ -1,6 +1,11 @@
+pub mod blobs;
 pub mod repositories;
 
 // This module will be generated by clorinde
 pub mod clorinde;
 
-pub use repositories::{PostgresArticlesRepository, PostgresCommentsRepository, PostgresUsersRepository};
+pub use blobs::{LocalBlobStore, S3BlobStore};
+pub use repositories::{
+    PostgresArticlesRepository, PostgresCommentsRepository, PostgresReportsRepository,
+    PostgresRevisionsRepository, PostgresSeriesRepository, PostgresTagsRepository, PostgresUsersRepository,
+};
@@ -1,8 +1,18 @@
+use std::collections::HashMap;
+
 use async_trait::async_trait;
 use deadpool_postgres::Pool;
+
+use crate::clorinde::client::async_::GenericClient;
 use domain::{
-    repositories::{ArticlesRepository, CommentsRepository, UsersRepository},
-    Article, ArticleFilters, ArticleId, ArticlesEnvelope, Comment, CommentId, FeedFilters, User,
+    repositories::{
+        ArticlesRepository, CommentsRepository, PartOfAnotherSeries, ReportsRepository, RevisionsRepository,
+        SeriesRepository, SlugTaken, TagsRepository, UsersRepository,
+    },
+    Article, ArticleFilters, ArticleId, ArticleRevision, ArticleSort, ArticleStatus, ArticleSummary,
+    ArticlesEnvelope, AuthoredContentPolicy, Bookmark, BookmarkFilters, BookmarksEnvelope, Coauthor, Comment, CommentId, CursorDirection, CursorPage, FeedFilters, Pagination,
+    ModerationAction, ModerationRecord, ReactionCount, ReactionKind, RelatedScoring, Report, ReportId, ReportReason,
+    ReportStatus, ReportTarget, RevisionId, SearchFilters, Series, SeriesId, SeriesKind, SitemapEntry, SitemapLocation, SearchQuery, SearchResultsEnvelope, SearchTerm, Tag, TagCount, TagList, TagMatch, Trashed, User,
     UserId,
 };
 
@@ -12,16 +22,185 @@ macro_rules! map_user {
         User {
             id: UserId::from($row.id),
             email: domain::Email::parse($row.email).expect("invalid email in db"),
-            username: domain::Username::new($row.username).expect("invalid username in db"),
+            username: domain::Username::stored($row.username).expect("invalid username in db"),
             bio: if $row.bio.is_empty() { None } else { Some($row.bio) },
             image: if $row.img.is_empty() { None } else { Some(domain::ImageUrl::new($row.img).expect("invalid image in db")) },
             password_hash: domain::PasswordHash::new($row.pwd).expect("invalid password in db"),
             created_at: $row.created_at.with_timezone(&chrono::Utc),
             updated_at: $row.updated_at.with_timezone(&chrono::Utc),
+            deactivated_at: $row.deactivated_at.map(|at| at.with_timezone(&chrono::Utc)),
+            suspended_at: $row.suspended_at.map(|at| at.with_timezone(&chrono::Utc)),
+        }
+    };
+}
+
+macro_rules! map_article {
+    ($row:expr) => {
+        Article {
+            id: ArticleId::from($row.id),
+            slug: domain::Slug::new($row.slug).expect("invalid slug in db"),
+            title: $row.title,
+            description: $row.description,
+            body: $row.body,
+            tag_list: domain::TagList::new($row.tag_list).expect("invalid tags in db"),
+            author_id: UserId::from($row.author_id),
+            coauthor_ids: $row.coauthor_ids.into_iter().map(UserId::from).collect(),
+            favorites_count: $row.favorites_count as u32,
+            status: ArticleStatus::parse(&$row.status).expect("invalid status in db"),
+            published_at: $row.published_at.map(|at| at.with_timezone(&chrono::Utc)),
+            hidden_at: $row.hidden_at.map(|at| at.with_timezone(&chrono::Utc)),
+            seo: domain::ArticleSeo {
+                canonical_url: $row.canonical_url,
+                meta_description: $row.meta_description,
+                social_image: $row.social_image,
+            },
+            created_at: $row.created_at.with_timezone(&chrono::Utc),
+            updated_at: $row.updated_at.with_timezone(&chrono::Utc),
+        }
+    };
+}
+
+macro_rules! map_summary {
+    ($row:expr) => {
+        domain::ArticleSummary {
+            slug: domain::Slug::new($row.slug).expect("invalid slug"),
+            title: $row.title,
+            description: $row.description,
+            tag_list: domain::TagList::new($row.tag_list).expect("invalid tags"),
+            created_at: $row.created_at.with_timezone(&chrono::Utc),
+            updated_at: $row.updated_at.with_timezone(&chrono::Utc),
+            favorited: $row.favorited,
+            favorites_count: $row.favorites_count as u32,
+            status: ArticleStatus::parse(&$row.status).expect("invalid status"),
+            published_at: $row.published_at.map(|at| at.with_timezone(&chrono::Utc)),
+            author: domain::Profile::new(
+                domain::Username::stored($row.author_username).expect("invalid username"),
+                if $row.author_bio.is_empty() { None } else { Some($row.author_bio) },
+                if $row.author_image.is_empty() { None } else { Some(domain::ImageUrl::new($row.author_image).expect("invalid image")) },
+                $row.following_author,
+            ),
+            bookmarked: $row.bookmarked,
+            reactions: {
+                let viewer_reactions = $row.viewer_reactions;
+                $row.reaction_kinds
+                    .into_iter()
+                    .zip($row.reaction_counts)
+                    .map(|(kind, count)| ReactionCount {
+                        reacted: viewer_reactions.contains(&kind),
+                        kind: ReactionKind::new(kind).expect("invalid reaction in db"),
+                        count: count as u32,
+                    })
+                    .collect()
+            },
+            reason: None,
+        }
+    };
+}
+
+macro_rules! map_bookmark {
+    ($row:expr) => {
+        Bookmark {
+            user_id: UserId::from($row.appuser_id),
+            article_id: ArticleId::from($row.article_id),
+            note: $row.note,
+            folder: $row.folder,
+            created_at: $row.created_at.with_timezone(&chrono::Utc),
+        }
+    };
+}
+
+/// Summary keyed by its position in newest-first lists, for cursor pages
+macro_rules! keyed_summary {
+    ($row:expr) => {
+        (
+            (
+                $row.published_at.unwrap_or($row.created_at).with_timezone(&chrono::Utc),
+                ArticleId::from($row.id),
+            ),
+            map_summary!($row),
+        )
+    };
+}
+
+/// Feed summary keyed like `keyed_summary!`, saying why it is in the feed
+macro_rules! keyed_feed_summary {
+    ($row:expr) => {{
+        let reason = domain::FeedReason {
+            followed_author: $row.following_author || $row.following_coauthor,
+            followed_tags: domain::TagList::new($row.followed_tags).expect("invalid tags"),
+        };
+        let (key, summary) = keyed_summary!($row);
+        (key, summary.with_reason(reason))
+    }};
+}
+
+macro_rules! map_coauthor {
+    ($row:expr) => {
+        Coauthor {
+            article_id: ArticleId::from($row.article_id),
+            user_id: UserId::from($row.appuser_id),
+            invited_at: $row.invited_at.with_timezone(&chrono::Utc),
+            accepted_at: $row.accepted_at.map(|at| at.with_timezone(&chrono::Utc)),
+        }
+    };
+}
+
+macro_rules! map_revision {
+    ($row:expr) => {
+        ArticleRevision {
+            id: RevisionId::from($row.id),
+            article_id: ArticleId::from($row.article_id),
+            version: $row.version as u32,
+            author_id: UserId::from($row.author_id),
+            title: $row.title,
+            description: $row.description,
+            body: $row.body,
+            created_at: $row.created_at.with_timezone(&chrono::Utc),
+        }
+    };
+}
+
+macro_rules! map_report {
+    ($row:expr) => {
+        Report {
+            id: ReportId::from($row.id),
+            reporter_id: UserId::from($row.reporter_id),
+            target: match ($row.article_id, $row.comment_id, $row.profile_id) {
+                (Some(id), _, _) => ReportTarget::Article(ArticleId::from(id)),
+                (_, Some(id), _) => ReportTarget::Comment(CommentId::from(id as i64)),
+                (_, _, Some(id)) => ReportTarget::Profile(UserId::from(id)),
+                _ => panic!("report without target in db"),
+            },
+            target_author_id: UserId::from($row.target_author_id),
+            reason: ReportReason::parse(&$row.reason).expect("invalid report reason in db"),
+            details: $row.details,
+            status: ReportStatus::parse(&$row.status).expect("invalid report status in db"),
+            moderator_id: $row.moderator_id.map(UserId::from),
+            created_at: $row.created_at.with_timezone(&chrono::Utc),
+            updated_at: $row.updated_at.with_timezone(&chrono::Utc),
+            closed_at: $row.closed_at.map(|at| at.with_timezone(&chrono::Utc)),
+        }
+    };
+}
+
+macro_rules! map_series {
+    ($row:expr) => {
+        Series {
+            id: SeriesId::from($row.id),
+            slug: domain::Slug::new($row.slug).expect("invalid slug in db"),
+            title: $row.title,
+            description: $row.description,
+            kind: SeriesKind::parse(&$row.kind).expect("invalid series kind in db"),
+            owner_id: UserId::from($row.owner_id),
+            created_at: $row.created_at.with_timezone(&chrono::Utc),
+            updated_at: $row.updated_at.with_timezone(&chrono::Utc),
         }
     };
 }
 
+/// Position of a row in newest-first lists
+type SummaryKey = (chrono::DateTime<chrono::Utc>, ArticleId);
+
 #[derive(Clone)]
 pub struct PostgresUsersRepository {
     pool: Pool,
@@ -132,8 +311,222 @@ impl UsersRepository for PostgresUsersRepository {
             .await?;
         Ok(is_following)
     }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn list_followed_users(&self, follower_id: UserId) -> anyhow::Result<Vec<User>> {
+        let client = self.pool.get().await?;
+        let rows = crate::clorinde::queries::users::list_followed_users()
+            .bind(&client, &follower_id.into())
+            .all()
+            .await?;
+        Ok(rows.into_iter().map(|row| map_user!(row)).collect())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn list_blocked_users(&self, blocker_id: UserId) -> anyhow::Result<Vec<User>> {
+        let client = self.pool.get().await?;
+        let rows = crate::clorinde::queries::users::list_blocked_users()
+            .bind(&client, &blocker_id.into())
+            .all()
+            .await?;
+        Ok(rows.into_iter().map(|row| map_user!(row)).collect())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn list_muted_users(&self, muter_id: UserId) -> anyhow::Result<Vec<User>> {
+        let client = self.pool.get().await?;
+        let rows = crate::clorinde::queries::users::list_muted_users()
+            .bind(&client, &muter_id.into())
+            .all()
+            .await?;
+        Ok(rows.into_iter().map(|row| map_user!(row)).collect())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn deactivate_user(
+        &self,
+        id: UserId,
+        deactivated_at: chrono::DateTime<chrono::Utc>,
+    ) -> anyhow::Result<()> {
+        let client = self.pool.get().await?;
+        crate::clorinde::queries::users::deactivate_user()
+            .bind(
+                &client,
+                &deactivated_at.with_timezone(&chrono::FixedOffset::east_opt(0).unwrap()),
+                &id.into(),
+            )
+            .await?;
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn reactivate_user(&self, id: UserId) -> anyhow::Result<()> {
+        let client = self.pool.get().await?;
+        crate::clorinde::queries::users::reactivate_user()
+            .bind(&client, &id.into())
+            .await?;
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn suspend_user(&self, id: UserId, suspended_at: chrono::DateTime<chrono::Utc>) -> anyhow::Result<()> {
+        let client = self.pool.get().await?;
+        crate::clorinde::queries::users::suspend_user()
+            .bind(&client, &suspended_at.fixed_offset(), &id.into())
+            .await?;
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn list_deactivated_users(
+        &self,
+        cutoff: chrono::DateTime<chrono::Utc>,
+    ) -> anyhow::Result<Vec<User>> {
+        let client = self.pool.get().await?;
+        let rows = crate::clorinde::queries::users::list_deactivated_users()
+            .bind(
+                &client,
+                &cutoff.with_timezone(&chrono::FixedOffset::east_opt(0).unwrap()),
+            )
+            .all()
+            .await?;
+        Ok(rows.into_iter().map(|row| map_user!(row)).collect())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn delete_user(&self, id: UserId) -> anyhow::Result<()> {
+        // follows and favorites go with the account via ON DELETE CASCADE
+        let client = self.pool.get().await?;
+        crate::clorinde::queries::users::delete_user()
+            .bind(&client, &id.into())
+            .await?;
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn block_user(&self, blocker_id: UserId, blocked_id: UserId) -> anyhow::Result<()> {
+        let mut client = self.pool.get().await?;
+        let tx = client.transaction().await?;
+        crate::clorinde::queries::users::block_user()
+            .bind(&tx, &blocker_id.into(), &blocked_id.into())
+            .await?;
+        crate::clorinde::queries::users::unfollow_both_ways()
+            .bind(&tx, &blocker_id.into(), &blocked_id.into())
+            .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn unblock_user(&self, blocker_id: UserId, blocked_id: UserId) -> anyhow::Result<()> {
+        let client = self.pool.get().await?;
+        crate::clorinde::queries::users::unblock_user()
+            .bind(&client, &blocker_id.into(), &blocked_id.into())
+            .await?;
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn is_blocking(&self, blocker_id: UserId, blocked_id: UserId) -> anyhow::Result<bool> {
+        let client = self.pool.get().await?;
+        let is_blocking = crate::clorinde::queries::users::is_blocking()
+            .bind(&client, &blocker_id.into(), &blocked_id.into())
+            .one()
+            .await?;
+        Ok(is_blocking)
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn mute_user(&self, muter_id: UserId, muted_id: UserId) -> anyhow::Result<()> {
+        let client = self.pool.get().await?;
+        crate::clorinde::queries::users::mute_user()
+            .bind(&client, &muter_id.into(), &muted_id.into())
+            .await?;
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn unmute_user(&self, muter_id: UserId, muted_id: UserId) -> anyhow::Result<()> {
+        let client = self.pool.get().await?;
+        crate::clorinde::queries::users::unmute_user()
+            .bind(&client, &muter_id.into(), &muted_id.into())
+            .await?;
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn is_muting(&self, muter_id: UserId, muted_id: UserId) -> anyhow::Result<bool> {
+        let client = self.pool.get().await?;
+        let is_muting = crate::clorinde::queries::users::is_muting()
+            .bind(&client, &muter_id.into(), &muted_id.into())
+            .one()
+            .await?;
+        Ok(is_muting)
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn list_hidden_authors(&self, viewer_id: UserId) -> anyhow::Result<Vec<UserId>> {
+        let client = self.pool.get().await?;
+        let hidden = crate::clorinde::queries::users::list_hidden_authors()
+            .bind(&client, &viewer_id.into())
+            .all()
+            .await?;
+        Ok(hidden.into_iter().map(UserId::from).collect())
+    }
+}
+
+/// Reports a clash on the unique article slug as [`SlugTaken`], so the caller
+/// can try another slug
+fn slug_conflict(err: tokio_postgres::Error, article: &Article) -> anyhow::Error {
+    let taken = err.as_db_error().is_some_and(|db| {
+        *db.code() == tokio_postgres::error::SqlState::UNIQUE_VIOLATION && db.constraint() == Some("article_slug_key")
+    });
+    if taken {
+        SlugTaken(article.slug.as_str().to_owned()).into()
+    } else {
+        err.into()
+    }
+}
+
+fn series_conflict(err: tokio_postgres::Error, article_id: ArticleId) -> anyhow::Error {
+    let taken = err.as_db_error().is_some_and(|db| {
+        *db.code() == tokio_postgres::error::SqlState::UNIQUE_VIOLATION
+            && db.constraint() == Some("series_article_one_series_idx")
+    });
+    if taken {
+        PartOfAnotherSeries(article_id).into()
+    } else {
+        err.into()
+    }
 }
 
+/// Links the article to each tag, creating missing ones, and returns the tags
+/// as stored: aliases replaced by the tag they stand for
+async fn link_tags<C: GenericClient>(client: &C, article_id: uuid::Uuid, tags: TagList) -> anyhow::Result<TagList> {
+    let mut linked = TagList::default();
+    for tag in tags.as_slice() {
+        let name = crate::clorinde::queries::tags::canonical_tag_name()
+            .bind(client, &tag.as_str())
+            .one()
+            .await?;
+        let tag = Tag::new(name)?;
+        if linked.contains(&tag) {
+            continue;
+        }
+        crate::clorinde::queries::articles::create_tag()
+            .bind(client, &uuid::Uuid::new_v4(), &tag.as_str())
+            .await?;
+        let tag_row = crate::clorinde::queries::articles::get_tag_by_name()
+            .bind(client, &tag.as_str())
+            .one()
+            .await?;
+        crate::clorinde::queries::articles::add_tag_to_article()
+            .bind(client, &article_id, &tag_row.id)
+            .await?;
+        linked.push(tag);
+    }
+    Ok(linked)
+}
 
 #[derive(Clone)]
 pub struct PostgresArticlesRepository {
@@ -160,36 +553,20 @@ impl ArticlesRepository for PostgresArticlesRepository {
                 &article.description,
                 &article.body,
                 &article.author_id.into(),
+                &article.status.as_str(),
+                &article.published_at.map(|at| at.fixed_offset()),
+                &article.seo.canonical_url,
+                &article.seo.meta_description,
+                &article.seo.social_image,
                 &article.created_at.with_timezone(&chrono::FixedOffset::east_opt(0).unwrap()),
             )
             .one()
-            .await?;
+            .await
+            .map_err(|e| slug_conflict(e, &article))?;
         
-        // We need to insert tags
-        for tag in article.tag_list.as_slice().iter() {
-            // Create tag if not exists
-            let tag_id = uuid::Uuid::new_v4(); // We need to generate ID for new tags? 
-            // Wait, tag table has ID.
-            // My query `create_tag` takes ID and Name.
-            // But `tag` table has `id uuid PRIMARY KEY`.
-            // So I should generate ID.
-            crate::clorinde::queries::articles::create_tag()
-                .bind(&client, &tag_id, &tag.as_str())
-                .await?;
-            
-            // Get tag id (in case it existed)
-            let tag_row = crate::clorinde::queries::articles::get_tag_by_name()
-                .bind(&client, &tag.as_str())
-                .one()
-                .await?;
-            
-            // Link tag
-            crate::clorinde::queries::articles::add_tag_to_article()
-                .bind(&client, &created.id, &tag_row.id)
-                .await?;
-        }
+        let tag_list = link_tags(&client, created.id, article.tag_list).await?;
 
-        Ok(article)
+        Ok(Article { tag_list, ..article })
     }
 
     #[tracing::instrument(skip(self), err)]
@@ -201,18 +578,7 @@ impl ArticlesRepository for PostgresArticlesRepository {
             .await?;
         
         if let Some(row) = article_row {
-            Ok(Some(Article {
-                id: ArticleId::from(row.id),
-                slug: domain::Slug::new(row.slug).expect("invalid slug in db"),
-                title: row.title,
-                description: row.description,
-                body: row.body,
-                tag_list: domain::TagList::new(row.tag_list).expect("invalid tags in db"),
-                author_id: UserId::from(row.author_id),
-                favorites_count: row.favorites_count as u32,
-                created_at: row.created_at.with_timezone(&chrono::Utc),
-                updated_at: row.updated_at.with_timezone(&chrono::Utc),
-            }))
+            Ok(Some(map_article!(row)))
         } else {
             Ok(None)
         }
@@ -227,18 +593,7 @@ impl ArticlesRepository for PostgresArticlesRepository {
             .await?;
         
         if let Some(row) = article_row {
-            Ok(Some(Article {
-                id: ArticleId::from(row.id),
-                slug: domain::Slug::new(row.slug).expect("invalid slug in db"),
-                title: row.title,
-                description: row.description,
-                body: row.body,
-                tag_list: domain::TagList::new(row.tag_list).expect("invalid tags in db"),
-                author_id: UserId::from(row.author_id),
-                favorites_count: row.favorites_count as u32,
-                created_at: row.created_at.with_timezone(&chrono::Utc),
-                updated_at: row.updated_at.with_timezone(&chrono::Utc),
-            }))
+            Ok(Some(map_article!(row)))
         } else {
             Ok(None)
         }
@@ -254,41 +609,41 @@ impl ArticlesRepository for PostgresArticlesRepository {
                 &article.title,
                 &article.description,
                 &article.body,
+                &article.status.as_str(),
+                &article.published_at.map(|at| at.fixed_offset()),
+                &article.seo.canonical_url,
+                &article.seo.meta_description,
+                &article.seo.social_image,
                 &article.updated_at.with_timezone(&chrono::FixedOffset::east_opt(0).unwrap()),
                 &article.id.into(),
             )
             .one()
-            .await?;
+            .await
+            .map_err(|e| slug_conflict(e, &article))?;
         
         // Update tags
         crate::clorinde::queries::articles::remove_tags_from_article()
             .bind(&client, &article.id.into())
             .await?;
-            
-        for tag in article.tag_list.as_slice().iter() {
-            let tag_id = uuid::Uuid::new_v4();
-            crate::clorinde::queries::articles::create_tag()
-                .bind(&client, &tag_id, &tag.as_str())
-                .await?;
-            
-            let tag_row = crate::clorinde::queries::articles::get_tag_by_name()
-                .bind(&client, &tag.as_str())
-                .one()
-                .await?;
-            
-            crate::clorinde::queries::articles::add_tag_to_article()
-                .bind(&client, &article.id.into(), &tag_row.id)
-                .await?;
-        }
+        let tag_list = link_tags(&client, article.id.into(), article.tag_list).await?;
 
-        Ok(article)
+        Ok(Article { tag_list, ..article })
     }
 
     #[tracing::instrument(skip(self), err)]
-    async fn delete_article(&self, id: ArticleId) -> anyhow::Result<()> {
+    async fn delete_article(&self, id: ArticleId, deleted_at: chrono::DateTime<chrono::Utc>) -> anyhow::Result<()> {
         let client = self.pool.get().await?;
         crate::clorinde::queries::articles::delete_article()
-            .bind(&client, &id.into())
+            .bind(&client, &deleted_at.fixed_offset(), &id.into())
+            .await?;
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn hide_article(&self, id: ArticleId, hidden_at: chrono::DateTime<chrono::Utc>) -> anyhow::Result<()> {
+        let client = self.pool.get().await?;
+        crate::clorinde::queries::articles::hide_article()
+            .bind(&client, &hidden_at.fixed_offset(), &id.into())
             .await?;
         Ok(())
     }
@@ -298,81 +653,114 @@ impl ArticlesRepository for PostgresArticlesRepository {
         let client = self.pool.get().await?;
         let limit = filters.pagination.limit() as i64;
         let offset = filters.pagination.offset() as i64;
-        let tag = filters.tag.map(|t| t.as_str().to_owned());
-        let author = filters.author;
+        let tags: Vec<String> = filters.tags.into_iter().map(String::from).collect();
+        let match_all_tags = filters.tag_match == TagMatch::All;
+        let excluded_tags: Vec<String> = filters.excluded_tags.into_iter().map(String::from).collect();
+        let authors = filters.authors;
         let favorited = filters.favorited;
+        let since = filters.since.map(|at| at.fixed_offset());
+        let until = filters.until.map(|at| at.fixed_offset());
         
-        // We need a viewer_id for `following_author` and `favorited` checks.
-        // But `list_articles` signature doesn't take viewer_id?
-        // The trait definition: `async fn list_articles(&self, filters: ArticleFilters) -> anyhow::Result<ArticlesEnvelope>;`
-        // It seems the trait is missing `viewer_id` or `user_id`?
-        // In `apps/api/src/routes/api/articles.rs`, `list_articles` takes `current_user: Option<CurrentUser>`.
-        // And it calls `state.list_articles(filters)`.
-        // But `state.list_articles` (in memory impl) didn't take user_id.
-        // It calculated `following` and `favorited` *after* fetching articles, using `author_profile_with_follow_state`.
-        // But my SQL query does it inside the query!
-        // My SQL query requires `viewer_id` (parameter $4 in original, :viewer_id in named).
-        // If I don't have viewer_id, I should pass NULL or a dummy UUID?
-        // If I pass NULL, the `EXISTS` check will return false, which is correct for anonymous user.
-        // But `uuid` type in Postgres cannot be NULL if the parameter is typed as UUID?
-        // `clorinde` generated `viewer_id: &'a uuid::Uuid`. It expects a reference to Uuid.
-        // It doesn't accept Option<Uuid>.
-        // So I must pass a Uuid.
-        // I can pass a random UUID that definitely doesn't exist?
-        // Or I should update the trait to accept `viewer_id`.
-        // But I should stick to the requested changes.
-        // The user asked to "hide the data impl behind a trait".
-        // If the trait doesn't support viewer_id, I can't use the optimized query fully.
-        // However, I can pass a random UUID for anonymous users.
-        
-        let viewer_id = uuid::Uuid::nil(); // Use nil UUID for anonymous
+        // Anonymous viewers use the nil UUID, which matches no follow, favorite, mute or block
+        let viewer_id = filters.viewer.map(Into::into).unwrap_or_else(uuid::Uuid::nil);
 
-        let rows = crate::clorinde::queries::articles::list_articles()
-            .bind(
-                &client,
-                &viewer_id,
-                &author.as_deref(),
-                &tag.as_deref(),
-                &favorited.as_deref(),
-                &limit,
-                &offset,
-            )
-            .all()
-            .await?;
-            
         let count = crate::clorinde::queries::articles::count_articles()
             .bind(
                 &client,
-                &author.as_deref(),
-                &tag.as_deref(),
+                &viewer_id,
+                &authors,
+                &tags,
+                &match_all_tags,
+                &excluded_tags,
                 &favorited.as_deref(),
+                &since,
+                &until,
             )
             .one()
             .await?;
 
-        let articles = rows.into_iter().map(|row| {
-            domain::ArticleSummary {
-                slug: domain::Slug::new(row.slug).expect("invalid slug"),
-                title: row.title,
-                description: row.description,
-                tag_list: domain::TagList::new(row.tag_list).expect("invalid tags"),
-                created_at: row.created_at.with_timezone(&chrono::Utc),
-                updated_at: row.updated_at.with_timezone(&chrono::Utc),
-                favorited: row.favorited,
-                favorites_count: row.favorites_count as u32,
-                author: domain::Profile::new(
-                    domain::Username::new(row.author_username).expect("invalid username"),
-                    if row.author_bio.is_empty() { None } else { Some(row.author_bio) },
-                    if row.author_image.is_empty() { None } else { Some(domain::ImageUrl::new(row.author_image).expect("invalid image")) },
-                    row.following_author,
-                ),
+        let page = match filters.cursor {
+            None => {
+                let sort = filters.sort.as_str();
+                let trending_since = filters.trending_since.map(|at| at.fixed_offset());
+                let rows = crate::clorinde::queries::articles::list_articles()
+                    .bind(
+                        &client,
+                        &viewer_id,
+                        &authors,
+                        &tags,
+                        &match_all_tags,
+                        &excluded_tags,
+                        &favorited.as_deref(),
+                        &since,
+                        &until,
+                        &sort,
+                        &trending_since,
+                        &limit,
+                        &offset,
+                    )
+                    .all()
+                    .await?;
+                let rows = rows.into_iter().map(|row| keyed_summary!(row)).collect();
+                let page = CursorPage::from_offset(rows, offset as u32, count as usize, |(key, _)| *key);
+                // Cursors only follow the newest-first order
+                match filters.sort {
+                    ArticleSort::Recent => page,
+                    _ => CursorPage { next: None, prev: None, ..page },
+                }
             }
-        }).collect();
+            Some(cursor) => {
+                let listed_at = cursor.listed_at().fixed_offset();
+                let id: uuid::Uuid = cursor.id().into();
+                // One extra row tells whether another page follows
+                let fetch = limit + 1;
+                let rows = match cursor.direction() {
+                    CursorDirection::Older => crate::clorinde::queries::articles::list_articles_older()
+                        .bind(
+                            &client,
+                            &viewer_id,
+                            &authors,
+                            &tags,
+                            &match_all_tags,
+                            &excluded_tags,
+                            &favorited.as_deref(),
+                            &since,
+                            &until,
+                            &listed_at,
+                            &id,
+                            &fetch,
+                        )
+                        .all()
+                        .await?
+                        .into_iter()
+                        .map(|row| keyed_summary!(row))
+                        .collect(),
+                    CursorDirection::Newer => crate::clorinde::queries::articles::list_articles_newer()
+                        .bind(
+                            &client,
+                            &viewer_id,
+                            &authors,
+                            &tags,
+                            &match_all_tags,
+                            &excluded_tags,
+                            &favorited.as_deref(),
+                            &since,
+                            &until,
+                            &listed_at,
+                            &id,
+                            &fetch,
+                        )
+                        .all()
+                        .await?
+                        .into_iter()
+                        .map(|row| keyed_summary!(row))
+                        .collect(),
+                };
+                CursorPage::from_cursor(rows, limit as u32, cursor, |(key, _)| *key)
+            }
+        };
 
-        Ok(ArticlesEnvelope {
-            articles,
-            articles_count: count as usize,
-        })
+        Ok(summaries_envelope(page, count))
     }
 
     #[tracing::instrument(skip(self, filters), fields(limit = filters.pagination.limit(), offset = filters.pagination.offset()), err)]
@@ -386,46 +774,62 @@ impl ArticlesRepository for PostgresArticlesRepository {
         let offset = filters.pagination.offset() as i64;
         let viewer_id = user_id.into();
 
-        let rows = crate::clorinde::queries::articles::feed_articles()
-            .bind(&client, &viewer_id, &limit, &offset)
-            .all()
-            .await?;
-            
         let count = crate::clorinde::queries::articles::count_feed_articles()
             .bind(&client, &viewer_id)
             .one()
             .await?;
 
-        let articles = rows.into_iter().map(|row| {
-            domain::ArticleSummary {
-                slug: domain::Slug::new(row.slug).expect("invalid slug"),
-                title: row.title,
-                description: row.description,
-                tag_list: domain::TagList::new(row.tag_list).expect("invalid tags"),
-                created_at: row.created_at.with_timezone(&chrono::Utc),
-                updated_at: row.updated_at.with_timezone(&chrono::Utc),
-                favorited: row.favorited,
-                favorites_count: row.favorites_count as u32,
-                author: domain::Profile::new(
-                    domain::Username::new(row.author_username).expect("invalid username"),
-                    if row.author_bio.is_empty() { None } else { Some(row.author_bio) },
-                    if row.author_image.is_empty() { None } else { Some(domain::ImageUrl::new(row.author_image).expect("invalid image")) },
-                    row.following_author,
-                ),
+        let page = match filters.cursor {
+            None => {
+                let rows = crate::clorinde::queries::articles::feed_articles()
+                    .bind(&client, &viewer_id, &limit, &offset)
+                    .all()
+                    .await?;
+                let rows = rows.into_iter().map(|row| keyed_feed_summary!(row)).collect();
+                CursorPage::from_offset(rows, offset as u32, count as usize, |(key, _)| *key)
             }
-        }).collect();
+            Some(cursor) => {
+                let listed_at = cursor.listed_at().fixed_offset();
+                let id: uuid::Uuid = cursor.id().into();
+                let fetch = limit + 1;
+                let rows = match cursor.direction() {
+                    CursorDirection::Older => crate::clorinde::queries::articles::feed_articles_older()
+                        .bind(&client, &viewer_id, &listed_at, &id, &fetch)
+                        .all()
+                        .await?
+                        .into_iter()
+                        .map(|row| keyed_feed_summary!(row))
+                        .collect(),
+                    CursorDirection::Newer => crate::clorinde::queries::articles::feed_articles_newer()
+                        .bind(&client, &viewer_id, &listed_at, &id, &fetch)
+                        .all()
+                        .await?
+                        .into_iter()
+                        .map(|row| keyed_feed_summary!(row))
+                        .collect(),
+                };
+                CursorPage::from_cursor(rows, limit as u32, cursor, |(key, _)| *key)
+            }
+        };
 
-        Ok(ArticlesEnvelope {
-            articles,
-            articles_count: count as usize,
-        })
+        Ok(summaries_envelope(page, count))
     }
 
     #[tracing::instrument(skip(self), err)]
-    async fn favorite_article(&self, user_id: UserId, article_id: ArticleId) -> anyhow::Result<()> {
+    async fn favorite_article(
+        &self,
+        user_id: UserId,
+        article_id: ArticleId,
+        favorited_at: chrono::DateTime<chrono::Utc>,
+    ) -> anyhow::Result<()> {
         let client = self.pool.get().await?;
         crate::clorinde::queries::articles::favorite_article()
-            .bind(&client, &user_id.into(), &article_id.into())
+            .bind(
+                &client,
+                &user_id.into(),
+                &article_id.into(),
+                &favorited_at.with_timezone(&chrono::FixedOffset::east_opt(0).unwrap()),
+            )
             .await?;
         Ok(())
     }
@@ -452,87 +856,1279 @@ impl ArticlesRepository for PostgresArticlesRepository {
             .await?;
         Ok(is_favorited)
     }
-}
-
-#[derive(Clone)]
-pub struct PostgresCommentsRepository {
-    pool: Pool,
-}
 
-impl PostgresCommentsRepository {
-    pub fn new(pool: Pool) -> Self {
-        Self { pool }
+    #[tracing::instrument(skip(self), err)]
+    async fn get_articles_by_slugs(&self, slugs: &[String]) -> anyhow::Result<Vec<Article>> {
+        let client = self.pool.get().await?;
+        let rows = crate::clorinde::queries::articles::get_articles_by_slugs()
+            .bind(&client, &slugs)
+            .all()
+            .await?;
+        Ok(rows.into_iter().map(|row| map_article!(row)).collect())
     }
-}
 
-#[async_trait]
-impl CommentsRepository for PostgresCommentsRepository {
-    #[tracing::instrument(skip(self, comment), fields(article_id = ?comment.article_id, author_id = ?comment.author_id), err)]
-    async fn create_comment(&self, comment: Comment) -> anyhow::Result<Comment> {
+    #[tracing::instrument(skip(self), err)]
+    async fn list_favorited_articles(&self, user_id: UserId) -> anyhow::Result<Vec<Article>> {
         let client = self.pool.get().await?;
-        let created = crate::clorinde::queries::comments::create_comment()
-            .bind(
+        let rows = crate::clorinde::queries::articles::list_favorited_articles()
+            .bind(&client, &user_id.into())
+            .all()
+            .await?;
+        Ok(rows.into_iter().map(|row| map_article!(row)).collect())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn delete_articles_by_author(&self, author_id: UserId) -> anyhow::Result<()> {
+        let client = self.pool.get().await?;
+        crate::clorinde::queries::articles::delete_articles_by_author()
+            .bind(&client, &author_id.into())
+            .await?;
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn reassign_articles(&self, from_author_id: UserId, to_author_id: UserId) -> anyhow::Result<()> {
+        let client = self.pool.get().await?;
+        crate::clorinde::queries::articles::reassign_articles()
+            .bind(&client, &to_author_id.into(), &from_author_id.into())
+            .await?;
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn remove_favorites_by_user(&self, user_id: UserId) -> anyhow::Result<()> {
+        let client = self.pool.get().await?;
+        crate::clorinde::queries::articles::remove_favorites_by_user()
+            .bind(&client, &user_id.into())
+            .await?;
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn erase_account(
+        &self,
+        user_id: UserId,
+        authored_content: AuthoredContentPolicy,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> anyhow::Result<()> {
+        let mut client = self.pool.get().await?;
+        let tx = client.transaction().await?;
+        let user_id = user_id.into();
+        match authored_content {
+            AuthoredContentPolicy::Delete => {
+                crate::clorinde::queries::comments::delete_comments_by_author()
+                    .bind(&tx, &user_id)
+                    .await?;
+                crate::clorinde::queries::articles::delete_articles_by_author()
+                    .bind(&tx, &user_id)
+                    .await?;
+            }
+            AuthoredContentPolicy::Anonymise => {
+                let placeholder = User::deleted_placeholder(now);
+                let placeholder_id = placeholder.id.into();
+                let existing = crate::clorinde::queries::users::get_user_by_id()
+                    .bind(&tx, &placeholder_id)
+                    .opt()
+                    .await?;
+                if existing.is_none() {
+                    crate::clorinde::queries::users::create_user()
+                        .bind(
+                            &tx,
+                            &placeholder_id,
+                            &placeholder.email.as_str(),
+                            &placeholder.username.as_str(),
+                            &placeholder.password_hash.as_str(),
+                            &now.with_timezone(&chrono::FixedOffset::east_opt(0).unwrap()),
+                        )
+                        .one()
+                        .await?;
+                }
+                crate::clorinde::queries::comments::reassign_comments()
+                    .bind(&tx, &placeholder_id, &user_id)
+                    .await?;
+                crate::clorinde::queries::articles::reassign_articles()
+                    .bind(&tx, &placeholder_id, &user_id)
+                    .await?;
+            }
+        }
+        crate::clorinde::queries::articles::remove_favorites_by_user()
+            .bind(&tx, &user_id)
+            .await?;
+        crate::clorinde::queries::articles::remove_article_reactions_by_user()
+            .bind(&tx, &user_id)
+            .await?;
+        crate::clorinde::queries::comments::remove_comment_reactions_by_user()
+            .bind(&tx, &user_id)
+            .await?;
+        crate::clorinde::queries::users::delete_user()
+            .bind(&tx, &user_id)
+            .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn add_article_reaction(&self, article_id: ArticleId, user_id: UserId, kind: &ReactionKind) -> anyhow::Result<()> {
+        let client = self.pool.get().await?;
+        crate::clorinde::queries::articles::add_article_reaction()
+            .bind(&client, &article_id.into(), &user_id.into(), &kind.as_str())
+            .await?;
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn remove_article_reaction(&self, article_id: ArticleId, user_id: UserId, kind: &ReactionKind) -> anyhow::Result<()> {
+        let client = self.pool.get().await?;
+        crate::clorinde::queries::articles::remove_article_reaction()
+            .bind(&client, &article_id.into(), &user_id.into(), &kind.as_str())
+            .await?;
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn article_reactions(&self, article_id: ArticleId, viewer: Option<UserId>) -> anyhow::Result<Vec<ReactionCount>> {
+        let client = self.pool.get().await?;
+        // Anonymous viewers use the nil UUID, which matches no reaction
+        let viewer_id = viewer.map(Into::into).unwrap_or_else(uuid::Uuid::nil);
+        let rows = crate::clorinde::queries::articles::article_reactions()
+            .bind(&client, &viewer_id, &article_id.into())
+            .all()
+            .await?;
+        Ok(rows
+            .into_iter()
+            .map(|row| ReactionCount {
+                kind: ReactionKind::new(row.kind).expect("invalid reaction in db"),
+                count: row.count as u32,
+                reacted: row.reacted,
+            })
+            .collect())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn remove_article_reactions_by_user(&self, user_id: UserId) -> anyhow::Result<()> {
+        let client = self.pool.get().await?;
+        crate::clorinde::queries::articles::remove_article_reactions_by_user()
+            .bind(&client, &user_id.into())
+            .await?;
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn list_article_reactions_by_user(&self, user_id: UserId) -> anyhow::Result<Vec<(ArticleId, ReactionKind)>> {
+        let client = self.pool.get().await?;
+        let rows = crate::clorinde::queries::articles::list_article_reactions_by_user()
+            .bind(&client, &user_id.into())
+            .all()
+            .await?;
+        Ok(rows
+            .into_iter()
+            .map(|row| (ArticleId::from(row.article_id), ReactionKind::new(row.kind).expect("invalid reaction in db")))
+            .collect())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn get_article_by_previous_slug(&self, slug: &str) -> anyhow::Result<Option<Article>> {
+        let client = self.pool.get().await?;
+        let article_row = crate::clorinde::queries::articles::get_article_by_previous_slug()
+            .bind(&client, &slug)
+            .opt()
+            .await?;
+
+        Ok(article_row.map(|row| map_article!(row)))
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn record_slug_change(&self, article_id: ArticleId, old_slug: &str, new_slug: &str) -> anyhow::Result<()> {
+        let mut client = self.pool.get().await?;
+        let tx = client.transaction().await?;
+        crate::clorinde::queries::articles::forget_previous_slug()
+            .bind(&tx, &new_slug)
+            .await?;
+        crate::clorinde::queries::articles::record_previous_slug()
+            .bind(&tx, &old_slug, &article_id.into())
+            .await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn list_articles_by_author(
+        &self,
+        author_id: UserId,
+    ) -> anyhow::Result<Vec<(Article, Option<chrono::DateTime<chrono::Utc>>)>> {
+        let client = self.pool.get().await?;
+        let rows = crate::clorinde::queries::articles::list_articles_by_author()
+            .bind(&client, &author_id.into())
+            .all()
+            .await?;
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                let deleted_at = row.deleted_at.map(|at| at.with_timezone(&chrono::Utc));
+                (map_article!(row), deleted_at)
+            })
+            .collect())
+    }
+
+    #[tracing::instrument(skip(self, pagination), fields(limit = pagination.limit(), offset = pagination.offset()), err)]
+    async fn list_drafts(&self, author_id: UserId, pagination: Pagination) -> anyhow::Result<ArticlesEnvelope> {
+        let client = self.pool.get().await?;
+        let limit = pagination.limit() as i64;
+        let offset = pagination.offset() as i64;
+        let author_id = author_id.into();
+
+        let rows = crate::clorinde::queries::articles::list_drafts()
+            .bind(&client, &author_id, &limit, &offset)
+            .all()
+            .await?;
+
+        let count = crate::clorinde::queries::articles::count_drafts()
+            .bind(&client, &author_id)
+            .one()
+            .await?;
+
+        Ok(ArticlesEnvelope {
+            articles: rows.into_iter().map(|row| map_summary!(row)).collect(),
+            articles_count: count as usize,
+            next_cursor: None,
+            prev_cursor: None,
+        })
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn publish_due_articles(&self, now: chrono::DateTime<chrono::Utc>) -> anyhow::Result<Vec<Article>> {
+        let client = self.pool.get().await?;
+        let rows = crate::clorinde::queries::articles::publish_due_articles()
+            .bind(&client, &now.fixed_offset())
+            .all()
+            .await?;
+        Ok(rows.into_iter().map(|row| map_article!(row)).collect())
+    }
+
+    #[tracing::instrument(skip(self, filters), fields(viewer = ?filters.viewer), err)]
+    async fn search_articles(&self, filters: SearchFilters) -> anyhow::Result<SearchResultsEnvelope> {
+        let client = self.pool.get().await?;
+        let query = ts_query(&filters.query);
+        let limit = filters.pagination.limit() as i64;
+        let offset = filters.pagination.offset() as i64;
+        let viewer_id = filters.viewer.map(Into::into).unwrap_or_else(uuid::Uuid::nil);
+
+        let rows = crate::clorinde::queries::articles::search_articles()
+            .bind(&client, &viewer_id, &query, &limit, &offset)
+            .all()
+            .await?;
+        let count = crate::clorinde::queries::articles::count_search_articles()
+            .bind(&client, &query, &viewer_id)
+            .one()
+            .await?;
+
+        let articles = rows
+            .into_iter()
+            .map(|row| domain::ArticleSearchHit {
+                rank: row.rank,
+                highlight: row.highlight,
+                article: map_summary!(row),
+            })
+            .collect();
+
+        Ok(SearchResultsEnvelope {
+            articles,
+            articles_count: count as usize,
+        })
+    }
+
+    #[tracing::instrument(skip(self, bookmark), fields(user_id = ?bookmark.user_id, article_id = ?bookmark.article_id), err)]
+    async fn bookmark_article(&self, bookmark: Bookmark) -> anyhow::Result<Bookmark> {
+        let client = self.pool.get().await?;
+        let row = crate::clorinde::queries::bookmarks::upsert_bookmark()
+            .bind(
                 &client,
-                &comment.body,
-                &comment.article_id.into(),
-                &comment.author_id.into(),
-                &comment.created_at.with_timezone(&chrono::FixedOffset::east_opt(0).unwrap()),
+                &bookmark.user_id.into(),
+                &bookmark.article_id.into(),
+                &bookmark.note,
+                &bookmark.folder,
+                &bookmark.created_at.fixed_offset(),
             )
             .one()
             .await?;
-        
-        Ok(Comment {
-            id: CommentId::from(created.id as i64),
-            body: created.body,
-            article_id: ArticleId::from(created.article_id),
-            author_id: UserId::from(created.author_id),
-            created_at: created.created_at.with_timezone(&chrono::Utc),
-            updated_at: created.updated_at.with_timezone(&chrono::Utc),
+        Ok(map_bookmark!(row))
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn remove_bookmark(&self, user_id: UserId, article_id: ArticleId) -> anyhow::Result<()> {
+        let client = self.pool.get().await?;
+        crate::clorinde::queries::bookmarks::delete_bookmark()
+            .bind(&client, &user_id.into(), &article_id.into())
+            .await?;
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn get_bookmark(&self, user_id: UserId, article_id: ArticleId) -> anyhow::Result<Option<Bookmark>> {
+        let client = self.pool.get().await?;
+        let row = crate::clorinde::queries::bookmarks::get_bookmark()
+            .bind(&client, &user_id.into(), &article_id.into())
+            .opt()
+            .await?;
+        Ok(row.map(|row| map_bookmark!(row)))
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn list_bookmarks_by_user(&self, user_id: UserId) -> anyhow::Result<Vec<Bookmark>> {
+        let client = self.pool.get().await?;
+        let rows = crate::clorinde::queries::bookmarks::list_bookmarks_by_user()
+            .bind(&client, &user_id.into())
+            .all()
+            .await?;
+        Ok(rows.into_iter().map(|row| map_bookmark!(row)).collect())
+    }
+
+    #[tracing::instrument(skip(self, filters), fields(folder = ?filters.folder), err)]
+    async fn list_bookmarks(&self, user_id: UserId, filters: BookmarkFilters) -> anyhow::Result<BookmarksEnvelope> {
+        let client = self.pool.get().await?;
+        let user_id = user_id.into();
+        let limit = filters.pagination.limit() as i64;
+        let offset = filters.pagination.offset() as i64;
+
+        let rows = crate::clorinde::queries::bookmarks::list_bookmarks()
+            .bind(&client, &user_id, &filters.folder, &limit, &offset)
+            .all()
+            .await?;
+        let count = crate::clorinde::queries::bookmarks::count_bookmarks()
+            .bind(&client, &user_id, &filters.folder)
+            .one()
+            .await?;
+
+        let bookmarks = rows
+            .into_iter()
+            .map(|row| domain::BookmarkView {
+                note: row.note,
+                folder: row.folder,
+                created_at: row.bookmarked_at.with_timezone(&chrono::Utc),
+                article: map_summary!(row),
+            })
+            .collect();
+
+        Ok(BookmarksEnvelope {
+            bookmarks,
+            bookmarks_count: count as usize,
         })
     }
 
+    #[tracing::instrument(skip(self, coauthor), fields(article_id = ?coauthor.article_id, user_id = ?coauthor.user_id), err)]
+    async fn invite_coauthor(&self, coauthor: Coauthor) -> anyhow::Result<()> {
+        let client = self.pool.get().await?;
+        crate::clorinde::queries::coauthors::invite_coauthor()
+            .bind(
+                &client,
+                &coauthor.article_id.into(),
+                &coauthor.user_id.into(),
+                &coauthor.invited_at.fixed_offset(),
+            )
+            .await?;
+        Ok(())
+    }
+
     #[tracing::instrument(skip(self), err)]
-    async fn get_comments_by_article(&self, article_id: ArticleId) -> anyhow::Result<Vec<Comment>> {
+    async fn get_coauthor(&self, article_id: ArticleId, user_id: UserId) -> anyhow::Result<Option<Coauthor>> {
         let client = self.pool.get().await?;
-        let rows = crate::clorinde::queries::comments::get_comments_by_article()
-            .bind(&client, &article_id.into())
+        let row = crate::clorinde::queries::coauthors::get_coauthor()
+            .bind(&client, &article_id.into(), &user_id.into())
+            .opt()
+            .await?;
+        Ok(row.map(|row| map_coauthor!(row)))
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn list_coauthorships(&self, user_id: UserId) -> anyhow::Result<Vec<Coauthor>> {
+        let client = self.pool.get().await?;
+        let rows = crate::clorinde::queries::coauthors::list_coauthorships()
+            .bind(&client, &user_id.into())
             .all()
             .await?;
-            
-        Ok(rows.into_iter().map(|row| Comment {
-            id: CommentId::from(row.id as i64),
-            body: row.body,
-            article_id: ArticleId::from(row.article_id),
-            author_id: UserId::from(row.author_id),
-            created_at: row.created_at.with_timezone(&chrono::Utc),
-            updated_at: row.updated_at.with_timezone(&chrono::Utc),
-        }).collect())
+        Ok(rows.into_iter().map(|row| map_coauthor!(row)).collect())
     }
 
     #[tracing::instrument(skip(self), err)]
-    async fn delete_comment(&self, id: CommentId) -> anyhow::Result<()> {
+    async fn accept_coauthor(&self, article_id: ArticleId, user_id: UserId, accepted_at: chrono::DateTime<chrono::Utc>) -> anyhow::Result<()> {
         let client = self.pool.get().await?;
-        crate::clorinde::queries::comments::delete_comment()
-            .bind(&client, &(id.as_i64() as i32)) // Comment ID is int in DB
+        crate::clorinde::queries::coauthors::accept_coauthor()
+            .bind(&client, &accepted_at.fixed_offset(), &article_id.into(), &user_id.into())
             .await?;
         Ok(())
     }
 
     #[tracing::instrument(skip(self), err)]
-    async fn get_comment_by_id(&self, id: CommentId) -> anyhow::Result<Option<Comment>> {
+    async fn remove_coauthor(&self, article_id: ArticleId, user_id: UserId) -> anyhow::Result<()> {
         let client = self.pool.get().await?;
-        let row = crate::clorinde::queries::comments::get_comment_by_id()
-            .bind(&client, &(id.as_i64() as i32))
+        crate::clorinde::queries::coauthors::remove_coauthor()
+            .bind(&client, &article_id.into(), &user_id.into())
+            .await?;
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self, scoring), err)]
+    async fn related_articles(
+        &self,
+        article_id: ArticleId,
+        viewer: Option<UserId>,
+        scoring: &RelatedScoring,
+        limit: u32,
+        now: chrono::DateTime<chrono::Utc>,
+    ) -> anyhow::Result<Vec<ArticleSummary>> {
+        let client = self.pool.get().await?;
+        let viewer_id = viewer.map(Into::into).unwrap_or_else(uuid::Uuid::nil);
+        let rows = crate::clorinde::queries::articles::related_articles()
+            .bind(
+                &client,
+                &article_id.into(),
+                &scoring.tag_weight,
+                &scoring.author_weight,
+                &scoring.cofavorite_weight,
+                &scoring.recency_weight,
+                &now.fixed_offset(),
+                &scoring.recency_days,
+                &viewer_id,
+                &(limit as i64),
+            )
+            .all()
+            .await?;
+        Ok(rows.into_iter().map(|row| map_summary!(row)).collect())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn count_sitemap_entries(&self) -> anyhow::Result<u64> {
+        let client = self.pool.get().await?;
+        let count = crate::clorinde::queries::articles::count_sitemap_entries()
+            .bind(&client)
+            .one()
+            .await?;
+        Ok(count as u64)
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn sitemap_entries(&self, offset: u64, limit: u32) -> anyhow::Result<Vec<SitemapEntry>> {
+        let client = self.pool.get().await?;
+        let rows = crate::clorinde::queries::articles::sitemap_entries()
+            .bind(&client, &(limit as i64), &(offset as i64))
+            .all()
+            .await?;
+        Ok(rows
+            .into_iter()
+            .map(|row| SitemapEntry {
+                location: match row.kind.as_str() {
+                    "article" => SitemapLocation::Article(row.key),
+                    _ => SitemapLocation::Profile(row.key),
+                },
+                last_modified: row.last_modified.with_timezone(&chrono::Utc),
+            })
+            .collect())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn get_trashed_article_by_slug(&self, slug: &str) -> anyhow::Result<Option<Trashed<Article>>> {
+        let client = self.pool.get().await?;
+        let row = crate::clorinde::queries::articles::get_trashed_article_by_slug()
+            .bind(&client, &slug)
             .opt()
             .await?;
-            
-        Ok(row.map(|row| Comment {
-            id: CommentId::from(row.id as i64),
-            body: row.body,
-            article_id: ArticleId::from(row.article_id),
-            author_id: UserId::from(row.author_id),
-            created_at: row.created_at.with_timezone(&chrono::Utc),
-            updated_at: row.updated_at.with_timezone(&chrono::Utc),
+        Ok(row.map(|row| {
+            let deleted_at = row.deleted_at.expect("trashed article without deletion time");
+            Trashed {
+                item: map_article!(row),
+                deleted_at: deleted_at.with_timezone(&chrono::Utc),
+            }
         }))
     }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn list_trashed_articles(&self, author_id: UserId) -> anyhow::Result<Vec<Trashed<Article>>> {
+        let client = self.pool.get().await?;
+        let rows = crate::clorinde::queries::articles::list_trashed_articles()
+            .bind(&client, &author_id.into())
+            .all()
+            .await?;
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                let deleted_at = row.deleted_at.expect("trashed article without deletion time");
+                Trashed {
+                    item: map_article!(row),
+                    deleted_at: deleted_at.with_timezone(&chrono::Utc),
+                }
+            })
+            .collect())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn restore_article(&self, id: ArticleId) -> anyhow::Result<()> {
+        let client = self.pool.get().await?;
+        crate::clorinde::queries::articles::restore_article()
+            .bind(&client, &id.into())
+            .await?;
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn purge_trashed_articles(&self, cutoff: chrono::DateTime<chrono::Utc>) -> anyhow::Result<Vec<ArticleId>> {
+        let client = self.pool.get().await?;
+        let ids = crate::clorinde::queries::articles::purge_trashed_articles()
+            .bind(&client, &cutoff.fixed_offset())
+            .all()
+            .await?;
+        Ok(ids.into_iter().map(ArticleId::from).collect())
+    }
+}
+
+fn summaries_envelope(page: CursorPage<(SummaryKey, ArticleSummary)>, count: i64) -> ArticlesEnvelope {
+    ArticlesEnvelope {
+        articles: page.items.into_iter().map(|(_, summary)| summary).collect(),
+        articles_count: count as usize,
+        next_cursor: page.next,
+        prev_cursor: page.prev,
+    }
+}
+
+/// Renders a parsed query for `to_tsquery`; terms only contain letters and
+/// digits, so quoting them is enough
+fn ts_query(query: &SearchQuery) -> String {
+    query
+        .terms()
+        .iter()
+        .map(|term| match term {
+            SearchTerm::Word(word) => format!("'{word}'"),
+            SearchTerm::Prefix(prefix) => format!("'{prefix}':*"),
+            SearchTerm::Phrase(words) => {
+                let words: Vec<String> = words.iter().map(|word| format!("'{word}'")).collect();
+                format!("({})", words.join(" <-> "))
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" & ")
+}
+
+#[derive(Clone)]
+pub struct PostgresCommentsRepository {
+    pool: Pool,
+}
+
+impl PostgresCommentsRepository {
+    pub fn new(pool: Pool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl CommentsRepository for PostgresCommentsRepository {
+    #[tracing::instrument(skip(self, comment), fields(article_id = ?comment.article_id, author_id = ?comment.author_id), err)]
+    async fn create_comment(&self, comment: Comment) -> anyhow::Result<Comment> {
+        let client = self.pool.get().await?;
+        let created = crate::clorinde::queries::comments::create_comment()
+            .bind(
+                &client,
+                &comment.body,
+                &comment.article_id.into(),
+                &comment.author_id.into(),
+                &comment.created_at.with_timezone(&chrono::FixedOffset::east_opt(0).unwrap()),
+            )
+            .one()
+            .await?;
+        
+        Ok(Comment {
+            id: CommentId::from(created.id as i64),
+            body: created.body,
+            article_id: ArticleId::from(created.article_id),
+            author_id: UserId::from(created.author_id),
+            created_at: created.created_at.with_timezone(&chrono::Utc),
+            updated_at: created.updated_at.with_timezone(&chrono::Utc),
+            hidden_at: created.hidden_at.map(|at| at.with_timezone(&chrono::Utc)),
+        })
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn get_comments_by_article(&self, article_id: ArticleId) -> anyhow::Result<Vec<Comment>> {
+        let client = self.pool.get().await?;
+        let rows = crate::clorinde::queries::comments::get_comments_by_article()
+            .bind(&client, &article_id.into())
+            .all()
+            .await?;
+            
+        Ok(rows.into_iter().map(|row| Comment {
+            id: CommentId::from(row.id as i64),
+            body: row.body,
+            article_id: ArticleId::from(row.article_id),
+            author_id: UserId::from(row.author_id),
+            created_at: row.created_at.with_timezone(&chrono::Utc),
+            updated_at: row.updated_at.with_timezone(&chrono::Utc),
+            hidden_at: row.hidden_at.map(|at| at.with_timezone(&chrono::Utc)),
+        }).collect())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn delete_comment(&self, id: CommentId, deleted_at: chrono::DateTime<chrono::Utc>) -> anyhow::Result<()> {
+        let client = self.pool.get().await?;
+        crate::clorinde::queries::comments::delete_comment()
+            .bind(&client, &deleted_at.fixed_offset(), &(id.as_i64() as i32)) // Comment ID is int in DB
+            .await?;
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn hide_comment(&self, id: CommentId, hidden_at: chrono::DateTime<chrono::Utc>) -> anyhow::Result<()> {
+        let client = self.pool.get().await?;
+        crate::clorinde::queries::comments::hide_comment()
+            .bind(&client, &hidden_at.fixed_offset(), &(id.as_i64() as i32))
+            .await?;
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn get_comment_by_id(&self, id: CommentId) -> anyhow::Result<Option<Comment>> {
+        let client = self.pool.get().await?;
+        let row = crate::clorinde::queries::comments::get_comment_by_id()
+            .bind(&client, &(id.as_i64() as i32))
+            .opt()
+            .await?;
+            
+        Ok(row.map(|row| Comment {
+            id: CommentId::from(row.id as i64),
+            body: row.body,
+            article_id: ArticleId::from(row.article_id),
+            author_id: UserId::from(row.author_id),
+            created_at: row.created_at.with_timezone(&chrono::Utc),
+            updated_at: row.updated_at.with_timezone(&chrono::Utc),
+            hidden_at: row.hidden_at.map(|at| at.with_timezone(&chrono::Utc)),
+        }))
+    }
+    #[tracing::instrument(skip(self), err)]
+    async fn get_comments_by_author(&self, author_id: UserId) -> anyhow::Result<Vec<Comment>> {
+        let client = self.pool.get().await?;
+        let rows = crate::clorinde::queries::comments::get_comments_by_author()
+            .bind(&client, &author_id.into())
+            .all()
+            .await?;
+
+        Ok(rows.into_iter().map(|row| Comment {
+            id: CommentId::from(row.id as i64),
+            body: row.body,
+            article_id: ArticleId::from(row.article_id),
+            author_id: UserId::from(row.author_id),
+            created_at: row.created_at.with_timezone(&chrono::Utc),
+            updated_at: row.updated_at.with_timezone(&chrono::Utc),
+            hidden_at: row.hidden_at.map(|at| at.with_timezone(&chrono::Utc)),
+        }).collect())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn delete_comments_by_author(&self, author_id: UserId) -> anyhow::Result<()> {
+        let client = self.pool.get().await?;
+        crate::clorinde::queries::comments::delete_comments_by_author()
+            .bind(&client, &author_id.into())
+            .await?;
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn reassign_comments(&self, from_author_id: UserId, to_author_id: UserId) -> anyhow::Result<()> {
+        let client = self.pool.get().await?;
+        crate::clorinde::queries::comments::reassign_comments()
+            .bind(&client, &to_author_id.into(), &from_author_id.into())
+            .await?;
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn get_trashed_comment(&self, id: CommentId) -> anyhow::Result<Option<Trashed<Comment>>> {
+        let client = self.pool.get().await?;
+        let row = crate::clorinde::queries::comments::get_trashed_comment()
+            .bind(&client, &(id.as_i64() as i32))
+            .opt()
+            .await?;
+
+        Ok(row.map(|row| Trashed {
+            deleted_at: row.deleted_at.expect("trashed comment without deletion time").with_timezone(&chrono::Utc),
+            item: Comment {
+                id: CommentId::from(row.id as i64),
+                body: row.body,
+                article_id: ArticleId::from(row.article_id),
+                author_id: UserId::from(row.author_id),
+                created_at: row.created_at.with_timezone(&chrono::Utc),
+                updated_at: row.updated_at.with_timezone(&chrono::Utc),
+                hidden_at: row.hidden_at.map(|at| at.with_timezone(&chrono::Utc)),
+            },
+        }))
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn list_trashed_comments(&self, author_id: UserId) -> anyhow::Result<Vec<Trashed<Comment>>> {
+        let client = self.pool.get().await?;
+        let rows = crate::clorinde::queries::comments::list_trashed_comments()
+            .bind(&client, &author_id.into())
+            .all()
+            .await?;
+
+        Ok(rows.into_iter().map(|row| Trashed {
+            deleted_at: row.deleted_at.expect("trashed comment without deletion time").with_timezone(&chrono::Utc),
+            item: Comment {
+                id: CommentId::from(row.id as i64),
+                body: row.body,
+                article_id: ArticleId::from(row.article_id),
+                author_id: UserId::from(row.author_id),
+                created_at: row.created_at.with_timezone(&chrono::Utc),
+                updated_at: row.updated_at.with_timezone(&chrono::Utc),
+                hidden_at: row.hidden_at.map(|at| at.with_timezone(&chrono::Utc)),
+            },
+        }).collect())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn restore_comment(&self, id: CommentId) -> anyhow::Result<()> {
+        let client = self.pool.get().await?;
+        crate::clorinde::queries::comments::restore_comment()
+            .bind(&client, &(id.as_i64() as i32))
+            .await?;
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn purge_trashed_comments(&self, cutoff: chrono::DateTime<chrono::Utc>) -> anyhow::Result<Vec<CommentId>> {
+        let client = self.pool.get().await?;
+        let ids = crate::clorinde::queries::comments::purge_trashed_comments()
+            .bind(&client, &cutoff.fixed_offset())
+            .all()
+            .await?;
+        Ok(ids.into_iter().map(|id| CommentId::from(id as i64)).collect())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn add_comment_reaction(&self, comment_id: CommentId, user_id: UserId, kind: &ReactionKind) -> anyhow::Result<()> {
+        let client = self.pool.get().await?;
+        crate::clorinde::queries::comments::add_comment_reaction()
+            .bind(&client, &(comment_id.as_i64() as i32), &user_id.into(), &kind.as_str())
+            .await?;
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn remove_comment_reaction(&self, comment_id: CommentId, user_id: UserId, kind: &ReactionKind) -> anyhow::Result<()> {
+        let client = self.pool.get().await?;
+        crate::clorinde::queries::comments::remove_comment_reaction()
+            .bind(&client, &(comment_id.as_i64() as i32), &user_id.into(), &kind.as_str())
+            .await?;
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self, comment_ids), fields(comments = comment_ids.len()), err)]
+    async fn comment_reactions(
+        &self,
+        comment_ids: &[CommentId],
+        viewer: Option<UserId>,
+    ) -> anyhow::Result<HashMap<CommentId, Vec<ReactionCount>>> {
+        let client = self.pool.get().await?;
+        let viewer_id = viewer.map(Into::into).unwrap_or_else(uuid::Uuid::nil);
+        let ids: Vec<i32> = comment_ids.iter().map(|id| id.as_i64() as i32).collect();
+        let rows = crate::clorinde::queries::comments::comment_reactions()
+            .bind(&client, &viewer_id, &ids)
+            .all()
+            .await?;
+
+        let mut reactions: HashMap<CommentId, Vec<ReactionCount>> = HashMap::new();
+        for row in rows {
+            reactions.entry(CommentId::from(row.comment_id as i64)).or_default().push(ReactionCount {
+                kind: ReactionKind::new(row.kind).expect("invalid reaction in db"),
+                count: row.count as u32,
+                reacted: row.reacted,
+            });
+        }
+        Ok(reactions)
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn remove_comment_reactions_by_user(&self, user_id: UserId) -> anyhow::Result<()> {
+        let client = self.pool.get().await?;
+        crate::clorinde::queries::comments::remove_comment_reactions_by_user()
+            .bind(&client, &user_id.into())
+            .await?;
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn list_comment_reactions_by_user(&self, user_id: UserId) -> anyhow::Result<Vec<(CommentId, ReactionKind)>> {
+        let client = self.pool.get().await?;
+        let rows = crate::clorinde::queries::comments::list_comment_reactions_by_user()
+            .bind(&client, &user_id.into())
+            .all()
+            .await?;
+        Ok(rows
+            .into_iter()
+            .map(|row| {
+                let kind = ReactionKind::new(row.kind).expect("invalid reaction in db");
+                (CommentId::from(row.comment_id as i64), kind)
+            })
+            .collect())
+    }
+}
+
+#[derive(Clone)]
+pub struct PostgresRevisionsRepository {
+    pool: Pool,
+}
+
+impl PostgresRevisionsRepository {
+    pub fn new(pool: Pool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl RevisionsRepository for PostgresRevisionsRepository {
+    #[tracing::instrument(skip(self, revision), fields(article_id = ?revision.article_id, version = revision.version), err)]
+    async fn create_revision(&self, revision: ArticleRevision) -> anyhow::Result<ArticleRevision> {
+        let client = self.pool.get().await?;
+        let created = crate::clorinde::queries::revisions::create_revision()
+            .bind(
+                &client,
+                &revision.id.into(),
+                &revision.article_id.into(),
+                &(revision.version as i32),
+                &revision.author_id.into(),
+                &revision.title,
+                &revision.description,
+                &revision.body,
+                &revision.created_at.fixed_offset(),
+            )
+            .one()
+            .await?;
+        Ok(map_revision!(created))
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn list_revisions(&self, article_id: ArticleId) -> anyhow::Result<Vec<ArticleRevision>> {
+        let client = self.pool.get().await?;
+        let rows = crate::clorinde::queries::revisions::list_revisions()
+            .bind(&client, &article_id.into())
+            .all()
+            .await?;
+        Ok(rows.into_iter().map(|row| map_revision!(row)).collect())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn get_revision(&self, id: RevisionId) -> anyhow::Result<Option<ArticleRevision>> {
+        let client = self.pool.get().await?;
+        let row = crate::clorinde::queries::revisions::get_revision()
+            .bind(&client, &id.into())
+            .opt()
+            .await?;
+        Ok(row.map(|row| map_revision!(row)))
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn latest_revision(&self, article_id: ArticleId) -> anyhow::Result<Option<ArticleRevision>> {
+        let client = self.pool.get().await?;
+        let row = crate::clorinde::queries::revisions::latest_revision()
+            .bind(&client, &article_id.into())
+            .opt()
+            .await?;
+        Ok(row.map(|row| map_revision!(row)))
+    }
+}
+
+#[derive(Clone)]
+pub struct PostgresTagsRepository {
+    pool: Pool,
+}
+
+impl PostgresTagsRepository {
+    pub fn new(pool: Pool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl TagsRepository for PostgresTagsRepository {
+    #[tracing::instrument(skip(self), err)]
+    async fn popular_tags(&self, limit: u32) -> anyhow::Result<Vec<TagCount>> {
+        let client = self.pool.get().await?;
+        let rows = crate::clorinde::queries::tags::popular_tags()
+            .bind(&client, &(limit as i64))
+            .all()
+            .await?;
+        Ok(rows
+            .into_iter()
+            .map(|row| TagCount {
+                name: row.name,
+                articles_count: row.articles_count as u64,
+            })
+            .collect())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn search_tags(&self, prefix: &str, limit: u32) -> anyhow::Result<Vec<TagCount>> {
+        let client = self.pool.get().await?;
+        let rows = crate::clorinde::queries::tags::search_tags()
+            .bind(&client, &prefix, &(limit as i64))
+            .all()
+            .await?;
+        Ok(rows
+            .into_iter()
+            .map(|row| TagCount {
+                name: row.name,
+                articles_count: row.articles_count as u64,
+            })
+            .collect())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn canonical_tag(&self, tag: &Tag) -> anyhow::Result<Tag> {
+        let client = self.pool.get().await?;
+        let name = crate::clorinde::queries::tags::canonical_tag_name()
+            .bind(&client, &tag.as_str())
+            .one()
+            .await?;
+        Ok(Tag::new(name)?)
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn merge_tags(&self, source: &Tag, target: &Tag) -> anyhow::Result<u64> {
+        let mut client = self.pool.get().await?;
+        let tx = client.transaction().await?;
+        crate::clorinde::queries::articles::create_tag()
+            .bind(&tx, &uuid::Uuid::new_v4(), &target.as_str())
+            .await?;
+        let target_id = crate::clorinde::queries::articles::get_tag_by_name()
+            .bind(&tx, &target.as_str())
+            .one()
+            .await?
+            .id;
+        let source_id = crate::clorinde::queries::articles::get_tag_by_name()
+            .bind(&tx, &source.as_str())
+            .opt()
+            .await?
+            .map(|row| row.id);
+
+        let mut relinked = 0;
+        if let Some(source_id) = source_id {
+            crate::clorinde::queries::tags::relink_tag()
+                .bind(&tx, &target_id, &source_id)
+                .await?;
+            relinked = crate::clorinde::queries::tags::unlink_tag()
+                .bind(&tx, &source_id)
+                .await?;
+            crate::clorinde::queries::tags::relink_follows()
+                .bind(&tx, &target_id, &source_id)
+                .await?;
+            crate::clorinde::queries::tags::repoint_aliases()
+                .bind(&tx, &target_id, &source_id)
+                .await?;
+            crate::clorinde::queries::tags::delete_tag()
+                .bind(&tx, &source_id)
+                .await?;
+        }
+        crate::clorinde::queries::tags::upsert_alias()
+            .bind(&tx, &source.as_str(), &target_id)
+            .await?;
+        tx.commit().await?;
+        Ok(relinked)
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn follow_tag(&self, user_id: UserId, tag: &Tag) -> anyhow::Result<()> {
+        let client = self.pool.get().await?;
+        crate::clorinde::queries::articles::create_tag()
+            .bind(&client, &uuid::Uuid::new_v4(), &tag.as_str())
+            .await?;
+        let tag_row = crate::clorinde::queries::articles::get_tag_by_name()
+            .bind(&client, &tag.as_str())
+            .one()
+            .await?;
+        crate::clorinde::queries::tags::follow_tag()
+            .bind(&client, &user_id.into(), &tag_row.id)
+            .await?;
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn unfollow_tag(&self, user_id: UserId, tag: &Tag) -> anyhow::Result<()> {
+        let client = self.pool.get().await?;
+        crate::clorinde::queries::tags::unfollow_tag()
+            .bind(&client, &user_id.into(), &tag.as_str())
+            .await?;
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn followed_tags(&self, user_id: UserId) -> anyhow::Result<Vec<Tag>> {
+        let client = self.pool.get().await?;
+        let names = crate::clorinde::queries::tags::followed_tags()
+            .bind(&client, &user_id.into())
+            .all()
+            .await?;
+        Ok(names.into_iter().map(Tag::new).collect::<Result<_, _>>()?)
+    }
+}
+
+#[derive(Clone)]
+pub struct PostgresSeriesRepository {
+    pool: Pool,
+}
+
+impl PostgresSeriesRepository {
+    pub fn new(pool: Pool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl SeriesRepository for PostgresSeriesRepository {
+    #[tracing::instrument(skip(self, series), fields(slug = %series.slug.as_str()), err)]
+    async fn create_series(&self, series: Series) -> anyhow::Result<Series> {
+        let client = self.pool.get().await?;
+        let row = crate::clorinde::queries::series::create_series()
+            .bind(
+                &client,
+                &series.id.into(),
+                &series.slug.as_str(),
+                &series.title,
+                &series.description,
+                &series.kind.as_str(),
+                &series.owner_id.into(),
+                &series.created_at.fixed_offset(),
+            )
+            .one()
+            .await?;
+        Ok(map_series!(row))
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn get_series_by_slug(&self, slug: &str) -> anyhow::Result<Option<Series>> {
+        let client = self.pool.get().await?;
+        let row = crate::clorinde::queries::series::get_series_by_slug()
+            .bind(&client, &slug)
+            .opt()
+            .await?;
+        Ok(row.map(|row| map_series!(row)))
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn list_series_by_owner(&self, owner_id: UserId) -> anyhow::Result<Vec<Series>> {
+        let client = self.pool.get().await?;
+        let rows = crate::clorinde::queries::series::list_series_by_owner()
+            .bind(&client, &owner_id.into())
+            .all()
+            .await?;
+        Ok(rows.into_iter().map(|row| map_series!(row)).collect())
+    }
+
+    #[tracing::instrument(skip(self, series), fields(slug = %series.slug.as_str()), err)]
+    async fn update_series(&self, series: Series) -> anyhow::Result<Series> {
+        let client = self.pool.get().await?;
+        let row = crate::clorinde::queries::series::update_series()
+            .bind(
+                &client,
+                &series.title,
+                &series.description,
+                &series.updated_at.fixed_offset(),
+                &series.id.into(),
+            )
+            .one()
+            .await?;
+        Ok(map_series!(row))
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn delete_series(&self, id: SeriesId) -> anyhow::Result<()> {
+        let client = self.pool.get().await?;
+        crate::clorinde::queries::series::delete_series()
+            .bind(&client, &id.into())
+            .await?;
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn set_series_parts(&self, id: SeriesId, article_ids: &[ArticleId]) -> anyhow::Result<()> {
+        let mut client = self.pool.get().await?;
+        let tx = client.transaction().await?;
+        crate::clorinde::queries::series::clear_series_parts()
+            .bind(&tx, &id.into())
+            .await?;
+        for (position, article_id) in article_ids.iter().enumerate() {
+            crate::clorinde::queries::series::add_series_part()
+                .bind(&tx, &(*article_id).into(), &(position as i32), &id.into())
+                .await
+                .map_err(|e| series_conflict(e, *article_id))?;
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn list_series_parts(&self, id: SeriesId, viewer: Option<UserId>) -> anyhow::Result<Vec<ArticleSummary>> {
+        let client = self.pool.get().await?;
+        let viewer_id = viewer.map(Into::into).unwrap_or_else(uuid::Uuid::nil);
+        let rows = crate::clorinde::queries::series::list_series_parts()
+            .bind(&client, &viewer_id, &id.into())
+            .all()
+            .await?;
+        Ok(rows.into_iter().map(|row| map_summary!(row)).collect())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn series_of_article(&self, article_id: ArticleId) -> anyhow::Result<Option<Series>> {
+        let client = self.pool.get().await?;
+        let row = crate::clorinde::queries::series::series_of_article()
+            .bind(&client, &article_id.into())
+            .opt()
+            .await?;
+        Ok(row.map(|row| map_series!(row)))
+    }
+}
+
+pub struct PostgresReportsRepository {
+    pool: Pool,
+}
+
+impl PostgresReportsRepository {
+    pub fn new(pool: Pool) -> Self {
+        Self { pool }
+    }
+}
+
+/// The report's article, comment and profile columns; only one is set
+fn target_columns(target: ReportTarget) -> (Option<uuid::Uuid>, Option<i32>, Option<uuid::Uuid>) {
+    match target {
+        ReportTarget::Article(id) => (Some(id.into()), None, None),
+        ReportTarget::Comment(id) => (None, Some(id.as_i64() as i32), None),
+        ReportTarget::Profile(id) => (None, None, Some(id.into())),
+    }
+}
+
+#[async_trait]
+impl ReportsRepository for PostgresReportsRepository {
+    #[tracing::instrument(skip(self, report), fields(id = ?report.id), err)]
+    async fn create_report(&self, report: Report) -> anyhow::Result<Report> {
+        let client = self.pool.get().await?;
+        let (article_id, comment_id, profile_id) = target_columns(report.target);
+        let row = crate::clorinde::queries::reports::create_report()
+            .bind(
+                &client,
+                &report.id.into(),
+                &report.reporter_id.into(),
+                &article_id,
+                &comment_id,
+                &profile_id,
+                &report.target_author_id.into(),
+                &report.reason.as_str(),
+                &report.details,
+                &report.created_at.fixed_offset(),
+            )
+            .one()
+            .await?;
+        Ok(map_report!(row))
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn get_report(&self, id: ReportId) -> anyhow::Result<Option<Report>> {
+        let client = self.pool.get().await?;
+        let row = crate::clorinde::queries::reports::get_report()
+            .bind(&client, &id.into())
+            .opt()
+            .await?;
+        Ok(row.map(|row| map_report!(row)))
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn find_open_report(&self, reporter_id: UserId, target: ReportTarget) -> anyhow::Result<Option<Report>> {
+        let client = self.pool.get().await?;
+        let (article_id, comment_id, profile_id) = target_columns(target);
+        let row = crate::clorinde::queries::reports::find_open_report()
+            .bind(&client, &reporter_id.into(), &article_id, &comment_id, &profile_id)
+            .opt()
+            .await?;
+        Ok(row.map(|row| map_report!(row)))
+    }
+
+    #[tracing::instrument(skip(self), fields(limit = pagination.limit(), offset = pagination.offset()), err)]
+    async fn list_reports(&self, status: Option<ReportStatus>, pagination: Pagination) -> anyhow::Result<Vec<Report>> {
+        let client = self.pool.get().await?;
+        let rows = crate::clorinde::queries::reports::list_reports()
+            .bind(
+                &client,
+                &status.map(|status| status.as_str()),
+                &(pagination.limit() as i64),
+                &(pagination.offset() as i64),
+            )
+            .all()
+            .await?;
+        Ok(rows.into_iter().map(|row| map_report!(row)).collect())
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn count_reports(&self, status: Option<ReportStatus>) -> anyhow::Result<u64> {
+        let client = self.pool.get().await?;
+        let count = crate::clorinde::queries::reports::count_reports()
+            .bind(&client, &status.map(|status| status.as_str()))
+            .one()
+            .await?;
+        Ok(count as u64)
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn list_reports_by_reporter(&self, reporter_id: UserId) -> anyhow::Result<Vec<Report>> {
+        let client = self.pool.get().await?;
+        let rows = crate::clorinde::queries::reports::list_reports_by_reporter()
+            .bind(&client, &reporter_id.into())
+            .all()
+            .await?;
+        Ok(rows.into_iter().map(|row| map_report!(row)).collect())
+    }
+
+    #[tracing::instrument(skip(self, report, record), fields(id = ?report.id, action = record.action.as_str()), err)]
+    async fn record_moderation(&self, report: &Report, record: ModerationRecord) -> anyhow::Result<bool> {
+        let mut client = self.pool.get().await?;
+        let tx = client.transaction().await?;
+        let updated = crate::clorinde::queries::reports::update_report()
+            .bind(
+                &tx,
+                &report.status.as_str(),
+                &report.moderator_id.map(Into::into),
+                &report.updated_at.fixed_offset(),
+                &report.closed_at.map(|at| at.fixed_offset()),
+                &report.id.into(),
+            )
+            .await?;
+        if updated == 0 {
+            return Ok(false);
+        }
+        crate::clorinde::queries::reports::record_moderation_action()
+            .bind(
+                &tx,
+                &record.report_id.into(),
+                &record.moderator_id.map(Into::into),
+                &record.action.as_str(),
+                &record.note,
+                &record.created_at.fixed_offset(),
+            )
+            .await?;
+        tx.commit().await?;
+        Ok(true)
+    }
+
+    #[tracing::instrument(skip(self), err)]
+    async fn list_moderation_records(&self, report_id: ReportId) -> anyhow::Result<Vec<ModerationRecord>> {
+        let client = self.pool.get().await?;
+        let rows = crate::clorinde::queries::reports::list_moderation_actions()
+            .bind(&client, &report_id.into())
+            .all()
+            .await?;
+        Ok(rows
+            .into_iter()
+            .map(|row| ModerationRecord {
+                report_id: ReportId::from(row.report_id),
+                moderator_id: row.moderator_id.map(UserId::from),
+                action: ModerationAction::parse(&row.action).expect("invalid moderation action in db"),
+                note: row.note,
+                created_at: row.created_at.with_timezone(&chrono::Utc),
+            })
+            .collect())
+    }
 }
@@ -0,0 +1,67 @@
+//! Runs the shared blob store scenario from `domain::testing` against the
+//! local filesystem and a MinIO container
+
+use common_config::S3Config;
+use data::{LocalBlobStore, S3BlobStore};
+use domain::{Blob, repositories::BlobStore};
+use testcontainers::{
+    GenericImage, ImageExt,
+    core::{CmdWaitFor, ContainerPort, ExecCommand, WaitFor},
+    runners::AsyncRunner,
+};
+
+#[tokio::test]
+async fn test_local_blob_storage() {
+    let root = std::env::temp_dir().join(format!("blobs-{}", uuid::Uuid::new_v4()));
+    let store = LocalBlobStore::new(&root);
+
+    domain::testing::blob_storage(&store).await;
+
+    // Keys never leave the directory
+    let blob = Blob {
+        content_type: "text/plain".to_owned(),
+        bytes: b"escape".to_vec(),
+    };
+    assert!(store.put_blob("../escape.txt", blob).await.is_err());
+    assert!(store.get_blob(".hidden").await.is_err());
+
+    std::fs::remove_dir_all(root).expect("Failed to clean up");
+}
+
+#[tokio::test]
+async fn test_s3_blob_storage() {
+    let container = GenericImage::new("minio/minio", "latest")
+        .with_exposed_port(ContainerPort::Tcp(9000))
+        .with_wait_for(WaitFor::message_on_stderr("API:"))
+        .with_cmd(["server", "/data"])
+        .start()
+        .await
+        .expect("Failed to start MinIO container");
+    container
+        .exec(
+            ExecCommand::new([
+                "sh",
+                "-c",
+                "mc alias set local http://localhost:9000 minioadmin minioadmin && mc mb local/uploads",
+            ])
+            .with_cmd_ready_condition(CmdWaitFor::exit_code(0)),
+        )
+        .await
+        .expect("Failed to create bucket");
+
+    let host = container.get_host().await.expect("Failed to get host");
+    let port = container
+        .get_host_port_ipv4(9000)
+        .await
+        .expect("Failed to get port");
+    let store = S3BlobStore::new(&S3Config {
+        bucket: "uploads".to_owned(),
+        region: "us-east-1".to_owned(),
+        endpoint: Some(format!("http://{host}:{port}")),
+        access_key_id: "minioadmin".to_owned(),
+        secret_access_key: "minioadmin".to_owned(),
+    })
+    .expect("Failed to configure S3 store");
+
+    domain::testing::blob_storage(&store).await;
+}
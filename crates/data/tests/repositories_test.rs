@@ -0,0 +1,237 @@
+//! Runs the shared repository scenarios from `domain::testing` against
+//! Postgres. One container is started and migrated for the whole run, and
+//! every scenario gets a database of its own cloned from the migrated one
+
+use data::{
+    PostgresArticlesRepository, PostgresCommentsRepository, PostgresReportsRepository, PostgresSeriesRepository,
+    PostgresTagsRepository, PostgresUsersRepository,
+};
+use deadpool_postgres::{Config as PoolConfig, Pool, Runtime};
+use testcontainers::{ContainerAsync, runners::AsyncRunner};
+use testcontainers_modules::postgres::Postgres;
+use tokio_postgres::NoTls;
+
+/// Database the migrations are applied to, used as the template for the
+/// scenario databases
+const MIGRATED: &str = "migrated";
+
+struct MigratedServer {
+    _container: ContainerAsync<Postgres>,
+    host: String,
+    port: u16,
+}
+
+impl MigratedServer {
+    async fn start() -> Self {
+        let container = Postgres::default()
+            .start()
+            .await
+            .expect("Failed to start Postgres container");
+        let host = container.get_host().await.expect("Failed to get host");
+        let port = container
+            .get_host_port_ipv4(5432)
+            .await
+            .expect("Failed to get port");
+        let server = Self {
+            _container: container,
+            host: host.to_string(),
+            port,
+        };
+
+        server.create_database(MIGRATED, "template0").await;
+
+        // Only the `migrate:up` half of each dbmate migration, oldest first
+        let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/migrations");
+        let mut migrations: Vec<_> = std::fs::read_dir(dir)
+            .expect("Failed to read migrations")
+            .map(|entry| entry.unwrap().path())
+            .collect();
+        migrations.sort();
+        let pool = server.pool(MIGRATED);
+        let client = pool.get().await.expect("Failed to connect to database");
+        for path in migrations {
+            let sql = std::fs::read_to_string(&path).unwrap();
+            let up = sql.split("-- migrate:down").next().unwrap();
+            client
+                .batch_execute(up)
+                .await
+                .unwrap_or_else(|e| panic!("Failed to apply {}: {e}", path.display()));
+        }
+        // A template can't be copied while anyone is connected to it
+        drop(client);
+        pool.close();
+
+        server
+    }
+
+    /// Fresh database with every migration applied
+    async fn database(&self, name: &str) -> Pool {
+        self.create_database(name, MIGRATED).await;
+        self.pool(name)
+    }
+
+    async fn create_database(&self, name: &str, template: &str) {
+        let pool = self.pool("postgres");
+        let client = pool.get().await.expect("Failed to connect to database");
+        client
+            .batch_execute(&format!(r#"CREATE DATABASE "{name}" TEMPLATE "{template}""#))
+            .await
+            .unwrap_or_else(|e| panic!("Failed to create database {name}: {e}"));
+    }
+
+    fn pool(&self, dbname: &str) -> Pool {
+        let mut config = PoolConfig::new();
+        config.host = Some(self.host.clone());
+        config.port = Some(self.port);
+        config.user = Some("postgres".to_string());
+        config.password = Some("postgres".to_string());
+        config.dbname = Some(dbname.to_string());
+        config
+            .create_pool(Some(Runtime::Tokio1), NoTls)
+            .expect("Failed to create pool")
+    }
+}
+
+/// The scenarios run concurrently, each against its own database; a failing
+/// one panics with the location of its assertion in `domain::testing`
+#[tokio::test]
+async fn test_postgres_repositories() {
+    let server = MigratedServer::start().await;
+
+    let pool = server.database("article_listing").await;
+    let article_listing = async move {
+        let users_repo = PostgresUsersRepository::new(pool.clone());
+        let articles_repo = PostgresArticlesRepository::new(pool);
+
+        domain::testing::article_listing(&users_repo, &articles_repo).await;
+    };
+
+    let pool = server.database("user_images").await;
+    let user_images = async move {
+        let users_repo = PostgresUsersRepository::new(pool);
+
+        domain::testing::user_images(&users_repo).await;
+    };
+
+    let pool = server.database("tag_merging").await;
+    let tag_merging = async move {
+        let users_repo = PostgresUsersRepository::new(pool.clone());
+        let articles_repo = PostgresArticlesRepository::new(pool.clone());
+        let tags_repo = PostgresTagsRepository::new(pool);
+
+        domain::testing::tag_merging(&users_repo, &articles_repo, &tags_repo).await;
+    };
+
+    let pool = server.database("followed_feed").await;
+    let followed_feed = async move {
+        let users_repo = PostgresUsersRepository::new(pool.clone());
+        let articles_repo = PostgresArticlesRepository::new(pool.clone());
+        let tags_repo = PostgresTagsRepository::new(pool);
+
+        domain::testing::followed_feed(&users_repo, &articles_repo, &tags_repo).await;
+    };
+
+    let pool = server.database("bookmarks").await;
+    let bookmarks = async move {
+        let users_repo = PostgresUsersRepository::new(pool.clone());
+        let articles_repo = PostgresArticlesRepository::new(pool);
+
+        domain::testing::bookmarks(&users_repo, &articles_repo).await;
+    };
+
+    let pool = server.database("related_articles").await;
+    let related_articles = async move {
+        let users_repo = PostgresUsersRepository::new(pool.clone());
+        let articles_repo = PostgresArticlesRepository::new(pool);
+
+        domain::testing::related_articles(&users_repo, &articles_repo).await;
+    };
+
+    let pool = server.database("sitemap_and_seo").await;
+    let sitemap_and_seo = async move {
+        let users_repo = PostgresUsersRepository::new(pool.clone());
+        let articles_repo = PostgresArticlesRepository::new(pool);
+
+        domain::testing::sitemap_and_seo(&users_repo, &articles_repo).await;
+    };
+
+    let pool = server.database("scheduled_publishing").await;
+    let scheduled_publishing = async move {
+        let users_repo = PostgresUsersRepository::new(pool.clone());
+        let articles_repo = PostgresArticlesRepository::new(pool.clone());
+        let tags_repo = PostgresTagsRepository::new(pool);
+
+        domain::testing::scheduled_publishing(&users_repo, &articles_repo, &tags_repo).await;
+    };
+
+    let pool = server.database("trash").await;
+    let trash = async move {
+        let users_repo = PostgresUsersRepository::new(pool.clone());
+        let articles_repo = PostgresArticlesRepository::new(pool.clone());
+        let comments_repo = PostgresCommentsRepository::new(pool);
+
+        domain::testing::trash(&users_repo, &articles_repo, &comments_repo).await;
+    };
+
+    let pool = server.database("erasure").await;
+    let erasure = async move {
+        let users_repo = PostgresUsersRepository::new(pool.clone());
+        let articles_repo = PostgresArticlesRepository::new(pool.clone());
+        let comments_repo = PostgresCommentsRepository::new(pool);
+
+        domain::testing::erasure(&users_repo, &articles_repo, &comments_repo).await;
+    };
+
+    let pool = server.database("reactions").await;
+    let reactions = async move {
+        let users_repo = PostgresUsersRepository::new(pool.clone());
+        let articles_repo = PostgresArticlesRepository::new(pool.clone());
+        let comments_repo = PostgresCommentsRepository::new(pool);
+
+        domain::testing::reactions(&users_repo, &articles_repo, &comments_repo).await;
+    };
+
+    let pool = server.database("coauthors").await;
+    let coauthors = async move {
+        let users_repo = PostgresUsersRepository::new(pool.clone());
+        let articles_repo = PostgresArticlesRepository::new(pool);
+
+        domain::testing::coauthors(&users_repo, &articles_repo).await;
+    };
+
+    let pool = server.database("series_parts").await;
+    let series_parts = async move {
+        let users_repo = PostgresUsersRepository::new(pool.clone());
+        let articles_repo = PostgresArticlesRepository::new(pool.clone());
+        let series_repo = PostgresSeriesRepository::new(pool);
+
+        domain::testing::series_parts(&users_repo, &articles_repo, &series_repo).await;
+    };
+
+    let pool = server.database("reports").await;
+    let reports = async move {
+        let users_repo = PostgresUsersRepository::new(pool.clone());
+        let articles_repo = PostgresArticlesRepository::new(pool.clone());
+        let comments_repo = PostgresCommentsRepository::new(pool.clone());
+        let reports_repo = PostgresReportsRepository::new(pool);
+
+        domain::testing::reports(&users_repo, &articles_repo, &comments_repo, &reports_repo).await;
+    };
+
+    tokio::join!(
+        article_listing,
+        user_images,
+        tag_merging,
+        followed_feed,
+        bookmarks,
+        related_articles,
+        sitemap_and_seo,
+        scheduled_publishing,
+        trash,
+        erasure,
+        reactions,
+        coauthors,
+        series_parts,
+        reports,
+    );
+}
@@ -4,8 +4,13 @@ use serde_with::skip_serializing_none;
 
 use crate::errors::{DomainError, DomainResult};
 use crate::identifiers::{ArticleId, UserId};
-use crate::pagination::Pagination;
+use crate::markdown::{RenderedBody, TocEntry};
+use crate::moderation::ModerationNotice;
+use crate::pagination::{Cursor, Pagination};
 use crate::profile::Profile;
+use crate::reaction::ReactionCount;
+use crate::seo::ArticleSeo;
+use crate::series::SeriesNavigation;
 use crate::tags::{Tag, TagList};
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -21,35 +26,118 @@ impl Slug {
         Ok(Self(trimmed))
     }
 
+    /// Slug for a title using the default [`SlugPolicy`]
     pub fn from_title(title: &str) -> DomainResult<Self> {
+        SlugPolicy::default().slugify(title)
+    }
+
+    /// The same slug with `-{suffix}` appended, used to tell apart articles
+    /// whose titles slugify identically
+    pub fn with_suffix(&self, suffix: &str) -> Self {
+        Self(format!("{}-{}", self.0, suffix))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<Slug> for String {
+    fn from(value: Slug) -> Self {
+        value.0
+    }
+}
+
+/// How titles are turned into slugs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SlugPolicy {
+    /// Longest slug derived from a title, before any collision suffix
+    pub max_len: usize,
+}
+
+impl SlugPolicy {
+    pub const DEFAULT_MAX_LEN: usize = 80;
+    /// Shortest accepted `max_len`; leaves room for a whole word or fallback id
+    pub const MIN_MAX_LEN: usize = 8;
+
+    pub fn new(max_len: usize) -> Self {
+        Self {
+            max_len: max_len.max(Self::MIN_MAX_LEN),
+        }
+    }
+
+    /// Transliterates the title to lowercase ASCII words joined by `-`
+    ///
+    /// Accented Latin, Greek, Cyrillic, CJK and most other scripts have an
+    /// ASCII reading. A title with nothing that can be read that way gets a
+    /// short id hashed from the title instead, so the same title always maps
+    /// to the same slug. Slugs longer than `max_len` are cut at the last word
+    /// boundary that fits.
+    pub fn slugify(&self, title: &str) -> DomainResult<Slug> {
+        let title = title.trim();
+        if title.is_empty() {
+            return Err(DomainError::InvalidSlug);
+        }
+
         let mut slug = String::new();
-        for ch in title.trim().chars() {
+        let mut push = |ch: char| {
             if ch.is_ascii_alphanumeric() {
                 slug.push(ch.to_ascii_lowercase());
-            } else if (ch.is_whitespace() || matches!(ch, '-' | '_' | ':'))
-                && !slug.ends_with('-')
-            {
+            } else if (ch.is_whitespace() || matches!(ch, '-' | '_' | ':')) && !slug.ends_with('-') {
                 slug.push('-');
             }
+        };
+        for ch in title.chars() {
+            if ch.is_ascii() {
+                push(ch);
+            } else {
+                deunicode::deunicode_char(ch)
+                    .unwrap_or_default()
+                    .chars()
+                    .for_each(&mut push);
+            }
         }
-        let slug = slug.trim_matches('-');
+
+        let slug = truncate_at_word(slug.trim_matches('-'), self.max_len);
         if slug.is_empty() {
-            return Err(DomainError::InvalidSlug);
+            return Ok(Slug(fallback_id(title)));
         }
-        Ok(Self(slug.to_owned()))
+        Ok(Slug(slug.to_owned()))
     }
+}
 
-    pub fn as_str(&self) -> &str {
-        &self.0
+impl Default for SlugPolicy {
+    fn default() -> Self {
+        Self {
+            max_len: Self::DEFAULT_MAX_LEN,
+        }
     }
 }
 
-impl From<Slug> for String {
-    fn from(value: Slug) -> Self {
-        value.0
+/// Cuts at the last `-` that keeps the slug within `max_len`, or mid-word
+/// when the first word alone is too long
+fn truncate_at_word(slug: &str, max_len: usize) -> &str {
+    if slug.len() <= max_len {
+        return slug;
+    }
+    // Slugs are ASCII by now, so any byte index is a char boundary
+    if slug.as_bytes()[max_len] == b'-' {
+        return &slug[..max_len];
+    }
+    match slug[..max_len].rfind('-') {
+        Some(boundary) => &slug[..boundary],
+        None => &slug[..max_len],
     }
 }
 
+/// Eight hex digits of the title's FNV-1a hash; stable across builds and runs
+fn fallback_id(title: &str) -> String {
+    let hash = title.bytes().fold(0x811c_9dc5_u32, |hash, byte| {
+        (hash ^ u32::from(byte)).wrapping_mul(0x0100_0193)
+    });
+    format!("{hash:08x}")
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ArticleDraft {
     pub title: String,
@@ -86,6 +174,47 @@ impl ArticleDraft {
     }
 }
 
+/// Where an article is in its publication lifecycle
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ArticleStatus {
+    /// Only the author can see it
+    Draft,
+    /// Goes live at `published_at`
+    Scheduled,
+    Published,
+}
+
+impl ArticleStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Draft => "draft",
+            Self::Scheduled => "scheduled",
+            Self::Published => "published",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "draft" => Some(Self::Draft),
+            "scheduled" => Some(Self::Scheduled),
+            "published" => Some(Self::Published),
+            _ => None,
+        }
+    }
+}
+
+/// When an article should go live
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Publication {
+    /// Keep it as a draft
+    Draft,
+    #[default]
+    Now,
+    /// Publish at the given time; a time already passed means now
+    At(DateTime<Utc>),
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Article {
     pub id: ArticleId,
@@ -94,10 +223,23 @@ pub struct Article {
     pub description: String,
     pub body: String,
     pub tag_list: TagList,
+    /// The owner: the only one who can delete the article or invite
+    /// co-authors
     pub author_id: UserId,
+    /// Co-authors who accepted their invitation, in the order they joined
+    #[serde(default)]
+    pub coauthor_ids: Vec<UserId>,
     pub favorites_count: u32,
+    pub status: ArticleStatus,
+    /// When the article went live, or is scheduled to; `None` for drafts
+    pub published_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub seo: ArticleSeo,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    /// Set when a moderator hid the article; only its authors see it since
+    #[serde(default)]
+    pub hidden_at: Option<DateTime<Utc>>,
 }
 
 impl Article {
@@ -116,12 +258,69 @@ impl Article {
             body: draft.body,
             tag_list: draft.tag_list,
             author_id,
+            coauthor_ids: Vec::new(),
             favorites_count: 0,
+            status: ArticleStatus::Published,
+            published_at: Some(now),
+            seo: ArticleSeo::default(),
             created_at: now,
             updated_at: now,
+            hidden_at: None,
         })
     }
 
+    /// Moves the article to the lifecycle stage `publication` asks for.
+    /// Publishing an article that is already live keeps its original
+    /// `published_at`.
+    pub fn set_publication(&mut self, publication: Publication, now: DateTime<Utc>) {
+        match publication {
+            Publication::Draft => {
+                self.status = ArticleStatus::Draft;
+                self.published_at = None;
+            }
+            Publication::At(at) if at > now => {
+                self.status = ArticleStatus::Scheduled;
+                self.published_at = Some(at);
+            }
+            Publication::Now | Publication::At(_) => {
+                if !self.is_published() {
+                    self.status = ArticleStatus::Published;
+                    self.published_at = Some(now);
+                }
+            }
+        }
+        self.updated_at = now;
+    }
+
+    pub fn is_published(&self) -> bool {
+        self.status == ArticleStatus::Published
+    }
+
+    pub fn is_hidden(&self) -> bool {
+        self.hidden_at.is_some()
+    }
+
+    /// Published and not hidden by a moderator
+    pub fn is_public(&self) -> bool {
+        self.is_published() && !self.is_hidden()
+    }
+
+    /// Public articles are seen by all; anything else only their authors see
+    pub fn is_visible_to(&self, viewer_id: Option<UserId>) -> bool {
+        self.is_public() || viewer_id.is_some_and(|viewer| self.can_edit(&viewer))
+    }
+
+    /// When the article went live, or is due to; drafts date from when they
+    /// were written. Lists run newest first by this
+    pub fn listed_at(&self) -> DateTime<Utc> {
+        self.published_at.unwrap_or(self.created_at)
+    }
+
+    /// A scheduled article whose publication time has come
+    pub fn is_due(&self, now: DateTime<Utc>) -> bool {
+        self.status == ArticleStatus::Scheduled && self.published_at.is_some_and(|at| at <= now)
+    }
+
     pub fn apply_changes(
         &mut self,
         changes: ArticleChanges,
@@ -149,6 +348,15 @@ impl Article {
         if let Some(tags) = changes.tag_list {
             self.tag_list = tags;
         }
+        if let Some(canonical_url) = changes.canonical_url {
+            self.seo.set_canonical_url(canonical_url)?;
+        }
+        if let Some(meta_description) = changes.meta_description {
+            self.seo.set_meta_description(meta_description)?;
+        }
+        if let Some(social_image) = changes.social_image {
+            self.seo.set_social_image(social_image)?;
+        }
         self.updated_at = now;
         Ok(())
     }
@@ -163,6 +371,7 @@ impl Article {
 
     pub fn to_view(&self, author: Profile, favorited: bool) -> ArticleView {
         ArticleView {
+            id: self.id,
             slug: self.slug.clone(),
             title: self.title.clone(),
             description: self.description.clone(),
@@ -172,7 +381,17 @@ impl Article {
             updated_at: self.updated_at,
             favorited,
             favorites_count: self.favorites_count,
+            status: self.status,
+            published_at: self.published_at,
+            seo: self.seo.clone(),
+            authors: vec![author.clone()],
             author,
+            bookmarked: false,
+            reactions: Vec::new(),
+            moderation: self.hidden_at.map(ModerationNotice::hidden),
+            body_html: None,
+            toc: None,
+            series: None,
         }
     }
 
@@ -186,14 +405,25 @@ impl Article {
             updated_at: self.updated_at,
             favorited,
             favorites_count: self.favorites_count,
+            status: self.status,
+            published_at: self.published_at,
             author,
+            bookmarked: false,
+            reactions: Vec::new(),
+            reason: None,
         }
     }
 
+    /// Whether `user_id` is the owner; co-authors don't count
     pub fn authored_by(&self, user_id: &UserId) -> bool {
         &self.author_id == user_id
     }
 
+    /// The owner and accepted co-authors can edit, publish and restore
+    pub fn can_edit(&self, user_id: &UserId) -> bool {
+        self.authored_by(user_id) || self.coauthor_ids.contains(user_id)
+    }
+
     /// Find article by slug in a collection
     pub fn find_by_slug<'a>(articles: &'a [Article], slug: &str) -> Option<&'a Article> {
         articles.iter().find(|a| a.slug.as_str() == slug)
@@ -229,10 +459,17 @@ pub struct ArticleChanges {
     pub description: Option<String>,
     pub body: Option<String>,
     pub tag_list: Option<TagList>,
+    /// `Some(None)` clears an SEO field, as does a blank value
+    pub canonical_url: Option<Option<String>>,
+    pub meta_description: Option<Option<String>>,
+    pub social_image: Option<Option<String>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ArticleView {
+    /// Kept out of responses; clients address articles by slug
+    #[serde(skip_serializing)]
+    pub id: ArticleId,
     pub slug: Slug,
     pub title: String,
     pub description: String,
@@ -246,7 +483,61 @@ pub struct ArticleView {
     pub favorited: bool,
     #[serde(rename = "favoritesCount")]
     pub favorites_count: u32,
+    pub status: ArticleStatus,
+    #[serde(rename = "publishedAt")]
+    pub published_at: Option<DateTime<Utc>>,
+    /// `canonicalUrl`, `metaDescription` and `socialImage`, null when unset
+    #[serde(flatten)]
+    pub seo: ArticleSeo,
     pub author: Profile,
+    /// The owner first, then accepted co-authors
+    pub authors: Vec<Profile>,
+    /// Whether the viewer saved the article to their private reading list
+    pub bookmarked: bool,
+    /// Every reaction received, most used first
+    #[serde(default)]
+    pub reactions: Vec<ReactionCount>,
+    /// Why the article is hidden, shown to its authors only
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub moderation: Option<ModerationNotice>,
+    /// Sanitized HTML of the body, only when the client asked for it
+    #[serde(rename = "bodyHtml", default, skip_serializing_if = "Option::is_none")]
+    pub body_html: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub toc: Option<Vec<TocEntry>>,
+    /// Where the article sits in its series, if it is part of one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub series: Option<SeriesNavigation>,
+}
+
+impl ArticleView {
+    /// Lists `coauthors` after the owner
+    pub fn with_coauthors(mut self, coauthors: Vec<Profile>) -> Self {
+        self.authors.truncate(1);
+        self.authors.extend(coauthors);
+        self
+    }
+
+    pub fn with_series(mut self, series: Option<SeriesNavigation>) -> Self {
+        self.series = series;
+        self
+    }
+
+    pub fn with_bookmarked(mut self, bookmarked: bool) -> Self {
+        self.bookmarked = bookmarked;
+        self
+    }
+
+    pub fn with_reactions(mut self, reactions: Vec<ReactionCount>) -> Self {
+        self.reactions = reactions;
+        self
+    }
+
+    pub fn with_rendered_body(mut self, rendered: &RenderedBody) -> Self {
+        self.body_html = Some(rendered.html.clone());
+        self.toc = Some(rendered.toc.clone());
+        self
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -263,7 +554,45 @@ pub struct ArticleSummary {
     pub favorited: bool,
     #[serde(rename = "favoritesCount")]
     pub favorites_count: u32,
+    pub status: ArticleStatus,
+    #[serde(rename = "publishedAt")]
+    pub published_at: Option<DateTime<Utc>>,
     pub author: Profile,
+    /// Whether the viewer saved the article to their private reading list
+    pub bookmarked: bool,
+    /// Every reaction received, most used first
+    #[serde(default)]
+    pub reactions: Vec<ReactionCount>,
+    /// Why the article is in the viewer's feed; only set on feed pages
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<FeedReason>,
+}
+
+impl ArticleSummary {
+    pub fn with_bookmarked(mut self, bookmarked: bool) -> Self {
+        self.bookmarked = bookmarked;
+        self
+    }
+
+    pub fn with_reactions(mut self, reactions: Vec<ReactionCount>) -> Self {
+        self.reactions = reactions;
+        self
+    }
+
+    pub fn with_reason(mut self, reason: FeedReason) -> Self {
+        self.reason = Some(reason);
+        self
+    }
+}
+
+/// What brought an article into the feed: its author, its tags, or both
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeedReason {
+    #[serde(rename = "followedAuthor")]
+    pub followed_author: bool,
+    /// Tags of the article the viewer follows
+    #[serde(rename = "followedTags")]
+    pub followed_tags: TagList,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -283,13 +612,67 @@ impl ArticleList {
     }
 }
 
+/// Order of article lists; ties fall back to newest first
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ArticleSort {
+    /// Newest first, by when articles went live (see [`Article::listed_at`])
+    #[default]
+    Recent,
+    /// Most recently edited first
+    Updated,
+    /// Most favorited first
+    Popular,
+    /// Most favorited within the trending window first
+    Trending,
+}
+
+impl ArticleSort {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ArticleSort::Recent => "recent",
+            ArticleSort::Updated => "updated",
+            ArticleSort::Popular => "popular",
+            ArticleSort::Trending => "trending",
+        }
+    }
+}
+
+/// Whether an article needs any or all of the requested tags
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TagMatch {
+    #[default]
+    Any,
+    All,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct ArticleFilters {
-    pub tag: Option<Tag>,
-    pub author: Option<String>,
+    pub tags: Vec<Tag>,
+    pub tag_match: TagMatch,
+    /// Articles with any of these tags are left out
+    pub excluded_tags: Vec<Tag>,
+    /// Usernames; articles by any of them match
+    pub authors: Vec<String>,
     pub favorited: Option<String>,
+    /// Created at or after
+    pub since: Option<DateTime<Utc>>,
+    /// Created before
+    pub until: Option<DateTime<Utc>>,
+    pub sort: ArticleSort,
+    /// Only favorites from then on count towards [`ArticleSort::Trending`];
+    /// all of them do when unset
+    #[serde(skip)]
+    pub trending_since: Option<DateTime<Utc>>,
     #[serde(flatten)]
     pub pagination: Pagination,
+    /// Who is looking; muted and blocking authors are left out for them
+    #[serde(skip)]
+    pub viewer: Option<UserId>,
+    /// Keyset position; when set, the offset is ignored
+    #[serde(skip)]
+    pub cursor: Option<Cursor>,
 }
 
 impl ArticleFilters {
@@ -299,31 +682,112 @@ impl ArticleFilters {
         favorited: Option<String>,
         pagination: Option<Pagination>,
     ) -> DomainResult<Self> {
-        let tag = match tag {
-            Some(value) => Some(Tag::new(value)?),
-            None => None,
+        let tags = match tag {
+            Some(value) => vec![Tag::new(value)?],
+            None => Vec::new(),
         };
         Ok(Self {
-            tag,
-            author,
+            tags,
+            authors: author.into_iter().collect(),
             favorited,
             pagination: pagination.unwrap_or_default(),
+            ..Default::default()
         })
     }
+
+    pub fn with_tags(mut self, tags: Vec<String>, tag_match: TagMatch) -> DomainResult<Self> {
+        self.tags = parse_tags(tags)?;
+        self.tag_match = tag_match;
+        Ok(self)
+    }
+
+    pub fn without_tags(mut self, tags: Vec<String>) -> DomainResult<Self> {
+        self.excluded_tags = parse_tags(tags)?;
+        Ok(self)
+    }
+
+    pub fn with_authors(mut self, authors: Vec<String>) -> Self {
+        self.authors = authors;
+        self
+    }
+
+    pub fn created_between(mut self, since: Option<DateTime<Utc>>, until: Option<DateTime<Utc>>) -> Self {
+        self.since = since;
+        self.until = until;
+        self
+    }
+
+    pub fn with_sort(mut self, sort: ArticleSort, trending_since: Option<DateTime<Utc>>) -> Self {
+        self.sort = sort;
+        self.trending_since = trending_since;
+        self
+    }
+
+    pub fn with_viewer(mut self, viewer: Option<UserId>) -> Self {
+        self.viewer = viewer;
+        self
+    }
+
+    /// Cursors are positions in the newest-first order, so they only page
+    /// [`ArticleSort::Recent`] lists
+    pub fn with_cursor(mut self, cursor: Option<Cursor>) -> DomainResult<Self> {
+        if cursor.is_some() && self.sort != ArticleSort::Recent {
+            return Err(DomainError::CursorRequiresRecentSort);
+        }
+        self.cursor = cursor;
+        Ok(self)
+    }
+
+    /// Whether an article passes every filter except visibility and
+    /// `favorited`, which need more than the article to decide
+    pub fn matches(&self, article: &Article, author_username: &str) -> bool {
+        let has = |tag: &Tag| article.tag_list.contains(tag);
+        let tags_match = match self.tag_match {
+            _ if self.tags.is_empty() => true,
+            TagMatch::Any => self.tags.iter().any(has),
+            TagMatch::All => self.tags.iter().all(has),
+        };
+        tags_match
+            && !self.excluded_tags.iter().any(has)
+            && (self.authors.is_empty() || self.authors.iter().any(|a| a == author_username))
+            && self.since.is_none_or(|since| article.created_at >= since)
+            && self.until.is_none_or(|until| article.created_at < until)
+    }
+}
+
+/// Trims, drops blanks and duplicates
+fn parse_tags(tags: Vec<String>) -> DomainResult<Vec<Tag>> {
+    let mut parsed: Vec<Tag> = Vec::new();
+    for value in tags.into_iter().filter(|t| !t.trim().is_empty()) {
+        let tag = Tag::new(value)?;
+        if !parsed.contains(&tag) {
+            parsed.push(tag);
+        }
+    }
+    Ok(parsed)
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct FeedFilters {
     #[serde(flatten)]
     pub pagination: Pagination,
+    /// Keyset position; when set, the offset is ignored
+    #[serde(skip)]
+    pub cursor: Option<Cursor>,
 }
 
 impl FeedFilters {
     pub fn new(pagination: Option<Pagination>) -> Self {
         Self {
             pagination: pagination.unwrap_or_default(),
+            cursor: None,
         }
     }
+
+    pub fn with_cursor(mut self, cursor: Option<Cursor>) -> Self {
+        self.cursor = cursor;
+        self
+    }
 }
 
 #[skip_serializing_none]
@@ -343,6 +807,12 @@ pub struct ArticlesEnvelope {
     pub articles: Vec<ArticleSummary>,
     #[serde(rename = "articlesCount")]
     pub articles_count: usize,
+    /// Where the following page starts, if there is one
+    #[serde(rename = "nextCursor", default, skip_serializing_if = "Option::is_none")]
+    pub next_cursor: Option<Cursor>,
+    /// Where the preceding page ends, if there is one
+    #[serde(rename = "prevCursor", default, skip_serializing_if = "Option::is_none")]
+    pub prev_cursor: Option<Cursor>,
 }
 
 impl From<Vec<ArticleSummary>> for ArticlesEnvelope {
@@ -350,6 +820,8 @@ impl From<Vec<ArticleSummary>> for ArticlesEnvelope {
         Self {
             articles_count: value.len(),
             articles: value,
+            next_cursor: None,
+            prev_cursor: None,
         }
     }
 }
@@ -390,6 +862,51 @@ mod tests {
         );
     }
 
+    #[test]
+    fn slug_transliterates_multilingual_titles() {
+        let corpus = [
+            ("Café Ünïcode", "cafe-unicode"),
+            ("Crème brûlée à la française", "creme-brulee-a-la-francaise"),
+            ("Straße über Köln", "strasse-uber-koln"),
+            ("Ελληνικά νέα", "ellenika-nea"),
+            ("Привет, мир!", "privet-mir"),
+            ("北京", "bei-jing"),
+            ("ひらがな", "hiragana"),
+            ("Rust: the_good parts", "rust-the-good-parts"),
+        ];
+        for (title, expected) in corpus {
+            assert_eq!(Slug::from_title(title).unwrap().as_str(), expected, "title: {title}");
+        }
+    }
+
+    #[test]
+    fn slug_falls_back_to_stable_id_without_readable_chars() {
+        let first = Slug::from_title("!!! ???").unwrap();
+        let second = Slug::from_title("!!! ???").unwrap();
+        assert_eq!(first, second);
+        assert_eq!(first.as_str().len(), 8);
+        assert!(first.as_str().chars().all(|c| c.is_ascii_hexdigit()));
+        assert_ne!(first, Slug::from_title("???").unwrap());
+    }
+
+    #[test]
+    fn slug_truncates_at_word_boundary() {
+        let policy = SlugPolicy::new(16);
+        let slug = policy.slugify("The quick brown fox jumps").unwrap();
+        assert_eq!(slug.as_str(), "the-quick-brown");
+
+        let slug = policy.slugify("Supercalifragilisticexpialidocious").unwrap();
+        assert_eq!(slug.as_str(), "supercalifragili");
+
+        assert_eq!(SlugPolicy::new(1).max_len, SlugPolicy::MIN_MAX_LEN);
+    }
+
+    #[test]
+    fn slug_with_suffix_appends_after_dash() {
+        let slug = Slug::from_title("Hello World").unwrap();
+        assert_eq!(slug.with_suffix("1a2b3c").as_str(), "hello-world-1a2b3c");
+    }
+
     #[test]
     fn publish_generates_slug_and_defaults_counters() {
         let draft = ArticleDraft::new(
@@ -413,6 +930,7 @@ mod tests {
             description: Some("new desc".into()),
             body: Some("new body".into()),
             tag_list: Some(TagList::new(["rust"]).unwrap()),
+            ..Default::default()
         };
         let future = article.created_at + chrono::Duration::minutes(10);
         article.apply_changes(changes, future).unwrap();
@@ -446,4 +964,30 @@ mod tests {
         assert_eq!(view.author.username.as_str(), author.username.as_str());
         assert!(view.favorited);
     }
+
+    #[test]
+    fn coauthors_edit_and_see_drafts_but_do_not_own() {
+        let draft = ArticleDraft::new("Title", "desc", "body", TagList::default()).unwrap();
+        let mut article = Article::publish(article_id(), user_id(), draft, now()).unwrap();
+        article.set_publication(Publication::Draft, now());
+        let coauthor = UserId::random();
+        assert!(!article.is_visible_to(Some(coauthor)));
+
+        article.coauthor_ids.push(coauthor);
+        assert!(article.can_edit(&coauthor));
+        assert!(article.is_visible_to(Some(coauthor)));
+        assert!(!article.authored_by(&coauthor));
+        assert!(!article.can_edit(&UserId::random()));
+    }
+
+    #[test]
+    fn view_lists_owner_before_coauthors() {
+        let draft = ArticleDraft::new("Title", "desc", "body", TagList::default()).unwrap();
+        let article = Article::publish(article_id(), user_id(), draft, now()).unwrap();
+        let view = article
+            .to_view(profile("alice"), false)
+            .with_coauthors(vec![profile("bob"), profile("carol")]);
+        let names: Vec<_> = view.authors.iter().map(|a| a.username.as_str()).collect();
+        assert_eq!(names, ["alice", "bob", "carol"]);
+    }
 }
@@ -0,0 +1,209 @@
+use std::fmt::Write;
+use std::io::Cursor;
+
+use image::{ImageFormat, Rgb, RgbImage};
+
+use crate::user::{ImageUrl, Username};
+
+/// Cells along each side of the pattern; the left half is mirrored
+const GRID: u32 = 5;
+/// Empty cells around the pattern, in cells
+const MARGIN: u32 = 1;
+/// Pixels per cell of the PNG rendering
+const PNG_CELL: u32 = 24;
+const BACKGROUND: [u8; 3] = [0xf0, 0xf0, 0xf0];
+/// Bump whenever the drawing changes, so cached avatars are fetched again
+const RENDER_VERSION: u32 = 1;
+
+/// Image formats a generated avatar is served in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AvatarFormat {
+    Svg,
+    Png,
+}
+
+impl AvatarFormat {
+    /// From the file name requested, `avatar.svg` or `avatar.png`
+    pub fn from_file_name(name: &str) -> Option<Self> {
+        match name {
+            "avatar.svg" => Some(Self::Svg),
+            "avatar.png" => Some(Self::Png),
+            _ => None,
+        }
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            Self::Svg => "image/svg+xml",
+            Self::Png => "image/png",
+        }
+    }
+}
+
+/// Identicon drawn from the username: a symmetric pattern of cells in one
+/// colour, so the same username always gets the same picture
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Avatar {
+    hash: u64,
+}
+
+impl Avatar {
+    pub fn new(username: &Username) -> Self {
+        Self {
+            hash: fnv1a(username.as_str()),
+        }
+    }
+
+    /// Where the generated avatar of `username` is served from
+    pub fn url(username: &Username) -> ImageUrl {
+        ImageUrl::new(format!(
+            "/api/profiles/{}/avatar.svg",
+            encode_path_segment(username.as_str())
+        ))
+        .expect("avatar url is never empty")
+    }
+
+    /// Quoted entity tag; it only changes with the username or the drawing
+    pub fn etag(&self) -> String {
+        format!("\"identicon-v{RENDER_VERSION}-{:016x}\"", self.hash)
+    }
+
+    pub fn render(&self, format: AvatarFormat) -> Vec<u8> {
+        match format {
+            AvatarFormat::Svg => self.svg().into_bytes(),
+            AvatarFormat::Png => self.png(),
+        }
+    }
+
+    pub fn svg(&self) -> String {
+        let size = GRID + 2 * MARGIN;
+        let [r, g, b] = self.colour();
+        let [br, bg, bb] = BACKGROUND;
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 {size} {size}\" shape-rendering=\"crispEdges\">\
+             <rect width=\"{size}\" height=\"{size}\" fill=\"#{br:02x}{bg:02x}{bb:02x}\"/>\
+             <g fill=\"#{r:02x}{g:02x}{b:02x}\">"
+        );
+        for (x, y) in self.cells() {
+            let _ = write!(svg, "<rect x=\"{}\" y=\"{}\" width=\"1\" height=\"1\"/>", x + MARGIN, y + MARGIN);
+        }
+        svg.push_str("</g></svg>");
+        svg
+    }
+
+    pub fn png(&self) -> Vec<u8> {
+        let side = (GRID + 2 * MARGIN) * PNG_CELL;
+        let mut image = RgbImage::from_pixel(side, side, Rgb(BACKGROUND));
+        let colour = Rgb(self.colour());
+        for (x, y) in self.cells() {
+            let (left, top) = ((x + MARGIN) * PNG_CELL, (y + MARGIN) * PNG_CELL);
+            for py in top..top + PNG_CELL {
+                for px in left..left + PNG_CELL {
+                    image.put_pixel(px, py, colour);
+                }
+            }
+        }
+        let mut bytes = Cursor::new(Vec::new());
+        image
+            .write_to(&mut bytes, ImageFormat::Png)
+            .expect("encoding an in-memory PNG cannot fail");
+        bytes.into_inner()
+    }
+
+    /// Filled cells as (column, row); the low 15 bits of the hash decide
+    /// the left three columns and the rest is their mirror image
+    fn cells(&self) -> impl Iterator<Item = (u32, u32)> + '_ {
+        let half = GRID.div_ceil(2);
+        (0..GRID).flat_map(move |y| {
+            (0..GRID).filter_map(move |x| {
+                let column = x.min(GRID - 1 - x);
+                let bit = y * half + column;
+                (self.hash >> bit & 1 == 1).then_some((x, y))
+            })
+        })
+    }
+
+    /// A hue taken from the high bits of the hash, at fixed saturation and
+    /// lightness so every avatar reads well on the light background
+    fn colour(&self) -> [u8; 3] {
+        let hue = (self.hash >> 32) % 360;
+        hsl_to_rgb(hue as f64, 0.55, 0.5)
+    }
+}
+
+/// FNV-1a, 64-bit; stable across builds and runs
+pub(crate) fn fnv1a(value: &str) -> u64 {
+    value.bytes().fold(0xcbf2_9ce4_8422_2325_u64, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+fn hsl_to_rgb(hue: f64, saturation: f64, lightness: f64) -> [u8; 3] {
+    let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+    let sector = hue / 60.0;
+    let x = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+    let (r, g, b) = match sector as u32 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    let m = lightness - chroma / 2.0;
+    [r, g, b].map(|channel| ((channel + m) * 255.0).round() as u8)
+}
+
+/// Percent-encodes everything but RFC 3986 unreserved characters
+pub(crate) fn encode_path_segment(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            encoded.push(byte as char);
+        } else {
+            let _ = write!(encoded, "%{byte:02X}");
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn avatar(name: &str) -> Avatar {
+        Avatar::new(&Username::new(name).unwrap())
+    }
+
+    #[test]
+    fn avatars_are_deterministic_per_username() {
+        assert_eq!(avatar("jake").svg(), avatar("jake").svg());
+        assert_eq!(avatar("jake").etag(), avatar("jake").etag());
+        assert_ne!(avatar("jake").etag(), avatar("jane").etag());
+        assert_ne!(avatar("jake").svg(), avatar("jane").svg());
+    }
+
+    #[test]
+    fn pattern_is_mirrored() {
+        let avatar = avatar("symmetry");
+        let cells: Vec<_> = avatar.cells().collect();
+        for (x, y) in &cells {
+            assert!(cells.contains(&(GRID - 1 - x, *y)));
+        }
+    }
+
+    #[test]
+    fn png_rendering_decodes() {
+        let png = avatar("jake").png();
+        let decoded = image::load_from_memory(&png).unwrap();
+        assert_eq!(decoded.width(), (GRID + 2 * MARGIN) * PNG_CELL);
+    }
+
+    #[test]
+    fn url_escapes_the_username() {
+        let url = Avatar::url(&Username::new("jake doe/2").unwrap());
+        assert_eq!(url.as_str(), "/api/profiles/jake%20doe%2F2/avatar.svg");
+        assert_eq!(AvatarFormat::from_file_name("avatar.png"), Some(AvatarFormat::Png));
+        assert_eq!(AvatarFormat::from_file_name("avatar.gif"), None);
+    }
+}
@@ -0,0 +1,132 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::article::ArticleSummary;
+use crate::errors::{DomainError, DomainResult};
+use crate::identifiers::{ArticleId, UserId};
+use crate::pagination::Pagination;
+
+/// Longest bookmark note, in characters
+pub const MAX_BOOKMARK_NOTE_LEN: usize = 2_000;
+/// Longest bookmark folder name, in characters
+pub const MAX_BOOKMARK_FOLDER_LEN: usize = 64;
+
+/// An article saved to a user's private reading list
+///
+/// Unlike favorites, bookmarks are only ever shown to the user who made them
+/// and never count towards anything public.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Bookmark {
+    pub user_id: UserId,
+    pub article_id: ArticleId,
+    pub note: Option<String>,
+    pub folder: Option<String>,
+    #[serde(rename = "createdAt")]
+    pub created_at: DateTime<Utc>,
+}
+
+impl Bookmark {
+    /// Blank notes and folders count as none; both are trimmed
+    pub fn new(
+        user_id: UserId,
+        article_id: ArticleId,
+        note: Option<String>,
+        folder: Option<String>,
+        now: DateTime<Utc>,
+    ) -> DomainResult<Self> {
+        let note = non_blank(note);
+        if note.as_ref().is_some_and(|n| n.chars().count() > MAX_BOOKMARK_NOTE_LEN) {
+            return Err(DomainError::BookmarkNoteTooLong {
+                max: MAX_BOOKMARK_NOTE_LEN,
+            });
+        }
+        let folder = non_blank(folder);
+        if folder.as_ref().is_some_and(|f| f.chars().count() > MAX_BOOKMARK_FOLDER_LEN) {
+            return Err(DomainError::BookmarkFolderTooLong {
+                max: MAX_BOOKMARK_FOLDER_LEN,
+            });
+        }
+        Ok(Self {
+            user_id,
+            article_id,
+            note,
+            folder,
+            created_at: now,
+        })
+    }
+
+    pub fn to_view(&self, article: ArticleSummary) -> BookmarkView {
+        BookmarkView {
+            note: self.note.clone(),
+            folder: self.folder.clone(),
+            created_at: self.created_at,
+            article,
+        }
+    }
+}
+
+fn non_blank(value: Option<String>) -> Option<String> {
+    value
+        .map(|v| v.trim().to_owned())
+        .filter(|v| !v.is_empty())
+}
+
+/// Which of a user's bookmarks to list
+#[derive(Debug, Clone, Default)]
+pub struct BookmarkFilters {
+    /// Only bookmarks filed in this folder
+    pub folder: Option<String>,
+    pub pagination: Pagination,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BookmarkView {
+    pub note: Option<String>,
+    pub folder: Option<String>,
+    #[serde(rename = "createdAt")]
+    pub created_at: DateTime<Utc>,
+    pub article: ArticleSummary,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BookmarksEnvelope {
+    pub bookmarks: Vec<BookmarkView>,
+    #[serde(rename = "bookmarksCount")]
+    pub bookmarks_count: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bookmark(note: Option<&str>, folder: Option<&str>) -> DomainResult<Bookmark> {
+        Bookmark::new(
+            UserId::random(),
+            ArticleId::random(),
+            note.map(str::to_owned),
+            folder.map(str::to_owned),
+            Utc::now(),
+        )
+    }
+
+    #[test]
+    fn bookmark_drops_blank_note_and_folder() {
+        let bookmark = bookmark(Some("  "), Some(" later ")).unwrap();
+        assert_eq!(bookmark.note, None);
+        assert_eq!(bookmark.folder.as_deref(), Some("later"));
+    }
+
+    #[test]
+    fn bookmark_rejects_overlong_fields() {
+        let note = "a".repeat(MAX_BOOKMARK_NOTE_LEN + 1);
+        assert_eq!(
+            bookmark(Some(&note), None).unwrap_err(),
+            DomainError::BookmarkNoteTooLong { max: MAX_BOOKMARK_NOTE_LEN }
+        );
+        let folder = "a".repeat(MAX_BOOKMARK_FOLDER_LEN + 1);
+        assert_eq!(
+            bookmark(None, Some(&folder)).unwrap_err(),
+            DomainError::BookmarkFolderTooLong { max: MAX_BOOKMARK_FOLDER_LEN }
+        );
+    }
+}
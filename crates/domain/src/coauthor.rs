@@ -0,0 +1,35 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::identifiers::{ArticleId, UserId};
+
+/// A user the owner of an article invited to write it with them
+///
+/// The invitation counts once accepted: from then on the co-author can do
+/// anything with the article its owner can, except delete it or change who
+/// else co-authors it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Coauthor {
+    pub article_id: ArticleId,
+    pub user_id: UserId,
+    #[serde(rename = "invitedAt")]
+    pub invited_at: DateTime<Utc>,
+    /// `None` while the invitation is pending
+    #[serde(rename = "acceptedAt")]
+    pub accepted_at: Option<DateTime<Utc>>,
+}
+
+impl Coauthor {
+    pub fn invite(article_id: ArticleId, user_id: UserId, now: DateTime<Utc>) -> Self {
+        Self {
+            article_id,
+            user_id,
+            invited_at: now,
+            accepted_at: None,
+        }
+    }
+
+    pub fn is_accepted(&self) -> bool {
+        self.accepted_at.is_some()
+    }
+}
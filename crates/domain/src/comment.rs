@@ -3,7 +3,9 @@ use serde::{Deserialize, Serialize};
 
 use crate::errors::{DomainError, DomainResult};
 use crate::identifiers::{ArticleId, CommentId, UserId};
+use crate::moderation::ModerationNotice;
 use crate::profile::Profile;
+use crate::reaction::ReactionCount;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CommentDraft {
@@ -30,6 +32,9 @@ pub struct Comment {
     pub created_at: DateTime<Utc>,
     #[serde(rename = "updatedAt")]
     pub updated_at: DateTime<Utc>,
+    /// Set when a moderator hid the comment; only its author sees it since
+    #[serde(rename = "hiddenAt", default)]
+    pub hidden_at: Option<DateTime<Utc>>,
 }
 
 impl Comment {
@@ -47,9 +52,15 @@ impl Comment {
             body: draft.body,
             created_at: now,
             updated_at: now,
+            hidden_at: None,
         }
     }
 
+    /// Hidden comments are only seen by their author
+    pub fn is_visible_to(&self, viewer_id: Option<UserId>) -> bool {
+        self.hidden_at.is_none() || viewer_id == Some(self.author_id)
+    }
+
     pub fn to_view(&self, author: Profile) -> CommentView {
         CommentView {
             id: self.id,
@@ -57,6 +68,8 @@ impl Comment {
             updated_at: self.updated_at,
             body: self.body.clone(),
             author,
+            reactions: Vec::new(),
+            moderation: self.hidden_at.map(ModerationNotice::hidden),
         }
     }
 }
@@ -70,6 +83,19 @@ pub struct CommentView {
     pub updated_at: DateTime<Utc>,
     pub body: String,
     pub author: Profile,
+    /// Every reaction received, most used first
+    #[serde(default)]
+    pub reactions: Vec<ReactionCount>,
+    /// Why the comment is hidden, shown to its author only
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub moderation: Option<ModerationNotice>,
+}
+
+impl CommentView {
+    pub fn with_reactions(mut self, reactions: Vec<ReactionCount>) -> Self {
+        self.reactions = reactions;
+        self
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -0,0 +1,304 @@
+//! Text diffs between article revisions
+//!
+//! Myers' O(ND) algorithm over lines (unified diffs) or words (word diffs).
+
+use serde::{Deserialize, Serialize};
+
+/// Edit scripts longer than this are reported as a whole replacement; it
+/// bounds the memory the search needs to O(MAX_EDITS²)
+const MAX_EDITS: usize = 2000;
+
+/// Lines of unchanged text kept around each change in a unified diff
+pub const UNIFIED_CONTEXT: usize = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DiffOp {
+    Equal,
+    Delete,
+    Insert,
+}
+
+/// A run of words that was kept, removed or added
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WordChange {
+    pub op: DiffOp,
+    pub text: String,
+}
+
+/// One step of an edit script with the token's index in the old and new text
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Edit {
+    op: DiffOp,
+    old: usize,
+    new: usize,
+}
+
+/// Unified diff of `old` and `new` under `---`/`+++` headers naming them;
+/// empty when the texts are equal
+pub fn unified_diff(old: &str, new: &str, old_label: &str, new_label: &str) -> String {
+    let old_lines: Vec<&str> = old.lines().collect();
+    let new_lines: Vec<&str> = new.lines().collect();
+    let edits = diff(&old_lines, &new_lines);
+    if edits.iter().all(|e| e.op == DiffOp::Equal) {
+        return String::new();
+    }
+
+    let mut out = format!("--- {old_label}\n+++ {new_label}\n");
+    for hunk in hunks(&edits, UNIFIED_CONTEXT) {
+        let hunk = &edits[hunk];
+        let old_count = hunk.iter().filter(|e| e.op != DiffOp::Insert).count();
+        let new_count = hunk.iter().filter(|e| e.op != DiffOp::Delete).count();
+        // Line numbers are 1-based, except that an empty range points at the
+        // line before it
+        let old_start = hunk[0].old + usize::from(old_count > 0);
+        let new_start = hunk[0].new + usize::from(new_count > 0);
+        out.push_str(&format!("@@ -{old_start},{old_count} +{new_start},{new_count} @@\n"));
+        for edit in hunk {
+            let (marker, line) = match edit.op {
+                DiffOp::Equal => (' ', old_lines[edit.old]),
+                DiffOp::Delete => ('-', old_lines[edit.old]),
+                DiffOp::Insert => ('+', new_lines[edit.new]),
+            };
+            out.push(marker);
+            out.push_str(line);
+            out.push('\n');
+        }
+    }
+    out
+}
+
+/// Word-level diff; concatenating the equal and inserted runs gives `new`,
+/// the equal and deleted runs give `old`
+pub fn word_diff(old: &str, new: &str) -> Vec<WordChange> {
+    let old_words = words(old);
+    let new_words = words(new);
+    let mut changes: Vec<WordChange> = Vec::new();
+    for edit in diff(&old_words, &new_words) {
+        let word = match edit.op {
+            DiffOp::Insert => new_words[edit.new],
+            _ => old_words[edit.old],
+        };
+        match changes.last_mut() {
+            Some(last) if last.op == edit.op => last.text.push_str(word),
+            _ => changes.push(WordChange {
+                op: edit.op,
+                text: word.to_owned(),
+            }),
+        }
+    }
+    changes
+}
+
+/// Splits into alternating runs of whitespace and non-whitespace so that no
+/// character is lost
+fn words(text: &str) -> Vec<&str> {
+    let mut out = Vec::new();
+    let mut start = 0;
+    let mut in_space = None;
+    for (i, ch) in text.char_indices() {
+        let space = ch.is_whitespace();
+        if in_space.is_some_and(|s| s != space) {
+            out.push(&text[start..i]);
+            start = i;
+        }
+        in_space = Some(space);
+    }
+    if start < text.len() {
+        out.push(&text[start..]);
+    }
+    out
+}
+
+/// Ranges of `edits` to print as hunks: each change with `context` equal
+/// edits around it, overlapping ranges merged
+fn hunks(edits: &[Edit], context: usize) -> Vec<std::ops::Range<usize>> {
+    let mut out: Vec<std::ops::Range<usize>> = Vec::new();
+    for (i, _) in edits.iter().enumerate().filter(|(_, e)| e.op != DiffOp::Equal) {
+        let start = i.saturating_sub(context);
+        let end = (i + context + 1).min(edits.len());
+        match out.last_mut() {
+            Some(last) if start <= last.end => last.end = end,
+            _ => out.push(start..end),
+        }
+    }
+    out
+}
+
+/// Shortest edit script turning `old` into `new`
+fn diff<T: PartialEq>(old: &[T], new: &[T]) -> Vec<Edit> {
+    // Common prefix and suffix need no search
+    let prefix = old.iter().zip(new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let mut edits: Vec<Edit> = (0..prefix)
+        .map(|i| Edit { op: DiffOp::Equal, old: i, new: i })
+        .collect();
+    let middle_old = &old[prefix..old.len() - suffix];
+    let middle_new = &new[prefix..new.len() - suffix];
+    let middle = myers(middle_old, middle_new).unwrap_or_else(|| replace_all(middle_old.len(), middle_new.len()));
+    edits.extend(middle.into_iter().map(|e| Edit {
+        op: e.op,
+        old: e.old + prefix,
+        new: e.new + prefix,
+    }));
+    let (old_tail, new_tail) = (old.len() - suffix, new.len() - suffix);
+    edits.extend((0..suffix).map(|i| Edit {
+        op: DiffOp::Equal,
+        old: old_tail + i,
+        new: new_tail + i,
+    }));
+    edits
+}
+
+fn replace_all(old_len: usize, new_len: usize) -> Vec<Edit> {
+    let deletes = (0..old_len).map(|i| Edit { op: DiffOp::Delete, old: i, new: 0 });
+    let inserts = (0..new_len).map(|i| Edit { op: DiffOp::Insert, old: old_len, new: i });
+    deletes.chain(inserts).collect()
+}
+
+/// Myers' greedy search; `None` when more than `MAX_EDITS` edits are needed
+fn myers<T: PartialEq>(old: &[T], new: &[T]) -> Option<Vec<Edit>> {
+    let (n, m) = (old.len() as isize, new.len() as isize);
+    let max = (old.len() + new.len()).min(MAX_EDITS) as isize;
+    // v[k + offset] is the furthest x reached on diagonal k = x - y
+    let offset = max + 1;
+    let mut v = vec![0isize; 2 * offset as usize + 1];
+    // trace[d] holds diagonals -d..=d of v as they were before round d
+    let mut trace: Vec<Vec<isize>> = Vec::new();
+
+    let mut found = false;
+    'search: for d in 0..=max {
+        trace.push(v[(offset - d) as usize..=(offset + d) as usize].to_vec());
+        for k in (-d..=d).step_by(2) {
+            let at = |k: isize| v[(k + offset) as usize];
+            let mut x = if k == -d || (k != d && at(k - 1) < at(k + 1)) {
+                at(k + 1)
+            } else {
+                at(k - 1) + 1
+            };
+            let mut y = x - k;
+            while x < n && y < m && old[x as usize] == new[y as usize] {
+                x += 1;
+                y += 1;
+            }
+            v[(k + offset) as usize] = x;
+            if x >= n && y >= m {
+                found = true;
+                break 'search;
+            }
+        }
+    }
+    if !found {
+        return None;
+    }
+
+    let mut edits = Vec::new();
+    let (mut x, mut y) = (n, m);
+    for (d, row) in trace.iter().enumerate().rev() {
+        let d = d as isize;
+        let at = |k: isize| row[(k + d) as usize];
+        let k = x - y;
+        let prev_k = if k == -d || (k != d && at(k - 1) < at(k + 1)) {
+            k + 1
+        } else {
+            k - 1
+        };
+        let prev_x = if d == 0 { 0 } else { at(prev_k) };
+        let prev_y = prev_x - prev_k;
+        while x > prev_x && y > prev_y {
+            x -= 1;
+            y -= 1;
+            edits.push(Edit { op: DiffOp::Equal, old: x as usize, new: y as usize });
+        }
+        if d > 0 {
+            if x == prev_x {
+                y -= 1;
+                edits.push(Edit { op: DiffOp::Insert, old: x as usize, new: y as usize });
+            } else {
+                x -= 1;
+                edits.push(Edit { op: DiffOp::Delete, old: x as usize, new: y as usize });
+            }
+        }
+    }
+    edits.reverse();
+    Some(edits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn apply(old: &[&str], new: &[&str], edits: &[Edit]) -> (Vec<String>, Vec<String>) {
+        let mut rebuilt_old = Vec::new();
+        let mut rebuilt_new = Vec::new();
+        for e in edits {
+            match e.op {
+                DiffOp::Equal => {
+                    assert_eq!(old[e.old], new[e.new]);
+                    rebuilt_old.push(old[e.old].to_owned());
+                    rebuilt_new.push(new[e.new].to_owned());
+                }
+                DiffOp::Delete => rebuilt_old.push(old[e.old].to_owned()),
+                DiffOp::Insert => rebuilt_new.push(new[e.new].to_owned()),
+            }
+        }
+        (rebuilt_old, rebuilt_new)
+    }
+
+    #[test]
+    fn diff_is_minimal_and_reconstructs_both_sides() {
+        let old = ["a", "b", "c", "a", "b", "b", "a"];
+        let new = ["c", "b", "a", "b", "a", "c"];
+        let edits = diff(&old, &new);
+        let (o, n) = apply(&old, &new, &edits);
+        assert_eq!(o, old);
+        assert_eq!(n, new);
+        assert_eq!(edits.iter().filter(|e| e.op != DiffOp::Equal).count(), 5);
+    }
+
+    #[test]
+    fn diff_handles_empty_sides() {
+        let edits = diff::<&str>(&[], &["x", "y"]);
+        assert!(edits.iter().all(|e| e.op == DiffOp::Insert));
+        let edits = diff::<&str>(&["x"], &[]);
+        assert_eq!(edits, vec![Edit { op: DiffOp::Delete, old: 0, new: 0 }]);
+    }
+
+    #[test]
+    fn unified_diff_formats_hunks() {
+        let old = "one\ntwo\nthree\nfour\nfive\nsix\nseven\neight\nnine\nten\n";
+        let new = "one\ntwo\n3\nfour\nfive\nsix\nseven\neight\nnine\nten\neleven\n";
+        let out = unified_diff(old, new, "a", "b");
+        assert_eq!(
+            out,
+            "--- a\n+++ b\n\
+             @@ -1,6 +1,6 @@\n one\n two\n-three\n+3\n four\n five\n six\n\
+             @@ -8,3 +8,4 @@\n eight\n nine\n ten\n+eleven\n"
+        );
+        assert!(unified_diff(old, old, "a", "b").is_empty());
+    }
+
+    #[test]
+    fn word_diff_keeps_whitespace() {
+        let changes = word_diff("the quick fox", "the slow brown fox");
+        let new: String = changes
+            .iter()
+            .filter(|c| c.op != DiffOp::Delete)
+            .map(|c| c.text.as_str())
+            .collect();
+        let old: String = changes
+            .iter()
+            .filter(|c| c.op != DiffOp::Insert)
+            .map(|c| c.text.as_str())
+            .collect();
+        assert_eq!(new, "the slow brown fox");
+        assert_eq!(old, "the quick fox");
+        assert!(changes.contains(&WordChange { op: DiffOp::Delete, text: "quick".into() }));
+    }
+}
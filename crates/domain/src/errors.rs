@@ -22,16 +22,68 @@ pub enum DomainError {
     InvalidSlug,
     #[error("tag must not be empty")]
     InvalidTag,
+    #[error("tag must be at most {max} characters")]
+    TagTooLong { max: usize },
+    #[error("a tag cannot be merged into itself")]
+    TagMergedIntoItself,
+    #[error("canonical url must be an absolute http or https URL of at most {max} characters")]
+    InvalidCanonicalUrl { max: usize },
+    #[error("meta description must be at most {max} characters")]
+    MetaDescriptionTooLong { max: usize },
+    #[error("social image must be an http or https URL or a path on this site, of at most {max} characters")]
+    InvalidSocialImage { max: usize },
+    #[error("bookmark note must be at most {max} characters")]
+    BookmarkNoteTooLong { max: usize },
+    #[error("bookmark folder must be at most {max} characters")]
+    BookmarkFolderTooLong { max: usize },
+    #[error("a series can hold at most {max} articles")]
+    TooManySeriesParts { max: usize },
+    #[error("an article can only appear once in a series")]
+    DuplicateSeriesPart,
+    #[error("a series only takes its author's articles and a collection only published ones")]
+    SeriesPartNotAllowed,
+    #[error("the article is already part of another series")]
+    ArticleInAnotherSeries,
+    #[error("the owner of an article cannot also be its co-author")]
+    CoauthorIsOwner,
+    #[error("upload must be at most {max} bytes")]
+    UploadTooLarge { max: usize },
+    #[error("only PNG, JPEG, GIF and WebP images and PDF documents can be uploaded")]
+    UnsupportedUpload,
+    #[error("image could not be decoded")]
+    InvalidImage,
     #[error("limit must be between 1 and 50")]
     LimitOutOfRange,
     #[error("offset cannot be negative")]
     NegativeOffset,
+    #[error("cursor is malformed")]
+    InvalidCursor,
+    #[error("cursors can only page articles sorted by recent")]
+    CursorRequiresRecentSort,
+    #[error("search query must contain at least one word")]
+    InvalidSearchQuery,
+    #[error("unknown reaction")]
+    UnknownReaction,
+    #[error("report details must be at most {max} characters")]
+    ReportDetailsTooLong { max: usize },
+    #[error("you cannot report yourself or your own content")]
+    SelfReport,
+    #[error("only articles and comments can be hidden")]
+    NothingToHide,
+    #[error("the report is already closed")]
+    ReportClosed,
+    #[error("the report is claimed by another moderator")]
+    ReportClaimed,
     #[error("requested entity `{entity}` was not found")]
     NotFound { entity: &'static str },
     #[error("`{entity}` already exists")]
     Conflict { entity: &'static str },
     #[error("operation is not allowed for the current user")]
     UnauthorizedAction,
+    #[error("the other user has blocked you")]
+    Blocked,
+    #[error("the account is suspended")]
+    Suspended,
     #[error("database error: {message}")]
     Database { message: String },
 }
@@ -0,0 +1,61 @@
+//! Domain events
+//!
+//! Use cases return the events they produce; publishing them is left to the
+//! caller so the domain stays free of messaging concerns.
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::identifiers::{ArticleId, CommentId, ReportId, UserId};
+use crate::moderation::{ModerationAction, ReportStatus};
+use crate::user::AuthoredContentPolicy;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum DomainEvent {
+    AccountDeactivated {
+        user_id: UserId,
+        purge_after: DateTime<Utc>,
+    },
+    AccountRestored {
+        user_id: UserId,
+    },
+    AccountDeleted {
+        user_id: UserId,
+        authored_content: AuthoredContentPolicy,
+    },
+    /// The account was left in place, to be retried on the next purge
+    AccountErasureFailed {
+        user_id: UserId,
+        message: String,
+    },
+    ArticlePublished {
+        article_id: ArticleId,
+        author_id: UserId,
+        published_at: DateTime<Utc>,
+    },
+    /// Erased from the trash once its retention period ran out
+    ArticlePurged {
+        article_id: ArticleId,
+    },
+    CommentPurged {
+        comment_id: CommentId,
+    },
+    /// A moderator suspended the account
+    AccountSuspended {
+        user_id: UserId,
+    },
+    /// A moderator hid the reported content or suspended its author; the
+    /// report stays claimed until it is resolved
+    ReportActioned {
+        report_id: ReportId,
+        reporter_id: UserId,
+        action: ModerationAction,
+    },
+    /// A moderator resolved or dismissed a report
+    ReportClosed {
+        report_id: ReportId,
+        reporter_id: UserId,
+        status: ReportStatus,
+    },
+}
@@ -0,0 +1,413 @@
+//! Syndication feeds of published articles, in Atom 1.0 and RSS 2.0
+//!
+//! Feed readers need absolute links, so every URL is built on `SiteUrls`.
+//! Entries link to the article page of the web app and are identified by
+//! that link.
+
+use atom_syndication as atom;
+use chrono::{DateTime, Utc};
+
+use crate::{
+    ArticleId, ArticleSummary, MAX_LIMIT, SiteUrls,
+    avatar::{encode_path_segment, fnv1a},
+};
+
+/// Articles in a feed unless another count is asked for
+pub const DEFAULT_FEED_ENTRIES: u32 = 20;
+
+/// Formats a feed is served in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FeedFormat {
+    Atom,
+    Rss,
+}
+
+impl FeedFormat {
+    /// Splits a requested file name such as `rust.atom` into its stem and format
+    pub fn split_file_name(name: &str) -> Option<(&str, Self)> {
+        let (stem, extension) = name.rsplit_once('.')?;
+        let format = match extension {
+            "atom" => Self::Atom,
+            "rss" => Self::Rss,
+            _ => return None,
+        };
+        (!stem.is_empty()).then_some((stem, format))
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Atom => "atom",
+            Self::Rss => "rss",
+        }
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            Self::Atom => "application/atom+xml; charset=utf-8",
+            Self::Rss => "application/rss+xml; charset=utf-8",
+        }
+    }
+}
+
+/// What feeds are called and how long they are
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeedPolicy {
+    /// Title of the global feed; the others add their tag or author to it
+    pub title: String,
+    /// Articles in a feed unless the request asks for another count, within
+    /// the article list bounds
+    pub entries: u32,
+}
+
+impl Default for FeedPolicy {
+    fn default() -> Self {
+        Self::new("Conduit", DEFAULT_FEED_ENTRIES)
+    }
+}
+
+impl FeedPolicy {
+    pub fn new(title: impl Into<String>, entries: u32) -> Self {
+        Self {
+            title: title.into(),
+            entries: entries.clamp(1, MAX_LIMIT),
+        }
+    }
+}
+
+/// Which articles a feed follows
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FeedScope {
+    All,
+    Tag(String),
+    Author(String),
+}
+
+impl FeedScope {
+    fn title(&self, policy: &FeedPolicy) -> String {
+        match self {
+            Self::All => policy.title.clone(),
+            Self::Tag(tag) => format!("{}: #{tag}", policy.title),
+            Self::Author(username) => format!("{}: {username}", policy.title),
+        }
+    }
+
+    fn subtitle(&self) -> String {
+        match self {
+            Self::All => "The latest articles".to_owned(),
+            Self::Tag(tag) => format!("The latest articles tagged {tag}"),
+            Self::Author(username) => format!("The latest articles by {username}"),
+        }
+    }
+
+    /// The web app page showing the same articles
+    fn page_url(&self, site: &SiteUrls) -> String {
+        match self {
+            Self::All => site.home_url(),
+            Self::Tag(tag) => site.tag_url(tag),
+            Self::Author(username) => site.profile_url(username),
+        }
+    }
+
+    fn feed_url(&self, site: &SiteUrls, format: FeedFormat) -> String {
+        let extension = format.extension();
+        match self {
+            Self::All => format!("{}/feeds/articles.{extension}", site.base_url),
+            Self::Tag(tag) => format!("{}/feeds/tags/{}.{extension}", site.base_url, encode_path_segment(tag)),
+            Self::Author(username) => {
+                format!("{}/feeds/authors/{}.{extension}", site.base_url, encode_path_segment(username))
+            }
+        }
+    }
+}
+
+/// An article in a feed, with its body when the feed carries full content
+#[derive(Debug, Clone)]
+pub struct FeedEntry {
+    pub article: ArticleSummary,
+    /// The article's id and Markdown body, for the caller to render into
+    /// `content_html` (through whatever cache it keeps)
+    pub body: Option<(ArticleId, String)>,
+    pub content_html: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct ArticleFeed {
+    pub scope: FeedScope,
+    /// Newest first
+    pub entries: Vec<FeedEntry>,
+}
+
+/// A feed document with the validator conditional requests are checked
+/// against
+///
+/// There is no modification date to check against: an entry dropping out of
+/// the feed leaves every remaining date as it was.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderedFeed {
+    pub xml: String,
+    /// Quoted entity tag derived from the document, so from every entry in it
+    pub etag: String,
+}
+
+impl ArticleFeed {
+    /// When an entry last changed or went live; the Unix epoch for an empty
+    /// feed, so its date stays put until an article shows up
+    pub fn updated(&self) -> DateTime<Utc> {
+        self.entries
+            .iter()
+            .map(|entry| updated(&entry.article))
+            .max()
+            .unwrap_or(DateTime::UNIX_EPOCH)
+    }
+
+    pub fn render(&self, format: FeedFormat, site: &SiteUrls, policy: &FeedPolicy) -> RenderedFeed {
+        let xml = match format {
+            FeedFormat::Atom => self.atom(site, policy).to_string(),
+            FeedFormat::Rss => self.rss(site, policy).to_string(),
+        };
+        RenderedFeed {
+            etag: format!("\"feed-{:016x}\"", fnv1a(&xml)),
+            xml,
+        }
+    }
+
+    fn atom(&self, site: &SiteUrls, policy: &FeedPolicy) -> atom::Feed {
+        let link = |href: String, rel: &str, mime_type: Option<&str>| atom::Link {
+            href,
+            rel: rel.to_owned(),
+            mime_type: mime_type.map(str::to_owned),
+            ..Default::default()
+        };
+        let self_url = self.scope.feed_url(site, FeedFormat::Atom);
+        let entries = self
+            .entries
+            .iter()
+            .map(|entry| {
+                let article = &entry.article;
+                let url = site.article_url(article.slug.as_str());
+                let username = article.author.username.as_str();
+                atom::Entry {
+                    title: atom::Text::plain(&article.title),
+                    id: url.clone(),
+                    updated: updated(article).fixed_offset(),
+                    published: Some(published(article).fixed_offset()),
+                    authors: vec![atom::Person {
+                        name: username.to_owned(),
+                        uri: Some(site.profile_url(username)),
+                        ..Default::default()
+                    }],
+                    categories: article
+                        .tag_list
+                        .as_slice()
+                        .iter()
+                        .map(|tag| atom::Category {
+                            term: tag.as_str().to_owned(),
+                            ..Default::default()
+                        })
+                        .collect(),
+                    links: vec![link(url, "alternate", Some("text/html"))],
+                    summary: Some(atom::Text::plain(&article.description)),
+                    content: entry.content_html.as_ref().map(|html| atom::Content {
+                        value: Some(html.clone()),
+                        content_type: Some("html".to_owned()),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }
+            })
+            .collect();
+
+        atom::Feed {
+            title: atom::Text::plain(self.scope.title(policy)),
+            subtitle: Some(atom::Text::plain(self.scope.subtitle())),
+            id: self_url.clone(),
+            updated: self.updated().fixed_offset(),
+            links: vec![
+                link(self_url, "self", Some("application/atom+xml")),
+                link(self.scope.page_url(site), "alternate", Some("text/html")),
+            ],
+            entries,
+            ..Default::default()
+        }
+    }
+
+    fn rss(&self, site: &SiteUrls, policy: &FeedPolicy) -> rss::Channel {
+        let items = self
+            .entries
+            .iter()
+            .map(|entry| {
+                let article = &entry.article;
+                let url = site.article_url(article.slug.as_str());
+                rss::Item {
+                    title: Some(article.title.clone()),
+                    link: Some(url.clone()),
+                    description: Some(article.description.clone()),
+                    guid: Some(rss::Guid {
+                        value: url,
+                        permalink: true,
+                    }),
+                    pub_date: Some(published(article).to_rfc2822()),
+                    categories: article
+                        .tag_list
+                        .as_slice()
+                        .iter()
+                        .map(|tag| rss::Category {
+                            name: tag.as_str().to_owned(),
+                            domain: None,
+                        })
+                        .collect(),
+                    // `author` must be an email address, which feeds don't
+                    // give away; Dublin Core carries the name instead
+                    dublin_core_ext: Some(rss::extension::dublincore::DublinCoreExtension {
+                        creators: vec![article.author.username.as_str().to_owned()],
+                        ..Default::default()
+                    }),
+                    content: entry.content_html.clone(),
+                    ..Default::default()
+                }
+            })
+            .collect();
+
+        rss::Channel {
+            title: self.scope.title(policy),
+            link: self.scope.page_url(site),
+            description: self.scope.subtitle(),
+            last_build_date: Some(self.updated().to_rfc2822()),
+            atom_ext: Some(rss::extension::atom::AtomExtension {
+                links: vec![atom::Link {
+                    href: self.scope.feed_url(site, FeedFormat::Rss),
+                    rel: "self".to_owned(),
+                    mime_type: Some("application/rss+xml".to_owned()),
+                    ..Default::default()
+                }],
+            }),
+            items,
+            ..Default::default()
+        }
+    }
+}
+
+/// Articles that went live later than they were written date from then
+fn published(article: &ArticleSummary) -> DateTime<Utc> {
+    article.published_at.unwrap_or(article.created_at)
+}
+
+/// A scheduled article going live counts as a change, even if it was last
+/// edited long before
+fn updated(article: &ArticleSummary) -> DateTime<Utc> {
+    article.updated_at.max(published(article))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Article, ArticleDraft, Profile, TagList, UserId, Username};
+    use chrono::TimeZone;
+    use rss::validation::Validate;
+
+    fn feed(scope: FeedScope, content_html: Option<&str>) -> ArticleFeed {
+        let at = Utc.with_ymd_and_hms(2026, 3, 1, 9, 30, 0).unwrap();
+        let entries = ["Rust & You", "Second <Post>"]
+            .into_iter()
+            .enumerate()
+            .map(|(i, title)| {
+                let draft = ArticleDraft::new(title, "A \"short\" description", "Body", TagList::new(["rust", "web dev"]).unwrap())
+                    .unwrap();
+                let created_at = at + chrono::Duration::days(i as i64);
+                let article = Article::publish(ArticleId::random(), UserId::random(), draft, created_at).unwrap();
+                let author = Profile::new(Username::new("jake").unwrap(), None, None, false);
+                FeedEntry {
+                    article: article.to_summary(author, false),
+                    body: None,
+                    content_html: content_html.map(str::to_owned),
+                }
+            })
+            .rev()
+            .collect();
+        ArticleFeed { scope, entries }
+    }
+
+    fn site() -> SiteUrls {
+        SiteUrls::new("https://example.com/")
+    }
+
+    #[test]
+    fn rss_feed_is_valid_rss_2() {
+        let rendered = feed(FeedScope::Tag("web-dev".to_owned()), Some("<p>Body &amp; more</p>")).render(FeedFormat::Rss, &site(), &FeedPolicy::default());
+        let channel = rss::Channel::read_from(rendered.xml.as_bytes()).unwrap();
+        channel.validate().unwrap();
+
+        assert_eq!(channel.title, "Conduit: #web-dev");
+        assert_eq!(channel.link, "https://example.com/tag/web-dev");
+        let self_link = &channel.atom_ext.as_ref().unwrap().links[0];
+        assert_eq!(self_link.href, "https://example.com/feeds/tags/web-dev.rss");
+        let item = &channel.items[0];
+        assert_eq!(item.title.as_deref(), Some("Second <Post>"));
+        assert_eq!(item.link.as_deref(), Some("https://example.com/article/second-post"));
+        assert_eq!(item.content.as_deref(), Some("<p>Body &amp; more</p>"));
+        assert_eq!(item.dublin_core_ext.as_ref().unwrap().creators, ["jake"]);
+        assert_eq!(item.categories.len(), 2);
+    }
+
+    #[test]
+    fn atom_feed_has_what_rfc_4287_requires() {
+        let rendered = feed(FeedScope::Author("jake".to_owned()), None).render(FeedFormat::Atom, &site(), &FeedPolicy::default());
+        let parsed = atom::Feed::read_from(rendered.xml.as_bytes()).unwrap();
+
+        // The feed and each entry carry an id, a title and an updated date;
+        // without a feed author, every entry names its own
+        assert_eq!(parsed.id, "https://example.com/feeds/authors/jake.atom");
+        assert_eq!(parsed.title.value, "Conduit: jake");
+        assert_eq!(parsed.updated, Utc.with_ymd_and_hms(2026, 3, 2, 9, 30, 0).unwrap());
+        assert!(parsed.links.iter().any(|link| link.rel == "self"));
+        assert_eq!(parsed.entries.len(), 2);
+        for entry in &parsed.entries {
+            assert!(entry.id.starts_with("https://example.com/article/"));
+            assert!(!entry.title.value.is_empty());
+            assert_eq!(entry.authors[0].name, "jake");
+            assert_eq!(entry.links[0].rel, "alternate");
+            assert!(entry.content.is_none());
+        }
+        assert_eq!(parsed.entries[0].title.value, "Second <Post>");
+    }
+
+    #[test]
+    fn going_live_updates_the_feed() {
+        let mut feed = feed(FeedScope::All, None);
+        let written = feed.updated();
+        let live = written + chrono::Duration::days(3);
+        feed.entries[1].article.published_at = Some(live);
+
+        assert_eq!(feed.updated(), live);
+        let parsed = atom::Feed::read_from(feed.render(FeedFormat::Atom, &site(), &FeedPolicy::default()).xml.as_bytes()).unwrap();
+        assert_eq!(parsed.updated, live);
+        assert_eq!(parsed.entries[1].updated, live);
+    }
+
+    #[test]
+    fn etag_follows_the_document() {
+        let (site, policy) = (site(), FeedPolicy::default());
+        let plain = feed(FeedScope::All, None);
+        assert_eq!(plain.render(FeedFormat::Atom, &site, &policy).etag, plain.render(FeedFormat::Atom, &site, &policy).etag);
+        assert_ne!(plain.render(FeedFormat::Atom, &site, &policy).etag, plain.render(FeedFormat::Rss, &site, &policy).etag);
+        let full = feed(FeedScope::All, Some("<p>Body</p>"));
+        assert_ne!(plain.render(FeedFormat::Atom, &site, &policy).etag, full.render(FeedFormat::Atom, &site, &policy).etag);
+
+        // Dropping the older entry leaves the feed's date alone but not its tag
+        let mut fewer = plain.clone();
+        fewer.entries.pop();
+        assert_eq!(fewer.updated(), plain.updated());
+        assert_ne!(fewer.render(FeedFormat::Atom, &site, &policy).etag, plain.render(FeedFormat::Atom, &site, &policy).etag);
+
+        let empty = ArticleFeed { scope: FeedScope::All, entries: Vec::new() };
+        assert_eq!(empty.updated(), DateTime::UNIX_EPOCH);
+    }
+
+    #[test]
+    fn file_names_split_into_stem_and_format() {
+        assert_eq!(FeedFormat::split_file_name("rust.atom"), Some(("rust", FeedFormat::Atom)));
+        assert_eq!(FeedFormat::split_file_name("c.sharp.rss"), Some(("c.sharp", FeedFormat::Rss)));
+        assert_eq!(FeedFormat::split_file_name("rust.json"), None);
+        assert_eq!(FeedFormat::split_file_name(".rss"), None);
+    }
+}
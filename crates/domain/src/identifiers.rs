@@ -13,6 +13,11 @@ impl UserId {
         Self(Uuid::new_v4())
     }
 
+    /// Well-known id of the placeholder account that owns anonymised content.
+    pub fn deleted_user() -> Self {
+        Self(Uuid::nil())
+    }
+
     pub fn as_uuid(&self) -> Uuid {
         self.0
     }
@@ -59,6 +64,35 @@ impl From<ArticleId> for Uuid {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SeriesId(Uuid);
+
+impl SeriesId {
+    pub fn new(id: Uuid) -> Self {
+        Self(id)
+    }
+
+    pub fn random() -> Self {
+        Self(Uuid::new_v4())
+    }
+
+    pub fn as_uuid(&self) -> Uuid {
+        self.0
+    }
+}
+
+impl From<Uuid> for SeriesId {
+    fn from(value: Uuid) -> Self {
+        Self(value)
+    }
+}
+
+impl From<SeriesId> for Uuid {
+    fn from(value: SeriesId) -> Self {
+        value.0
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct CommentId(i64);
 
@@ -84,6 +118,64 @@ impl From<CommentId> for i64 {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct RevisionId(Uuid);
+
+impl RevisionId {
+    pub fn new(id: Uuid) -> Self {
+        Self(id)
+    }
+
+    pub fn random() -> Self {
+        Self(Uuid::new_v4())
+    }
+
+    pub fn as_uuid(&self) -> Uuid {
+        self.0
+    }
+}
+
+impl From<Uuid> for RevisionId {
+    fn from(value: Uuid) -> Self {
+        Self(value)
+    }
+}
+
+impl From<RevisionId> for Uuid {
+    fn from(value: RevisionId) -> Self {
+        value.0
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ReportId(Uuid);
+
+impl ReportId {
+    pub fn new(id: Uuid) -> Self {
+        Self(id)
+    }
+
+    pub fn random() -> Self {
+        Self(Uuid::new_v4())
+    }
+
+    pub fn as_uuid(&self) -> Uuid {
+        self.0
+    }
+}
+
+impl From<Uuid> for ReportId {
+    fn from(value: Uuid) -> Self {
+        Self(value)
+    }
+}
+
+impl From<ReportId> for Uuid {
+    fn from(value: ReportId) -> Self {
+        value.0
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,32 +1,97 @@
 pub mod article;
+pub mod avatar;
+pub mod bookmark;
+pub mod coauthor;
 pub mod comment;
+pub mod diff;
 pub mod errors;
+pub mod events;
+pub mod feed;
 pub mod identifiers;
+pub mod markdown;
+pub mod moderation;
 pub mod pagination;
 pub mod profile;
+pub mod reaction;
+pub mod related;
 pub mod repositories;
+pub mod revision;
+pub mod search;
+pub mod seo;
+pub mod series;
 pub mod services;
+pub mod site;
+pub mod sitemap;
 pub mod tags;
+pub mod upload;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+pub mod trash;
 pub mod use_cases;
 pub mod user;
 
 pub use article::{
     Article, ArticleChanges, ArticleDraft, ArticleEnvelope, ArticleFilters, ArticleList,
-    ArticleSummary, ArticleView, ArticlesEnvelope, FeedFilters, Slug,
+    ArticleSort, ArticleStatus, ArticleSummary, ArticleView, ArticlesEnvelope, FeedFilters,
+    FeedReason, Publication, Slug, SlugPolicy, TagMatch,
 };
+pub use avatar::{Avatar, AvatarFormat};
+pub use bookmark::{
+    Bookmark, BookmarkFilters, BookmarkView, BookmarksEnvelope, MAX_BOOKMARK_FOLDER_LEN,
+    MAX_BOOKMARK_NOTE_LEN,
+};
+pub use coauthor::Coauthor;
 pub use comment::{Comment, CommentDraft, CommentEnvelope, CommentView, CommentsEnvelope};
 pub use errors::{DomainError, DomainResult};
-pub use identifiers::{ArticleId, CommentId, UserId};
-pub use pagination::{DEFAULT_LIMIT, MAX_LIMIT, Pagination};
+pub use events::DomainEvent;
+pub use feed::{
+    ArticleFeed, DEFAULT_FEED_ENTRIES, FeedEntry, FeedFormat, FeedPolicy, FeedScope, RenderedFeed,
+};
+pub use identifiers::{ArticleId, CommentId, ReportId, RevisionId, SeriesId, UserId};
+pub use markdown::{RenderedBody, TocEntry, render_markdown};
+pub use moderation::{
+    MAX_REPORT_DETAILS_LEN, ModerationAction, ModerationNotice, ModerationQueueEnvelope, ModerationRecord,
+    ModerationRecordView, ModerationReportEnvelope, ModerationReportView, Report, ReportEnvelope, ReportReason,
+    ReportStatus, ReportSubject, ReportTarget, ReportView, ReportsEnvelope,
+};
+pub use pagination::{Cursor, CursorDirection, CursorPage, DEFAULT_LIMIT, MAX_LIMIT, Pagination};
 pub use profile::{Profile, ProfileEnvelope};
+pub use reaction::{
+    MAX_REACTION_KIND_LEN, Reaction, ReactionCount, ReactionKind, ReactionPolicy, ReactionsEnvelope,
+};
+pub use related::{DEFAULT_RELATED_LIMIT, RelatedScoring};
 pub use repositories::{
-    ArticlesRepository, CommentsRepository, UsersRepository,
-    InMemoryArticlesRepository, InMemoryCommentsRepository, InMemoryUsersRepository,
+    ArticlesRepository, BlobStore, CommentsRepository, ReportsRepository, RevisionsRepository, SeriesRepository,
+    TagsRepository, UsersRepository, InMemoryArticlesRepository, InMemoryCommentsRepository,
+    InMemoryBlobStore, InMemoryReportsRepository, InMemoryRevisionsRepository, InMemorySeriesRepository,
+    InMemoryTagsRepository, InMemoryUsersRepository,
+};
+pub use revision::{
+    ArticleRevision, DiffFormat, FieldDiff, RevisionDiff, RevisionDiffEnvelope, RevisionView,
+    RevisionsEnvelope,
+};
+pub use search::{
+    ArticleSearchHit, SearchFilters, SearchQuery, SearchResultsEnvelope, SearchTerm,
+};
+pub use seo::{ArticleSeo, MAX_META_DESCRIPTION_LEN, MAX_SEO_URL_LEN};
+pub use series::{
+    MAX_SERIES_PARTS, Series, SeriesEnvelope, SeriesKind, SeriesLink, SeriesNavigation, SeriesView,
 };
 pub use services::{add_follower, is_article_favorited, is_following, remove_follower};
-pub use tags::{Tag, TagList};
+pub use site::SiteUrls;
+pub use sitemap::{MAX_SITEMAP_URLS, Sitemap, SitemapEntry, SitemapLocation};
+pub use tags::{
+    FollowedTagsEnvelope, MAX_TAG_LEN, Tag, TagCount, TagEnvelope, TagList, TagMerge,
+    TagMergeEnvelope, TagView, TagsEnvelope,
+};
+pub use upload::{
+    Blob, DEFAULT_MAX_UPLOAD_BYTES, DEFAULT_THUMBNAIL_SIZE, ProcessedUpload, Upload, UploadEnvelope, UploadKind,
+    UploadPolicy, UploadView,
+};
+pub use trash::{TrashEnvelope, TrashPolicy, Trashed, TrashedArticleView, TrashedCommentView};
 pub use use_cases::UseCases;
 pub use user::{
-    AuthToken, Email, ImageUrl, LoginUserInput, PasswordHash, PlainPassword, RegisterUserInput,
-    UpdateUserInput, User, UserEnvelope, UserView, Username,
+    AccountDeletionPolicy, AuthToken, AuthoredContentPolicy, Email, ImageUrl, LoginUserInput,
+    PasswordHash, PlainPassword, RegisterUserInput, UpdateUserInput, User, UserEnvelope, UserView,
+    Username,
 };
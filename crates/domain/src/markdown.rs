@@ -0,0 +1,214 @@
+//! Server-side rendering of article bodies
+//!
+//! Bodies are CommonMark with the GitHub extensions (tables, task lists,
+//! strikethrough, footnotes). The HTML is cleaned with an allow-list, so raw
+//! HTML in a body can never run script in a reader's browser. Every `id` in
+//! the output starts with [`ID_PREFIX`], so a body can't clash with or
+//! clobber the ids of the page it is shown on.
+
+use std::collections::HashMap;
+
+use pulldown_cmark::{CowStr, Event, HeadingLevel, Options, Parser, Tag, TagEnd, html};
+use serde::{Deserialize, Serialize};
+
+/// Prepended to heading anchors, footnote targets and any `id` a body sets
+pub const ID_PREFIX: &str = "user-content-";
+
+/// Rendered body with the headings it contains, in document order
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RenderedBody {
+    pub html: String,
+    pub toc: Vec<TocEntry>,
+}
+
+/// A heading, linked from the table of contents through its anchor `id`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TocEntry {
+    pub level: u8,
+    pub id: String,
+    pub text: String,
+}
+
+pub fn render_markdown(source: &str) -> RenderedBody {
+    let options = Options::ENABLE_TABLES
+        | Options::ENABLE_FOOTNOTES
+        | Options::ENABLE_STRIKETHROUGH
+        | Options::ENABLE_TASKLISTS;
+    let mut events: Vec<Event> = Parser::new_ext(source, options).collect();
+
+    let mut toc = Vec::new();
+    let mut anchors = Anchors::default();
+    let mut open_heading: Option<(usize, String)> = None;
+    for i in 0..events.len() {
+        match &events[i] {
+            Event::Start(Tag::Heading { .. }) => open_heading = Some((i, String::new())),
+            // Footnote links have to point at the prefixed ids
+            Event::FootnoteReference(name) => {
+                events[i] = Event::FootnoteReference(prefixed(name));
+            }
+            Event::Start(Tag::FootnoteDefinition(name)) => {
+                events[i] = Event::Start(Tag::FootnoteDefinition(prefixed(name)));
+            }
+            Event::Text(text) | Event::Code(text) => {
+                if let Some((_, heading_text)) = open_heading.as_mut() {
+                    heading_text.push_str(text);
+                }
+            }
+            Event::End(TagEnd::Heading(level)) => {
+                let level = heading_level(*level);
+                let Some((start, text)) = open_heading.take() else {
+                    continue;
+                };
+                let id = format!("{ID_PREFIX}{}", anchors.next(&text));
+                if let Event::Start(Tag::Heading { id: heading_id, .. }) = &mut events[start] {
+                    *heading_id = Some(CowStr::from(id.clone()));
+                }
+                toc.push(TocEntry {
+                    level,
+                    id,
+                    text: text.trim().to_owned(),
+                });
+            }
+            _ => {}
+        }
+    }
+
+    let mut unsafe_html = String::with_capacity(source.len() * 3 / 2);
+    html::push_html(&mut unsafe_html, events.into_iter());
+
+    RenderedBody {
+        html: sanitizer().clean(&unsafe_html).to_string(),
+        toc,
+    }
+}
+
+fn prefixed(name: &str) -> CowStr<'static> {
+    CowStr::from(format!("{ID_PREFIX}{name}"))
+}
+
+/// Default ammonia allow-list plus what the renderer itself emits: heading
+/// anchors, footnote targets and task list checkboxes. Every `input` comes
+/// out as a disabled checkbox, whatever type the body gave it
+fn sanitizer() -> ammonia::Builder<'static> {
+    let mut builder = ammonia::Builder::default();
+    for tag in ["h1", "h2", "h3", "h4", "h5", "h6", "div"] {
+        builder.add_tag_attributes(tag, ["id"]);
+    }
+    builder
+        .id_prefix(Some(ID_PREFIX))
+        .add_tags(["input"])
+        .add_tag_attributes("input", ["type", "checked", "disabled"])
+        .set_tag_attribute_value("input", "type", "checkbox")
+        .set_tag_attribute_value("input", "disabled", "");
+    builder
+}
+
+fn heading_level(level: HeadingLevel) -> u8 {
+    match level {
+        HeadingLevel::H1 => 1,
+        HeadingLevel::H2 => 2,
+        HeadingLevel::H3 => 3,
+        HeadingLevel::H4 => 4,
+        HeadingLevel::H5 => 5,
+        HeadingLevel::H6 => 6,
+    }
+}
+
+/// GitHub-style heading anchors: lowercase words joined by dashes, with
+/// `-1`, `-2`… appended to repeats
+#[derive(Default)]
+struct Anchors {
+    seen: HashMap<String, usize>,
+}
+
+impl Anchors {
+    fn next(&mut self, text: &str) -> String {
+        let mut base = String::new();
+        for ch in text.trim().chars() {
+            if ch.is_alphanumeric() || ch == '_' {
+                base.extend(ch.to_lowercase());
+            } else if (ch == '-' || ch.is_whitespace()) && !base.ends_with('-') {
+                base.push('-');
+            }
+        }
+        let base = match base.trim_matches('-') {
+            "" => "section".to_owned(),
+            trimmed => trimmed.to_owned(),
+        };
+
+        let count = self.seen.entry(base.clone()).or_insert(0);
+        let id = if *count == 0 { base } else { format!("{base}-{count}") };
+        *count += 1;
+        id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_markdown_adds_anchors_and_toc() {
+        let rendered = render_markdown("# Intro\n\n## Set `up`\n\ntext\n\n## Set up\n\n### ¿Qué?\n");
+        assert!(rendered.html.contains(r#"<h1 id="user-content-intro">Intro</h1>"#));
+        assert!(rendered.html.contains(r#"<h2 id="user-content-set-up">Set <code>up</code></h2>"#));
+        assert!(rendered.html.contains(r#"<h2 id="user-content-set-up-1">Set up</h2>"#));
+        let ids: Vec<(u8, &str)> = rendered.toc.iter().map(|e| (e.level, e.id.as_str())).collect();
+        assert_eq!(
+            ids,
+            vec![
+                (1, "user-content-intro"),
+                (2, "user-content-set-up"),
+                (2, "user-content-set-up-1"),
+                (3, "user-content-qué")
+            ]
+        );
+        assert_eq!(rendered.toc[1].text, "Set up");
+    }
+
+    #[test]
+    fn render_markdown_supports_gfm() {
+        let rendered = render_markdown("| a | b |\n|---|---|\n| 1 | 2 |\n\n- [x] done\n\n~~old~~\n");
+        assert!(rendered.html.contains("<table>"));
+        assert!(rendered.html.contains(r#"<input disabled="" type="checkbox" checked="""#));
+        assert!(rendered.html.contains("<del>old</del>"));
+    }
+
+    #[test]
+    fn render_markdown_prefixes_ids() {
+        let rendered = render_markdown("Note[^1]\n\n[^1]: Aside\n\n<div id=\"app\">x</div>\n");
+        assert!(rendered.html.contains(r##"<a href="#user-content-1" rel="noopener noreferrer">1</a>"##));
+        assert!(rendered.html.contains(r#"<div id="user-content-1">"#));
+        assert!(rendered.html.contains(r#"<div id="user-content-app">"#));
+    }
+
+    #[test]
+    fn render_markdown_only_keeps_disabled_checkboxes() {
+        let rendered = render_markdown(
+            "<input type=\"password\" name=\"p\"> <input> <input type=\"submit\" value=\"Go\">\n",
+        );
+        let inputs: Vec<&str> = rendered
+            .html
+            .split("<input")
+            .skip(1)
+            .map(|rest| &rest[..rest.find('>').unwrap()])
+            .collect();
+        assert_eq!(inputs.len(), 3);
+        for attributes in inputs {
+            let mut attributes: Vec<&str> = attributes.split_whitespace().collect();
+            attributes.sort();
+            assert_eq!(attributes, [r#"disabled="""#, r#"type="checkbox""#]);
+        }
+    }
+
+    #[test]
+    fn render_markdown_strips_unsafe_html() {
+        let rendered = render_markdown(
+            "<script>alert(1)</script>\n\n[x](javascript:alert(1)) <img src=x onerror=alert(1)>",
+        );
+        assert!(!rendered.html.contains("<script"));
+        assert!(!rendered.html.contains("javascript:"));
+        assert!(!rendered.html.contains("onerror"));
+        assert!(rendered.html.contains(r#"<img src="x">"#));
+    }
+}
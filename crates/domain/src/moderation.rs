@@ -0,0 +1,443 @@
+//! Reports of abusive content and the moderation queue
+//!
+//! Users report an article, comment or profile for one of the
+//! [`ReportReason`]s. Moderators work through open reports: they claim one,
+//! may hide the reported content or suspend its author, and close it as
+//! resolved or dismissed. Every action is kept as a [`ModerationRecord`],
+//! and reporters see how their reports were closed.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::errors::{DomainError, DomainResult};
+use crate::identifiers::{ArticleId, CommentId, ReportId, UserId};
+use crate::user::Username;
+
+/// Longest explanation a reporter may add, in characters
+pub const MAX_REPORT_DETAILS_LEN: usize = 1000;
+
+/// Why something was reported
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReportReason {
+    Spam,
+    Harassment,
+    HateSpeech,
+    Violence,
+    SexualContent,
+    Misinformation,
+    /// Anything else; the details should say what
+    Other,
+}
+
+impl ReportReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Spam => "spam",
+            Self::Harassment => "harassment",
+            Self::HateSpeech => "hate_speech",
+            Self::Violence => "violence",
+            Self::SexualContent => "sexual_content",
+            Self::Misinformation => "misinformation",
+            Self::Other => "other",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "spam" => Some(Self::Spam),
+            "harassment" => Some(Self::Harassment),
+            "hate_speech" => Some(Self::HateSpeech),
+            "violence" => Some(Self::Violence),
+            "sexual_content" => Some(Self::SexualContent),
+            "misinformation" => Some(Self::Misinformation),
+            "other" => Some(Self::Other),
+            _ => None,
+        }
+    }
+}
+
+/// Where a report is in the moderation queue
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReportStatus {
+    /// Waiting for a moderator
+    Open,
+    /// A moderator is looking into it
+    Claimed,
+    /// Closed after action was taken
+    Resolved,
+    /// Closed as needing no action
+    Dismissed,
+}
+
+impl ReportStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Open => "open",
+            Self::Claimed => "claimed",
+            Self::Resolved => "resolved",
+            Self::Dismissed => "dismissed",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "open" => Some(Self::Open),
+            "claimed" => Some(Self::Claimed),
+            "resolved" => Some(Self::Resolved),
+            "dismissed" => Some(Self::Dismissed),
+            _ => None,
+        }
+    }
+
+    pub fn is_closed(&self) -> bool {
+        matches!(self, Self::Resolved | Self::Dismissed)
+    }
+}
+
+/// What a report is about
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ReportTarget {
+    Article(ArticleId),
+    Comment(CommentId),
+    Profile(UserId),
+}
+
+/// What a moderator can do with a report
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ModerationAction {
+    /// Take the report so no other moderator works on it
+    Claim,
+    /// Hide the reported article or comment from everyone but its authors
+    Hide,
+    /// Suspend whoever wrote the reported content, or the reported user
+    Suspend,
+    /// Close the report as acted upon
+    Resolve,
+    /// Close the report as needing no action
+    Dismiss,
+}
+
+impl ModerationAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Claim => "claim",
+            Self::Hide => "hide",
+            Self::Suspend => "suspend",
+            Self::Resolve => "resolve",
+            Self::Dismiss => "dismiss",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "claim" => Some(Self::Claim),
+            "hide" => Some(Self::Hide),
+            "suspend" => Some(Self::Suspend),
+            "resolve" => Some(Self::Resolve),
+            "dismiss" => Some(Self::Dismiss),
+            _ => None,
+        }
+    }
+}
+
+/// A user's report of an article, comment or profile
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Report {
+    pub id: ReportId,
+    pub reporter_id: UserId,
+    pub target: ReportTarget,
+    /// Who wrote the reported article or comment, or whose profile it is
+    pub target_author_id: UserId,
+    pub reason: ReportReason,
+    pub details: Option<String>,
+    pub status: ReportStatus,
+    /// The moderator who claimed or closed the report
+    pub moderator_id: Option<UserId>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub closed_at: Option<DateTime<Utc>>,
+}
+
+impl Report {
+    /// An open report; blank details count as none
+    pub fn new(
+        id: ReportId,
+        reporter_id: UserId,
+        target: ReportTarget,
+        target_author_id: UserId,
+        reason: ReportReason,
+        details: Option<String>,
+        now: DateTime<Utc>,
+    ) -> DomainResult<Self> {
+        if reporter_id == target_author_id {
+            return Err(DomainError::SelfReport);
+        }
+        let details = details
+            .map(|details| details.trim().to_owned())
+            .filter(|details| !details.is_empty());
+        if details
+            .as_ref()
+            .is_some_and(|details| details.chars().count() > MAX_REPORT_DETAILS_LEN)
+        {
+            return Err(DomainError::ReportDetailsTooLong {
+                max: MAX_REPORT_DETAILS_LEN,
+            });
+        }
+        Ok(Self {
+            id,
+            reporter_id,
+            target,
+            target_author_id,
+            reason,
+            details,
+            status: ReportStatus::Open,
+            moderator_id: None,
+            created_at: now,
+            updated_at: now,
+            closed_at: None,
+        })
+    }
+
+    pub fn is_closed(&self) -> bool {
+        self.status.is_closed()
+    }
+
+    /// Records that `moderator_id` took `action`: resolving and dismissing
+    /// close the report, anything else claims it. Closed reports, and those
+    /// another moderator claimed, are left alone
+    pub fn apply(&mut self, action: ModerationAction, moderator_id: UserId, now: DateTime<Utc>) -> DomainResult<()> {
+        if self.is_closed() {
+            return Err(DomainError::ReportClosed);
+        }
+        if self.moderator_id.is_some_and(|claimer| claimer != moderator_id) {
+            return Err(DomainError::ReportClaimed);
+        }
+        self.moderator_id = Some(moderator_id);
+        self.status = match action {
+            ModerationAction::Resolve => ReportStatus::Resolved,
+            ModerationAction::Dismiss => ReportStatus::Dismissed,
+            ModerationAction::Claim | ModerationAction::Hide | ModerationAction::Suspend => ReportStatus::Claimed,
+        };
+        if self.is_closed() {
+            self.closed_at = Some(now);
+        }
+        self.updated_at = now;
+        Ok(())
+    }
+
+    /// `target` is the reported content as it is now, if it is still around
+    pub fn to_view(&self, target: Option<ReportSubject>) -> ReportView {
+        ReportView {
+            id: self.id,
+            target,
+            reason: self.reason,
+            details: self.details.clone(),
+            status: self.status,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+            closed_at: self.closed_at,
+        }
+    }
+}
+
+/// An action a moderator took on a report
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModerationRecord {
+    pub report_id: ReportId,
+    /// `None` once the moderator's account is gone
+    pub moderator_id: Option<UserId>,
+    pub action: ModerationAction,
+    pub note: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Tells the authors of hidden content why nobody else sees it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModerationNotice {
+    pub hidden_at: DateTime<Utc>,
+    pub message: String,
+}
+
+impl ModerationNotice {
+    pub const HIDDEN_MESSAGE: &'static str =
+        "A moderator hid this after it was reported; nobody but its authors can see it.";
+
+    pub fn hidden(hidden_at: DateTime<Utc>) -> Self {
+        Self {
+            hidden_at,
+            message: Self::HIDDEN_MESSAGE.to_owned(),
+        }
+    }
+}
+
+/// The reported content as reports show it; comments carry their body so
+/// moderators can judge them
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum ReportSubject {
+    Article {
+        slug: String,
+        title: String,
+    },
+    Comment {
+        id: CommentId,
+        #[serde(rename = "articleSlug")]
+        article_slug: String,
+        body: String,
+    },
+    Profile {
+        username: Username,
+    },
+}
+
+/// A report as its reporter sees it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportView {
+    pub id: ReportId,
+    /// `null` once the reported content or account is gone
+    pub target: Option<ReportSubject>,
+    pub reason: ReportReason,
+    pub details: Option<String>,
+    pub status: ReportStatus,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub closed_at: Option<DateTime<Utc>>,
+}
+
+/// A report as moderators see it in the queue
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModerationReportView {
+    #[serde(flatten)]
+    pub report: ReportView,
+    pub reporter: Option<Username>,
+    pub target_author: Option<Username>,
+    pub moderator: Option<Username>,
+    /// Every action taken so far, oldest first; only on a single report
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub actions: Option<Vec<ModerationRecordView>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModerationRecordView {
+    pub moderator: Option<Username>,
+    pub action: ModerationAction,
+    pub note: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReportEnvelope {
+    pub report: ReportView,
+}
+
+impl From<ReportView> for ReportEnvelope {
+    fn from(report: ReportView) -> Self {
+        Self { report }
+    }
+}
+
+/// The current user's reports, most recent first
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReportsEnvelope {
+    pub reports: Vec<ReportView>,
+}
+
+impl From<Vec<ReportView>> for ReportsEnvelope {
+    fn from(reports: Vec<ReportView>) -> Self {
+        Self { reports }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModerationReportEnvelope {
+    pub report: ModerationReportView,
+}
+
+impl From<ModerationReportView> for ModerationReportEnvelope {
+    fn from(report: ModerationReportView) -> Self {
+        Self { report }
+    }
+}
+
+/// A page of the moderation queue, oldest report first
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModerationQueueEnvelope {
+    pub reports: Vec<ModerationReportView>,
+    pub reports_count: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report() -> Report {
+        Report::new(
+            ReportId::random(),
+            UserId::random(),
+            ReportTarget::Article(ArticleId::random()),
+            UserId::random(),
+            ReportReason::Spam,
+            Some("  buy now  ".to_owned()),
+            Utc::now(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn reports_validate_details_and_reporter() {
+        assert_eq!(report().details.as_deref(), Some("buy now"));
+
+        let user = UserId::random();
+        let own = Report::new(
+            ReportId::random(),
+            user,
+            ReportTarget::Profile(user),
+            user,
+            ReportReason::Other,
+            None,
+            Utc::now(),
+        );
+        assert_eq!(own.unwrap_err(), DomainError::SelfReport);
+
+        let long = Report::new(
+            ReportId::random(),
+            UserId::random(),
+            ReportTarget::Comment(CommentId::new(1)),
+            UserId::random(),
+            ReportReason::Other,
+            Some("a".repeat(MAX_REPORT_DETAILS_LEN + 1)),
+            Utc::now(),
+        );
+        assert!(matches!(long, Err(DomainError::ReportDetailsTooLong { .. })));
+    }
+
+    #[test]
+    fn actions_claim_then_close_reports() {
+        let moderator = UserId::random();
+        let mut report = report();
+
+        report.apply(ModerationAction::Hide, moderator, Utc::now()).unwrap();
+        assert_eq!(report.status, ReportStatus::Claimed);
+        assert_eq!(report.moderator_id, Some(moderator));
+        assert_eq!(
+            report.apply(ModerationAction::Claim, UserId::random(), Utc::now()),
+            Err(DomainError::ReportClaimed)
+        );
+
+        report.apply(ModerationAction::Resolve, moderator, Utc::now()).unwrap();
+        assert_eq!(report.status, ReportStatus::Resolved);
+        assert!(report.closed_at.is_some());
+        assert_eq!(
+            report.apply(ModerationAction::Dismiss, moderator, Utc::now()),
+            Err(DomainError::ReportClosed)
+        );
+    }
+}
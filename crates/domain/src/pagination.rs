@@ -1,6 +1,9 @@
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use crate::errors::{DomainError, DomainResult};
+use crate::identifiers::ArticleId;
 
 pub const DEFAULT_LIMIT: u32 = 20;
 pub const MAX_LIMIT: u32 = 50;
@@ -42,6 +45,179 @@ impl Default for Pagination {
     }
 }
 
+/// Which side of its position a cursor pages towards
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CursorDirection {
+    /// Next page: articles after the position, i.e. older ones
+    Older,
+    /// Previous page: articles before the position, i.e. newer ones
+    Newer,
+}
+
+/// Opaque keyset position in a list ordered newest first by
+/// `(listed_at, id)`, see [`Article::listed_at`](crate::Article::listed_at)
+///
+/// Unlike an offset it stays put when articles are added or removed while a
+/// client is scrolling.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Cursor {
+    direction: CursorDirection,
+    listed_at: DateTime<Utc>,
+    id: ArticleId,
+}
+
+impl Cursor {
+    pub fn older_than(listed_at: DateTime<Utc>, id: ArticleId) -> Self {
+        Self {
+            direction: CursorDirection::Older,
+            listed_at,
+            id,
+        }
+    }
+
+    pub fn newer_than(listed_at: DateTime<Utc>, id: ArticleId) -> Self {
+        Self {
+            direction: CursorDirection::Newer,
+            listed_at,
+            id,
+        }
+    }
+
+    pub fn direction(&self) -> CursorDirection {
+        self.direction
+    }
+
+    pub fn listed_at(&self) -> DateTime<Utc> {
+        self.listed_at
+    }
+
+    pub fn id(&self) -> ArticleId {
+        self.id
+    }
+
+    /// Whether an article at `(listed_at, id)` lies on the side this cursor
+    /// pages towards
+    pub fn admits(&self, listed_at: DateTime<Utc>, id: ArticleId) -> bool {
+        let position = (listed_at, id.as_uuid());
+        let cursor = (self.listed_at, self.id.as_uuid());
+        match self.direction {
+            CursorDirection::Older => position < cursor,
+            CursorDirection::Newer => position > cursor,
+        }
+    }
+
+    pub fn encode(&self) -> String {
+        let direction = match self.direction {
+            CursorDirection::Older => 'o',
+            CursorDirection::Newer => 'n',
+        };
+        let nanos = self.listed_at.timestamp_nanos_opt().unwrap_or_default();
+        URL_SAFE_NO_PAD.encode(format!("{direction}:{nanos}:{}", self.id.as_uuid()))
+    }
+
+    pub fn decode(value: &str) -> DomainResult<Self> {
+        let decoded = URL_SAFE_NO_PAD
+            .decode(value)
+            .ok()
+            .and_then(|bytes| String::from_utf8(bytes).ok())
+            .ok_or(DomainError::InvalidCursor)?;
+        let mut parts = decoded.splitn(3, ':');
+        let direction = match parts.next() {
+            Some("o") => CursorDirection::Older,
+            Some("n") => CursorDirection::Newer,
+            _ => return Err(DomainError::InvalidCursor),
+        };
+        let listed_at = parts
+            .next()
+            .and_then(|nanos| nanos.parse::<i64>().ok())
+            .map(DateTime::from_timestamp_nanos)
+            .ok_or(DomainError::InvalidCursor)?;
+        let id = parts
+            .next()
+            .and_then(|id| uuid::Uuid::parse_str(id).ok())
+            .map(ArticleId::new)
+            .ok_or(DomainError::InvalidCursor)?;
+        Ok(Self {
+            direction,
+            listed_at,
+            id,
+        })
+    }
+}
+
+impl From<Cursor> for String {
+    fn from(value: Cursor) -> Self {
+        value.encode()
+    }
+}
+
+impl TryFrom<String> for Cursor {
+    type Error = DomainError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        Self::decode(&value)
+    }
+}
+
+/// A page of a newest-first list with the cursors leading away from it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CursorPage<T> {
+    pub items: Vec<T>,
+    pub next: Option<Cursor>,
+    pub prev: Option<Cursor>,
+}
+
+impl<T> CursorPage<T> {
+    /// Page fetched by offset out of `total` matching items
+    pub fn from_offset<K>(items: Vec<T>, offset: u32, total: usize, key: K) -> Self
+    where
+        K: Fn(&T) -> (DateTime<Utc>, ArticleId),
+    {
+        let next = match items.last() {
+            Some(last) if offset as usize + items.len() < total => {
+                let (listed_at, id) = key(last);
+                Some(Cursor::older_than(listed_at, id))
+            }
+            _ => None,
+        };
+        let prev = match items.first() {
+            Some(first) if offset > 0 => {
+                let (listed_at, id) = key(first);
+                Some(Cursor::newer_than(listed_at, id))
+            }
+            _ => None,
+        };
+        Self { items, next, prev }
+    }
+
+    /// Page fetched from `cursor`: the first `limit + 1` items on its side,
+    /// nearest first. The extra item only tells whether more follow.
+    pub fn from_cursor<K>(mut items: Vec<T>, limit: u32, cursor: Cursor, key: K) -> Self
+    where
+        K: Fn(&T) -> (DateTime<Utc>, ArticleId),
+    {
+        let more = items.len() > limit as usize;
+        items.truncate(limit as usize);
+        if cursor.direction == CursorDirection::Newer {
+            items.reverse();
+        }
+        let older = items.last().map(|last| {
+            let (listed_at, id) = key(last);
+            Cursor::older_than(listed_at, id)
+        });
+        let newer = items.first().map(|first| {
+            let (listed_at, id) = key(first);
+            Cursor::newer_than(listed_at, id)
+        });
+        let (next, prev) = match cursor.direction {
+            CursorDirection::Older => (older.filter(|_| more), newer),
+            CursorDirection::Newer => (older, newer.filter(|_| more)),
+        };
+        Self { items, next, prev }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -60,4 +236,56 @@ mod tests {
             DomainError::LimitOutOfRange
         );
     }
+
+    #[test]
+    fn cursor_round_trips_and_rejects_garbage() {
+        let cursor = Cursor::newer_than(Utc::now(), ArticleId::random());
+        assert_eq!(Cursor::decode(&cursor.encode()).unwrap(), cursor);
+        assert_eq!(Cursor::decode("not a cursor").unwrap_err(), DomainError::InvalidCursor);
+        assert_eq!(
+            Cursor::decode(&URL_SAFE_NO_PAD.encode("x:1:2")).unwrap_err(),
+            DomainError::InvalidCursor
+        );
+    }
+
+    #[test]
+    fn cursor_admits_its_side_only() {
+        let now = Utc::now();
+        let id = ArticleId::random();
+        let older = Cursor::older_than(now, id);
+        assert!(older.admits(now - chrono::Duration::seconds(1), ArticleId::random()));
+        assert!(!older.admits(now, id));
+        assert!(Cursor::newer_than(now, id).admits(now + chrono::Duration::seconds(1), id));
+    }
+
+    #[test]
+    fn cursor_page_links_back_and_forth() {
+        let now = Utc::now();
+        // Newest first, one second apart
+        let all: Vec<(DateTime<Utc>, ArticleId)> = (0..5)
+            .map(|i| (now - chrono::Duration::seconds(i), ArticleId::random()))
+            .collect();
+        let key = |item: &(DateTime<Utc>, ArticleId)| *item;
+
+        let first = CursorPage::from_offset(all[..2].to_vec(), 0, all.len(), key);
+        assert_eq!(first.prev, None);
+        let next = first.next.unwrap();
+        assert_eq!(next, Cursor::older_than(all[1].0, all[1].1));
+
+        let fetched: Vec<_> = all.iter().copied().filter(|(at, id)| next.admits(*at, *id)).take(3).collect();
+        let second = CursorPage::from_cursor(fetched, 2, next, key);
+        assert_eq!(second.items, all[2..4].to_vec());
+        let back = second.prev.unwrap();
+        assert_eq!(back, Cursor::newer_than(all[2].0, all[2].1));
+
+        // Newer pages are fetched oldest first and come back newest first
+        let fetched: Vec<_> = all.iter().rev().copied().filter(|(at, id)| back.admits(*at, *id)).take(3).collect();
+        let again = CursorPage::from_cursor(fetched, 2, back, key);
+        assert_eq!(again.items, all[..2].to_vec());
+        assert_eq!(again.prev, None);
+        assert_eq!(again.next, Some(next));
+
+        let last = CursorPage::from_cursor(all[4..].to_vec(), 2, second.next.unwrap(), key);
+        assert_eq!(last.next, None);
+    }
 }
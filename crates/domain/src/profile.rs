@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 
+use crate::avatar::Avatar;
 use crate::user::{ImageUrl, Username};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -11,6 +12,7 @@ pub struct Profile {
 }
 
 impl Profile {
+    /// Without an image of their own, users show their generated avatar
     pub fn new(
         username: Username,
         bio: Option<String>,
@@ -18,9 +20,9 @@ impl Profile {
         following: bool,
     ) -> Self {
         Self {
+            image: image.or_else(|| Some(Avatar::url(&username))),
             username,
             bio,
-            image,
             following,
         }
     }
@@ -67,4 +69,14 @@ mod tests {
         profile.unfollow();
         assert!(!profile.following);
     }
+
+    #[test]
+    fn profile_without_image_uses_generated_avatar() {
+        let profile = Profile::new(Username::new("jake").unwrap(), None, None, false);
+        assert_eq!(profile.image.unwrap().as_str(), "/api/profiles/jake/avatar.svg");
+
+        let image = ImageUrl::new("https://example.com/jake.png").unwrap();
+        let profile = Profile::new(Username::new("jake").unwrap(), None, Some(image.clone()), false);
+        assert_eq!(profile.image, Some(image));
+    }
 }
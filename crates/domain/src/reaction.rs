@@ -0,0 +1,128 @@
+//! Emoji reactions on articles and comments
+//!
+//! Users react with any of the kinds the [`ReactionPolicy`] offers, each at
+//! most once per article or comment. Unlike favorites, an article or comment
+//! carries a count for every kind it received.
+
+use serde::{Deserialize, Serialize};
+
+use crate::errors::{DomainError, DomainResult};
+
+/// Longest reaction name, in characters
+pub const MAX_REACTION_KIND_LEN: usize = 32;
+
+/// Name of a reaction as used in URLs, such as `heart`; the emoji it stands
+/// for comes from the [`ReactionPolicy`]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct ReactionKind(String);
+
+impl ReactionKind {
+    /// Lowercase ASCII letters, digits, `_`, `-` and `+` only
+    pub fn new(name: impl Into<String>) -> DomainResult<Self> {
+        let name = name.into();
+        let allowed = |c: char| c.is_ascii_lowercase() || c.is_ascii_digit() || matches!(c, '_' | '-' | '+');
+        if name.is_empty() || name.len() > MAX_REACTION_KIND_LEN || !name.chars().all(allowed) {
+            return Err(DomainError::UnknownReaction);
+        }
+        Ok(Self(name))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// A reaction users may leave, as offered by the [`ReactionPolicy`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Reaction {
+    pub kind: ReactionKind,
+    pub emoji: String,
+}
+
+/// The reactions users may leave, in the order clients should offer them
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReactionPolicy {
+    reactions: Vec<Reaction>,
+}
+
+impl ReactionPolicy {
+    pub const DEFAULT_REACTIONS: [(&'static str, &'static str); 4] =
+        [("thumbs_up", "👍"), ("heart", "❤️"), ("tada", "🎉"), ("thinking", "🤔")];
+
+    pub fn new(reactions: Vec<Reaction>) -> Self {
+        Self { reactions }
+    }
+
+    pub fn reactions(&self) -> &[Reaction] {
+        &self.reactions
+    }
+
+    /// The offered reaction named `name`
+    pub fn kind(&self, name: &str) -> DomainResult<ReactionKind> {
+        self.reactions
+            .iter()
+            .find(|reaction| reaction.kind.as_str() == name)
+            .map(|reaction| reaction.kind.clone())
+            .ok_or(DomainError::UnknownReaction)
+    }
+}
+
+impl Default for ReactionPolicy {
+    fn default() -> Self {
+        let reactions = Self::DEFAULT_REACTIONS
+            .iter()
+            .map(|(kind, emoji)| Reaction {
+                kind: ReactionKind::new(*kind).expect("default reactions are valid"),
+                emoji: (*emoji).to_owned(),
+            })
+            .collect();
+        Self::new(reactions)
+    }
+}
+
+/// How many users reacted with one kind, and whether the viewer is among them
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReactionCount {
+    pub kind: ReactionKind,
+    pub count: u32,
+    pub reacted: bool,
+}
+
+/// The reactions users may leave
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReactionsEnvelope {
+    pub reactions: Vec<Reaction>,
+}
+
+impl From<&ReactionPolicy> for ReactionsEnvelope {
+    fn from(policy: &ReactionPolicy) -> Self {
+        Self {
+            reactions: policy.reactions().to_vec(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kinds_are_url_friendly_names() {
+        assert_eq!(ReactionKind::new("thumbs_up").unwrap().as_str(), "thumbs_up");
+        assert!(ReactionKind::new("+1").is_ok());
+        assert_eq!(ReactionKind::new("").unwrap_err(), DomainError::UnknownReaction);
+        assert_eq!(ReactionKind::new("Heart").unwrap_err(), DomainError::UnknownReaction);
+        assert_eq!(ReactionKind::new("👍").unwrap_err(), DomainError::UnknownReaction);
+        assert!(ReactionKind::new("a".repeat(MAX_REACTION_KIND_LEN + 1)).is_err());
+    }
+
+    #[test]
+    fn policy_only_offers_configured_kinds() {
+        let policy = ReactionPolicy::default();
+
+        assert_eq!(policy.kind("heart").unwrap().as_str(), "heart");
+        assert_eq!(policy.kind("rocket").unwrap_err(), DomainError::UnknownReaction);
+        assert_eq!(policy.reactions()[0].emoji, "👍");
+    }
+}
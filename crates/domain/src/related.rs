@@ -0,0 +1,93 @@
+//! Scoring of related articles
+//!
+//! A candidate earns points for every tag it shares with the article, more
+//! for rare tags than common ones, for having the same owner, for being
+//! favorited by the same readers, and for being recent. Postgres computes the
+//! same score in SQL from these weights.
+
+use chrono::{DateTime, Utc};
+
+/// Related articles returned when no limit is asked for
+pub const DEFAULT_RELATED_LIMIT: u32 = 5;
+
+/// Weights of each signal in a related article's score
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RelatedScoring {
+    /// Per shared tag, divided by `ln(1 + n)` where `n` is the number of
+    /// published articles carrying the tag
+    pub tag_weight: f64,
+    /// When the candidate has the same owner
+    pub author_weight: f64,
+    /// Times `ln(1 + m)`, `m` being the number of users who favorited both
+    pub cofavorite_weight: f64,
+    /// Divided by `1 + age / recency_days`, so it halves after that many days
+    pub recency_weight: f64,
+    pub recency_days: f64,
+}
+
+impl Default for RelatedScoring {
+    fn default() -> Self {
+        Self {
+            tag_weight: 3.0,
+            author_weight: 1.0,
+            cofavorite_weight: 2.0,
+            recency_weight: 1.0,
+            recency_days: 30.0,
+        }
+    }
+}
+
+impl RelatedScoring {
+    /// `shared_tag_uses` holds, for each shared tag, how many published
+    /// articles carry it; `created_at` is the candidate's
+    pub fn score(
+        &self,
+        shared_tag_uses: impl IntoIterator<Item = usize>,
+        same_author: bool,
+        cofavorites: usize,
+        created_at: DateTime<Utc>,
+        now: DateTime<Utc>,
+    ) -> f64 {
+        let rarity: f64 = shared_tag_uses
+            .into_iter()
+            .map(|uses| 1.0 / (1.0 + uses as f64).ln())
+            .sum();
+        let age_days = ((now - created_at).num_seconds() as f64 / 86_400.0).max(0.0);
+        self.tag_weight * rarity
+            + if same_author { self.author_weight } else { 0.0 }
+            + self.cofavorite_weight * (1.0 + cofavorites as f64).ln()
+            + self.recency_weight / (1.0 + age_days / self.recency_days)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn rare_tags_outweigh_common_ones() {
+        let scoring = RelatedScoring::default();
+        let now = Utc::now();
+        let rare = scoring.score([2], false, 0, now, now);
+        let common = scoring.score([200], false, 0, now, now);
+        assert!(rare > common);
+        assert!(scoring.score([2, 200], false, 0, now, now) > rare);
+    }
+
+    #[test]
+    fn recency_halves_after_recency_days() {
+        let scoring = RelatedScoring {
+            tag_weight: 0.0,
+            author_weight: 0.0,
+            cofavorite_weight: 0.0,
+            ..RelatedScoring::default()
+        };
+        let now = Utc::now();
+        let fresh = scoring.score([], false, 0, now, now);
+        let month_old = scoring.score([], false, 0, now - Duration::days(30), now);
+        assert!((fresh - 2.0 * month_old).abs() < 1e-9);
+        // Articles dated in the future count as new
+        assert_eq!(scoring.score([], false, 0, now + Duration::days(3), now), fresh);
+    }
+}
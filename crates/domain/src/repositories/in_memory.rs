@@ -1,19 +1,28 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use tokio::sync::RwLock;
 
 use crate::{
-    Article, ArticleFilters, ArticleId, ArticlesEnvelope,
-    Comment, CommentId, FeedFilters, User, UserId,
+    Article, ArticleFilters, ArticleId, ArticleRevision, ArticleSort, ArticlesEnvelope,
+    ArticleSearchHit, ArticleSummary, AuthoredContentPolicy, Blob, Bookmark, BookmarkFilters, BookmarksEnvelope, Coauthor, Comment, CommentId, Cursor, CursorDirection, CursorPage, FeedFilters, FeedReason, ModerationRecord, Pagination, ReactionCount, ReactionKind, RelatedScoring, Report, ReportId, ReportStatus, ReportTarget,
+    RevisionId, SearchFilters, SearchResultsEnvelope, Series, SeriesId, SeriesKind, SitemapEntry, SitemapLocation, Tag, TagCount, TagList, Trashed, User, UserId,
     services::{add_follower, is_following, remove_follower, is_article_favorited},
-    repositories::{ArticlesRepository, CommentsRepository, UsersRepository},
+    repositories::{
+        ArticlesRepository, BlobStore, CommentsRepository, PartOfAnotherSeries, ReportsRepository,
+        RevisionsRepository, SeriesRepository, SlugTaken, TagsRepository, UsersRepository,
+    },
 };
 
 #[derive(Clone, Default)]
 pub struct InMemoryUsersRepository {
     users: Arc<RwLock<Vec<User>>>,
     followers: Arc<RwLock<HashMap<UserId, std::collections::HashSet<UserId>>>>,
+    /// (blocker, blocked) pairs
+    blocks: Arc<RwLock<HashSet<(UserId, UserId)>>>,
+    /// (muter, muted) pairs
+    mutes: Arc<RwLock<HashSet<(UserId, UserId)>>>,
 }
 
 impl InMemoryUsersRepository {
@@ -71,13 +80,194 @@ impl UsersRepository for InMemoryUsersRepository {
         let followers = self.followers.read().await;
         Ok(is_following(&followers, followee_id, follower_id))
     }
+
+    async fn list_followed_users(&self, follower_id: UserId) -> anyhow::Result<Vec<User>> {
+        let users = self.users.read().await;
+        let followers = self.followers.read().await;
+        let mut followed: Vec<User> = users
+            .iter()
+            .filter(|u| is_following(&followers, u.id, follower_id))
+            .cloned()
+            .collect();
+        followed.sort_by(|a, b| a.username.as_str().cmp(b.username.as_str()));
+        Ok(followed)
+    }
+
+    async fn list_blocked_users(&self, blocker_id: UserId) -> anyhow::Result<Vec<User>> {
+        let users = self.users.read().await;
+        let blocks = self.blocks.read().await;
+        let mut blocked: Vec<User> = users
+            .iter()
+            .filter(|u| blocks.contains(&(blocker_id, u.id)))
+            .cloned()
+            .collect();
+        blocked.sort_by(|a, b| a.username.as_str().cmp(b.username.as_str()));
+        Ok(blocked)
+    }
+
+    async fn list_muted_users(&self, muter_id: UserId) -> anyhow::Result<Vec<User>> {
+        let users = self.users.read().await;
+        let mutes = self.mutes.read().await;
+        let mut muted: Vec<User> = users
+            .iter()
+            .filter(|u| mutes.contains(&(muter_id, u.id)))
+            .cloned()
+            .collect();
+        muted.sort_by(|a, b| a.username.as_str().cmp(b.username.as_str()));
+        Ok(muted)
+    }
+
+    async fn deactivate_user(&self, id: UserId, deactivated_at: DateTime<Utc>) -> anyhow::Result<()> {
+        let mut users = self.users.write().await;
+        if let Some(user) = users.iter_mut().find(|u| u.id == id) {
+            user.deactivate(deactivated_at);
+        }
+        Ok(())
+    }
+
+    async fn reactivate_user(&self, id: UserId) -> anyhow::Result<()> {
+        let mut users = self.users.write().await;
+        if let Some(user) = users.iter_mut().find(|u| u.id == id) {
+            user.reactivate();
+        }
+        Ok(())
+    }
+
+    async fn suspend_user(&self, id: UserId, suspended_at: DateTime<Utc>) -> anyhow::Result<()> {
+        let mut users = self.users.write().await;
+        if let Some(user) = users.iter_mut().find(|u| u.id == id) {
+            user.suspended_at = Some(suspended_at);
+        }
+        Ok(())
+    }
+
+    async fn list_deactivated_users(&self, cutoff: DateTime<Utc>) -> anyhow::Result<Vec<User>> {
+        let users = self.users.read().await;
+        Ok(users
+            .iter()
+            .filter(|u| u.deactivated_at.is_some_and(|at| at <= cutoff))
+            .cloned()
+            .collect())
+    }
+
+    async fn delete_user(&self, id: UserId) -> anyhow::Result<()> {
+        let mut users = self.users.write().await;
+        users.retain(|u| u.id != id);
+
+        let mut followers = self.followers.write().await;
+        followers.remove(&id);
+        for follower_set in followers.values_mut() {
+            follower_set.remove(&id);
+        }
+
+        self.blocks.write().await.retain(|(a, b)| *a != id && *b != id);
+        self.mutes.write().await.retain(|(a, b)| *a != id && *b != id);
+        Ok(())
+    }
+
+    async fn block_user(&self, blocker_id: UserId, blocked_id: UserId) -> anyhow::Result<()> {
+        self.blocks.write().await.insert((blocker_id, blocked_id));
+
+        let mut followers = self.followers.write().await;
+        remove_follower(&mut followers, blocker_id, blocked_id);
+        remove_follower(&mut followers, blocked_id, blocker_id);
+        Ok(())
+    }
+
+    async fn unblock_user(&self, blocker_id: UserId, blocked_id: UserId) -> anyhow::Result<()> {
+        self.blocks.write().await.remove(&(blocker_id, blocked_id));
+        Ok(())
+    }
+
+    async fn is_blocking(&self, blocker_id: UserId, blocked_id: UserId) -> anyhow::Result<bool> {
+        Ok(self.blocks.read().await.contains(&(blocker_id, blocked_id)))
+    }
+
+    async fn mute_user(&self, muter_id: UserId, muted_id: UserId) -> anyhow::Result<()> {
+        self.mutes.write().await.insert((muter_id, muted_id));
+        Ok(())
+    }
+
+    async fn unmute_user(&self, muter_id: UserId, muted_id: UserId) -> anyhow::Result<()> {
+        self.mutes.write().await.remove(&(muter_id, muted_id));
+        Ok(())
+    }
+
+    async fn is_muting(&self, muter_id: UserId, muted_id: UserId) -> anyhow::Result<bool> {
+        Ok(self.mutes.read().await.contains(&(muter_id, muted_id)))
+    }
+
+    async fn list_hidden_authors(&self, viewer_id: UserId) -> anyhow::Result<Vec<UserId>> {
+        let mutes = self.mutes.read().await;
+        let blocks = self.blocks.read().await;
+        let mut hidden: Vec<UserId> = mutes
+            .iter()
+            .filter(|(muter, _)| *muter == viewer_id)
+            .map(|(_, muted)| *muted)
+            .chain(
+                blocks
+                    .iter()
+                    .filter(|(_, blocked)| *blocked == viewer_id)
+                    .map(|(blocker, _)| *blocker),
+            )
+            .collect();
+        hidden.sort_by_key(|id| id.as_uuid());
+        hidden.dedup();
+        Ok(hidden)
+    }
+}
+
+type FavoriteTimes = HashMap<(ArticleId, UserId), DateTime<Utc>>;
+type Bookmarks = HashMap<(UserId, ArticleId), Bookmark>;
+type Coauthors = HashMap<(ArticleId, UserId), Coauthor>;
+/// (article or comment, user, kind) triples, oldest first
+type Reactions<T> = Vec<(T, UserId, ReactionKind)>;
+
+/// Counts of the reactions `target` received, ordered as the repositories
+/// promise
+fn tally_reactions<T: Eq>(reactions: &Reactions<T>, target: &T, viewer: Option<UserId>) -> Vec<ReactionCount> {
+    let mut counts: Vec<ReactionCount> = Vec::new();
+    for (_, user_id, kind) in reactions.iter().filter(|(reacted_to, _, _)| reacted_to == target) {
+        let index = match counts.iter().position(|count| count.kind == *kind) {
+            Some(index) => index,
+            None => {
+                counts.push(ReactionCount {
+                    kind: kind.clone(),
+                    count: 0,
+                    reacted: false,
+                });
+                counts.len() - 1
+            }
+        };
+        counts[index].count += 1;
+        counts[index].reacted |= viewer == Some(*user_id);
+    }
+    counts.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.kind.cmp(&b.kind)));
+    counts
 }
 
 #[derive(Clone)]
 pub struct InMemoryArticlesRepository {
     articles: Arc<RwLock<Vec<Article>>>,
     favorites: Arc<RwLock<HashMap<ArticleId, std::collections::HashSet<UserId>>>>,
+    /// When each favorite was added, for the trending sort
+    favorited_at: Arc<RwLock<FavoriteTimes>>,
+    /// previous slug -> article it now belongs to
+    slug_history: Arc<RwLock<HashMap<String, ArticleId>>>,
+    /// alias -> tag it stands for
+    tag_aliases: Arc<RwLock<HashMap<Tag, Tag>>>,
+    /// user -> tags they follow
+    tag_follows: Arc<RwLock<HashMap<UserId, HashSet<Tag>>>>,
+    bookmarks: Arc<RwLock<Bookmarks>>,
+    /// Invitations, pending and accepted; accepted ones are mirrored in
+    /// each article's `coauthor_ids`
+    coauthors: Arc<RwLock<Coauthors>>,
+    reactions: Arc<RwLock<Reactions<ArticleId>>>,
+    /// Deleted articles, out of `articles` so that nothing else sees them
+    trash: Arc<RwLock<Vec<Trashed<Article>>>>,
     users_repo: InMemoryUsersRepository,
+    /// Where comments on erased articles go from, the way Postgres cascades
+    comments_repo: Option<InMemoryCommentsRepository>,
 }
 
 impl InMemoryArticlesRepository {
@@ -85,14 +275,98 @@ impl InMemoryArticlesRepository {
         Self {
             articles: Arc::new(RwLock::new(Vec::new())),
             favorites: Arc::new(RwLock::new(HashMap::new())),
+            favorited_at: Arc::new(RwLock::new(HashMap::new())),
+            slug_history: Arc::new(RwLock::new(HashMap::new())),
+            tag_aliases: Arc::new(RwLock::new(HashMap::new())),
+            tag_follows: Arc::new(RwLock::new(HashMap::new())),
+            bookmarks: Arc::new(RwLock::new(HashMap::new())),
+            coauthors: Arc::new(RwLock::new(HashMap::new())),
+            reactions: Arc::new(RwLock::new(Vec::new())),
+            trash: Arc::new(RwLock::new(Vec::new())),
             users_repo,
+            comments_repo: None,
+        }
+    }
+
+    /// Erasing an article also erases its comments in `comments_repo`
+    pub fn with_comments(mut self, comments_repo: InMemoryCommentsRepository) -> Self {
+        self.comments_repo = Some(comments_repo);
+        self
+    }
+
+    /// Drops everything kept alongside the erased articles
+    async fn forget_articles(&self, ids: &[ArticleId]) {
+        self.favorites.write().await.retain(|article_id, _| !ids.contains(article_id));
+        self.favorited_at.write().await.retain(|(article_id, _), _| !ids.contains(article_id));
+        self.slug_history.write().await.retain(|_, article_id| !ids.contains(article_id));
+        self.bookmarks.write().await.retain(|(_, article_id), _| !ids.contains(article_id));
+        self.coauthors.write().await.retain(|(article_id, _), _| !ids.contains(article_id));
+        self.reactions.write().await.retain(|(article_id, _, _)| !ids.contains(article_id));
+        if let Some(comments_repo) = &self.comments_repo {
+            comments_repo.forget_articles(ids).await;
+        }
+    }
+
+    async fn resolve_aliases(&self, mut article: Article) -> Article {
+        let aliases = self.tag_aliases.read().await;
+        article.tag_list = article
+            .tag_list
+            .map(|tag| aliases.get(&tag).cloned().unwrap_or(tag));
+        article
+    }
+
+    /// Fails like the unique slug constraint in Postgres does
+    async fn check_slug_free(&self, article: &Article) -> anyhow::Result<()> {
+        let clashes = |other: &Article| other.slug == article.slug && other.id != article.id;
+        let taken = self.articles.read().await.iter().any(clashes)
+            || self.trash.read().await.iter().any(|t| clashes(&t.item));
+        if taken {
+            return Err(SlugTaken(article.slug.as_str().to_owned()).into());
+        }
+        Ok(())
+    }
+
+    /// Every sitemap entry, in the order Postgres lists them
+    async fn sitemap(&self) -> Vec<SitemapEntry> {
+        let articles = self.articles.read().await;
+        let users = self.users_repo.users.read().await;
+
+        let mut listed: Vec<(&Article, &User)> = articles
+            .iter()
+            .filter(|a| a.is_public())
+            .filter_map(|a| {
+                let author = users.iter().find(|u| u.id == a.author_id && !u.is_deactivated())?;
+                Some((a, author))
+            })
+            .collect();
+        listed.sort_by(|(a, _), (b, _)| a.slug.as_str().cmp(b.slug.as_str()));
+
+        let mut profiles: Vec<(&User, DateTime<Utc>)> = Vec::new();
+        for (article, author) in &listed {
+            match profiles.iter_mut().find(|(user, _)| user.id == author.id) {
+                Some((_, last_modified)) => *last_modified = (*last_modified).max(article.updated_at),
+                None => profiles.push((author, author.updated_at.max(article.updated_at))),
+            }
         }
+        profiles.sort_by(|(a, _), (b, _)| a.username.as_str().cmp(b.username.as_str()));
+
+        let articles = listed.into_iter().map(|(article, _)| SitemapEntry {
+            location: SitemapLocation::Article(article.slug.as_str().to_owned()),
+            last_modified: article.updated_at,
+        });
+        let profiles = profiles.into_iter().map(|(user, last_modified)| SitemapEntry {
+            location: SitemapLocation::Profile(user.username.as_str().to_owned()),
+            last_modified,
+        });
+        articles.chain(profiles).collect()
     }
 }
 
 #[async_trait]
 impl ArticlesRepository for InMemoryArticlesRepository {
     async fn create_article(&self, article: Article) -> anyhow::Result<Article> {
+        let article = self.resolve_aliases(article).await;
+        self.check_slug_free(&article).await?;
         let mut articles = self.articles.write().await;
         articles.push(article.clone());
         Ok(article)
@@ -108,9 +382,25 @@ impl ArticlesRepository for InMemoryArticlesRepository {
         Ok(articles.iter().find(|a| a.id == id).cloned())
     }
 
+    async fn get_articles_by_slugs(&self, slugs: &[String]) -> anyhow::Result<Vec<Article>> {
+        let articles = self.articles.read().await;
+        Ok(articles
+            .iter()
+            .filter(|a| slugs.iter().any(|slug| slug == a.slug.as_str()))
+            .cloned()
+            .collect())
+    }
+
     async fn update_article(&self, article: Article) -> anyhow::Result<Article> {
+        let article = self.resolve_aliases(article).await;
+        self.check_slug_free(&article).await?;
         let mut articles = self.articles.write().await;
         if let Some(existing) = articles.iter_mut().find(|a| a.id == article.id) {
+            let article = Article {
+                coauthor_ids: existing.coauthor_ids.clone(),
+                hidden_at: existing.hidden_at,
+                ..article
+            };
             *existing = article.clone();
             Ok(article)
         } else {
@@ -118,31 +408,47 @@ impl ArticlesRepository for InMemoryArticlesRepository {
         }
     }
 
-    async fn delete_article(&self, id: ArticleId) -> anyhow::Result<()> {
+    async fn delete_article(&self, id: ArticleId, deleted_at: DateTime<Utc>) -> anyhow::Result<()> {
         let mut articles = self.articles.write().await;
-        articles.retain(|a| a.id != id);
+        if let Some(index) = articles.iter().position(|a| a.id == id) {
+            let item = articles.remove(index);
+            self.trash.write().await.push(Trashed { item, deleted_at });
+        }
+        Ok(())
+    }
+
+    async fn hide_article(&self, id: ArticleId, hidden_at: DateTime<Utc>) -> anyhow::Result<()> {
+        let mut articles = self.articles.write().await;
+        if let Some(article) = articles.iter_mut().find(|a| a.id == id) {
+            article.hidden_at = Some(hidden_at);
+        }
         Ok(())
     }
 
     async fn list_articles(&self, filters: ArticleFilters) -> anyhow::Result<ArticlesEnvelope> {
+        let hidden = match filters.viewer {
+            Some(viewer) => self.users_repo.list_hidden_authors(viewer).await?,
+            None => Vec::new(),
+        };
         let articles = self.articles.read().await;
         let users = self.users_repo.users.read().await;
         let favorites = self.favorites.read().await;
+        let bookmarks = self.bookmarks.read().await;
+        let reactions = self.reactions.read().await;
 
-        // Filter articles
-        let mut filtered: Vec<&Article> = articles.iter().collect();
-
-        if let Some(ref tag) = filters.tag {
-            filtered.retain(|a| a.tag_list.contains(tag));
-        }
-
-        if let Some(ref author_username) = filters.author {
-            if let Some(author) = users.iter().find(|u| u.username.as_str() == author_username) {
-                filtered.retain(|a| a.author_id == author.id);
-            } else {
-                filtered.clear();
-            }
-        }
+        // Filter articles, hiding those whose author is deactivated or hidden from the viewer,
+        // and unpublished ones from everyone but their author
+        let mut filtered: Vec<&Article> = articles
+            .iter()
+            .filter(|a| a.is_visible_to(filters.viewer))
+            .filter(|a| !hidden.contains(&a.author_id))
+            .filter(|a| {
+                users
+                    .iter()
+                    .find(|u| u.id == a.author_id && !u.is_deactivated())
+                    .is_some_and(|author| filters.matches(a, author.username.as_str()))
+            })
+            .collect();
 
         if let Some(ref favorited_username) = filters.favorited {
             if let Some(user) = users.iter().find(|u| u.username.as_str() == favorited_username) {
@@ -153,77 +459,137 @@ impl ArticlesRepository for InMemoryArticlesRepository {
         }
 
         let total = filtered.len();
-
-        // Apply pagination
-        let start = filters.pagination.offset() as usize;
-        let end = (start + filters.pagination.limit() as usize).min(total);
-        let paginated: Vec<&Article> = filtered.into_iter().skip(start).take(end - start).collect();
+        let page = match filters.sort {
+            ArticleSort::Recent => paginate(filtered, filters.pagination, filters.cursor),
+            sort => {
+                let favorited_at = self.favorited_at.read().await;
+                let trending = |a: &Article| {
+                    favorited_at
+                        .iter()
+                        .filter(|((article_id, _), at)| {
+                            *article_id == a.id && filters.trending_since.is_none_or(|since| **at >= since)
+                        })
+                        .count()
+                };
+                // Newest first breaks ties, as it does in Postgres
+                filtered.sort_by_key(|a| std::cmp::Reverse((a.listed_at(), a.id.as_uuid())));
+                match sort {
+                    ArticleSort::Updated => filtered.sort_by_key(|a| std::cmp::Reverse(a.updated_at)),
+                    ArticleSort::Popular => filtered.sort_by_key(|a| std::cmp::Reverse(a.favorites_count)),
+                    _ => filtered.sort_by_cached_key(|a| std::cmp::Reverse(trending(a))),
+                }
+                let offset = filters.pagination.offset();
+                let items = filtered
+                    .into_iter()
+                    .skip(offset as usize)
+                    .take(filters.pagination.limit() as usize)
+                    .collect();
+                // Cursors only follow the newest-first order
+                CursorPage { items, next: None, prev: None }
+            }
+        };
 
         // Build summaries
         let mut summaries = Vec::new();
-        for article in paginated {
+        for article in page.items {
             if let Some(author) = users.iter().find(|u| u.id == article.author_id) {
                 let following = false; // TODO: needs viewer context
                 let favorited = false; // TODO: needs viewer context
+                let bookmarked = filters
+                    .viewer
+                    .is_some_and(|viewer| bookmarks.contains_key(&(viewer, article.id)));
                 let profile = author.to_profile(following);
-                summaries.push(article.to_summary(profile, favorited));
+                summaries.push(
+                    article
+                        .to_summary(profile, favorited)
+                        .with_bookmarked(bookmarked)
+                        .with_reactions(tally_reactions(&reactions, &article.id, filters.viewer)),
+                );
             }
         }
 
         Ok(ArticlesEnvelope {
             articles: summaries,
             articles_count: total,
+            next_cursor: page.next,
+            prev_cursor: page.prev,
         })
     }
 
     async fn feed_articles(&self, user_id: UserId, filters: FeedFilters) -> anyhow::Result<ArticlesEnvelope> {
+        let hidden = self.users_repo.list_hidden_authors(user_id).await?;
         let articles = self.articles.read().await;
         let users = self.users_repo.users.read().await;
         let followers = self.users_repo.followers.read().await;
         let favorites = self.favorites.read().await;
+        let tag_follows = self.tag_follows.read().await;
+        let bookmarks = self.bookmarks.read().await;
+        let reactions = self.reactions.read().await;
+        let no_tags = HashSet::new();
+        let followed_tags = tag_follows.get(&user_id).unwrap_or(&no_tags);
 
-        // Get all users that this user follows
-        let mut followed_users = Vec::new();
-        for (target_id, follower_set) in followers.iter() {
-            if follower_set.contains(&user_id) {
-                followed_users.push(*target_id);
+        let reason = |article: &Article| {
+            let mut tags = TagList::default();
+            for tag in article.tag_list.as_slice().iter().filter(|tag| followed_tags.contains(*tag)) {
+                tags.push(tag.clone());
             }
-        }
+            let followed_author = std::iter::once(&article.author_id)
+                .chain(&article.coauthor_ids)
+                .any(|author_id| is_following(&followers, *author_id, user_id));
+            FeedReason {
+                followed_author,
+                followed_tags: tags,
+            }
+        };
 
-        // Filter articles by followed authors
+        // Published articles by followed authors or with followed tags, from
+        // authors that are still active and not muted
         let filtered: Vec<&Article> = articles
             .iter()
-            .filter(|a| followed_users.contains(&a.author_id))
+            .filter(|a| a.is_public())
+            .filter(|a| {
+                let reason = reason(a);
+                reason.followed_author || !reason.followed_tags.as_slice().is_empty()
+            })
+            .filter(|a| !hidden.contains(&a.author_id))
+            .filter(|a| users.iter().any(|u| u.id == a.author_id && !u.is_deactivated()))
             .collect();
 
         let total = filtered.len();
-
-        // Apply pagination
-        let start = filters.pagination.offset() as usize;
-        let end = (start + filters.pagination.limit() as usize).min(total);
-        let paginated: Vec<&Article> = filtered.into_iter().skip(start).take(end - start).collect();
+        let page = paginate(filtered, filters.pagination, filters.cursor);
 
         // Build summaries
         let mut summaries = Vec::new();
-        for article in paginated {
+        for article in page.items {
             if let Some(author) = users.iter().find(|u| u.id == article.author_id) {
-                let following = true; // By definition
+                let reason = reason(article);
                 let favorited = is_article_favorited(&favorites, article.id, user_id);
+                let bookmarked = bookmarks.contains_key(&(user_id, article.id));
+                let following = is_following(&followers, author.id, user_id);
                 let profile = author.to_profile(following);
-                summaries.push(article.to_summary(profile, favorited));
+                summaries.push(
+                    article
+                        .to_summary(profile, favorited)
+                        .with_bookmarked(bookmarked)
+                        .with_reactions(tally_reactions(&reactions, &article.id, Some(user_id)))
+                        .with_reason(reason),
+                );
             }
         }
 
         Ok(ArticlesEnvelope {
             articles: summaries,
             articles_count: total,
+            next_cursor: page.next,
+            prev_cursor: page.prev,
         })
     }
 
-    async fn favorite_article(&self, user_id: UserId, article_id: ArticleId) -> anyhow::Result<()> {
+    async fn favorite_article(&self, user_id: UserId, article_id: ArticleId, favorited_at: DateTime<Utc>) -> anyhow::Result<()> {
         let mut favorites = self.favorites.write().await;
         let entry = favorites.entry(article_id).or_insert_with(std::collections::HashSet::new);
         entry.insert(user_id);
+        self.favorited_at.write().await.entry((article_id, user_id)).or_insert(favorited_at);
 
         // Update favorites count
         let mut articles = self.articles.write().await;
@@ -239,6 +605,7 @@ impl ArticlesRepository for InMemoryArticlesRepository {
         if let Some(entry) = favorites.get_mut(&article_id) {
             entry.remove(&user_id);
         }
+        self.favorited_at.write().await.remove(&(article_id, user_id));
 
         // Update favorites count
         let mut articles = self.articles.write().await;
@@ -253,87 +620,1207 @@ impl ArticlesRepository for InMemoryArticlesRepository {
         let favorites = self.favorites.read().await;
         Ok(is_article_favorited(&favorites, article_id, user_id))
     }
-}
 
-#[derive(Clone, Default)]
-pub struct InMemoryCommentsRepository {
-    comments: Arc<RwLock<Vec<Comment>>>,
-}
+    async fn list_favorited_articles(&self, user_id: UserId) -> anyhow::Result<Vec<Article>> {
+        let articles = self.articles.read().await;
+        let favorites = self.favorites.read().await;
+        let favorited_at = self.favorited_at.read().await;
+        let mut favorited: Vec<(DateTime<Utc>, Article)> = articles
+            .iter()
+            .filter(|a| is_article_favorited(&favorites, a.id, user_id))
+            .map(|a| (favorited_at.get(&(a.id, user_id)).copied().unwrap_or_default(), a.clone()))
+            .collect();
+        favorited.sort_by_key(|(at, _)| *at);
+        Ok(favorited.into_iter().map(|(_, article)| article).collect())
+    }
 
-impl InMemoryCommentsRepository {
-    pub fn new() -> Self {
-        Self::default()
+    async fn delete_articles_by_author(&self, author_id: UserId) -> anyhow::Result<()> {
+        let mut erased = Vec::new();
+        self.trash.write().await.retain(|t| {
+            if t.item.author_id == author_id {
+                erased.push(t.item.id);
+                false
+            } else {
+                true
+            }
+        });
+        self.articles.write().await.retain(|a| {
+            if a.author_id == author_id {
+                erased.push(a.id);
+                false
+            } else {
+                true
+            }
+        });
+        self.forget_articles(&erased).await;
+        Ok(())
     }
-}
 
-#[async_trait]
-impl CommentsRepository for InMemoryCommentsRepository {
-    async fn create_comment(&self, comment: Comment) -> anyhow::Result<Comment> {
-        let mut comments = self.comments.write().await;
-        comments.push(comment.clone());
-        Ok(comment)
+    async fn reassign_articles(&self, from_author_id: UserId, to_author_id: UserId) -> anyhow::Result<()> {
+        let mut articles = self.articles.write().await;
+        for article in articles.iter_mut().filter(|a| a.author_id == from_author_id) {
+            article.author_id = to_author_id;
+        }
+        let mut trash = self.trash.write().await;
+        for trashed in trash.iter_mut().filter(|t| t.item.author_id == from_author_id) {
+            trashed.item.author_id = to_author_id;
+        }
+        Ok(())
     }
 
-    async fn get_comments_by_article(&self, article_id: ArticleId) -> anyhow::Result<Vec<Comment>> {
-        let comments = self.comments.read().await;
-        Ok(comments
+    async fn remove_favorites_by_user(&self, user_id: UserId) -> anyhow::Result<()> {
+        let mut favorites = self.favorites.write().await;
+        self.favorited_at.write().await.retain(|(_, favoriter), _| *favoriter != user_id);
+        let mut articles = self.articles.write().await;
+        for (article_id, users) in favorites.iter_mut() {
+            if users.remove(&user_id)
+                && let Some(article) = articles.iter_mut().find(|a| a.id == *article_id)
+            {
+                article.favorites_count = article.favorites_count.saturating_sub(1);
+            }
+        }
+        Ok(())
+    }
+
+    async fn erase_account(
+        &self,
+        user_id: UserId,
+        authored_content: AuthoredContentPolicy,
+        now: DateTime<Utc>,
+    ) -> anyhow::Result<()> {
+        match authored_content {
+            AuthoredContentPolicy::Delete => {
+                if let Some(comments_repo) = &self.comments_repo {
+                    comments_repo.delete_comments_by_author(user_id).await?;
+                }
+                self.delete_articles_by_author(user_id).await?;
+            }
+            AuthoredContentPolicy::Anonymise => {
+                let placeholder = UserId::deleted_user();
+                if self.users_repo.get_user_by_id(placeholder).await?.is_none() {
+                    self.users_repo.create_user(User::deleted_placeholder(now)).await?;
+                }
+                if let Some(comments_repo) = &self.comments_repo {
+                    comments_repo.reassign_comments(user_id, placeholder).await?;
+                }
+                self.reassign_articles(user_id, placeholder).await?;
+            }
+        }
+
+        self.remove_favorites_by_user(user_id).await?;
+        self.remove_article_reactions_by_user(user_id).await?;
+        if let Some(comments_repo) = &self.comments_repo {
+            comments_repo.remove_comment_reactions_by_user(user_id).await?;
+        }
+        self.users_repo.delete_user(user_id).await
+    }
+
+    async fn add_article_reaction(&self, article_id: ArticleId, user_id: UserId, kind: &ReactionKind) -> anyhow::Result<()> {
+        let mut reactions = self.reactions.write().await;
+        let reaction = (article_id, user_id, kind.clone());
+        if !reactions.contains(&reaction) {
+            reactions.push(reaction);
+        }
+        Ok(())
+    }
+
+    async fn remove_article_reaction(&self, article_id: ArticleId, user_id: UserId, kind: &ReactionKind) -> anyhow::Result<()> {
+        self.reactions
+            .write()
+            .await
+            .retain(|reaction| *reaction != (article_id, user_id, kind.clone()));
+        Ok(())
+    }
+
+    async fn article_reactions(&self, article_id: ArticleId, viewer: Option<UserId>) -> anyhow::Result<Vec<ReactionCount>> {
+        let reactions = self.reactions.read().await;
+        Ok(tally_reactions(&reactions, &article_id, viewer))
+    }
+
+    async fn remove_article_reactions_by_user(&self, user_id: UserId) -> anyhow::Result<()> {
+        self.reactions.write().await.retain(|(_, reactor, _)| *reactor != user_id);
+        Ok(())
+    }
+
+    async fn list_article_reactions_by_user(&self, user_id: UserId) -> anyhow::Result<Vec<(ArticleId, ReactionKind)>> {
+        let reactions = self.reactions.read().await;
+        Ok(reactions
             .iter()
-            .filter(|c| c.article_id == article_id)
-            .cloned()
+            .filter(|(_, reactor, _)| *reactor == user_id)
+            .map(|(article_id, _, kind)| (*article_id, kind.clone()))
             .collect())
     }
 
-    async fn delete_comment(&self, id: CommentId) -> anyhow::Result<()> {
-        let mut comments = self.comments.write().await;
-        comments.retain(|c| c.id != id);
+    async fn get_article_by_previous_slug(&self, slug: &str) -> anyhow::Result<Option<Article>> {
+        let Some(article_id) = self.slug_history.read().await.get(slug).copied() else {
+            return Ok(None);
+        };
+        let articles = self.articles.read().await;
+        Ok(articles.iter().find(|a| a.id == article_id).cloned())
+    }
+
+    async fn record_slug_change(&self, article_id: ArticleId, old_slug: &str, new_slug: &str) -> anyhow::Result<()> {
+        let mut history = self.slug_history.write().await;
+        history.remove(new_slug);
+        history.insert(old_slug.to_owned(), article_id);
         Ok(())
     }
 
-    async fn get_comment_by_id(&self, id: CommentId) -> anyhow::Result<Option<Comment>> {
-        let comments = self.comments.read().await;
-        Ok(comments.iter().find(|c| c.id == id).cloned())
+    async fn list_articles_by_author(&self, author_id: UserId) -> anyhow::Result<Vec<(Article, Option<DateTime<Utc>>)>> {
+        let articles = self.articles.read().await;
+        let trash = self.trash.read().await;
+        let mut found: Vec<(Article, Option<DateTime<Utc>>)> = articles
+            .iter()
+            .filter(|a| a.author_id == author_id)
+            .map(|a| (a.clone(), None))
+            .chain(
+                trash
+                    .iter()
+                    .filter(|t| t.item.author_id == author_id)
+                    .map(|t| (t.item.clone(), Some(t.deleted_at))),
+            )
+            .collect();
+        found.sort_by_key(|(a, _)| a.created_at);
+        Ok(found)
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::{Email, PasswordHash, Username};
+    async fn list_drafts(&self, author_id: UserId, pagination: Pagination) -> anyhow::Result<ArticlesEnvelope> {
+        let articles = self.articles.read().await;
+        let users = self.users_repo.users.read().await;
+        let favorites = self.favorites.read().await;
+        let bookmarks = self.bookmarks.read().await;
+        let reactions = self.reactions.read().await;
+        let Some(author) = users.iter().find(|u| u.id == author_id) else {
+            return Ok(ArticlesEnvelope::from(Vec::new()));
+        };
 
-    fn create_test_user(id: UserId, username: &str, email: &str) -> User {
-        User::new(
-            id,
-            Email::parse(email).unwrap(),
-            Username::new(username).unwrap(),
-            PasswordHash::new("hash".to_string()).unwrap(),
-            chrono::Utc::now(),
-        )
+        let mut drafts: Vec<&Article> = articles
+            .iter()
+            .filter(|a| a.author_id == author_id && !a.is_published())
+            .collect();
+        drafts.sort_by_key(|a| std::cmp::Reverse(a.updated_at));
+        let total = drafts.len();
+
+        let summaries = drafts
+            .into_iter()
+            .skip(pagination.offset() as usize)
+            .take(pagination.limit() as usize)
+            .map(|a| {
+                let favorited = is_article_favorited(&favorites, a.id, author_id);
+                a.to_summary(author.to_profile(false), favorited)
+                    .with_bookmarked(bookmarks.contains_key(&(author_id, a.id)))
+                    .with_reactions(tally_reactions(&reactions, &a.id, Some(author_id)))
+            })
+            .collect();
+
+        Ok(ArticlesEnvelope {
+            articles: summaries,
+            articles_count: total,
+            next_cursor: None,
+            prev_cursor: None,
+        })
     }
 
-    #[tokio::test]
-    async fn test_create_and_get_user() {
-        let repo = InMemoryUsersRepository::new();
-        let user = create_test_user(UserId::random(), "testuser", "test@example.com");
+    async fn publish_due_articles(&self, now: DateTime<Utc>) -> anyhow::Result<Vec<Article>> {
+        let mut articles = self.articles.write().await;
+        let mut published = Vec::new();
+        for article in articles.iter_mut().filter(|a| a.is_due(now)) {
+            // Goes live at the time it was scheduled for, even if the run is late
+            article.status = crate::ArticleStatus::Published;
+            article.updated_at = now;
+            published.push(article.clone());
+        }
+        Ok(published)
+    }
 
-        repo.create_user(user.clone()).await.unwrap();
-        
-        let found = repo.get_user_by_email("test@example.com").await.unwrap();
-        assert!(found.is_some());
-        assert_eq!(found.unwrap().username.as_str(), "testuser");
+    async fn search_articles(&self, filters: SearchFilters) -> anyhow::Result<SearchResultsEnvelope> {
+        let hidden = match filters.viewer {
+            Some(viewer) => self.users_repo.list_hidden_authors(viewer).await?,
+            None => Vec::new(),
+        };
+        let articles = self.articles.read().await;
+        let users = self.users_repo.users.read().await;
+        let followers = self.users_repo.followers.read().await;
+        let favorites = self.favorites.read().await;
+        let bookmarks = self.bookmarks.read().await;
+        let reactions = self.reactions.read().await;
+
+        // Same visibility as list_articles, then the naive word match
+        let mut ranked: Vec<(&Article, f64)> = articles
+            .iter()
+            .filter(|a| a.is_visible_to(filters.viewer))
+            .filter(|a| users.iter().any(|u| u.id == a.author_id && !u.is_deactivated()))
+            .filter(|a| !hidden.contains(&a.author_id))
+            .filter_map(|a| {
+                let rank = filters.query.rank(&a.title, &a.description, &a.body)?;
+                Some((a, rank))
+            })
+            .collect();
+        ranked.sort_by(|(a, a_rank), (b, b_rank)| {
+            b_rank.total_cmp(a_rank).then(b.created_at.cmp(&a.created_at))
+        });
+
+        let total = ranked.len();
+        let hits = ranked
+            .into_iter()
+            .skip(filters.pagination.offset() as usize)
+            .take(filters.pagination.limit() as usize)
+            .filter_map(|(article, rank)| {
+                let author = users.iter().find(|u| u.id == article.author_id)?;
+                let (following, favorited, bookmarked) = match filters.viewer {
+                    Some(viewer) => (
+                        is_following(&followers, author.id, viewer),
+                        is_article_favorited(&favorites, article.id, viewer),
+                        bookmarks.contains_key(&(viewer, article.id)),
+                    ),
+                    None => (false, false, false),
+                };
+                Some(ArticleSearchHit {
+                    article: article
+                        .to_summary(author.to_profile(following), favorited)
+                        .with_bookmarked(bookmarked)
+                        .with_reactions(tally_reactions(&reactions, &article.id, filters.viewer)),
+                    rank,
+                    highlight: filters.query.highlight(&article.body),
+                })
+            })
+            .collect();
+
+        Ok(SearchResultsEnvelope {
+            articles: hits,
+            articles_count: total,
+        })
     }
 
-    #[tokio::test]
-    async fn test_follow_and_is_following() {
-        let repo = InMemoryUsersRepository::new();
-        let user1_id = UserId::random();
-        let user2_id = UserId::random();
+    async fn bookmark_article(&self, bookmark: Bookmark) -> anyhow::Result<Bookmark> {
+        let mut bookmarks = self.bookmarks.write().await;
+        let key = (bookmark.user_id, bookmark.article_id);
+        let created_at = bookmarks.get(&key).map_or(bookmark.created_at, |b| b.created_at);
+        let bookmark = Bookmark { created_at, ..bookmark };
+        bookmarks.insert(key, bookmark.clone());
+        Ok(bookmark)
+    }
 
-        repo.follow_user(user1_id, user2_id).await.unwrap();
+    async fn remove_bookmark(&self, user_id: UserId, article_id: ArticleId) -> anyhow::Result<()> {
+        self.bookmarks.write().await.remove(&(user_id, article_id));
+        Ok(())
+    }
+
+    async fn get_bookmark(&self, user_id: UserId, article_id: ArticleId) -> anyhow::Result<Option<Bookmark>> {
+        Ok(self.bookmarks.read().await.get(&(user_id, article_id)).cloned())
+    }
+
+    async fn list_bookmarks_by_user(&self, user_id: UserId) -> anyhow::Result<Vec<Bookmark>> {
+        let bookmarks = self.bookmarks.read().await;
+        let mut kept: Vec<Bookmark> = bookmarks.values().filter(|b| b.user_id == user_id).cloned().collect();
+        kept.sort_by_key(|b| std::cmp::Reverse(b.created_at));
+        Ok(kept)
+    }
+
+    async fn list_bookmarks(&self, user_id: UserId, filters: BookmarkFilters) -> anyhow::Result<BookmarksEnvelope> {
+        let articles = self.articles.read().await;
+        let users = self.users_repo.users.read().await;
+        let followers = self.users_repo.followers.read().await;
+        let favorites = self.favorites.read().await;
+        let bookmarks = self.bookmarks.read().await;
+        let reactions = self.reactions.read().await;
+
+        // Articles that were unpublished or whose author left drop out of the
+        // list but keep their bookmark, as in Postgres
+        let mut saved: Vec<(&Bookmark, &Article, &User)> = bookmarks
+            .values()
+            .filter(|b| b.user_id == user_id)
+            .filter(|b| filters.folder.is_none() || b.folder == filters.folder)
+            .filter_map(|b| {
+                let article = articles.iter().find(|a| a.id == b.article_id)?;
+                let author = users
+                    .iter()
+                    .find(|u| u.id == article.author_id && !u.is_deactivated())?;
+                article.is_visible_to(Some(user_id)).then_some((b, article, author))
+            })
+            .collect();
+        saved.sort_by_key(|(b, a, _)| std::cmp::Reverse((b.created_at, a.id.as_uuid())));
+
+        let total = saved.len();
+        let views = saved
+            .into_iter()
+            .skip(filters.pagination.offset() as usize)
+            .take(filters.pagination.limit() as usize)
+            .map(|(bookmark, article, author)| {
+                let following = is_following(&followers, author.id, user_id);
+                let favorited = is_article_favorited(&favorites, article.id, user_id);
+                let summary = article
+                    .to_summary(author.to_profile(following), favorited)
+                    .with_bookmarked(true)
+                    .with_reactions(tally_reactions(&reactions, &article.id, Some(user_id)));
+                bookmark.to_view(summary)
+            })
+            .collect();
+
+        Ok(BookmarksEnvelope {
+            bookmarks: views,
+            bookmarks_count: total,
+        })
+    }
+    async fn invite_coauthor(&self, coauthor: Coauthor) -> anyhow::Result<()> {
+        self.coauthors
+            .write()
+            .await
+            .entry((coauthor.article_id, coauthor.user_id))
+            .or_insert(coauthor);
+        Ok(())
+    }
+
+    async fn get_coauthor(&self, article_id: ArticleId, user_id: UserId) -> anyhow::Result<Option<Coauthor>> {
+        Ok(self.coauthors.read().await.get(&(article_id, user_id)).cloned())
+    }
+
+    async fn list_coauthorships(&self, user_id: UserId) -> anyhow::Result<Vec<Coauthor>> {
+        let coauthors = self.coauthors.read().await;
+        let mut invited: Vec<Coauthor> = coauthors.values().filter(|c| c.user_id == user_id).cloned().collect();
+        invited.sort_by_key(|c| c.invited_at);
+        Ok(invited)
+    }
+
+    async fn accept_coauthor(&self, article_id: ArticleId, user_id: UserId, accepted_at: DateTime<Utc>) -> anyhow::Result<()> {
+        let mut coauthors = self.coauthors.write().await;
+        let Some(coauthor) = coauthors.get_mut(&(article_id, user_id)) else {
+            return Ok(());
+        };
+        if coauthor.accepted_at.is_none() {
+            coauthor.accepted_at = Some(accepted_at);
+        }
+        let mut articles = self.articles.write().await;
+        if let Some(article) = articles.iter_mut().find(|a| a.id == article_id)
+            && !article.coauthor_ids.contains(&user_id)
+        {
+            article.coauthor_ids.push(user_id);
+        }
+        Ok(())
+    }
+
+    async fn remove_coauthor(&self, article_id: ArticleId, user_id: UserId) -> anyhow::Result<()> {
+        self.coauthors.write().await.remove(&(article_id, user_id));
+        let mut articles = self.articles.write().await;
+        if let Some(article) = articles.iter_mut().find(|a| a.id == article_id) {
+            article.coauthor_ids.retain(|id| *id != user_id);
+        }
+        Ok(())
+    }
+
+    async fn related_articles(
+        &self,
+        article_id: ArticleId,
+        viewer: Option<UserId>,
+        scoring: &RelatedScoring,
+        limit: u32,
+        now: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<ArticleSummary>> {
+        let hidden = match viewer {
+            Some(viewer) => self.users_repo.list_hidden_authors(viewer).await?,
+            None => Vec::new(),
+        };
+        let articles = self.articles.read().await;
+        let users = self.users_repo.users.read().await;
+        let followers = self.users_repo.followers.read().await;
+        let favorites = self.favorites.read().await;
+        let bookmarks = self.bookmarks.read().await;
+        let reactions = self.reactions.read().await;
+
+        let Some(source) = articles.iter().find(|a| a.id == article_id) else {
+            return Ok(Vec::new());
+        };
+        let tag_uses = |tag: &Tag| {
+            articles
+                .iter()
+                .filter(|a| a.is_public() && a.tag_list.contains(tag))
+                .count()
+        };
+        let source_fans = favorites.get(&source.id);
+
+        let mut scored: Vec<(f64, &Article, &User)> = articles
+            .iter()
+            .filter(|a| a.id != source.id && a.is_public())
+            .filter(|a| !viewer.is_some_and(|viewer| a.can_edit(&viewer)))
+            .filter(|a| !hidden.contains(&a.author_id))
+            .filter_map(|a| {
+                let author = users
+                    .iter()
+                    .find(|u| u.id == a.author_id && !u.is_deactivated())?;
+                let shared: Vec<usize> = a
+                    .tag_list
+                    .as_slice()
+                    .iter()
+                    .filter(|tag| source.tag_list.contains(tag))
+                    .map(tag_uses)
+                    .collect();
+                let same_author = a.author_id == source.author_id;
+                let cofavorites = match (source_fans, favorites.get(&a.id)) {
+                    (Some(mine), Some(theirs)) => mine.intersection(theirs).count(),
+                    _ => 0,
+                };
+                if shared.is_empty() && !same_author && cofavorites == 0 {
+                    return None;
+                }
+                let score = scoring.score(shared, same_author, cofavorites, a.created_at, now);
+                Some((score, a, author))
+            })
+            .collect();
+        // Best first, then newest first, as in Postgres
+        scored.sort_by(|(score_a, a, _), (score_b, b, _)| {
+            score_b
+                .total_cmp(score_a)
+                .then_with(|| (b.created_at, b.id.as_uuid()).cmp(&(a.created_at, a.id.as_uuid())))
+        });
+
+        let summaries = scored
+            .into_iter()
+            .take(limit as usize)
+            .map(|(_, article, author)| {
+                let (following, favorited, bookmarked) = match viewer {
+                    Some(viewer) => (
+                        is_following(&followers, author.id, viewer),
+                        is_article_favorited(&favorites, article.id, viewer),
+                        bookmarks.contains_key(&(viewer, article.id)),
+                    ),
+                    None => (false, false, false),
+                };
+                article
+                    .to_summary(author.to_profile(following), favorited)
+                    .with_bookmarked(bookmarked)
+                    .with_reactions(tally_reactions(&reactions, &article.id, viewer))
+            })
+            .collect();
+        Ok(summaries)
+    }
+
+    async fn count_sitemap_entries(&self) -> anyhow::Result<u64> {
+        Ok(self.sitemap().await.len() as u64)
+    }
+
+    async fn sitemap_entries(&self, offset: u64, limit: u32) -> anyhow::Result<Vec<SitemapEntry>> {
+        Ok(self
+            .sitemap()
+            .await
+            .into_iter()
+            .skip(offset as usize)
+            .take(limit as usize)
+            .collect())
+    }
+
+    async fn get_trashed_article_by_slug(&self, slug: &str) -> anyhow::Result<Option<Trashed<Article>>> {
+        let trash = self.trash.read().await;
+        Ok(trash.iter().find(|t| t.item.slug.as_str() == slug).cloned())
+    }
+
+    async fn list_trashed_articles(&self, author_id: UserId) -> anyhow::Result<Vec<Trashed<Article>>> {
+        let trash = self.trash.read().await;
+        let mut found: Vec<Trashed<Article>> = trash
+            .iter()
+            .filter(|t| t.item.author_id == author_id)
+            .cloned()
+            .collect();
+        found.sort_by_key(|t| std::cmp::Reverse(t.deleted_at));
+        Ok(found)
+    }
+
+    async fn restore_article(&self, id: ArticleId) -> anyhow::Result<()> {
+        let mut trash = self.trash.write().await;
+        let Some(index) = trash.iter().position(|t| t.item.id == id) else {
+            return Ok(());
+        };
+        let mut article = trash.remove(index).item;
+        // Favorites may have been taken back meanwhile, as accounts are erased
+        article.favorites_count = self
+            .favorites
+            .read()
+            .await
+            .get(&id)
+            .map_or(0, |users| users.len() as u32);
+        self.articles.write().await.push(article);
+        Ok(())
+    }
+
+    async fn purge_trashed_articles(&self, cutoff: DateTime<Utc>) -> anyhow::Result<Vec<ArticleId>> {
+        let mut purged = Vec::new();
+        self.trash.write().await.retain(|t| {
+            if t.deleted_at <= cutoff {
+                purged.push(t.item.id);
+                false
+            } else {
+                true
+            }
+        });
+        self.forget_articles(&purged).await;
+        Ok(purged)
+    }
+}
+
+/// Orders articles newest first, the way the Postgres queries do, and cuts
+/// out the page asked for by cursor or else by offset
+fn paginate(mut articles: Vec<&Article>, pagination: Pagination, cursor: Option<Cursor>) -> CursorPage<&Article> {
+    articles.sort_by_key(|a| std::cmp::Reverse((a.listed_at(), a.id.as_uuid())));
+    let key = |a: &&Article| (a.listed_at(), a.id);
+    let limit = pagination.limit() as usize;
+    match cursor {
+        Some(cursor) => {
+            let mut admitted: Vec<&Article> = articles
+                .into_iter()
+                .filter(|a| cursor.admits(a.listed_at(), a.id))
+                .collect();
+            // The cursor's side, nearest first
+            if cursor.direction() == CursorDirection::Newer {
+                admitted.reverse();
+            }
+            admitted.truncate(limit + 1);
+            CursorPage::from_cursor(admitted, pagination.limit(), cursor, key)
+        }
+        None => {
+            let total = articles.len();
+            let page = articles
+                .into_iter()
+                .skip(pagination.offset() as usize)
+                .take(limit)
+                .collect();
+            CursorPage::from_offset(page, pagination.offset(), total, key)
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct InMemoryCommentsRepository {
+    comments: Arc<RwLock<Vec<Comment>>>,
+    /// Deleted comments, out of `comments` so that nothing else sees them
+    trash: Arc<RwLock<Vec<Trashed<Comment>>>>,
+    reactions: Arc<RwLock<Reactions<CommentId>>>,
+}
+
+impl InMemoryCommentsRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Drops the comments on erased articles, trashed ones included, and
+    /// their reactions
+    async fn forget_articles(&self, ids: &[ArticleId]) {
+        let mut comments = self.comments.write().await;
+        let mut trash = self.trash.write().await;
+        let erased: HashSet<CommentId> = comments
+            .iter()
+            .chain(trash.iter().map(|t| &t.item))
+            .filter(|c| ids.contains(&c.article_id))
+            .map(|c| c.id)
+            .collect();
+        comments.retain(|c| !erased.contains(&c.id));
+        trash.retain(|t| !erased.contains(&t.item.id));
+        self.reactions.write().await.retain(|(comment_id, _, _)| !erased.contains(comment_id));
+    }
+}
+
+#[async_trait]
+impl CommentsRepository for InMemoryCommentsRepository {
+    async fn create_comment(&self, comment: Comment) -> anyhow::Result<Comment> {
+        let mut comments = self.comments.write().await;
+        comments.push(comment.clone());
+        Ok(comment)
+    }
+
+    async fn get_comments_by_article(&self, article_id: ArticleId) -> anyhow::Result<Vec<Comment>> {
+        let comments = self.comments.read().await;
+        Ok(comments
+            .iter()
+            .filter(|c| c.article_id == article_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn delete_comment(&self, id: CommentId, deleted_at: DateTime<Utc>) -> anyhow::Result<()> {
+        let mut comments = self.comments.write().await;
+        if let Some(index) = comments.iter().position(|c| c.id == id) {
+            let item = comments.remove(index);
+            self.trash.write().await.push(Trashed { item, deleted_at });
+        }
+        Ok(())
+    }
+
+    async fn get_comment_by_id(&self, id: CommentId) -> anyhow::Result<Option<Comment>> {
+        let comments = self.comments.read().await;
+        Ok(comments.iter().find(|c| c.id == id).cloned())
+    }
+
+    async fn hide_comment(&self, id: CommentId, hidden_at: DateTime<Utc>) -> anyhow::Result<()> {
+        let mut comments = self.comments.write().await;
+        if let Some(comment) = comments.iter_mut().find(|c| c.id == id) {
+            comment.hidden_at = Some(hidden_at);
+        }
+        Ok(())
+    }
+
+    async fn get_comments_by_author(&self, author_id: UserId) -> anyhow::Result<Vec<Comment>> {
+        let comments = self.comments.read().await;
+        Ok(comments
+            .iter()
+            .filter(|c| c.author_id == author_id)
+            .cloned()
+            .collect())
+    }
+
+    async fn delete_comments_by_author(&self, author_id: UserId) -> anyhow::Result<()> {
+        let mut comments = self.comments.write().await;
+        let mut trash = self.trash.write().await;
+        let erased: HashSet<CommentId> = comments
+            .iter()
+            .filter(|c| c.author_id == author_id)
+            .chain(trash.iter().map(|t| &t.item).filter(|c| c.author_id == author_id))
+            .map(|c| c.id)
+            .collect();
+        comments.retain(|c| c.author_id != author_id);
+        trash.retain(|t| t.item.author_id != author_id);
+        self.reactions.write().await.retain(|(comment_id, _, _)| !erased.contains(comment_id));
+        Ok(())
+    }
+
+    async fn reassign_comments(&self, from_author_id: UserId, to_author_id: UserId) -> anyhow::Result<()> {
+        let mut comments = self.comments.write().await;
+        for comment in comments.iter_mut().filter(|c| c.author_id == from_author_id) {
+            comment.author_id = to_author_id;
+        }
+        let mut trash = self.trash.write().await;
+        for trashed in trash.iter_mut().filter(|t| t.item.author_id == from_author_id) {
+            trashed.item.author_id = to_author_id;
+        }
+        Ok(())
+    }
+
+    async fn get_trashed_comment(&self, id: CommentId) -> anyhow::Result<Option<Trashed<Comment>>> {
+        let trash = self.trash.read().await;
+        Ok(trash.iter().find(|t| t.item.id == id).cloned())
+    }
+
+    async fn list_trashed_comments(&self, author_id: UserId) -> anyhow::Result<Vec<Trashed<Comment>>> {
+        let trash = self.trash.read().await;
+        let mut found: Vec<Trashed<Comment>> = trash
+            .iter()
+            .filter(|t| t.item.author_id == author_id)
+            .cloned()
+            .collect();
+        found.sort_by_key(|t| std::cmp::Reverse(t.deleted_at));
+        Ok(found)
+    }
+
+    async fn restore_comment(&self, id: CommentId) -> anyhow::Result<()> {
+        let mut trash = self.trash.write().await;
+        if let Some(index) = trash.iter().position(|t| t.item.id == id) {
+            let comment = trash.remove(index).item;
+            self.comments.write().await.push(comment);
+        }
+        Ok(())
+    }
+
+    async fn purge_trashed_comments(&self, cutoff: DateTime<Utc>) -> anyhow::Result<Vec<CommentId>> {
+        let mut purged = Vec::new();
+        self.trash.write().await.retain(|t| {
+            if t.deleted_at <= cutoff {
+                purged.push(t.item.id);
+                false
+            } else {
+                true
+            }
+        });
+        self.reactions.write().await.retain(|(comment_id, _, _)| !purged.contains(comment_id));
+        Ok(purged)
+    }
+
+    async fn add_comment_reaction(&self, comment_id: CommentId, user_id: UserId, kind: &ReactionKind) -> anyhow::Result<()> {
+        let mut reactions = self.reactions.write().await;
+        let reaction = (comment_id, user_id, kind.clone());
+        if !reactions.contains(&reaction) {
+            reactions.push(reaction);
+        }
+        Ok(())
+    }
+
+    async fn remove_comment_reaction(&self, comment_id: CommentId, user_id: UserId, kind: &ReactionKind) -> anyhow::Result<()> {
+        self.reactions
+            .write()
+            .await
+            .retain(|reaction| *reaction != (comment_id, user_id, kind.clone()));
+        Ok(())
+    }
+
+    async fn comment_reactions(
+        &self,
+        comment_ids: &[CommentId],
+        viewer: Option<UserId>,
+    ) -> anyhow::Result<HashMap<CommentId, Vec<ReactionCount>>> {
+        let reactions = self.reactions.read().await;
+        Ok(comment_ids
+            .iter()
+            .map(|comment_id| (*comment_id, tally_reactions(&reactions, comment_id, viewer)))
+            .filter(|(_, counts)| !counts.is_empty())
+            .collect())
+    }
+
+    async fn remove_comment_reactions_by_user(&self, user_id: UserId) -> anyhow::Result<()> {
+        self.reactions.write().await.retain(|(_, reactor, _)| *reactor != user_id);
+        Ok(())
+    }
+
+    async fn list_comment_reactions_by_user(&self, user_id: UserId) -> anyhow::Result<Vec<(CommentId, ReactionKind)>> {
+        let reactions = self.reactions.read().await;
+        Ok(reactions
+            .iter()
+            .filter(|(_, reactor, _)| *reactor == user_id)
+            .map(|(comment_id, _, kind)| (*comment_id, kind.clone()))
+            .collect())
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct InMemoryRevisionsRepository {
+    revisions: Arc<RwLock<Vec<ArticleRevision>>>,
+}
+
+impl InMemoryRevisionsRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl RevisionsRepository for InMemoryRevisionsRepository {
+    async fn create_revision(&self, revision: ArticleRevision) -> anyhow::Result<ArticleRevision> {
+        let mut revisions = self.revisions.write().await;
+        if revisions
+            .iter()
+            .any(|r| r.article_id == revision.article_id && r.version == revision.version)
+        {
+            return Err(anyhow::anyhow!("Revision {} already exists", revision.version));
+        }
+        revisions.push(revision.clone());
+        Ok(revision)
+    }
+
+    async fn list_revisions(&self, article_id: ArticleId) -> anyhow::Result<Vec<ArticleRevision>> {
+        let revisions = self.revisions.read().await;
+        let mut found: Vec<ArticleRevision> = revisions
+            .iter()
+            .filter(|r| r.article_id == article_id)
+            .cloned()
+            .collect();
+        found.sort_by_key(|r| std::cmp::Reverse(r.version));
+        Ok(found)
+    }
+
+    async fn get_revision(&self, id: RevisionId) -> anyhow::Result<Option<ArticleRevision>> {
+        let revisions = self.revisions.read().await;
+        Ok(revisions.iter().find(|r| r.id == id).cloned())
+    }
+
+    async fn latest_revision(&self, article_id: ArticleId) -> anyhow::Result<Option<ArticleRevision>> {
+        let revisions = self.revisions.read().await;
+        Ok(revisions
+            .iter()
+            .filter(|r| r.article_id == article_id)
+            .max_by_key(|r| r.version)
+            .cloned())
+    }
+}
+
+/// Reads tags straight off the articles, so it needs no bookkeeping of its own
+#[derive(Clone)]
+pub struct InMemoryTagsRepository {
+    articles_repo: InMemoryArticlesRepository,
+}
+
+impl InMemoryTagsRepository {
+    pub fn new(articles_repo: InMemoryArticlesRepository) -> Self {
+        Self { articles_repo }
+    }
+
+    async fn tag_counts(&self) -> Vec<TagCount> {
+        let articles = self.articles_repo.articles.read().await;
+        let users = self.articles_repo.users_repo.users.read().await;
+        let mut counts: HashMap<&str, u64> = HashMap::new();
+        for article in articles
+            .iter()
+            .filter(|a| a.is_public())
+            .filter(|a| users.iter().any(|u| u.id == a.author_id && !u.is_deactivated()))
+        {
+            for tag in article.tag_list.as_slice() {
+                *counts.entry(tag.as_str()).or_default() += 1;
+            }
+        }
+        let mut counts: Vec<TagCount> = counts
+            .into_iter()
+            .map(|(name, articles_count)| TagCount {
+                name: name.to_owned(),
+                articles_count,
+            })
+            .collect();
+        counts.sort_by(|a, b| b.articles_count.cmp(&a.articles_count).then_with(|| a.name.cmp(&b.name)));
+        counts
+    }
+}
+
+#[async_trait]
+impl TagsRepository for InMemoryTagsRepository {
+    async fn popular_tags(&self, limit: u32) -> anyhow::Result<Vec<TagCount>> {
+        let mut counts = self.tag_counts().await;
+        counts.truncate(limit as usize);
+        Ok(counts)
+    }
+
+    async fn search_tags(&self, prefix: &str, limit: u32) -> anyhow::Result<Vec<TagCount>> {
+        let prefix = prefix.to_lowercase();
+        Ok(self
+            .tag_counts()
+            .await
+            .into_iter()
+            .filter(|t| t.name.to_lowercase().starts_with(&prefix))
+            .take(limit as usize)
+            .collect())
+    }
+
+    async fn canonical_tag(&self, tag: &Tag) -> anyhow::Result<Tag> {
+        let aliases = self.articles_repo.tag_aliases.read().await;
+        Ok(aliases.get(tag).cloned().unwrap_or_else(|| tag.clone()))
+    }
+
+    async fn merge_tags(&self, source: &Tag, target: &Tag) -> anyhow::Result<u64> {
+        let mut articles = self.articles_repo.articles.write().await;
+        let mut aliases = self.articles_repo.tag_aliases.write().await;
+        let mut relinked = 0;
+        for article in articles.iter_mut().filter(|a| a.tag_list.contains(source)) {
+            let tags = std::mem::take(&mut article.tag_list);
+            article.tag_list = tags.map(|tag| if &tag == source { target.clone() } else { tag });
+            relinked += 1;
+        }
+        for canonical in aliases.values_mut().filter(|canonical| *canonical == source) {
+            *canonical = target.clone();
+        }
+        aliases.insert(source.clone(), target.clone());
+        for follows in self.articles_repo.tag_follows.write().await.values_mut() {
+            if follows.remove(source) {
+                follows.insert(target.clone());
+            }
+        }
+        Ok(relinked)
+    }
+
+    async fn follow_tag(&self, user_id: UserId, tag: &Tag) -> anyhow::Result<()> {
+        let mut follows = self.articles_repo.tag_follows.write().await;
+        follows.entry(user_id).or_default().insert(tag.clone());
+        Ok(())
+    }
+
+    async fn unfollow_tag(&self, user_id: UserId, tag: &Tag) -> anyhow::Result<()> {
+        let mut follows = self.articles_repo.tag_follows.write().await;
+        if let Some(tags) = follows.get_mut(&user_id) {
+            tags.remove(tag);
+        }
+        Ok(())
+    }
+
+    async fn followed_tags(&self, user_id: UserId) -> anyhow::Result<Vec<Tag>> {
+        let follows = self.articles_repo.tag_follows.read().await;
+        let mut tags: Vec<Tag> = follows.get(&user_id).into_iter().flatten().cloned().collect();
+        tags.sort_by(|a, b| a.as_str().cmp(b.as_str()));
+        Ok(tags)
+    }
+}
+
+/// Keeps series and their ordering; parts are read off the articles store
+#[derive(Clone)]
+pub struct InMemorySeriesRepository {
+    series: Arc<RwLock<Vec<Series>>>,
+    /// series -> its articles, in order
+    parts: Arc<RwLock<HashMap<SeriesId, Vec<ArticleId>>>>,
+    articles_repo: InMemoryArticlesRepository,
+}
+
+impl InMemorySeriesRepository {
+    pub fn new(articles_repo: InMemoryArticlesRepository) -> Self {
+        Self {
+            series: Arc::new(RwLock::new(Vec::new())),
+            parts: Arc::new(RwLock::new(HashMap::new())),
+            articles_repo,
+        }
+    }
+}
+
+#[async_trait]
+impl SeriesRepository for InMemorySeriesRepository {
+    async fn create_series(&self, series: Series) -> anyhow::Result<Series> {
+        let mut all = self.series.write().await;
+        if all.iter().any(|s| s.slug == series.slug) {
+            return Err(anyhow::anyhow!("Series {} already exists", series.slug.as_str()));
+        }
+        all.push(series.clone());
+        Ok(series)
+    }
+
+    async fn get_series_by_slug(&self, slug: &str) -> anyhow::Result<Option<Series>> {
+        let all = self.series.read().await;
+        Ok(all.iter().find(|s| s.slug.as_str() == slug).cloned())
+    }
+
+    async fn list_series_by_owner(&self, owner_id: UserId) -> anyhow::Result<Vec<Series>> {
+        let all = self.series.read().await;
+        let mut owned: Vec<Series> = all.iter().filter(|s| s.owner_id == owner_id).cloned().collect();
+        owned.sort_by_key(|s| s.created_at);
+        Ok(owned)
+    }
+
+    async fn update_series(&self, series: Series) -> anyhow::Result<Series> {
+        let mut all = self.series.write().await;
+        let existing = all
+            .iter_mut()
+            .find(|s| s.id == series.id)
+            .ok_or_else(|| anyhow::anyhow!("Series not found"))?;
+        *existing = series.clone();
+        Ok(series)
+    }
+
+    async fn delete_series(&self, id: SeriesId) -> anyhow::Result<()> {
+        self.series.write().await.retain(|s| s.id != id);
+        self.parts.write().await.remove(&id);
+        Ok(())
+    }
+
+    async fn set_series_parts(&self, id: SeriesId, parts: &[ArticleId]) -> anyhow::Result<()> {
+        let series = self.series.read().await;
+        let mut all_parts = self.parts.write().await;
+        let kind_of = |series_id: &SeriesId| series.iter().find(|s| s.id == *series_id).map(|s| s.kind);
+        if kind_of(&id) == Some(SeriesKind::Series) {
+            let taken = all_parts
+                .iter()
+                .filter(|(other, _)| **other != id && kind_of(other) == Some(SeriesKind::Series))
+                .flat_map(|(_, ids)| ids.iter())
+                .find(|article_id| parts.contains(article_id));
+            if let Some(article_id) = taken {
+                return Err(PartOfAnotherSeries(*article_id).into());
+            }
+        }
+        all_parts.insert(id, parts.to_vec());
+        Ok(())
+    }
+
+    async fn list_series_parts(&self, id: SeriesId, viewer: Option<UserId>) -> anyhow::Result<Vec<ArticleSummary>> {
+        let parts = self.parts.read().await;
+        let articles = self.articles_repo.articles.read().await;
+        let users = self.articles_repo.users_repo.users.read().await;
+        let followers = self.articles_repo.users_repo.followers.read().await;
+        let favorites = self.articles_repo.favorites.read().await;
+        let bookmarks = self.articles_repo.bookmarks.read().await;
+        let reactions = self.articles_repo.reactions.read().await;
+
+        let summaries = parts
+            .get(&id)
+            .into_iter()
+            .flatten()
+            .filter_map(|article_id| articles.iter().find(|a| a.id == *article_id))
+            .filter(|a| a.is_visible_to(viewer))
+            .filter_map(|article| {
+                let author = users
+                    .iter()
+                    .find(|u| u.id == article.author_id && !u.is_deactivated())?;
+                let (following, favorited, bookmarked) = match viewer {
+                    Some(viewer) => (
+                        is_following(&followers, author.id, viewer),
+                        is_article_favorited(&favorites, article.id, viewer),
+                        bookmarks.contains_key(&(viewer, article.id)),
+                    ),
+                    None => (false, false, false),
+                };
+                Some(
+                    article
+                        .to_summary(author.to_profile(following), favorited)
+                        .with_bookmarked(bookmarked)
+                        .with_reactions(tally_reactions(&reactions, &article.id, viewer)),
+                )
+            })
+            .collect();
+        Ok(summaries)
+    }
+
+    async fn series_of_article(&self, article_id: ArticleId) -> anyhow::Result<Option<Series>> {
+        let all = self.series.read().await;
+        let parts = self.parts.read().await;
+        Ok(all
+            .iter()
+            .filter(|s| s.kind == SeriesKind::Series)
+            .find(|s| parts.get(&s.id).is_some_and(|ids| ids.contains(&article_id)))
+            .cloned())
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct InMemoryReportsRepository {
+    reports: Arc<RwLock<Vec<Report>>>,
+    records: Arc<RwLock<Vec<ModerationRecord>>>,
+}
+
+impl InMemoryReportsRepository {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl ReportsRepository for InMemoryReportsRepository {
+    async fn create_report(&self, report: Report) -> anyhow::Result<Report> {
+        self.reports.write().await.push(report.clone());
+        Ok(report)
+    }
+
+    async fn get_report(&self, id: ReportId) -> anyhow::Result<Option<Report>> {
+        let reports = self.reports.read().await;
+        Ok(reports.iter().find(|r| r.id == id).cloned())
+    }
+
+    async fn find_open_report(&self, reporter_id: UserId, target: ReportTarget) -> anyhow::Result<Option<Report>> {
+        let reports = self.reports.read().await;
+        Ok(reports
+            .iter()
+            .find(|r| r.reporter_id == reporter_id && r.target == target && !r.is_closed())
+            .cloned())
+    }
+
+    async fn list_reports(&self, status: Option<ReportStatus>, pagination: Pagination) -> anyhow::Result<Vec<Report>> {
+        let reports = self.reports.read().await;
+        let mut found: Vec<Report> = reports
+            .iter()
+            .filter(|r| status.is_none_or(|status| r.status == status))
+            .cloned()
+            .collect();
+        found.sort_by_key(|r| (r.created_at, r.id.as_uuid()));
+        Ok(found
+            .into_iter()
+            .skip(pagination.offset() as usize)
+            .take(pagination.limit() as usize)
+            .collect())
+    }
+
+    async fn count_reports(&self, status: Option<ReportStatus>) -> anyhow::Result<u64> {
+        let reports = self.reports.read().await;
+        Ok(reports
+            .iter()
+            .filter(|r| status.is_none_or(|status| r.status == status))
+            .count() as u64)
+    }
+
+    async fn list_reports_by_reporter(&self, reporter_id: UserId) -> anyhow::Result<Vec<Report>> {
+        let reports = self.reports.read().await;
+        let mut found: Vec<Report> = reports.iter().filter(|r| r.reporter_id == reporter_id).cloned().collect();
+        found.sort_by_key(|r| std::cmp::Reverse((r.created_at, r.id.as_uuid())));
+        Ok(found)
+    }
+
+    async fn record_moderation(&self, report: &Report, record: ModerationRecord) -> anyhow::Result<bool> {
+        let mut reports = self.reports.write().await;
+        let Some(existing) = reports.iter_mut().find(|r| r.id == report.id) else {
+            return Ok(false);
+        };
+        let claimed_by_other = existing.moderator_id.is_some_and(|id| Some(id) != report.moderator_id);
+        if existing.is_closed() || claimed_by_other {
+            return Ok(false);
+        }
+        *existing = report.clone();
+        self.records.write().await.push(record);
+        Ok(true)
+    }
+
+    async fn list_moderation_records(&self, report_id: ReportId) -> anyhow::Result<Vec<ModerationRecord>> {
+        let records = self.records.read().await;
+        Ok(records.iter().filter(|r| r.report_id == report_id).cloned().collect())
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct InMemoryBlobStore {
+    blobs: Arc<RwLock<HashMap<String, Blob>>>,
+}
+
+impl InMemoryBlobStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl BlobStore for InMemoryBlobStore {
+    async fn put_blob(&self, key: &str, blob: Blob) -> anyhow::Result<()> {
+        self.blobs.write().await.insert(key.to_owned(), blob);
+        Ok(())
+    }
+
+    async fn get_blob(&self, key: &str) -> anyhow::Result<Option<Blob>> {
+        Ok(self.blobs.read().await.get(key).cloned())
+    }
+
+    async fn delete_blob(&self, key: &str) -> anyhow::Result<()> {
+        self.blobs.write().await.remove(key);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Email, PasswordHash, Username};
+
+    fn create_test_user(id: UserId, username: &str, email: &str) -> User {
+        User::new(
+            id,
+            Email::parse(email).unwrap(),
+            Username::new(username).unwrap(),
+            PasswordHash::new("hash".to_string()).unwrap(),
+            chrono::Utc::now(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_create_and_get_user() {
+        let repo = InMemoryUsersRepository::new();
+        let user = create_test_user(UserId::random(), "testuser", "test@example.com");
+
+        repo.create_user(user.clone()).await.unwrap();
+        
+        let found = repo.get_user_by_email("test@example.com").await.unwrap();
+        assert!(found.is_some());
+        assert_eq!(found.unwrap().username.as_str(), "testuser");
+    }
+
+    #[tokio::test]
+    async fn test_follow_and_is_following() {
+        let repo = InMemoryUsersRepository::new();
+        let user1_id = UserId::random();
+        let user2_id = UserId::random();
+
+        repo.follow_user(user1_id, user2_id).await.unwrap();
         
         let is_following = repo.is_following(user1_id, user2_id).await.unwrap();
         assert!(is_following);
     }
 
+    #[tokio::test]
+    async fn test_delete_user_removes_follows() {
+        let repo = InMemoryUsersRepository::new();
+        let user1 = create_test_user(UserId::random(), "user1", "user1@example.com");
+        let user2 = create_test_user(UserId::random(), "user2", "user2@example.com");
+        repo.create_user(user1.clone()).await.unwrap();
+        repo.create_user(user2.clone()).await.unwrap();
+        repo.follow_user(user1.id, user2.id).await.unwrap();
+        repo.follow_user(user2.id, user1.id).await.unwrap();
+
+        repo.delete_user(user1.id).await.unwrap();
+
+        assert!(repo.get_user_by_id(user1.id).await.unwrap().is_none());
+        assert!(!repo.is_following(user1.id, user2.id).await.unwrap());
+        assert!(!repo.is_following(user2.id, user1.id).await.unwrap());
+    }
+
     #[tokio::test]
     async fn test_create_and_get_article() {
         let users_repo = InMemoryUsersRepository::new();
@@ -345,14 +1832,19 @@ mod tests {
         let article = Article {
             id: article_id,
             author_id,
+            coauthor_ids: Vec::new(),
             slug: crate::Slug::new("test-slug").unwrap(),
             title: "Test".to_string(),
             description: "Test".to_string(),
             body: "Test".to_string(),
             tag_list: crate::TagList::default(),
             favorites_count: 0,
+            status: crate::ArticleStatus::Published,
+            published_at: Some(chrono::Utc::now()),
+            seo: crate::ArticleSeo::default(),
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
+            hidden_at: None,
         };
 
         repo.create_article(article.clone()).await.unwrap();
@@ -373,18 +1865,23 @@ mod tests {
         let article = Article {
             id: article_id,
             author_id: UserId::random(),
+            coauthor_ids: Vec::new(),
             slug: crate::Slug::new("test-slug").unwrap(),
             title: "Test".to_string(),
             description: "Test".to_string(),
             body: "Test".to_string(),
             tag_list: crate::TagList::default(),
             favorites_count: 0,
+            status: crate::ArticleStatus::Published,
+            published_at: Some(chrono::Utc::now()),
+            seo: crate::ArticleSeo::default(),
             created_at: chrono::Utc::now(),
             updated_at: chrono::Utc::now(),
+            hidden_at: None,
         };
 
         repo.create_article(article).await.unwrap();
-        repo.favorite_article(user_id, article_id).await.unwrap();
+        repo.favorite_article(user_id, article_id, Utc::now()).await.unwrap();
         
         let is_favorited = repo.is_favorited(user_id, article_id).await.unwrap();
         assert!(is_favorited);
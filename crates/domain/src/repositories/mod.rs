@@ -1,13 +1,31 @@
 pub mod in_memory;
 
+use std::collections::HashMap;
+
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use crate::{
-    Article, ArticleId, ArticleFilters, ArticlesEnvelope, FeedFilters,
-    Comment, CommentId, User, UserId,
+    Article, ArticleId, ArticleFilters, ArticleRevision, ArticleSummary, ArticlesEnvelope, AuthoredContentPolicy, Blob, Bookmark,
+    BookmarkFilters, BookmarksEnvelope, Coauthor, FeedFilters, Comment, CommentId, ModerationRecord, Pagination, RelatedScoring, RevisionId, SearchFilters, SearchResultsEnvelope,
+    ReactionCount, ReactionKind, Report, ReportId, ReportStatus, ReportTarget, Series, SeriesId, SitemapEntry, Tag, TagCount, Trashed, User, UserId,
+};
+
+pub use in_memory::{
+    InMemoryArticlesRepository, InMemoryBlobStore, InMemoryCommentsRepository, InMemoryReportsRepository,
+    InMemoryRevisionsRepository, InMemorySeriesRepository, InMemoryTagsRepository, InMemoryUsersRepository,
 };
 
-pub use in_memory::{InMemoryArticlesRepository, InMemoryCommentsRepository, InMemoryUsersRepository};
+/// What `create_article` and `update_article` fail with, inside the
+/// `anyhow::Error`, when another article holds the slug already
+#[derive(Debug, thiserror::Error)]
+#[error("slug {0} is taken")]
+pub struct SlugTaken(pub String);
 
+/// What `set_series_parts` fails with, inside the `anyhow::Error`, when a
+/// series would take an article that is part of another series
+#[derive(Debug, thiserror::Error)]
+#[error("article {0:?} is part of another series")]
+pub struct PartOfAnotherSeries(pub ArticleId);
 
 #[async_trait]
 pub trait UsersRepository: Send + Sync {
@@ -15,30 +33,270 @@ pub trait UsersRepository: Send + Sync {
     async fn get_user_by_username(&self, username: &str) -> anyhow::Result<Option<User>>;
     async fn get_user_by_id(&self, id: UserId) -> anyhow::Result<Option<User>>;
     async fn create_user(&self, user: User) -> anyhow::Result<User>;
+    /// An image of `None` clears the one set before
     async fn update_user(&self, user: User) -> anyhow::Result<User>;
     async fn follow_user(&self, follower_id: UserId, followee_id: UserId) -> anyhow::Result<()>;
     async fn unfollow_user(&self, follower_id: UserId, followee_id: UserId) -> anyhow::Result<()>;
     async fn is_following(&self, follower_id: UserId, followee_id: UserId) -> anyhow::Result<bool>;
+    async fn list_followed_users(&self, follower_id: UserId) -> anyhow::Result<Vec<User>>;
+    /// In username order
+    async fn list_blocked_users(&self, blocker_id: UserId) -> anyhow::Result<Vec<User>>;
+    /// In username order
+    async fn list_muted_users(&self, muter_id: UserId) -> anyhow::Result<Vec<User>>;
+    async fn deactivate_user(&self, id: UserId, deactivated_at: DateTime<Utc>) -> anyhow::Result<()>;
+    async fn reactivate_user(&self, id: UserId) -> anyhow::Result<()>;
+    async fn suspend_user(&self, id: UserId, suspended_at: DateTime<Utc>) -> anyhow::Result<()>;
+    /// Accounts whose deactivation happened at or before `cutoff`
+    async fn list_deactivated_users(&self, cutoff: DateTime<Utc>) -> anyhow::Result<Vec<User>>;
+    /// Removes the account together with its follow relationships
+    async fn delete_user(&self, id: UserId) -> anyhow::Result<()>;
+    /// Records the block and drops any follow relationship between the two users
+    async fn block_user(&self, blocker_id: UserId, blocked_id: UserId) -> anyhow::Result<()>;
+    async fn unblock_user(&self, blocker_id: UserId, blocked_id: UserId) -> anyhow::Result<()>;
+    async fn is_blocking(&self, blocker_id: UserId, blocked_id: UserId) -> anyhow::Result<bool>;
+    async fn mute_user(&self, muter_id: UserId, muted_id: UserId) -> anyhow::Result<()>;
+    async fn unmute_user(&self, muter_id: UserId, muted_id: UserId) -> anyhow::Result<()>;
+    async fn is_muting(&self, muter_id: UserId, muted_id: UserId) -> anyhow::Result<bool>;
+    /// Authors whose content the viewer must not see: those the viewer muted
+    /// and those who blocked the viewer
+    async fn list_hidden_authors(&self, viewer_id: UserId) -> anyhow::Result<Vec<UserId>>;
 }
 
 #[async_trait]
 pub trait ArticlesRepository: Send + Sync {
+    /// Tags are stored under the tag their alias stands for; the returned
+    /// article carries the stored tags. The same goes for `update_article`,
+    /// which leaves `coauthor_ids` and `hidden_at` alone: co-authors only
+    /// change through the co-author methods below, and only `hide_article`
+    /// hides an article. Both fail with [`SlugTaken`] when another article,
+    /// trashed ones included, has the slug
     async fn create_article(&self, article: Article) -> anyhow::Result<Article>;
     async fn get_article_by_slug(&self, slug: &str) -> anyhow::Result<Option<Article>>;
     async fn get_article_by_id(&self, id: ArticleId) -> anyhow::Result<Option<Article>>;
+    /// The articles with any of the slugs, in no particular order; slugs
+    /// matching none are skipped
+    async fn get_articles_by_slugs(&self, slugs: &[String]) -> anyhow::Result<Vec<Article>>;
     async fn update_article(&self, article: Article) -> anyhow::Result<Article>;
-    async fn delete_article(&self, id: ArticleId) -> anyhow::Result<()>;
+    /// Moves the article to the trash, where no other method but the trash
+    /// ones below finds it. Its slugs stay taken, and its comments,
+    /// favorites and bookmarks are kept for when it is restored
+    async fn delete_article(&self, id: ArticleId, deleted_at: DateTime<Utc>) -> anyhow::Result<()>;
+    /// Hides the article from everyone but its authors: listings, feeds,
+    /// search, sitemaps and tag counts leave it out for anyone else
+    async fn hide_article(&self, id: ArticleId, hidden_at: DateTime<Utc>) -> anyhow::Result<()>;
     async fn list_articles(&self, filters: ArticleFilters) -> anyhow::Result<ArticlesEnvelope>;
+    /// Articles by authors (owners or co-authors) the user follows or carrying
+    /// tags the user follows, each once and with its `reason` set
     async fn feed_articles(&self, user_id: UserId, filters: FeedFilters) -> anyhow::Result<ArticlesEnvelope>;
-    async fn favorite_article(&self, user_id: UserId, article_id: ArticleId) -> anyhow::Result<()>;
+    /// `favorited_at` is what trending counts from; favoriting again keeps
+    /// the first time
+    async fn favorite_article(&self, user_id: UserId, article_id: ArticleId, favorited_at: DateTime<Utc>) -> anyhow::Result<()>;
     async fn unfavorite_article(&self, user_id: UserId, article_id: ArticleId) -> anyhow::Result<()>;
     async fn is_favorited(&self, user_id: UserId, article_id: ArticleId) -> anyhow::Result<bool>;
+    /// Every article the user favorited, oldest favorite first, whatever its
+    /// status or author; only those in the trash are left out
+    async fn list_favorited_articles(&self, user_id: UserId) -> anyhow::Result<Vec<Article>>;
+    /// Trashed articles included
+    async fn delete_articles_by_author(&self, author_id: UserId) -> anyhow::Result<()>;
+    /// Trashed articles included
+    async fn reassign_articles(&self, from_author_id: UserId, to_author_id: UserId) -> anyhow::Result<()>;
+    async fn remove_favorites_by_user(&self, user_id: UserId) -> anyhow::Result<()>;
+    /// Erase the account in one go: its articles and comments are deleted or
+    /// handed to the deleted-user placeholder (created when missing) per
+    /// `authored_content`, its favorites and reactions removed, and the user
+    /// deleted; nothing is changed when any step fails
+    async fn erase_account(
+        &self,
+        user_id: UserId,
+        authored_content: AuthoredContentPolicy,
+        now: DateTime<Utc>,
+    ) -> anyhow::Result<()>;
+    /// Does nothing when the user already reacted with that kind
+    async fn add_article_reaction(&self, article_id: ArticleId, user_id: UserId, kind: &ReactionKind) -> anyhow::Result<()>;
+    async fn remove_article_reaction(&self, article_id: ArticleId, user_id: UserId, kind: &ReactionKind) -> anyhow::Result<()>;
+    /// Count of every kind the article received, most used first and by
+    /// kind on ties, marking those `viewer` reacted with
+    async fn article_reactions(&self, article_id: ArticleId, viewer: Option<UserId>) -> anyhow::Result<Vec<ReactionCount>>;
+    async fn remove_article_reactions_by_user(&self, user_id: UserId) -> anyhow::Result<()>;
+    /// Each reaction the user left on an article, oldest first
+    async fn list_article_reactions_by_user(&self, user_id: UserId) -> anyhow::Result<Vec<(ArticleId, ReactionKind)>>;
+    /// Looks an article up by a slug it used to have
+    async fn get_article_by_previous_slug(&self, slug: &str) -> anyhow::Result<Option<Article>>;
+    /// Keeps `old_slug` pointing at the article after a rename; `new_slug` stops
+    /// being a previous slug if the article is renamed back
+    async fn record_slug_change(&self, article_id: ArticleId, old_slug: &str, new_slug: &str) -> anyhow::Result<()>;
+    /// Every article the user owns whatever its status, oldest first, hidden
+    /// ones included. Those in the trash come with when they were deleted
+    async fn list_articles_by_author(&self, author_id: UserId) -> anyhow::Result<Vec<(Article, Option<DateTime<Utc>>)>>;
+    /// The author's drafts and scheduled articles, most recently edited first
+    async fn list_drafts(&self, author_id: UserId, pagination: Pagination) -> anyhow::Result<ArticlesEnvelope>;
+    /// Publishes every scheduled article whose time has come and returns them
+    async fn publish_due_articles(&self, now: DateTime<Utc>) -> anyhow::Result<Vec<Article>>;
+    /// Matching articles, best match first
+    async fn search_articles(&self, filters: SearchFilters) -> anyhow::Result<SearchResultsEnvelope>;
+    /// Saves the article to the user's reading list; bookmarking it again
+    /// replaces the note and folder but keeps when it was first saved
+    async fn bookmark_article(&self, bookmark: Bookmark) -> anyhow::Result<Bookmark>;
+    async fn remove_bookmark(&self, user_id: UserId, article_id: ArticleId) -> anyhow::Result<()>;
+    async fn get_bookmark(&self, user_id: UserId, article_id: ArticleId) -> anyhow::Result<Option<Bookmark>>;
+    /// The user's bookmarks of articles they can still see, most recently saved first
+    async fn list_bookmarks(&self, user_id: UserId, filters: BookmarkFilters) -> anyhow::Result<BookmarksEnvelope>;
+    /// Every bookmark the user kept, most recently saved first, whether or
+    /// not they can still see the article
+    async fn list_bookmarks_by_user(&self, user_id: UserId) -> anyhow::Result<Vec<Bookmark>>;
+    /// Inviting someone already invited or co-authoring is a no-op
+    async fn invite_coauthor(&self, coauthor: Coauthor) -> anyhow::Result<()>;
+    async fn get_coauthor(&self, article_id: ArticleId, user_id: UserId) -> anyhow::Result<Option<Coauthor>>;
+    /// The user's invitations, pending and accepted, oldest first
+    async fn list_coauthorships(&self, user_id: UserId) -> anyhow::Result<Vec<Coauthor>>;
+    /// Accepts a pending invitation; the article lists the user among its
+    /// `coauthor_ids` from then on
+    async fn accept_coauthor(&self, article_id: ArticleId, user_id: UserId, accepted_at: DateTime<Utc>) -> anyhow::Result<()>;
+    /// Withdraws an invitation or removes a co-author
+    async fn remove_coauthor(&self, article_id: ArticleId, user_id: UserId) -> anyhow::Result<()>;
+    /// Published articles sharing a tag, the owner or a favoriting user with
+    /// the article, best scored first as of `now` and newest first on ties.
+    /// Leaves out the article itself, those the viewer owns or co-authors,
+    /// and those by muted, blocking or deactivated authors
+    async fn related_articles(
+        &self,
+        article_id: ArticleId,
+        viewer: Option<UserId>,
+        scoring: &RelatedScoring,
+        limit: u32,
+        now: DateTime<Utc>,
+    ) -> anyhow::Result<Vec<ArticleSummary>>;
+    /// Number of pages `sitemap_entries` lists
+    async fn count_sitemap_entries(&self) -> anyhow::Result<u64>;
+    /// Published articles by active authors, by slug, followed by the
+    /// profiles of those authors, by username. A profile was last modified
+    /// when the user or one of their published articles last was
+    async fn sitemap_entries(&self, offset: u64, limit: u32) -> anyhow::Result<Vec<SitemapEntry>>;
+    async fn get_trashed_article_by_slug(&self, slug: &str) -> anyhow::Result<Option<Trashed<Article>>>;
+    /// The author's deleted articles, most recently deleted first
+    async fn list_trashed_articles(&self, author_id: UserId) -> anyhow::Result<Vec<Trashed<Article>>>;
+    /// Takes the article out of the trash
+    async fn restore_article(&self, id: ArticleId) -> anyhow::Result<()>;
+    /// Erases the articles deleted at or before `cutoff` and returns which
+    /// they were
+    async fn purge_trashed_articles(&self, cutoff: DateTime<Utc>) -> anyhow::Result<Vec<ArticleId>>;
 }
 
 #[async_trait]
 pub trait CommentsRepository: Send + Sync {
     async fn create_comment(&self, comment: Comment) -> anyhow::Result<Comment>;
     async fn get_comments_by_article(&self, article_id: ArticleId) -> anyhow::Result<Vec<Comment>>;
-    async fn delete_comment(&self, id: CommentId) -> anyhow::Result<()>;
+    /// Moves the comment to the trash, where no other method but the trash
+    /// ones below finds it
+    async fn delete_comment(&self, id: CommentId, deleted_at: DateTime<Utc>) -> anyhow::Result<()>;
     async fn get_comment_by_id(&self, id: CommentId) -> anyhow::Result<Option<Comment>>;
+    /// Comments stay listed by the methods above once hidden; their
+    /// `hidden_at` tells who may see them
+    async fn hide_comment(&self, id: CommentId, hidden_at: DateTime<Utc>) -> anyhow::Result<()>;
+    async fn get_comments_by_author(&self, author_id: UserId) -> anyhow::Result<Vec<Comment>>;
+    /// Trashed comments included
+    async fn delete_comments_by_author(&self, author_id: UserId) -> anyhow::Result<()>;
+    /// Trashed comments included
+    async fn reassign_comments(&self, from_author_id: UserId, to_author_id: UserId) -> anyhow::Result<()>;
+    async fn get_trashed_comment(&self, id: CommentId) -> anyhow::Result<Option<Trashed<Comment>>>;
+    /// The author's deleted comments, most recently deleted first
+    async fn list_trashed_comments(&self, author_id: UserId) -> anyhow::Result<Vec<Trashed<Comment>>>;
+    /// Takes the comment out of the trash
+    async fn restore_comment(&self, id: CommentId) -> anyhow::Result<()>;
+    /// Erases the comments deleted at or before `cutoff` and returns which
+    /// they were
+    async fn purge_trashed_comments(&self, cutoff: DateTime<Utc>) -> anyhow::Result<Vec<CommentId>>;
+    /// Does nothing when the user already reacted with that kind
+    async fn add_comment_reaction(&self, comment_id: CommentId, user_id: UserId, kind: &ReactionKind) -> anyhow::Result<()>;
+    async fn remove_comment_reaction(&self, comment_id: CommentId, user_id: UserId, kind: &ReactionKind) -> anyhow::Result<()>;
+    /// Reactions of each of the comments as `article_reactions` orders them;
+    /// comments without any are left out
+    async fn comment_reactions(
+        &self,
+        comment_ids: &[CommentId],
+        viewer: Option<UserId>,
+    ) -> anyhow::Result<HashMap<CommentId, Vec<ReactionCount>>>;
+    async fn remove_comment_reactions_by_user(&self, user_id: UserId) -> anyhow::Result<()>;
+    /// Each reaction the user left on a comment, oldest first
+    async fn list_comment_reactions_by_user(&self, user_id: UserId) -> anyhow::Result<Vec<(CommentId, ReactionKind)>>;
+}
+
+/// Reports and the actions moderators took on them. Reports go away with
+/// their reporter's account or the reported content
+#[async_trait]
+pub trait ReportsRepository: Send + Sync {
+    async fn create_report(&self, report: Report) -> anyhow::Result<Report>;
+    async fn get_report(&self, id: ReportId) -> anyhow::Result<Option<Report>>;
+    /// The reporter's report of `target` that is not closed yet
+    async fn find_open_report(&self, reporter_id: UserId, target: ReportTarget) -> anyhow::Result<Option<Report>>;
+    /// Oldest first, of any status when `status` is `None`
+    async fn list_reports(&self, status: Option<ReportStatus>, pagination: Pagination) -> anyhow::Result<Vec<Report>>;
+    async fn count_reports(&self, status: Option<ReportStatus>) -> anyhow::Result<u64>;
+    /// Most recent first
+    async fn list_reports_by_reporter(&self, reporter_id: UserId) -> anyhow::Result<Vec<Report>>;
+    /// Saves the report's status and moderator together with the record of
+    /// the action that changed them; nothing is saved, and `false` returned,
+    /// if the stored report was closed or claimed by another moderator since
+    async fn record_moderation(&self, report: &Report, record: ModerationRecord) -> anyhow::Result<bool>;
+    /// Oldest first
+    async fn list_moderation_records(&self, report_id: ReportId) -> anyhow::Result<Vec<ModerationRecord>>;
+}
+
+#[async_trait]
+pub trait RevisionsRepository: Send + Sync {
+    async fn create_revision(&self, revision: ArticleRevision) -> anyhow::Result<ArticleRevision>;
+    /// Newest version first
+    async fn list_revisions(&self, article_id: ArticleId) -> anyhow::Result<Vec<ArticleRevision>>;
+    async fn get_revision(&self, id: RevisionId) -> anyhow::Result<Option<ArticleRevision>>;
+    async fn latest_revision(&self, article_id: ArticleId) -> anyhow::Result<Option<ArticleRevision>>;
+}
+
+/// Tags in use, counted over published articles by active authors
+#[async_trait]
+pub trait TagsRepository: Send + Sync {
+    /// Most used first, ties by name
+    async fn popular_tags(&self, limit: u32) -> anyhow::Result<Vec<TagCount>>;
+    /// Tags starting with `prefix`, ignoring case, most used first
+    async fn search_tags(&self, prefix: &str, limit: u32) -> anyhow::Result<Vec<TagCount>>;
+    /// The tag `tag` is an alias of, or `tag` itself
+    async fn canonical_tag(&self, tag: &Tag) -> anyhow::Result<Tag>;
+    /// Moves every article and follower of `source` over to `target`, all at
+    /// once, and keeps `source` (and any alias of it) as an alias of `target`.
+    /// Returns how many articles carried `source`
+    async fn merge_tags(&self, source: &Tag, target: &Tag) -> anyhow::Result<u64>;
+    /// Following an already followed tag is a no-op
+    async fn follow_tag(&self, user_id: UserId, tag: &Tag) -> anyhow::Result<()>;
+    async fn unfollow_tag(&self, user_id: UserId, tag: &Tag) -> anyhow::Result<()>;
+    /// In name order
+    async fn followed_tags(&self, user_id: UserId) -> anyhow::Result<Vec<Tag>>;
+}
+
+#[async_trait]
+pub trait SeriesRepository: Send + Sync {
+    async fn create_series(&self, series: Series) -> anyhow::Result<Series>;
+    async fn get_series_by_slug(&self, slug: &str) -> anyhow::Result<Option<Series>>;
+    /// The user's series and collections, oldest first
+    async fn list_series_by_owner(&self, owner_id: UserId) -> anyhow::Result<Vec<Series>>;
+    async fn update_series(&self, series: Series) -> anyhow::Result<Series>;
+    async fn delete_series(&self, id: SeriesId) -> anyhow::Result<()>;
+    /// Replaces the series' parts with `parts`, in that order; fails with
+    /// [`PartOfAnotherSeries`], leaving the parts as they were, when the
+    /// series is of the series kind and one of `parts` is in another such
+    /// series. Collections take any article
+    async fn set_series_parts(&self, id: SeriesId, parts: &[ArticleId]) -> anyhow::Result<()>;
+    /// The parts the viewer can see, in order: published ones, the viewer's
+    /// own drafts, and none by deactivated authors
+    async fn list_series_parts(&self, id: SeriesId, viewer: Option<UserId>) -> anyhow::Result<Vec<ArticleSummary>>;
+    /// The series (not collection) the article is a part of
+    async fn series_of_article(&self, article_id: ArticleId) -> anyhow::Result<Option<Series>>;
+}
+
+/// Files kept by key, such as uploads; keys are plain names without `/`
+#[async_trait]
+pub trait BlobStore: Send + Sync {
+    /// Replaces whatever was stored under `key`
+    async fn put_blob(&self, key: &str, blob: Blob) -> anyhow::Result<()>;
+    async fn get_blob(&self, key: &str) -> anyhow::Result<Option<Blob>>;
+    /// Deleting a missing blob is a no-op
+    async fn delete_blob(&self, key: &str) -> anyhow::Result<()>;
 }
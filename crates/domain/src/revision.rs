@@ -0,0 +1,175 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::article::Article;
+use crate::diff::{WordChange, unified_diff, word_diff};
+use crate::identifiers::{ArticleId, RevisionId, UserId};
+use crate::profile::Profile;
+
+/// Immutable snapshot of an article's content after an edit
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArticleRevision {
+    pub id: RevisionId,
+    pub article_id: ArticleId,
+    /// 1 for the content the article was created with, then one per edit
+    pub version: u32,
+    pub author_id: UserId,
+    pub title: String,
+    pub description: String,
+    pub body: String,
+    #[serde(rename = "createdAt")]
+    pub created_at: DateTime<Utc>,
+}
+
+impl ArticleRevision {
+    pub fn snapshot(
+        id: RevisionId,
+        article: &Article,
+        version: u32,
+        author_id: UserId,
+        now: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id,
+            article_id: article.id,
+            version,
+            author_id,
+            title: article.title.clone(),
+            description: article.description.clone(),
+            body: article.body.clone(),
+            created_at: now,
+        }
+    }
+
+    pub fn to_view(&self, author: Profile) -> RevisionView {
+        RevisionView {
+            id: self.id,
+            version: self.version,
+            title: self.title.clone(),
+            description: self.description.clone(),
+            body: self.body.clone(),
+            created_at: self.created_at,
+            author,
+        }
+    }
+
+    /// Changes from this revision to `to`, field by field
+    pub fn diff(&self, to: &ArticleRevision, format: DiffFormat) -> RevisionDiff {
+        let field = |old: &str, new: &str, name: &str| match format {
+            DiffFormat::Unified => FieldDiff::Unified(unified_diff(
+                old,
+                new,
+                &format!("{name}@{}", self.version),
+                &format!("{name}@{}", to.version),
+            )),
+            DiffFormat::Word => FieldDiff::Words(word_diff(old, new)),
+        };
+        RevisionDiff {
+            from: self.version,
+            to: to.version,
+            format,
+            title: field(&self.title, &to.title, "title"),
+            description: field(&self.description, &to.description, "description"),
+            body: field(&self.body, &to.body, "body"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RevisionView {
+    pub id: RevisionId,
+    pub version: u32,
+    pub title: String,
+    pub description: String,
+    pub body: String,
+    #[serde(rename = "createdAt")]
+    pub created_at: DateTime<Utc>,
+    pub author: Profile,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RevisionsEnvelope {
+    pub revisions: Vec<RevisionView>,
+    #[serde(rename = "revisionsCount")]
+    pub revisions_count: usize,
+}
+
+impl From<Vec<RevisionView>> for RevisionsEnvelope {
+    fn from(value: Vec<RevisionView>) -> Self {
+        Self {
+            revisions_count: value.len(),
+            revisions: value,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DiffFormat {
+    #[default]
+    Unified,
+    Word,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum FieldDiff {
+    /// Unified diff text, empty when the field did not change
+    Unified(String),
+    Words(Vec<WordChange>),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RevisionDiff {
+    pub from: u32,
+    pub to: u32,
+    pub format: DiffFormat,
+    pub title: FieldDiff,
+    pub description: FieldDiff,
+    pub body: FieldDiff,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RevisionDiffEnvelope {
+    pub diff: RevisionDiff,
+}
+
+impl From<RevisionDiff> for RevisionDiffEnvelope {
+    fn from(value: RevisionDiff) -> Self {
+        Self { diff: value }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diff::DiffOp;
+    use crate::{ArticleDraft, TagList};
+
+    fn revision(version: u32, title: &str, body: &str) -> ArticleRevision {
+        let draft = ArticleDraft::new(title, "Description", body, TagList::default()).unwrap();
+        let article = Article::publish(ArticleId::random(), UserId::random(), draft, Utc::now()).unwrap();
+        ArticleRevision::snapshot(RevisionId::random(), &article, version, article.author_id, Utc::now())
+    }
+
+    #[test]
+    fn revision_diff_covers_each_field() {
+        let from = revision(1, "Title", "one\ntwo\n");
+        let to = revision(2, "New title", "one\n2\n");
+
+        let unified = from.diff(&to, DiffFormat::Unified);
+        assert_eq!(unified.description, FieldDiff::Unified(String::new()));
+        let FieldDiff::Unified(body) = unified.body else {
+            panic!("expected a unified diff");
+        };
+        assert!(body.starts_with("--- body@1\n+++ body@2\n"));
+        assert!(body.contains("-two\n+2\n"));
+
+        let words = from.diff(&to, DiffFormat::Word);
+        let FieldDiff::Words(title) = words.title else {
+            panic!("expected a word diff");
+        };
+        assert!(title.iter().any(|c| c.op == DiffOp::Insert && c.text.contains("New")));
+        assert!(title.iter().any(|c| c.op == DiffOp::Delete && c.text == "Title"));
+    }
+}
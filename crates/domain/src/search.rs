@@ -0,0 +1,263 @@
+//! Full-text article search
+//!
+//! Queries are a list of words that must all appear. `"quoted words"` must
+//! appear next to each other in that order and `word*` matches any word
+//! starting with `word`. Everything except letters and digits is treated as
+//! a separator, so user input can never inject query syntax.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{ArticleSummary, DomainError, DomainResult, Pagination, UserId};
+
+/// Terms beyond this are ignored
+pub const MAX_SEARCH_TERMS: usize = 16;
+
+/// Opening and closing marks around matched words in a highlight
+pub const HIGHLIGHT_START: &str = "<mark>";
+pub const HIGHLIGHT_END: &str = "</mark>";
+
+/// Words kept in a highlight, roughly what Postgres' `ts_headline` returns
+const HIGHLIGHT_WORDS: usize = 35;
+/// Words of context kept before the first match in a highlight
+const HIGHLIGHT_LEAD: usize = 10;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SearchTerm {
+    Word(String),
+    Prefix(String),
+    Phrase(Vec<String>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SearchQuery {
+    terms: Vec<SearchTerm>,
+}
+
+impl SearchQuery {
+    pub fn parse(input: &str) -> DomainResult<Self> {
+        let mut terms = Vec::new();
+        // Odd pieces are inside quotes; an unclosed quote runs to the end
+        for (i, piece) in input.split('"').enumerate() {
+            if i % 2 == 1 {
+                let words = words(piece).map(|(_, w)| w.to_lowercase()).collect::<Vec<_>>();
+                match words.len() {
+                    0 => {}
+                    1 => terms.extend(words.into_iter().map(SearchTerm::Word)),
+                    _ => terms.push(SearchTerm::Phrase(words)),
+                }
+                continue;
+            }
+            for token in piece.split_whitespace() {
+                let prefix = token.ends_with('*');
+                let mut token_words = words(token).map(|(_, w)| w.to_lowercase()).peekable();
+                while let Some(word) = token_words.next() {
+                    // Only the part right before the `*` is a prefix
+                    let term = if prefix && token_words.peek().is_none() {
+                        SearchTerm::Prefix(word)
+                    } else {
+                        SearchTerm::Word(word)
+                    };
+                    terms.push(term);
+                }
+            }
+        }
+
+        terms.truncate(MAX_SEARCH_TERMS);
+        if terms.is_empty() {
+            return Err(DomainError::InvalidSearchQuery);
+        }
+        Ok(Self { terms })
+    }
+
+    pub fn terms(&self) -> &[SearchTerm] {
+        &self.terms
+    }
+
+    /// Naive ranking without stemming: each term scores by the most important
+    /// field it appears in, and `None` means some term is missing
+    pub fn rank(&self, title: &str, description: &str, body: &str) -> Option<f64> {
+        // Postgres' default weights for the A, B and C labels
+        let fields = [(title, 1.0), (description, 0.4), (body, 0.2)]
+            .map(|(text, weight)| (lowercase_words(text), weight));
+        let mut rank = 0.0;
+        for term in &self.terms {
+            let weight = fields
+                .iter()
+                .filter(|(words, _)| !term_positions(term, words).is_empty())
+                .map(|(_, weight)| *weight)
+                .reduce(f64::max)?;
+            rank += weight;
+        }
+        Some(rank)
+    }
+
+    /// Excerpt of `text` around the first match, with matched words marked
+    ///
+    /// The text is HTML-escaped before the marks go in, so the result is safe
+    /// to render as HTML.
+    pub fn highlight(&self, text: &str) -> String {
+        let spans: Vec<(usize, &str)> = words(text).collect();
+        let lowered: Vec<String> = spans.iter().map(|(_, w)| w.to_lowercase()).collect();
+        let mut marked = vec![false; spans.len()];
+        for term in &self.terms {
+            for position in term_positions(term, &lowered) {
+                marked[position] = true;
+            }
+        }
+        if spans.is_empty() {
+            return String::new();
+        }
+
+        let first = marked.iter().position(|m| *m).unwrap_or(0);
+        let start = first.saturating_sub(HIGHLIGHT_LEAD);
+        let end = (start + HIGHLIGHT_WORDS).min(spans.len());
+
+        let mut out = String::new();
+        let mut cursor = spans[start].0;
+        for i in start..end {
+            let (offset, word) = spans[i];
+            push_escaped(&mut out, &text[cursor..offset]);
+            if marked[i] {
+                out.push_str(HIGHLIGHT_START);
+                push_escaped(&mut out, word);
+                out.push_str(HIGHLIGHT_END);
+            } else {
+                push_escaped(&mut out, word);
+            }
+            cursor = offset + word.len();
+        }
+        out
+    }
+}
+
+/// Appends `text` with the characters that are special in HTML escaped
+fn push_escaped(out: &mut String, text: &str) {
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            c => out.push(c),
+        }
+    }
+}
+
+/// Runs of letters and digits with their byte offsets
+fn words(text: &str) -> impl Iterator<Item = (usize, &str)> {
+    let mut start = None;
+    text.char_indices()
+        .chain(std::iter::once((text.len(), ' ')))
+        .filter_map(move |(i, c)| match (c.is_alphanumeric(), start) {
+            (true, None) => {
+                start = Some(i);
+                None
+            }
+            (false, Some(from)) => {
+                start = None;
+                Some((from, &text[from..i]))
+            }
+            _ => None,
+        })
+}
+
+fn lowercase_words(text: &str) -> Vec<String> {
+    words(text).map(|(_, w)| w.to_lowercase()).collect()
+}
+
+/// Indexes of the words a term matched
+fn term_positions(term: &SearchTerm, words: &[String]) -> Vec<usize> {
+    match term {
+        SearchTerm::Word(word) => (0..words.len()).filter(|&i| words[i] == *word).collect(),
+        SearchTerm::Prefix(prefix) => (0..words.len()).filter(|&i| words[i].starts_with(prefix.as_str())).collect(),
+        SearchTerm::Phrase(phrase) => words
+            .windows(phrase.len())
+            .enumerate()
+            .filter(|(_, window)| window == phrase)
+            .flat_map(|(i, _)| i..i + phrase.len())
+            .collect(),
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SearchFilters {
+    pub query: SearchQuery,
+    pub pagination: Pagination,
+    /// Who is searching; drafts, muted and blocking authors follow the same
+    /// rules as in article lists
+    pub viewer: Option<UserId>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArticleSearchHit {
+    #[serde(flatten)]
+    pub article: ArticleSummary,
+    pub rank: f64,
+    /// HTML-escaped excerpt of the body with matches wrapped in `<mark>`
+    pub highlight: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchResultsEnvelope {
+    pub articles: Vec<ArticleSearchHit>,
+    #[serde(rename = "articlesCount")]
+    pub articles_count: usize,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_recognises_phrases_and_prefixes() {
+        let query = SearchQuery::parse(r#"Rust "async  Traits" axu* foo-bar* 'x'"#).unwrap();
+        assert_eq!(
+            query.terms(),
+            &[
+                SearchTerm::Word("rust".into()),
+                SearchTerm::Phrase(vec!["async".into(), "traits".into()]),
+                SearchTerm::Prefix("axu".into()),
+                SearchTerm::Word("foo".into()),
+                SearchTerm::Prefix("bar".into()),
+                SearchTerm::Word("x".into()),
+            ]
+        );
+        assert_eq!(SearchQuery::parse(" \"\" & | !* ").unwrap_err(), DomainError::InvalidSearchQuery);
+    }
+
+    #[test]
+    fn rank_requires_every_term_and_prefers_titles() {
+        let query = SearchQuery::parse("rust async*").unwrap();
+        let rank = query.rank("Rust", "asynchronous code", "").unwrap();
+        assert!((rank - 1.4).abs() < 1e-9);
+        assert_eq!(query.rank("", "", "rust asyncio"), Some(0.4));
+        assert_eq!(query.rank("Rust", "", ""), None);
+
+        let phrase = SearchQuery::parse("\"error handling\"").unwrap();
+        assert!(phrase.rank("", "", "Error handling in Rust").is_some());
+        assert!(phrase.rank("", "", "handling an error").is_none());
+    }
+
+    #[test]
+    fn highlight_marks_matches_around_first_hit() {
+        let query = SearchQuery::parse("\"borrow checker\" life*").unwrap();
+        let body = format!("{} The borrow checker, and lifetimes.", "word ".repeat(20));
+        let highlight = query.highlight(&body);
+        assert!(highlight.starts_with("word "));
+        assert!(highlight.ends_with(
+            "The <mark>borrow</mark> <mark>checker</mark>, and <mark>lifetimes</mark>"
+        ));
+        assert_eq!(highlight.matches("word").count(), HIGHLIGHT_LEAD - 1);
+    }
+
+    #[test]
+    fn highlight_escapes_html_in_the_text() {
+        let query = SearchQuery::parse("script").unwrap();
+        let highlight = query.highlight("Tom's <script>alert(\"x\" & 1)</script>");
+        assert_eq!(
+            highlight,
+            "Tom&#39;s &lt;<mark>script</mark>&gt;alert(&quot;x&quot; &amp; 1)&lt;/<mark>script</mark>"
+        );
+    }
+}
@@ -0,0 +1,130 @@
+//! Search engine and link preview metadata of an article
+//!
+//! Every field is optional: without them, pages fall back to the article's
+//! own URL and description, and link previews go without an image.
+
+use serde::{Deserialize, Serialize};
+
+use crate::errors::{DomainError, DomainResult};
+
+/// Longest meta description, in characters; search engines show far less
+pub const MAX_META_DESCRIPTION_LEN: usize = 320;
+/// Longest canonical URL or social image URL
+pub const MAX_SEO_URL_LEN: usize = 2_048;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArticleSeo {
+    /// Where the original lives, when the article was first published
+    /// elsewhere; an absolute `http` or `https` URL
+    pub canonical_url: Option<String>,
+    /// Shown by search engines instead of the description
+    pub meta_description: Option<String>,
+    /// Image for link previews, either an absolute URL or a path on this
+    /// site such as an upload link
+    pub social_image: Option<String>,
+}
+
+impl ArticleSeo {
+    /// Blank values clear the field; all are trimmed
+    pub fn set_canonical_url(&mut self, value: Option<String>) -> DomainResult<()> {
+        let value = non_blank(value);
+        if value.as_deref().is_some_and(|url| !is_absolute_url(url)) {
+            return Err(DomainError::InvalidCanonicalUrl { max: MAX_SEO_URL_LEN });
+        }
+        self.canonical_url = value;
+        Ok(())
+    }
+
+    pub fn set_meta_description(&mut self, value: Option<String>) -> DomainResult<()> {
+        let value = non_blank(value);
+        if value.as_ref().is_some_and(|d| d.chars().count() > MAX_META_DESCRIPTION_LEN) {
+            return Err(DomainError::MetaDescriptionTooLong {
+                max: MAX_META_DESCRIPTION_LEN,
+            });
+        }
+        self.meta_description = value;
+        Ok(())
+    }
+
+    pub fn set_social_image(&mut self, value: Option<String>) -> DomainResult<()> {
+        let value = non_blank(value);
+        if value.as_deref().is_some_and(|url| !is_absolute_url(url) && !is_site_path(url)) {
+            return Err(DomainError::InvalidSocialImage { max: MAX_SEO_URL_LEN });
+        }
+        self.social_image = value;
+        Ok(())
+    }
+}
+
+fn non_blank(value: Option<String>) -> Option<String> {
+    value
+        .map(|v| v.trim().to_owned())
+        .filter(|v| !v.is_empty())
+}
+
+fn is_plain_url(url: &str) -> bool {
+    url.len() <= MAX_SEO_URL_LEN && !url.chars().any(|c| c.is_whitespace() || c.is_control())
+}
+
+fn is_absolute_url(url: &str) -> bool {
+    let rest = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"));
+    is_plain_url(url) && rest.is_some_and(|rest| !rest.is_empty() && !rest.starts_with('/'))
+}
+
+/// `/path` on this site; `//host` would leave it
+fn is_site_path(url: &str) -> bool {
+    is_plain_url(url) && url.starts_with('/') && !url.starts_with("//")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn canonical_urls_must_be_absolute() {
+        let mut seo = ArticleSeo::default();
+        seo.set_canonical_url(Some(" https://blog.example.com/post ".into())).unwrap();
+        assert_eq!(seo.canonical_url.as_deref(), Some("https://blog.example.com/post"));
+
+        for invalid in ["/article/post", "ftp://example.com", "https://", "https:///path", "https://exa mple.com"] {
+            assert_eq!(
+                seo.set_canonical_url(Some(invalid.into())),
+                Err(DomainError::InvalidCanonicalUrl { max: MAX_SEO_URL_LEN })
+            );
+        }
+        let too_long = format!("https://example.com/{}", "a".repeat(MAX_SEO_URL_LEN));
+        assert!(seo.set_canonical_url(Some(too_long)).is_err());
+
+        seo.set_canonical_url(Some("  ".into())).unwrap();
+        assert_eq!(seo.canonical_url, None);
+    }
+
+    #[test]
+    fn social_images_may_be_site_paths() {
+        let mut seo = ArticleSeo::default();
+        seo.set_social_image(Some("/api/uploads/cover.png".into())).unwrap();
+        seo.set_social_image(Some("https://cdn.example.com/cover.png".into())).unwrap();
+        assert_eq!(
+            seo.set_social_image(Some("//evil.example.com/cover.png".into())),
+            Err(DomainError::InvalidSocialImage { max: MAX_SEO_URL_LEN })
+        );
+        assert_eq!(seo.social_image.as_deref(), Some("https://cdn.example.com/cover.png"));
+    }
+
+    #[test]
+    fn meta_descriptions_are_bounded() {
+        let mut seo = ArticleSeo::default();
+        seo.set_meta_description(Some("é".repeat(MAX_META_DESCRIPTION_LEN))).unwrap();
+        assert_eq!(
+            seo.set_meta_description(Some("a".repeat(MAX_META_DESCRIPTION_LEN + 1))),
+            Err(DomainError::MetaDescriptionTooLong {
+                max: MAX_META_DESCRIPTION_LEN
+            })
+        );
+        seo.set_meta_description(None).unwrap();
+        assert_eq!(seo.meta_description, None);
+    }
+}
@@ -0,0 +1,304 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::article::{Article, ArticleSummary, Slug, SlugPolicy};
+use crate::errors::{DomainError, DomainResult};
+use crate::identifiers::{SeriesId, UserId};
+use crate::profile::Profile;
+
+/// Most articles a series or collection can hold
+pub const MAX_SERIES_PARTS: usize = 100;
+
+/// What holds a series' parts together
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SeriesKind {
+    /// A multi-part post: one author's articles, each in at most one series,
+    /// read in order with previous/next links
+    #[default]
+    Series,
+    /// A curated reading list of published articles by anyone
+    Collection,
+}
+
+impl SeriesKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Series => "series",
+            Self::Collection => "collection",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "series" => Some(Self::Series),
+            "collection" => Some(Self::Collection),
+            _ => None,
+        }
+    }
+}
+
+/// An ordered group of articles with its own slug, title and description
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Series {
+    pub id: SeriesId,
+    pub slug: Slug,
+    pub title: String,
+    pub description: String,
+    pub kind: SeriesKind,
+    /// Author of a series, curator of a collection
+    pub owner_id: UserId,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Series {
+    /// The description may be blank; the slug is derived from the title
+    pub fn new(
+        id: SeriesId,
+        owner_id: UserId,
+        title: impl Into<String>,
+        description: impl Into<String>,
+        kind: SeriesKind,
+        slug_policy: &SlugPolicy,
+        now: DateTime<Utc>,
+    ) -> DomainResult<Self> {
+        let title = non_empty_title(title.into())?;
+        Ok(Self {
+            id,
+            slug: slug_policy.slugify(&title)?,
+            title,
+            description: description.into().trim().to_owned(),
+            kind,
+            owner_id,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    /// Retitling keeps the slug, so links to the series never break
+    pub fn apply_changes(
+        &mut self,
+        title: Option<String>,
+        description: Option<String>,
+        now: DateTime<Utc>,
+    ) -> DomainResult<()> {
+        if let Some(title) = title {
+            self.title = non_empty_title(title)?;
+        }
+        if let Some(description) = description {
+            self.description = description.trim().to_owned();
+        }
+        self.updated_at = now;
+        Ok(())
+    }
+
+    pub fn owned_by(&self, user_id: &UserId) -> bool {
+        self.owner_id == *user_id
+    }
+
+    /// Checks articles about to become the parts of this series, in order
+    ///
+    /// A series only takes its owner's articles, drafts included; a
+    /// collection only takes published ones. No article may appear twice.
+    pub fn check_parts(&self, parts: &[Article]) -> DomainResult<()> {
+        if parts.len() > MAX_SERIES_PARTS {
+            return Err(DomainError::TooManySeriesParts {
+                max: MAX_SERIES_PARTS,
+            });
+        }
+        for (i, part) in parts.iter().enumerate() {
+            if parts[..i].iter().any(|earlier| earlier.id == part.id) {
+                return Err(DomainError::DuplicateSeriesPart);
+            }
+            let allowed = match self.kind {
+                SeriesKind::Series => part.authored_by(&self.owner_id),
+                SeriesKind::Collection => part.is_published(),
+            };
+            if !allowed {
+                return Err(DomainError::SeriesPartNotAllowed);
+            }
+        }
+        Ok(())
+    }
+
+    pub fn to_view(&self, owner: Profile, articles: Vec<ArticleSummary>) -> SeriesView {
+        SeriesView {
+            slug: self.slug.clone(),
+            title: self.title.clone(),
+            description: self.description.clone(),
+            kind: self.kind,
+            author: owner,
+            articles_count: articles.len(),
+            articles,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+        }
+    }
+
+    /// Where the article with `slug` sits among the `parts` a viewer can see
+    pub fn navigation(&self, parts: &[ArticleSummary], slug: &Slug) -> Option<SeriesNavigation> {
+        let index = parts.iter().position(|part| part.slug == *slug)?;
+        let link = |part: &ArticleSummary| SeriesLink {
+            slug: part.slug.clone(),
+            title: part.title.clone(),
+        };
+        Some(SeriesNavigation {
+            slug: self.slug.clone(),
+            title: self.title.clone(),
+            part: index + 1,
+            total: parts.len(),
+            previous: index.checked_sub(1).map(|i| link(&parts[i])),
+            next: parts.get(index + 1).map(link),
+        })
+    }
+}
+
+fn non_empty_title(title: String) -> DomainResult<String> {
+    let title = title.trim();
+    if title.is_empty() {
+        return Err(DomainError::InvalidTitle);
+    }
+    Ok(title.to_owned())
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SeriesView {
+    pub slug: Slug,
+    pub title: String,
+    pub description: String,
+    pub kind: SeriesKind,
+    pub author: Profile,
+    /// The parts the viewer can see, in reading order
+    pub articles: Vec<ArticleSummary>,
+    #[serde(rename = "articlesCount")]
+    pub articles_count: usize,
+    #[serde(rename = "createdAt")]
+    pub created_at: DateTime<Utc>,
+    #[serde(rename = "updatedAt")]
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SeriesEnvelope {
+    pub series: SeriesView,
+}
+
+impl From<SeriesView> for SeriesEnvelope {
+    fn from(series: SeriesView) -> Self {
+        Self { series }
+    }
+}
+
+/// Part N of M, with links to the neighbouring parts
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SeriesNavigation {
+    pub slug: Slug,
+    pub title: String,
+    pub part: usize,
+    pub total: usize,
+    pub previous: Option<SeriesLink>,
+    pub next: Option<SeriesLink>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SeriesLink {
+    pub slug: Slug,
+    pub title: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ArticleDraft, ArticleId, Publication, TagList, Username};
+
+    fn series(kind: SeriesKind, owner_id: UserId) -> Series {
+        Series::new(
+            SeriesId::random(),
+            owner_id,
+            "Rust in Production",
+            "  A multi-part post ",
+            kind,
+            &SlugPolicy::default(),
+            Utc::now(),
+        )
+        .unwrap()
+    }
+
+    fn article(author_id: UserId, title: &str, publication: Publication) -> Article {
+        let draft = ArticleDraft::new(title, "desc", "body", TagList::default()).unwrap();
+        let mut article = Article::publish(ArticleId::random(), author_id, draft, Utc::now()).unwrap();
+        article.set_publication(publication, Utc::now());
+        article
+    }
+
+    #[test]
+    fn series_slug_and_description_from_input() {
+        let series = series(SeriesKind::Series, UserId::random());
+        assert_eq!(series.slug.as_str(), "rust-in-production");
+        assert_eq!(series.description, "A multi-part post");
+    }
+
+    #[test]
+    fn retitling_keeps_slug() {
+        let mut series = series(SeriesKind::Series, UserId::random());
+        series.apply_changes(Some("Rust at Scale".to_owned()), None, Utc::now()).unwrap();
+        assert_eq!(series.title, "Rust at Scale");
+        assert_eq!(series.slug.as_str(), "rust-in-production");
+        assert_eq!(
+            series.apply_changes(Some(" ".to_owned()), None, Utc::now()),
+            Err(DomainError::InvalidTitle)
+        );
+    }
+
+    #[test]
+    fn series_parts_must_be_the_owners() {
+        let owner = UserId::random();
+        let series = series(SeriesKind::Series, owner);
+        let own = article(owner, "One", Publication::Draft);
+        let other = article(UserId::random(), "Two", Publication::Now);
+
+        assert_eq!(series.check_parts(std::slice::from_ref(&own)), Ok(()));
+        assert_eq!(
+            series.check_parts(&[own.clone(), other]),
+            Err(DomainError::SeriesPartNotAllowed)
+        );
+        assert_eq!(
+            series.check_parts(&[own.clone(), own]),
+            Err(DomainError::DuplicateSeriesPart)
+        );
+    }
+
+    #[test]
+    fn collection_parts_must_be_published() {
+        let series = series(SeriesKind::Collection, UserId::random());
+        let published = article(UserId::random(), "One", Publication::Now);
+        let draft = article(UserId::random(), "Two", Publication::Draft);
+
+        assert_eq!(series.check_parts(std::slice::from_ref(&published)), Ok(()));
+        assert_eq!(
+            series.check_parts(&[published, draft]),
+            Err(DomainError::SeriesPartNotAllowed)
+        );
+    }
+
+    #[test]
+    fn navigation_links_neighbours() {
+        let owner = UserId::random();
+        let series = series(SeriesKind::Series, owner);
+        let profile = Profile::new(Username::new("author").unwrap(), None, None, false);
+        let parts: Vec<ArticleSummary> = ["One", "Two", "Three"]
+            .into_iter()
+            .map(|title| article(owner, title, Publication::Now).to_summary(profile.clone(), false))
+            .collect();
+
+        let middle = series.navigation(&parts, &parts[1].slug).unwrap();
+        assert_eq!((middle.part, middle.total), (2, 3));
+        assert_eq!(middle.previous.unwrap().title, "One");
+        assert_eq!(middle.next.unwrap().title, "Three");
+
+        let first = series.navigation(&parts, &parts[0].slug).unwrap();
+        assert!(first.previous.is_none());
+        assert!(series.navigation(&parts, &Slug::new("elsewhere").unwrap()).is_none());
+    }
+}
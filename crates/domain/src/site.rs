@@ -0,0 +1,44 @@
+//! Absolute links into the web app
+//!
+//! Feeds and sitemaps are read outside the app, so their links carry the
+//! public site URL rather than a path.
+
+use crate::avatar::encode_path_segment;
+
+/// Builds absolute URLs of the web app's pages
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SiteUrls {
+    /// Where the web app is served, without a trailing `/`; feeds and
+    /// sitemaps are expected under it too
+    pub base_url: String,
+}
+
+impl Default for SiteUrls {
+    fn default() -> Self {
+        Self::new("http://localhost:3000")
+    }
+}
+
+impl SiteUrls {
+    pub fn new(base_url: impl Into<String>) -> Self {
+        Self {
+            base_url: base_url.into().trim_end_matches('/').to_owned(),
+        }
+    }
+
+    pub fn home_url(&self) -> String {
+        format!("{}/", self.base_url)
+    }
+
+    pub fn article_url(&self, slug: &str) -> String {
+        format!("{}/article/{}", self.base_url, encode_path_segment(slug))
+    }
+
+    pub fn profile_url(&self, username: &str) -> String {
+        format!("{}/profile/{}", self.base_url, encode_path_segment(username))
+    }
+
+    pub fn tag_url(&self, tag: &str) -> String {
+        format!("{}/tag/{}", self.base_url, encode_path_segment(tag))
+    }
+}
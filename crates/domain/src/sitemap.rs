@@ -0,0 +1,137 @@
+//! XML sitemaps of published articles and their authors' profiles
+//!
+//! A sitemap may list at most 50,000 URLs, so a site with more serves a
+//! sitemap index at `/sitemap.xml` pointing at numbered sitemaps under
+//! `/sitemaps/`, as the sitemaps.org protocol lays out.
+
+use std::fmt::Write;
+
+use chrono::{DateTime, SecondsFormat, Utc};
+
+use crate::SiteUrls;
+
+/// Most URLs a single sitemap may list
+pub const MAX_SITEMAP_URLS: u32 = 50_000;
+
+const SITEMAP_NAMESPACE: &str = "http://www.sitemaps.org/schemas/sitemap/0.9";
+
+/// A page listed in the sitemap
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SitemapLocation {
+    /// By slug
+    Article(String),
+    /// By username
+    Profile(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SitemapEntry {
+    pub location: SitemapLocation,
+    pub last_modified: DateTime<Utc>,
+}
+
+/// What `/sitemap.xml` or one of the numbered sitemaps holds
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Sitemap {
+    /// A `urlset` listing the pages themselves
+    Urls(Vec<SitemapEntry>),
+    /// A `sitemapindex` of the numbered sitemaps, from 1 to `pages`
+    Index { pages: u32 },
+}
+
+impl Sitemap {
+    /// Where the numbered sitemap `page` is served, counting from 1
+    pub fn page_url(site: &SiteUrls, page: u32) -> String {
+        format!("{}/sitemaps/{page}.xml", site.base_url)
+    }
+
+    /// Splits a requested file name such as `2.xml` into its page number
+    pub fn parse_page(file_name: &str) -> Option<u32> {
+        let page = file_name.strip_suffix(".xml")?;
+        if !page.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        page.parse().ok().filter(|page| *page > 0)
+    }
+
+    pub fn render(&self, site: &SiteUrls) -> String {
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        match self {
+            Self::Urls(entries) => {
+                let _ = writeln!(xml, "<urlset xmlns=\"{SITEMAP_NAMESPACE}\">");
+                for entry in entries {
+                    let loc = match &entry.location {
+                        SitemapLocation::Article(slug) => site.article_url(slug),
+                        SitemapLocation::Profile(username) => site.profile_url(username),
+                    };
+                    let _ = writeln!(
+                        xml,
+                        "<url><loc>{}</loc><lastmod>{}</lastmod></url>",
+                        escape(&loc),
+                        entry.last_modified.to_rfc3339_opts(SecondsFormat::Secs, true),
+                    );
+                }
+                xml.push_str("</urlset>\n");
+            }
+            Self::Index { pages } => {
+                let _ = writeln!(xml, "<sitemapindex xmlns=\"{SITEMAP_NAMESPACE}\">");
+                for page in 1..=*pages {
+                    let _ = writeln!(xml, "<sitemap><loc>{}</loc></sitemap>", escape(&Self::page_url(site, page)));
+                }
+                xml.push_str("</sitemapindex>\n");
+            }
+        }
+        xml
+    }
+}
+
+/// Paths are percent-encoded already, but the site URL is taken as given
+fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn urlset_lists_articles_and_profiles() {
+        let at = Utc.with_ymd_and_hms(2026, 3, 1, 9, 30, 15).unwrap();
+        let sitemap = Sitemap::Urls(vec![
+            SitemapEntry {
+                location: SitemapLocation::Article("héllo-world".into()),
+                last_modified: at,
+            },
+            SitemapEntry {
+                location: SitemapLocation::Profile("jake".into()),
+                last_modified: at,
+            },
+        ]);
+        let xml = sitemap.render(&SiteUrls::new("https://example.com/?a=1&b=2"));
+
+        assert!(xml.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">"));
+        assert!(xml.contains("<url><loc>https://example.com/?a=1&amp;b=2/article/h%C3%A9llo-world</loc><lastmod>2026-03-01T09:30:15Z</lastmod></url>"));
+        assert!(xml.contains("/profile/jake</loc>"));
+        assert!(xml.ends_with("</urlset>\n"));
+    }
+
+    #[test]
+    fn index_points_at_numbered_sitemaps() {
+        let xml = Sitemap::Index { pages: 2 }.render(&SiteUrls::new("https://example.com"));
+        assert!(xml.contains("<sitemapindex xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">"));
+        assert!(xml.contains("<sitemap><loc>https://example.com/sitemaps/1.xml</loc></sitemap>"));
+        assert!(xml.contains("<sitemap><loc>https://example.com/sitemaps/2.xml</loc></sitemap>"));
+        assert!(!xml.contains("3.xml"));
+
+        assert_eq!(Sitemap::parse_page("2.xml"), Some(2));
+        for invalid in ["0.xml", "+1.xml", "1.txt", ".xml", "one.xml"] {
+            assert_eq!(Sitemap::parse_page(invalid), None);
+        }
+    }
+}
@@ -1,18 +1,28 @@
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use unicode_normalization::UnicodeNormalization;
 
 use crate::errors::{DomainError, DomainResult};
 
+/// Longest tag accepted, in characters, after normalization
+pub const MAX_TAG_LEN: usize = 32;
+
+/// A tag in canonical form: NFKC-normalized, lowercase, with whitespace runs
+/// turned into single hyphens, so `Rust`, ` rust ` and `ｒｕｓｔ` are one tag
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
-#[serde(transparent)]
+#[serde(try_from = "String", into = "String")]
 pub struct Tag(String);
 
 impl Tag {
     pub fn new(value: impl Into<String>) -> DomainResult<Self> {
-        let trimmed = value.into().trim().to_owned();
-        if trimmed.is_empty() {
+        let normalized = value.into().nfkc().collect::<String>().to_lowercase();
+        let canonical = normalized.split_whitespace().collect::<Vec<_>>().join("-");
+        if canonical.is_empty() {
             return Err(DomainError::InvalidTag);
         }
-        Ok(Self(trimmed))
+        if canonical.chars().count() > MAX_TAG_LEN {
+            return Err(DomainError::TagTooLong { max: MAX_TAG_LEN });
+        }
+        Ok(Self(canonical))
     }
 
     pub fn as_str(&self) -> &str {
@@ -26,6 +36,14 @@ impl From<Tag> for String {
     }
 }
 
+impl TryFrom<String> for Tag {
+    type Error = DomainError;
+
+    fn try_from(value: String) -> DomainResult<Self> {
+        Self::new(value)
+    }
+}
+
 /// A list of tags that serializes as a flat array of strings.
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct TagList {
@@ -48,11 +66,11 @@ impl<'de> Deserialize<'de> for TagList {
         D: Deserializer<'de>,
     {
         let strings: Vec<String> = Vec::deserialize(deserializer)?;
-        let tags = strings
-            .into_iter()
-            .filter_map(|s| Tag::new(s).ok())
-            .collect();
-        Ok(Self { tags })
+        let mut tags = Self::default();
+        for tag in strings.into_iter().filter_map(|s| Tag::new(s).ok()) {
+            tags.push(tag);
+        }
+        Ok(tags)
     }
 }
 
@@ -86,6 +104,75 @@ impl TagList {
             self.tags.push(tag);
         }
     }
+
+    /// Replaces tags through `resolve`, dropping any that become duplicates
+    pub fn map(self, mut resolve: impl FnMut(Tag) -> Tag) -> Self {
+        let mut mapped = Self::default();
+        for tag in self.tags {
+            mapped.push(resolve(tag));
+        }
+        mapped
+    }
+}
+
+/// A tag and how many published articles carry it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TagCount {
+    pub name: String,
+    #[serde(rename = "articlesCount")]
+    pub articles_count: u64,
+}
+
+/// `tags` keeps the plain list clients already read; `tagCounts` carries the
+/// same tags, in the same order, with their counts
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TagsEnvelope {
+    pub tags: Vec<String>,
+    #[serde(rename = "tagCounts")]
+    pub tag_counts: Vec<TagCount>,
+}
+
+impl From<Vec<TagCount>> for TagsEnvelope {
+    fn from(value: Vec<TagCount>) -> Self {
+        Self {
+            tags: value.iter().map(|t| t.name.clone()).collect(),
+            tag_counts: value,
+        }
+    }
+}
+
+/// A tag as seen by the current user
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TagView {
+    pub name: String,
+    pub following: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TagEnvelope {
+    pub tag: TagView,
+}
+
+/// Tags the current user follows, by name
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FollowedTagsEnvelope {
+    pub tags: TagList,
+}
+
+/// Outcome of merging one tag into another
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TagMerge {
+    pub from: String,
+    pub into: String,
+    /// Articles that carried `from` and now carry `into`
+    #[serde(rename = "articlesCount")]
+    pub articles_count: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TagMergeEnvelope {
+    #[serde(rename = "tagMerge")]
+    pub tag_merge: TagMerge,
 }
 
 #[cfg(test)]
@@ -98,6 +185,30 @@ mod tests {
         assert_eq!(Tag::new("   ").unwrap_err(), DomainError::InvalidTag);
     }
 
+    #[test]
+    fn tag_is_normalized() {
+        assert_eq!(Tag::new("  Rust ").unwrap().as_str(), "rust");
+        assert_eq!(Tag::new("Web \t Assembly").unwrap().as_str(), "web-assembly");
+        // Fullwidth letters fold to ASCII under NFKC
+        assert_eq!(Tag::new("ＲＵＳＴ").unwrap().as_str(), "rust");
+        assert_eq!(Tag::new("ﬁle").unwrap().as_str(), "file");
+    }
+
+    #[test]
+    fn tag_rejects_overlong_values() {
+        assert!(Tag::new("a".repeat(MAX_TAG_LEN)).is_ok());
+        assert_eq!(
+            Tag::new("a".repeat(MAX_TAG_LEN + 1)).unwrap_err(),
+            DomainError::TagTooLong { max: MAX_TAG_LEN }
+        );
+    }
+
+    #[test]
+    fn tag_list_merges_spellings() {
+        let tags = TagList::new(["Rust", " rust", "RUST", "axum"]).unwrap();
+        assert_eq!(tags.as_slice().len(), 2);
+    }
+
     #[test]
     fn tag_list_deduplicates() {
         let tags = TagList::new(["rust", "rust", "axum"]).unwrap();
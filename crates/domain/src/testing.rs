@@ -0,0 +1,1062 @@
+//! Scenarios every repository implementation has to pass
+//!
+//! The in-memory repositories run them as unit tests; the Postgres ones run
+//! them from `crates/data/tests` against a throwaway database. Enable the
+//! `testing` feature to use them outside this crate.
+//!
+//! They pin down what each repository method returns, so both
+//! implementations agree; the business rules built on top are tested next to
+//! the use cases.
+
+use chrono::{DateTime, TimeZone, Utc};
+
+use crate::repositories::{
+    ArticlesRepository, BlobStore, CommentsRepository, PartOfAnotherSeries, ReportsRepository, SeriesRepository,
+    TagsRepository, UsersRepository,
+};
+use crate::{
+    Article, ArticleChanges, ArticleDraft, ArticleFilters, ArticleId, ArticleSort, AuthoredContentPolicy, Blob, Bookmark, ImageUrl, BookmarkFilters, Coauthor, Comment, CommentDraft, CommentId, Email,
+    FeedFilters, ModerationAction, ModerationRecord, Pagination, PasswordHash, Publication, ReactionCount, ReactionKind, RelatedScoring, Report, ReportId, ReportReason, ReportStatus, ReportTarget, Series, SeriesId, SeriesKind, SitemapEntry, SitemapLocation, SlugPolicy, Tag,
+    TagList, TagMatch, User, UserId, Username,
+};
+
+/// Sorting and filtering of `list_articles`
+///
+/// Expects empty repositories.
+pub async fn article_listing<U, A>(users_repo: &U, articles_repo: &A)
+where
+    U: UsersRepository,
+    A: ArticlesRepository,
+{
+    let base = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+    let day = |n: i64| base + chrono::Duration::days(n);
+
+    let mut users = Vec::new();
+    for name in ["alice", "bob", "carol"] {
+        let user = User::new(
+            UserId::random(),
+            Email::parse(format!("{name}@example.com")).unwrap(),
+            Username::new(name).unwrap(),
+            PasswordHash::new("hash").unwrap(),
+            base,
+        );
+        users.push(users_repo.create_user(user).await.unwrap());
+    }
+    let (alice, bob, carol) = (users[0].id, users[1].id, users[2].id);
+
+    let mut articles = Vec::new();
+    for (title, author, tags, created_at) in [
+        ("One", alice, vec!["rust", "web"], day(1)),
+        ("Two", bob, vec!["rust"], day(2)),
+        ("Three", alice, vec!["go", "web"], day(3)),
+        ("Four", bob, vec![], day(4)),
+    ] {
+        articles.push(publish(articles_repo, author, title, tags, created_at).await);
+    }
+
+    let list = |filters: ArticleFilters| async move {
+        let envelope = articles_repo.list_articles(filters).await.unwrap();
+        let titles: Vec<String> = envelope.articles.into_iter().map(|a| a.title).collect();
+        titles
+    };
+    let filters = ArticleFilters::default;
+    let strings = |list: &[&str]| list.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+
+    assert_eq!(list(filters()).await, ["Four", "Three", "Two", "One"]);
+
+    let any = filters().with_tags(strings(&["rust", "web"]), TagMatch::Any).unwrap();
+    assert_eq!(list(any).await, ["Three", "Two", "One"]);
+    let all = filters().with_tags(strings(&["rust", "web"]), TagMatch::All).unwrap();
+    assert_eq!(list(all).await, ["One"]);
+    let excluded = filters().without_tags(strings(&["web"])).unwrap();
+    assert_eq!(list(excluded).await, ["Four", "Two"]);
+    let rust_not_web = filters()
+        .with_tags(strings(&["rust"]), TagMatch::Any)
+        .unwrap()
+        .without_tags(strings(&["web"]))
+        .unwrap();
+    assert_eq!(list(rust_not_web).await, ["Two"]);
+
+    let by_alice = filters().with_authors(strings(&["alice"]));
+    assert_eq!(list(by_alice).await, ["Three", "One"]);
+    let by_both = filters().with_authors(strings(&["alice", "bob", "nobody"]));
+    assert_eq!(list(by_both).await.len(), 4);
+
+    let between = filters().created_between(Some(day(2)), Some(day(4)));
+    assert_eq!(list(between).await, ["Three", "Two"]);
+
+    // Editing "One" makes it the most recently updated
+    let mut edited = articles[0].clone();
+    edited.updated_at = day(10);
+    articles_repo.update_article(edited).await.unwrap();
+    let updated = filters().with_sort(ArticleSort::Updated, None);
+    assert_eq!(list(updated).await, ["One", "Four", "Three", "Two"]);
+
+    // Two earlier favorites for "One", one for "Two", and a fresh one for "Four"
+    articles_repo.favorite_article(carol, articles[0].id, day(5)).await.unwrap();
+    articles_repo.favorite_article(bob, articles[0].id, day(6)).await.unwrap();
+    articles_repo.favorite_article(carol, articles[1].id, day(6)).await.unwrap();
+    let window_start = day(8);
+    articles_repo.favorite_article(alice, articles[3].id, day(9)).await.unwrap();
+
+    let popular = filters().with_sort(ArticleSort::Popular, None);
+    assert_eq!(list(popular).await, ["One", "Four", "Two", "Three"]);
+    let trending = filters().with_sort(ArticleSort::Trending, Some(window_start));
+    assert_eq!(list(trending).await, ["Four", "Three", "Two", "One"]);
+
+    let favorited_trending = ArticleFilters {
+        favorited: Some("carol".to_owned()),
+        ..filters().with_sort(ArticleSort::Trending, Some(window_start))
+    };
+    assert_eq!(list(favorited_trending).await, ["Two", "One"]);
+}
+
+/// Merging tags retags articles and leaves the old name as an alias
+///
+/// Expects empty repositories sharing one store.
+pub async fn tag_merging<U, A, T>(users_repo: &U, articles_repo: &A, tags_repo: &T)
+where
+    U: UsersRepository,
+    A: ArticlesRepository,
+    T: TagsRepository,
+{
+    let base = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+    let author = create_user(users_repo, "author", base).await;
+    let reader = create_user(users_repo, "reader", base).await;
+    let tag = |name: &str| Tag::new(name).unwrap();
+    let tags = |names: &[&str]| TagList::new(names.iter().copied()).unwrap();
+
+    let both = publish(articles_repo, author, "Both", vec!["js", "javascript"], base).await;
+    let alias_only = publish(articles_repo, author, "Alias", vec!["js", "web"], base).await;
+    tags_repo.follow_tag(reader, &tag("js")).await.unwrap();
+    tags_repo.follow_tag(reader, &tag("javascript")).await.unwrap();
+
+    assert_eq!(tags_repo.merge_tags(&tag("js"), &tag("javascript")).await.unwrap(), 2);
+
+    let stored = |id| async move { articles_repo.get_article_by_id(id).await.unwrap().unwrap().tag_list };
+    assert_eq!(stored(both.id).await, tags(&["javascript"]));
+    assert_eq!(stored(alias_only.id).await, tags(&["javascript", "web"]));
+    assert_eq!(tags_repo.canonical_tag(&tag("js")).await.unwrap(), tag("javascript"));
+    assert_eq!(tags_repo.canonical_tag(&tag("web")).await.unwrap(), tag("web"));
+    assert_eq!(tags_repo.followed_tags(reader).await.unwrap(), [tag("javascript")]);
+
+    let created = publish(articles_repo, author, "Later", vec!["js"], base).await;
+    assert_eq!(created.tag_list, tags(&["javascript"]));
+    let popular = tags_repo.popular_tags(10).await.unwrap();
+    let counts: Vec<(&str, u64)> = popular.iter().map(|t| (t.name.as_str(), t.articles_count)).collect();
+    assert_eq!(counts, [("javascript", 3), ("web", 1)]);
+}
+
+/// `feed_articles` takes articles by followed authors or with a followed tag,
+/// minus muted authors, and says which reasons apply
+///
+/// Expects empty repositories sharing one store.
+pub async fn followed_feed<U, A, T>(users_repo: &U, articles_repo: &A, tags_repo: &T)
+where
+    U: UsersRepository,
+    A: ArticlesRepository,
+    T: TagsRepository,
+{
+    let base = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+    let day = |n: i64| base + chrono::Duration::days(n);
+    let reader = create_user(users_repo, "reader", base).await;
+    let followed = create_user(users_repo, "followed", base).await;
+    let muted = create_user(users_repo, "muted", base).await;
+    users_repo.follow_user(reader, followed).await.unwrap();
+    tags_repo.follow_tag(reader, &Tag::new("rust").unwrap()).await.unwrap();
+
+    for (title, author, tags, created_at) in [
+        ("Followed author", followed, vec!["rust", "go"], day(1)),
+        ("Followed tag", muted, vec!["rust"], day(2)),
+        ("Neither", muted, vec!["go"], day(3)),
+    ] {
+        publish(articles_repo, author, title, tags, created_at).await;
+    }
+
+    let feed = articles_repo.feed_articles(reader, FeedFilters::default()).await.unwrap();
+    let reasons: Vec<_> = feed
+        .articles
+        .iter()
+        .map(|a| {
+            let reason = a.reason.clone().unwrap();
+            (a.title.as_str(), reason.followed_author, reason.followed_tags)
+        })
+        .collect();
+    let rust = || TagList::new(["rust"]).unwrap();
+    assert_eq!(reasons, [("Followed tag", false, rust()), ("Followed author", true, rust())]);
+    assert_eq!(feed.articles_count, 2);
+
+    users_repo.mute_user(reader, muted).await.unwrap();
+    let feed = articles_repo.feed_articles(reader, FeedFilters::default()).await.unwrap();
+    assert_eq!(feed.articles_count, 1);
+}
+
+/// Bookmarks round trip through `list_bookmarks`, newest first, and show up
+/// on article listings for their owner only
+///
+/// Expects empty repositories sharing one store.
+pub async fn bookmarks<U, A>(users_repo: &U, articles_repo: &A)
+where
+    U: UsersRepository,
+    A: ArticlesRepository,
+{
+    let base = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+    let day = |n: i64| base + chrono::Duration::days(n);
+    let reader = create_user(users_repo, "reader", base).await;
+    let author = create_user(users_repo, "author", base).await;
+    let first = publish(articles_repo, author, "First", vec![], day(1)).await;
+    let second = publish(articles_repo, author, "Second", vec![], day(2)).await;
+
+    let save = |article: &Article, folder: Option<&str>, at| {
+        let bookmark = Bookmark::new(reader, article.id, Some("note".to_owned()), folder.map(str::to_owned), at)
+            .unwrap();
+        articles_repo.bookmark_article(bookmark)
+    };
+    save(&first, Some("later"), day(10)).await.unwrap();
+    save(&second, None, day(11)).await.unwrap();
+
+    let list = |filters: BookmarkFilters| async move {
+        let envelope = articles_repo.list_bookmarks(reader, filters).await.unwrap();
+        let titles: Vec<String> = envelope.bookmarks.into_iter().map(|b| b.article.title).collect();
+        titles
+    };
+    let envelope = articles_repo.list_bookmarks(reader, BookmarkFilters::default()).await.unwrap();
+    assert_eq!(envelope.bookmarks_count, 2);
+    assert_eq!(envelope.bookmarks[0].article.title, "Second");
+    assert!(envelope.bookmarks[0].article.bookmarked);
+    assert_eq!(envelope.bookmarks[1].note.as_deref(), Some("note"));
+    assert_eq!(envelope.bookmarks[1].folder.as_deref(), Some("later"));
+    assert_eq!(envelope.bookmarks[1].created_at, day(10));
+    let later = BookmarkFilters {
+        folder: Some("later".to_owned()),
+        ..Default::default()
+    };
+    assert_eq!(list(later).await, ["First"]);
+    let paged = BookmarkFilters {
+        pagination: Pagination::new(Some(1), Some(1)).unwrap(),
+        ..Default::default()
+    };
+    assert_eq!(list(paged).await, ["First"]);
+
+    // Saving again replaces the folder but keeps the original time
+    let moved = save(&first, None, day(20)).await.unwrap();
+    assert_eq!((moved.folder, moved.created_at), (None, day(10)));
+
+    let bookmarked = |viewer: Option<UserId>| async move {
+        let filters = ArticleFilters {
+            viewer,
+            ..Default::default()
+        };
+        let envelope = articles_repo.list_articles(filters).await.unwrap();
+        envelope.articles.into_iter().map(|a| a.bookmarked).collect::<Vec<_>>()
+    };
+    assert_eq!(bookmarked(Some(reader)).await, [true, true]);
+    assert_eq!(bookmarked(Some(author)).await, [false, false]);
+
+    articles_repo.remove_bookmark(reader, first.id).await.unwrap();
+    assert!(articles_repo.get_bookmark(reader, first.id).await.unwrap().is_none());
+    assert_eq!(list(BookmarkFilters::default()).await, ["Second"]);
+}
+
+/// Series keep their parts in order, drafts only for their author, and drop
+/// articles that go to the trash
+///
+/// Expects empty repositories sharing one store.
+pub async fn series_parts<U, A, S>(users_repo: &U, articles_repo: &A, series_repo: &S)
+where
+    U: UsersRepository,
+    A: ArticlesRepository,
+    S: SeriesRepository,
+{
+    let base = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+    let day = |n: i64| base + chrono::Duration::days(n);
+    let author = create_user(users_repo, "author", base).await;
+    let curator = create_user(users_repo, "curator", base).await;
+    let one = publish(articles_repo, author, "One", vec![], day(1)).await;
+    let two = publish(articles_repo, author, "Two", vec![], day(2)).await;
+    let mut draft = publish(articles_repo, author, "Three", vec![], day(3)).await;
+    draft.set_publication(Publication::Draft, day(3));
+    let draft = articles_repo.update_article(draft).await.unwrap();
+
+    let new_series = |owner, title: &str, kind| {
+        let series = Series::new(SeriesId::random(), owner, title, "About it", kind, &SlugPolicy::default(), base).unwrap();
+        series_repo.create_series(series)
+    };
+    let series = new_series(author, "Deep Dive", SeriesKind::Series).await.unwrap();
+    let collection = new_series(curator, "Best Of", SeriesKind::Collection).await.unwrap();
+    assert!(new_series(curator, "Deep Dive", SeriesKind::Collection).await.is_err());
+    assert_eq!(series_repo.get_series_by_slug("deep-dive").await.unwrap(), Some(series.clone()));
+
+    series_repo.set_series_parts(series.id, &[two.id, draft.id, one.id]).await.unwrap();
+    series_repo.set_series_parts(collection.id, &[one.id]).await.unwrap();
+    let titles = |viewer| async move {
+        let parts = series_repo.list_series_parts(series.id, viewer).await.unwrap();
+        parts.into_iter().map(|a| a.title).collect::<Vec<_>>()
+    };
+    assert_eq!(titles(Some(author)).await, ["Two", "Three", "One"]);
+    assert_eq!(titles(None).await, ["Two", "One"]);
+    series_repo.set_series_parts(series.id, &[one.id, two.id]).await.unwrap();
+    assert_eq!(titles(Some(author)).await, ["One", "Two"]);
+
+    let of = |article_id| async move { series_repo.series_of_article(article_id).await.unwrap().map(|s| s.id) };
+    assert_eq!(of(one.id).await, Some(series.id));
+    assert_eq!(of(draft.id).await, None);
+
+    let rival = new_series(author, "Shallow Dive", SeriesKind::Series).await.unwrap();
+    let err = series_repo.set_series_parts(rival.id, &[draft.id, two.id]).await.unwrap_err();
+    assert!(err.is::<PartOfAnotherSeries>());
+    assert!(series_repo.list_series_parts(rival.id, Some(author)).await.unwrap().is_empty());
+
+    let mut renamed = series.clone();
+    renamed.apply_changes(Some("Deeper Dive".to_owned()), None, day(4)).unwrap();
+    series_repo.update_series(renamed.clone()).await.unwrap();
+    assert_eq!(series_repo.get_series_by_slug("deep-dive").await.unwrap(), Some(renamed));
+
+    articles_repo.delete_article(one.id, day(5)).await.unwrap();
+    assert_eq!(titles(None).await, ["Two"]);
+    assert!(series_repo.list_series_parts(collection.id, None).await.unwrap().is_empty());
+
+    series_repo.delete_series(series.id).await.unwrap();
+    assert!(series_repo.get_series_by_slug("deep-dive").await.unwrap().is_none());
+    assert_eq!(of(two.id).await, None);
+}
+
+/// Co-author invitations round trip, accepted co-authors are listed in the
+/// order they joined, and feeds reach followers of any co-author
+///
+/// Expects empty repositories sharing one store.
+pub async fn coauthors<U, A>(users_repo: &U, articles_repo: &A)
+where
+    U: UsersRepository,
+    A: ArticlesRepository,
+{
+    let base = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+    let day = |n: i64| base + chrono::Duration::days(n);
+    let owner = create_user(users_repo, "owner", base).await;
+    let first = create_user(users_repo, "first", base).await;
+    let second = create_user(users_repo, "second", base).await;
+    let reader = create_user(users_repo, "reader", base).await;
+    users_repo.follow_user(reader, second).await.unwrap();
+    let article = publish(articles_repo, owner, "Joint Work", vec![], day(1)).await;
+    let coauthor_ids = |id| async move {
+        articles_repo.get_article_by_id(id).await.unwrap().unwrap().coauthor_ids
+    };
+    let feed_count = || async {
+        articles_repo.feed_articles(reader, FeedFilters::default()).await.unwrap().articles_count
+    };
+
+    for user_id in [first, second] {
+        articles_repo.invite_coauthor(Coauthor::invite(article.id, user_id, day(2))).await.unwrap();
+    }
+    let pending = articles_repo.get_coauthor(article.id, first).await.unwrap().unwrap();
+    assert_eq!((pending.invited_at, pending.accepted_at), (day(2), None));
+    assert!(coauthor_ids(article.id).await.is_empty());
+    assert_eq!(feed_count().await, 0);
+
+    articles_repo.accept_coauthor(article.id, second, day(3)).await.unwrap();
+    articles_repo.accept_coauthor(article.id, first, day(4)).await.unwrap();
+    articles_repo.invite_coauthor(Coauthor::invite(article.id, first, day(5))).await.unwrap();
+    assert_eq!(coauthor_ids(article.id).await, [second, first]);
+    let accepted = articles_repo.get_coauthor(article.id, first).await.unwrap().unwrap();
+    assert_eq!(accepted.accepted_at, Some(day(4)));
+    assert_eq!(feed_count().await, 1);
+
+    // Co-authors aren't part of the article row
+    let mut edited = articles_repo.get_article_by_id(article.id).await.unwrap().unwrap();
+    edited.coauthor_ids.clear();
+    articles_repo.update_article(edited).await.unwrap();
+    assert_eq!(coauthor_ids(article.id).await, [second, first]);
+
+    articles_repo.remove_coauthor(article.id, second).await.unwrap();
+    assert_eq!(coauthor_ids(article.id).await, [first]);
+    assert!(articles_repo.get_coauthor(article.id, second).await.unwrap().is_none());
+    assert_eq!(feed_count().await, 0);
+
+    articles_repo.delete_article(article.id, day(6)).await.unwrap();
+    articles_repo.purge_trashed_articles(day(6)).await.unwrap();
+    assert!(articles_repo.get_coauthor(article.id, first).await.unwrap().is_none());
+}
+
+/// New users have no image of their own, and one set can be cleared again
+///
+/// Expects an empty repository.
+pub async fn user_images<U>(users_repo: &U)
+where
+    U: UsersRepository,
+{
+    let base = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+    let id = create_user(users_repo, "pictured", base).await;
+    let image = |id| async move { users_repo.get_user_by_id(id).await.unwrap().unwrap().image };
+    assert_eq!(image(id).await, None);
+
+    let mut user = users_repo.get_user_by_id(id).await.unwrap().unwrap();
+    let url = ImageUrl::new("https://example.com/pictured.png").unwrap();
+    user.image = Some(url.clone());
+    users_repo.update_user(user.clone()).await.unwrap();
+    assert_eq!(image(id).await, Some(url));
+
+    user.image = None;
+    users_repo.update_user(user).await.unwrap();
+    assert_eq!(image(id).await, None);
+}
+
+/// Storing, replacing and deleting blobs
+///
+/// Expects an empty store.
+pub async fn blob_storage<B>(store: &B)
+where
+    B: BlobStore + ?Sized,
+{
+    let key = "0123456789abcdef0123456789abcdef.png";
+    let blob = |content_type: &str, bytes: &[u8]| Blob {
+        content_type: content_type.to_owned(),
+        bytes: bytes.to_vec(),
+    };
+
+    assert!(store.get_blob(key).await.unwrap().is_none());
+    store.put_blob(key, blob("image/png", b"first")).await.unwrap();
+    assert_eq!(store.get_blob(key).await.unwrap(), Some(blob("image/png", b"first")));
+
+    store.put_blob(key, blob("image/png", b"second")).await.unwrap();
+    assert_eq!(store.get_blob(key).await.unwrap().unwrap().bytes, b"second");
+
+    store.delete_blob(key).await.unwrap();
+    assert!(store.get_blob(key).await.unwrap().is_none());
+    store.delete_blob(key).await.unwrap();
+}
+
+/// Ranking of `related_articles` and who gets to see which
+///
+/// Expects empty repositories.
+pub async fn related_articles<U, A>(users_repo: &U, articles_repo: &A)
+where
+    U: UsersRepository,
+    A: ArticlesRepository,
+{
+    let base = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+    let day = |n: i64| base + chrono::Duration::days(n);
+    let author = create_user(users_repo, "author", base).await;
+    let other = create_user(users_repo, "other", base).await;
+    let loud = create_user(users_repo, "loud", base).await;
+    let fan = create_user(users_repo, "fan", base).await;
+    let reader = create_user(users_repo, "reader", base).await;
+
+    let source = publish(articles_repo, author, "Source", vec!["rust", "wasm"], day(30)).await;
+    publish(articles_repo, loud, "Loud Wasm", vec!["wasm"], day(11)).await;
+    publish(articles_repo, other, "Wasm Tricks", vec!["wasm"], day(10)).await;
+    publish(articles_repo, other, "Rust Basics", vec!["rust"], day(10)).await;
+    let coauthored = publish(articles_repo, other, "Rust Again", vec!["rust"], day(1)).await;
+    publish(articles_repo, other, "Rust Thrice", vec!["rust", "cooking"], day(2)).await;
+    let favourite = publish(articles_repo, other, "Fan Favourite", vec!["gardening"], day(10)).await;
+    publish(articles_repo, author, "By Author", vec!["cooking"], day(30)).await;
+    publish(articles_repo, other, "Elsewhere", vec!["cooking"], day(35)).await;
+    let mut draft = publish(articles_repo, author, "Unfinished Wasm", vec!["wasm"], day(35)).await;
+    draft.set_publication(Publication::Draft, day(35));
+    articles_repo.update_article(draft).await.unwrap();
+
+    for article_id in [source.id, favourite.id] {
+        articles_repo.favorite_article(fan, article_id, day(36)).await.unwrap();
+    }
+    users_repo.mute_user(reader, loud).await.unwrap();
+    articles_repo.invite_coauthor(Coauthor::invite(coauthored.id, reader, day(2))).await.unwrap();
+    articles_repo.accept_coauthor(coauthored.id, reader, day(2)).await.unwrap();
+
+    let related = |viewer, limit| async move {
+        articles_repo
+            .related_articles(source.id, viewer, &RelatedScoring::default(), limit, day(40))
+            .await
+            .unwrap()
+    };
+    let titles = |viewer, limit| async move {
+        related(viewer, limit).await.into_iter().map(|a| a.title).collect::<Vec<_>>()
+    };
+
+    // The rarer wasm beats rust, both beat a shared fan, which beats a shared
+    // owner; within each, newer first. Nothing else qualifies
+    assert_eq!(
+        titles(None, 10).await,
+        ["Loud Wasm", "Wasm Tricks", "Rust Basics", "Rust Thrice", "Rust Again", "Fan Favourite", "By Author"]
+    );
+    assert_eq!(titles(None, 2).await, ["Loud Wasm", "Wasm Tricks"]);
+
+    // Muted authors and the viewer's own and co-authored articles are left out
+    assert_eq!(
+        titles(Some(reader), 10).await,
+        ["Wasm Tricks", "Rust Basics", "Rust Thrice", "Fan Favourite", "By Author"]
+    );
+    let for_fan = related(Some(fan), 10).await;
+    let favorited: Vec<_> = for_fan.iter().filter(|a| a.favorited).map(|a| a.title.as_str()).collect();
+    assert_eq!(favorited, ["Fan Favourite"]);
+}
+
+/// SEO fields round trip, and `sitemap_entries` pages through articles then
+/// profiles in code point order, each with when it last changed
+///
+/// Expects empty repositories.
+pub async fn sitemap_and_seo<U, A>(users_repo: &U, articles_repo: &A)
+where
+    U: UsersRepository,
+    A: ArticlesRepository,
+{
+    let base = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+    let day = |n: i64| base + chrono::Duration::days(n);
+    let zed = create_user(users_repo, "Zed", base).await;
+    let amy = create_user(users_repo, "amy", day(3)).await;
+
+    let mut zebra = publish(articles_repo, zed, "Zebra", vec![], day(1)).await;
+    publish(articles_repo, amy, "Apple", vec![], day(2)).await;
+
+    let changes = ArticleChanges {
+        canonical_url: Some(Some("https://blog.example.com/zebra".to_owned())),
+        meta_description: Some(Some("Stripes".to_owned())),
+        social_image: Some(Some("/api/uploads/zebra.png".to_owned())),
+        ..Default::default()
+    };
+    zebra.apply_changes(changes, day(5)).unwrap();
+    articles_repo.update_article(zebra).await.unwrap();
+    let stored = articles_repo.get_article_by_slug("zebra").await.unwrap().unwrap();
+    assert_eq!(stored.seo.canonical_url.as_deref(), Some("https://blog.example.com/zebra"));
+    assert_eq!(stored.seo.meta_description.as_deref(), Some("Stripes"));
+    assert_eq!(stored.seo.social_image.as_deref(), Some("/api/uploads/zebra.png"));
+
+    let mut cleared = stored;
+    cleared.apply_changes(ArticleChanges { meta_description: Some(None), ..Default::default() }, day(5)).unwrap();
+    articles_repo.update_article(cleared).await.unwrap();
+    let stored = articles_repo.get_article_by_slug("zebra").await.unwrap().unwrap();
+    assert_eq!(stored.seo.meta_description, None);
+    assert!(stored.seo.canonical_url.is_some());
+
+    assert_eq!(articles_repo.count_sitemap_entries().await.unwrap(), 4);
+    let entry = |location, last_modified| SitemapEntry { location, last_modified };
+    assert_eq!(
+        articles_repo.sitemap_entries(0, 10).await.unwrap(),
+        [
+            entry(SitemapLocation::Article("apple".to_owned()), day(2)),
+            entry(SitemapLocation::Article("zebra".to_owned()), day(5)),
+            // Upper case sorts first; Amy's profile changed after her article
+            entry(SitemapLocation::Profile("Zed".to_owned()), day(5)),
+            entry(SitemapLocation::Profile("amy".to_owned()), day(3)),
+        ]
+    );
+    let page = articles_repo.sitemap_entries(1, 2).await.unwrap();
+    assert_eq!(page.len(), 2);
+    assert_eq!(page[0].location, SitemapLocation::Article("zebra".to_owned()));
+    assert_eq!(page[1].location, SitemapLocation::Profile("Zed".to_owned()));
+}
+
+/// Scheduled articles go live with a fresh `updated_at` and take their place
+/// in lists and feeds by when they went live, not when they were written
+///
+/// Expects empty repositories sharing one store.
+pub async fn scheduled_publishing<U, A, T>(users_repo: &U, articles_repo: &A, tags_repo: &T)
+where
+    U: UsersRepository,
+    A: ArticlesRepository,
+    T: TagsRepository,
+{
+    let base = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+    let day = |n: i64| base + chrono::Duration::days(n);
+    let author = create_user(users_repo, "author", base).await;
+    let reader = create_user(users_repo, "reader", base).await;
+    tags_repo.follow_tag(reader, &Tag::new("rust").unwrap()).await.unwrap();
+
+    let mut planned = publish(articles_repo, author, "Planned", vec!["rust"], day(1)).await;
+    planned.set_publication(Publication::At(day(5)), day(1));
+    articles_repo.update_article(planned.clone()).await.unwrap();
+    publish(articles_repo, author, "Quick", vec!["rust"], day(2)).await;
+    publish(articles_repo, author, "Older", vec!["rust"], day(0)).await;
+
+    let published = articles_repo.publish_due_articles(day(6)).await.unwrap();
+    assert_eq!(published.len(), 1);
+    assert_eq!(published[0].updated_at, day(6));
+    assert_eq!(published[0].published_at, Some(day(5)));
+
+    let titles = |envelope: crate::ArticlesEnvelope| envelope.articles.into_iter().map(|a| a.title).collect::<Vec<_>>();
+    let listed = articles_repo.list_articles(ArticleFilters::default()).await.unwrap();
+    assert_eq!(titles(listed), ["Planned", "Quick", "Older"]);
+    let fed = articles_repo.feed_articles(reader, FeedFilters::default()).await.unwrap();
+    assert_eq!(titles(fed), ["Planned", "Quick", "Older"]);
+
+    // Cursors follow the same order
+    let first = ArticleFilters::new(None, None, None, Some(Pagination::new(Some(1), None).unwrap())).unwrap();
+    let first = articles_repo.list_articles(first).await.unwrap();
+    assert_eq!(first.articles[0].title, "Planned");
+    let rest = ArticleFilters::default().with_cursor(first.next_cursor).unwrap();
+    assert_eq!(titles(articles_repo.list_articles(rest).await.unwrap()), ["Quick", "Older"]);
+}
+
+/// Deleted articles and comments are only found in the trash until restored,
+/// and purging erases those deleted long enough ago
+///
+/// Expects empty repositories sharing one store.
+pub async fn trash<U, A, C>(users_repo: &U, articles_repo: &A, comments_repo: &C)
+where
+    U: UsersRepository,
+    A: ArticlesRepository,
+    C: CommentsRepository,
+{
+    let base = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+    let day = |n: i64| base + chrono::Duration::days(n);
+    let author = create_user(users_repo, "author", base).await;
+    let fan = create_user(users_repo, "fan", base).await;
+    let article = publish(articles_repo, author, "Second Thoughts", vec!["rust"], day(1)).await;
+    articles_repo.favorite_article(fan, article.id, day(1)).await.unwrap();
+    articles_repo
+        .bookmark_article(Bookmark::new(fan, article.id, None, None, day(1)).unwrap())
+        .await
+        .unwrap();
+    let draft = CommentDraft::new("Nice one").unwrap();
+    let comment = Comment::new(CommentId::new(1), article.id, fan, draft, day(1));
+    let comment = comments_repo.create_comment(comment).await.unwrap();
+
+    let visible = || async {
+        let listed = articles_repo.list_articles(ArticleFilters::default()).await.unwrap();
+        let found = articles_repo.get_article_by_slug("second-thoughts").await.unwrap();
+        let by_id = articles_repo.get_article_by_id(article.id).await.unwrap();
+        let slugs = ["second-thoughts".to_owned(), "missing".to_owned()];
+        let by_slugs = articles_repo.get_articles_by_slugs(&slugs).await.unwrap();
+        (listed.articles_count, found.is_some(), by_id.is_some() && by_slugs.len() == 1)
+    };
+    assert_eq!(visible().await, (1, true, true));
+
+    articles_repo.delete_article(article.id, day(2)).await.unwrap();
+    comments_repo.delete_comment(comment.id, day(3)).await.unwrap();
+    assert_eq!(visible().await, (0, false, false));
+    // The trashed article keeps its slug
+    let draft = ArticleDraft::new("Second Thoughts", "Description", "Body", TagList::default()).unwrap();
+    let clash = Article::publish(ArticleId::random(), author, draft, day(2)).unwrap();
+    let refused = articles_repo.create_article(clash).await.unwrap_err();
+    assert!(refused.is::<crate::repositories::SlugTaken>());
+    assert!(comments_repo.get_comment_by_id(comment.id).await.unwrap().is_none());
+    assert!(comments_repo.get_comments_by_author(fan).await.unwrap().is_empty());
+
+    let trashed = articles_repo.list_trashed_articles(author).await.unwrap();
+    assert_eq!(trashed.len(), 1);
+    assert_eq!((trashed[0].item.id, trashed[0].deleted_at), (article.id, day(2)));
+    let by_slug = articles_repo.get_trashed_article_by_slug("second-thoughts").await.unwrap();
+    assert_eq!(by_slug.map(|t| t.item.id), Some(article.id));
+    assert!(articles_repo.list_trashed_articles(fan).await.unwrap().is_empty());
+    let trashed = comments_repo.list_trashed_comments(fan).await.unwrap();
+    assert_eq!(trashed.len(), 1);
+    assert_eq!((trashed[0].item.id, trashed[0].deleted_at), (comment.id, day(3)));
+
+    articles_repo.restore_article(article.id).await.unwrap();
+    comments_repo.restore_comment(comment.id).await.unwrap();
+    assert_eq!(visible().await, (1, true, true));
+    let restored = articles_repo.get_article_by_id(article.id).await.unwrap().unwrap();
+    assert_eq!(restored.favorites_count, 1);
+    assert_eq!(restored.tag_list.as_slice(), [Tag::new("rust").unwrap()]);
+    let comments = comments_repo.get_comments_by_article(article.id).await.unwrap();
+    assert_eq!(comments.into_iter().map(|c| c.id).collect::<Vec<_>>(), [comment.id]);
+    assert!(articles_repo.get_trashed_article_by_slug("second-thoughts").await.unwrap().is_none());
+    assert!(comments_repo.get_trashed_comment(comment.id).await.unwrap().is_none());
+
+    // Only what was deleted at or before the cutoff is erased
+    comments_repo.delete_comment(comment.id, day(4)).await.unwrap();
+    articles_repo.delete_article(article.id, day(5)).await.unwrap();
+    assert!(articles_repo.purge_trashed_articles(day(4)).await.unwrap().is_empty());
+    assert_eq!(comments_repo.purge_trashed_comments(day(4)).await.unwrap(), [comment.id]);
+    assert_eq!(articles_repo.purge_trashed_articles(day(5)).await.unwrap(), [article.id]);
+    assert!(articles_repo.list_trashed_articles(author).await.unwrap().is_empty());
+    assert!(comments_repo.list_trashed_comments(fan).await.unwrap().is_empty());
+    assert!(articles_repo.get_bookmark(fan, article.id).await.unwrap().is_none());
+    assert!(!articles_repo.is_favorited(fan, article.id).await.unwrap());
+}
+
+/// Erasing an author's articles, trashed ones included, takes along what
+/// others left on them; erasing the account takes along the author's
+/// reactions and the user
+///
+/// Expects empty repositories sharing one store.
+pub async fn erasure<U, A, C>(users_repo: &U, articles_repo: &A, comments_repo: &C)
+where
+    U: UsersRepository,
+    A: ArticlesRepository,
+    C: CommentsRepository,
+{
+    let base = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+    let author = create_user(users_repo, "author", base).await;
+    let fan = create_user(users_repo, "fan", base).await;
+    let erased = publish(articles_repo, author, "Erased", vec![], base).await;
+    let trashed = publish(articles_repo, author, "Trashed", vec![], base).await;
+    let kept = publish(articles_repo, fan, "Kept", vec![], base).await;
+    let heart = ReactionKind::new("heart").unwrap();
+
+    let mut comments = Vec::new();
+    for (id, article_id) in [(1, erased.id), (2, trashed.id), (3, erased.id), (4, kept.id)] {
+        let draft = CommentDraft::new("Thanks").unwrap();
+        let comment = Comment::new(CommentId::new(id), article_id, fan, draft, base);
+        let comment = comments_repo.create_comment(comment).await.unwrap();
+        comments_repo.add_comment_reaction(comment.id, author, &heart).await.unwrap();
+        comments.push(comment.id);
+    }
+    comments_repo.delete_comment(comments[2], base).await.unwrap();
+    articles_repo.favorite_article(fan, erased.id, base).await.unwrap();
+    articles_repo.add_article_reaction(erased.id, fan, &heart).await.unwrap();
+    articles_repo
+        .bookmark_article(Bookmark::new(fan, erased.id, None, None, base).unwrap())
+        .await
+        .unwrap();
+    articles_repo.delete_article(trashed.id, base).await.unwrap();
+
+    articles_repo.delete_articles_by_author(author).await.unwrap();
+
+    assert!(articles_repo.list_articles_by_author(author).await.unwrap().is_empty());
+    assert!(articles_repo.list_trashed_articles(author).await.unwrap().is_empty());
+    let left: Vec<CommentId> = comments_repo
+        .get_comments_by_author(fan)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|c| c.id)
+        .collect();
+    assert_eq!(left, [comments[3]]);
+    assert!(comments_repo.get_comment_by_id(comments[0]).await.unwrap().is_none());
+    assert!(comments_repo.list_trashed_comments(fan).await.unwrap().is_empty());
+    let reactions = comments_repo.comment_reactions(&comments, None).await.unwrap();
+    assert_eq!(reactions.keys().collect::<Vec<_>>(), [&comments[3]]);
+    assert!(!articles_repo.is_favorited(fan, erased.id).await.unwrap());
+    assert!(articles_repo.article_reactions(erased.id, None).await.unwrap().is_empty());
+    assert!(articles_repo.get_bookmark(fan, erased.id).await.unwrap().is_none());
+    assert!(articles_repo.get_article_by_id(kept.id).await.unwrap().is_some());
+
+    articles_repo
+        .erase_account(author, AuthoredContentPolicy::Delete, base)
+        .await
+        .unwrap();
+
+    assert!(users_repo.get_user_by_id(author).await.unwrap().is_none());
+    assert!(comments_repo.comment_reactions(&comments, None).await.unwrap().is_empty());
+    assert!(articles_repo.get_article_by_id(kept.id).await.unwrap().is_some());
+}
+
+/// Reaction counts per kind, the viewer's own reactions, and removing all of
+/// a user's reactions
+///
+/// Expects empty repositories sharing one store.
+pub async fn reactions<U, A, C>(users_repo: &U, articles_repo: &A, comments_repo: &C)
+where
+    U: UsersRepository,
+    A: ArticlesRepository,
+    C: CommentsRepository,
+{
+    let base = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+    let author = create_user(users_repo, "author", base).await;
+    let fan = create_user(users_repo, "fan", base).await;
+    let article = publish(articles_repo, author, "Reacted To", vec![], base).await;
+    let mut comments = Vec::new();
+    for (id, body) in [(1, "First"), (2, "Second")] {
+        let draft = CommentDraft::new(body).unwrap();
+        let comment = Comment::new(CommentId::new(id), article.id, author, draft, base);
+        comments.push(comments_repo.create_comment(comment).await.unwrap().id);
+    }
+    let kind = |name: &str| ReactionKind::new(name).unwrap();
+    let count = |name: &str, count: u32, reacted: bool| ReactionCount {
+        kind: kind(name),
+        count,
+        reacted,
+    };
+
+    assert!(articles_repo.article_reactions(article.id, Some(fan)).await.unwrap().is_empty());
+    for (user, name) in [(author, "tada"), (fan, "heart"), (author, "heart"), (fan, "heart"), (fan, "+1")] {
+        articles_repo.add_article_reaction(article.id, user, &kind(name)).await.unwrap();
+    }
+    // Most used first, then by kind; reacting twice with a kind counts once
+    assert_eq!(
+        articles_repo.article_reactions(article.id, Some(fan)).await.unwrap(),
+        [count("heart", 2, true), count("+1", 1, true), count("tada", 1, false)]
+    );
+    let anonymous = articles_repo.article_reactions(article.id, None).await.unwrap();
+    assert!(anonymous.iter().all(|c| !c.reacted));
+
+    articles_repo.remove_article_reaction(article.id, fan, &kind("heart")).await.unwrap();
+    assert_eq!(
+        articles_repo.article_reactions(article.id, Some(fan)).await.unwrap(),
+        [count("+1", 1, true), count("heart", 1, false), count("tada", 1, false)]
+    );
+    // Summaries carry the same counts
+    let filters = ArticleFilters {
+        viewer: Some(fan),
+        ..Default::default()
+    };
+    let listed = articles_repo.list_articles(filters).await.unwrap();
+    assert_eq!(
+        listed.articles[0].reactions,
+        [count("+1", 1, true), count("heart", 1, false), count("tada", 1, false)]
+    );
+
+    comments_repo.add_comment_reaction(comments[0], fan, &kind("thinking")).await.unwrap();
+    comments_repo.add_comment_reaction(comments[0], author, &kind("thinking")).await.unwrap();
+    let by_comment = comments_repo.comment_reactions(&comments, Some(author)).await.unwrap();
+    assert_eq!(by_comment.len(), 1);
+    assert_eq!(by_comment[&comments[0]], [count("thinking", 2, true)]);
+
+    articles_repo.remove_article_reactions_by_user(fan).await.unwrap();
+    comments_repo.remove_comment_reactions_by_user(fan).await.unwrap();
+    assert_eq!(
+        articles_repo.article_reactions(article.id, Some(fan)).await.unwrap(),
+        [count("heart", 1, false), count("tada", 1, false)]
+    );
+    let by_comment = comments_repo.comment_reactions(&comments, None).await.unwrap();
+    assert_eq!(by_comment[&comments[0]], [count("thinking", 1, false)]);
+}
+
+/// Reports list oldest first for moderators and newest first for their
+/// reporter, and moderation hides content and suspends accounts
+///
+/// Expects empty repositories sharing one store.
+pub async fn reports<U, A, C, R>(users_repo: &U, articles_repo: &A, comments_repo: &C, reports_repo: &R)
+where
+    U: UsersRepository,
+    A: ArticlesRepository,
+    C: CommentsRepository,
+    R: ReportsRepository,
+{
+    let base = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+    let day = |n: i64| base + chrono::Duration::days(n);
+    let author = create_user(users_repo, "author", base).await;
+    let reporter = create_user(users_repo, "reporter", base).await;
+    let moderator = create_user(users_repo, "moderator", base).await;
+    let other_moderator = create_user(users_repo, "other_moderator", base).await;
+    let article = publish(articles_repo, author, "Reported", vec![], base).await;
+    let draft = CommentDraft::new("Rude").unwrap();
+    let comment = Comment::new(CommentId::new(1), article.id, author, draft, base);
+    let comment = comments_repo.create_comment(comment).await.unwrap();
+
+    let mut ids = Vec::new();
+    for (n, target) in [
+        ReportTarget::Article(article.id),
+        ReportTarget::Comment(comment.id),
+        ReportTarget::Profile(author),
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        let details = Some("details".to_owned());
+        let report = Report::new(ReportId::random(), reporter, target, author, ReportReason::Spam, details, day(n as i64))
+            .unwrap();
+        let created = reports_repo.create_report(report.clone()).await.unwrap();
+        assert_eq!(created, report);
+        ids.push(report.id);
+    }
+    let ids_of = |reports: Vec<Report>| reports.into_iter().map(|r| r.id).collect::<Vec<_>>();
+
+    let open = reports_repo.find_open_report(reporter, ReportTarget::Comment(comment.id)).await.unwrap();
+    assert_eq!(open.map(|r| r.id), Some(ids[1]));
+    let others = reports_repo.find_open_report(moderator, ReportTarget::Comment(comment.id)).await.unwrap();
+    assert!(others.is_none());
+
+    let all = Pagination::default();
+    assert_eq!(ids_of(reports_repo.list_reports(Some(ReportStatus::Open), all).await.unwrap()), ids);
+    let paged = Pagination::new(Some(1), Some(1)).unwrap();
+    assert_eq!(ids_of(reports_repo.list_reports(None, paged).await.unwrap()), [ids[1]]);
+    let newest_first: Vec<_> = ids.iter().rev().copied().collect();
+    assert_eq!(ids_of(reports_repo.list_reports_by_reporter(reporter).await.unwrap()), newest_first);
+
+    // Claiming and then resolving is recorded action by action
+    let mut report = reports_repo.get_report(ids[0]).await.unwrap().unwrap();
+    let stale = report.clone();
+    for (n, action, note) in [(5, ModerationAction::Claim, None), (6, ModerationAction::Resolve, Some("spam"))] {
+        report.apply(action, moderator, day(n)).unwrap();
+        let record = ModerationRecord {
+            report_id: report.id,
+            moderator_id: Some(moderator),
+            action,
+            note: note.map(str::to_owned),
+            created_at: day(n),
+        };
+        assert!(reports_repo.record_moderation(&report, record).await.unwrap());
+    }
+    let stored = reports_repo.get_report(ids[0]).await.unwrap().unwrap();
+    assert_eq!(stored, report);
+    assert_eq!(stored.status, ReportStatus::Resolved);
+    assert_eq!(stored.closed_at, Some(day(6)));
+    let records = reports_repo.list_moderation_records(ids[0]).await.unwrap();
+    let actions: Vec<_> = records.iter().map(|r| r.action).collect();
+    assert_eq!(actions, [ModerationAction::Claim, ModerationAction::Resolve]);
+    assert_eq!(records[1].note.as_deref(), Some("spam"));
+
+    // Acting on a copy read before someone else claimed or closed the report
+    // saves nothing
+    let mut late = stale;
+    late.apply(ModerationAction::Dismiss, other_moderator, day(7)).unwrap();
+    let record = ModerationRecord {
+        report_id: late.id,
+        moderator_id: Some(other_moderator),
+        action: ModerationAction::Dismiss,
+        note: None,
+        created_at: day(7),
+    };
+    assert!(!reports_repo.record_moderation(&late, record).await.unwrap());
+    assert_eq!(reports_repo.get_report(ids[0]).await.unwrap().unwrap(), report);
+    assert_eq!(reports_repo.list_moderation_records(ids[0]).await.unwrap().len(), 2);
+    assert!(reports_repo.find_open_report(reporter, ReportTarget::Article(article.id)).await.unwrap().is_none());
+    assert_eq!(reports_repo.count_reports(Some(ReportStatus::Open)).await.unwrap(), 2);
+    assert_eq!(reports_repo.count_reports(Some(ReportStatus::Resolved)).await.unwrap(), 1);
+    assert_eq!(reports_repo.count_reports(None).await.unwrap(), 3);
+
+    // Hidden content is still found directly but left out of listings
+    articles_repo.hide_article(article.id, day(6)).await.unwrap();
+    comments_repo.hide_comment(comment.id, day(6)).await.unwrap();
+    let hidden = articles_repo.get_article_by_id(article.id).await.unwrap().unwrap();
+    assert_eq!(hidden.hidden_at, Some(day(6)));
+    let listed = articles_repo.list_articles(ArticleFilters::default()).await.unwrap();
+    assert_eq!(listed.articles_count, 0);
+    let own = ArticleFilters {
+        viewer: Some(author),
+        ..Default::default()
+    };
+    assert_eq!(articles_repo.list_articles(own).await.unwrap().articles_count, 1);
+    let hidden = comments_repo.get_comment_by_id(comment.id).await.unwrap().unwrap();
+    assert_eq!(hidden.hidden_at, Some(day(6)));
+
+    users_repo.suspend_user(author, day(7)).await.unwrap();
+    let suspended = users_repo.get_user_by_id(author).await.unwrap().unwrap();
+    assert_eq!(suspended.suspended_at, Some(day(7)));
+}
+
+async fn create_user<U>(users_repo: &U, name: &str, created_at: DateTime<Utc>) -> UserId
+where
+    U: UsersRepository,
+{
+    let user = User::new(
+        UserId::random(),
+        Email::parse(format!("{name}@example.com")).unwrap(),
+        Username::new(name).unwrap(),
+        PasswordHash::new("hash").unwrap(),
+        created_at,
+    );
+    users_repo.create_user(user).await.unwrap().id
+}
+
+async fn publish<A>(
+    articles_repo: &A,
+    author_id: UserId,
+    title: &str,
+    tags: Vec<&str>,
+    created_at: DateTime<Utc>,
+) -> Article
+where
+    A: ArticlesRepository,
+{
+    let draft = ArticleDraft::new(title, "Description", "Body", TagList::new(tags).unwrap()).unwrap();
+    let article = Article::publish(ArticleId::random(), author_id, draft, created_at).unwrap();
+    articles_repo.create_article(article).await.unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::{
+        InMemoryArticlesRepository, InMemoryBlobStore, InMemoryCommentsRepository, InMemoryReportsRepository,
+        InMemorySeriesRepository, InMemoryTagsRepository, InMemoryUsersRepository,
+    };
+
+    #[tokio::test]
+    async fn in_memory_reports() {
+        let users_repo = InMemoryUsersRepository::new();
+        let articles_repo = InMemoryArticlesRepository::new(users_repo.clone());
+        let comments_repo = InMemoryCommentsRepository::new();
+        let reports_repo = InMemoryReportsRepository::new();
+        reports(&users_repo, &articles_repo, &comments_repo, &reports_repo).await;
+    }
+
+    #[tokio::test]
+    async fn in_memory_article_listing() {
+        let users_repo = InMemoryUsersRepository::new();
+        let articles_repo = InMemoryArticlesRepository::new(users_repo.clone());
+        article_listing(&users_repo, &articles_repo).await;
+    }
+
+    #[tokio::test]
+    async fn in_memory_tag_merging() {
+        let users_repo = InMemoryUsersRepository::new();
+        let articles_repo = InMemoryArticlesRepository::new(users_repo.clone());
+        let tags_repo = InMemoryTagsRepository::new(articles_repo.clone());
+        tag_merging(&users_repo, &articles_repo, &tags_repo).await;
+    }
+
+    #[tokio::test]
+    async fn in_memory_followed_feed() {
+        let users_repo = InMemoryUsersRepository::new();
+        let articles_repo = InMemoryArticlesRepository::new(users_repo.clone());
+        let tags_repo = InMemoryTagsRepository::new(articles_repo.clone());
+        followed_feed(&users_repo, &articles_repo, &tags_repo).await;
+    }
+
+    #[tokio::test]
+    async fn in_memory_bookmarks() {
+        let users_repo = InMemoryUsersRepository::new();
+        let articles_repo = InMemoryArticlesRepository::new(users_repo.clone());
+        bookmarks(&users_repo, &articles_repo).await;
+    }
+
+    #[tokio::test]
+    async fn in_memory_user_images() {
+        user_images(&InMemoryUsersRepository::new()).await;
+    }
+
+    #[tokio::test]
+    async fn in_memory_blob_storage() {
+        blob_storage(&InMemoryBlobStore::new()).await;
+    }
+
+    #[tokio::test]
+    async fn in_memory_related_articles() {
+        let users_repo = InMemoryUsersRepository::new();
+        let articles_repo = InMemoryArticlesRepository::new(users_repo.clone());
+        related_articles(&users_repo, &articles_repo).await;
+    }
+
+    #[tokio::test]
+    async fn in_memory_sitemap_and_seo() {
+        let users_repo = InMemoryUsersRepository::new();
+        let articles_repo = InMemoryArticlesRepository::new(users_repo.clone());
+        sitemap_and_seo(&users_repo, &articles_repo).await;
+    }
+
+    #[tokio::test]
+    async fn in_memory_scheduled_publishing() {
+        let users_repo = InMemoryUsersRepository::new();
+        let articles_repo = InMemoryArticlesRepository::new(users_repo.clone());
+        let tags_repo = InMemoryTagsRepository::new(articles_repo.clone());
+        scheduled_publishing(&users_repo, &articles_repo, &tags_repo).await;
+    }
+
+    #[tokio::test]
+    async fn in_memory_trash() {
+        let users_repo = InMemoryUsersRepository::new();
+        let articles_repo = InMemoryArticlesRepository::new(users_repo.clone());
+        trash(&users_repo, &articles_repo, &InMemoryCommentsRepository::new()).await;
+    }
+
+    #[tokio::test]
+    async fn in_memory_erasure() {
+        let users_repo = InMemoryUsersRepository::new();
+        let comments_repo = InMemoryCommentsRepository::new();
+        let articles_repo = InMemoryArticlesRepository::new(users_repo.clone()).with_comments(comments_repo.clone());
+        erasure(&users_repo, &articles_repo, &comments_repo).await;
+    }
+
+    #[tokio::test]
+    async fn in_memory_reactions() {
+        let users_repo = InMemoryUsersRepository::new();
+        let articles_repo = InMemoryArticlesRepository::new(users_repo.clone());
+        reactions(&users_repo, &articles_repo, &InMemoryCommentsRepository::new()).await;
+    }
+
+    #[tokio::test]
+    async fn in_memory_coauthors() {
+        let users_repo = InMemoryUsersRepository::new();
+        let articles_repo = InMemoryArticlesRepository::new(users_repo.clone());
+        coauthors(&users_repo, &articles_repo).await;
+    }
+
+    #[tokio::test]
+    async fn in_memory_series_parts() {
+        let users_repo = InMemoryUsersRepository::new();
+        let articles_repo = InMemoryArticlesRepository::new(users_repo.clone());
+        let series_repo = InMemorySeriesRepository::new(articles_repo.clone());
+        series_parts(&users_repo, &articles_repo, &series_repo).await;
+    }
+}
@@ -0,0 +1,120 @@
+//! Deleted articles and comments awaiting erasure
+//!
+//! Deleting only moves an article or comment to its author's trash, from
+//! where it can be restored until the retention period runs out; the purge
+//! job erases it for good after that.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::article::{Article, ArticleStatus};
+use crate::comment::Comment;
+use crate::identifiers::CommentId;
+
+/// How long deleted articles and comments stay restorable
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TrashPolicy {
+    pub retention: chrono::Duration,
+}
+
+impl TrashPolicy {
+    pub const DEFAULT_RETENTION_DAYS: i64 = 30;
+
+    pub fn new(retention: chrono::Duration) -> Self {
+        Self { retention }
+    }
+
+    /// Moment after which something deleted at `deleted_at` is erased
+    pub fn purge_after(&self, deleted_at: DateTime<Utc>) -> DateTime<Utc> {
+        deleted_at + self.retention
+    }
+
+    /// Whether something deleted at `deleted_at` may still be restored
+    pub fn is_restorable(&self, deleted_at: DateTime<Utc>, now: DateTime<Utc>) -> bool {
+        now < self.purge_after(deleted_at)
+    }
+}
+
+impl Default for TrashPolicy {
+    fn default() -> Self {
+        Self::new(chrono::Duration::days(Self::DEFAULT_RETENTION_DAYS))
+    }
+}
+
+/// An article or comment in the trash, with when it was deleted
+#[derive(Debug, Clone)]
+pub struct Trashed<T> {
+    pub item: T,
+    pub deleted_at: DateTime<Utc>,
+}
+
+impl Trashed<Article> {
+    pub fn to_view(&self, policy: &TrashPolicy) -> TrashedArticleView {
+        TrashedArticleView {
+            slug: self.item.slug.as_str().to_owned(),
+            title: self.item.title.clone(),
+            description: self.item.description.clone(),
+            status: self.item.status,
+            deleted_at: self.deleted_at,
+            purge_after: policy.purge_after(self.deleted_at),
+        }
+    }
+}
+
+impl Trashed<Comment> {
+    /// `article_slug` is that of the article commented on, unless it is in
+    /// the trash itself
+    pub fn to_view(&self, article_slug: Option<String>, policy: &TrashPolicy) -> TrashedCommentView {
+        TrashedCommentView {
+            id: self.item.id,
+            body: self.item.body.clone(),
+            article_slug,
+            deleted_at: self.deleted_at,
+            purge_after: policy.purge_after(self.deleted_at),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrashedArticleView {
+    pub slug: String,
+    pub title: String,
+    pub description: String,
+    pub status: ArticleStatus,
+    pub deleted_at: DateTime<Utc>,
+    pub purge_after: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrashedCommentView {
+    pub id: CommentId,
+    pub body: String,
+    pub article_slug: Option<String>,
+    pub deleted_at: DateTime<Utc>,
+    pub purge_after: DateTime<Utc>,
+}
+
+/// The current user's trash, most recently deleted first
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrashEnvelope {
+    pub articles: Vec<TrashedArticleView>,
+    pub comments: Vec<TrashedCommentView>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[test]
+    fn items_are_restorable_until_the_retention_period_ends() {
+        let policy = TrashPolicy::new(Duration::days(7));
+        let deleted_at = Utc::now();
+
+        assert_eq!(policy.purge_after(deleted_at), deleted_at + Duration::days(7));
+        assert!(policy.is_restorable(deleted_at, deleted_at + Duration::days(6)));
+        assert!(!policy.is_restorable(deleted_at, deleted_at + Duration::days(7)));
+    }
+}
@@ -0,0 +1,388 @@
+use std::io::Cursor;
+
+use image::codecs::jpeg::JpegEncoder;
+use image::{DynamicImage, ImageDecoder, ImageFormat, ImageReader, Limits};
+use serde::{Deserialize, Serialize};
+
+use crate::errors::{DomainError, DomainResult};
+
+/// Largest accepted upload, in bytes
+pub const DEFAULT_MAX_UPLOAD_BYTES: usize = 5 * 1024 * 1024;
+/// Longest side of a generated thumbnail, in pixels
+pub const DEFAULT_THUMBNAIL_SIZE: u32 = 320;
+/// Widest or tallest image decoded, so a tiny file cannot claim a huge canvas
+const MAX_IMAGE_DIMENSION: u32 = 10_000;
+/// Most pixels an image may have; within the dimension limit a canvas could
+/// still need gigabytes once decoded
+const MAX_IMAGE_PIXELS: u64 = 40_000_000;
+/// Memory the decoder may allocate: the pixel budget at four bytes a pixel
+const MAX_DECODE_BYTES: u64 = MAX_IMAGE_PIXELS * 4;
+const JPEG_QUALITY: u8 = 85;
+const THUMBNAIL_SUFFIX: &str = "-thumb";
+
+/// What an uploaded file turned out to be, judging by its contents
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum UploadKind {
+    Png,
+    Jpeg,
+    Gif,
+    Webp,
+    /// Attachments are stored exactly as uploaded
+    Pdf,
+}
+
+impl UploadKind {
+    pub const ALL: [Self; 5] = [Self::Png, Self::Jpeg, Self::Gif, Self::Webp, Self::Pdf];
+
+    /// Recognises a file by its leading bytes; the name and content type the
+    /// client sent are never trusted
+    pub fn sniff(bytes: &[u8]) -> Option<Self> {
+        if bytes.starts_with(b"%PDF-") {
+            return Some(Self::Pdf);
+        }
+        match image::guess_format(bytes).ok()? {
+            ImageFormat::Png => Some(Self::Png),
+            ImageFormat::Jpeg => Some(Self::Jpeg),
+            ImageFormat::Gif => Some(Self::Gif),
+            ImageFormat::WebP => Some(Self::Webp),
+            _ => None,
+        }
+    }
+
+    /// The kind of the file stored under `key`, from its extension
+    pub fn from_key(key: &str) -> Option<Self> {
+        let (_, extension) = key.rsplit_once('.')?;
+        Self::ALL.into_iter().find(|kind| kind.extension() == extension)
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            Self::Png => "image/png",
+            Self::Jpeg => "image/jpeg",
+            Self::Gif => "image/gif",
+            Self::Webp => "image/webp",
+            Self::Pdf => "application/pdf",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Png => "png",
+            Self::Jpeg => "jpg",
+            Self::Gif => "gif",
+            Self::Webp => "webp",
+            Self::Pdf => "pdf",
+        }
+    }
+
+    pub fn is_image(&self) -> bool {
+        self.image_format().is_some()
+    }
+
+    fn image_format(&self) -> Option<ImageFormat> {
+        match self {
+            Self::Png => Some(ImageFormat::Png),
+            Self::Jpeg => Some(ImageFormat::Jpeg),
+            Self::Gif => Some(ImageFormat::Gif),
+            Self::Webp => Some(ImageFormat::WebP),
+            Self::Pdf => None,
+        }
+    }
+}
+
+/// Bytes kept in a `BlobStore`, with the content type to serve them as
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Blob {
+    pub content_type: String,
+    pub bytes: Vec<u8>,
+}
+
+/// Limits on uploads and where stored files are served from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UploadPolicy {
+    pub max_bytes: usize,
+    /// Longest side of a thumbnail; images already smaller keep their size
+    pub thumbnail_size: u32,
+    /// Base URL that upload keys are appended to, without a trailing `/`
+    pub public_url: String,
+}
+
+impl UploadPolicy {
+    pub fn new(max_bytes: usize, thumbnail_size: u32, public_url: impl Into<String>) -> Self {
+        Self {
+            max_bytes,
+            thumbnail_size: thumbnail_size.max(1),
+            public_url: public_url.into().trim_end_matches('/').to_owned(),
+        }
+    }
+
+    /// Where the file stored under `key` can be fetched from
+    pub fn url_for(&self, key: &str) -> String {
+        format!("{}/{key}", self.public_url)
+    }
+
+    /// Checks an upload and prepares it for storage
+    ///
+    /// Images are decoded, turned upright according to their EXIF orientation
+    /// and encoded again, which drops EXIF and any other metadata they carried.
+    /// GIFs hold no EXIF and are kept as uploaded so animations survive; their
+    /// thumbnail is a PNG of the first frame. PDFs are kept as uploaded.
+    ///
+    /// Decoding and encoding are CPU-bound; async callers should run this off
+    /// their executor's threads.
+    pub fn process(&self, bytes: &[u8]) -> DomainResult<ProcessedUpload> {
+        if bytes.len() > self.max_bytes {
+            return Err(DomainError::UploadTooLarge { max: self.max_bytes });
+        }
+        let kind = UploadKind::sniff(bytes).ok_or(DomainError::UnsupportedUpload)?;
+        let Some(format) = kind.image_format() else {
+            return Ok(ProcessedUpload {
+                kind,
+                bytes: bytes.to_vec(),
+                dimensions: None,
+                thumbnail: None,
+            });
+        };
+
+        let image = decode(bytes, format)?;
+        let stored = match kind {
+            UploadKind::Gif => bytes.to_vec(),
+            _ => encode(&image, kind)?,
+        };
+        let thumbnail_kind = match kind {
+            UploadKind::Gif => UploadKind::Png,
+            other => other,
+        };
+        let thumbnail = image.thumbnail(self.thumbnail_size, self.thumbnail_size);
+        Ok(ProcessedUpload {
+            kind,
+            bytes: stored,
+            dimensions: Some((image.width(), image.height())),
+            thumbnail: Some(Thumbnail {
+                kind: thumbnail_kind,
+                bytes: encode(&thumbnail, thumbnail_kind)?,
+            }),
+        })
+    }
+}
+
+impl Default for UploadPolicy {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_UPLOAD_BYTES, DEFAULT_THUMBNAIL_SIZE, "/api/uploads")
+    }
+}
+
+fn decode(bytes: &[u8], format: ImageFormat) -> DomainResult<DynamicImage> {
+    let mut reader = ImageReader::with_format(Cursor::new(bytes), format);
+    let mut limits = Limits::default();
+    limits.max_image_width = Some(MAX_IMAGE_DIMENSION);
+    limits.max_image_height = Some(MAX_IMAGE_DIMENSION);
+    limits.max_alloc = Some(MAX_DECODE_BYTES);
+    reader.limits(limits);
+
+    let mut decoder = reader.into_decoder().map_err(|_| DomainError::InvalidImage)?;
+    let (width, height) = decoder.dimensions();
+    if u64::from(width) * u64::from(height) > MAX_IMAGE_PIXELS {
+        return Err(DomainError::InvalidImage);
+    }
+    let orientation = decoder.orientation().map_err(|_| DomainError::InvalidImage)?;
+    let mut image = DynamicImage::from_decoder(decoder).map_err(|_| DomainError::InvalidImage)?;
+    image.apply_orientation(orientation);
+    Ok(image)
+}
+
+fn encode(image: &DynamicImage, kind: UploadKind) -> DomainResult<Vec<u8>> {
+    let mut bytes = Cursor::new(Vec::new());
+    let written = match kind {
+        // Neither encoder takes every pixel layout a decoder can produce
+        UploadKind::Jpeg => DynamicImage::ImageRgb8(image.to_rgb8())
+            .write_with_encoder(JpegEncoder::new_with_quality(&mut bytes, JPEG_QUALITY)),
+        UploadKind::Webp => DynamicImage::ImageRgba8(image.to_rgba8()).write_to(&mut bytes, ImageFormat::WebP),
+        UploadKind::Png | UploadKind::Gif => DynamicImage::ImageRgba8(image.to_rgba8())
+            .write_to(&mut bytes, kind.image_format().unwrap_or(ImageFormat::Png)),
+        UploadKind::Pdf => return Err(DomainError::UnsupportedUpload),
+    };
+    written.map_err(|_| DomainError::InvalidImage)?;
+    Ok(bytes.into_inner())
+}
+
+/// An upload that passed the policy, ready to be stored
+#[derive(Debug, Clone)]
+pub struct ProcessedUpload {
+    pub kind: UploadKind,
+    pub bytes: Vec<u8>,
+    /// Width and height of an image, upright
+    pub dimensions: Option<(u32, u32)>,
+    pub thumbnail: Option<Thumbnail>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Thumbnail {
+    pub kind: UploadKind,
+    pub bytes: Vec<u8>,
+}
+
+/// Key for a new upload: a random name and the extension of its kind
+pub fn upload_key(id: uuid::Uuid, kind: UploadKind) -> String {
+    format!("{}.{}", id.simple(), kind.extension())
+}
+
+/// Key of the thumbnail belonging to the upload with the same id
+pub fn thumbnail_key(id: uuid::Uuid, kind: UploadKind) -> String {
+    format!("{}{THUMBNAIL_SUFFIX}.{}", id.simple(), kind.extension())
+}
+
+/// Whether `key` has the shape of a key this module hands out
+///
+/// Keys come back in request paths, so anything else is turned away before
+/// it reaches a store.
+pub fn is_upload_key(key: &str) -> bool {
+    let Some((name, _)) = key.rsplit_once('.') else {
+        return false;
+    };
+    let id = name.strip_suffix(THUMBNAIL_SUFFIX).unwrap_or(name);
+    UploadKind::from_key(key).is_some()
+        && id.len() == 32
+        && id.bytes().all(|b| b.is_ascii_digit() || (b'a'..=b'f').contains(&b))
+}
+
+/// A stored upload, with a thumbnail for images
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Upload {
+    pub key: String,
+    pub kind: UploadKind,
+    pub size: usize,
+    pub dimensions: Option<(u32, u32)>,
+    pub thumbnail_key: Option<String>,
+}
+
+impl Upload {
+    pub fn to_view(&self, policy: &UploadPolicy) -> UploadView {
+        UploadView {
+            url: policy.url_for(&self.key),
+            thumbnail_url: self.thumbnail_key.as_deref().map(|key| policy.url_for(key)),
+            content_type: self.kind.content_type().to_owned(),
+            size: self.size,
+            width: self.dimensions.map(|(width, _)| width),
+            height: self.dimensions.map(|(_, height)| height),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UploadView {
+    /// Stable link, fit for article bodies and profile images
+    pub url: String,
+    #[serde(rename = "thumbnailUrl")]
+    pub thumbnail_url: Option<String>,
+    #[serde(rename = "contentType")]
+    pub content_type: String,
+    pub size: usize,
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UploadEnvelope {
+    pub upload: UploadView,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{Rgb, RgbImage};
+
+    fn png(width: u32, height: u32) -> Vec<u8> {
+        let image = RgbImage::from_pixel(width, height, Rgb([200, 40, 40]));
+        let mut bytes = Cursor::new(Vec::new());
+        image.write_to(&mut bytes, ImageFormat::Png).unwrap();
+        bytes.into_inner()
+    }
+
+    /// A JPEG with an APP1 EXIF segment saying it is rotated 90° clockwise
+    fn rotated_jpeg(width: u32, height: u32) -> Vec<u8> {
+        let image = RgbImage::from_pixel(width, height, Rgb([40, 200, 40]));
+        let mut plain = Vec::new();
+        image
+            .write_with_encoder(JpegEncoder::new_with_quality(&mut plain, 90))
+            .unwrap();
+        let tiff: &[u8] = &[
+            b'M', b'M', 0, 42, 0, 0, 0, 8, // big-endian header, IFD at 8
+            0, 1, // one entry
+            0x01, 0x12, 0, 3, 0, 0, 0, 1, 0, 6, 0, 0, // Orientation = 6
+            0, 0, 0, 0, // no next IFD
+        ];
+        let mut app1 = b"Exif\0\0".to_vec();
+        app1.extend_from_slice(tiff);
+        let mut jpeg = plain[..2].to_vec();
+        jpeg.extend_from_slice(&[0xFF, 0xE1]);
+        jpeg.extend_from_slice(&((app1.len() + 2) as u16).to_be_bytes());
+        jpeg.extend_from_slice(&app1);
+        jpeg.extend_from_slice(&plain[2..]);
+        jpeg
+    }
+
+    #[test]
+    fn sniffing_ignores_names_and_rejects_unknown_files() {
+        assert_eq!(UploadKind::sniff(&png(2, 2)), Some(UploadKind::Png));
+        assert_eq!(UploadKind::sniff(b"%PDF-1.7\n"), Some(UploadKind::Pdf));
+        assert_eq!(UploadKind::sniff(b"<svg xmlns=\"http://www.w3.org/2000/svg\"/>"), None);
+        assert_eq!(
+            UploadPolicy::default().process(b"#!/bin/sh\n").unwrap_err(),
+            DomainError::UnsupportedUpload
+        );
+    }
+
+    #[test]
+    fn uploads_over_the_limit_are_rejected() {
+        let policy = UploadPolicy::new(16, DEFAULT_THUMBNAIL_SIZE, "/files");
+        assert_eq!(
+            policy.process(&png(8, 8)).unwrap_err(),
+            DomainError::UploadTooLarge { max: 16 }
+        );
+    }
+
+    #[test]
+    fn images_get_a_thumbnail_within_the_size() {
+        let policy = UploadPolicy::new(DEFAULT_MAX_UPLOAD_BYTES, 40, "/files/");
+        let processed = policy.process(&png(200, 100)).unwrap();
+        assert_eq!(processed.kind, UploadKind::Png);
+        assert_eq!(processed.dimensions, Some((200, 100)));
+
+        let thumbnail = processed.thumbnail.unwrap();
+        let decoded = image::load_from_memory(&thumbnail.bytes).unwrap();
+        assert_eq!((decoded.width(), decoded.height()), (40, 20));
+        assert_eq!(policy.url_for("a.png"), "/files/a.png");
+    }
+
+    #[test]
+    fn exif_is_stripped_after_orienting_the_image() {
+        let original = rotated_jpeg(40, 20);
+        assert!(original.windows(6).any(|w| w == b"Exif\0\0"));
+
+        let processed = UploadPolicy::default().process(&original).unwrap();
+        assert!(!processed.bytes.windows(6).any(|w| w == b"Exif\0\0"));
+        assert_eq!(processed.dimensions, Some((20, 40)));
+    }
+
+    #[test]
+    fn undecodable_images_are_rejected() {
+        let mut truncated = png(4, 4);
+        truncated.truncate(24);
+        assert_eq!(
+            UploadPolicy::default().process(&truncated).unwrap_err(),
+            DomainError::InvalidImage
+        );
+    }
+
+    #[test]
+    fn only_generated_keys_are_accepted() {
+        let id = uuid::Uuid::new_v4();
+        assert!(is_upload_key(&upload_key(id, UploadKind::Jpeg)));
+        assert!(is_upload_key(&thumbnail_key(id, UploadKind::Png)));
+        assert!(!is_upload_key("../../etc/passwd"));
+        assert!(!is_upload_key(&format!("{}.exe", id.simple())));
+        assert_eq!(UploadKind::from_key("x.webp"), Some(UploadKind::Webp));
+    }
+}
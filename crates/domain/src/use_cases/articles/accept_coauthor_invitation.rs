@@ -0,0 +1,103 @@
+//! Accept co-author invitation use case
+
+use chrono::{DateTime, Utc};
+
+use crate::{
+    ArticleView, DomainError, DomainResult, UserId,
+    repositories::{ArticlesRepository, UsersRepository},
+    use_cases::articles::get_article::build_article_view,
+};
+
+/// Accept an invitation to co-author an article
+///
+/// # Business Rules
+/// - Article must exist and the user must have been invited to it; drafts
+///   can be co-authored too
+/// - From then on the user can edit, publish and restore the article, and
+///   is listed among its `authors`
+/// - Accepting again is idempotent
+pub async fn accept_coauthor_invitation<U, A>(
+    users_repo: &U,
+    articles_repo: &A,
+    slug: &str,
+    user_id: UserId,
+    now: DateTime<Utc>,
+) -> DomainResult<ArticleView>
+where
+    U: UsersRepository,
+    A: ArticlesRepository,
+{
+    let db_error = |e: anyhow::Error| DomainError::Database {
+        message: e.to_string(),
+    };
+
+    let article = articles_repo
+        .get_article_by_slug(slug)
+        .await
+        .map_err(db_error)?
+        .ok_or(DomainError::NotFound { entity: "article" })?;
+    // Without an invitation, even the existence of a draft stays hidden
+    articles_repo
+        .get_coauthor(article.id, user_id)
+        .await
+        .map_err(db_error)?
+        .ok_or(DomainError::NotFound { entity: "article" })?;
+
+    articles_repo
+        .accept_coauthor(article.id, user_id, now)
+        .await
+        .map_err(db_error)?;
+
+    let updated = articles_repo
+        .get_article_by_id(article.id)
+        .await
+        .map_err(db_error)?
+        .ok_or(DomainError::NotFound { entity: "article" })?;
+    build_article_view(users_repo, articles_repo, &updated, Some(user_id)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::{InMemoryArticlesRepository, InMemoryUsersRepository};
+    use crate::use_cases::articles::invite_coauthor;
+    use crate::{Article, ArticleDraft, ArticleId, Email, PasswordHash, Publication, TagList, User, Username};
+
+    #[tokio::test]
+    async fn test_accept_coauthor_invitation() {
+        let users_repo = InMemoryUsersRepository::new();
+        let articles_repo = InMemoryArticlesRepository::new(users_repo.clone());
+        let mut users = Vec::new();
+        for name in ["owner", "invitee", "stranger"] {
+            let user = User::new(
+                UserId::random(),
+                Email::parse(format!("{name}@example.com")).unwrap(),
+                Username::new(name).unwrap(),
+                PasswordHash::new("hash").unwrap(),
+                Utc::now(),
+            );
+            users.push(users_repo.create_user(user).await.unwrap());
+        }
+        let draft = ArticleDraft::new("Joint Work", "desc", "body", TagList::default()).unwrap();
+        let mut article = Article::publish(ArticleId::random(), users[0].id, draft, Utc::now()).unwrap();
+        article.set_publication(Publication::Draft, Utc::now());
+        articles_repo.create_article(article).await.unwrap();
+
+        let result = accept_coauthor_invitation(&users_repo, &articles_repo, "joint-work", users[2].id, Utc::now()).await;
+        assert!(matches!(result, Err(DomainError::NotFound { entity: "article" })));
+
+        invite_coauthor(&users_repo, &articles_repo, "joint-work", users[0].id, "invitee", Utc::now())
+            .await
+            .unwrap();
+        let view = accept_coauthor_invitation(&users_repo, &articles_repo, "joint-work", users[1].id, Utc::now())
+            .await
+            .unwrap();
+
+        let names: Vec<_> = view.authors.iter().map(|a| a.username.as_str()).collect();
+        assert_eq!(names, ["owner", "invitee"]);
+        assert_eq!(view.author.username.as_str(), "owner");
+        let article = articles_repo.get_article_by_slug("joint-work").await.unwrap().unwrap();
+        assert!(article.can_edit(&users[1].id));
+        assert!(article.is_visible_to(Some(users[1].id)));
+    }
+}
@@ -0,0 +1,144 @@
+//! Add article reaction use case
+
+use crate::{
+    ArticleView, DomainError, DomainResult, ReactionPolicy, UserId,
+    repositories::{ArticlesRepository, UsersRepository},
+    use_cases::articles::get_article::build_article_view,
+};
+
+/// React to an article with one of the offered kinds
+///
+/// # Business Rules
+/// - Only kinds the policy offers can be used
+/// - Article must exist and be published, unless the user is its author
+/// - Users blocked by the article's author cannot react to it
+/// - A user reacts with each kind at most once; reacting again is idempotent
+pub async fn add_article_reaction<U, A>(
+    users_repo: &U,
+    articles_repo: &A,
+    slug: &str,
+    kind: &str,
+    user_id: UserId,
+    policy: &ReactionPolicy,
+) -> DomainResult<ArticleView>
+where
+    U: UsersRepository,
+    A: ArticlesRepository,
+{
+    let kind = policy.kind(kind)?;
+
+    let article = articles_repo
+        .get_article_by_slug(slug)
+        .await
+        .map_err(|_| DomainError::NotFound { entity: "article" })?
+        .ok_or(DomainError::NotFound { entity: "article" })?;
+    if !article.is_visible_to(Some(user_id)) {
+        return Err(DomainError::NotFound { entity: "article" });
+    }
+
+    let blocked = users_repo
+        .is_blocking(article.author_id, user_id)
+        .await
+        .map_err(|e| DomainError::Database {
+            message: e.to_string(),
+        })?;
+    if blocked {
+        return Err(DomainError::Blocked);
+    }
+
+    articles_repo
+        .add_article_reaction(article.id, user_id, &kind)
+        .await
+        .map_err(|e| DomainError::Database {
+            message: e.to_string(),
+        })?;
+
+    build_article_view(users_repo, articles_repo, &article, Some(user_id)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::{InMemoryArticlesRepository, InMemoryUsersRepository};
+    use crate::{Article, ArticleDraft, ArticleId, Email, PasswordHash, TagList, User, Username};
+    use chrono::Utc;
+
+    async fn setup() -> (InMemoryUsersRepository, InMemoryArticlesRepository, User, User) {
+        let users_repo = InMemoryUsersRepository::new();
+        let articles_repo = InMemoryArticlesRepository::new(users_repo.clone());
+        let mut users = Vec::new();
+        for name in ["author", "reader"] {
+            let user = User::new(
+                UserId::random(),
+                Email::parse(format!("{name}@example.com")).unwrap(),
+                Username::new(name).unwrap(),
+                PasswordHash::new("hash").unwrap(),
+                Utc::now(),
+            );
+            users_repo.create_user(user.clone()).await.unwrap();
+            users.push(user);
+        }
+        let draft = ArticleDraft::new("Test Article", "Description", "Body", TagList::default()).unwrap();
+        let article = Article::publish(ArticleId::random(), users[0].id, draft, Utc::now()).unwrap();
+        articles_repo.create_article(article).await.unwrap();
+        let reader = users.pop().unwrap();
+        let author = users.pop().unwrap();
+        (users_repo, articles_repo, author, reader)
+    }
+
+    #[tokio::test]
+    async fn test_add_article_reaction_counts_each_user_once() {
+        let (users_repo, articles_repo, author, reader) = setup().await;
+        let policy = ReactionPolicy::default();
+
+        add_article_reaction(&users_repo, &articles_repo, "test-article", "heart", author.id, &policy)
+            .await
+            .unwrap();
+        add_article_reaction(&users_repo, &articles_repo, "test-article", "heart", reader.id, &policy)
+            .await
+            .unwrap();
+        let view = add_article_reaction(&users_repo, &articles_repo, "test-article", "heart", reader.id, &policy)
+            .await
+            .unwrap();
+
+        assert_eq!(view.reactions.len(), 1);
+        assert_eq!(view.reactions[0].kind.as_str(), "heart");
+        assert_eq!(view.reactions[0].count, 2);
+        assert!(view.reactions[0].reacted);
+    }
+
+    #[tokio::test]
+    async fn test_add_article_reaction_unknown_kind() {
+        let (users_repo, articles_repo, _, reader) = setup().await;
+
+        let result = add_article_reaction(
+            &users_repo,
+            &articles_repo,
+            "test-article",
+            "rocket",
+            reader.id,
+            &ReactionPolicy::default(),
+        )
+        .await;
+
+        assert!(matches!(result, Err(DomainError::UnknownReaction)));
+    }
+
+    #[tokio::test]
+    async fn test_add_article_reaction_blocked() {
+        let (users_repo, articles_repo, author, reader) = setup().await;
+        users_repo.block_user(author.id, reader.id).await.unwrap();
+
+        let result = add_article_reaction(
+            &users_repo,
+            &articles_repo,
+            "test-article",
+            "heart",
+            reader.id,
+            &ReactionPolicy::default(),
+        )
+        .await;
+
+        assert!(matches!(result, Err(DomainError::Blocked)));
+    }
+}
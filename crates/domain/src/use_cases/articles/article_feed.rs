@@ -0,0 +1,196 @@
+//! Article feed use case
+
+use std::collections::HashMap;
+
+use crate::{
+    ArticleFeed, DomainError, DomainResult, FeedEntry, FeedScope, Tag,
+    repositories::{ArticlesRepository, TagsRepository, UsersRepository},
+};
+
+use super::{ListArticlesInput, list_articles};
+
+/// Input for building a syndication feed
+#[derive(Debug, Clone)]
+pub struct ArticleFeedInput {
+    pub scope: FeedScope,
+    /// Carry each article's body, not just its description
+    pub with_content: bool,
+    pub limit: Option<u32>,
+}
+
+/// The newest articles of the global list, a tag or an author, for a feed
+///
+/// # Business Rules
+/// - Built from `list_articles` as an anonymous reader sees it: published
+///   articles by active authors, newest first
+/// - Tags are normalised and aliases resolved as they are on articles, and
+///   the feed is named after the tag they stand for
+/// - The author must exist and must not be deactivated
+/// - Bodies are fetched, all at once, only when content is asked for; the
+///   caller renders them
+/// - The limit follows the article list bounds
+pub async fn article_feed<U, A, T>(
+    users_repo: &U,
+    articles_repo: &A,
+    tags_repo: &T,
+    input: ArticleFeedInput,
+) -> DomainResult<ArticleFeed>
+where
+    U: UsersRepository,
+    A: ArticlesRepository,
+    T: TagsRepository + ?Sized,
+{
+    let (scope, tags, authors) = match input.scope {
+        FeedScope::All => (FeedScope::All, vec![], vec![]),
+        FeedScope::Tag(tag) => {
+            let tag = tags_repo
+                .canonical_tag(&Tag::new(tag)?)
+                .await
+                .map_err(|e| DomainError::Database {
+                    message: e.to_string(),
+                })?;
+            let tag = String::from(tag);
+            (FeedScope::Tag(tag.clone()), vec![tag], vec![])
+        }
+        FeedScope::Author(username) => {
+            let author = users_repo
+                .get_user_by_username(&username)
+                .await
+                .map_err(|_| DomainError::NotFound { entity: "profile" })?
+                .filter(|user| !user.is_deactivated())
+                .ok_or(DomainError::NotFound { entity: "profile" })?;
+            let username = author.username.as_str().to_owned();
+            (FeedScope::Author(username.clone()), vec![], vec![username])
+        }
+    };
+
+    let listing = list_articles(
+        articles_repo,
+        tags_repo,
+        ListArticlesInput {
+            tags,
+            authors,
+            limit: input.limit,
+            ..Default::default()
+        },
+    )
+    .await?;
+
+    let mut bodies = HashMap::new();
+    if input.with_content {
+        let slugs: Vec<String> = listing.articles.iter().map(|a| a.slug.as_str().to_owned()).collect();
+        let full = articles_repo
+            .get_articles_by_slugs(&slugs)
+            .await
+            .map_err(|e| DomainError::Database {
+                message: e.to_string(),
+            })?;
+        bodies.extend(full.into_iter().map(|a| (a.slug.as_str().to_owned(), (a.id, a.body))));
+    }
+
+    let entries = listing
+        .articles
+        .into_iter()
+        .map(|article| FeedEntry {
+            body: bodies.remove(article.slug.as_str()),
+            article,
+            content_html: None,
+        })
+        .collect();
+
+    Ok(ArticleFeed { scope, entries })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::{InMemoryArticlesRepository, InMemoryTagsRepository, InMemoryUsersRepository};
+    use crate::{Article, ArticleDraft, ArticleId, Email, PasswordHash, Publication, TagList, User, UserId, Username};
+    use chrono::Utc;
+
+    async fn setup() -> (InMemoryUsersRepository, InMemoryArticlesRepository, InMemoryTagsRepository, User) {
+        let users_repo = InMemoryUsersRepository::new();
+        let articles_repo = InMemoryArticlesRepository::new(users_repo.clone());
+        let author = User::new(
+            UserId::random(),
+            Email::parse("author@example.com").unwrap(),
+            Username::new("author").unwrap(),
+            PasswordHash::new("hash").unwrap(),
+            Utc::now(),
+        );
+        users_repo.create_user(author.clone()).await.unwrap();
+
+        for (title, tag) in [("Rusty", "rust"), ("Webby", "web"), ("Hidden", "rust")] {
+            let draft = ArticleDraft::new(title, "Description", "# Heading", TagList::new([tag]).unwrap()).unwrap();
+            let mut article = Article::publish(ArticleId::random(), author.id, draft, Utc::now()).unwrap();
+            if title == "Hidden" {
+                article.set_publication(Publication::Draft, Utc::now());
+            }
+            articles_repo.create_article(article).await.unwrap();
+        }
+        let tags_repo = InMemoryTagsRepository::new(articles_repo.clone());
+        (users_repo, articles_repo, tags_repo, author)
+    }
+
+    fn input(scope: FeedScope, with_content: bool) -> ArticleFeedInput {
+        ArticleFeedInput {
+            scope,
+            with_content,
+            limit: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_article_feed_by_tag_carries_bodies_on_request() {
+        let (users_repo, articles_repo, tags_repo, _) = setup().await;
+
+        let feed = article_feed(&users_repo, &articles_repo, &tags_repo, input(FeedScope::Tag(" Rust ".into()), true))
+            .await
+            .unwrap();
+
+        assert_eq!(feed.scope, FeedScope::Tag("rust".into()));
+        assert_eq!(feed.entries.len(), 1);
+        assert_eq!(feed.entries[0].article.title, "Rusty");
+        assert_eq!(feed.entries[0].body.as_ref().unwrap().1, "# Heading");
+
+        let feed = article_feed(&users_repo, &articles_repo, &tags_repo, input(FeedScope::All, false))
+            .await
+            .unwrap();
+        assert_eq!(feed.entries.len(), 2);
+        assert!(feed.entries.iter().all(|entry| entry.body.is_none()));
+    }
+
+    #[tokio::test]
+    async fn test_article_feed_by_alias_is_named_after_its_tag() {
+        let (users_repo, articles_repo, tags_repo, _) = setup().await;
+        tags_repo
+            .merge_tags(&Tag::new("rs").unwrap(), &Tag::new("rust").unwrap())
+            .await
+            .unwrap();
+
+        let feed = article_feed(&users_repo, &articles_repo, &tags_repo, input(FeedScope::Tag("RS".into()), false))
+            .await
+            .unwrap();
+
+        assert_eq!(feed.scope, FeedScope::Tag("rust".into()));
+        assert_eq!(feed.entries.len(), 1);
+        assert_eq!(feed.entries[0].article.title, "Rusty");
+    }
+
+    #[tokio::test]
+    async fn test_article_feed_of_missing_or_deactivated_author() {
+        let (users_repo, articles_repo, tags_repo, author) = setup().await;
+
+        let feed = article_feed(&users_repo, &articles_repo, &tags_repo, input(FeedScope::Author("author".into()), false))
+            .await
+            .unwrap();
+        assert_eq!(feed.entries.len(), 2);
+
+        let result = article_feed(&users_repo, &articles_repo, &tags_repo, input(FeedScope::Author("nobody".into()), false)).await;
+        assert!(matches!(result, Err(DomainError::NotFound { entity: "profile" })));
+
+        users_repo.deactivate_user(author.id, Utc::now()).await.unwrap();
+        let result = article_feed(&users_repo, &articles_repo, &tags_repo, input(FeedScope::Author("author".into()), false)).await;
+        assert!(matches!(result, Err(DomainError::NotFound { entity: "profile" })));
+    }
+}
@@ -0,0 +1,146 @@
+//! Bookmark article use case
+
+use chrono::Utc;
+
+use crate::{
+    ArticleView, Bookmark, DomainError, DomainResult, UserId,
+    repositories::{ArticlesRepository, UsersRepository},
+    use_cases::articles::get_article::build_article_view,
+};
+
+/// Input for bookmarking an article
+#[derive(Debug, Clone, Default)]
+pub struct BookmarkArticleInput {
+    pub note: Option<String>,
+    pub folder: Option<String>,
+}
+
+/// Save an article to the user's private reading list
+///
+/// # Business Rules
+/// - Article must exist and be visible to the user
+/// - Bookmarks are private: they change neither the favorites count nor
+///   anything shown on profiles
+/// - Bookmarking an already-bookmarked article replaces its note and folder
+///   but keeps it where it was in the list
+pub async fn bookmark_article<U, A>(
+    users_repo: &U,
+    articles_repo: &A,
+    slug: &str,
+    user_id: UserId,
+    input: BookmarkArticleInput,
+) -> DomainResult<ArticleView>
+where
+    U: UsersRepository,
+    A: ArticlesRepository,
+{
+    let article = articles_repo
+        .get_article_by_slug(slug)
+        .await
+        .map_err(|_| DomainError::NotFound { entity: "article" })?
+        .ok_or(DomainError::NotFound { entity: "article" })?;
+    if !article.is_visible_to(Some(user_id)) {
+        return Err(DomainError::NotFound { entity: "article" });
+    }
+
+    let bookmark = Bookmark::new(user_id, article.id, input.note, input.folder, Utc::now())?;
+    articles_repo
+        .bookmark_article(bookmark)
+        .await
+        .map_err(|e| DomainError::Database {
+            message: e.to_string(),
+        })?;
+
+    build_article_view(users_repo, articles_repo, &article, Some(user_id)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::{InMemoryArticlesRepository, InMemoryUsersRepository};
+    use crate::{Article, ArticleDraft, ArticleId, Email, PasswordHash, Publication, TagList, User, Username};
+
+    async fn setup() -> (InMemoryUsersRepository, InMemoryArticlesRepository, User, Article) {
+        let users_repo = InMemoryUsersRepository::new();
+        let articles_repo = InMemoryArticlesRepository::new(users_repo.clone());
+
+        let author = User::new(
+            UserId::random(),
+            Email::parse("author@example.com").unwrap(),
+            Username::new("author").unwrap(),
+            PasswordHash::new("hash").unwrap(),
+            Utc::now(),
+        );
+        users_repo.create_user(author.clone()).await.unwrap();
+
+        let draft = ArticleDraft::new("Test Article", "Description", "Body", TagList::default()).unwrap();
+        let article = Article::publish(ArticleId::random(), author.id, draft, Utc::now()).unwrap();
+        let article = articles_repo.create_article(article).await.unwrap();
+
+        (users_repo, articles_repo, author, article)
+    }
+
+    #[tokio::test]
+    async fn test_bookmark_article_is_private() {
+        let (users_repo, articles_repo, _author, article) = setup().await;
+        let user_id = UserId::random();
+        let input = BookmarkArticleInput {
+            note: Some("read on the train".to_owned()),
+            folder: None,
+        };
+
+        let view = bookmark_article(&users_repo, &articles_repo, article.slug.as_str(), user_id, input)
+            .await
+            .unwrap();
+
+        assert!(view.bookmarked);
+        assert!(!view.favorited);
+        assert_eq!(view.favorites_count, 0);
+        let bookmark = articles_repo.get_bookmark(user_id, article.id).await.unwrap().unwrap();
+        assert_eq!(bookmark.note.as_deref(), Some("read on the train"));
+
+        let other = crate::use_cases::get_article(&users_repo, &articles_repo, article.slug.as_str(), None)
+            .await
+            .unwrap();
+        assert!(!other.bookmarked);
+    }
+
+    #[tokio::test]
+    async fn test_bookmark_article_again_keeps_position() {
+        let (users_repo, articles_repo, _author, article) = setup().await;
+        let user_id = UserId::random();
+        let slug = article.slug.as_str();
+
+        bookmark_article(&users_repo, &articles_repo, slug, user_id, BookmarkArticleInput::default())
+            .await
+            .unwrap();
+        let first = articles_repo.get_bookmark(user_id, article.id).await.unwrap().unwrap();
+        let input = BookmarkArticleInput {
+            note: None,
+            folder: Some("rust".to_owned()),
+        };
+        bookmark_article(&users_repo, &articles_repo, slug, user_id, input).await.unwrap();
+
+        let second = articles_repo.get_bookmark(user_id, article.id).await.unwrap().unwrap();
+        assert_eq!(second.folder.as_deref(), Some("rust"));
+        assert_eq!(second.created_at, first.created_at);
+    }
+
+    #[tokio::test]
+    async fn test_bookmark_article_hidden_draft() {
+        let (users_repo, articles_repo, _author, mut article) = setup().await;
+        article.set_publication(Publication::Draft, Utc::now());
+        articles_repo.update_article(article.clone()).await.unwrap();
+
+        let result = bookmark_article(
+            &users_repo,
+            &articles_repo,
+            article.slug.as_str(),
+            UserId::random(),
+            BookmarkArticleInput::default(),
+        )
+        .await;
+
+        assert!(matches!(result, Err(DomainError::NotFound { entity: "article" })));
+    }
+}
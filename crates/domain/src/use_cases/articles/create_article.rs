@@ -3,8 +3,10 @@
 use chrono::{DateTime, Utc};
 
 use crate::{
-    Article, ArticleDraft, ArticleId, ArticleView, DomainResult, Profile, TagList, UserId,
+    Article, ArticleDraft, ArticleId, ArticleView, DomainResult, Profile, Publication, SlugPolicy,
+    TagList, UserId,
     repositories::ArticlesRepository,
+    use_cases::articles::slugs::{SlugWrite, save_with_free_slug},
 };
 
 /// Input for creating a new article
@@ -14,20 +16,25 @@ pub struct CreateArticleInput {
     pub description: String,
     pub body: String,
     pub tag_list: Vec<String>,
+    pub publication: Publication,
 }
 
 /// Create a new article
 ///
 /// # Business Rules
 /// - Title, description, and body must not be empty
-/// - Slug is generated from title
+/// - Slug is transliterated from title and truncated per the slug policy; if
+///   another article has (or had) it, a short suffix makes it unique
 /// - Author is the current user
+/// - The article is published now, kept as a draft or scheduled, as the
+///   input's publication asks
 /// - Initial favorites count is 0
 pub async fn create_article<A>(
     articles_repo: &A,
     author_id: UserId,
     author_profile: Profile,
     input: CreateArticleInput,
+    slug_policy: &SlugPolicy,
     now: DateTime<Utc>,
 ) -> DomainResult<ArticleView>
 where
@@ -36,22 +43,10 @@ where
     let tags = TagList::new(input.tag_list)?;
     let draft = ArticleDraft::new(input.title, input.description, input.body, tags)?;
 
-    let article = Article::publish(ArticleId::random(), author_id, draft, now)?;
-
-    let created = articles_repo
-        .create_article(article)
-        .await
-        .map_err(|e| {
-            // Format the full error chain
-            let mut msg = e.to_string();
-            let mut source = e.source();
-            while let Some(s) = source {
-                msg.push_str(": ");
-                msg.push_str(&s.to_string());
-                source = s.source();
-            }
-            crate::DomainError::Database { message: msg }
-        })?;
+    let mut article = Article::publish(ArticleId::random(), author_id, draft, now)?;
+    article.set_publication(input.publication, now);
+    let base = slug_policy.slugify(&article.title)?;
+    let created = save_with_free_slug(articles_repo, &base, article, SlugWrite::Create).await?;
 
     Ok(created.to_view(author_profile, false))
 }
@@ -59,6 +54,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::ArticleStatus;
     use crate::repositories::{InMemoryArticlesRepository, InMemoryUsersRepository};
     use crate::user::Username;
 
@@ -76,9 +72,11 @@ mod tests {
             description: "A test description".to_string(),
             body: "The article body".to_string(),
             tag_list: vec!["rust".to_string(), "testing".to_string()],
+            publication: Publication::Now,
         };
 
-        let result = create_article(&repo, author_id, author_profile(), input, Utc::now()).await;
+        let policy = SlugPolicy::default();
+        let result = create_article(&repo, author_id, author_profile(), input, &policy, Utc::now()).await;
 
         assert!(result.is_ok());
         let view = result.unwrap();
@@ -98,10 +96,72 @@ mod tests {
             description: "A test description".to_string(),
             body: "The article body".to_string(),
             tag_list: vec![],
+            publication: Publication::Now,
         };
 
-        let result = create_article(&repo, author_id, author_profile(), input, Utc::now()).await;
+        let policy = SlugPolicy::default();
+        let result = create_article(&repo, author_id, author_profile(), input, &policy, Utc::now()).await;
 
         assert!(result.is_err());
     }
+
+    #[tokio::test]
+    async fn test_create_article_same_title_gets_unique_slug() {
+        let users_repo = InMemoryUsersRepository::new();
+        let repo = InMemoryArticlesRepository::new(users_repo);
+        let author_id = UserId::random();
+        let input = CreateArticleInput {
+            title: "Hello World".to_string(),
+            description: "A test description".to_string(),
+            body: "The article body".to_string(),
+            tag_list: vec![],
+            publication: Publication::Now,
+        };
+
+        let policy = SlugPolicy::default();
+        let first = create_article(&repo, author_id, author_profile(), input.clone(), &policy, Utc::now())
+            .await
+            .unwrap();
+        let second = create_article(&repo, author_id, author_profile(), input, &policy, Utc::now())
+            .await
+            .unwrap();
+
+        assert_eq!(first.slug.as_str(), "hello-world");
+        assert_ne!(second.slug, first.slug);
+        assert!(second.slug.as_str().starts_with("hello-world-"));
+        assert_eq!(second.slug.as_str().len(), "hello-world-".len() + 6);
+    }
+
+    #[tokio::test]
+    async fn test_create_article_as_draft_or_scheduled() {
+        let users_repo = InMemoryUsersRepository::new();
+        let repo = InMemoryArticlesRepository::new(users_repo);
+        let author_id = UserId::random();
+        let now = Utc::now();
+        let input = CreateArticleInput {
+            title: "Work in progress".to_string(),
+            description: "A test description".to_string(),
+            body: "The article body".to_string(),
+            tag_list: vec![],
+            publication: Publication::Draft,
+        };
+        let policy = SlugPolicy::default();
+
+        let draft = create_article(&repo, author_id, author_profile(), input.clone(), &policy, now)
+            .await
+            .unwrap();
+        assert_eq!(draft.status, ArticleStatus::Draft);
+        assert_eq!(draft.published_at, None);
+
+        let later = now + chrono::Duration::hours(1);
+        let input = CreateArticleInput {
+            publication: Publication::At(later),
+            ..input
+        };
+        let scheduled = create_article(&repo, author_id, author_profile(), input, &policy, now)
+            .await
+            .unwrap();
+        assert_eq!(scheduled.status, ArticleStatus::Scheduled);
+        assert_eq!(scheduled.published_at, Some(later));
+    }
 }
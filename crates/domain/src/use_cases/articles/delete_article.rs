@@ -1,5 +1,7 @@
 //! Delete article use case
 
+use chrono::{DateTime, Utc};
+
 use crate::{
     DomainError, DomainResult, UserId,
     repositories::ArticlesRepository,
@@ -9,11 +11,14 @@ use crate::{
 ///
 /// # Business Rules
 /// - Article must exist
-/// - Only the author can delete their article
+/// - Only the owner can delete the article; co-authors cannot
+/// - The article only moves to the owner's trash, from where it can be
+///   restored until the purge job erases it
 pub async fn delete_article<A>(
     articles_repo: &A,
     slug: &str,
     author_id: UserId,
+    now: DateTime<Utc>,
 ) -> DomainResult<()>
 where
     A: ArticlesRepository,
@@ -24,13 +29,13 @@ where
         .map_err(|_| DomainError::NotFound { entity: "article" })?
         .ok_or(DomainError::NotFound { entity: "article" })?;
 
-    // Authorization: only author can delete
-    if article.author_id != author_id {
+    // Authorization: only the owner can delete
+    if !article.authored_by(&author_id) {
         return Err(DomainError::NotFound { entity: "article" });
     }
 
     articles_repo
-        .delete_article(article.id)
+        .delete_article(article.id, now)
         .await
         .map_err(|_| DomainError::NotFound { entity: "article" })?;
 
@@ -40,9 +45,11 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::repositories::{InMemoryArticlesRepository, InMemoryUsersRepository};
-    use crate::{Article, ArticleDraft, ArticleId, TagList};
-    use chrono::Utc;
+    use crate::repositories::{InMemoryArticlesRepository, InMemoryUsersRepository, UsersRepository};
+    use crate::{
+        Article, ArticleDraft, ArticleFilters, ArticleId, Bookmark, BookmarkFilters, Email, PasswordHash, TagList, User,
+        Username,
+    };
 
     async fn setup() -> (InMemoryArticlesRepository, UserId, Article) {
         let users_repo = InMemoryUsersRepository::new();
@@ -60,13 +67,21 @@ mod tests {
     async fn test_delete_article_success() {
         let (articles_repo, author_id, article) = setup().await;
 
-        let result = delete_article(&articles_repo, article.slug.as_str(), author_id).await;
+        let now = Utc::now();
+
+        let result = delete_article(&articles_repo, article.slug.as_str(), author_id, now).await;
 
         assert!(result.is_ok());
 
         // Verify article is deleted
         let found = articles_repo.get_article_by_slug(article.slug.as_str()).await.unwrap();
         assert!(found.is_none());
+        let trashed = articles_repo
+            .get_trashed_article_by_slug(article.slug.as_str())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(trashed.deleted_at, now);
     }
 
     #[tokio::test]
@@ -74,7 +89,7 @@ mod tests {
         let (articles_repo, _author_id, article) = setup().await;
         let other_user_id = UserId::random();
 
-        let result = delete_article(&articles_repo, article.slug.as_str(), other_user_id).await;
+        let result = delete_article(&articles_repo, article.slug.as_str(), other_user_id, Utc::now()).await;
 
         assert!(matches!(result, Err(DomainError::NotFound { entity: "article" })));
     }
@@ -83,8 +98,39 @@ mod tests {
     async fn test_delete_article_not_found() {
         let (articles_repo, author_id, _article) = setup().await;
 
-        let result = delete_article(&articles_repo, "nonexistent", author_id).await;
+        let result = delete_article(&articles_repo, "nonexistent", author_id, Utc::now()).await;
 
         assert!(matches!(result, Err(DomainError::NotFound { entity: "article" })));
     }
+
+    #[tokio::test]
+    async fn test_delete_article_leaves_listings() {
+        let users_repo = InMemoryUsersRepository::new();
+        let articles_repo = InMemoryArticlesRepository::new(users_repo.clone());
+        let author = User::new(
+            UserId::random(),
+            Email::parse("author@example.com").unwrap(),
+            Username::new("author").unwrap(),
+            PasswordHash::new("hash").unwrap(),
+            Utc::now(),
+        );
+        users_repo.create_user(author.clone()).await.unwrap();
+        let draft = ArticleDraft::new("Test Article", "Description", "Body", TagList::default()).unwrap();
+        let article = Article::publish(ArticleId::random(), author.id, draft, Utc::now()).unwrap();
+        let article = articles_repo.create_article(article).await.unwrap();
+        let reader = UserId::random();
+        let bookmark = Bookmark::new(reader, article.id, None, None, Utc::now()).unwrap();
+        articles_repo.bookmark_article(bookmark).await.unwrap();
+        let counts = || async {
+            let listed = articles_repo.list_articles(ArticleFilters::default()).await.unwrap();
+            let bookmarked = articles_repo.list_bookmarks(reader, BookmarkFilters::default()).await.unwrap();
+            let sitemap = articles_repo.count_sitemap_entries().await.unwrap();
+            (listed.articles_count, bookmarked.bookmarks_count, sitemap)
+        };
+        assert_eq!(counts().await, (1, 1, 2));
+
+        delete_article(&articles_repo, "test-article", author.id, Utc::now()).await.unwrap();
+
+        assert_eq!(counts().await, (0, 0, 0));
+    }
 }
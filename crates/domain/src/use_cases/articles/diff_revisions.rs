@@ -0,0 +1,90 @@
+//! Diff article revisions use case
+
+use crate::{
+    DiffFormat, DomainResult, RevisionDiff, RevisionId, UserId,
+    repositories::{ArticlesRepository, RevisionsRepository},
+    use_cases::articles::revisions::{get_article_revision, get_own_article},
+};
+
+/// Compare two revisions of an article
+///
+/// # Business Rules
+/// - Article and both revisions must exist, and the revisions must belong
+///   to the article
+/// - Only the article's authors (owner and co-authors) can see its history
+/// - Title, description and body are diffed separately, as unified diffs or
+///   as word runs
+pub async fn diff_revisions<A, R>(
+    articles_repo: &A,
+    revisions_repo: &R,
+    slug: &str,
+    user_id: UserId,
+    from: RevisionId,
+    to: RevisionId,
+    format: DiffFormat,
+) -> DomainResult<RevisionDiff>
+where
+    A: ArticlesRepository,
+    R: RevisionsRepository + ?Sized,
+{
+    let article = get_own_article(articles_repo, slug, user_id).await?;
+    let from = get_article_revision(revisions_repo, &article, from).await?;
+    let to = get_article_revision(revisions_repo, &article, to).await?;
+
+    Ok(from.diff(&to, format))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::{InMemoryArticlesRepository, InMemoryRevisionsRepository, InMemoryUsersRepository};
+    use crate::{Article, ArticleDraft, ArticleId, ArticleRevision, DomainError, FieldDiff, TagList};
+    use chrono::Utc;
+
+    #[tokio::test]
+    async fn test_diff_revisions_of_same_article_only() {
+        let users_repo = InMemoryUsersRepository::new();
+        let articles_repo = InMemoryArticlesRepository::new(users_repo);
+        let revisions_repo = InMemoryRevisionsRepository::new();
+        let author_id = UserId::random();
+
+        let mut revision_ids = Vec::new();
+        for title in ["First", "Second"] {
+            let draft = ArticleDraft::new(title, "Description", "Body", TagList::default()).unwrap();
+            let mut article = Article::publish(ArticleId::random(), author_id, draft, Utc::now()).unwrap();
+            article = articles_repo.create_article(article).await.unwrap();
+            for (version, body) in [(1, "Body"), (2, "Body\nwith more")] {
+                article.body = body.to_string();
+                let revision = ArticleRevision::snapshot(RevisionId::random(), &article, version, author_id, Utc::now());
+                revision_ids.push(revisions_repo.create_revision(revision).await.unwrap().id);
+            }
+        }
+
+        let diff = diff_revisions(
+            &articles_repo,
+            &revisions_repo,
+            "first",
+            author_id,
+            revision_ids[0],
+            revision_ids[1],
+            DiffFormat::Unified,
+        )
+        .await
+        .unwrap();
+        assert_eq!((diff.from, diff.to), (1, 2));
+        assert_eq!(diff.title, FieldDiff::Unified(String::new()));
+        assert!(matches!(diff.body, FieldDiff::Unified(ref text) if text.contains("+with more\n")));
+
+        let result = diff_revisions(
+            &articles_repo,
+            &revisions_repo,
+            "first",
+            author_id,
+            revision_ids[0],
+            revision_ids[3],
+            DiffFormat::Word,
+        )
+        .await;
+        assert!(matches!(result, Err(DomainError::NotFound { entity: "revision" })));
+    }
+}
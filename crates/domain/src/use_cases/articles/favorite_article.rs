@@ -1,5 +1,7 @@
 //! Favorite article use case
 
+use chrono::{DateTime, Utc};
+
 use crate::{
     ArticleView, DomainError, DomainResult, UserId,
     repositories::{ArticlesRepository, UsersRepository},
@@ -9,14 +11,16 @@ use crate::{
 /// Favorite an article
 ///
 /// # Business Rules
-/// - Article must exist
-/// - User can favorite any article (including their own)
+/// - Article must exist and be published, unless the user is its author
+/// - User can favorite any article (including their own) unless its author
+///   has blocked them
 /// - Favoriting already-favorited article is idempotent
 pub async fn favorite_article<U, A>(
     users_repo: &U,
     articles_repo: &A,
     slug: &str,
     user_id: UserId,
+    now: DateTime<Utc>,
 ) -> DomainResult<ArticleView>
 where
     U: UsersRepository,
@@ -27,9 +31,22 @@ where
         .await
         .map_err(|_| DomainError::NotFound { entity: "article" })?
         .ok_or(DomainError::NotFound { entity: "article" })?;
+    if !article.is_visible_to(Some(user_id)) {
+        return Err(DomainError::NotFound { entity: "article" });
+    }
+
+    let blocked = users_repo
+        .is_blocking(article.author_id, user_id)
+        .await
+        .map_err(|e| DomainError::Database {
+            message: e.to_string(),
+        })?;
+    if blocked {
+        return Err(DomainError::Blocked);
+    }
 
     articles_repo
-        .favorite_article(user_id, article.id)
+        .favorite_article(user_id, article.id, now)
         .await
         .map_err(|_| DomainError::NotFound { entity: "article" })?;
 
@@ -75,7 +92,7 @@ mod tests {
         let (users_repo, articles_repo, _author, article) = setup().await;
         let user_id = UserId::random();
 
-        let result = favorite_article(&users_repo, &articles_repo, article.slug.as_str(), user_id).await;
+        let result = favorite_article(&users_repo, &articles_repo, article.slug.as_str(), user_id, Utc::now()).await;
 
         assert!(result.is_ok());
         let view = result.unwrap();
@@ -88,8 +105,19 @@ mod tests {
         let (users_repo, articles_repo, _, _) = setup().await;
         let user_id = UserId::random();
 
-        let result = favorite_article(&users_repo, &articles_repo, "nonexistent", user_id).await;
+        let result = favorite_article(&users_repo, &articles_repo, "nonexistent", user_id, Utc::now()).await;
 
         assert!(matches!(result, Err(DomainError::NotFound { entity: "article" })));
     }
+
+    #[tokio::test]
+    async fn test_favorite_article_blocked_by_author() {
+        let (users_repo, articles_repo, author, article) = setup().await;
+        let user_id = UserId::random();
+        users_repo.block_user(author.id, user_id).await.unwrap();
+
+        let result = favorite_article(&users_repo, &articles_repo, article.slug.as_str(), user_id, Utc::now()).await;
+
+        assert!(matches!(result, Err(DomainError::Blocked)));
+    }
 }
@@ -1,7 +1,7 @@
 //! Feed articles use case
 
 use crate::{
-    ArticlesEnvelope, DomainResult, FeedFilters, Pagination, UserId,
+    ArticlesEnvelope, Cursor, DomainResult, FeedFilters, Pagination, UserId,
     repositories::ArticlesRepository,
 };
 
@@ -10,14 +10,22 @@ use crate::{
 pub struct FeedArticlesInput {
     pub limit: Option<u32>,
     pub offset: Option<u32>,
+    /// Opaque cursor from a previous page; takes precedence over `offset`
+    pub cursor: Option<String>,
 }
 
-/// Get articles from users the current user follows
+/// Get articles from users and tags the current user follows
 ///
 /// # Business Rules
-/// - Only returns articles from followed users
+/// - Returns articles from followed users and articles carrying a followed
+///   tag, each once
+/// - Every article says why it is there: its author, which followed tags it
+///   carries, or both
+/// - Articles by muted authors are left out, whatever their tags
+/// - Only published articles are included
 /// - Pagination is applied with default limits
 /// - Articles are returned in descending order by creation date
+/// - Pages link to their neighbours through `nextCursor` and `prevCursor`
 pub async fn feed_articles<A>(
     articles_repo: &A,
     user_id: UserId,
@@ -27,7 +35,8 @@ where
     A: ArticlesRepository,
 {
     let pagination = Pagination::new(input.limit, input.offset)?;
-    let filters = FeedFilters::new(Some(pagination));
+    let cursor = input.cursor.as_deref().map(Cursor::decode).transpose()?;
+    let filters = FeedFilters::new(Some(pagination)).with_cursor(cursor);
 
     let envelope = articles_repo
         .feed_articles(user_id, filters)
@@ -40,7 +49,53 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::repositories::{InMemoryArticlesRepository, InMemoryUsersRepository};
+    use crate::repositories::{
+        InMemoryArticlesRepository, InMemoryTagsRepository, InMemoryUsersRepository, TagsRepository, UsersRepository,
+    };
+    use crate::{Article, ArticleDraft, ArticleId, Coauthor, Email, PasswordHash, Tag, TagList, User, Username};
+    use chrono::{Duration, Utc};
+
+    struct Setup {
+        users_repo: InMemoryUsersRepository,
+        articles_repo: InMemoryArticlesRepository,
+        tags_repo: InMemoryTagsRepository,
+        reader: UserId,
+    }
+
+    async fn setup() -> Setup {
+        let users_repo = InMemoryUsersRepository::new();
+        let articles_repo = InMemoryArticlesRepository::new(users_repo.clone());
+        let tags_repo = InMemoryTagsRepository::new(articles_repo.clone());
+        let reader = create_user(&users_repo, "reader").await;
+        Setup {
+            users_repo,
+            articles_repo,
+            tags_repo,
+            reader,
+        }
+    }
+
+    async fn create_user(users_repo: &InMemoryUsersRepository, name: &str) -> UserId {
+        let user = User::new(
+            UserId::random(),
+            Email::parse(format!("{name}@example.com")).unwrap(),
+            Username::new(name).unwrap(),
+            PasswordHash::new("hash").unwrap(),
+            Utc::now(),
+        );
+        users_repo.create_user(user).await.unwrap().id
+    }
+
+    async fn publish(s: &Setup, author_id: UserId, title: &str, tags: &[&str], days_ago: i64) {
+        let draft = ArticleDraft::new(title, "Description", "Body", TagList::new(tags.iter().copied()).unwrap()).unwrap();
+        let article = Article::publish(ArticleId::random(), author_id, draft, Utc::now() - Duration::days(days_ago)).unwrap();
+        s.articles_repo.create_article(article).await.unwrap();
+    }
+
+    async fn titles(s: &Setup) -> Vec<String> {
+        let envelope = feed_articles(&s.articles_repo, s.reader, FeedArticlesInput::default()).await.unwrap();
+        envelope.articles.into_iter().map(|a| a.title).collect()
+    }
 
     #[tokio::test]
     async fn test_feed_articles_empty() {
@@ -55,4 +110,79 @@ mod tests {
         let envelope = result.unwrap();
         assert_eq!(envelope.articles_count, 0);
     }
+
+    #[tokio::test]
+    async fn test_feed_articles_says_why_each_article_is_there() {
+        let s = setup().await;
+        let followed = create_user(&s.users_repo, "followed").await;
+        let stranger = create_user(&s.users_repo, "stranger").await;
+        s.users_repo.follow_user(s.reader, followed).await.unwrap();
+        for name in ["rust", "wasm"] {
+            s.tags_repo.follow_tag(s.reader, &Tag::new(name).unwrap()).await.unwrap();
+        }
+        publish(&s, followed, "Followed author", &["go"], 4).await;
+        publish(&s, followed, "Both", &["rust", "web", "wasm"], 3).await;
+        publish(&s, stranger, "Followed tag", &["rust"], 2).await;
+        publish(&s, stranger, "Neither", &["go"], 1).await;
+
+        let envelope = feed_articles(&s.articles_repo, s.reader, FeedArticlesInput::default()).await.unwrap();
+
+        let titles: Vec<&str> = envelope.articles.iter().map(|a| a.title.as_str()).collect();
+        assert_eq!(titles, ["Followed tag", "Both", "Followed author"]);
+        let reasons: Vec<_> = envelope.articles.iter().map(|a| a.reason.clone().unwrap()).collect();
+        assert!(!reasons[0].followed_author);
+        assert!(!envelope.articles[0].author.following);
+        assert_eq!(reasons[0].followed_tags, TagList::new(["rust"]).unwrap());
+        assert!(reasons[1].followed_author);
+        assert_eq!(reasons[1].followed_tags, TagList::new(["rust", "wasm"]).unwrap());
+        assert!(reasons[2].followed_author);
+        assert!(reasons[2].followed_tags.as_slice().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_feed_articles_leaves_out_muted_authors() {
+        let s = setup().await;
+        let muted = create_user(&s.users_repo, "muted").await;
+        s.users_repo.follow_user(s.reader, muted).await.unwrap();
+        s.users_repo.mute_user(s.reader, muted).await.unwrap();
+        s.tags_repo.follow_tag(s.reader, &Tag::new("rust").unwrap()).await.unwrap();
+        publish(&s, muted, "Muted", &["rust"], 1).await;
+
+        assert!(titles(&s).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_feed_articles_drops_unfollowed_tags() {
+        let s = setup().await;
+        let stranger = create_user(&s.users_repo, "stranger").await;
+        let rust = Tag::new("rust").unwrap();
+        s.tags_repo.follow_tag(s.reader, &rust).await.unwrap();
+        publish(&s, stranger, "Rust", &["rust"], 1).await;
+        assert_eq!(titles(&s).await, ["Rust"]);
+
+        s.tags_repo.unfollow_tag(s.reader, &rust).await.unwrap();
+
+        assert!(titles(&s).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_feed_articles_reaches_followers_of_coauthors() {
+        let s = setup().await;
+        let owner = create_user(&s.users_repo, "owner").await;
+        let coauthor = create_user(&s.users_repo, "coauthor").await;
+        s.users_repo.follow_user(s.reader, coauthor).await.unwrap();
+        publish(&s, owner, "Joint Work", &[], 1).await;
+        let article = s.articles_repo.get_article_by_slug("joint-work").await.unwrap().unwrap();
+
+        // A pending invitation is not enough
+        s.articles_repo.invite_coauthor(Coauthor::invite(article.id, coauthor, Utc::now())).await.unwrap();
+        assert!(titles(&s).await.is_empty());
+
+        s.articles_repo.accept_coauthor(article.id, coauthor, Utc::now()).await.unwrap();
+        let envelope = feed_articles(&s.articles_repo, s.reader, FeedArticlesInput::default()).await.unwrap();
+        assert_eq!(envelope.articles_count, 1);
+        assert!(envelope.articles[0].reason.as_ref().unwrap().followed_author);
+        // The author shown is the owner, whom the reader doesn't follow
+        assert!(!envelope.articles[0].author.following);
+    }
 }
@@ -8,9 +8,15 @@ use crate::{
 /// Get an article by slug with author profile and favorite status
 ///
 /// # Business Rules
-/// - Article must exist
+/// - Article must exist; a slug it had before a rename still finds it, and the
+///   returned view carries the current slug
+/// - Articles by an author who blocked the viewer are reported as missing
+/// - Drafts and scheduled articles are reported as missing to anyone but
+///   their authors
+/// - `authors` lists the owner first, then accepted co-authors who are
+///   still active
 /// - Author profile includes following status relative to viewer
-/// - Favorited status is relative to viewer (false if no viewer)
+/// - Favorited and bookmarked status are relative to viewer (false if no viewer)
 pub async fn get_article<U, A>(
     users_repo: &U,
     articles_repo: &A,
@@ -21,13 +27,52 @@ where
     U: UsersRepository,
     A: ArticlesRepository,
 {
-    let article = articles_repo
+    let article = find_visible_article(users_repo, articles_repo, slug, viewer_id).await?;
+
+    build_article_view(users_repo, articles_repo, &article, viewer_id).await
+}
+
+/// The article at `slug`, or at a slug it had before a rename, if the viewer
+/// may read it: published, or theirs, and not by an author who blocked them
+pub(crate) async fn find_visible_article<U, A>(
+    users_repo: &U,
+    articles_repo: &A,
+    slug: &str,
+    viewer_id: Option<UserId>,
+) -> DomainResult<Article>
+where
+    U: UsersRepository,
+    A: ArticlesRepository,
+{
+    let current = articles_repo
         .get_article_by_slug(slug)
         .await
-        .map_err(|_| DomainError::NotFound { entity: "article" })?
-        .ok_or(DomainError::NotFound { entity: "article" })?;
+        .map_err(|_| DomainError::NotFound { entity: "article" })?;
+    let article = match current {
+        Some(article) => article,
+        None => articles_repo
+            .get_article_by_previous_slug(slug)
+            .await
+            .map_err(|_| DomainError::NotFound { entity: "article" })?
+            .ok_or(DomainError::NotFound { entity: "article" })?,
+    };
+    if !article.is_visible_to(viewer_id) {
+        return Err(DomainError::NotFound { entity: "article" });
+    }
 
-    build_article_view(users_repo, articles_repo, &article, viewer_id).await
+    if let Some(viewer) = viewer_id {
+        let blocked = users_repo
+            .is_blocking(article.author_id, viewer)
+            .await
+            .map_err(|e| DomainError::Database {
+                message: e.to_string(),
+            })?;
+        if blocked {
+            return Err(DomainError::NotFound { entity: "article" });
+        }
+    }
+
+    Ok(article)
 }
 
 /// Get an article by ID with author profile and favorite status
@@ -83,8 +128,48 @@ where
         None => false,
     };
 
+    let bookmarked = match viewer_id {
+        Some(viewer) => articles_repo
+            .get_bookmark(viewer, article.id)
+            .await
+            .is_ok_and(|bookmark| bookmark.is_some()),
+        None => false,
+    };
+
+    let mut coauthors = Vec::with_capacity(article.coauthor_ids.len());
+    for coauthor_id in &article.coauthor_ids {
+        let coauthor = users_repo
+            .get_user_by_id(*coauthor_id)
+            .await
+            .map_err(|e| DomainError::Database {
+                message: e.to_string(),
+            })?;
+        let Some(coauthor) = coauthor.filter(|user| !user.is_deactivated()) else {
+            continue;
+        };
+        let following = match viewer_id {
+            Some(viewer) => users_repo
+                .is_following(viewer, coauthor.id)
+                .await
+                .unwrap_or(false),
+            None => false,
+        };
+        coauthors.push(coauthor.to_profile(following));
+    }
+
+    let reactions = articles_repo
+        .article_reactions(article.id, viewer_id)
+        .await
+        .map_err(|e| DomainError::Database {
+            message: e.to_string(),
+        })?;
+
     let profile = author.to_profile(following);
-    Ok(article.to_view(profile, favorited))
+    Ok(article
+        .to_view(profile, favorited)
+        .with_bookmarked(bookmarked)
+        .with_reactions(reactions)
+        .with_coauthors(coauthors))
 }
 
 #[cfg(test)]
@@ -149,4 +234,19 @@ mod tests {
         let view = result.unwrap();
         assert!(view.author.following);
     }
+
+    #[tokio::test]
+    async fn test_get_article_draft_only_visible_to_author() {
+        let (users_repo, articles_repo, author, mut article) = setup().await;
+        article.set_publication(crate::Publication::Draft, Utc::now());
+        articles_repo.update_article(article.clone()).await.unwrap();
+
+        let result = get_article(&users_repo, &articles_repo, article.slug.as_str(), Some(UserId::random())).await;
+        assert!(matches!(result, Err(DomainError::NotFound { entity: "article" })));
+
+        let view = get_article(&users_repo, &articles_repo, article.slug.as_str(), Some(author.id))
+            .await
+            .unwrap();
+        assert_eq!(view.status, crate::ArticleStatus::Draft);
+    }
 }
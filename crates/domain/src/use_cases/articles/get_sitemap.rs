@@ -0,0 +1,134 @@
+//! Get sitemap use case
+
+use crate::{
+    DomainError, DomainResult, MAX_SITEMAP_URLS, Sitemap,
+    repositories::ArticlesRepository,
+};
+
+/// The sitemap at `/sitemap.xml` when `page` is `None`, or the numbered
+/// sitemap `page` of the index
+///
+/// # Business Rules
+/// - Published articles by active authors are listed, then the profiles of
+///   those authors
+/// - Each sitemap holds at most `urls_per_sitemap` URLs, capped at the
+///   50,000 the protocol allows
+/// - When everything fits in one, `/sitemap.xml` is that sitemap; otherwise
+///   it is an index of the numbered sitemaps
+/// - Numbered sitemaps start at 1; those past the last are not found
+pub async fn get_sitemap<A>(
+    articles_repo: &A,
+    page: Option<u32>,
+    urls_per_sitemap: u32,
+) -> DomainResult<Sitemap>
+where
+    A: ArticlesRepository,
+{
+    let per_page = urls_per_sitemap.clamp(1, MAX_SITEMAP_URLS);
+    let total = articles_repo
+        .count_sitemap_entries()
+        .await
+        .map_err(|e| DomainError::Database {
+            message: e.to_string(),
+        })?;
+    let pages = total.div_ceil(u64::from(per_page)).max(1);
+
+    let page = match page {
+        None if pages > 1 => {
+            return Ok(Sitemap::Index {
+                pages: u32::try_from(pages).unwrap_or(u32::MAX),
+            });
+        }
+        None => 1,
+        Some(page) if page >= 1 && u64::from(page) <= pages => page,
+        Some(_) => return Err(DomainError::NotFound { entity: "sitemap" }),
+    };
+
+    let entries = articles_repo
+        .sitemap_entries(u64::from(page - 1) * u64::from(per_page), per_page)
+        .await
+        .map_err(|e| DomainError::Database {
+            message: e.to_string(),
+        })?;
+
+    Ok(Sitemap::Urls(entries))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::{InMemoryArticlesRepository, InMemoryUsersRepository, UsersRepository};
+    use crate::{
+        Article, ArticleDraft, ArticleId, Email, PasswordHash, Publication, SitemapLocation, TagList, User,
+        UserId, Username,
+    };
+    use chrono::Utc;
+
+    async fn setup() -> (InMemoryUsersRepository, InMemoryArticlesRepository) {
+        let users_repo = InMemoryUsersRepository::new();
+        let articles_repo = InMemoryArticlesRepository::new(users_repo.clone());
+        for name in ["bob", "alice", "carol"] {
+            let user = User::new(
+                UserId::random(),
+                Email::parse(format!("{name}@example.com")).unwrap(),
+                Username::new(name).unwrap(),
+                PasswordHash::new("hash").unwrap(),
+                Utc::now(),
+            );
+            users_repo.create_user(user.clone()).await.unwrap();
+            // Carol only has a draft, so she isn't listed
+            let draft = ArticleDraft::new(format!("By {name}"), "Description", "Body", TagList::default()).unwrap();
+            let mut article = Article::publish(ArticleId::random(), user.id, draft, Utc::now()).unwrap();
+            if name == "carol" {
+                article.set_publication(Publication::Draft, Utc::now());
+            }
+            articles_repo.create_article(article).await.unwrap();
+        }
+        (users_repo, articles_repo)
+    }
+
+    fn locations(sitemap: Sitemap) -> Vec<SitemapLocation> {
+        match sitemap {
+            Sitemap::Urls(entries) => entries.into_iter().map(|entry| entry.location).collect(),
+            Sitemap::Index { .. } => panic!("expected a urlset"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_sitemap_lists_articles_then_authors() {
+        let (_users_repo, articles_repo) = setup().await;
+
+        let sitemap = get_sitemap(&articles_repo, None, MAX_SITEMAP_URLS).await.unwrap();
+
+        assert_eq!(
+            locations(sitemap),
+            [
+                SitemapLocation::Article("by-alice".into()),
+                SitemapLocation::Article("by-bob".into()),
+                SitemapLocation::Profile("alice".into()),
+                SitemapLocation::Profile("bob".into()),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_sitemap_becomes_an_index_when_too_long() {
+        let (users_repo, articles_repo) = setup().await;
+
+        let index = get_sitemap(&articles_repo, None, 3).await.unwrap();
+        assert_eq!(index, Sitemap::Index { pages: 2 });
+        let second = get_sitemap(&articles_repo, Some(2), 3).await.unwrap();
+        assert_eq!(locations(second), [SitemapLocation::Profile("bob".into())]);
+        let result = get_sitemap(&articles_repo, Some(3), 3).await;
+        assert!(matches!(result, Err(DomainError::NotFound { entity: "sitemap" })));
+
+        // Deactivated authors drop out along with their articles
+        let bob = users_repo.get_user_by_username("bob").await.unwrap().unwrap();
+        users_repo.deactivate_user(bob.id, Utc::now()).await.unwrap();
+        let sitemap = get_sitemap(&articles_repo, None, 3).await.unwrap();
+        assert_eq!(
+            locations(sitemap),
+            [SitemapLocation::Article("by-alice".into()), SitemapLocation::Profile("alice".into())]
+        );
+    }
+}
@@ -0,0 +1,134 @@
+//! Invite co-author use case
+
+use chrono::{DateTime, Utc};
+
+use crate::{
+    ArticleView, Coauthor, DomainError, DomainResult, UserId,
+    repositories::{ArticlesRepository, UsersRepository},
+    use_cases::articles::get_article::build_article_view,
+};
+
+/// Invite another user to co-author an article
+///
+/// # Business Rules
+/// - Article must exist and only its owner can invite; co-authors cannot
+/// - The invitee must be an active user other than the owner, and must not
+///   have blocked the owner
+/// - The invitee only becomes a co-author once they accept
+/// - Inviting someone already invited or co-authoring is idempotent
+pub async fn invite_coauthor<U, A>(
+    users_repo: &U,
+    articles_repo: &A,
+    slug: &str,
+    owner_id: UserId,
+    username: &str,
+    now: DateTime<Utc>,
+) -> DomainResult<ArticleView>
+where
+    U: UsersRepository,
+    A: ArticlesRepository,
+{
+    let db_error = |e: anyhow::Error| DomainError::Database {
+        message: e.to_string(),
+    };
+
+    let article = articles_repo
+        .get_article_by_slug(slug)
+        .await
+        .map_err(db_error)?
+        .filter(|article| article.is_visible_to(Some(owner_id)))
+        .ok_or(DomainError::NotFound { entity: "article" })?;
+    if !article.authored_by(&owner_id) {
+        return Err(DomainError::UnauthorizedAction);
+    }
+
+    let invitee = users_repo
+        .get_user_by_username(username)
+        .await
+        .map_err(db_error)?
+        .filter(|user| !user.is_deactivated())
+        .ok_or(DomainError::NotFound { entity: "profile" })?;
+    if invitee.id == owner_id {
+        return Err(DomainError::CoauthorIsOwner);
+    }
+    let blocked = users_repo
+        .is_blocking(invitee.id, owner_id)
+        .await
+        .map_err(db_error)?;
+    if blocked {
+        return Err(DomainError::Blocked);
+    }
+
+    articles_repo
+        .invite_coauthor(Coauthor::invite(article.id, invitee.id, now))
+        .await
+        .map_err(db_error)?;
+
+    build_article_view(users_repo, articles_repo, &article, Some(owner_id)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::{InMemoryArticlesRepository, InMemoryUsersRepository};
+    use crate::{Article, ArticleDraft, ArticleId, Email, PasswordHash, TagList, User, Username};
+
+    async fn setup() -> (InMemoryUsersRepository, InMemoryArticlesRepository, User, User, Article) {
+        let users_repo = InMemoryUsersRepository::new();
+        let articles_repo = InMemoryArticlesRepository::new(users_repo.clone());
+        let mut users = Vec::new();
+        for name in ["owner", "invitee"] {
+            let user = User::new(
+                UserId::random(),
+                Email::parse(format!("{name}@example.com")).unwrap(),
+                Username::new(name).unwrap(),
+                PasswordHash::new("hash").unwrap(),
+                Utc::now(),
+            );
+            users.push(users_repo.create_user(user).await.unwrap());
+        }
+        let invitee = users.pop().unwrap();
+        let owner = users.pop().unwrap();
+        let draft = ArticleDraft::new("Joint Work", "desc", "body", TagList::default()).unwrap();
+        let article = Article::publish(ArticleId::random(), owner.id, draft, Utc::now()).unwrap();
+        let article = articles_repo.create_article(article).await.unwrap();
+        (users_repo, articles_repo, owner, invitee, article)
+    }
+
+    #[tokio::test]
+    async fn test_invite_coauthor_stays_pending() {
+        let (users_repo, articles_repo, owner, invitee, article) = setup().await;
+
+        let view = invite_coauthor(&users_repo, &articles_repo, "joint-work", owner.id, "invitee", Utc::now())
+            .await
+            .unwrap();
+
+        // Not listed until accepted
+        assert_eq!(view.authors.len(), 1);
+        let invitation = articles_repo.get_coauthor(article.id, invitee.id).await.unwrap().unwrap();
+        assert!(!invitation.is_accepted());
+
+        // Inviting again keeps the first invitation
+        invite_coauthor(&users_repo, &articles_repo, "joint-work", owner.id, "invitee", Utc::now())
+            .await
+            .unwrap();
+        let again = articles_repo.get_coauthor(article.id, invitee.id).await.unwrap().unwrap();
+        assert_eq!(again.invited_at, invitation.invited_at);
+    }
+
+    #[tokio::test]
+    async fn test_invite_coauthor_rules() {
+        let (users_repo, articles_repo, owner, invitee, _) = setup().await;
+
+        let result = invite_coauthor(&users_repo, &articles_repo, "joint-work", invitee.id, "owner", Utc::now()).await;
+        assert!(matches!(result, Err(DomainError::UnauthorizedAction)));
+        let result = invite_coauthor(&users_repo, &articles_repo, "joint-work", owner.id, "owner", Utc::now()).await;
+        assert!(matches!(result, Err(DomainError::CoauthorIsOwner)));
+        let result = invite_coauthor(&users_repo, &articles_repo, "joint-work", owner.id, "nobody", Utc::now()).await;
+        assert!(matches!(result, Err(DomainError::NotFound { entity: "profile" })));
+
+        users_repo.block_user(invitee.id, owner.id).await.unwrap();
+        let result = invite_coauthor(&users_repo, &articles_repo, "joint-work", owner.id, "invitee", Utc::now()).await;
+        assert!(matches!(result, Err(DomainError::Blocked)));
+    }
+}
@@ -1,35 +1,67 @@
 //! List articles use case
 
+use chrono::{DateTime, Utc};
+
 use crate::{
-    ArticleFilters, ArticlesEnvelope, DomainResult, Pagination,
-    repositories::ArticlesRepository,
+    ArticleFilters, ArticleSort, ArticlesEnvelope, Cursor, DomainError, DomainResult, Pagination,
+    Tag, TagMatch, UserId,
+    repositories::{ArticlesRepository, TagsRepository},
 };
 
 /// Input for listing articles
 #[derive(Debug, Clone, Default)]
 pub struct ListArticlesInput {
-    pub tag: Option<String>,
-    pub author: Option<String>,
+    pub tags: Vec<String>,
+    pub tag_match: TagMatch,
+    pub excluded_tags: Vec<String>,
+    pub authors: Vec<String>,
     pub favorited: Option<String>,
+    pub since: Option<DateTime<Utc>>,
+    pub until: Option<DateTime<Utc>>,
+    pub sort: ArticleSort,
+    /// Start of the window favorites count in when sorting by trending
+    pub trending_since: Option<DateTime<Utc>>,
     pub limit: Option<u32>,
     pub offset: Option<u32>,
+    /// Opaque cursor from a previous page; takes precedence over `offset`
+    pub cursor: Option<String>,
+    pub viewer_id: Option<UserId>,
 }
 
 /// List articles with optional filters
 ///
 /// # Business Rules
 /// - Pagination is applied with default limits
-/// - Can filter by tag, author username, or favorited by username
-/// - Articles are returned in descending order by creation date
-pub async fn list_articles<A>(
+/// - Can filter by any or all of several tags, excluded tags, several author
+///   usernames, favorited by username and a creation date range
+/// - Tag filters are normalised and aliases stand for the tag they point to,
+///   as they do on articles
+/// - Sorted by creation date, last edit, favorites or recent favorites
+/// - Authors the viewer muted, or who blocked the viewer, are left out
+/// - Drafts and scheduled articles are listed only for their author
+/// - Ties, and the default order, are newest first
+/// - Recent-sorted pages link to their neighbours through `nextCursor` and `prevCursor`
+pub async fn list_articles<A, T>(
     articles_repo: &A,
+    tags_repo: &T,
     input: ListArticlesInput,
 ) -> DomainResult<ArticlesEnvelope>
 where
     A: ArticlesRepository,
+    T: TagsRepository + ?Sized,
 {
     let pagination = Pagination::new(input.limit, input.offset)?;
-    let filters = ArticleFilters::new(input.tag, input.author, input.favorited, Some(pagination))?;
+    let cursor = input.cursor.as_deref().map(Cursor::decode).transpose()?;
+    let mut filters = ArticleFilters::new(None, None, input.favorited, Some(pagination))?
+        .with_tags(input.tags, input.tag_match)?
+        .without_tags(input.excluded_tags)?
+        .with_authors(input.authors)
+        .created_between(input.since, input.until)
+        .with_sort(input.sort, input.trending_since)
+        .with_viewer(input.viewer_id)
+        .with_cursor(cursor)?;
+    filters.tags = canonical_tags(tags_repo, filters.tags).await?;
+    filters.excluded_tags = canonical_tags(tags_repo, filters.excluded_tags).await?;
 
     let envelope = articles_repo
         .list_articles(filters)
@@ -39,10 +71,32 @@ where
     Ok(envelope)
 }
 
+/// The tags the filter names stand for, each once
+async fn canonical_tags<T>(tags_repo: &T, tags: Vec<Tag>) -> DomainResult<Vec<Tag>>
+where
+    T: TagsRepository + ?Sized,
+{
+    let mut canonical: Vec<Tag> = Vec::with_capacity(tags.len());
+    for tag in tags {
+        let tag = tags_repo
+            .canonical_tag(&tag)
+            .await
+            .map_err(|e| DomainError::Database {
+                message: e.to_string(),
+            })?;
+        if !canonical.contains(&tag) {
+            canonical.push(tag);
+        }
+    }
+    Ok(canonical)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::repositories::{InMemoryArticlesRepository, InMemoryUsersRepository, UsersRepository};
+    use crate::repositories::{
+        InMemoryArticlesRepository, InMemoryTagsRepository, InMemoryUsersRepository, UsersRepository,
+    };
     use crate::{Article, ArticleDraft, ArticleId, Email, PasswordHash, TagList, User, UserId, Username};
     use chrono::Utc;
 
@@ -77,12 +131,16 @@ mod tests {
         articles_repo
     }
 
+    fn tags(articles_repo: &InMemoryArticlesRepository) -> InMemoryTagsRepository {
+        InMemoryTagsRepository::new(articles_repo.clone())
+    }
+
     #[tokio::test]
     async fn test_list_articles_success() {
         let articles_repo = setup_with_articles().await;
         let input = ListArticlesInput::default();
 
-        let result = list_articles(&articles_repo, input).await;
+        let result = list_articles(&articles_repo, &tags(&articles_repo), input).await;
 
         assert!(result.is_ok());
         let envelope = result.unwrap();
@@ -98,10 +156,121 @@ mod tests {
             ..Default::default()
         };
 
-        let result = list_articles(&articles_repo, input).await;
+        let result = list_articles(&articles_repo, &tags(&articles_repo), input).await;
 
         assert!(result.is_ok());
         let envelope = result.unwrap();
         assert_eq!(envelope.articles.len(), 2);
     }
+
+    #[tokio::test]
+    async fn test_list_articles_hides_muted_authors() {
+        let users_repo = InMemoryUsersRepository::new();
+        let author = User::new(
+            UserId::random(),
+            Email::parse("author@example.com").unwrap(),
+            Username::new("author").unwrap(),
+            PasswordHash::new("hash").unwrap(),
+            Utc::now(),
+        );
+        users_repo.create_user(author.clone()).await.unwrap();
+        let articles_repo = InMemoryArticlesRepository::new(users_repo.clone());
+        let draft = ArticleDraft::new("Loud", "Description", "Body", TagList::default()).unwrap();
+        let article = Article::publish(ArticleId::random(), author.id, draft, Utc::now()).unwrap();
+        articles_repo.create_article(article).await.unwrap();
+
+        let viewer_id = UserId::random();
+        users_repo.mute_user(viewer_id, author.id).await.unwrap();
+
+        let muted = list_articles(
+            &articles_repo,
+            &tags(&articles_repo),
+            ListArticlesInput {
+                viewer_id: Some(viewer_id),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+        let anonymous = list_articles(&articles_repo, &tags(&articles_repo), ListArticlesInput::default())
+            .await
+            .unwrap();
+
+        assert_eq!(muted.articles_count, 0);
+        assert_eq!(anonymous.articles_count, 1);
+    }
+
+    #[tokio::test]
+    async fn test_list_articles_pages_by_cursor() {
+        let articles_repo = setup_with_articles().await;
+        let page = |cursor: Option<String>| ListArticlesInput {
+            limit: Some(2),
+            cursor,
+            ..Default::default()
+        };
+
+        let first = list_articles(&articles_repo, &tags(&articles_repo), page(None)).await.unwrap();
+        assert!(first.prev_cursor.is_none());
+        let mut seen: Vec<_> = first.articles.iter().map(|a| a.slug.clone()).collect();
+        let mut next = first.next_cursor;
+        let mut last = first;
+        while let Some(cursor) = next {
+            last = list_articles(&articles_repo, &tags(&articles_repo), page(Some(cursor.encode()))).await.unwrap();
+            seen.extend(last.articles.iter().map(|a| a.slug.clone()));
+            next = last.next_cursor;
+        }
+        assert_eq!(seen.len(), 5);
+        assert_eq!(last.articles.len(), 1);
+
+        // Going back from the last page returns the one before it
+        let back = last.prev_cursor.unwrap().encode();
+        let previous = list_articles(&articles_repo, &tags(&articles_repo), page(Some(back))).await.unwrap();
+        let slugs: Vec<_> = previous.articles.iter().map(|a| a.slug.clone()).collect();
+        assert_eq!(slugs, seen[2..4]);
+        assert!(previous.prev_cursor.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_list_articles_resolves_tag_aliases() {
+        let articles_repo = setup_with_articles().await;
+        let tags_repo = tags(&articles_repo);
+        let rust = Tag::new("rust").unwrap();
+        tags_repo.merge_tags(&Tag::new("rs").unwrap(), &rust).await.unwrap();
+
+        let tagged = list_articles(
+            &articles_repo,
+            &tags_repo,
+            ListArticlesInput {
+                tags: vec!["RS".into(), "rust".into()],
+                tag_match: TagMatch::All,
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+        let excluded = list_articles(
+            &articles_repo,
+            &tags_repo,
+            ListArticlesInput {
+                excluded_tags: vec!["rs".into()],
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(tagged.articles_count, 5);
+        assert_eq!(excluded.articles_count, 0);
+    }
+
+    #[tokio::test]
+    async fn test_list_articles_rejects_malformed_cursor() {
+        let articles_repo = setup_with_articles().await;
+        let input = ListArticlesInput {
+            cursor: Some("bogus".into()),
+            ..Default::default()
+        };
+        let result = list_articles(&articles_repo, &tags(&articles_repo), input).await;
+        assert_eq!(result.unwrap_err(), crate::DomainError::InvalidCursor);
+    }
 }
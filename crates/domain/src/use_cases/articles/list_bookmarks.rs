@@ -0,0 +1,139 @@
+//! List bookmarks use case
+
+use crate::{
+    BookmarkFilters, BookmarksEnvelope, DomainError, DomainResult, Pagination, UserId,
+    repositories::ArticlesRepository,
+};
+
+/// Input for listing the current user's bookmarks
+#[derive(Debug, Clone, Default)]
+pub struct ListBookmarksInput {
+    pub folder: Option<String>,
+    pub limit: Option<u32>,
+    pub offset: Option<u32>,
+}
+
+/// List the current user's reading list
+///
+/// # Business Rules
+/// - Only the user's own bookmarks, most recently saved first
+/// - Articles the user can no longer see are left out
+/// - A folder narrows the list to bookmarks filed there; a blank folder is
+///   no filter
+/// - Pagination is applied with default limits
+pub async fn list_bookmarks<A>(
+    articles_repo: &A,
+    user_id: UserId,
+    input: ListBookmarksInput,
+) -> DomainResult<BookmarksEnvelope>
+where
+    A: ArticlesRepository,
+{
+    let filters = BookmarkFilters {
+        folder: input
+            .folder
+            .map(|f| f.trim().to_owned())
+            .filter(|f| !f.is_empty()),
+        pagination: Pagination::new(input.limit, input.offset)?,
+    };
+
+    articles_repo
+        .list_bookmarks(user_id, filters)
+        .await
+        .map_err(|e| DomainError::Database {
+            message: e.to_string(),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::{InMemoryArticlesRepository, InMemoryUsersRepository, UsersRepository};
+    use crate::{Article, ArticleDraft, ArticleId, Bookmark, Email, PasswordHash, Publication, TagList, User, Username};
+    use chrono::{Duration, Utc};
+
+    /// Articles "First" and "Second", with the reader's bookmarks on both and
+    /// someone else's on "First"
+    async fn setup() -> (InMemoryArticlesRepository, UserId, Vec<Article>) {
+        let users_repo = InMemoryUsersRepository::new();
+        let articles_repo = InMemoryArticlesRepository::new(users_repo.clone());
+        let author = User::new(
+            UserId::random(),
+            Email::parse("author@example.com").unwrap(),
+            Username::new("author").unwrap(),
+            PasswordHash::new("hash").unwrap(),
+            Utc::now(),
+        );
+        users_repo.create_user(author.clone()).await.unwrap();
+
+        let reader = UserId::random();
+        let now = Utc::now();
+        let mut articles = Vec::new();
+        for (n, (title, folder)) in [("First", Some("later")), ("Second", None)].into_iter().enumerate() {
+            let draft = ArticleDraft::new(title, "Description", "Body", TagList::default()).unwrap();
+            let article = Article::publish(ArticleId::random(), author.id, draft, now).unwrap();
+            let article = articles_repo.create_article(article).await.unwrap();
+            let saved_at = now + Duration::minutes(n as i64);
+            let bookmark = Bookmark::new(reader, article.id, None, folder.map(str::to_owned), saved_at).unwrap();
+            articles_repo.bookmark_article(bookmark).await.unwrap();
+            articles.push(article);
+        }
+        let bookmark = Bookmark::new(UserId::random(), articles[0].id, None, None, now).unwrap();
+        articles_repo.bookmark_article(bookmark).await.unwrap();
+
+        (articles_repo, reader, articles)
+    }
+
+    async fn titles(articles_repo: &InMemoryArticlesRepository, user_id: UserId, input: ListBookmarksInput) -> Vec<String> {
+        let envelope = list_bookmarks(articles_repo, user_id, input).await.unwrap();
+        envelope.bookmarks.into_iter().map(|b| b.article.title).collect()
+    }
+
+    #[tokio::test]
+    async fn test_list_bookmarks_own_newest_first() {
+        let (articles_repo, reader, _) = setup().await;
+
+        let envelope = list_bookmarks(&articles_repo, reader, ListBookmarksInput::default()).await.unwrap();
+
+        assert_eq!(envelope.bookmarks_count, 2);
+        let titles: Vec<&str> = envelope.bookmarks.iter().map(|b| b.article.title.as_str()).collect();
+        assert_eq!(titles, ["Second", "First"]);
+        assert!(envelope.bookmarks.iter().all(|b| b.article.bookmarked));
+    }
+
+    #[tokio::test]
+    async fn test_list_bookmarks_by_folder() {
+        let (articles_repo, reader, _) = setup().await;
+        let folder = |folder: &str| ListBookmarksInput {
+            folder: Some(folder.to_owned()),
+            ..Default::default()
+        };
+
+        assert_eq!(titles(&articles_repo, reader, folder(" later ")).await, ["First"]);
+        assert!(titles(&articles_repo, reader, folder("elsewhere")).await.is_empty());
+        assert_eq!(titles(&articles_repo, reader, folder(" ")).await.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_list_bookmarks_leaves_out_unpublished() {
+        let (articles_repo, reader, mut articles) = setup().await;
+        articles[1].set_publication(Publication::Draft, Utc::now());
+        articles_repo.update_article(articles[1].clone()).await.unwrap();
+
+        assert_eq!(titles(&articles_repo, reader, ListBookmarksInput::default()).await, ["First"]);
+    }
+
+    #[tokio::test]
+    async fn test_list_bookmarks_limit_out_of_range() {
+        let users_repo = InMemoryUsersRepository::new();
+        let articles_repo = InMemoryArticlesRepository::new(users_repo);
+        let input = ListBookmarksInput {
+            limit: Some(0),
+            ..Default::default()
+        };
+
+        let result = list_bookmarks(&articles_repo, UserId::random(), input).await;
+
+        assert!(matches!(result, Err(DomainError::LimitOutOfRange)));
+    }
+}
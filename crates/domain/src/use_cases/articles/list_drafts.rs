@@ -0,0 +1,73 @@
+//! List drafts use case
+
+use crate::{
+    ArticlesEnvelope, DomainError, DomainResult, Pagination, UserId,
+    repositories::ArticlesRepository,
+};
+
+/// List the current user's unpublished articles
+///
+/// # Business Rules
+/// - Includes drafts and articles scheduled for later
+/// - Pagination is applied with default limits
+/// - Articles are returned most recently edited first
+pub async fn list_drafts<A>(
+    articles_repo: &A,
+    author_id: UserId,
+    limit: Option<u32>,
+    offset: Option<u32>,
+) -> DomainResult<ArticlesEnvelope>
+where
+    A: ArticlesRepository,
+{
+    let pagination = Pagination::new(limit, offset)?;
+
+    articles_repo
+        .list_drafts(author_id, pagination)
+        .await
+        .map_err(|e| DomainError::Database {
+            message: e.to_string(),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::{InMemoryArticlesRepository, InMemoryUsersRepository, UsersRepository};
+    use crate::{Article, ArticleDraft, ArticleId, Email, PasswordHash, Publication, TagList, User, Username};
+    use chrono::Utc;
+
+    #[tokio::test]
+    async fn test_list_drafts_only_unpublished() {
+        let users_repo = InMemoryUsersRepository::new();
+        let articles_repo = InMemoryArticlesRepository::new(users_repo.clone());
+        let author = User::new(
+            UserId::random(),
+            Email::parse("author@example.com").unwrap(),
+            Username::new("author").unwrap(),
+            PasswordHash::new("hash").unwrap(),
+            Utc::now(),
+        );
+        users_repo.create_user(author.clone()).await.unwrap();
+
+        let now = Utc::now();
+        let publications = [
+            Publication::Now,
+            Publication::Draft,
+            Publication::At(now + chrono::Duration::hours(1)),
+        ];
+        for (i, publication) in publications.into_iter().enumerate() {
+            let draft = ArticleDraft::new(format!("Article {i}"), "Description", "Body", TagList::default()).unwrap();
+            let mut article = Article::publish(ArticleId::random(), author.id, draft, now).unwrap();
+            article.set_publication(publication, now);
+            articles_repo.create_article(article).await.unwrap();
+        }
+
+        let drafts = list_drafts(&articles_repo, author.id, None, None).await.unwrap();
+        assert_eq!(drafts.articles_count, 2);
+        assert!(drafts.articles.iter().all(|a| a.title != "Article 0"));
+
+        let others = list_drafts(&articles_repo, UserId::random(), None, None).await.unwrap();
+        assert_eq!(others.articles_count, 0);
+    }
+}
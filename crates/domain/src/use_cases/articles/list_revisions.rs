@@ -0,0 +1,117 @@
+//! List article revisions use case
+
+use std::collections::HashMap;
+
+use crate::{
+    DomainError, DomainResult, Profile, RevisionsEnvelope, UserId,
+    repositories::{ArticlesRepository, RevisionsRepository, UsersRepository},
+    use_cases::articles::revisions::get_own_article,
+};
+
+/// List the stored revisions of an article
+///
+/// # Business Rules
+/// - Article must exist
+/// - Only the article's authors (owner and co-authors) can see its history
+/// - Revisions are returned newest first
+/// - Articles that were never edited have no revisions yet
+pub async fn list_revisions<U, A, R>(
+    users_repo: &U,
+    articles_repo: &A,
+    revisions_repo: &R,
+    slug: &str,
+    user_id: UserId,
+) -> DomainResult<RevisionsEnvelope>
+where
+    U: UsersRepository,
+    A: ArticlesRepository,
+    R: RevisionsRepository + ?Sized,
+{
+    let article = get_own_article(articles_repo, slug, user_id).await?;
+    let revisions = revisions_repo
+        .list_revisions(article.id)
+        .await
+        .map_err(|e| DomainError::Database {
+            message: e.to_string(),
+        })?;
+
+    let mut profiles: HashMap<UserId, Profile> = HashMap::new();
+    let mut views = Vec::with_capacity(revisions.len());
+    for revision in revisions {
+        let profile = match profiles.get(&revision.author_id) {
+            Some(profile) => profile.clone(),
+            None => {
+                let author = users_repo
+                    .get_user_by_id(revision.author_id)
+                    .await
+                    .map_err(|_| DomainError::NotFound { entity: "author" })?
+                    .ok_or(DomainError::NotFound { entity: "author" })?;
+                let profile = author.to_profile(false);
+                profiles.insert(revision.author_id, profile.clone());
+                profile
+            }
+        };
+        views.push(revision.to_view(profile));
+    }
+
+    Ok(views.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::{InMemoryArticlesRepository, InMemoryRevisionsRepository, InMemoryUsersRepository};
+    use crate::use_cases::{UpdateArticleInput, update_article};
+    use crate::{Article, ArticleDraft, ArticleId, Email, PasswordHash, SlugPolicy, TagList, User, Username};
+    use chrono::Utc;
+
+    #[tokio::test]
+    async fn test_list_revisions_author_only() {
+        let users_repo = InMemoryUsersRepository::new();
+        let articles_repo = InMemoryArticlesRepository::new(users_repo.clone());
+        let revisions_repo = InMemoryRevisionsRepository::new();
+        let author = User::new(
+            UserId::random(),
+            Email::parse("author@example.com").unwrap(),
+            Username::new("author").unwrap(),
+            PasswordHash::new("hash").unwrap(),
+            Utc::now(),
+        );
+        users_repo.create_user(author.clone()).await.unwrap();
+        let draft = ArticleDraft::new("Test Article", "Description", "Body", TagList::default()).unwrap();
+        let article = Article::publish(ArticleId::random(), author.id, draft, Utc::now()).unwrap();
+        let article = articles_repo.create_article(article).await.unwrap();
+
+        let empty = list_revisions(&users_repo, &articles_repo, &revisions_repo, "test-article", author.id)
+            .await
+            .unwrap();
+        assert_eq!(empty.revisions_count, 0);
+
+        let input = UpdateArticleInput {
+            slug: article.slug.as_str().to_owned(),
+            description: Some("Better description".to_string()),
+            ..Default::default()
+        };
+        update_article(
+            &users_repo,
+            &articles_repo,
+            &revisions_repo,
+            author.id,
+            input,
+            &SlugPolicy::default(),
+            Utc::now(),
+        )
+        .await
+        .unwrap();
+
+        let listed = list_revisions(&users_repo, &articles_repo, &revisions_repo, "test-article", author.id)
+            .await
+            .unwrap();
+        assert_eq!(listed.revisions_count, 2);
+        assert_eq!(listed.revisions[0].description, "Better description");
+        assert_eq!(listed.revisions[0].author.username.as_str(), "author");
+
+        let result = list_revisions(&users_repo, &articles_repo, &revisions_repo, "test-article", UserId::random()).await;
+        assert!(matches!(result, Err(DomainError::UnauthorizedAction)));
+    }
+}
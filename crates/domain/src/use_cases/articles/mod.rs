@@ -2,20 +2,60 @@
 //!
 //! All business logic for article operations lives here.
 
+mod accept_coauthor_invitation;
+mod add_article_reaction;
+mod article_feed;
+mod bookmark_article;
 mod create_article;
 mod delete_article;
+mod diff_revisions;
 mod favorite_article;
 mod feed_articles;
 mod get_article;
+mod get_sitemap;
+mod invite_coauthor;
 mod list_articles;
+mod list_bookmarks;
+mod list_drafts;
+mod list_revisions;
+mod publish_article;
+mod publish_due_articles;
+mod related_articles;
+mod remove_article_reaction;
+mod remove_coauthor;
+mod restore_revision;
+mod revisions;
+mod search_articles;
+mod slugs;
+mod unbookmark_article;
 mod unfavorite_article;
+mod unpublish_article;
 mod update_article;
 
+pub use accept_coauthor_invitation::*;
+pub use add_article_reaction::*;
+pub use article_feed::*;
+pub use bookmark_article::*;
 pub use create_article::*;
 pub use delete_article::*;
+pub use diff_revisions::*;
 pub use favorite_article::*;
 pub use feed_articles::*;
 pub use get_article::*;
+pub use get_sitemap::*;
+pub use invite_coauthor::*;
 pub use list_articles::*;
+pub use list_bookmarks::*;
+pub use list_drafts::*;
+pub use list_revisions::*;
+pub use publish_article::*;
+pub use publish_due_articles::*;
+pub use related_articles::*;
+pub use remove_article_reaction::*;
+pub use remove_coauthor::*;
+pub use restore_revision::*;
+pub use search_articles::*;
+pub use unbookmark_article::*;
 pub use unfavorite_article::*;
+pub use unpublish_article::*;
 pub use update_article::*;
@@ -0,0 +1,141 @@
+//! Publish article use case
+
+use chrono::{DateTime, Utc};
+
+use crate::{
+    ArticleView, DomainError, DomainResult, Publication, UserId,
+    repositories::{ArticlesRepository, UsersRepository},
+    use_cases::articles::get_article::build_article_view,
+};
+
+/// Publish a draft, or schedule it for later
+///
+/// # Business Rules
+/// - Article must exist
+/// - Only the article's authors (owner and co-authors) can publish it
+/// - Without `publish_at`, or with a time already passed, the article goes
+///   live now; otherwise it is scheduled for that time
+/// - Publishing an article that is already live changes nothing
+pub async fn publish_article<U, A>(
+    users_repo: &U,
+    articles_repo: &A,
+    slug: &str,
+    author_id: UserId,
+    publish_at: Option<DateTime<Utc>>,
+    now: DateTime<Utc>,
+) -> DomainResult<ArticleView>
+where
+    U: UsersRepository,
+    A: ArticlesRepository,
+{
+    let publication = match publish_at {
+        Some(at) => Publication::At(at),
+        None => Publication::Now,
+    };
+    change_publication(users_repo, articles_repo, slug, author_id, publication, now).await
+}
+
+/// Moves an article one of its authors is editing to another lifecycle stage
+/// and returns its view
+pub(crate) async fn change_publication<U, A>(
+    users_repo: &U,
+    articles_repo: &A,
+    slug: &str,
+    author_id: UserId,
+    publication: Publication,
+    now: DateTime<Utc>,
+) -> DomainResult<ArticleView>
+where
+    U: UsersRepository,
+    A: ArticlesRepository,
+{
+    let mut article = articles_repo
+        .get_article_by_slug(slug)
+        .await
+        .map_err(|_| DomainError::NotFound { entity: "article" })?
+        .ok_or(DomainError::NotFound { entity: "article" })?;
+
+    if !article.can_edit(&author_id) {
+        return Err(DomainError::UnauthorizedAction);
+    }
+
+    article.set_publication(publication, now);
+    let updated = articles_repo
+        .update_article(article)
+        .await
+        .map_err(|_| DomainError::NotFound { entity: "article" })?;
+
+    build_article_view(users_repo, articles_repo, &updated, Some(author_id)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::{InMemoryArticlesRepository, InMemoryUsersRepository};
+    use crate::{Article, ArticleDraft, ArticleId, ArticleStatus, Email, PasswordHash, TagList, User, Username};
+
+    async fn setup() -> (InMemoryUsersRepository, InMemoryArticlesRepository, User, Article) {
+        let users_repo = InMemoryUsersRepository::new();
+        let articles_repo = InMemoryArticlesRepository::new(users_repo.clone());
+
+        let author = User::new(
+            UserId::random(),
+            Email::parse("author@example.com").unwrap(),
+            Username::new("author").unwrap(),
+            PasswordHash::new("hash").unwrap(),
+            Utc::now(),
+        );
+        users_repo.create_user(author.clone()).await.unwrap();
+
+        let draft = ArticleDraft::new("Test Article", "Description", "Body", TagList::default()).unwrap();
+        let mut article = Article::publish(ArticleId::random(), author.id, draft, Utc::now()).unwrap();
+        article.set_publication(Publication::Draft, Utc::now());
+        let article = articles_repo.create_article(article).await.unwrap();
+
+        (users_repo, articles_repo, author, article)
+    }
+
+    #[tokio::test]
+    async fn test_publish_article_now() {
+        let (users_repo, articles_repo, author, article) = setup().await;
+        let now = Utc::now();
+
+        let view = publish_article(&users_repo, &articles_repo, article.slug.as_str(), author.id, None, now)
+            .await
+            .unwrap();
+
+        assert_eq!(view.status, ArticleStatus::Published);
+        assert_eq!(view.published_at, Some(now));
+    }
+
+    #[tokio::test]
+    async fn test_publish_article_schedules_future_time() {
+        let (users_repo, articles_repo, author, article) = setup().await;
+        let now = Utc::now();
+        let at = now + chrono::Duration::days(1);
+
+        let view = publish_article(&users_repo, &articles_repo, article.slug.as_str(), author.id, Some(at), now)
+            .await
+            .unwrap();
+
+        assert_eq!(view.status, ArticleStatus::Scheduled);
+        assert_eq!(view.published_at, Some(at));
+    }
+
+    #[tokio::test]
+    async fn test_publish_article_not_author() {
+        let (users_repo, articles_repo, _, article) = setup().await;
+
+        let result = publish_article(
+            &users_repo,
+            &articles_repo,
+            article.slug.as_str(),
+            UserId::random(),
+            None,
+            Utc::now(),
+        )
+        .await;
+
+        assert!(matches!(result, Err(DomainError::UnauthorizedAction)));
+    }
+}
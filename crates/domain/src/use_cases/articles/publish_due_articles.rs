@@ -0,0 +1,71 @@
+//! Publish scheduled articles use case
+
+use chrono::{DateTime, Utc};
+
+use crate::{DomainError, DomainEvent, DomainResult, repositories::ArticlesRepository};
+
+/// Publish every scheduled article whose time has come
+///
+/// # Business Rules
+/// - Only scheduled articles with `published_at` at or before `now` change
+/// - They keep the time they were scheduled for as `published_at`
+/// - One `ArticlePublished` event is returned per article
+pub async fn publish_due_articles<A>(articles_repo: &A, now: DateTime<Utc>) -> DomainResult<Vec<DomainEvent>>
+where
+    A: ArticlesRepository,
+{
+    let published = articles_repo
+        .publish_due_articles(now)
+        .await
+        .map_err(|e| DomainError::Database {
+            message: e.to_string(),
+        })?;
+
+    Ok(published
+        .into_iter()
+        .map(|article| DomainEvent::ArticlePublished {
+            article_id: article.id,
+            author_id: article.author_id,
+            published_at: article.published_at.unwrap_or(now),
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::{InMemoryArticlesRepository, InMemoryUsersRepository};
+    use crate::{Article, ArticleDraft, ArticleId, ArticleStatus, Publication, TagList, UserId};
+
+    #[tokio::test]
+    async fn test_publish_due_articles_only_flips_due_ones() {
+        let users_repo = InMemoryUsersRepository::new();
+        let articles_repo = InMemoryArticlesRepository::new(users_repo);
+        let now = Utc::now();
+        let soon = now + chrono::Duration::minutes(5);
+        let later = now + chrono::Duration::days(1);
+
+        let mut ids = Vec::new();
+        for (i, at) in [soon, later].into_iter().enumerate() {
+            let draft = ArticleDraft::new(format!("Article {i}"), "Description", "Body", TagList::default()).unwrap();
+            let mut article = Article::publish(ArticleId::random(), UserId::random(), draft, now).unwrap();
+            article.set_publication(Publication::At(at), now);
+            ids.push(articles_repo.create_article(article).await.unwrap().id);
+        }
+
+        let events = publish_due_articles(&articles_repo, now + chrono::Duration::minutes(10))
+            .await
+            .unwrap();
+
+        assert_eq!(events.len(), 1);
+        assert!(matches!(
+            events[0],
+            DomainEvent::ArticlePublished { article_id, published_at, .. }
+                if article_id == ids[0] && published_at == soon
+        ));
+        let first = articles_repo.get_article_by_id(ids[0]).await.unwrap().unwrap();
+        assert_eq!(first.status, ArticleStatus::Published);
+        let second = articles_repo.get_article_by_id(ids[1]).await.unwrap().unwrap();
+        assert_eq!(second.status, ArticleStatus::Scheduled);
+    }
+}
@@ -0,0 +1,158 @@
+//! Related articles use case
+
+use chrono::{DateTime, Utc};
+
+use crate::{
+    ArticlesEnvelope, DEFAULT_RELATED_LIMIT, DomainError, DomainResult, Pagination, RelatedScoring,
+    UserId,
+    repositories::{ArticlesRepository, UsersRepository},
+};
+
+use super::get_article::find_visible_article;
+
+/// Articles to read after the one at `slug`
+///
+/// # Business Rules
+/// - The article must be one the viewer can read, found as `get_article`
+///   finds it
+/// - Candidates are published articles sharing a tag, the owner or a
+///   favoriting user with it, scored by `RelatedScoring` as of `now`: rare
+///   shared tags weigh more than common ones, and newer articles more than
+///   older ones
+/// - Articles the viewer owns or co-authors, and those by authors the viewer
+///   muted, who blocked the viewer or who are deactivated, are left out
+/// - Five are returned unless another limit is asked for, within the article
+///   list bounds
+pub async fn related_articles<U, A>(
+    users_repo: &U,
+    articles_repo: &A,
+    slug: &str,
+    viewer_id: Option<UserId>,
+    limit: Option<u32>,
+    now: DateTime<Utc>,
+) -> DomainResult<ArticlesEnvelope>
+where
+    U: UsersRepository,
+    A: ArticlesRepository,
+{
+    let limit = Pagination::new(Some(limit.unwrap_or(DEFAULT_RELATED_LIMIT)), None)?.limit();
+    let article = find_visible_article(users_repo, articles_repo, slug, viewer_id).await?;
+
+    let related = articles_repo
+        .related_articles(article.id, viewer_id, &RelatedScoring::default(), limit, now)
+        .await
+        .map_err(|e| DomainError::Database {
+            message: e.to_string(),
+        })?;
+
+    Ok(ArticlesEnvelope::from(related))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::{InMemoryArticlesRepository, InMemoryUsersRepository};
+    use crate::{Article, ArticleDraft, ArticleId, Coauthor, Email, PasswordHash, Publication, TagList, User, Username};
+
+    async fn setup() -> (InMemoryUsersRepository, InMemoryArticlesRepository, User, Vec<Article>) {
+        let users_repo = InMemoryUsersRepository::new();
+        let articles_repo = InMemoryArticlesRepository::new(users_repo.clone());
+        let author = User::new(
+            UserId::random(),
+            Email::parse("author@example.com").unwrap(),
+            Username::new("author").unwrap(),
+            PasswordHash::new("hash").unwrap(),
+            Utc::now(),
+        );
+        users_repo.create_user(author.clone()).await.unwrap();
+
+        let mut articles = Vec::new();
+        for title in ["First", "Second", "Third"] {
+            let draft = ArticleDraft::new(title, "Description", "Body", TagList::new(["rust"]).unwrap()).unwrap();
+            let article = Article::publish(ArticleId::random(), author.id, draft, Utc::now()).unwrap();
+            articles.push(articles_repo.create_article(article).await.unwrap());
+        }
+        (users_repo, articles_repo, author, articles)
+    }
+
+    #[tokio::test]
+    async fn test_related_articles_skips_the_article_itself() {
+        let (users_repo, articles_repo, _author, articles) = setup().await;
+
+        let envelope = related_articles(&users_repo, &articles_repo, "first", None, None, Utc::now())
+            .await
+            .unwrap();
+
+        assert_eq!(envelope.articles_count, 2);
+        assert!(envelope.articles.iter().all(|a| a.slug != articles[0].slug));
+
+        let one = related_articles(&users_repo, &articles_repo, "first", None, Some(1), Utc::now())
+            .await
+            .unwrap();
+        assert_eq!(one.articles_count, 1);
+    }
+
+    #[tokio::test]
+    async fn test_related_articles_of_hidden_article_not_found() {
+        let (users_repo, articles_repo, author, mut articles) = setup().await;
+        articles[0].set_publication(Publication::Draft, Utc::now());
+        articles_repo.update_article(articles[0].clone()).await.unwrap();
+
+        let result = related_articles(&users_repo, &articles_repo, "first", None, None, Utc::now()).await;
+        assert!(matches!(result, Err(DomainError::NotFound { entity: "article" })));
+
+        // The author reads their draft, but is never recommended their own work
+        let envelope = related_articles(&users_repo, &articles_repo, "first", Some(author.id), None, Utc::now())
+            .await
+            .unwrap();
+        assert_eq!(envelope.articles_count, 0);
+
+        let result = related_articles(&users_repo, &articles_repo, "second", None, Some(0), Utc::now()).await;
+        assert_eq!(result.unwrap_err(), DomainError::LimitOutOfRange);
+    }
+
+    async fn titles(
+        users_repo: &InMemoryUsersRepository,
+        articles_repo: &InMemoryArticlesRepository,
+        viewer_id: Option<UserId>,
+    ) -> Vec<String> {
+        let envelope = related_articles(users_repo, articles_repo, "first", viewer_id, None, Utc::now())
+            .await
+            .unwrap();
+        envelope.articles.into_iter().map(|a| a.title).collect()
+    }
+
+    #[tokio::test]
+    async fn test_related_articles_leaves_out_muted_and_deactivated_authors() {
+        let (users_repo, articles_repo, _author, _) = setup().await;
+        let loud = User::new(
+            UserId::random(),
+            Email::parse("loud@example.com").unwrap(),
+            Username::new("loud").unwrap(),
+            PasswordHash::new("hash").unwrap(),
+            Utc::now(),
+        );
+        users_repo.create_user(loud.clone()).await.unwrap();
+        let draft = ArticleDraft::new("Loud", "Description", "Body", TagList::new(["rust"]).unwrap()).unwrap();
+        let article = Article::publish(ArticleId::random(), loud.id, draft, Utc::now()).unwrap();
+        articles_repo.create_article(article).await.unwrap();
+        let viewer_id = UserId::random();
+        users_repo.mute_user(viewer_id, loud.id).await.unwrap();
+
+        assert!(titles(&users_repo, &articles_repo, None).await.contains(&"Loud".to_owned()));
+        assert!(!titles(&users_repo, &articles_repo, Some(viewer_id)).await.contains(&"Loud".to_owned()));
+
+        users_repo.deactivate_user(loud.id, Utc::now()).await.unwrap();
+        assert!(!titles(&users_repo, &articles_repo, None).await.contains(&"Loud".to_owned()));
+    }
+
+    #[tokio::test]
+    async fn test_related_articles_leaves_out_coauthored_articles() {
+        let (users_repo, articles_repo, _author, articles) = setup().await;
+        let coauthor = UserId::random();
+        articles_repo.invite_coauthor(Coauthor::invite(articles[1].id, coauthor, Utc::now())).await.unwrap();
+        articles_repo.accept_coauthor(articles[1].id, coauthor, Utc::now()).await.unwrap();
+
+        assert_eq!(titles(&users_repo, &articles_repo, Some(coauthor)).await, ["Third"]);
+    }
+}
@@ -0,0 +1,87 @@
+//! Remove article reaction use case
+
+use crate::{
+    ArticleView, DomainError, DomainResult, ReactionKind, UserId,
+    repositories::{ArticlesRepository, UsersRepository},
+    use_cases::articles::get_article::build_article_view,
+};
+
+/// Take back a reaction to an article
+///
+/// # Business Rules
+/// - Article must exist and be visible to the user
+/// - Kinds no longer offered can still be taken back
+/// - Removing a reaction the user did not leave is idempotent
+pub async fn remove_article_reaction<U, A>(
+    users_repo: &U,
+    articles_repo: &A,
+    slug: &str,
+    kind: &str,
+    user_id: UserId,
+) -> DomainResult<ArticleView>
+where
+    U: UsersRepository,
+    A: ArticlesRepository,
+{
+    let kind = ReactionKind::new(kind)?;
+
+    let article = articles_repo
+        .get_article_by_slug(slug)
+        .await
+        .map_err(|_| DomainError::NotFound { entity: "article" })?
+        .ok_or(DomainError::NotFound { entity: "article" })?;
+    if !article.is_visible_to(Some(user_id)) {
+        return Err(DomainError::NotFound { entity: "article" });
+    }
+
+    articles_repo
+        .remove_article_reaction(article.id, user_id, &kind)
+        .await
+        .map_err(|e| DomainError::Database {
+            message: e.to_string(),
+        })?;
+
+    build_article_view(users_repo, articles_repo, &article, Some(user_id)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::{InMemoryArticlesRepository, InMemoryUsersRepository};
+    use crate::{Article, ArticleDraft, ArticleId, Email, PasswordHash, TagList, User, Username};
+    use chrono::Utc;
+
+    #[tokio::test]
+    async fn test_remove_article_reaction_keeps_other_kinds() {
+        let users_repo = InMemoryUsersRepository::new();
+        let articles_repo = InMemoryArticlesRepository::new(users_repo.clone());
+        let author = User::new(
+            UserId::random(),
+            Email::parse("author@example.com").unwrap(),
+            Username::new("author").unwrap(),
+            PasswordHash::new("hash").unwrap(),
+            Utc::now(),
+        );
+        users_repo.create_user(author.clone()).await.unwrap();
+        let draft = ArticleDraft::new("Test Article", "Description", "Body", TagList::default()).unwrap();
+        let article = Article::publish(ArticleId::random(), author.id, draft, Utc::now()).unwrap();
+        articles_repo.create_article(article.clone()).await.unwrap();
+        for kind in ["heart", "tada"] {
+            let kind = ReactionKind::new(kind).unwrap();
+            articles_repo.add_article_reaction(article.id, author.id, &kind).await.unwrap();
+        }
+
+        let view = remove_article_reaction(&users_repo, &articles_repo, "test-article", "heart", author.id)
+            .await
+            .unwrap();
+
+        let kinds: Vec<&str> = view.reactions.iter().map(|r| r.kind.as_str()).collect();
+        assert_eq!(kinds, ["tada"]);
+
+        // Taking back a reaction never left changes nothing
+        let view = remove_article_reaction(&users_repo, &articles_repo, "test-article", "thinking", author.id)
+            .await
+            .unwrap();
+        assert_eq!(view.reactions.len(), 1);
+    }
+}
@@ -0,0 +1,107 @@
+//! Remove co-author use case
+
+use crate::{
+    DomainError, DomainResult, UserId,
+    repositories::{ArticlesRepository, UsersRepository},
+};
+
+/// Withdraw an invitation or remove a co-author from an article
+///
+/// # Business Rules
+/// - Article must exist and the named user must be invited to it or
+///   co-authoring it
+/// - The owner can remove anyone; anyone else can only remove themselves,
+///   which is how invitations are declined and co-authors step down
+pub async fn remove_coauthor<U, A>(
+    users_repo: &U,
+    articles_repo: &A,
+    slug: &str,
+    user_id: UserId,
+    username: &str,
+) -> DomainResult<()>
+where
+    U: UsersRepository,
+    A: ArticlesRepository,
+{
+    let db_error = |e: anyhow::Error| DomainError::Database {
+        message: e.to_string(),
+    };
+
+    let article = articles_repo
+        .get_article_by_slug(slug)
+        .await
+        .map_err(db_error)?
+        .ok_or(DomainError::NotFound { entity: "article" })?;
+    let coauthor = users_repo
+        .get_user_by_username(username)
+        .await
+        .map_err(db_error)?
+        .ok_or(DomainError::NotFound { entity: "coauthor" })?;
+    articles_repo
+        .get_coauthor(article.id, coauthor.id)
+        .await
+        .map_err(db_error)?
+        .ok_or(DomainError::NotFound { entity: "coauthor" })?;
+
+    if !article.authored_by(&user_id) && coauthor.id != user_id {
+        return Err(DomainError::UnauthorizedAction);
+    }
+
+    articles_repo
+        .remove_coauthor(article.id, coauthor.id)
+        .await
+        .map_err(db_error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::{InMemoryArticlesRepository, InMemoryUsersRepository};
+    use crate::use_cases::articles::{accept_coauthor_invitation, invite_coauthor};
+    use crate::{Article, ArticleDraft, ArticleId, Email, PasswordHash, TagList, User, Username};
+    use chrono::Utc;
+
+    #[tokio::test]
+    async fn test_remove_coauthor() {
+        let users_repo = InMemoryUsersRepository::new();
+        let articles_repo = InMemoryArticlesRepository::new(users_repo.clone());
+        let mut users = Vec::new();
+        for name in ["owner", "first", "second"] {
+            let user = User::new(
+                UserId::random(),
+                Email::parse(format!("{name}@example.com")).unwrap(),
+                Username::new(name).unwrap(),
+                PasswordHash::new("hash").unwrap(),
+                Utc::now(),
+            );
+            users.push(users_repo.create_user(user).await.unwrap());
+        }
+        let (owner, first, second) = (users[0].id, users[1].id, users[2].id);
+        let draft = ArticleDraft::new("Joint Work", "desc", "body", TagList::default()).unwrap();
+        let article = Article::publish(ArticleId::random(), owner, draft, Utc::now()).unwrap();
+        articles_repo.create_article(article).await.unwrap();
+        for (id, name) in [(first, "first"), (second, "second")] {
+            invite_coauthor(&users_repo, &articles_repo, "joint-work", owner, name, Utc::now())
+                .await
+                .unwrap();
+            accept_coauthor_invitation(&users_repo, &articles_repo, "joint-work", id, Utc::now())
+                .await
+                .unwrap();
+        }
+
+        // Co-authors can step down but not remove each other
+        let result = remove_coauthor(&users_repo, &articles_repo, "joint-work", first, "second").await;
+        assert!(matches!(result, Err(DomainError::UnauthorizedAction)));
+        remove_coauthor(&users_repo, &articles_repo, "joint-work", first, "first")
+            .await
+            .unwrap();
+        remove_coauthor(&users_repo, &articles_repo, "joint-work", owner, "second")
+            .await
+            .unwrap();
+
+        let article = articles_repo.get_article_by_slug("joint-work").await.unwrap().unwrap();
+        assert!(article.coauthor_ids.is_empty());
+        let result = remove_coauthor(&users_repo, &articles_repo, "joint-work", owner, "second").await;
+        assert!(matches!(result, Err(DomainError::NotFound { entity: "coauthor" })));
+    }
+}
@@ -0,0 +1,129 @@
+//! Restore article revision use case
+
+use chrono::{DateTime, Utc};
+
+use crate::{
+    ArticleView, DomainResult, RevisionId, SlugPolicy, UserId,
+    repositories::{ArticlesRepository, RevisionsRepository, UsersRepository},
+    use_cases::articles::{
+        revisions::{get_article_revision, get_own_article},
+        update_article::{UpdateArticleInput, update_article},
+    },
+};
+
+/// Input for restoring an article revision
+#[derive(Debug, Clone)]
+pub struct RestoreRevisionInput {
+    pub slug: String,
+    pub revision_id: RevisionId,
+}
+
+/// Bring back the title, description and body of an earlier revision
+///
+/// # Business Rules
+/// - Article and revision must exist, and the revision must belong to the
+///   article
+/// - Only the article's authors (owner and co-authors) can restore a revision
+/// - Restoring is an ordinary edit: it is stored as a new revision, so the
+///   history is never rewritten, and the slug follows the restored title
+/// - Tags and publication status are left as they are
+pub async fn restore_revision<U, A, R>(
+    users_repo: &U,
+    articles_repo: &A,
+    revisions_repo: &R,
+    input: RestoreRevisionInput,
+    user_id: UserId,
+    slug_policy: &SlugPolicy,
+    now: DateTime<Utc>,
+) -> DomainResult<ArticleView>
+where
+    U: UsersRepository,
+    A: ArticlesRepository,
+    R: RevisionsRepository + ?Sized,
+{
+    let article = get_own_article(articles_repo, &input.slug, user_id).await?;
+    let revision = get_article_revision(revisions_repo, &article, input.revision_id).await?;
+
+    let input = UpdateArticleInput {
+        slug: article.slug.as_str().to_owned(),
+        title: Some(revision.title),
+        description: Some(revision.description),
+        body: Some(revision.body),
+        ..Default::default()
+    };
+    update_article(
+        users_repo,
+        articles_repo,
+        revisions_repo,
+        user_id,
+        input,
+        slug_policy,
+        now,
+    )
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::{InMemoryArticlesRepository, InMemoryRevisionsRepository, InMemoryUsersRepository};
+    use crate::{Article, ArticleDraft, ArticleId, Email, PasswordHash, TagList, User, Username};
+
+    #[tokio::test]
+    async fn test_restore_revision_adds_new_version() {
+        let users_repo = InMemoryUsersRepository::new();
+        let articles_repo = InMemoryArticlesRepository::new(users_repo.clone());
+        let revisions_repo = InMemoryRevisionsRepository::new();
+        let author = User::new(
+            UserId::random(),
+            Email::parse("author@example.com").unwrap(),
+            Username::new("author").unwrap(),
+            PasswordHash::new("hash").unwrap(),
+            Utc::now(),
+        );
+        users_repo.create_user(author.clone()).await.unwrap();
+        let draft = ArticleDraft::new("Original Title", "Description", "Body", TagList::default()).unwrap();
+        let article = Article::publish(ArticleId::random(), author.id, draft, Utc::now()).unwrap();
+        let article = articles_repo.create_article(article).await.unwrap();
+
+        let input = UpdateArticleInput {
+            slug: article.slug.as_str().to_owned(),
+            title: Some("Rewritten".to_string()),
+            body: Some("New body".to_string()),
+            ..Default::default()
+        };
+        update_article(
+            &users_repo,
+            &articles_repo,
+            &revisions_repo,
+            author.id,
+            input,
+            &SlugPolicy::default(),
+            Utc::now(),
+        )
+        .await
+        .unwrap();
+        let original = revisions_repo.list_revisions(article.id).await.unwrap()[1].clone();
+
+        let view = restore_revision(
+            &users_repo,
+            &articles_repo,
+            &revisions_repo,
+            RestoreRevisionInput {
+                slug: "rewritten".to_owned(),
+                revision_id: original.id,
+            },
+            author.id,
+            &SlugPolicy::default(),
+            Utc::now(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(view.title, "Original Title");
+        assert_eq!(view.body, "Body");
+        let latest = revisions_repo.latest_revision(article.id).await.unwrap().unwrap();
+        assert_eq!(latest.version, 3);
+        assert_eq!(latest.body, "Body");
+    }
+}
@@ -0,0 +1,102 @@
+//! Revision bookkeeping shared by the article use cases
+
+use chrono::{DateTime, Utc};
+
+use crate::{
+    Article, ArticleRevision, DomainError, DomainResult, RevisionId, UserId,
+    repositories::{ArticlesRepository, RevisionsRepository},
+};
+
+/// Stores the article's new content as the next revision
+///
+/// Articles written before revisions were kept get their previous content
+/// recorded as version 1 first, so the first edit can still be diffed and
+/// undone. Edits that leave title, description and body alone (tags only)
+/// record nothing.
+pub(crate) async fn record_revision<R>(
+    revisions_repo: &R,
+    previous: &Article,
+    updated: &Article,
+    editor_id: UserId,
+    now: DateTime<Utc>,
+) -> DomainResult<()>
+where
+    R: RevisionsRepository + ?Sized,
+{
+    let unchanged = previous.title == updated.title
+        && previous.description == updated.description
+        && previous.body == updated.body;
+    if unchanged {
+        return Ok(());
+    }
+
+    let latest = revisions_repo
+        .latest_revision(updated.id)
+        .await
+        .map_err(database_error)?;
+    let version = match latest {
+        Some(latest) => latest.version,
+        None => {
+            let baseline = ArticleRevision::snapshot(
+                RevisionId::random(),
+                previous,
+                1,
+                previous.author_id,
+                previous.updated_at,
+            );
+            revisions_repo
+                .create_revision(baseline)
+                .await
+                .map_err(database_error)?;
+            1
+        }
+    };
+
+    let revision = ArticleRevision::snapshot(RevisionId::random(), updated, version + 1, editor_id, now);
+    revisions_repo
+        .create_revision(revision)
+        .await
+        .map_err(database_error)?;
+    Ok(())
+}
+
+/// Loads an article whose history only its authors may see
+pub(crate) async fn get_own_article<A>(articles_repo: &A, slug: &str, user_id: UserId) -> DomainResult<Article>
+where
+    A: ArticlesRepository,
+{
+    let article = articles_repo
+        .get_article_by_slug(slug)
+        .await
+        .map_err(|_| DomainError::NotFound { entity: "article" })?
+        .ok_or(DomainError::NotFound { entity: "article" })?;
+
+    if !article.can_edit(&user_id) {
+        return Err(DomainError::UnauthorizedAction);
+    }
+    Ok(article)
+}
+
+/// Loads one of the article's revisions; revisions of other articles are
+/// reported as missing
+pub(crate) async fn get_article_revision<R>(
+    revisions_repo: &R,
+    article: &Article,
+    id: RevisionId,
+) -> DomainResult<ArticleRevision>
+where
+    R: RevisionsRepository + ?Sized,
+{
+    revisions_repo
+        .get_revision(id)
+        .await
+        .map_err(|_| DomainError::NotFound { entity: "revision" })?
+        .filter(|revision| revision.article_id == article.id)
+        .ok_or(DomainError::NotFound { entity: "revision" })
+}
+
+fn database_error(e: anyhow::Error) -> DomainError {
+    DomainError::Database {
+        message: e.to_string(),
+    }
+}
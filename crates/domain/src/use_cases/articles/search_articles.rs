@@ -0,0 +1,102 @@
+//! Search articles use case
+
+use crate::{
+    DomainError, DomainResult, Pagination, SearchFilters, SearchQuery, SearchResultsEnvelope, UserId,
+    repositories::ArticlesRepository,
+};
+
+/// Input for searching articles
+#[derive(Debug, Clone, Default)]
+pub struct SearchArticlesInput {
+    pub query: String,
+    pub limit: Option<u32>,
+    pub offset: Option<u32>,
+    pub viewer_id: Option<UserId>,
+}
+
+/// Full-text search over titles, descriptions and bodies
+///
+/// # Business Rules
+/// - The query needs at least one word; `"quoted words"` match as a phrase
+///   and `word*` as a prefix
+/// - Every term must match; title matches rank above description matches,
+///   which rank above body matches
+/// - Results follow the visibility rules of article lists
+/// - Pagination is applied with default limits
+pub async fn search_articles<A>(articles_repo: &A, input: SearchArticlesInput) -> DomainResult<SearchResultsEnvelope>
+where
+    A: ArticlesRepository,
+{
+    let filters = SearchFilters {
+        query: SearchQuery::parse(&input.query)?,
+        pagination: Pagination::new(input.limit, input.offset)?,
+        viewer: input.viewer_id,
+    };
+
+    articles_repo
+        .search_articles(filters)
+        .await
+        .map_err(|e| DomainError::Database {
+            message: e.to_string(),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::{InMemoryArticlesRepository, InMemoryUsersRepository, UsersRepository};
+    use crate::{Article, ArticleDraft, ArticleId, Email, PasswordHash, Publication, TagList, User, Username};
+    use chrono::Utc;
+
+    #[tokio::test]
+    async fn test_search_articles_ranks_and_paginates() {
+        let users_repo = InMemoryUsersRepository::new();
+        let articles_repo = InMemoryArticlesRepository::new(users_repo.clone());
+        let author = User::new(
+            UserId::random(),
+            Email::parse("author@example.com").unwrap(),
+            Username::new("author").unwrap(),
+            PasswordHash::new("hash").unwrap(),
+            Utc::now(),
+        );
+        users_repo.create_user(author.clone()).await.unwrap();
+
+        let articles = [
+            ("Cooking pasta", "Dinner", "Boil water, mention rust once.", Publication::Now),
+            ("Rust ownership", "Borrowing", "Ownership rules.", Publication::Now),
+            ("Rust draft", "Unfinished", "Rust notes.", Publication::Draft),
+        ];
+        for (title, description, body, publication) in articles {
+            let draft = ArticleDraft::new(title, description, body, TagList::default()).unwrap();
+            let mut article = Article::publish(ArticleId::random(), author.id, draft, Utc::now()).unwrap();
+            article.set_publication(publication, Utc::now());
+            articles_repo.create_article(article).await.unwrap();
+        }
+
+        let input = SearchArticlesInput {
+            query: "rust".to_string(),
+            ..Default::default()
+        };
+        let results = search_articles(&articles_repo, input).await.unwrap();
+        assert_eq!(results.articles_count, 2);
+        assert_eq!(results.articles[0].article.title, "Rust ownership");
+        assert_eq!(results.articles[1].highlight, "Boil water, mention <mark>rust</mark> once");
+
+        let input = SearchArticlesInput {
+            query: "rust".to_string(),
+            limit: Some(1),
+            offset: Some(1),
+            viewer_id: Some(author.id),
+        };
+        let page = search_articles(&articles_repo, input).await.unwrap();
+        assert_eq!(page.articles_count, 3);
+        assert_eq!(page.articles.len(), 1);
+
+        let input = SearchArticlesInput {
+            query: "***".to_string(),
+            ..Default::default()
+        };
+        let result = search_articles(&articles_repo, input).await;
+        assert!(matches!(result, Err(DomainError::InvalidSearchQuery)));
+    }
+}
@@ -0,0 +1,102 @@
+//! Slug allocation shared by the article use cases
+
+use crate::{
+    Article, ArticleId, DomainError, DomainResult, Slug,
+    repositories::{ArticlesRepository, SlugTaken},
+};
+
+/// Suffixes are cut from the article id, so each attempt is stable for a
+/// given article and a 32-digit id yields this many distinct ones
+const SUFFIX_LEN: usize = 6;
+const MAX_SUFFIX_ATTEMPTS: usize = 32 / SUFFIX_LEN;
+
+/// Whether the article is new or already stored
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SlugWrite {
+    Create,
+    Update,
+}
+
+/// Stores the article under the first free slug for it: the title slug
+/// itself, then the same slug with a short suffix taken from the article id
+///
+/// A slug is free when no other article uses it now or used to use it, so old
+/// links never start pointing at a different article. Articles in the trash
+/// keep theirs, to get it back when restored. Another writer can take a slug
+/// between the check and the write; the store then refuses it with
+/// [`SlugTaken`] and the next candidate is tried.
+pub(crate) async fn save_with_free_slug<A>(
+    articles_repo: &A,
+    base: &Slug,
+    mut article: Article,
+    write: SlugWrite,
+) -> DomainResult<Article>
+where
+    A: ArticlesRepository,
+{
+    let hex = article.id.as_uuid().simple().to_string();
+    let candidates = std::iter::once(base.clone()).chain(
+        (0..MAX_SUFFIX_ATTEMPTS)
+            .map(|i| base.with_suffix(&hex[i * SUFFIX_LEN..(i + 1) * SUFFIX_LEN])),
+    );
+
+    for candidate in candidates {
+        if slug_taken(articles_repo, &candidate, article.id).await? {
+            continue;
+        }
+        article.slug = candidate;
+        let saved = match write {
+            SlugWrite::Create => articles_repo.create_article(article.clone()).await,
+            SlugWrite::Update => articles_repo.update_article(article.clone()).await,
+        };
+        match saved {
+            Ok(saved) => return Ok(saved),
+            Err(e) if e.is::<SlugTaken>() => continue,
+            Err(e) => return Err(write.error(e)),
+        }
+    }
+    Err(DomainError::Conflict { entity: "slug" })
+}
+
+impl SlugWrite {
+    pub(crate) fn error(self, e: anyhow::Error) -> DomainError {
+        match self {
+            // Format the full error chain
+            Self::Create => DomainError::Database {
+                message: format!("{e:#}"),
+            },
+            Self::Update => DomainError::NotFound { entity: "article" },
+        }
+    }
+}
+
+async fn slug_taken<A>(articles_repo: &A, slug: &Slug, article_id: ArticleId) -> DomainResult<bool>
+where
+    A: ArticlesRepository,
+{
+    let db_error = |e: anyhow::Error| DomainError::Database {
+        message: e.to_string(),
+    };
+
+    let current = articles_repo
+        .get_article_by_slug(slug.as_str())
+        .await
+        .map_err(db_error)?;
+    if current.is_some_and(|article| article.id != article_id) {
+        return Ok(true);
+    }
+
+    let trashed = articles_repo
+        .get_trashed_article_by_slug(slug.as_str())
+        .await
+        .map_err(db_error)?;
+    if trashed.is_some_and(|trashed| trashed.item.id != article_id) {
+        return Ok(true);
+    }
+
+    let previous = articles_repo
+        .get_article_by_previous_slug(slug.as_str())
+        .await
+        .map_err(db_error)?;
+    Ok(previous.is_some_and(|article| article.id != article_id))
+}
@@ -0,0 +1,77 @@
+//! Unbookmark article use case
+
+use crate::{
+    ArticleView, DomainError, DomainResult, UserId,
+    repositories::{ArticlesRepository, UsersRepository},
+    use_cases::articles::get_article::build_article_view,
+};
+
+/// Remove an article from the user's reading list
+///
+/// # Business Rules
+/// - Article must exist
+/// - Removing an article that is not bookmarked is idempotent
+pub async fn unbookmark_article<U, A>(
+    users_repo: &U,
+    articles_repo: &A,
+    slug: &str,
+    user_id: UserId,
+) -> DomainResult<ArticleView>
+where
+    U: UsersRepository,
+    A: ArticlesRepository,
+{
+    let article = articles_repo
+        .get_article_by_slug(slug)
+        .await
+        .map_err(|_| DomainError::NotFound { entity: "article" })?
+        .ok_or(DomainError::NotFound { entity: "article" })?;
+
+    articles_repo
+        .remove_bookmark(user_id, article.id)
+        .await
+        .map_err(|e| DomainError::Database {
+            message: e.to_string(),
+        })?;
+
+    build_article_view(users_repo, articles_repo, &article, Some(user_id)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::{InMemoryArticlesRepository, InMemoryUsersRepository};
+    use crate::{Article, ArticleDraft, ArticleId, Bookmark, Email, PasswordHash, TagList, User, Username};
+    use chrono::Utc;
+
+    #[tokio::test]
+    async fn test_unbookmark_article() {
+        let users_repo = InMemoryUsersRepository::new();
+        let articles_repo = InMemoryArticlesRepository::new(users_repo.clone());
+        let author = User::new(
+            UserId::random(),
+            Email::parse("author@example.com").unwrap(),
+            Username::new("author").unwrap(),
+            PasswordHash::new("hash").unwrap(),
+            Utc::now(),
+        );
+        users_repo.create_user(author.clone()).await.unwrap();
+        let draft = ArticleDraft::new("Test Article", "Description", "Body", TagList::default()).unwrap();
+        let article = Article::publish(ArticleId::random(), author.id, draft, Utc::now()).unwrap();
+        let article = articles_repo.create_article(article).await.unwrap();
+        let user_id = UserId::random();
+        let bookmark = Bookmark::new(user_id, article.id, None, None, Utc::now()).unwrap();
+        articles_repo.bookmark_article(bookmark).await.unwrap();
+
+        let view = unbookmark_article(&users_repo, &articles_repo, article.slug.as_str(), user_id)
+            .await
+            .unwrap();
+        assert!(!view.bookmarked);
+
+        // Again is a no-op
+        let view = unbookmark_article(&users_repo, &articles_repo, article.slug.as_str(), user_id)
+            .await
+            .unwrap();
+        assert!(!view.bookmarked);
+    }
+}
@@ -75,7 +75,7 @@ mod tests {
         let user_id = UserId::random();
 
         // First favorite
-        articles_repo.favorite_article(user_id, article.id).await.unwrap();
+        articles_repo.favorite_article(user_id, article.id, Utc::now()).await.unwrap();
 
         // Then unfavorite
         let result = unfavorite_article(&users_repo, &articles_repo, article.slug.as_str(), user_id).await;
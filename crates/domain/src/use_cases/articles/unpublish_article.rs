@@ -0,0 +1,70 @@
+//! Unpublish article use case
+
+use chrono::{DateTime, Utc};
+
+use crate::{
+    ArticleView, DomainResult, Publication, UserId,
+    repositories::{ArticlesRepository, UsersRepository},
+    use_cases::articles::publish_article::change_publication,
+};
+
+/// Take an article back to draft
+///
+/// # Business Rules
+/// - Article must exist
+/// - Only the article's authors (owner and co-authors) can unpublish it
+/// - Published and scheduled articles become drafts and lose `published_at`
+pub async fn unpublish_article<U, A>(
+    users_repo: &U,
+    articles_repo: &A,
+    slug: &str,
+    author_id: UserId,
+    now: DateTime<Utc>,
+) -> DomainResult<ArticleView>
+where
+    U: UsersRepository,
+    A: ArticlesRepository,
+{
+    change_publication(users_repo, articles_repo, slug, author_id, Publication::Draft, now).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::{InMemoryArticlesRepository, InMemoryUsersRepository};
+    use crate::{
+        Article, ArticleDraft, ArticleFilters, ArticleId, ArticleStatus, Email, PasswordHash, TagList, User,
+        Username,
+    };
+
+    #[tokio::test]
+    async fn test_unpublish_article_hides_it_from_others() {
+        let users_repo = InMemoryUsersRepository::new();
+        let articles_repo = InMemoryArticlesRepository::new(users_repo.clone());
+        let author = User::new(
+            UserId::random(),
+            Email::parse("author@example.com").unwrap(),
+            Username::new("author").unwrap(),
+            PasswordHash::new("hash").unwrap(),
+            Utc::now(),
+        );
+        users_repo.create_user(author.clone()).await.unwrap();
+        let draft = ArticleDraft::new("Test Article", "Description", "Body", TagList::default()).unwrap();
+        let article = Article::publish(ArticleId::random(), author.id, draft, Utc::now()).unwrap();
+        let article = articles_repo.create_article(article).await.unwrap();
+
+        let view = unpublish_article(&users_repo, &articles_repo, article.slug.as_str(), author.id, Utc::now())
+            .await
+            .unwrap();
+        assert_eq!(view.status, ArticleStatus::Draft);
+        assert_eq!(view.published_at, None);
+
+        let public = articles_repo.list_articles(ArticleFilters::default()).await.unwrap();
+        assert_eq!(public.articles_count, 0);
+        let own = articles_repo
+            .list_articles(ArticleFilters::default().with_viewer(Some(author.id)))
+            .await
+            .unwrap();
+        assert_eq!(own.articles_count, 1);
+    }
+}
@@ -3,46 +3,60 @@
 use chrono::{DateTime, Utc};
 
 use crate::{
-    ArticleChanges, ArticleView, DomainError, DomainResult, TagList, UserId,
-    repositories::{ArticlesRepository, UsersRepository},
+    ArticleChanges, ArticleView, DomainError, DomainResult, SlugPolicy, TagList, UserId,
+    repositories::{ArticlesRepository, RevisionsRepository, UsersRepository},
+    use_cases::articles::{
+        get_article::build_article_view, revisions::record_revision, slugs::{SlugWrite, save_with_free_slug},
+    },
 };
 
 /// Input for updating an article
 #[derive(Debug, Clone, Default)]
 pub struct UpdateArticleInput {
+    /// Current slug of the article to update
+    pub slug: String,
     pub title: Option<String>,
     pub description: Option<String>,
     pub body: Option<String>,
     pub tag_list: Option<Vec<String>>,
+    /// `Some(None)` clears the field
+    pub canonical_url: Option<Option<String>>,
+    pub meta_description: Option<Option<String>>,
+    pub social_image: Option<Option<String>>,
 }
 
 /// Update an existing article
 ///
 /// # Business Rules
 /// - Article must exist
-/// - Only the author can update their article
-/// - Slug is regenerated if title changes
+/// - Only the article's authors (owner and co-authors) can update it
+/// - Slug is regenerated if title changes, kept unique, and the old slug is
+///   remembered so existing links still resolve
 /// - Updated fields must pass validation
-pub async fn update_article<U, A>(
+/// - Changes to title, description or body are kept as a new revision
+/// - SEO fields can be set or cleared; they aren't part of revisions
+pub async fn update_article<U, A, R>(
     users_repo: &U,
     articles_repo: &A,
-    slug: &str,
+    revisions_repo: &R,
     author_id: UserId,
     input: UpdateArticleInput,
+    slug_policy: &SlugPolicy,
     now: DateTime<Utc>,
 ) -> DomainResult<ArticleView>
 where
     U: UsersRepository,
     A: ArticlesRepository,
+    R: RevisionsRepository + ?Sized,
 {
     let mut article = articles_repo
-        .get_article_by_slug(slug)
+        .get_article_by_slug(&input.slug)
         .await
         .map_err(|_| DomainError::NotFound { entity: "article" })?
         .ok_or(DomainError::NotFound { entity: "article" })?;
 
-    // Authorization: only author can update
-    if article.author_id != author_id {
+    // Authorization: only the owner and co-authors can update
+    if !article.can_edit(&author_id) {
         return Err(DomainError::UnauthorizedAction);
     }
 
@@ -57,35 +71,44 @@ where
         description: input.description,
         body: input.body,
         tag_list,
+        canonical_url: input.canonical_url,
+        meta_description: input.meta_description,
+        social_image: input.social_image,
     };
 
+    let previous = article.clone();
+    let previous_slug = article.slug.clone();
     article.apply_changes(changes, now)?;
+    let updated = if article.title == previous.title {
+        articles_repo
+            .update_article(article)
+            .await
+            .map_err(|e| SlugWrite::Update.error(e))?
+    } else {
+        let base = slug_policy.slugify(&article.title)?;
+        save_with_free_slug(articles_repo, &base, article, SlugWrite::Update).await?
+    };
 
-    let updated = articles_repo
-        .update_article(article)
-        .await
-        .map_err(|_| DomainError::NotFound { entity: "article" })?;
+    record_revision(revisions_repo, &previous, &updated, author_id, now).await?;
 
-    // Build view
-    let author = users_repo
-        .get_user_by_id(author_id)
-        .await
-        .map_err(|_| DomainError::NotFound { entity: "author" })?
-        .ok_or(DomainError::NotFound { entity: "author" })?;
-
-    let favorited = articles_repo
-        .is_favorited(author_id, updated.id)
-        .await
-        .unwrap_or(false);
+    if updated.slug != previous_slug {
+        articles_repo
+            .record_slug_change(updated.id, previous_slug.as_str(), updated.slug.as_str())
+            .await
+            .map_err(|e| DomainError::Database {
+                message: e.to_string(),
+            })?;
+    }
 
-    let profile = author.to_profile(false);
-    Ok(updated.to_view(profile, favorited))
+    // The editor may be a co-author, so the view is built for them rather
+    // than assuming they are the owner
+    build_article_view(users_repo, articles_repo, &updated, Some(author_id)).await
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::repositories::{InMemoryArticlesRepository, InMemoryUsersRepository};
+    use crate::repositories::{InMemoryArticlesRepository, InMemoryRevisionsRepository, InMemoryUsersRepository};
     use crate::{Article, ArticleDraft, ArticleId, Email, PasswordHash, TagList, User, Username};
     use chrono::Utc;
 
@@ -113,6 +136,7 @@ mod tests {
     async fn test_update_article_success() {
         let (users_repo, articles_repo, author, article) = setup().await;
         let input = UpdateArticleInput {
+            slug: article.slug.as_str().to_owned(),
             title: Some("Updated Title".to_string()),
             ..Default::default()
         };
@@ -120,9 +144,10 @@ mod tests {
         let result = update_article(
             &users_repo,
             &articles_repo,
-            article.slug.as_str(),
+            &InMemoryRevisionsRepository::new(),
             author.id,
             input,
+            &SlugPolicy::default(),
             Utc::now(),
         )
         .await;
@@ -138,6 +163,7 @@ mod tests {
         let (users_repo, articles_repo, _author, article) = setup().await;
         let other_user_id = UserId::random();
         let input = UpdateArticleInput {
+            slug: article.slug.as_str().to_owned(),
             title: Some("Hacked Title".to_string()),
             ..Default::default()
         };
@@ -145,9 +171,10 @@ mod tests {
         let result = update_article(
             &users_repo,
             &articles_repo,
-            article.slug.as_str(),
+            &InMemoryRevisionsRepository::new(),
             other_user_id,
             input,
+            &SlugPolicy::default(),
             Utc::now(),
         )
         .await;
@@ -158,18 +185,179 @@ mod tests {
     #[tokio::test]
     async fn test_update_article_not_found() {
         let (users_repo, articles_repo, author, _article) = setup().await;
-        let input = UpdateArticleInput::default();
+        let input = UpdateArticleInput {
+            slug: "nonexistent".to_owned(),
+            ..Default::default()
+        };
 
         let result = update_article(
             &users_repo,
             &articles_repo,
-            "nonexistent",
+            &InMemoryRevisionsRepository::new(),
             author.id,
             input,
+            &SlugPolicy::default(),
             Utc::now(),
         )
         .await;
 
         assert!(matches!(result, Err(DomainError::NotFound { entity: "article" })));
     }
+
+    #[tokio::test]
+    async fn test_update_article_sets_and_clears_seo_fields() {
+        let (users_repo, articles_repo, author, article) = setup().await;
+        let revisions_repo = InMemoryRevisionsRepository::new();
+        let slug_policy = SlugPolicy::default();
+        let update = |input: UpdateArticleInput| {
+            let input = UpdateArticleInput {
+                slug: article.slug.as_str().to_owned(),
+                ..input
+            };
+            update_article(
+                &users_repo,
+                &articles_repo,
+                &revisions_repo,
+                author.id,
+                input,
+                &slug_policy,
+                Utc::now(),
+            )
+        };
+
+        let view = update(UpdateArticleInput {
+            canonical_url: Some(Some("https://blog.example.com/original".to_string())),
+            meta_description: Some(Some("What search engines show".to_string())),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+        assert_eq!(view.seo.canonical_url.as_deref(), Some("https://blog.example.com/original"));
+        assert_eq!(view.seo.meta_description.as_deref(), Some("What search engines show"));
+
+        // Fields left out stay as they are
+        let view = update(UpdateArticleInput {
+            canonical_url: Some(None),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+        assert_eq!(view.seo.canonical_url, None);
+        assert_eq!(view.seo.meta_description.as_deref(), Some("What search engines show"));
+
+        let result = update(UpdateArticleInput {
+            social_image: Some(Some("javascript:alert(1)".to_string())),
+            ..Default::default()
+        })
+        .await;
+        assert!(matches!(result, Err(DomainError::InvalidSocialImage { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_update_article_keeps_old_slug_resolvable() {
+        let (users_repo, articles_repo, author, article) = setup().await;
+        let input = UpdateArticleInput {
+            slug: article.slug.as_str().to_owned(),
+            title: Some("Renamed".to_string()),
+            ..Default::default()
+        };
+
+        let view = update_article(
+            &users_repo,
+            &articles_repo,
+            &InMemoryRevisionsRepository::new(),
+            author.id,
+            input,
+            &SlugPolicy::default(),
+            Utc::now(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(view.slug.as_str(), "renamed");
+        let resolved = crate::use_cases::get_article(
+            &users_repo,
+            &articles_repo,
+            "original-title",
+            None,
+        )
+        .await
+        .unwrap();
+        assert_eq!(resolved.slug.as_str(), "renamed");
+    }
+
+    #[tokio::test]
+    async fn test_update_article_cannot_take_previous_slug_of_another() {
+        let (users_repo, articles_repo, author, article) = setup().await;
+        let rename = |slug: &str, title: &str| UpdateArticleInput {
+            slug: slug.to_owned(),
+            title: Some(title.to_string()),
+            ..Default::default()
+        };
+        update_article(
+            &users_repo,
+            &articles_repo,
+            &InMemoryRevisionsRepository::new(),
+            author.id,
+            rename(article.slug.as_str(), "Renamed"),
+            &SlugPolicy::default(),
+            Utc::now(),
+        )
+        .await
+        .unwrap();
+
+        let draft = ArticleDraft::new("Other", "Description", "Body", TagList::default()).unwrap();
+        let other = Article::publish(ArticleId::random(), author.id, draft, Utc::now()).unwrap();
+        articles_repo.create_article(other).await.unwrap();
+        let view = update_article(
+            &users_repo,
+            &articles_repo,
+            &InMemoryRevisionsRepository::new(),
+            author.id,
+            rename("other", "Original Title"),
+            &SlugPolicy::default(),
+            Utc::now(),
+        )
+        .await
+        .unwrap();
+
+        assert_ne!(view.slug.as_str(), "original-title");
+        assert!(view.slug.as_str().starts_with("original-title-"));
+    }
+
+    #[tokio::test]
+    async fn test_update_article_records_revisions() {
+        let (users_repo, articles_repo, author, article) = setup().await;
+        let revisions_repo = InMemoryRevisionsRepository::new();
+        for input in [
+            UpdateArticleInput {
+                slug: article.slug.as_str().to_owned(),
+                body: Some("Second body".to_string()),
+                ..Default::default()
+            },
+            UpdateArticleInput {
+                slug: article.slug.as_str().to_owned(),
+                tag_list: Some(vec!["rust".to_string()]),
+                ..Default::default()
+            },
+        ] {
+            update_article(
+                &users_repo,
+                &articles_repo,
+                &revisions_repo,
+                author.id,
+                input,
+                &SlugPolicy::default(),
+                Utc::now(),
+            )
+            .await
+            .unwrap();
+        }
+
+        let revisions = revisions_repo.list_revisions(article.id).await.unwrap();
+        assert_eq!(revisions.len(), 2);
+        assert_eq!((revisions[0].version, revisions[0].body.as_str()), (2, "Second body"));
+        assert_eq!((revisions[1].version, revisions[1].body.as_str()), (1, "Body"));
+        assert_eq!(revisions[1].created_at, article.updated_at);
+    }
 }
@@ -0,0 +1,224 @@
+//! Add comment reaction use case
+
+use crate::{
+    Comment, CommentId, CommentView, DomainError, DomainResult, ReactionPolicy, UserId,
+    repositories::{ArticlesRepository, CommentsRepository, UsersRepository},
+};
+
+/// Input for reacting to a comment, or taking a reaction back
+#[derive(Debug, Clone)]
+pub struct CommentReactionInput {
+    /// Slug of the article the comment is on
+    pub slug: String,
+    pub comment_id: CommentId,
+    pub kind: String,
+}
+
+/// React to a comment with one of the offered kinds
+///
+/// # Business Rules
+/// - Only kinds the policy offers can be used
+/// - Article must exist and be visible to the user, and the comment must be
+///   on it; hidden comments can only be reacted to by their author
+/// - Users blocked by the article's or the comment's author cannot react
+/// - A user reacts with each kind at most once; reacting again is idempotent
+pub async fn add_comment_reaction<U, A, C>(
+    users_repo: &U,
+    articles_repo: &A,
+    comments_repo: &C,
+    input: CommentReactionInput,
+    user_id: UserId,
+    policy: &ReactionPolicy,
+) -> DomainResult<CommentView>
+where
+    U: UsersRepository,
+    A: ArticlesRepository,
+    C: CommentsRepository,
+{
+    let kind = policy.kind(&input.kind)?;
+
+    let article = articles_repo
+        .get_article_by_slug(&input.slug)
+        .await
+        .map_err(|_| DomainError::NotFound { entity: "article" })?
+        .ok_or(DomainError::NotFound { entity: "article" })?;
+    if !article.is_visible_to(Some(user_id)) {
+        return Err(DomainError::NotFound { entity: "article" });
+    }
+
+    let comment = comments_repo
+        .get_comment_by_id(input.comment_id)
+        .await
+        .map_err(|_| DomainError::NotFound { entity: "comment" })?
+        .filter(|comment| comment.article_id == article.id)
+        .filter(|comment| comment.is_visible_to(Some(user_id)))
+        .ok_or(DomainError::NotFound { entity: "comment" })?;
+
+    for author_id in [article.author_id, comment.author_id] {
+        let blocked = users_repo
+            .is_blocking(author_id, user_id)
+            .await
+            .map_err(|e| DomainError::Database {
+                message: e.to_string(),
+            })?;
+        if blocked {
+            return Err(DomainError::Blocked);
+        }
+    }
+
+    comments_repo
+        .add_comment_reaction(comment.id, user_id, &kind)
+        .await
+        .map_err(|e| DomainError::Database {
+            message: e.to_string(),
+        })?;
+
+    build_comment_view(users_repo, comments_repo, &comment, user_id).await
+}
+
+/// The comment as `viewer_id` sees it, reactions included
+pub(crate) async fn build_comment_view<U, C>(
+    users_repo: &U,
+    comments_repo: &C,
+    comment: &Comment,
+    viewer_id: UserId,
+) -> DomainResult<CommentView>
+where
+    U: UsersRepository,
+    C: CommentsRepository,
+{
+    let author = users_repo
+        .get_user_by_id(comment.author_id)
+        .await
+        .map_err(|_| DomainError::NotFound { entity: "author" })?
+        .ok_or(DomainError::NotFound { entity: "author" })?;
+    let following = users_repo
+        .is_following(viewer_id, author.id)
+        .await
+        .unwrap_or(false);
+
+    let reactions = comments_repo
+        .comment_reactions(&[comment.id], Some(viewer_id))
+        .await
+        .map_err(|e| DomainError::Database {
+            message: e.to_string(),
+        })?
+        .remove(&comment.id)
+        .unwrap_or_default();
+
+    Ok(comment
+        .to_view(author.to_profile(following))
+        .with_reactions(reactions))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::{InMemoryArticlesRepository, InMemoryCommentsRepository, InMemoryUsersRepository};
+    use crate::{Article, ArticleDraft, ArticleId, CommentDraft, Email, PasswordHash, TagList, User, Username};
+    use chrono::Utc;
+
+    async fn setup() -> (
+        InMemoryUsersRepository,
+        InMemoryArticlesRepository,
+        InMemoryCommentsRepository,
+        User,
+        User,
+    ) {
+        let users_repo = InMemoryUsersRepository::new();
+        let articles_repo = InMemoryArticlesRepository::new(users_repo.clone());
+        let comments_repo = InMemoryCommentsRepository::new();
+        let mut users = Vec::new();
+        for name in ["author", "reader"] {
+            let user = User::new(
+                UserId::random(),
+                Email::parse(format!("{name}@example.com")).unwrap(),
+                Username::new(name).unwrap(),
+                PasswordHash::new("hash").unwrap(),
+                Utc::now(),
+            );
+            users_repo.create_user(user.clone()).await.unwrap();
+            users.push(user);
+        }
+        let draft = ArticleDraft::new("Test Article", "Description", "Body", TagList::default()).unwrap();
+        let article = Article::publish(ArticleId::random(), users[0].id, draft, Utc::now()).unwrap();
+        let article = articles_repo.create_article(article).await.unwrap();
+        let draft = CommentDraft::new("Test comment").unwrap();
+        let comment = Comment::new(CommentId::new(1), article.id, users[0].id, draft, Utc::now());
+        comments_repo.create_comment(comment).await.unwrap();
+        let reader = users.pop().unwrap();
+        let author = users.pop().unwrap();
+        (users_repo, articles_repo, comments_repo, author, reader)
+    }
+
+    #[tokio::test]
+    async fn test_add_comment_reaction_success() {
+        let (users_repo, articles_repo, comments_repo, _, reader) = setup().await;
+
+        let view = add_comment_reaction(
+            &users_repo,
+            &articles_repo,
+            &comments_repo,
+            CommentReactionInput {
+                slug: "test-article".to_owned(),
+                comment_id: CommentId::new(1),
+                kind: "tada".to_owned(),
+            },
+            reader.id,
+            &ReactionPolicy::default(),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(view.reactions.len(), 1);
+        assert_eq!(view.reactions[0].kind.as_str(), "tada");
+        assert_eq!(view.reactions[0].count, 1);
+        assert!(view.reactions[0].reacted);
+    }
+
+    #[tokio::test]
+    async fn test_add_comment_reaction_wrong_article() {
+        let (users_repo, articles_repo, comments_repo, author, reader) = setup().await;
+        let draft = ArticleDraft::new("Other Article", "Description", "Body", TagList::default()).unwrap();
+        let other = Article::publish(ArticleId::random(), author.id, draft, Utc::now()).unwrap();
+        articles_repo.create_article(other).await.unwrap();
+
+        let result = add_comment_reaction(
+            &users_repo,
+            &articles_repo,
+            &comments_repo,
+            CommentReactionInput {
+                slug: "other-article".to_owned(),
+                comment_id: CommentId::new(1),
+                kind: "tada".to_owned(),
+            },
+            reader.id,
+            &ReactionPolicy::default(),
+        )
+        .await;
+
+        assert!(matches!(result, Err(DomainError::NotFound { entity: "comment" })));
+    }
+
+    #[tokio::test]
+    async fn test_add_comment_reaction_blocked_by_comment_author() {
+        let (users_repo, articles_repo, comments_repo, author, reader) = setup().await;
+        users_repo.block_user(author.id, reader.id).await.unwrap();
+
+        let result = add_comment_reaction(
+            &users_repo,
+            &articles_repo,
+            &comments_repo,
+            CommentReactionInput {
+                slug: "test-article".to_owned(),
+                comment_id: CommentId::new(1),
+                kind: "tada".to_owned(),
+            },
+            reader.id,
+            &ReactionPolicy::default(),
+        )
+        .await;
+
+        assert!(matches!(result, Err(DomainError::Blocked)));
+    }
+}
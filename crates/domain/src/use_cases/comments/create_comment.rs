@@ -16,7 +16,8 @@ pub struct CreateCommentInput {
 /// Create a new comment on an article
 ///
 /// # Business Rules
-/// - Article must exist
+/// - Article must exist and be published, unless the commenter is its author
+/// - Commenter must not be blocked by the article's author
 /// - Body must not be empty
 /// - Author is the current user
 pub async fn create_comment<U, A, C>(
@@ -39,6 +40,19 @@ where
         .await
         .map_err(|_| DomainError::NotFound { entity: "article" })?
         .ok_or(DomainError::NotFound { entity: "article" })?;
+    if !article.is_visible_to(Some(author_id)) {
+        return Err(DomainError::NotFound { entity: "article" });
+    }
+
+    let blocked = users_repo
+        .is_blocking(article.author_id, author_id)
+        .await
+        .map_err(|e| DomainError::Database {
+            message: e.to_string(),
+        })?;
+    if blocked {
+        return Err(DomainError::Blocked);
+    }
 
     // Get author for profile
     let author = users_repo
@@ -164,4 +178,33 @@ mod tests {
 
         assert!(matches!(result, Err(DomainError::InvalidBody)));
     }
+
+    #[tokio::test]
+    async fn test_create_comment_blocked_by_author() {
+        let (users_repo, articles_repo, comments_repo, author, article) = setup().await;
+        let commenter = User::new(
+            UserId::random(),
+            Email::parse("troll@example.com").unwrap(),
+            Username::new("troll").unwrap(),
+            PasswordHash::new("hash").unwrap(),
+            Utc::now(),
+        );
+        users_repo.create_user(commenter.clone()).await.unwrap();
+        users_repo.block_user(author.id, commenter.id).await.unwrap();
+
+        let result = create_comment(
+            &users_repo,
+            &articles_repo,
+            &comments_repo,
+            article.slug.as_str(),
+            commenter.id,
+            CreateCommentInput {
+                body: "Comment".to_string(),
+            },
+            Utc::now(),
+        )
+        .await;
+
+        assert!(matches!(result, Err(DomainError::Blocked)));
+    }
 }
@@ -1,5 +1,7 @@
 //! Delete comment use case
 
+use chrono::{DateTime, Utc};
+
 use crate::{
     CommentId, DomainError, DomainResult, UserId,
     repositories::{ArticlesRepository, CommentsRepository},
@@ -11,12 +13,15 @@ use crate::{
 /// - Article must exist
 /// - Comment must exist and belong to the article
 /// - Only the comment author can delete it
+/// - The comment only moves to its author's trash, from where it can be
+///   restored until the purge job erases it
 pub async fn delete_comment<A, C>(
     articles_repo: &A,
     comments_repo: &C,
     slug: &str,
     comment_id: CommentId,
     user_id: UserId,
+    now: DateTime<Utc>,
 ) -> DomainResult<()>
 where
     A: ArticlesRepository,
@@ -47,7 +52,7 @@ where
     }
 
     comments_repo
-        .delete_comment(comment_id)
+        .delete_comment(comment_id, now)
         .await
         .map_err(|_| DomainError::NotFound { entity: "comment" })?;
 
@@ -59,7 +64,6 @@ mod tests {
     use super::*;
     use crate::repositories::{InMemoryArticlesRepository, InMemoryCommentsRepository, InMemoryUsersRepository};
     use crate::{Article, ArticleDraft, ArticleId, Comment, CommentDraft, TagList};
-    use chrono::Utc;
 
     async fn setup() -> (InMemoryArticlesRepository, InMemoryCommentsRepository, Article, Comment, UserId) {
         let users_repo = InMemoryUsersRepository::new();
@@ -88,6 +92,7 @@ mod tests {
             article.slug.as_str(),
             comment.id,
             author_id,
+            Utc::now(),
         )
         .await;
 
@@ -96,6 +101,7 @@ mod tests {
         // Verify comment is deleted
         let found = comments_repo.get_comment_by_id(comment.id).await.unwrap();
         assert!(found.is_none());
+        assert!(comments_repo.get_trashed_comment(comment.id).await.unwrap().is_some());
     }
 
     #[tokio::test]
@@ -108,6 +114,7 @@ mod tests {
             "nonexistent",
             comment.id,
             author_id,
+            Utc::now(),
         )
         .await;
 
@@ -124,6 +131,7 @@ mod tests {
             article.slug.as_str(),
             CommentId::new(999),
             author_id,
+            Utc::now(),
         )
         .await;
 
@@ -141,6 +149,7 @@ mod tests {
             article.slug.as_str(),
             comment.id,
             other_user_id,
+            Utc::now(),
         )
         .await;
 
@@ -162,6 +171,7 @@ mod tests {
             other_article.slug.as_str(),
             comment.id,
             author_id,
+            Utc::now(),
         )
         .await;
 
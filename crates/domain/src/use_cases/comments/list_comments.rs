@@ -1,16 +1,20 @@
 //! List comments use case
 
 use crate::{
-    CommentView, DomainError, DomainResult, UserId,
+    CommentId, CommentView, DomainError, DomainResult, UserId,
     repositories::{ArticlesRepository, CommentsRepository, UsersRepository},
 };
 
 /// List all comments on an article
 ///
 /// # Business Rules
-/// - Article must exist
+/// - Article must exist, be visible to the viewer, and its author must not
+///   have blocked the viewer
+/// - Comments by authors the viewer muted, or who blocked the viewer, are left out
+/// - Comments a moderator hid are left out for everyone but their author
 /// - Comments are sorted by creation date (newest first)
 /// - Author profiles include following status relative to viewer
+/// - Each comment carries its reactions, marking the viewer's own
 pub async fn list_comments<U, A, C>(
     users_repo: &U,
     articles_repo: &A,
@@ -29,14 +33,47 @@ where
         .await
         .map_err(|_| DomainError::NotFound { entity: "article" })?
         .ok_or(DomainError::NotFound { entity: "article" })?;
+    if !article.is_visible_to(viewer_id) {
+        return Err(DomainError::NotFound { entity: "article" });
+    }
+
+    let hidden = match viewer_id {
+        Some(viewer) => {
+            let blocked = users_repo
+                .is_blocking(article.author_id, viewer)
+                .await
+                .map_err(|e| DomainError::Database {
+                    message: e.to_string(),
+                })?;
+            if blocked {
+                return Err(DomainError::NotFound { entity: "article" });
+            }
+            users_repo
+                .list_hidden_authors(viewer)
+                .await
+                .map_err(|e| DomainError::Database {
+                    message: e.to_string(),
+                })?
+        }
+        None => Vec::new(),
+    };
 
     let mut comments = comments_repo
         .get_comments_by_article(article.id)
         .await
         .map_err(|_| DomainError::NotFound { entity: "comments" })?;
+    comments.retain(|c| !hidden.contains(&c.author_id) && c.is_visible_to(viewer_id));
 
     // Sort by creation date, newest first
-    comments.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+    comments.sort_by_key(|c| std::cmp::Reverse(c.created_at));
+
+    let ids: Vec<CommentId> = comments.iter().map(|c| c.id).collect();
+    let mut reactions = comments_repo
+        .comment_reactions(&ids, viewer_id)
+        .await
+        .map_err(|e| DomainError::Database {
+            message: e.to_string(),
+        })?;
 
     let mut views = Vec::with_capacity(comments.len());
     for comment in comments {
@@ -55,7 +92,8 @@ where
         };
 
         let profile = author.to_profile(following);
-        views.push(comment.to_view(profile));
+        let comment_reactions = reactions.remove(&comment.id).unwrap_or_default();
+        views.push(comment.to_view(profile).with_reactions(comment_reactions));
     }
 
     Ok(views)
@@ -153,4 +191,37 @@ mod tests {
 
         assert!(matches!(result, Err(DomainError::NotFound { entity: "article" })));
     }
+
+    #[tokio::test]
+    async fn test_list_comments_hides_muted_and_blocked() {
+        let (users_repo, articles_repo, comments_repo, author, article) = setup().await;
+        let viewer_id = UserId::random();
+
+        let draft = CommentDraft::new("Muted").unwrap();
+        let comment = Comment::new(CommentId::new(1), article.id, author.id, draft, Utc::now());
+        comments_repo.create_comment(comment).await.unwrap();
+        users_repo.mute_user(viewer_id, author.id).await.unwrap();
+
+        let views = list_comments(
+            &users_repo,
+            &articles_repo,
+            &comments_repo,
+            article.slug.as_str(),
+            Some(viewer_id),
+        )
+        .await
+        .unwrap();
+        assert!(views.is_empty());
+
+        users_repo.block_user(author.id, viewer_id).await.unwrap();
+        let result = list_comments(
+            &users_repo,
+            &articles_repo,
+            &comments_repo,
+            article.slug.as_str(),
+            Some(viewer_id),
+        )
+        .await;
+        assert!(matches!(result, Err(DomainError::NotFound { entity: "article" })));
+    }
 }
@@ -2,10 +2,14 @@
 //!
 //! All business logic for comment operations lives here.
 
+mod add_comment_reaction;
 mod create_comment;
 mod delete_comment;
 mod list_comments;
+mod remove_comment_reaction;
 
+pub use add_comment_reaction::*;
 pub use create_comment::*;
 pub use delete_comment::*;
 pub use list_comments::*;
+pub use remove_comment_reaction::*;
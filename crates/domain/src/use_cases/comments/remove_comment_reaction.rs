@@ -0,0 +1,101 @@
+//! Remove comment reaction use case
+
+use crate::{
+    CommentView, DomainError, DomainResult, ReactionKind, UserId,
+    repositories::{ArticlesRepository, CommentsRepository, UsersRepository},
+    use_cases::comments::add_comment_reaction::{CommentReactionInput, build_comment_view},
+};
+
+/// Take back a reaction to a comment
+///
+/// # Business Rules
+/// - Article must exist and be visible to the user, and the comment must be
+///   on it
+/// - Kinds no longer offered can still be taken back
+/// - Removing a reaction the user did not leave is idempotent
+pub async fn remove_comment_reaction<U, A, C>(
+    users_repo: &U,
+    articles_repo: &A,
+    comments_repo: &C,
+    input: CommentReactionInput,
+    user_id: UserId,
+) -> DomainResult<CommentView>
+where
+    U: UsersRepository,
+    A: ArticlesRepository,
+    C: CommentsRepository,
+{
+    let kind = ReactionKind::new(input.kind)?;
+
+    let article = articles_repo
+        .get_article_by_slug(&input.slug)
+        .await
+        .map_err(|_| DomainError::NotFound { entity: "article" })?
+        .ok_or(DomainError::NotFound { entity: "article" })?;
+    if !article.is_visible_to(Some(user_id)) {
+        return Err(DomainError::NotFound { entity: "article" });
+    }
+
+    let comment = comments_repo
+        .get_comment_by_id(input.comment_id)
+        .await
+        .map_err(|_| DomainError::NotFound { entity: "comment" })?
+        .filter(|comment| comment.article_id == article.id)
+        .ok_or(DomainError::NotFound { entity: "comment" })?;
+
+    comments_repo
+        .remove_comment_reaction(comment.id, user_id, &kind)
+        .await
+        .map_err(|e| DomainError::Database {
+            message: e.to_string(),
+        })?;
+
+    build_comment_view(users_repo, comments_repo, &comment, user_id).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::{InMemoryArticlesRepository, InMemoryCommentsRepository, InMemoryUsersRepository};
+    use crate::{Article, ArticleDraft, ArticleId, Comment, CommentDraft, CommentId, Email, PasswordHash, TagList, User, Username};
+    use chrono::Utc;
+
+    #[tokio::test]
+    async fn test_remove_comment_reaction_success() {
+        let users_repo = InMemoryUsersRepository::new();
+        let articles_repo = InMemoryArticlesRepository::new(users_repo.clone());
+        let comments_repo = InMemoryCommentsRepository::new();
+        let author = User::new(
+            UserId::random(),
+            Email::parse("author@example.com").unwrap(),
+            Username::new("author").unwrap(),
+            PasswordHash::new("hash").unwrap(),
+            Utc::now(),
+        );
+        users_repo.create_user(author.clone()).await.unwrap();
+        let draft = ArticleDraft::new("Test Article", "Description", "Body", TagList::default()).unwrap();
+        let article = Article::publish(ArticleId::random(), author.id, draft, Utc::now()).unwrap();
+        let article = articles_repo.create_article(article).await.unwrap();
+        let draft = CommentDraft::new("Test comment").unwrap();
+        let comment = Comment::new(CommentId::new(1), article.id, author.id, draft, Utc::now());
+        comments_repo.create_comment(comment).await.unwrap();
+        let kind = ReactionKind::new("thinking").unwrap();
+        comments_repo.add_comment_reaction(CommentId::new(1), author.id, &kind).await.unwrap();
+
+        let view = remove_comment_reaction(
+            &users_repo,
+            &articles_repo,
+            &comments_repo,
+            CommentReactionInput {
+                slug: "test-article".to_owned(),
+                comment_id: CommentId::new(1),
+                kind: "thinking".to_owned(),
+            },
+            author.id,
+        )
+        .await
+        .unwrap();
+
+        assert!(view.reactions.is_empty());
+    }
+}
@@ -6,27 +6,49 @@
 //! Use cases are organized by domain aggregate:
 //! - `articles` - Article CRUD, favorites, feed
 //! - `users` - Registration, login, profile updates
-//! - `profiles` - Follow/unfollow users
+//! - `profiles` - Follow/unfollow, block and mute users
 //! - `comments` - Article comments
+//! - `moderation` - Reporting content and working through the moderation queue
+//! - `series` - Series and curated collections of articles
+//! - `tags` - Tag catalogue and autocomplete
+//! - `trash` - Restoring and purging deleted articles and comments
+//! - `uploads` - Uploaded images and attachments
 
 pub mod articles;
 pub mod comments;
+pub mod moderation;
 pub mod profiles;
+pub mod series;
+pub mod tags;
+pub mod trash;
+pub mod uploads;
 pub mod users;
 
 // Re-export all use cases for convenient access
 pub use articles::*;
 pub use comments::*;
+pub use moderation::*;
 pub use profiles::*;
+pub use series::*;
+pub use tags::*;
+pub use trash::*;
+pub use uploads::*;
 pub use users::*;
 
-use crate::repositories::{ArticlesRepository, CommentsRepository, UsersRepository};
+use std::sync::Arc;
+
+use crate::repositories::{
+    ArticlesRepository, BlobStore, CommentsRepository, ReportsRepository, RevisionsRepository, SeriesRepository,
+    TagsRepository, UsersRepository,
+};
 
 /// Container for all repositories with injected dependencies
 ///
 /// This struct holds references to all repositories and can be used
 /// to access them in a unified way. Use case functions are standalone
-/// and receive repository references as parameters.
+/// and receive repository references as parameters; the few that read
+/// from nearly all of them, like the personal data export, take the
+/// container instead.
 pub struct UseCases<U, A, C>
 where
     U: UsersRepository,
@@ -36,21 +58,11 @@ where
     pub users_repo: U,
     pub articles_repo: A,
     pub comments_repo: C,
-}
-
-impl<U, A, C> UseCases<U, A, C>
-where
-    U: UsersRepository,
-    A: ArticlesRepository,
-    C: CommentsRepository,
-{
-    pub fn new(users_repo: U, articles_repo: A, comments_repo: C) -> Self {
-        Self {
-            users_repo,
-            articles_repo,
-            comments_repo,
-        }
-    }
+    pub revisions_repo: Arc<dyn RevisionsRepository>,
+    pub tags_repo: Arc<dyn TagsRepository>,
+    pub series_repo: Arc<dyn SeriesRepository>,
+    pub blob_store: Arc<dyn BlobStore>,
+    pub reports_repo: Arc<dyn ReportsRepository>,
 }
 
 impl<U, A, C> Clone for UseCases<U, A, C>
@@ -64,6 +76,11 @@ where
             users_repo: self.users_repo.clone(),
             articles_repo: self.articles_repo.clone(),
             comments_repo: self.comments_repo.clone(),
+            revisions_repo: self.revisions_repo.clone(),
+            tags_repo: self.tags_repo.clone(),
+            series_repo: self.series_repo.clone(),
+            blob_store: self.blob_store.clone(),
+            reports_repo: self.reports_repo.clone(),
         }
     }
 }
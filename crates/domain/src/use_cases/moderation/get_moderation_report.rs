@@ -0,0 +1,85 @@
+//! Get moderation report use case
+
+use crate::{
+    DomainError, DomainResult, ModerationRecordView, ModerationReportEnvelope, ModerationReportView, Report, ReportId,
+    repositories::{ArticlesRepository, CommentsRepository, ReportsRepository, UsersRepository},
+    use_cases::moderation::report_content::{report_subject, username},
+};
+
+/// Get a report for moderators, with every action taken on it
+///
+/// # Business Rules
+/// - Report must exist
+/// - Actions come oldest first; those by moderators whose account is gone
+///   have no moderator
+pub async fn get_moderation_report<U, A, C, R>(
+    users_repo: &U,
+    articles_repo: &A,
+    comments_repo: &C,
+    reports_repo: &R,
+    report_id: ReportId,
+) -> DomainResult<ModerationReportEnvelope>
+where
+    U: UsersRepository,
+    A: ArticlesRepository,
+    C: CommentsRepository,
+    R: ReportsRepository + ?Sized,
+{
+    let report = reports_repo
+        .get_report(report_id)
+        .await
+        .map_err(|e| DomainError::Database {
+            message: e.to_string(),
+        })?
+        .ok_or(DomainError::NotFound { entity: "report" })?;
+
+    let view = build_moderation_view(users_repo, articles_repo, comments_repo, reports_repo, &report, true).await?;
+    Ok(ModerationReportEnvelope::from(view))
+}
+
+/// The report as moderators see it; the actions log only when asked for
+pub(super) async fn build_moderation_view<U, A, C, R>(
+    users_repo: &U,
+    articles_repo: &A,
+    comments_repo: &C,
+    reports_repo: &R,
+    report: &Report,
+    with_actions: bool,
+) -> DomainResult<ModerationReportView>
+where
+    U: UsersRepository,
+    A: ArticlesRepository,
+    C: CommentsRepository,
+    R: ReportsRepository + ?Sized,
+{
+    let subject = report_subject(users_repo, articles_repo, comments_repo, report.target).await?;
+
+    let actions = if with_actions {
+        let records = reports_repo
+            .list_moderation_records(report.id)
+            .await
+            .map_err(|e| DomainError::Database {
+                message: e.to_string(),
+            })?;
+        let mut actions = Vec::with_capacity(records.len());
+        for record in records {
+            actions.push(ModerationRecordView {
+                moderator: username(users_repo, record.moderator_id).await?,
+                action: record.action,
+                note: record.note,
+                created_at: record.created_at,
+            });
+        }
+        Some(actions)
+    } else {
+        None
+    };
+
+    Ok(ModerationReportView {
+        report: report.to_view(subject),
+        reporter: username(users_repo, Some(report.reporter_id)).await?,
+        target_author: username(users_repo, Some(report.target_author_id)).await?,
+        moderator: username(users_repo, report.moderator_id).await?,
+        actions,
+    })
+}
@@ -0,0 +1,149 @@
+//! List moderation queue use case
+
+use crate::{
+    DomainError, DomainResult, ModerationQueueEnvelope, Pagination, ReportStatus,
+    repositories::{ArticlesRepository, CommentsRepository, ReportsRepository, UsersRepository},
+    use_cases::moderation::get_moderation_report::build_moderation_view,
+};
+
+/// List reports for moderators to work through
+///
+/// # Business Rules
+/// - Oldest report first, so none waits forever
+/// - Reports of any status unless one is asked for
+/// - The count covers every report of that status, not just the page
+pub async fn list_moderation_queue<U, A, C, R>(
+    users_repo: &U,
+    articles_repo: &A,
+    comments_repo: &C,
+    reports_repo: &R,
+    status: Option<ReportStatus>,
+    limit: Option<u32>,
+    offset: Option<u32>,
+) -> DomainResult<ModerationQueueEnvelope>
+where
+    U: UsersRepository,
+    A: ArticlesRepository,
+    C: CommentsRepository,
+    R: ReportsRepository + ?Sized,
+{
+    let pagination = Pagination::new(limit, offset)?;
+    let reports = reports_repo
+        .list_reports(status, pagination)
+        .await
+        .map_err(|e| DomainError::Database {
+            message: e.to_string(),
+        })?;
+    let reports_count = reports_repo
+        .count_reports(status)
+        .await
+        .map_err(|e| DomainError::Database {
+            message: e.to_string(),
+        })?;
+
+    let mut views = Vec::with_capacity(reports.len());
+    for report in &reports {
+        views.push(build_moderation_view(users_repo, articles_repo, comments_repo, reports_repo, report, false).await?);
+    }
+    Ok(ModerationQueueEnvelope {
+        reports: views,
+        reports_count,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::{
+        InMemoryArticlesRepository, InMemoryCommentsRepository, InMemoryReportsRepository, InMemoryUsersRepository,
+    };
+    use crate::{
+        Email, ModerationAction, ModerationRecord, PasswordHash, Report, ReportId, ReportReason, ReportTarget,
+        User, UserId, Username,
+    };
+    use chrono::{DateTime, Duration, Utc};
+
+    struct Setup {
+        users_repo: InMemoryUsersRepository,
+        articles_repo: InMemoryArticlesRepository,
+        comments_repo: InMemoryCommentsRepository,
+        reports_repo: InMemoryReportsRepository,
+        author: UserId,
+        reporter: UserId,
+        moderator: UserId,
+    }
+
+    async fn setup() -> Setup {
+        let users_repo = InMemoryUsersRepository::new();
+        let mut ids = Vec::new();
+        for name in ["author", "reporter", "moderator"] {
+            let user = User::new(
+                UserId::random(),
+                Email::parse(format!("{name}@example.com")).unwrap(),
+                Username::new(name).unwrap(),
+                PasswordHash::new("hash").unwrap(),
+                Utc::now(),
+            );
+            ids.push(users_repo.create_user(user).await.unwrap().id);
+        }
+        Setup {
+            articles_repo: InMemoryArticlesRepository::new(users_repo.clone()),
+            comments_repo: InMemoryCommentsRepository::new(),
+            reports_repo: InMemoryReportsRepository::new(),
+            users_repo,
+            author: ids[0],
+            reporter: ids[1],
+            moderator: ids[2],
+        }
+    }
+
+    /// A report about the author's profile, made `days_ago`
+    async fn report(s: &Setup, reporter_id: UserId, days_ago: i64) -> ReportId {
+        let created_at = Utc::now() - Duration::days(days_ago);
+        let target = ReportTarget::Profile(s.author);
+        let reason = ReportReason::Spam;
+        let report = Report::new(ReportId::random(), reporter_id, target, s.author, reason, None, created_at).unwrap();
+        s.reports_repo.create_report(report).await.unwrap().id
+    }
+
+    async fn resolve(s: &Setup, id: ReportId, at: DateTime<Utc>) {
+        let mut report = s.reports_repo.get_report(id).await.unwrap().unwrap();
+        report.apply(ModerationAction::Resolve, s.moderator, at).unwrap();
+        let record = ModerationRecord {
+            report_id: id,
+            moderator_id: Some(s.moderator),
+            action: ModerationAction::Resolve,
+            note: None,
+            created_at: at,
+        };
+        s.reports_repo.record_moderation(&report, record).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_list_moderation_queue_oldest_first() {
+        let s = setup().await;
+        let oldest = report(&s, s.reporter, 3).await;
+        let middle = report(&s, s.moderator, 2).await;
+        let newest = report(&s, s.reporter, 1).await;
+        resolve(&s, middle, Utc::now()).await;
+        let queue = |status, limit| {
+            let s = &s;
+            async move {
+                let (users, articles, comments) = (&s.users_repo, &s.articles_repo, &s.comments_repo);
+                list_moderation_queue(users, articles, comments, &s.reports_repo, status, limit, None)
+                    .await
+                    .unwrap()
+            }
+        };
+        let ids = |queue: &ModerationQueueEnvelope| queue.reports.iter().map(|r| r.report.id).collect::<Vec<_>>();
+
+        let all = queue(None, None).await;
+        assert_eq!(ids(&all), [oldest, middle, newest]);
+        assert_eq!(all.reports[1].moderator.as_ref().map(Username::as_str), Some("moderator"));
+        assert!(all.reports[0].actions.is_none());
+
+        let open = queue(Some(ReportStatus::Open), Some(1)).await;
+        assert_eq!(ids(&open), [oldest]);
+        assert_eq!(open.reports_count, 2);
+    }
+}
@@ -0,0 +1,133 @@
+//! List my reports use case
+
+use crate::{
+    DomainError, DomainResult, ReportsEnvelope, UserId,
+    repositories::{ArticlesRepository, CommentsRepository, ReportsRepository, UsersRepository},
+    use_cases::moderation::report_content::report_subject,
+};
+
+/// List the reports the user made, so they can see how each was handled
+///
+/// Nothing is sent to reporters when a moderator acts; this is how they
+/// follow their reports.
+///
+/// # Business Rules
+/// - Most recent report first
+/// - Each report shows its status and when it was closed, but not who
+///   moderated it
+pub async fn list_my_reports<U, A, C, R>(
+    users_repo: &U,
+    articles_repo: &A,
+    comments_repo: &C,
+    reports_repo: &R,
+    reporter_id: UserId,
+) -> DomainResult<ReportsEnvelope>
+where
+    U: UsersRepository,
+    A: ArticlesRepository,
+    C: CommentsRepository,
+    R: ReportsRepository + ?Sized,
+{
+    let reports = reports_repo
+        .list_reports_by_reporter(reporter_id)
+        .await
+        .map_err(|e| DomainError::Database {
+            message: e.to_string(),
+        })?;
+
+    let mut views = Vec::with_capacity(reports.len());
+    for report in reports {
+        let subject = report_subject(users_repo, articles_repo, comments_repo, report.target).await?;
+        views.push(report.to_view(subject));
+    }
+    Ok(ReportsEnvelope::from(views))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::{
+        InMemoryArticlesRepository, InMemoryCommentsRepository, InMemoryReportsRepository, InMemoryUsersRepository,
+    };
+    use crate::{
+        Email, ModerationAction, ModerationRecord, PasswordHash, Report, ReportId, ReportReason, ReportStatus, ReportTarget,
+        User, Username,
+    };
+    use chrono::{DateTime, Duration, Utc};
+
+    struct Setup {
+        users_repo: InMemoryUsersRepository,
+        articles_repo: InMemoryArticlesRepository,
+        comments_repo: InMemoryCommentsRepository,
+        reports_repo: InMemoryReportsRepository,
+        author: UserId,
+        reporter: UserId,
+        moderator: UserId,
+    }
+
+    async fn setup() -> Setup {
+        let users_repo = InMemoryUsersRepository::new();
+        let mut ids = Vec::new();
+        for name in ["author", "reporter", "moderator"] {
+            let user = User::new(
+                UserId::random(),
+                Email::parse(format!("{name}@example.com")).unwrap(),
+                Username::new(name).unwrap(),
+                PasswordHash::new("hash").unwrap(),
+                Utc::now(),
+            );
+            ids.push(users_repo.create_user(user).await.unwrap().id);
+        }
+        Setup {
+            articles_repo: InMemoryArticlesRepository::new(users_repo.clone()),
+            comments_repo: InMemoryCommentsRepository::new(),
+            reports_repo: InMemoryReportsRepository::new(),
+            users_repo,
+            author: ids[0],
+            reporter: ids[1],
+            moderator: ids[2],
+        }
+    }
+
+    /// A report about the author's profile, made `days_ago`
+    async fn report(s: &Setup, reporter_id: UserId, days_ago: i64) -> ReportId {
+        let created_at = Utc::now() - Duration::days(days_ago);
+        let target = ReportTarget::Profile(s.author);
+        let reason = ReportReason::Spam;
+        let report = Report::new(ReportId::random(), reporter_id, target, s.author, reason, None, created_at).unwrap();
+        s.reports_repo.create_report(report).await.unwrap().id
+    }
+
+    async fn resolve(s: &Setup, id: ReportId, at: DateTime<Utc>) {
+        let mut report = s.reports_repo.get_report(id).await.unwrap().unwrap();
+        report.apply(ModerationAction::Resolve, s.moderator, at).unwrap();
+        let record = ModerationRecord {
+            report_id: id,
+            moderator_id: Some(s.moderator),
+            action: ModerationAction::Resolve,
+            note: None,
+            created_at: at,
+        };
+        s.reports_repo.record_moderation(&report, record).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_list_my_reports_newest_first() {
+        let s = setup().await;
+        let older = report(&s, s.reporter, 2).await;
+        let newer = report(&s, s.reporter, 1).await;
+        report(&s, s.moderator, 0).await;
+        let closed_at = Utc::now();
+        resolve(&s, older, closed_at).await;
+
+        let envelope = list_my_reports(&s.users_repo, &s.articles_repo, &s.comments_repo, &s.reports_repo, s.reporter)
+            .await
+            .unwrap();
+
+        let ids: Vec<ReportId> = envelope.reports.iter().map(|r| r.id).collect();
+        assert_eq!(ids, [newer, older]);
+        assert_eq!(envelope.reports[0].status, ReportStatus::Open);
+        assert_eq!(envelope.reports[1].status, ReportStatus::Resolved);
+        assert_eq!(envelope.reports[1].closed_at, Some(closed_at));
+    }
+}
@@ -0,0 +1,17 @@
+//! Moderation use cases
+//!
+//! Users report articles, comments and profiles; moderators work through
+//! the reports, hide content, suspend authors and close the reports, with
+//! every action on record.
+
+mod get_moderation_report;
+mod list_moderation_queue;
+mod list_my_reports;
+mod moderate_report;
+mod report_content;
+
+pub use get_moderation_report::*;
+pub use list_moderation_queue::*;
+pub use list_my_reports::*;
+pub use moderate_report::*;
+pub use report_content::*;
@@ -0,0 +1,461 @@
+//! Moderate report use case
+
+use chrono::{DateTime, Utc};
+
+use crate::{
+    DomainError, DomainEvent, DomainResult, ModerationAction, ModerationRecord, ModerationReportView, ReportId,
+    ReportTarget, UserId,
+    repositories::{ArticlesRepository, CommentsRepository, ReportsRepository, UsersRepository},
+    use_cases::moderation::get_moderation_report::build_moderation_view,
+};
+
+/// Input for taking a moderation action on a report
+#[derive(Debug, Clone)]
+pub struct ModerateReportInput {
+    pub report_id: ReportId,
+    pub action: ModerationAction,
+    /// Moderator's note, recorded with the action
+    pub note: Option<String>,
+}
+
+/// Output from moderating a report
+#[derive(Debug, Clone)]
+pub struct ModerateReportOutput {
+    pub view: ModerationReportView,
+    /// What the action did to the report and its target, for the caller to
+    /// act on
+    pub events: Vec<DomainEvent>,
+}
+
+/// Take a moderation action on a report
+///
+/// # Business Rules
+/// - Report must exist and not be closed yet
+/// - Acting on a report claims it; another moderator's claim must be
+///   respected
+/// - Moderators cannot act on reports about themselves
+/// - Only reported articles and comments can be hidden; they stay visible
+///   to their authors with a notice
+/// - Suspending affects whoever wrote the reported content, or the reported
+///   user
+/// - Resolving or dismissing closes the report
+/// - Nothing is sent to reporters; they follow their reports through
+///   `list_my_reports`, and the events returned are for the caller to log
+/// - Every action is recorded with the moderator's note before it is carried
+///   out; if another moderator claimed or closed the report in the meantime,
+///   nothing happens
+pub async fn moderate_report<U, A, C, R>(
+    users_repo: &U,
+    articles_repo: &A,
+    comments_repo: &C,
+    reports_repo: &R,
+    input: ModerateReportInput,
+    moderator_id: UserId,
+    now: DateTime<Utc>,
+) -> DomainResult<ModerateReportOutput>
+where
+    U: UsersRepository,
+    A: ArticlesRepository,
+    C: CommentsRepository,
+    R: ReportsRepository + ?Sized,
+{
+    let ModerateReportInput { report_id, action, note } = input;
+    let mut report = reports_repo
+        .get_report(report_id)
+        .await
+        .map_err(|e| DomainError::Database {
+            message: e.to_string(),
+        })?
+        .ok_or(DomainError::NotFound { entity: "report" })?;
+    if report.target_author_id == moderator_id {
+        return Err(DomainError::UnauthorizedAction);
+    }
+    if action == ModerationAction::Hide && matches!(report.target, ReportTarget::Profile(_)) {
+        return Err(DomainError::NothingToHide);
+    }
+    report.apply(action, moderator_id, now)?;
+
+    // Recorded before the action is carried out, so a moderator who lost a
+    // race for the report changes nothing
+    let record = ModerationRecord {
+        report_id: report.id,
+        moderator_id: Some(moderator_id),
+        action,
+        note: note
+            .map(|note| note.trim().to_owned())
+            .filter(|note| !note.is_empty()),
+        created_at: now,
+    };
+    let saved = reports_repo
+        .record_moderation(&report, record)
+        .await
+        .map_err(|e| DomainError::Database {
+            message: e.to_string(),
+        })?;
+    if !saved {
+        let current = reports_repo
+            .get_report(report_id)
+            .await
+            .map_err(|e| DomainError::Database {
+                message: e.to_string(),
+            })?;
+        return Err(match current {
+            Some(current) if !current.is_closed() => DomainError::ReportClaimed,
+            _ => DomainError::ReportClosed,
+        });
+    }
+
+    let mut events = Vec::new();
+    match (action, report.target) {
+        (ModerationAction::Hide, ReportTarget::Article(id)) => {
+            articles_repo
+                .hide_article(id, now)
+                .await
+                .map_err(|e| DomainError::Database {
+                    message: e.to_string(),
+                })?;
+            events.push(DomainEvent::ReportActioned {
+                report_id: report.id,
+                reporter_id: report.reporter_id,
+                action,
+            });
+        }
+        (ModerationAction::Hide, ReportTarget::Comment(id)) => {
+            comments_repo
+                .hide_comment(id, now)
+                .await
+                .map_err(|e| DomainError::Database {
+                    message: e.to_string(),
+                })?;
+            events.push(DomainEvent::ReportActioned {
+                report_id: report.id,
+                reporter_id: report.reporter_id,
+                action,
+            });
+        }
+        (ModerationAction::Suspend, _) => {
+            users_repo
+                .suspend_user(report.target_author_id, now)
+                .await
+                .map_err(|e| DomainError::Database {
+                    message: e.to_string(),
+                })?;
+            events.push(DomainEvent::AccountSuspended {
+                user_id: report.target_author_id,
+            });
+            events.push(DomainEvent::ReportActioned {
+                report_id: report.id,
+                reporter_id: report.reporter_id,
+                action,
+            });
+        }
+        (ModerationAction::Resolve | ModerationAction::Dismiss, _) => {
+            events.push(DomainEvent::ReportClosed {
+                report_id: report.id,
+                reporter_id: report.reporter_id,
+                status: report.status,
+            });
+        }
+        _ => {}
+    }
+
+    let view = build_moderation_view(users_repo, articles_repo, comments_repo, reports_repo, &report, true).await?;
+    Ok(ModerateReportOutput { view, events })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::{
+        InMemoryArticlesRepository, InMemoryCommentsRepository, InMemoryReportsRepository, InMemoryUsersRepository,
+    };
+    use crate::use_cases::comments::list_comments;
+    use crate::use_cases::moderation::{ReportInput, ReportedContent, report_content};
+    use crate::{
+        Article, ArticleDraft, ArticleId, Comment, CommentDraft, CommentId, Email, Pagination, PasswordHash, Report,
+        ReportReason, ReportStatus, TagList, User, Username,
+    };
+
+    struct Setup {
+        users_repo: InMemoryUsersRepository,
+        articles_repo: InMemoryArticlesRepository,
+        comments_repo: InMemoryCommentsRepository,
+        reports_repo: InMemoryReportsRepository,
+        author: User,
+        reporter: User,
+        moderator: User,
+        article: Article,
+    }
+
+    async fn user(users_repo: &InMemoryUsersRepository, name: &str) -> User {
+        let user = User::new(
+            UserId::random(),
+            Email::parse(format!("{name}@example.com")).unwrap(),
+            Username::new(name).unwrap(),
+            PasswordHash::new("hash").unwrap(),
+            Utc::now(),
+        );
+        users_repo.create_user(user).await.unwrap()
+    }
+
+    async fn setup() -> Setup {
+        let users_repo = InMemoryUsersRepository::new();
+        let articles_repo = InMemoryArticlesRepository::new(users_repo.clone());
+        let comments_repo = InMemoryCommentsRepository::new();
+        let reports_repo = InMemoryReportsRepository::new();
+        let author = user(&users_repo, "author").await;
+        let reporter = user(&users_repo, "reporter").await;
+        let moderator = user(&users_repo, "moderator").await;
+
+        let draft = ArticleDraft::new("Abusive", "Description", "Body", TagList::default()).unwrap();
+        let article = Article::publish(ArticleId::random(), author.id, draft, Utc::now()).unwrap();
+        let article = articles_repo.create_article(article).await.unwrap();
+
+        Setup {
+            users_repo,
+            articles_repo,
+            comments_repo,
+            reports_repo,
+            author,
+            reporter,
+            moderator,
+            article,
+        }
+    }
+
+    async fn report(s: &Setup, content: ReportedContent) -> ReportId {
+        let input = ReportInput {
+            content,
+            reason: ReportReason::Harassment,
+            details: None,
+        };
+        report_content(
+            &s.users_repo,
+            &s.articles_repo,
+            &s.comments_repo,
+            &s.reports_repo,
+            s.reporter.id,
+            input,
+            Utc::now(),
+        )
+        .await
+        .unwrap()
+        .id
+    }
+
+    async fn moderate(s: &Setup, id: ReportId, moderator_id: UserId, action: ModerationAction) -> DomainResult<ModerateReportOutput> {
+        moderate_report(
+            &s.users_repo,
+            &s.articles_repo,
+            &s.comments_repo,
+            &s.reports_repo,
+            ModerateReportInput {
+                report_id: id,
+                action,
+                note: Some("  looked into it ".to_owned()),
+            },
+            moderator_id,
+            Utc::now(),
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn test_moderate_report_hides_article_and_resolves() {
+        let s = setup().await;
+        let id = report(&s, ReportedContent::Article { slug: s.article.slug.as_str().to_owned() }).await;
+
+        let output = moderate(&s, id, s.moderator.id, ModerationAction::Hide).await.unwrap();
+        assert_eq!(output.view.report.status, ReportStatus::Claimed);
+        assert_eq!(
+            output.events,
+            vec![DomainEvent::ReportActioned {
+                report_id: id,
+                reporter_id: s.reporter.id,
+                action: ModerationAction::Hide,
+            }]
+        );
+
+        let stored = s.articles_repo.get_article_by_id(s.article.id).await.unwrap().unwrap();
+        assert!(stored.is_hidden());
+        assert!(!stored.is_visible_to(Some(s.reporter.id)));
+        assert!(stored.is_visible_to(Some(s.author.id)));
+
+        let output = moderate(&s, id, s.moderator.id, ModerationAction::Resolve).await.unwrap();
+        assert_eq!(output.view.report.status, ReportStatus::Resolved);
+        assert_eq!(
+            output.events,
+            vec![DomainEvent::ReportClosed {
+                report_id: id,
+                reporter_id: s.reporter.id,
+                status: ReportStatus::Resolved,
+            }]
+        );
+        let actions = output.view.actions.unwrap();
+        assert_eq!(actions.len(), 2);
+        assert_eq!(actions[0].action, ModerationAction::Hide);
+        assert_eq!(actions[0].note.as_deref(), Some("looked into it"));
+
+        let result = moderate(&s, id, s.moderator.id, ModerationAction::Dismiss).await;
+        assert!(matches!(result, Err(DomainError::ReportClosed)));
+    }
+
+    #[tokio::test]
+    async fn test_moderate_report_hides_comment_from_others() {
+        let s = setup().await;
+        let draft = CommentDraft::new("Rude").unwrap();
+        let comment = Comment::new(CommentId::new(1), s.article.id, s.author.id, draft, Utc::now());
+        s.comments_repo.create_comment(comment).await.unwrap();
+        let id = report(&s, ReportedContent::Comment { id: CommentId::new(1) }).await;
+
+        moderate(&s, id, s.moderator.id, ModerationAction::Hide).await.unwrap();
+
+        let slug = s.article.slug.as_str();
+        let seen = list_comments(&s.users_repo, &s.articles_repo, &s.comments_repo, slug, Some(s.reporter.id))
+            .await
+            .unwrap();
+        assert!(seen.is_empty());
+        let own = list_comments(&s.users_repo, &s.articles_repo, &s.comments_repo, slug, Some(s.author.id))
+            .await
+            .unwrap();
+        assert_eq!(own.len(), 1);
+        assert!(own[0].moderation.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_moderate_report_suspends_author() {
+        let s = setup().await;
+        let id = report(&s, ReportedContent::Profile { username: "author".to_owned() }).await;
+
+        let result = moderate(&s, id, s.moderator.id, ModerationAction::Hide).await;
+        assert!(matches!(result, Err(DomainError::NothingToHide)));
+
+        let output = moderate(&s, id, s.moderator.id, ModerationAction::Suspend).await.unwrap();
+        assert_eq!(
+            output.events,
+            vec![
+                DomainEvent::AccountSuspended { user_id: s.author.id },
+                DomainEvent::ReportActioned {
+                    report_id: id,
+                    reporter_id: s.reporter.id,
+                    action: ModerationAction::Suspend,
+                },
+            ]
+        );
+        let author = s.users_repo.get_user_by_id(s.author.id).await.unwrap().unwrap();
+        assert!(author.is_suspended());
+    }
+
+    #[tokio::test]
+    async fn test_moderate_report_respects_claims() {
+        let s = setup().await;
+        let other = user(&s.users_repo, "other").await;
+        let id = report(&s, ReportedContent::Article { slug: s.article.slug.as_str().to_owned() }).await;
+
+        moderate(&s, id, s.moderator.id, ModerationAction::Claim).await.unwrap();
+        let result = moderate(&s, id, other.id, ModerationAction::Dismiss).await;
+        assert!(matches!(result, Err(DomainError::ReportClaimed)));
+
+        let result = moderate(&s, id, s.author.id, ModerationAction::Dismiss).await;
+        assert!(matches!(result, Err(DomainError::UnauthorizedAction)));
+
+        let result = moderate(&s, ReportId::random(), s.moderator.id, ModerationAction::Claim).await;
+        assert!(matches!(result, Err(DomainError::NotFound { entity: "report" })));
+    }
+
+    /// Hands out the report as it was before another moderator got to it,
+    /// once
+    struct StaleReports {
+        inner: InMemoryReportsRepository,
+        stale: std::sync::Mutex<Option<Report>>,
+    }
+
+    #[async_trait::async_trait]
+    impl ReportsRepository for StaleReports {
+        async fn create_report(&self, report: Report) -> anyhow::Result<Report> {
+            self.inner.create_report(report).await
+        }
+        async fn get_report(&self, id: ReportId) -> anyhow::Result<Option<Report>> {
+            let stale = self.stale.lock().unwrap().take();
+            match stale {
+                Some(report) => Ok(Some(report)),
+                None => self.inner.get_report(id).await,
+            }
+        }
+        async fn find_open_report(&self, reporter_id: UserId, target: ReportTarget) -> anyhow::Result<Option<Report>> {
+            self.inner.find_open_report(reporter_id, target).await
+        }
+        async fn list_reports(&self, status: Option<ReportStatus>, pagination: Pagination) -> anyhow::Result<Vec<Report>> {
+            self.inner.list_reports(status, pagination).await
+        }
+        async fn count_reports(&self, status: Option<ReportStatus>) -> anyhow::Result<u64> {
+            self.inner.count_reports(status).await
+        }
+        async fn list_reports_by_reporter(&self, reporter_id: UserId) -> anyhow::Result<Vec<Report>> {
+            self.inner.list_reports_by_reporter(reporter_id).await
+        }
+        async fn record_moderation(&self, report: &Report, record: ModerationRecord) -> anyhow::Result<bool> {
+            self.inner.record_moderation(report, record).await
+        }
+        async fn list_moderation_records(&self, report_id: ReportId) -> anyhow::Result<Vec<ModerationRecord>> {
+            self.inner.list_moderation_records(report_id).await
+        }
+    }
+
+    #[tokio::test]
+    async fn test_moderate_report_loses_race_without_acting() {
+        let s = setup().await;
+        let other = user(&s.users_repo, "other").await;
+        let id = report(&s, ReportedContent::Article { slug: s.article.slug.as_str().to_owned() }).await;
+        let stale = s.reports_repo.get_report(id).await.unwrap().unwrap();
+        moderate(&s, id, s.moderator.id, ModerationAction::Claim).await.unwrap();
+
+        let reports_repo = StaleReports {
+            inner: s.reports_repo.clone(),
+            stale: std::sync::Mutex::new(Some(stale.clone())),
+        };
+        let input = ModerateReportInput {
+            report_id: id,
+            action: ModerationAction::Hide,
+            note: None,
+        };
+        let result = moderate_report(
+            &s.users_repo,
+            &s.articles_repo,
+            &s.comments_repo,
+            &reports_repo,
+            input,
+            other.id,
+            Utc::now(),
+        )
+        .await;
+        assert!(matches!(result, Err(DomainError::ReportClaimed)));
+        let article = s.articles_repo.get_article_by_id(s.article.id).await.unwrap().unwrap();
+        assert!(!article.is_hidden());
+        assert_eq!(s.reports_repo.list_moderation_records(id).await.unwrap().len(), 1);
+
+        moderate(&s, id, s.moderator.id, ModerationAction::Dismiss).await.unwrap();
+        let reports_repo = StaleReports {
+            inner: s.reports_repo.clone(),
+            stale: std::sync::Mutex::new(Some(stale)),
+        };
+        let input = ModerateReportInput {
+            report_id: id,
+            action: ModerationAction::Suspend,
+            note: None,
+        };
+        let result = moderate_report(
+            &s.users_repo,
+            &s.articles_repo,
+            &s.comments_repo,
+            &reports_repo,
+            input,
+            other.id,
+            Utc::now(),
+        )
+        .await;
+        assert!(matches!(result, Err(DomainError::ReportClosed)));
+        let author = s.users_repo.get_user_by_id(s.author.id).await.unwrap().unwrap();
+        assert!(!author.is_suspended());
+    }
+}
@@ -0,0 +1,302 @@
+//! Report content use case
+
+use chrono::{DateTime, Utc};
+
+use crate::{
+    CommentId, DomainError, DomainResult, Report, ReportId, ReportReason, ReportSubject, ReportTarget, ReportView,
+    UserId, Username,
+    repositories::{ArticlesRepository, CommentsRepository, ReportsRepository, UsersRepository},
+};
+
+/// What is being reported, as the reporter names it
+#[derive(Debug, Clone)]
+pub enum ReportedContent {
+    Article { slug: String },
+    Comment { id: CommentId },
+    Profile { username: String },
+}
+
+/// Input for reporting content
+#[derive(Debug, Clone)]
+pub struct ReportInput {
+    pub content: ReportedContent,
+    pub reason: ReportReason,
+    pub details: Option<String>,
+}
+
+/// Report an article, comment or profile to the moderators
+///
+/// # Business Rules
+/// - The content must exist and be visible to the reporter
+/// - Users cannot report themselves or content they wrote
+/// - Details are optional and limited in length
+/// - Reporting the same content again while the first report is still
+///   open returns that report
+pub async fn report_content<U, A, C, R>(
+    users_repo: &U,
+    articles_repo: &A,
+    comments_repo: &C,
+    reports_repo: &R,
+    reporter_id: UserId,
+    input: ReportInput,
+    now: DateTime<Utc>,
+) -> DomainResult<ReportView>
+where
+    U: UsersRepository,
+    A: ArticlesRepository,
+    C: CommentsRepository,
+    R: ReportsRepository + ?Sized,
+{
+    let (target, target_author_id) = match &input.content {
+        ReportedContent::Article { slug } => {
+            let article = articles_repo
+                .get_article_by_slug(slug)
+                .await
+                .map_err(|e| DomainError::Database {
+                    message: e.to_string(),
+                })?
+                .filter(|article| article.is_visible_to(Some(reporter_id)))
+                .ok_or(DomainError::NotFound { entity: "article" })?;
+            if article.can_edit(&reporter_id) {
+                return Err(DomainError::SelfReport);
+            }
+            (ReportTarget::Article(article.id), article.author_id)
+        }
+        ReportedContent::Comment { id } => {
+            let comment = comments_repo
+                .get_comment_by_id(*id)
+                .await
+                .map_err(|e| DomainError::Database {
+                    message: e.to_string(),
+                })?
+                .filter(|comment| comment.is_visible_to(Some(reporter_id)))
+                .ok_or(DomainError::NotFound { entity: "comment" })?;
+            articles_repo
+                .get_article_by_id(comment.article_id)
+                .await
+                .map_err(|e| DomainError::Database {
+                    message: e.to_string(),
+                })?
+                .filter(|article| article.is_visible_to(Some(reporter_id)))
+                .ok_or(DomainError::NotFound { entity: "comment" })?;
+            (ReportTarget::Comment(comment.id), comment.author_id)
+        }
+        ReportedContent::Profile { username } => {
+            let user = users_repo
+                .get_user_by_username(username)
+                .await
+                .map_err(|e| DomainError::Database {
+                    message: e.to_string(),
+                })?
+                .filter(|user| !user.is_deactivated())
+                .ok_or(DomainError::NotFound { entity: "profile" })?;
+            (ReportTarget::Profile(user.id), user.id)
+        }
+    };
+
+    let report = Report::new(
+        ReportId::random(),
+        reporter_id,
+        target,
+        target_author_id,
+        input.reason,
+        input.details,
+        now,
+    )?;
+
+    let existing = reports_repo
+        .find_open_report(reporter_id, target)
+        .await
+        .map_err(|e| DomainError::Database {
+            message: e.to_string(),
+        })?;
+    let report = match existing {
+        Some(existing) => existing,
+        None => reports_repo
+            .create_report(report)
+            .await
+            .map_err(|e| DomainError::Database {
+                message: e.to_string(),
+            })?,
+    };
+
+    let subject = report_subject(users_repo, articles_repo, comments_repo, report.target).await?;
+    Ok(report.to_view(subject))
+}
+
+/// The reported content as it is now; `None` once it is gone
+pub(super) async fn report_subject<U, A, C>(
+    users_repo: &U,
+    articles_repo: &A,
+    comments_repo: &C,
+    target: ReportTarget,
+) -> DomainResult<Option<ReportSubject>>
+where
+    U: UsersRepository,
+    A: ArticlesRepository,
+    C: CommentsRepository,
+{
+    let subject = match target {
+        ReportTarget::Article(id) => articles_repo
+            .get_article_by_id(id)
+            .await
+            .map_err(|e| DomainError::Database {
+                message: e.to_string(),
+            })?
+            .map(|article| ReportSubject::Article {
+                slug: article.slug.as_str().to_owned(),
+                title: article.title,
+            }),
+        ReportTarget::Comment(id) => {
+            let comment = comments_repo
+                .get_comment_by_id(id)
+                .await
+                .map_err(|e| DomainError::Database {
+                    message: e.to_string(),
+                })?;
+            match comment {
+                Some(comment) => articles_repo
+                    .get_article_by_id(comment.article_id)
+                    .await
+                    .map_err(|e| DomainError::Database {
+                        message: e.to_string(),
+                    })?
+                    .map(|article| ReportSubject::Comment {
+                        id: comment.id,
+                        article_slug: article.slug.as_str().to_owned(),
+                        body: comment.body,
+                    }),
+                None => None,
+            }
+        }
+        ReportTarget::Profile(id) => username(users_repo, Some(id))
+            .await?
+            .map(|username| ReportSubject::Profile { username }),
+    };
+    Ok(subject)
+}
+
+/// The username of the account, if there is one
+pub(super) async fn username<U>(users_repo: &U, id: Option<UserId>) -> DomainResult<Option<Username>>
+where
+    U: UsersRepository,
+{
+    let Some(id) = id else {
+        return Ok(None);
+    };
+    let user = users_repo
+        .get_user_by_id(id)
+        .await
+        .map_err(|e| DomainError::Database {
+            message: e.to_string(),
+        })?;
+    Ok(user.map(|user| user.username))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::{
+        InMemoryArticlesRepository, InMemoryCommentsRepository, InMemoryReportsRepository, InMemoryUsersRepository,
+    };
+    use crate::use_cases::moderation::list_my_reports;
+    use crate::{Article, ArticleDraft, ArticleId, Email, PasswordHash, ReportStatus, TagList, User};
+
+    async fn user(users_repo: &InMemoryUsersRepository, name: &str) -> User {
+        let user = User::new(
+            UserId::random(),
+            Email::parse(format!("{name}@example.com")).unwrap(),
+            Username::new(name).unwrap(),
+            PasswordHash::new("hash").unwrap(),
+            Utc::now(),
+        );
+        users_repo.create_user(user).await.unwrap()
+    }
+
+    fn input(content: ReportedContent) -> ReportInput {
+        ReportInput {
+            content,
+            reason: ReportReason::Spam,
+            details: Some("buy now".to_owned()),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_report_content() {
+        let users_repo = InMemoryUsersRepository::new();
+        let articles_repo = InMemoryArticlesRepository::new(users_repo.clone());
+        let comments_repo = InMemoryCommentsRepository::new();
+        let reports_repo = InMemoryReportsRepository::new();
+        let author = user(&users_repo, "author").await;
+        let reporter = user(&users_repo, "reporter").await;
+        let draft = ArticleDraft::new("Spam", "Description", "Body", TagList::default()).unwrap();
+        let article = Article::publish(ArticleId::random(), author.id, draft, Utc::now()).unwrap();
+        let article = articles_repo.create_article(article).await.unwrap();
+        let slug = article.slug.as_str().to_owned();
+
+        let view = report_content(
+            &users_repo,
+            &articles_repo,
+            &comments_repo,
+            &reports_repo,
+            reporter.id,
+            input(ReportedContent::Article { slug: slug.clone() }),
+            Utc::now(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(view.status, ReportStatus::Open);
+        assert_eq!(
+            view.target,
+            Some(ReportSubject::Article {
+                slug: slug.clone(),
+                title: "Spam".to_owned(),
+            })
+        );
+
+        let again = report_content(
+            &users_repo,
+            &articles_repo,
+            &comments_repo,
+            &reports_repo,
+            reporter.id,
+            input(ReportedContent::Article { slug: slug.clone() }),
+            Utc::now(),
+        )
+        .await
+        .unwrap();
+        assert_eq!(again.id, view.id);
+
+        let own = report_content(
+            &users_repo,
+            &articles_repo,
+            &comments_repo,
+            &reports_repo,
+            author.id,
+            input(ReportedContent::Article { slug }),
+            Utc::now(),
+        )
+        .await;
+        assert!(matches!(own, Err(DomainError::SelfReport)));
+
+        let missing = report_content(
+            &users_repo,
+            &articles_repo,
+            &comments_repo,
+            &reports_repo,
+            reporter.id,
+            input(ReportedContent::Profile {
+                username: "nobody".to_owned(),
+            }),
+            Utc::now(),
+        )
+        .await;
+        assert!(matches!(missing, Err(DomainError::NotFound { entity: "profile" })));
+
+        let mine = list_my_reports(&users_repo, &articles_repo, &comments_repo, &reports_repo, reporter.id)
+            .await
+            .unwrap();
+        assert_eq!(mine.reports.len(), 1);
+        assert_eq!(mine.reports[0].id, view.id);
+    }
+}
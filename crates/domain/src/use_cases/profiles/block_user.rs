@@ -0,0 +1,97 @@
+//! Block user use case
+
+use crate::{
+    DomainError, DomainResult, Profile, UserId,
+    repositories::UsersRepository,
+};
+
+/// Block a user
+///
+/// # Business Rules
+/// - Target user must exist
+/// - Cannot block yourself
+/// - Any follow relationship between the two users is removed
+/// - Blocking already-blocked user is idempotent
+pub async fn block_user<U>(
+    users_repo: &U,
+    username: &str,
+    blocker_id: UserId,
+) -> DomainResult<Profile>
+where
+    U: UsersRepository,
+{
+    let target = users_repo
+        .get_user_by_username(username)
+        .await
+        .map_err(|_| DomainError::NotFound { entity: "profile" })?
+        .ok_or(DomainError::NotFound { entity: "profile" })?;
+
+    // Cannot block yourself
+    Profile::validate_follow_action(&target.id, &blocker_id)?;
+
+    users_repo
+        .block_user(blocker_id, target.id)
+        .await
+        .map_err(|e| DomainError::Database {
+            message: e.to_string(),
+        })?;
+
+    // The block also ended any follow
+    Ok(target.to_profile(false))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::InMemoryUsersRepository;
+    use crate::{Email, PasswordHash, User, Username};
+    use chrono::Utc;
+
+    async fn setup() -> (InMemoryUsersRepository, User, UserId) {
+        let users_repo = InMemoryUsersRepository::new();
+        let target = User::new(
+            UserId::random(),
+            Email::parse("target@example.com").unwrap(),
+            Username::new("target").unwrap(),
+            PasswordHash::new("hash").unwrap(),
+            Utc::now(),
+        );
+        let target = users_repo.create_user(target).await.unwrap();
+        let blocker_id = UserId::random();
+        (users_repo, target, blocker_id)
+    }
+
+    #[tokio::test]
+    async fn test_block_user_removes_follows() {
+        let (users_repo, target, blocker_id) = setup().await;
+        users_repo.follow_user(blocker_id, target.id).await.unwrap();
+        users_repo.follow_user(target.id, blocker_id).await.unwrap();
+
+        let profile = block_user(&users_repo, target.username.as_str(), blocker_id)
+            .await
+            .unwrap();
+
+        assert!(!profile.following);
+        assert!(users_repo.is_blocking(blocker_id, target.id).await.unwrap());
+        assert!(!users_repo.is_following(blocker_id, target.id).await.unwrap());
+        assert!(!users_repo.is_following(target.id, blocker_id).await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_block_user_self() {
+        let (users_repo, target, _) = setup().await;
+
+        let result = block_user(&users_repo, target.username.as_str(), target.id).await;
+
+        assert!(matches!(result, Err(DomainError::UnauthorizedAction)));
+    }
+
+    #[tokio::test]
+    async fn test_block_user_not_found() {
+        let (users_repo, _, blocker_id) = setup().await;
+
+        let result = block_user(&users_repo, "nonexistent", blocker_id).await;
+
+        assert!(matches!(result, Err(DomainError::NotFound { entity: "profile" })));
+    }
+}
@@ -10,6 +10,7 @@ use crate::{
 /// # Business Rules
 /// - Target user must exist
 /// - Cannot follow yourself
+/// - Cannot follow someone who has blocked you
 /// - Following already-followed user is idempotent
 pub async fn follow_user<U>(
     users_repo: &U,
@@ -28,6 +29,16 @@ where
     // Cannot follow yourself
     Profile::validate_follow_action(&target.id, &follower_id)?;
 
+    let blocked = users_repo
+        .is_blocking(target.id, follower_id)
+        .await
+        .map_err(|e| DomainError::Database {
+            message: e.to_string(),
+        })?;
+    if blocked {
+        return Err(DomainError::Blocked);
+    }
+
     users_repo
         .follow_user(follower_id, target.id)
         .await
@@ -100,4 +111,14 @@ mod tests {
         let profile = result.unwrap();
         assert!(profile.following);
     }
+
+    #[tokio::test]
+    async fn test_follow_user_blocked() {
+        let (users_repo, target, follower_id) = setup().await;
+        users_repo.block_user(target.id, follower_id).await.unwrap();
+
+        let result = follow_user(&users_repo, target.username.as_str(), follower_id).await;
+
+        assert!(matches!(result, Err(DomainError::Blocked)));
+    }
 }
@@ -0,0 +1,26 @@
+//! Get avatar use case
+
+use crate::{
+    Avatar, DomainError, DomainResult,
+    repositories::UsersRepository,
+};
+
+/// Get the generated avatar of a user
+///
+/// # Business Rules
+/// - User must exist and must not be deactivated
+/// - The avatar depends on the username alone, whether or not the user has
+///   set an image of their own
+pub async fn get_avatar<U>(users_repo: &U, username: &str) -> DomainResult<Avatar>
+where
+    U: UsersRepository,
+{
+    let user = users_repo
+        .get_user_by_username(username)
+        .await
+        .map_err(|_| DomainError::NotFound { entity: "profile" })?
+        .filter(|user| !user.is_deactivated())
+        .ok_or(DomainError::NotFound { entity: "profile" })?;
+
+    Ok(Avatar::new(&user.username))
+}
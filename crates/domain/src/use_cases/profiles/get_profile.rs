@@ -8,7 +8,8 @@ use crate::{
 /// Get a user's profile by username
 ///
 /// # Business Rules
-/// - User must exist
+/// - User must exist and must not be deactivated
+/// - Cannot view the profile of someone who has blocked you
 /// - Following status is relative to the viewer (false if no viewer)
 pub async fn get_profile<U>(
     users_repo: &U,
@@ -22,15 +23,20 @@ where
         .get_user_by_username(username)
         .await
         .map_err(|_| DomainError::NotFound { entity: "profile" })?
+        .filter(|user| !user.is_deactivated())
         .ok_or(DomainError::NotFound { entity: "profile" })?;
 
-    let following = match viewer_id {
-        Some(viewer) => users_repo
-            .is_following(viewer, user.id)
-            .await
-            .unwrap_or(false),
-        None => false,
+    let Some(viewer) = viewer_id else {
+        return Ok(user.to_profile(false));
     };
+    let db_error = |e: anyhow::Error| DomainError::Database {
+        message: e.to_string(),
+    };
+
+    if users_repo.is_blocking(user.id, viewer).await.map_err(db_error)? {
+        return Err(DomainError::Blocked);
+    }
+    let following = users_repo.is_following(viewer, user.id).await.map_err(db_error)?;
 
     Ok(user.to_profile(following))
 }
@@ -76,6 +82,16 @@ mod tests {
         assert!(matches!(result, Err(DomainError::NotFound { entity: "profile" })));
     }
 
+    #[tokio::test]
+    async fn test_get_profile_deactivated() {
+        let (users_repo, user) = setup().await;
+        users_repo.deactivate_user(user.id, Utc::now()).await.unwrap();
+
+        let result = get_profile(&users_repo, user.username.as_str(), None).await;
+
+        assert!(matches!(result, Err(DomainError::NotFound { entity: "profile" })));
+    }
+
     #[tokio::test]
     async fn test_get_profile_with_following() {
         let (users_repo, user) = setup().await;
@@ -101,4 +117,17 @@ mod tests {
         let profile = result.unwrap();
         assert!(!profile.following);
     }
+
+    #[tokio::test]
+    async fn test_get_profile_blocked_viewer() {
+        let (users_repo, user) = setup().await;
+        let viewer_id = UserId::random();
+        users_repo.block_user(user.id, viewer_id).await.unwrap();
+
+        let blocked = get_profile(&users_repo, user.username.as_str(), Some(viewer_id)).await;
+        let anonymous = get_profile(&users_repo, user.username.as_str(), None).await;
+
+        assert!(matches!(blocked, Err(DomainError::Blocked)));
+        assert!(anonymous.is_ok());
+    }
 }
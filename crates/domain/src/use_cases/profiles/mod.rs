@@ -2,10 +2,20 @@
 //!
 //! All business logic for profile operations lives here.
 
+mod block_user;
 mod follow_user;
+mod get_avatar;
 mod get_profile;
+mod mute_user;
+mod unblock_user;
 mod unfollow_user;
+mod unmute_user;
 
+pub use block_user::*;
 pub use follow_user::*;
+pub use get_avatar::*;
 pub use get_profile::*;
+pub use mute_user::*;
+pub use unblock_user::*;
 pub use unfollow_user::*;
+pub use unmute_user::*;
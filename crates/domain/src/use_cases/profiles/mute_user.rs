@@ -0,0 +1,95 @@
+//! Mute user use case
+
+use crate::{
+    DomainError, DomainResult, Profile, UserId,
+    repositories::UsersRepository,
+};
+
+/// Mute a user
+///
+/// # Business Rules
+/// - Target user must exist
+/// - Cannot mute yourself
+/// - The muted user is not told and can still interact; their articles and
+///   comments are just hidden from the muter
+/// - Muting already-muted user is idempotent
+pub async fn mute_user<U>(
+    users_repo: &U,
+    username: &str,
+    muter_id: UserId,
+) -> DomainResult<Profile>
+where
+    U: UsersRepository,
+{
+    let target = users_repo
+        .get_user_by_username(username)
+        .await
+        .map_err(|_| DomainError::NotFound { entity: "profile" })?
+        .ok_or(DomainError::NotFound { entity: "profile" })?;
+
+    // Cannot mute yourself
+    Profile::validate_follow_action(&target.id, &muter_id)?;
+
+    users_repo
+        .mute_user(muter_id, target.id)
+        .await
+        .map_err(|e| DomainError::Database {
+            message: e.to_string(),
+        })?;
+
+    let following = users_repo
+        .is_following(muter_id, target.id)
+        .await
+        .map_err(|e| DomainError::Database {
+            message: e.to_string(),
+        })?;
+    Ok(target.to_profile(following))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::InMemoryUsersRepository;
+    use crate::{Email, PasswordHash, User, Username};
+    use chrono::Utc;
+
+    async fn setup() -> (InMemoryUsersRepository, User, UserId) {
+        let users_repo = InMemoryUsersRepository::new();
+        let target = User::new(
+            UserId::random(),
+            Email::parse("target@example.com").unwrap(),
+            Username::new("target").unwrap(),
+            PasswordHash::new("hash").unwrap(),
+            Utc::now(),
+        );
+        let target = users_repo.create_user(target).await.unwrap();
+        let muter_id = UserId::random();
+        (users_repo, target, muter_id)
+    }
+
+    #[tokio::test]
+    async fn test_mute_user_keeps_follow() {
+        let (users_repo, target, muter_id) = setup().await;
+        users_repo.follow_user(muter_id, target.id).await.unwrap();
+
+        let profile = mute_user(&users_repo, target.username.as_str(), muter_id)
+            .await
+            .unwrap();
+
+        assert!(profile.following);
+        assert!(users_repo.is_muting(muter_id, target.id).await.unwrap());
+        assert_eq!(
+            users_repo.list_hidden_authors(muter_id).await.unwrap(),
+            vec![target.id]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_mute_user_self() {
+        let (users_repo, target, _) = setup().await;
+
+        let result = mute_user(&users_repo, target.username.as_str(), target.id).await;
+
+        assert!(matches!(result, Err(DomainError::UnauthorizedAction)));
+    }
+}
@@ -0,0 +1,68 @@
+//! Unblock user use case
+
+use crate::{
+    DomainError, DomainResult, Profile, UserId,
+    repositories::UsersRepository,
+};
+
+/// Unblock a user
+///
+/// # Business Rules
+/// - Target user must exist
+/// - Cannot unblock yourself
+/// - Unblocking not-blocked user is idempotent
+/// - Follows removed by the block are not restored
+pub async fn unblock_user<U>(
+    users_repo: &U,
+    username: &str,
+    blocker_id: UserId,
+) -> DomainResult<Profile>
+where
+    U: UsersRepository,
+{
+    let target = users_repo
+        .get_user_by_username(username)
+        .await
+        .map_err(|_| DomainError::NotFound { entity: "profile" })?
+        .ok_or(DomainError::NotFound { entity: "profile" })?;
+
+    // Cannot unblock yourself
+    Profile::validate_follow_action(&target.id, &blocker_id)?;
+
+    users_repo
+        .unblock_user(blocker_id, target.id)
+        .await
+        .map_err(|e| DomainError::Database {
+            message: e.to_string(),
+        })?;
+
+    Ok(target.to_profile(false))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::InMemoryUsersRepository;
+    use crate::{Email, PasswordHash, User, Username};
+    use chrono::Utc;
+
+    #[tokio::test]
+    async fn test_unblock_user_success() {
+        let users_repo = InMemoryUsersRepository::new();
+        let target = User::new(
+            UserId::random(),
+            Email::parse("target@example.com").unwrap(),
+            Username::new("target").unwrap(),
+            PasswordHash::new("hash").unwrap(),
+            Utc::now(),
+        );
+        let target = users_repo.create_user(target).await.unwrap();
+        let blocker_id = UserId::random();
+        users_repo.block_user(blocker_id, target.id).await.unwrap();
+
+        let result = unblock_user(&users_repo, target.username.as_str(), blocker_id).await;
+
+        assert!(result.is_ok());
+        assert!(!users_repo.is_blocking(blocker_id, target.id).await.unwrap());
+    }
+}
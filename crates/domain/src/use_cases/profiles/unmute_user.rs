@@ -0,0 +1,73 @@
+//! Unmute user use case
+
+use crate::{
+    DomainError, DomainResult, Profile, UserId,
+    repositories::UsersRepository,
+};
+
+/// Unmute a user
+///
+/// # Business Rules
+/// - Target user must exist
+/// - Cannot unmute yourself
+/// - Unmuting not-muted user is idempotent
+pub async fn unmute_user<U>(
+    users_repo: &U,
+    username: &str,
+    muter_id: UserId,
+) -> DomainResult<Profile>
+where
+    U: UsersRepository,
+{
+    let target = users_repo
+        .get_user_by_username(username)
+        .await
+        .map_err(|_| DomainError::NotFound { entity: "profile" })?
+        .ok_or(DomainError::NotFound { entity: "profile" })?;
+
+    // Cannot unmute yourself
+    Profile::validate_follow_action(&target.id, &muter_id)?;
+
+    users_repo
+        .unmute_user(muter_id, target.id)
+        .await
+        .map_err(|e| DomainError::Database {
+            message: e.to_string(),
+        })?;
+
+    let following = users_repo
+        .is_following(muter_id, target.id)
+        .await
+        .map_err(|e| DomainError::Database {
+            message: e.to_string(),
+        })?;
+    Ok(target.to_profile(following))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::InMemoryUsersRepository;
+    use crate::{Email, PasswordHash, User, Username};
+    use chrono::Utc;
+
+    #[tokio::test]
+    async fn test_unmute_user_success() {
+        let users_repo = InMemoryUsersRepository::new();
+        let target = User::new(
+            UserId::random(),
+            Email::parse("target@example.com").unwrap(),
+            Username::new("target").unwrap(),
+            PasswordHash::new("hash").unwrap(),
+            Utc::now(),
+        );
+        let target = users_repo.create_user(target).await.unwrap();
+        let muter_id = UserId::random();
+        users_repo.mute_user(muter_id, target.id).await.unwrap();
+
+        let result = unmute_user(&users_repo, target.username.as_str(), muter_id).await;
+
+        assert!(result.is_ok());
+        assert!(users_repo.list_hidden_authors(muter_id).await.unwrap().is_empty());
+    }
+}
@@ -0,0 +1,162 @@
+//! Create series use case
+
+use chrono::{DateTime, Utc};
+
+use crate::{
+    DomainError, DomainResult, Series, SeriesEnvelope, SeriesId, SeriesKind, Slug, SlugPolicy,
+    UserId,
+    repositories::{ArticlesRepository, SeriesRepository, UsersRepository},
+};
+
+use super::get_series::build_series_view;
+use super::set_series_articles::{parts_error, resolve_parts};
+
+/// Suffixes are cut from the series id, as they are for article slugs
+const SUFFIX_LEN: usize = 6;
+const MAX_SUFFIX_ATTEMPTS: usize = 32 / SUFFIX_LEN;
+
+/// Input for creating a series or collection
+#[derive(Debug, Clone, Default)]
+pub struct CreateSeriesInput {
+    pub title: String,
+    pub description: String,
+    pub kind: SeriesKind,
+    /// Slugs of the first parts, in order
+    pub articles: Vec<String>,
+}
+
+/// Create a series of the current user's articles, or a collection of
+/// anyone's published articles
+///
+/// # Business Rules
+/// - Title must not be empty; the description may be
+/// - Slug is derived from the title per the slug policy; if another series
+///   has it, a short suffix makes it unique
+/// - The current user owns it and is the only one who can change it
+/// - Any initial parts follow the rules of `set_series_articles`
+pub async fn create_series<U, A, S>(
+    users_repo: &U,
+    articles_repo: &A,
+    series_repo: &S,
+    owner_id: UserId,
+    input: CreateSeriesInput,
+    slug_policy: &SlugPolicy,
+    now: DateTime<Utc>,
+) -> DomainResult<SeriesEnvelope>
+where
+    U: UsersRepository,
+    A: ArticlesRepository,
+    S: SeriesRepository + ?Sized,
+{
+    let mut series = Series::new(
+        SeriesId::random(),
+        owner_id,
+        input.title,
+        input.description,
+        input.kind,
+        slug_policy,
+        now,
+    )?;
+    series.slug = allocate_series_slug(series_repo, &series.slug, series.id).await?;
+
+    let db_error = |e: anyhow::Error| DomainError::Database {
+        message: e.to_string(),
+    };
+    // Checked up front so a rejected part list leaves no empty series behind
+    let parts = resolve_parts(articles_repo, series_repo, &series, &input.articles).await?;
+    let created = series_repo.create_series(series).await.map_err(db_error)?;
+    if !parts.is_empty()
+        && let Err(e) = series_repo.set_series_parts(created.id, &parts).await
+    {
+        series_repo.delete_series(created.id).await.map_err(db_error)?;
+        return Err(parts_error(e));
+    }
+
+    let view = build_series_view(users_repo, series_repo, &created, Some(owner_id)).await?;
+    Ok(SeriesEnvelope::from(view))
+}
+
+/// The title slug itself, else the same slug with a suffix from the series id
+async fn allocate_series_slug<S>(series_repo: &S, base: &Slug, series_id: SeriesId) -> DomainResult<Slug>
+where
+    S: SeriesRepository + ?Sized,
+{
+    let hex = series_id.as_uuid().simple().to_string();
+    let candidates = std::iter::once(base.clone()).chain(
+        (0..MAX_SUFFIX_ATTEMPTS)
+            .map(|i| base.with_suffix(&hex[i * SUFFIX_LEN..(i + 1) * SUFFIX_LEN])),
+    );
+
+    for candidate in candidates {
+        let taken = series_repo
+            .get_series_by_slug(candidate.as_str())
+            .await
+            .map_err(|e| DomainError::Database {
+                message: e.to_string(),
+            })?;
+        if taken.is_none() {
+            return Ok(candidate);
+        }
+    }
+    Err(DomainError::Conflict { entity: "slug" })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::{InMemoryArticlesRepository, InMemorySeriesRepository, InMemoryUsersRepository};
+    use crate::{Email, PasswordHash, User, Username};
+
+    #[tokio::test]
+    async fn test_create_series_suffixes_taken_slugs() {
+        let users_repo = InMemoryUsersRepository::new();
+        let articles_repo = InMemoryArticlesRepository::new(users_repo.clone());
+        let series_repo = InMemorySeriesRepository::new(articles_repo.clone());
+        let owner = User::new(
+            UserId::random(),
+            Email::parse("owner@example.com").unwrap(),
+            Username::new("owner").unwrap(),
+            PasswordHash::new("hash").unwrap(),
+            Utc::now(),
+        );
+        users_repo.create_user(owner.clone()).await.unwrap();
+        let input = || CreateSeriesInput {
+            title: "Reading List".to_owned(),
+            kind: SeriesKind::Collection,
+            ..Default::default()
+        };
+        let policy = SlugPolicy::default();
+
+        let first = create_series(&users_repo, &articles_repo, &series_repo, owner.id, input(), &policy, Utc::now())
+            .await
+            .unwrap();
+        let second = create_series(&users_repo, &articles_repo, &series_repo, owner.id, input(), &policy, Utc::now())
+            .await
+            .unwrap();
+
+        assert_eq!(first.series.slug.as_str(), "reading-list");
+        assert!(second.series.slug.as_str().starts_with("reading-list-"));
+        assert_eq!(second.series.kind, SeriesKind::Collection);
+        assert_eq!(second.series.articles_count, 0);
+    }
+
+    #[tokio::test]
+    async fn test_create_series_requires_title() {
+        let users_repo = InMemoryUsersRepository::new();
+        let articles_repo = InMemoryArticlesRepository::new(users_repo.clone());
+        let series_repo = InMemorySeriesRepository::new(articles_repo.clone());
+
+        let result = create_series(
+            &users_repo,
+            &articles_repo,
+            &series_repo,
+            UserId::random(),
+            CreateSeriesInput::default(),
+            &SlugPolicy::default(),
+            Utc::now(),
+        )
+        .await;
+
+        assert!(matches!(result, Err(DomainError::InvalidTitle)));
+    }
+}
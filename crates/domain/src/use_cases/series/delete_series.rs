@@ -0,0 +1,89 @@
+//! Delete series use case
+
+use crate::{
+    DomainError, DomainResult, UserId,
+    repositories::SeriesRepository,
+};
+
+use super::get_series::find_series;
+
+/// Delete a series or collection; its articles are left as they are
+///
+/// # Business Rules
+/// - Series must exist and only its owner can delete it
+pub async fn delete_series<S>(series_repo: &S, slug: &str, user_id: UserId) -> DomainResult<()>
+where
+    S: SeriesRepository + ?Sized,
+{
+    let series = find_series(series_repo, slug).await?;
+    if !series.owned_by(&user_id) {
+        return Err(DomainError::UnauthorizedAction);
+    }
+
+    series_repo
+        .delete_series(series.id)
+        .await
+        .map_err(|e| DomainError::Database {
+            message: e.to_string(),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::{
+        ArticlesRepository, InMemoryArticlesRepository, InMemorySeriesRepository, InMemoryUsersRepository,
+        UsersRepository,
+    };
+    use crate::use_cases::series_navigation;
+    use crate::{
+        Article, ArticleDraft, ArticleId, Email, PasswordHash, Series, SeriesId, SeriesKind, SlugPolicy, TagList,
+        User, Username,
+    };
+    use chrono::Utc;
+
+    async fn setup() -> (InMemoryArticlesRepository, InMemorySeriesRepository, User, Article) {
+        let users_repo = InMemoryUsersRepository::new();
+        let articles_repo = InMemoryArticlesRepository::new(users_repo.clone());
+        let series_repo = InMemorySeriesRepository::new(articles_repo.clone());
+        let author = User::new(
+            UserId::random(),
+            Email::parse("author@example.com").unwrap(),
+            Username::new("author").unwrap(),
+            PasswordHash::new("hash").unwrap(),
+            Utc::now(),
+        );
+        users_repo.create_user(author.clone()).await.unwrap();
+        let draft = ArticleDraft::new("Part One", "desc", "body", TagList::default()).unwrap();
+        let article = Article::publish(ArticleId::random(), author.id, draft, Utc::now()).unwrap();
+        let article = articles_repo.create_article(article).await.unwrap();
+        let series = Series::new(
+            SeriesId::random(),
+            author.id,
+            "Deep Dive",
+            "About it",
+            SeriesKind::Series,
+            &SlugPolicy::default(),
+            Utc::now(),
+        )
+        .unwrap();
+        series_repo.create_series(series.clone()).await.unwrap();
+        series_repo.set_series_parts(series.id, &[article.id]).await.unwrap();
+        (articles_repo, series_repo, author, article)
+    }
+
+    #[tokio::test]
+    async fn test_delete_series_leaves_articles() {
+        let (articles_repo, series_repo, author, article) = setup().await;
+
+        let result = delete_series(&series_repo, "deep-dive", UserId::random()).await;
+        assert!(matches!(result, Err(DomainError::UnauthorizedAction)));
+
+        delete_series(&series_repo, "deep-dive", author.id).await.unwrap();
+
+        assert!(series_repo.get_series_by_slug("deep-dive").await.unwrap().is_none());
+        assert!(articles_repo.get_article_by_id(article.id).await.unwrap().is_some());
+        let navigation = series_navigation(&articles_repo, &series_repo, article.slug.as_str(), None).await.unwrap();
+        assert!(navigation.is_none());
+    }
+}
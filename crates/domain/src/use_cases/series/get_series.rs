@@ -0,0 +1,135 @@
+//! Get series use case
+
+use crate::{
+    DomainError, DomainResult, Series, SeriesEnvelope, SeriesView, UserId,
+    repositories::{SeriesRepository, UsersRepository},
+};
+
+/// Get a series or collection with the parts the viewer can see
+///
+/// # Business Rules
+/// - Series must exist and its owner must still be active
+/// - Parts come in the owner's order; drafts only show to their author and
+///   parts by deactivated authors are left out
+/// - The owner's profile includes following status relative to viewer
+pub async fn get_series<U, S>(
+    users_repo: &U,
+    series_repo: &S,
+    slug: &str,
+    viewer_id: Option<UserId>,
+) -> DomainResult<SeriesEnvelope>
+where
+    U: UsersRepository,
+    S: SeriesRepository + ?Sized,
+{
+    let series = find_series(series_repo, slug).await?;
+    let view = build_series_view(users_repo, series_repo, &series, viewer_id).await?;
+    Ok(SeriesEnvelope::from(view))
+}
+
+pub(super) async fn find_series<S>(series_repo: &S, slug: &str) -> DomainResult<Series>
+where
+    S: SeriesRepository + ?Sized,
+{
+    series_repo
+        .get_series_by_slug(slug)
+        .await
+        .map_err(|e| DomainError::Database {
+            message: e.to_string(),
+        })?
+        .ok_or(DomainError::NotFound { entity: "series" })
+}
+
+pub(super) async fn build_series_view<U, S>(
+    users_repo: &U,
+    series_repo: &S,
+    series: &Series,
+    viewer_id: Option<UserId>,
+) -> DomainResult<SeriesView>
+where
+    U: UsersRepository,
+    S: SeriesRepository + ?Sized,
+{
+    let owner = users_repo
+        .get_user_by_id(series.owner_id)
+        .await
+        .map_err(|e| DomainError::Database {
+            message: e.to_string(),
+        })?
+        .filter(|owner| !owner.is_deactivated())
+        .ok_or(DomainError::NotFound { entity: "series" })?;
+
+    let following = match viewer_id {
+        Some(viewer) => users_repo
+            .is_following(viewer, series.owner_id)
+            .await
+            .unwrap_or(false),
+        None => false,
+    };
+
+    let parts = series_repo
+        .list_series_parts(series.id, viewer_id)
+        .await
+        .map_err(|e| DomainError::Database {
+            message: e.to_string(),
+        })?;
+
+    Ok(series.to_view(owner.to_profile(following), parts))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::{
+        ArticlesRepository, InMemoryArticlesRepository, InMemorySeriesRepository, InMemoryUsersRepository,
+    };
+    use crate::{
+        Article, ArticleDraft, ArticleId, Email, PasswordHash, Publication, SeriesId, SeriesKind,
+        SlugPolicy, TagList, User, Username,
+    };
+    use chrono::Utc;
+
+    #[tokio::test]
+    async fn test_get_series_hides_drafts_from_readers() {
+        let users_repo = InMemoryUsersRepository::new();
+        let articles_repo = InMemoryArticlesRepository::new(users_repo.clone());
+        let series_repo = InMemorySeriesRepository::new(articles_repo.clone());
+        let author = User::new(
+            UserId::random(),
+            Email::parse("author@example.com").unwrap(),
+            Username::new("author").unwrap(),
+            PasswordHash::new("hash").unwrap(),
+            Utc::now(),
+        );
+        users_repo.create_user(author.clone()).await.unwrap();
+        let mut ids = Vec::new();
+        for (title, publication) in [("Part One", Publication::Now), ("Part Two", Publication::Draft)] {
+            let draft = ArticleDraft::new(title, "desc", "body", TagList::default()).unwrap();
+            let mut article = Article::publish(ArticleId::random(), author.id, draft, Utc::now()).unwrap();
+            article.set_publication(publication, Utc::now());
+            ids.push(articles_repo.create_article(article).await.unwrap().id);
+        }
+        let series = Series::new(
+            SeriesId::random(),
+            author.id,
+            "Deep Dive",
+            "",
+            SeriesKind::Series,
+            &SlugPolicy::default(),
+            Utc::now(),
+        )
+        .unwrap();
+        series_repo.create_series(series.clone()).await.unwrap();
+        series_repo.set_series_parts(series.id, &ids).await.unwrap();
+
+        let reader = get_series(&users_repo, &series_repo, "deep-dive", None).await.unwrap();
+        assert_eq!(reader.series.articles_count, 1);
+
+        let own = get_series(&users_repo, &series_repo, "deep-dive", Some(author.id)).await.unwrap();
+        assert_eq!(own.series.articles_count, 2);
+        assert_eq!(own.series.articles[1].title, "Part Two");
+
+        let result = get_series(&users_repo, &series_repo, "missing", None).await;
+        assert!(matches!(result, Err(DomainError::NotFound { entity: "series" })));
+    }
+}
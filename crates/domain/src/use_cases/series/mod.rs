@@ -0,0 +1,17 @@
+//! Series use cases
+//!
+//! All business logic for series and curated collections lives here.
+
+mod create_series;
+mod delete_series;
+mod get_series;
+mod series_navigation;
+mod set_series_articles;
+mod update_series;
+
+pub use create_series::*;
+pub use delete_series::*;
+pub use get_series::*;
+pub use series_navigation::*;
+pub use set_series_articles::*;
+pub use update_series::*;
@@ -0,0 +1,111 @@
+//! Series navigation use case
+
+use crate::{
+    DomainError, DomainResult, SeriesNavigation, UserId,
+    repositories::{ArticlesRepository, SeriesRepository},
+};
+
+/// Where an article sits in its series, for the article page
+///
+/// # Business Rules
+/// - Only series count; collections have no reading order to navigate
+/// - Part numbers and neighbours only count the parts the viewer can see
+/// - An article in no series has no navigation
+pub async fn series_navigation<A, S>(
+    articles_repo: &A,
+    series_repo: &S,
+    article_slug: &str,
+    viewer_id: Option<UserId>,
+) -> DomainResult<Option<SeriesNavigation>>
+where
+    A: ArticlesRepository,
+    S: SeriesRepository + ?Sized,
+{
+    let db_error = |e: anyhow::Error| DomainError::Database {
+        message: e.to_string(),
+    };
+
+    let Some(article) = articles_repo
+        .get_article_by_slug(article_slug)
+        .await
+        .map_err(db_error)?
+    else {
+        return Ok(None);
+    };
+    let Some(series) = series_repo.series_of_article(article.id).await.map_err(db_error)? else {
+        return Ok(None);
+    };
+
+    let parts = series_repo
+        .list_series_parts(series.id, viewer_id)
+        .await
+        .map_err(db_error)?;
+    Ok(series.navigation(&parts, &article.slug))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::{
+        ArticlesRepository, InMemoryArticlesRepository, InMemorySeriesRepository, InMemoryUsersRepository,
+        UsersRepository,
+    };
+    use crate::{
+        Article, ArticleDraft, ArticleId, Email, PasswordHash, Publication, Series, SeriesId, SeriesKind, SlugPolicy,
+        TagList, User, Username,
+    };
+    use chrono::Utc;
+
+    #[tokio::test]
+    async fn test_series_navigation_counts_visible_series_parts() {
+        let users_repo = InMemoryUsersRepository::new();
+        let articles_repo = InMemoryArticlesRepository::new(users_repo.clone());
+        let series_repo = InMemorySeriesRepository::new(articles_repo.clone());
+        let author = User::new(
+            UserId::random(),
+            Email::parse("author@example.com").unwrap(),
+            Username::new("author").unwrap(),
+            PasswordHash::new("hash").unwrap(),
+            Utc::now(),
+        );
+        users_repo.create_user(author.clone()).await.unwrap();
+        let mut ids = Vec::new();
+        for (title, publication) in [
+            ("Part One", Publication::Now),
+            ("Part Two", Publication::Draft),
+            ("Part Three", Publication::Now),
+            ("Standalone", Publication::Now),
+        ] {
+            let draft = ArticleDraft::new(title, "desc", "body", TagList::default()).unwrap();
+            let mut article = Article::publish(ArticleId::random(), author.id, draft, Utc::now()).unwrap();
+            article.set_publication(publication, Utc::now());
+            ids.push(articles_repo.create_article(article).await.unwrap().id);
+        }
+        for (title, kind, parts) in [
+            ("Deep Dive", SeriesKind::Series, &ids[..3]),
+            ("Best Of", SeriesKind::Collection, &ids[2..]),
+        ] {
+            let series = Series::new(SeriesId::random(), author.id, title, "", kind, &SlugPolicy::default(), Utc::now())
+                .unwrap();
+            series_repo.create_series(series.clone()).await.unwrap();
+            series_repo.set_series_parts(series.id, parts).await.unwrap();
+        }
+        let navigation = |slug: &'static str, viewer| {
+            let (articles_repo, series_repo) = (&articles_repo, &series_repo);
+            async move { series_navigation(articles_repo, series_repo, slug, viewer).await.unwrap() }
+        };
+
+        let reader = navigation("part-three", None).await.unwrap();
+        assert_eq!((reader.title.as_str(), reader.part, reader.total), ("Deep Dive", 2, 2));
+        assert_eq!(reader.previous.unwrap().title, "Part One");
+        assert!(reader.next.is_none());
+
+        let own = navigation("part-three", Some(author.id)).await.unwrap();
+        assert_eq!((own.part, own.total), (3, 3));
+        assert_eq!(own.previous.unwrap().title, "Part Two");
+
+        // Being in a collection gives no navigation
+        assert!(navigation("standalone", None).await.is_none());
+        assert!(navigation("missing", None).await.is_none());
+    }
+}
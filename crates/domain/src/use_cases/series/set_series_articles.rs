@@ -0,0 +1,202 @@
+//! Set series articles use case
+
+use crate::{
+    Article, ArticleId, DomainError, DomainResult, Series, SeriesEnvelope, SeriesKind, UserId,
+    repositories::{ArticlesRepository, PartOfAnotherSeries, SeriesRepository, UsersRepository},
+};
+
+use super::get_series::{build_series_view, find_series};
+
+/// Replace the parts of a series with the given article slugs, in order
+///
+/// Adding, removing and reordering parts all go through here.
+///
+/// # Business Rules
+/// - Series must exist and only its owner can change it
+/// - Every slug must name an article the owner can see, at most once
+/// - A series only takes its owner's articles, drafts included, and an
+///   article can be part of one series only
+/// - A collection takes published articles by anyone, and an article can
+///   be in any number of collections
+pub async fn set_series_articles<U, A, S>(
+    users_repo: &U,
+    articles_repo: &A,
+    series_repo: &S,
+    slug: &str,
+    user_id: UserId,
+    articles: Vec<String>,
+) -> DomainResult<SeriesEnvelope>
+where
+    U: UsersRepository,
+    A: ArticlesRepository,
+    S: SeriesRepository + ?Sized,
+{
+    let series = find_series(series_repo, slug).await?;
+    if !series.owned_by(&user_id) {
+        return Err(DomainError::UnauthorizedAction);
+    }
+
+    let parts = resolve_parts(articles_repo, series_repo, &series, &articles).await?;
+    series_repo
+        .set_series_parts(series.id, &parts)
+        .await
+        .map_err(parts_error)?;
+
+    let view = build_series_view(users_repo, series_repo, &series, Some(user_id)).await?;
+    Ok(SeriesEnvelope::from(view))
+}
+
+/// Checks the articles behind `slugs` against the series' rules, returning
+/// their ids in order; nothing is stored
+pub(super) async fn resolve_parts<A, S>(
+    articles_repo: &A,
+    series_repo: &S,
+    series: &Series,
+    slugs: &[String],
+) -> DomainResult<Vec<ArticleId>>
+where
+    A: ArticlesRepository,
+    S: SeriesRepository + ?Sized,
+{
+    let db_error = |e: anyhow::Error| DomainError::Database {
+        message: e.to_string(),
+    };
+
+    let mut parts: Vec<Article> = Vec::with_capacity(slugs.len());
+    for slug in slugs {
+        let article = articles_repo
+            .get_article_by_slug(slug)
+            .await
+            .map_err(db_error)?
+            .filter(|article| article.is_visible_to(Some(series.owner_id)))
+            .ok_or(DomainError::NotFound { entity: "article" })?;
+        parts.push(article);
+    }
+    series.check_parts(&parts)?;
+
+    if series.kind == SeriesKind::Series {
+        for part in &parts {
+            let current = series_repo.series_of_article(part.id).await.map_err(db_error)?;
+            if current.is_some_and(|other| other.id != series.id) {
+                return Err(DomainError::ArticleInAnotherSeries);
+            }
+        }
+    }
+
+    Ok(parts.iter().map(|part| part.id).collect())
+}
+
+/// Another series may have taken one of the parts since they were checked
+pub(super) fn parts_error(e: anyhow::Error) -> DomainError {
+    if e.is::<PartOfAnotherSeries>() {
+        DomainError::ArticleInAnotherSeries
+    } else {
+        DomainError::Database {
+            message: e.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::{InMemoryArticlesRepository, InMemorySeriesRepository, InMemoryUsersRepository};
+    use crate::use_cases::{create_series, CreateSeriesInput};
+    use crate::{ArticleDraft, Email, PasswordHash, SlugPolicy, TagList, User, Username};
+    use chrono::Utc;
+
+    struct Fixture {
+        users_repo: InMemoryUsersRepository,
+        articles_repo: InMemoryArticlesRepository,
+        series_repo: InMemorySeriesRepository,
+        author: User,
+        other: User,
+    }
+
+    async fn setup() -> Fixture {
+        let users_repo = InMemoryUsersRepository::new();
+        let articles_repo = InMemoryArticlesRepository::new(users_repo.clone());
+        let series_repo = InMemorySeriesRepository::new(articles_repo.clone());
+        let mut users = Vec::new();
+        for name in ["author", "other"] {
+            let user = User::new(
+                UserId::random(),
+                Email::parse(format!("{name}@example.com")).unwrap(),
+                Username::new(name).unwrap(),
+                PasswordHash::new("hash").unwrap(),
+                Utc::now(),
+            );
+            users.push(users_repo.create_user(user).await.unwrap());
+        }
+        for (title, author) in [("One", &users[0]), ("Two", &users[0]), ("Theirs", &users[1])] {
+            let draft = ArticleDraft::new(title, "desc", "body", TagList::default()).unwrap();
+            let article = Article::publish(ArticleId::random(), author.id, draft, Utc::now()).unwrap();
+            articles_repo.create_article(article).await.unwrap();
+        }
+        let other = users.pop().unwrap();
+        let author = users.pop().unwrap();
+        Fixture { users_repo, articles_repo, series_repo, author, other }
+    }
+
+    async fn create(f: &Fixture, title: &str, kind: SeriesKind) -> String {
+        let input = CreateSeriesInput {
+            title: title.to_owned(),
+            kind,
+            ..Default::default()
+        };
+        let envelope = create_series(
+            &f.users_repo,
+            &f.articles_repo,
+            &f.series_repo,
+            f.author.id,
+            input,
+            &SlugPolicy::default(),
+            Utc::now(),
+        )
+        .await
+        .unwrap();
+        envelope.series.slug.as_str().to_owned()
+    }
+
+    async fn set(f: &Fixture, series: &str, user_id: UserId, parts: &[&str]) -> DomainResult<SeriesEnvelope> {
+        let parts = parts.iter().map(|s| s.to_string()).collect();
+        set_series_articles(&f.users_repo, &f.articles_repo, &f.series_repo, series, user_id, parts).await
+    }
+
+    #[tokio::test]
+    async fn test_set_series_articles_reorders() {
+        let f = setup().await;
+        let series = create(&f, "Deep Dive", SeriesKind::Series).await;
+
+        let envelope = set(&f, &series, f.author.id, &["one", "two"]).await.unwrap();
+        let titles: Vec<_> = envelope.series.articles.iter().map(|a| a.title.as_str()).collect();
+        assert_eq!(titles, ["One", "Two"]);
+
+        let envelope = set(&f, &series, f.author.id, &["two", "one"]).await.unwrap();
+        let titles: Vec<_> = envelope.series.articles.iter().map(|a| a.title.as_str()).collect();
+        assert_eq!(titles, ["Two", "One"]);
+    }
+
+    #[tokio::test]
+    async fn test_set_series_articles_rules() {
+        let f = setup().await;
+        let series = create(&f, "Deep Dive", SeriesKind::Series).await;
+        let another = create(&f, "Another", SeriesKind::Series).await;
+        let collection = create(&f, "Favourites", SeriesKind::Collection).await;
+
+        let result = set(&f, &series, f.other.id, &["one"]).await;
+        assert!(matches!(result, Err(DomainError::UnauthorizedAction)));
+        let result = set(&f, &series, f.author.id, &["theirs"]).await;
+        assert!(matches!(result, Err(DomainError::SeriesPartNotAllowed)));
+        let result = set(&f, &series, f.author.id, &["missing"]).await;
+        assert!(matches!(result, Err(DomainError::NotFound { entity: "article" })));
+
+        set(&f, &series, f.author.id, &["one"]).await.unwrap();
+        let result = set(&f, &another, f.author.id, &["one"]).await;
+        assert!(matches!(result, Err(DomainError::ArticleInAnotherSeries)));
+
+        // Collections span authors and overlap with series
+        let envelope = set(&f, &collection, f.author.id, &["theirs", "one"]).await.unwrap();
+        assert_eq!(envelope.series.articles_count, 2);
+    }
+}
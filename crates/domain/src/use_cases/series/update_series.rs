@@ -0,0 +1,130 @@
+//! Update series use case
+
+use chrono::{DateTime, Utc};
+
+use crate::{
+    DomainError, DomainResult, SeriesEnvelope, UserId,
+    repositories::{SeriesRepository, UsersRepository},
+};
+
+use super::get_series::{build_series_view, find_series};
+
+/// Input for updating a series; fields left out stay as they are
+#[derive(Debug, Clone, Default)]
+pub struct UpdateSeriesInput {
+    pub title: Option<String>,
+    pub description: Option<String>,
+}
+
+/// Retitle or redescribe a series
+///
+/// # Business Rules
+/// - Series must exist and only its owner can change it
+/// - Title must not be empty
+/// - The slug stays the same, so links to the series keep working
+pub async fn update_series<U, S>(
+    users_repo: &U,
+    series_repo: &S,
+    slug: &str,
+    user_id: UserId,
+    input: UpdateSeriesInput,
+    now: DateTime<Utc>,
+) -> DomainResult<SeriesEnvelope>
+where
+    U: UsersRepository,
+    S: SeriesRepository + ?Sized,
+{
+    let mut series = find_series(series_repo, slug).await?;
+    if !series.owned_by(&user_id) {
+        return Err(DomainError::UnauthorizedAction);
+    }
+
+    series.apply_changes(input.title, input.description, now)?;
+    let updated = series_repo
+        .update_series(series)
+        .await
+        .map_err(|e| DomainError::Database {
+            message: e.to_string(),
+        })?;
+
+    let view = build_series_view(users_repo, series_repo, &updated, Some(user_id)).await?;
+    Ok(SeriesEnvelope::from(view))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::{
+        ArticlesRepository, InMemoryArticlesRepository, InMemorySeriesRepository, InMemoryUsersRepository,
+        UsersRepository,
+    };
+    use crate::{
+        Article, ArticleDraft, ArticleId, Email, PasswordHash, Series, SeriesId, SeriesKind, SlugPolicy, TagList,
+        User, Username,
+    };
+
+    async fn setup() -> (InMemoryUsersRepository, InMemorySeriesRepository, User, Article) {
+        let users_repo = InMemoryUsersRepository::new();
+        let articles_repo = InMemoryArticlesRepository::new(users_repo.clone());
+        let series_repo = InMemorySeriesRepository::new(articles_repo.clone());
+        let author = User::new(
+            UserId::random(),
+            Email::parse("author@example.com").unwrap(),
+            Username::new("author").unwrap(),
+            PasswordHash::new("hash").unwrap(),
+            Utc::now(),
+        );
+        users_repo.create_user(author.clone()).await.unwrap();
+        let draft = ArticleDraft::new("Part One", "desc", "body", TagList::default()).unwrap();
+        let article = Article::publish(ArticleId::random(), author.id, draft, Utc::now()).unwrap();
+        let article = articles_repo.create_article(article).await.unwrap();
+        let series = Series::new(
+            SeriesId::random(),
+            author.id,
+            "Deep Dive",
+            "About it",
+            SeriesKind::Series,
+            &SlugPolicy::default(),
+            Utc::now(),
+        )
+        .unwrap();
+        series_repo.create_series(series.clone()).await.unwrap();
+        series_repo.set_series_parts(series.id, &[article.id]).await.unwrap();
+        (users_repo, series_repo, author, article)
+    }
+
+    #[tokio::test]
+    async fn test_update_series_keeps_slug() {
+        let (users_repo, series_repo, author, _) = setup().await;
+        let input = UpdateSeriesInput {
+            title: Some("Deeper Dive".to_owned()),
+            description: None,
+        };
+
+        let envelope = update_series(&users_repo, &series_repo, "deep-dive", author.id, input, Utc::now())
+            .await
+            .unwrap();
+
+        assert_eq!(envelope.series.slug.as_str(), "deep-dive");
+        assert_eq!(envelope.series.title, "Deeper Dive");
+        assert_eq!(envelope.series.description, "About it");
+        assert_eq!(envelope.series.articles_count, 1);
+    }
+
+    #[tokio::test]
+    async fn test_update_series_rules() {
+        let (users_repo, series_repo, author, _) = setup().await;
+        let retitle = |title: &str| UpdateSeriesInput {
+            title: Some(title.to_owned()),
+            description: None,
+        };
+
+        let stranger = UserId::random();
+        let result = update_series(&users_repo, &series_repo, "deep-dive", stranger, retitle("Mine"), Utc::now()).await;
+        assert!(matches!(result, Err(DomainError::UnauthorizedAction)));
+        let result = update_series(&users_repo, &series_repo, "deep-dive", author.id, retitle(" "), Utc::now()).await;
+        assert!(matches!(result, Err(DomainError::InvalidTitle)));
+        let result = update_series(&users_repo, &series_repo, "missing", author.id, retitle("Mine"), Utc::now()).await;
+        assert!(matches!(result, Err(DomainError::NotFound { entity: "series" })));
+    }
+}
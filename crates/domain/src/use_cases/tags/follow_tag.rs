@@ -0,0 +1,81 @@
+//! Follow tag use case
+
+use crate::{
+    DomainError, DomainResult, Tag, TagEnvelope, TagView, UserId,
+    repositories::TagsRepository,
+};
+
+/// Follow a tag, so articles carrying it show up in the feed
+///
+/// # Business Rules
+/// - The name is normalized, and an alias follows the tag it stands for
+/// - Tags no article carries yet can be followed too
+/// - Following an already followed tag is idempotent
+pub async fn follow_tag<T>(tags_repo: &T, name: &str, user_id: UserId) -> DomainResult<TagEnvelope>
+where
+    T: TagsRepository + ?Sized,
+{
+    let tag = canonical(tags_repo, name).await?;
+    tags_repo
+        .follow_tag(user_id, &tag)
+        .await
+        .map_err(|e| DomainError::Database {
+            message: e.to_string(),
+        })?;
+
+    Ok(TagEnvelope {
+        tag: TagView {
+            name: tag.into(),
+            following: true,
+        },
+    })
+}
+
+/// The tag `name` stands for, once normalized and stripped of aliases
+pub(super) async fn canonical<T>(tags_repo: &T, name: &str) -> DomainResult<Tag>
+where
+    T: TagsRepository + ?Sized,
+{
+    let tag = Tag::new(name)?;
+    tags_repo
+        .canonical_tag(&tag)
+        .await
+        .map_err(|e| DomainError::Database {
+            message: e.to_string(),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::{InMemoryArticlesRepository, InMemoryTagsRepository, InMemoryUsersRepository};
+
+    fn tags_repo() -> InMemoryTagsRepository {
+        InMemoryTagsRepository::new(InMemoryArticlesRepository::new(InMemoryUsersRepository::new()))
+    }
+
+    #[tokio::test]
+    async fn test_follow_tag_normalizes_and_resolves_aliases() {
+        let tags_repo = tags_repo();
+        let user_id = UserId::random();
+        tags_repo
+            .merge_tags(&Tag::new("js").unwrap(), &Tag::new("javascript").unwrap())
+            .await
+            .unwrap();
+
+        let envelope = follow_tag(&tags_repo, " JS ", user_id).await.unwrap();
+        follow_tag(&tags_repo, "javascript", user_id).await.unwrap();
+
+        assert_eq!(envelope.tag.name, "javascript");
+        assert!(envelope.tag.following);
+        let followed = tags_repo.followed_tags(user_id).await.unwrap();
+        assert_eq!(followed, [Tag::new("javascript").unwrap()]);
+    }
+
+    #[tokio::test]
+    async fn test_follow_tag_rejects_blank_names() {
+        let result = follow_tag(&tags_repo(), "  ", UserId::random()).await;
+
+        assert_eq!(result.unwrap_err(), DomainError::InvalidTag);
+    }
+}
@@ -0,0 +1,46 @@
+//! List followed tags use case
+
+use crate::{
+    DomainError, DomainResult, FollowedTagsEnvelope, TagList, UserId,
+    repositories::TagsRepository,
+};
+
+/// Tags the user follows, in name order
+pub async fn list_followed_tags<T>(tags_repo: &T, user_id: UserId) -> DomainResult<FollowedTagsEnvelope>
+where
+    T: TagsRepository + ?Sized,
+{
+    let followed = tags_repo
+        .followed_tags(user_id)
+        .await
+        .map_err(|e| DomainError::Database {
+            message: e.to_string(),
+        })?;
+
+    let mut tags = TagList::default();
+    for tag in followed {
+        tags.push(tag);
+    }
+    Ok(FollowedTagsEnvelope { tags })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::{InMemoryArticlesRepository, InMemoryTagsRepository, InMemoryUsersRepository};
+    use crate::use_cases::follow_tag;
+
+    #[tokio::test]
+    async fn test_list_followed_tags_in_name_order() {
+        let tags_repo = InMemoryTagsRepository::new(InMemoryArticlesRepository::new(InMemoryUsersRepository::new()));
+        let user_id = UserId::random();
+        for name in ["web", "axum", "rust"] {
+            follow_tag(&tags_repo, name, user_id).await.unwrap();
+        }
+        follow_tag(&tags_repo, "go", UserId::random()).await.unwrap();
+
+        let envelope = list_followed_tags(&tags_repo, user_id).await.unwrap();
+
+        assert_eq!(envelope.tags, TagList::new(["axum", "rust", "web"]).unwrap());
+    }
+}
@@ -0,0 +1,108 @@
+//! List tags use case
+
+use crate::{
+    DomainError, DomainResult, Pagination, TagsEnvelope,
+    repositories::TagsRepository,
+};
+
+/// Input for listing tags
+#[derive(Debug, Clone, Default)]
+pub struct ListTagsInput {
+    /// Autocomplete: only tags starting with this
+    pub prefix: Option<String>,
+    pub limit: Option<u32>,
+}
+
+/// Tags in use with the number of articles carrying each
+///
+/// # Business Rules
+/// - Only published articles by active authors are counted, and tags no such
+///   article carries are left out
+/// - Most used tags come first, ties in name order
+/// - A prefix matches the start of a tag regardless of case; a blank prefix
+///   lists the popular tags
+/// - The limit follows the article list bounds
+pub async fn list_tags<T>(tags_repo: &T, input: ListTagsInput) -> DomainResult<TagsEnvelope>
+where
+    T: TagsRepository + ?Sized,
+{
+    let limit = Pagination::new(input.limit, None)?.limit();
+    let prefix = input.prefix.as_deref().map(str::trim).filter(|p| !p.is_empty());
+
+    let tags = match prefix {
+        Some(prefix) => tags_repo.search_tags(prefix, limit).await,
+        None => tags_repo.popular_tags(limit).await,
+    }
+    .map_err(|e| DomainError::Database {
+        message: e.to_string(),
+    })?;
+
+    Ok(TagsEnvelope::from(tags))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::{
+        ArticlesRepository, InMemoryArticlesRepository, InMemoryTagsRepository, InMemoryUsersRepository,
+        UsersRepository,
+    };
+    use crate::{Article, ArticleDraft, ArticleId, Email, PasswordHash, Publication, TagList, User, UserId, Username};
+    use chrono::Utc;
+
+    async fn setup() -> InMemoryTagsRepository {
+        let users_repo = InMemoryUsersRepository::new();
+        let articles_repo = InMemoryArticlesRepository::new(users_repo.clone());
+        let author = User::new(
+            UserId::random(),
+            Email::parse("author@example.com").unwrap(),
+            Username::new("author").unwrap(),
+            PasswordHash::new("hash").unwrap(),
+            Utc::now(),
+        );
+        users_repo.create_user(author.clone()).await.unwrap();
+
+        for (title, tags) in [
+            ("One", vec!["rust", "web"]),
+            ("Two", vec!["rust", "Rocket"]),
+            ("Three", vec!["rust", "wasm"]),
+        ] {
+            let draft = ArticleDraft::new(title, "desc", "body", TagList::new(tags).unwrap()).unwrap();
+            let article = Article::publish(ArticleId::random(), author.id, draft, Utc::now()).unwrap();
+            articles_repo.create_article(article).await.unwrap();
+        }
+        // Drafts do not count
+        let draft = ArticleDraft::new("Draft", "desc", "body", TagList::new(["secret"]).unwrap()).unwrap();
+        let mut article = Article::publish(ArticleId::random(), author.id, draft, Utc::now()).unwrap();
+        article.set_publication(Publication::Draft, Utc::now());
+        articles_repo.create_article(article).await.unwrap();
+
+        InMemoryTagsRepository::new(articles_repo)
+    }
+
+    #[tokio::test]
+    async fn test_list_tags_orders_by_article_count() {
+        let tags_repo = setup().await;
+
+        let envelope = list_tags(&tags_repo, ListTagsInput::default()).await.unwrap();
+
+        assert_eq!(envelope.tags, ["rust", "rocket", "wasm", "web"]);
+        assert_eq!(envelope.tag_counts[0].articles_count, 3);
+        assert_eq!(envelope.tag_counts[1].articles_count, 1);
+    }
+
+    #[tokio::test]
+    async fn test_list_tags_autocompletes_prefix() {
+        let tags_repo = setup().await;
+        let input = ListTagsInput {
+            prefix: Some("R".into()),
+            limit: Some(1),
+        };
+
+        let envelope = list_tags(&tags_repo, input).await.unwrap();
+
+        assert_eq!(envelope.tags, ["rust"]);
+        let all = list_tags(&tags_repo, ListTagsInput { prefix: Some("w".into()), limit: None }).await.unwrap();
+        assert_eq!(all.tags, ["wasm", "web"]);
+    }
+}
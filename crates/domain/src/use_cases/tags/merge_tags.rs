@@ -0,0 +1,155 @@
+//! Merge tags use case
+
+use crate::{
+    DomainError, DomainResult, Tag, TagMerge, TagMergeEnvelope,
+    repositories::TagsRepository,
+};
+
+/// Input for merging one tag into another
+#[derive(Debug, Clone)]
+pub struct MergeTagsInput {
+    pub from: String,
+    pub into: String,
+}
+
+/// Fold one tag into another, for administrators tidying the catalogue
+///
+/// # Business Rules
+/// - Both names are normalized first
+/// - Merging into an alias merges into the tag it stands for
+/// - A tag cannot be merged into itself
+/// - Every article tagged `from` is retagged `into` in one step, and `from`
+///   stays behind as an alias, so later uses of it land on `into`
+/// - Merging a tag no article carries just records the alias
+pub async fn merge_tags<T>(tags_repo: &T, input: MergeTagsInput) -> DomainResult<TagMergeEnvelope>
+where
+    T: TagsRepository + ?Sized,
+{
+    let from = Tag::new(input.from)?;
+    let into = Tag::new(input.into)?;
+    let into = tags_repo
+        .canonical_tag(&into)
+        .await
+        .map_err(|e| DomainError::Database {
+            message: e.to_string(),
+        })?;
+    if from == into {
+        return Err(DomainError::TagMergedIntoItself);
+    }
+
+    let articles_count = tags_repo
+        .merge_tags(&from, &into)
+        .await
+        .map_err(|e| DomainError::Database {
+            message: e.to_string(),
+        })?;
+
+    Ok(TagMergeEnvelope {
+        tag_merge: TagMerge {
+            from: from.into(),
+            into: into.into(),
+            articles_count,
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::{
+        ArticlesRepository, InMemoryArticlesRepository, InMemoryTagsRepository, InMemoryUsersRepository,
+        UsersRepository,
+    };
+    use crate::{Article, ArticleDraft, ArticleId, Email, PasswordHash, TagList, User, UserId, Username};
+    use crate::use_cases::{follow_tag, list_followed_tags};
+    use chrono::Utc;
+
+    async fn setup() -> (InMemoryArticlesRepository, InMemoryTagsRepository, UserId) {
+        let users_repo = InMemoryUsersRepository::new();
+        let articles_repo = InMemoryArticlesRepository::new(users_repo.clone());
+        let author = User::new(
+            UserId::random(),
+            Email::parse("author@example.com").unwrap(),
+            Username::new("author").unwrap(),
+            PasswordHash::new("hash").unwrap(),
+            Utc::now(),
+        );
+        users_repo.create_user(author.clone()).await.unwrap();
+        let tags_repo = InMemoryTagsRepository::new(articles_repo.clone());
+        (articles_repo, tags_repo, author.id)
+    }
+
+    async fn publish(articles_repo: &InMemoryArticlesRepository, author_id: UserId, tags: &[&str]) -> Article {
+        let title = format!("Tagged {}", tags.join(" "));
+        let draft = ArticleDraft::new(title, "desc", "body", TagList::new(tags.iter().copied()).unwrap()).unwrap();
+        let article = Article::publish(ArticleId::random(), author_id, draft, Utc::now()).unwrap();
+        articles_repo.create_article(article).await.unwrap()
+    }
+
+    fn merge(from: &str, into: &str) -> MergeTagsInput {
+        MergeTagsInput {
+            from: from.into(),
+            into: into.into(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_merge_tags_retags_articles() {
+        let (articles_repo, tags_repo, author_id) = setup().await;
+        let both = publish(&articles_repo, author_id, &["js", "javascript"]).await;
+        let alias_only = publish(&articles_repo, author_id, &["js", "web"]).await;
+
+        let envelope = merge_tags(&tags_repo, merge("JS", "JavaScript")).await.unwrap();
+
+        assert_eq!(envelope.tag_merge.from, "js");
+        assert_eq!(envelope.tag_merge.into, "javascript");
+        assert_eq!(envelope.tag_merge.articles_count, 2);
+        let both = articles_repo.get_article_by_id(both.id).await.unwrap().unwrap();
+        assert_eq!(both.tag_list, TagList::new(["javascript"]).unwrap());
+        let alias_only = articles_repo.get_article_by_id(alias_only.id).await.unwrap().unwrap();
+        assert_eq!(alias_only.tag_list, TagList::new(["javascript", "web"]).unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_merged_tag_becomes_alias() {
+        let (articles_repo, tags_repo, author_id) = setup().await;
+        merge_tags(&tags_repo, merge("js", "javascript")).await.unwrap();
+
+        let article = publish(&articles_repo, author_id, &["js", "web"]).await;
+        assert_eq!(article.tag_list, TagList::new(["javascript", "web"]).unwrap());
+
+        // Merging into the alias lands on the tag it stands for
+        let envelope = merge_tags(&tags_repo, merge("ecmascript", "js")).await.unwrap();
+        assert_eq!(envelope.tag_merge.into, "javascript");
+
+        // Aliases of a merged tag follow it
+        merge_tags(&tags_repo, merge("javascript", "typescript")).await.unwrap();
+        let article = publish(&articles_repo, author_id, &["js"]).await;
+        assert_eq!(article.tag_list, TagList::new(["typescript"]).unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_merge_tags_moves_followers() {
+        let (_, tags_repo, author_id) = setup().await;
+        follow_tag(&tags_repo, "js", author_id).await.unwrap();
+        follow_tag(&tags_repo, "javascript", author_id).await.unwrap();
+        follow_tag(&tags_repo, "ecmascript", author_id).await.unwrap();
+
+        merge_tags(&tags_repo, merge("js", "javascript")).await.unwrap();
+        merge_tags(&tags_repo, merge("ecmascript", "js")).await.unwrap();
+
+        let envelope = list_followed_tags(&tags_repo, author_id).await.unwrap();
+        assert_eq!(envelope.tags, TagList::new(["javascript"]).unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_merge_tags_rejects_self_merge() {
+        let (_, tags_repo, _) = setup().await;
+        merge_tags(&tags_repo, merge("js", "javascript")).await.unwrap();
+
+        let result = merge_tags(&tags_repo, merge("Rust", " rust ")).await;
+        assert_eq!(result.unwrap_err(), DomainError::TagMergedIntoItself);
+        let result = merge_tags(&tags_repo, merge("javascript", "js")).await;
+        assert_eq!(result.unwrap_err(), DomainError::TagMergedIntoItself);
+    }
+}
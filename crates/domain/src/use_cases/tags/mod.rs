@@ -0,0 +1,15 @@
+//! Tag use cases
+//!
+//! All business logic for the tag catalogue lives here.
+
+mod follow_tag;
+mod list_followed_tags;
+mod list_tags;
+mod merge_tags;
+mod unfollow_tag;
+
+pub use follow_tag::*;
+pub use list_followed_tags::*;
+pub use list_tags::*;
+pub use merge_tags::*;
+pub use unfollow_tag::*;
@@ -0,0 +1,55 @@
+//! Unfollow tag use case
+
+use crate::{
+    DomainError, DomainResult, TagEnvelope, TagView, UserId,
+    repositories::TagsRepository,
+    use_cases::tags::follow_tag::canonical,
+};
+
+/// Stop following a tag
+///
+/// # Business Rules
+/// - The name is normalized, and an alias unfollows the tag it stands for
+/// - Unfollowing a tag that is not followed is idempotent
+pub async fn unfollow_tag<T>(tags_repo: &T, name: &str, user_id: UserId) -> DomainResult<TagEnvelope>
+where
+    T: TagsRepository + ?Sized,
+{
+    let tag = canonical(tags_repo, name).await?;
+    tags_repo
+        .unfollow_tag(user_id, &tag)
+        .await
+        .map_err(|e| DomainError::Database {
+            message: e.to_string(),
+        })?;
+
+    Ok(TagEnvelope {
+        tag: TagView {
+            name: tag.into(),
+            following: false,
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::{InMemoryArticlesRepository, InMemoryTagsRepository, InMemoryUsersRepository};
+    use crate::use_cases::follow_tag;
+
+    #[tokio::test]
+    async fn test_unfollow_tag() {
+        let tags_repo = InMemoryTagsRepository::new(InMemoryArticlesRepository::new(InMemoryUsersRepository::new()));
+        let user_id = UserId::random();
+        follow_tag(&tags_repo, "rust", user_id).await.unwrap();
+        follow_tag(&tags_repo, "web", user_id).await.unwrap();
+
+        let envelope = unfollow_tag(&tags_repo, "Rust", user_id).await.unwrap();
+        unfollow_tag(&tags_repo, "never-followed", user_id).await.unwrap();
+
+        assert!(!envelope.tag.following);
+        let followed = tags_repo.followed_tags(user_id).await.unwrap();
+        assert_eq!(followed.len(), 1);
+        assert_eq!(followed[0].as_str(), "web");
+    }
+}
@@ -0,0 +1,90 @@
+//! List trash use case
+
+use crate::{
+    DomainError, DomainResult, TrashEnvelope, TrashPolicy, UserId,
+    repositories::{ArticlesRepository, CommentsRepository},
+};
+
+/// The articles and comments the user deleted and can still restore
+///
+/// # Business Rules
+/// - Only the user's own articles and comments are listed, most recently
+///   deleted first
+/// - Each says when it will be erased
+/// - Comments name the article they were on, unless it is in the trash too
+pub async fn list_trash<A, C>(
+    articles_repo: &A,
+    comments_repo: &C,
+    user_id: UserId,
+    policy: &TrashPolicy,
+) -> DomainResult<TrashEnvelope>
+where
+    A: ArticlesRepository,
+    C: CommentsRepository,
+{
+    let db_error = |e: anyhow::Error| DomainError::Database {
+        message: e.to_string(),
+    };
+
+    let articles = articles_repo
+        .list_trashed_articles(user_id)
+        .await
+        .map_err(db_error)?
+        .iter()
+        .map(|trashed| trashed.to_view(policy))
+        .collect();
+
+    let mut comments = Vec::new();
+    for trashed in comments_repo.list_trashed_comments(user_id).await.map_err(db_error)? {
+        let article = articles_repo
+            .get_article_by_id(trashed.item.article_id)
+            .await
+            .map_err(db_error)?;
+        let article_slug = article.map(|article| article.slug.as_str().to_owned());
+        comments.push(trashed.to_view(article_slug, policy));
+    }
+
+    Ok(TrashEnvelope { articles, comments })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::{InMemoryArticlesRepository, InMemoryCommentsRepository, InMemoryUsersRepository};
+    use crate::{Article, ArticleDraft, ArticleId, Comment, CommentDraft, CommentId, TagList};
+    use chrono::{Duration, Utc};
+
+    #[tokio::test]
+    async fn test_list_trash_shows_own_items_with_purge_time() {
+        let users_repo = InMemoryUsersRepository::new();
+        let articles_repo = InMemoryArticlesRepository::new(users_repo);
+        let comments_repo = InMemoryCommentsRepository::new();
+        let author_id = UserId::random();
+        let now = Utc::now();
+        let mut articles = Vec::new();
+        for title in ["Kept", "Dropped"] {
+            let draft = ArticleDraft::new(title, "Description", "Body", TagList::default()).unwrap();
+            let article = Article::publish(ArticleId::random(), author_id, draft, now).unwrap();
+            articles.push(articles_repo.create_article(article).await.unwrap());
+        }
+        for (id, article) in [(1, &articles[0]), (2, &articles[1])] {
+            let draft = CommentDraft::new("Own comment").unwrap();
+            let comment = Comment::new(CommentId::new(id), article.id, author_id, draft, now);
+            comments_repo.create_comment(comment).await.unwrap();
+            comments_repo.delete_comment(CommentId::new(id), now).await.unwrap();
+        }
+        articles_repo.delete_article(articles[1].id, now).await.unwrap();
+        let policy = TrashPolicy::new(Duration::days(7));
+
+        let trash = list_trash(&articles_repo, &comments_repo, author_id, &policy).await.unwrap();
+
+        assert_eq!(trash.articles.len(), 1);
+        assert_eq!(trash.articles[0].slug, "dropped");
+        assert_eq!(trash.articles[0].purge_after, now + Duration::days(7));
+        let slugs: Vec<Option<&str>> = trash.comments.iter().map(|c| c.article_slug.as_deref()).collect();
+        assert_eq!(slugs, [Some("kept"), None]);
+
+        let other = list_trash(&articles_repo, &comments_repo, UserId::random(), &policy).await.unwrap();
+        assert!(other.articles.is_empty() && other.comments.is_empty());
+    }
+}
@@ -0,0 +1,14 @@
+//! Trash use cases
+//!
+//! Deleted articles and comments wait in their author's trash, from where
+//! they can be restored until the retention period runs out.
+
+mod list_trash;
+mod purge_trash;
+mod restore_article;
+mod restore_comment;
+
+pub use list_trash::*;
+pub use purge_trash::*;
+pub use restore_article::*;
+pub use restore_comment::*;
@@ -0,0 +1,97 @@
+//! Purge trash use case
+
+use chrono::{DateTime, Utc};
+
+use crate::{
+    DomainError, DomainEvent, DomainResult, TrashPolicy,
+    repositories::{ArticlesRepository, CommentsRepository},
+};
+
+/// Erase every article and comment whose retention period has elapsed
+///
+/// # Business Rules
+/// - Only what was deleted longer than the retention period ago is erased
+/// - Erasing an article takes its comments, favorites and bookmarks along
+/// - One `CommentPurged` or `ArticlePurged` event is returned per erased
+///   comment or article; comments erased along with their article get none
+pub async fn purge_trash<A, C>(
+    articles_repo: &A,
+    comments_repo: &C,
+    policy: &TrashPolicy,
+    now: DateTime<Utc>,
+) -> DomainResult<Vec<DomainEvent>>
+where
+    A: ArticlesRepository,
+    C: CommentsRepository,
+{
+    let cutoff = now - policy.retention;
+    let db_error = |e: anyhow::Error| DomainError::Database {
+        message: e.to_string(),
+    };
+
+    let comments = comments_repo
+        .purge_trashed_comments(cutoff)
+        .await
+        .map_err(db_error)?;
+    let articles = articles_repo
+        .purge_trashed_articles(cutoff)
+        .await
+        .map_err(db_error)?;
+
+    let comments = comments
+        .into_iter()
+        .map(|comment_id| DomainEvent::CommentPurged { comment_id });
+    let articles = articles
+        .into_iter()
+        .map(|article_id| DomainEvent::ArticlePurged { article_id });
+    Ok(comments.chain(articles).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::{InMemoryArticlesRepository, InMemoryCommentsRepository, InMemoryUsersRepository};
+    use crate::{Article, ArticleDraft, ArticleId, Comment, CommentDraft, CommentId, TagList, UserId};
+    use chrono::Duration;
+
+    #[tokio::test]
+    async fn test_purge_trash_erases_expired_items() {
+        let users_repo = InMemoryUsersRepository::new();
+        let articles_repo = InMemoryArticlesRepository::new(users_repo);
+        let comments_repo = InMemoryCommentsRepository::new();
+        let policy = TrashPolicy::default();
+        let now = Utc::now();
+        let expired = now - policy.retention - Duration::days(1);
+        let author_id = UserId::random();
+
+        let mut articles = Vec::new();
+        for (title, deleted_at) in [("Expired", expired), ("Recent", now)] {
+            let draft = ArticleDraft::new(title, "Description", "Body", TagList::default()).unwrap();
+            let article = Article::publish(ArticleId::random(), author_id, draft, expired).unwrap();
+            let article = articles_repo.create_article(article).await.unwrap();
+            articles_repo.delete_article(article.id, deleted_at).await.unwrap();
+            articles.push(article);
+        }
+        let draft = CommentDraft::new("Old news").unwrap();
+        let comment = Comment::new(CommentId::new(1), articles[1].id, author_id, draft, expired);
+        comments_repo.create_comment(comment).await.unwrap();
+        comments_repo.delete_comment(CommentId::new(1), expired).await.unwrap();
+
+        let events = purge_trash(&articles_repo, &comments_repo, &policy, now).await.unwrap();
+
+        assert_eq!(
+            events,
+            [
+                DomainEvent::CommentPurged {
+                    comment_id: CommentId::new(1)
+                },
+                DomainEvent::ArticlePurged {
+                    article_id: articles[0].id
+                },
+            ]
+        );
+        let trashed = articles_repo.list_trashed_articles(author_id).await.unwrap();
+        assert_eq!(trashed.len(), 1);
+        assert_eq!(trashed[0].item.id, articles[1].id);
+    }
+}
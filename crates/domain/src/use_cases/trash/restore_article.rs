@@ -0,0 +1,138 @@
+//! Restore article use case
+
+use chrono::{DateTime, Utc};
+
+use crate::{
+    ArticleView, DomainError, DomainResult, TrashPolicy, UserId,
+    repositories::{ArticlesRepository, UsersRepository},
+    use_cases::articles::build_article_view,
+};
+
+/// Take an article back out of the trash
+///
+/// # Business Rules
+/// - Article must be in the trash
+/// - Only the owner can restore it, as only the owner can delete it
+/// - Articles past the retention period are as good as erased
+/// - The article comes back as it was deleted, slug, status and comments
+///   included
+pub async fn restore_article<U, A>(
+    users_repo: &U,
+    articles_repo: &A,
+    slug: &str,
+    user_id: UserId,
+    policy: &TrashPolicy,
+    now: DateTime<Utc>,
+) -> DomainResult<ArticleView>
+where
+    U: UsersRepository,
+    A: ArticlesRepository,
+{
+    let trashed = articles_repo
+        .get_trashed_article_by_slug(slug)
+        .await
+        .map_err(|_| DomainError::NotFound { entity: "article" })?
+        .filter(|trashed| trashed.item.authored_by(&user_id))
+        .filter(|trashed| policy.is_restorable(trashed.deleted_at, now))
+        .ok_or(DomainError::NotFound { entity: "article" })?;
+
+    articles_repo
+        .restore_article(trashed.item.id)
+        .await
+        .map_err(|e| DomainError::Database {
+            message: e.to_string(),
+        })?;
+    let article = articles_repo
+        .get_article_by_id(trashed.item.id)
+        .await
+        .map_err(|_| DomainError::NotFound { entity: "article" })?
+        .ok_or(DomainError::NotFound { entity: "article" })?;
+
+    build_article_view(users_repo, articles_repo, &article, Some(user_id)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::{InMemoryArticlesRepository, InMemoryUsersRepository};
+    use crate::{Article, ArticleDraft, ArticleId, Bookmark, Email, PasswordHash, TagList, User, Username};
+    use chrono::Duration;
+
+    async fn setup(deleted_at: DateTime<Utc>) -> (InMemoryUsersRepository, InMemoryArticlesRepository, User) {
+        let users_repo = InMemoryUsersRepository::new();
+        let articles_repo = InMemoryArticlesRepository::new(users_repo.clone());
+        let author = User::new(
+            UserId::random(),
+            Email::parse("author@example.com").unwrap(),
+            Username::new("author").unwrap(),
+            PasswordHash::new("hash").unwrap(),
+            Utc::now(),
+        );
+        users_repo.create_user(author.clone()).await.unwrap();
+        let draft = ArticleDraft::new("Test Article", "Description", "Body", TagList::default()).unwrap();
+        let article = Article::publish(ArticleId::random(), author.id, draft, Utc::now()).unwrap();
+        let article = articles_repo.create_article(article).await.unwrap();
+        articles_repo.delete_article(article.id, deleted_at).await.unwrap();
+        (users_repo, articles_repo, author)
+    }
+
+    #[tokio::test]
+    async fn test_restore_article_success() {
+        let now = Utc::now();
+        let (users_repo, articles_repo, author) = setup(now - Duration::days(1)).await;
+
+        let view = restore_article(&users_repo, &articles_repo, "test-article", author.id, &TrashPolicy::default(), now)
+            .await
+            .unwrap();
+
+        assert_eq!(view.slug.as_str(), "test-article");
+        assert!(articles_repo.get_article_by_slug("test-article").await.unwrap().is_some());
+        assert!(articles_repo.list_trashed_articles(author.id).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_restore_article_not_owner() {
+        let now = Utc::now();
+        let (users_repo, articles_repo, _) = setup(now).await;
+
+        let result =
+            restore_article(&users_repo, &articles_repo, "test-article", UserId::random(), &TrashPolicy::default(), now)
+                .await;
+
+        assert!(matches!(result, Err(DomainError::NotFound { entity: "article" })));
+    }
+
+    #[tokio::test]
+    async fn test_restore_article_after_retention() {
+        let now = Utc::now();
+        let policy = TrashPolicy::default();
+        let (users_repo, articles_repo, author) = setup(now - policy.retention).await;
+
+        let result = restore_article(&users_repo, &articles_repo, "test-article", author.id, &policy, now).await;
+
+        assert!(matches!(result, Err(DomainError::NotFound { entity: "article" })));
+        assert!(articles_repo.get_article_by_slug("test-article").await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_restore_article_brings_back_favorites_and_bookmarks() {
+        let now = Utc::now();
+        let (users_repo, articles_repo, author) = setup(now).await;
+        let trashed = articles_repo.list_trashed_articles(author.id).await.unwrap().remove(0).item;
+        articles_repo.restore_article(trashed.id).await.unwrap();
+        let fan = UserId::random();
+        articles_repo.favorite_article(fan, trashed.id, now).await.unwrap();
+        let bookmark = Bookmark::new(fan, trashed.id, Some("later".to_owned()), None, now).unwrap();
+        articles_repo.bookmark_article(bookmark).await.unwrap();
+        articles_repo.delete_article(trashed.id, now).await.unwrap();
+
+        let view = restore_article(&users_repo, &articles_repo, "test-article", author.id, &TrashPolicy::default(), now)
+            .await
+            .unwrap();
+
+        assert_eq!(view.favorites_count, 1);
+        assert!(articles_repo.is_favorited(fan, trashed.id).await.unwrap());
+        let bookmark = articles_repo.get_bookmark(fan, trashed.id).await.unwrap().unwrap();
+        assert_eq!(bookmark.note.as_deref(), Some("later"));
+    }
+}
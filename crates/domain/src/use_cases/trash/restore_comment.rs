@@ -0,0 +1,110 @@
+//! Restore comment use case
+
+use chrono::{DateTime, Utc};
+
+use crate::{
+    CommentId, CommentView, DomainError, DomainResult, TrashPolicy, UserId,
+    repositories::{CommentsRepository, UsersRepository},
+    use_cases::comments::build_comment_view,
+};
+
+/// Take a comment back out of the trash
+///
+/// # Business Rules
+/// - Comment must be in the trash
+/// - Only the comment author can restore it
+/// - Comments past the retention period are as good as erased
+/// - A comment on an article that is in the trash itself stays hidden
+///   until the article is restored too
+pub async fn restore_comment<U, C>(
+    users_repo: &U,
+    comments_repo: &C,
+    comment_id: CommentId,
+    user_id: UserId,
+    policy: &TrashPolicy,
+    now: DateTime<Utc>,
+) -> DomainResult<CommentView>
+where
+    U: UsersRepository,
+    C: CommentsRepository,
+{
+    let trashed = comments_repo
+        .get_trashed_comment(comment_id)
+        .await
+        .map_err(|_| DomainError::NotFound { entity: "comment" })?
+        .filter(|trashed| trashed.item.author_id == user_id)
+        .filter(|trashed| policy.is_restorable(trashed.deleted_at, now))
+        .ok_or(DomainError::NotFound { entity: "comment" })?;
+
+    comments_repo
+        .restore_comment(comment_id)
+        .await
+        .map_err(|e| DomainError::Database {
+            message: e.to_string(),
+        })?;
+
+    build_comment_view(users_repo, comments_repo, &trashed.item, user_id).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::{InMemoryCommentsRepository, InMemoryUsersRepository};
+    use crate::{ArticleId, Comment, CommentDraft, Email, PasswordHash, User, Username};
+
+    async fn setup() -> (InMemoryUsersRepository, InMemoryCommentsRepository, User, DateTime<Utc>) {
+        let users_repo = InMemoryUsersRepository::new();
+        let comments_repo = InMemoryCommentsRepository::new();
+        let author = User::new(
+            UserId::random(),
+            Email::parse("author@example.com").unwrap(),
+            Username::new("author").unwrap(),
+            PasswordHash::new("hash").unwrap(),
+            Utc::now(),
+        );
+        users_repo.create_user(author.clone()).await.unwrap();
+        let deleted_at = Utc::now();
+        let draft = CommentDraft::new("Test comment").unwrap();
+        let comment = Comment::new(CommentId::new(1), ArticleId::random(), author.id, draft, deleted_at);
+        comments_repo.create_comment(comment).await.unwrap();
+        comments_repo.delete_comment(CommentId::new(1), deleted_at).await.unwrap();
+        (users_repo, comments_repo, author, deleted_at)
+    }
+
+    #[tokio::test]
+    async fn test_restore_comment_success() {
+        let (users_repo, comments_repo, author, deleted_at) = setup().await;
+
+        let view = restore_comment(
+            &users_repo,
+            &comments_repo,
+            CommentId::new(1),
+            author.id,
+            &TrashPolicy::default(),
+            deleted_at,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(view.body, "Test comment");
+        assert!(comments_repo.get_comment_by_id(CommentId::new(1)).await.unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_restore_comment_unauthorized() {
+        let (users_repo, comments_repo, _, deleted_at) = setup().await;
+
+        let result = restore_comment(
+            &users_repo,
+            &comments_repo,
+            CommentId::new(1),
+            UserId::random(),
+            &TrashPolicy::default(),
+            deleted_at,
+        )
+        .await;
+
+        assert!(matches!(result, Err(DomainError::NotFound { entity: "comment" })));
+        assert!(comments_repo.get_trashed_comment(CommentId::new(1)).await.unwrap().is_some());
+    }
+}
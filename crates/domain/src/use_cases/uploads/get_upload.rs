@@ -0,0 +1,28 @@
+//! Get upload use case
+
+use crate::{
+    Blob, DomainError, DomainResult,
+    repositories::BlobStore,
+    upload::is_upload_key,
+};
+
+/// Fetch a stored upload or thumbnail by its key
+///
+/// # Business Rules
+/// - Only keys handed out by `upload_file` are looked up; anything else is
+///   not found
+pub async fn get_upload<B>(blob_store: &B, key: &str) -> DomainResult<Blob>
+where
+    B: BlobStore + ?Sized,
+{
+    if !is_upload_key(key) {
+        return Err(DomainError::NotFound { entity: "upload" });
+    }
+    blob_store
+        .get_blob(key)
+        .await
+        .map_err(|e| DomainError::Database {
+            message: e.to_string(),
+        })?
+        .ok_or(DomainError::NotFound { entity: "upload" })
+}
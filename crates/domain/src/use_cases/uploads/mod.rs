@@ -0,0 +1,9 @@
+//! Upload use cases
+//!
+//! All business logic for uploaded images and attachments lives here.
+
+mod get_upload;
+mod upload_file;
+
+pub use get_upload::*;
+pub use upload_file::*;
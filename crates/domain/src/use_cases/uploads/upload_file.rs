@@ -0,0 +1,121 @@
+//! Upload file use case
+
+use uuid::Uuid;
+
+use crate::{
+    Blob, DomainError, DomainResult, ProcessedUpload, Upload, UploadEnvelope, UploadPolicy,
+    repositories::BlobStore,
+    upload::{thumbnail_key, upload_key},
+};
+
+/// Store an image or attachment and return the links to it
+///
+/// `processed` comes from `UploadPolicy::process`, which the caller runs
+/// where blocking on CPU-bound work is fine.
+///
+/// # Business Rules
+/// - Uploads larger than the policy allows are rejected
+/// - The file's contents decide its type: PNG, JPEG, GIF and WebP images and
+///   PDF documents are accepted, anything else is rejected
+/// - Images must decode; they are stored upright and without EXIF or other
+///   metadata, next to a thumbnail no larger than the policy's size
+/// - Every upload gets a new random key, so its URL never changes meaning
+pub async fn upload_file<B>(
+    blob_store: &B,
+    policy: &UploadPolicy,
+    processed: ProcessedUpload,
+) -> DomainResult<UploadEnvelope>
+where
+    B: BlobStore + ?Sized,
+{
+    let id = Uuid::new_v4();
+    let key = upload_key(id, processed.kind);
+    let db_error = |e: anyhow::Error| DomainError::Database {
+        message: e.to_string(),
+    };
+
+    let upload = Upload {
+        key: key.clone(),
+        kind: processed.kind,
+        size: processed.bytes.len(),
+        dimensions: processed.dimensions,
+        thumbnail_key: processed
+            .thumbnail
+            .as_ref()
+            .map(|thumbnail| thumbnail_key(id, thumbnail.kind)),
+    };
+    blob_store
+        .put_blob(
+            &key,
+            Blob {
+                content_type: processed.kind.content_type().to_owned(),
+                bytes: processed.bytes,
+            },
+        )
+        .await
+        .map_err(db_error)?;
+
+    if let (Some(thumbnail), Some(thumbnail_key)) = (processed.thumbnail, &upload.thumbnail_key) {
+        let stored = blob_store
+            .put_blob(
+                thumbnail_key,
+                Blob {
+                    content_type: thumbnail.kind.content_type().to_owned(),
+                    bytes: thumbnail.bytes,
+                },
+            )
+            .await;
+        if let Err(e) = stored {
+            // Never hand out an image whose thumbnail is missing
+            let _ = blob_store.delete_blob(&key).await;
+            return Err(db_error(e));
+        }
+    }
+
+    Ok(UploadEnvelope {
+        upload: upload.to_view(policy),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::InMemoryBlobStore;
+    use crate::use_cases::get_upload;
+
+    #[tokio::test]
+    async fn test_upload_stores_file_and_thumbnail() {
+        let store = InMemoryBlobStore::new();
+        let policy = UploadPolicy::default();
+        let mut png = std::io::Cursor::new(Vec::new());
+        image::RgbImage::new(640, 480)
+            .write_to(&mut png, image::ImageFormat::Png)
+            .unwrap();
+
+        let processed = policy.process(png.get_ref()).unwrap();
+        let upload = upload_file(&store, &policy, processed).await.unwrap().upload;
+        assert_eq!(upload.content_type, "image/png");
+        assert_eq!((upload.width, upload.height), (Some(640), Some(480)));
+
+        let key = upload.url.strip_prefix("/api/uploads/").unwrap();
+        assert_eq!(get_upload(&store, key).await.unwrap().content_type, "image/png");
+        let thumbnail_key = upload.thumbnail_url.unwrap().replace("/api/uploads/", "");
+        let thumbnail = get_upload(&store, &thumbnail_key).await.unwrap();
+        let decoded = image::load_from_memory(&thumbnail.bytes).unwrap();
+        assert_eq!((decoded.width(), decoded.height()), (320, 240));
+    }
+
+    #[tokio::test]
+    async fn test_upload_attachment_has_no_thumbnail() {
+        let store = InMemoryBlobStore::new();
+        let policy = UploadPolicy::default();
+        let processed = policy.process(b"%PDF-1.4\n%%EOF\n").unwrap();
+        let upload = upload_file(&store, &policy, processed)
+            .await
+            .unwrap()
+            .upload;
+        assert_eq!(upload.content_type, "application/pdf");
+        assert!(upload.thumbnail_url.is_none());
+        assert!(upload.url.ends_with(".pdf"));
+    }
+}
@@ -0,0 +1,136 @@
+//! Delete account use case
+
+use chrono::{DateTime, Utc};
+
+use crate::{
+    AccountDeletionPolicy, DomainError, DomainEvent, DomainResult, PasswordHash, UserId,
+    repositories::UsersRepository,
+};
+
+/// Input for deleting the current user's account
+#[derive(Debug, Clone)]
+pub struct DeleteAccountInput {
+    pub password_hash: PasswordHash,
+}
+
+/// Output from requesting account deletion
+#[derive(Debug, Clone)]
+pub struct DeleteAccountOutput {
+    pub purge_after: DateTime<Utc>,
+    pub event: DomainEvent,
+}
+
+/// Request deletion of the current user's account
+///
+/// # Business Rules
+/// - User must exist
+/// - Password must be re-confirmed
+/// - The account is only deactivated; it is erased once the grace period
+///   elapses and can be restored by logging in before then
+pub async fn delete_account<U>(
+    users_repo: &U,
+    user_id: UserId,
+    input: DeleteAccountInput,
+    policy: &AccountDeletionPolicy,
+    now: DateTime<Utc>,
+) -> DomainResult<DeleteAccountOutput>
+where
+    U: UsersRepository,
+{
+    let mut user = users_repo
+        .get_user_by_id(user_id)
+        .await
+        .map_err(|_| DomainError::NotFound { entity: "user" })?
+        .ok_or(DomainError::NotFound { entity: "user" })?;
+
+    if user.password_hash != input.password_hash {
+        return Err(DomainError::UnauthorizedAction);
+    }
+
+    if !user.is_deactivated() {
+        users_repo
+            .deactivate_user(user.id, now)
+            .await
+            .map_err(|_| DomainError::NotFound { entity: "user" })?;
+        user.deactivate(now);
+    }
+
+    let purge_after = user
+        .purge_after(policy)
+        .ok_or(DomainError::NotFound { entity: "user" })?;
+
+    Ok(DeleteAccountOutput {
+        purge_after,
+        event: DomainEvent::AccountDeactivated {
+            user_id: user.id,
+            purge_after,
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::InMemoryUsersRepository;
+    use crate::{Email, User, Username};
+
+    fn password_hash() -> PasswordHash {
+        PasswordHash::new("hashed:password123").unwrap()
+    }
+
+    async fn setup() -> (InMemoryUsersRepository, User) {
+        let users_repo = InMemoryUsersRepository::new();
+        let user = User::new(
+            UserId::random(),
+            Email::parse("test@example.com").unwrap(),
+            Username::new("testuser").unwrap(),
+            password_hash(),
+            Utc::now(),
+        );
+        let user = users_repo.create_user(user).await.unwrap();
+        (users_repo, user)
+    }
+
+    #[tokio::test]
+    async fn test_delete_account_deactivates_user() {
+        let (users_repo, user) = setup().await;
+        let policy = AccountDeletionPolicy::default();
+        let now = Utc::now();
+
+        let output = delete_account(
+            &users_repo,
+            user.id,
+            DeleteAccountInput {
+                password_hash: password_hash(),
+            },
+            &policy,
+            now,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(output.purge_after, now + policy.grace_period);
+        let stored = users_repo.get_user_by_id(user.id).await.unwrap().unwrap();
+        assert_eq!(stored.deactivated_at, Some(now));
+    }
+
+    #[tokio::test]
+    async fn test_delete_account_wrong_password() {
+        let (users_repo, user) = setup().await;
+
+        let result = delete_account(
+            &users_repo,
+            user.id,
+            DeleteAccountInput {
+                password_hash: PasswordHash::new("wrong").unwrap(),
+            },
+            &AccountDeletionPolicy::default(),
+            Utc::now(),
+        )
+        .await;
+
+        assert!(matches!(result, Err(DomainError::UnauthorizedAction)));
+        let stored = users_repo.get_user_by_id(user.id).await.unwrap().unwrap();
+        assert!(!stored.is_deactivated());
+    }
+}
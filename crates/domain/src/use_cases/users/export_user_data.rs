@@ -0,0 +1,718 @@
+//! Export user data use case
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
+use crate::{
+    Article, ArticleId, ArticleStatus, CommentId, DomainError, DomainResult, ReactionKind, ReportView, SeriesKind,
+    User, UserId,
+    repositories::{ArticlesRepository, CommentsRepository, UsersRepository},
+    use_cases::{UseCases, list_my_reports},
+};
+
+/// Input for exporting a user's data
+#[derive(Debug, Clone, Default)]
+pub struct ExportUserDataInput {
+    /// Sessions are held by the delivery layer, so it reports how many are open
+    pub active_sessions: usize,
+}
+
+/// Everything held about a single user, ready to be packaged
+#[derive(Debug, Clone, Serialize)]
+pub struct UserDataExport {
+    #[serde(rename = "generatedAt")]
+    pub generated_at: DateTime<Utc>,
+    pub profile: User,
+    pub articles: Vec<ExportedArticle>,
+    pub comments: Vec<ExportedComment>,
+    pub favorites: Vec<ExportedFavorite>,
+    pub bookmarks: Vec<ExportedBookmark>,
+    /// Usernames, like `blocks` and `mutes`
+    pub follows: Vec<String>,
+    pub blocks: Vec<String>,
+    pub mutes: Vec<String>,
+    #[serde(rename = "followedTags")]
+    pub followed_tags: Vec<String>,
+    pub reactions: Vec<ExportedReaction>,
+    pub reports: Vec<ReportView>,
+    /// Series and collections the user owns
+    pub series: Vec<ExportedSeries>,
+    pub coauthorships: Vec<ExportedCoauthorship>,
+    pub sessions: ExportedSessions,
+    pub audit: Vec<AuditEntry>,
+}
+
+/// An authored article rendered as Markdown with YAML front matter
+#[derive(Debug, Clone, Serialize)]
+pub struct ExportedArticle {
+    pub slug: String,
+    pub markdown: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ExportedComment {
+    pub id: CommentId,
+    #[serde(rename = "articleSlug")]
+    pub article_slug: Option<String>,
+    pub body: String,
+    #[serde(rename = "createdAt")]
+    pub created_at: DateTime<Utc>,
+    #[serde(rename = "updatedAt")]
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ExportedFavorite {
+    pub slug: String,
+    pub title: String,
+}
+
+/// `articleSlug` is `null` once the article is in the trash or gone, here
+/// and below
+#[derive(Debug, Clone, Serialize)]
+pub struct ExportedBookmark {
+    #[serde(rename = "articleSlug")]
+    pub article_slug: Option<String>,
+    pub note: Option<String>,
+    pub folder: Option<String>,
+    #[serde(rename = "createdAt")]
+    pub created_at: DateTime<Utc>,
+}
+
+/// A reaction to an article, or to one of its comments when `commentId` is set
+#[derive(Debug, Clone, Serialize)]
+pub struct ExportedReaction {
+    #[serde(rename = "articleSlug")]
+    pub article_slug: Option<String>,
+    #[serde(rename = "commentId", skip_serializing_if = "Option::is_none")]
+    pub comment_id: Option<CommentId>,
+    pub kind: ReactionKind,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ExportedSeries {
+    pub slug: String,
+    pub title: String,
+    pub description: String,
+    pub kind: SeriesKind,
+    /// Slugs of the parts, in order
+    pub parts: Vec<String>,
+}
+
+/// An invitation to co-author someone else's article
+#[derive(Debug, Clone, Serialize)]
+pub struct ExportedCoauthorship {
+    #[serde(rename = "articleSlug")]
+    pub article_slug: Option<String>,
+    #[serde(rename = "invitedAt")]
+    pub invited_at: DateTime<Utc>,
+    #[serde(rename = "acceptedAt")]
+    pub accepted_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ExportedSessions {
+    pub active: usize,
+}
+
+/// A dated record of something that happened to the account
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct AuditEntry {
+    pub at: DateTime<Utc>,
+    pub action: &'static str,
+    pub subject: Option<String>,
+}
+
+/// Collect everything held about a user
+///
+/// # Business Rules
+/// - User must exist
+/// - Articles carry their full body as Markdown with front matter, drafts
+///   and scheduled articles included, and so are those in the trash until
+///   they are purged and those hidden by moderators, saying so
+/// - Favorites, bookmarks, reactions and co-authorships are exported whatever
+///   became of the article since, as long as it is not in the trash
+/// - Relationships, followed tags, filed reports and owned series and
+///   collections are included too
+/// - Audit entries are reconstructed from the timestamps we store, oldest first
+///
+/// The export reads from nearly every repository, so it takes them all.
+pub async fn export_user_data<U, A, C>(
+    repos: &UseCases<U, A, C>,
+    user_id: UserId,
+    input: ExportUserDataInput,
+    now: DateTime<Utc>,
+) -> DomainResult<UserDataExport>
+where
+    U: UsersRepository,
+    A: ArticlesRepository,
+    C: CommentsRepository,
+{
+    let UseCases {
+        users_repo,
+        articles_repo,
+        comments_repo,
+        ..
+    } = repos;
+    let db_error = |e: anyhow::Error| DomainError::Database {
+        message: e.to_string(),
+    };
+    let user = users_repo
+        .get_user_by_id(user_id)
+        .await
+        .map_err(|_| DomainError::NotFound { entity: "user" })?
+        .ok_or(DomainError::NotFound { entity: "user" })?;
+
+    let authored = articles_repo
+        .list_articles_by_author(user_id)
+        .await
+        .map_err(|e| DomainError::Database {
+            message: e.to_string(),
+        })?;
+    let mut articles = Vec::with_capacity(authored.len());
+    let mut audit = vec![AuditEntry {
+        at: user.created_at,
+        action: "account_created",
+        subject: None,
+    }];
+    if user.updated_at > user.created_at {
+        audit.push(AuditEntry {
+            at: user.updated_at,
+            action: "profile_updated",
+            subject: None,
+        });
+    }
+    if let Some(at) = user.deactivated_at {
+        audit.push(AuditEntry {
+            at,
+            action: "deletion_requested",
+            subject: None,
+        });
+    }
+
+    for (article, deleted_at) in authored {
+        let slug = article.slug.as_str().to_owned();
+        audit.push(AuditEntry {
+            at: article.created_at,
+            action: if article.status == ArticleStatus::Published {
+                "article_published"
+            } else {
+                "article_drafted"
+            },
+            subject: Some(slug.clone()),
+        });
+        if let Some(at) = article.hidden_at {
+            audit.push(AuditEntry {
+                at,
+                action: "article_hidden",
+                subject: Some(slug.clone()),
+            });
+        }
+        if let Some(at) = deleted_at {
+            audit.push(AuditEntry {
+                at,
+                action: "article_deleted",
+                subject: Some(slug.clone()),
+            });
+        }
+        articles.push(ExportedArticle {
+            slug,
+            markdown: article_markdown(&article, deleted_at),
+        });
+    }
+
+    let mut comments = Vec::new();
+    for comment in comments_repo
+        .get_comments_by_author(user_id)
+        .await
+        .map_err(|e| DomainError::Database {
+            message: e.to_string(),
+        })?
+    {
+        let article_slug = articles_repo
+            .get_article_by_id(comment.article_id)
+            .await
+            .map_err(|e| DomainError::Database {
+                message: e.to_string(),
+            })?
+            .map(|article| article.slug.as_str().to_owned());
+        audit.push(AuditEntry {
+            at: comment.created_at,
+            action: "comment_posted",
+            subject: article_slug.clone(),
+        });
+        comments.push(ExportedComment {
+            id: comment.id,
+            article_slug,
+            body: comment.body,
+            created_at: comment.created_at,
+            updated_at: comment.updated_at,
+        });
+    }
+
+    let favorites = articles_repo
+        .list_favorited_articles(user_id)
+        .await
+        .map_err(db_error)?
+        .into_iter()
+        .map(|article| ExportedFavorite {
+            slug: article.slug.as_str().to_owned(),
+            title: article.title,
+        })
+        .collect();
+
+    let mut bookmarks = Vec::new();
+    for bookmark in articles_repo.list_bookmarks_by_user(user_id).await.map_err(db_error)? {
+        bookmarks.push(ExportedBookmark {
+            article_slug: article_slug(articles_repo, bookmark.article_id).await?,
+            note: bookmark.note,
+            folder: bookmark.folder,
+            created_at: bookmark.created_at,
+        });
+    }
+
+    let usernames = |users: Vec<User>| -> Vec<String> {
+        users.into_iter().map(|user| user.username.as_str().to_owned()).collect()
+    };
+    let follows = usernames(users_repo.list_followed_users(user_id).await.map_err(db_error)?);
+    let blocks = usernames(users_repo.list_blocked_users(user_id).await.map_err(db_error)?);
+    let mutes = usernames(users_repo.list_muted_users(user_id).await.map_err(db_error)?);
+    let followed_tags = repos
+        .tags_repo
+        .followed_tags(user_id)
+        .await
+        .map_err(db_error)?
+        .into_iter()
+        .map(|tag| tag.as_str().to_owned())
+        .collect();
+
+    let mut reactions = Vec::new();
+    for (article_id, kind) in articles_repo.list_article_reactions_by_user(user_id).await.map_err(db_error)? {
+        reactions.push(ExportedReaction {
+            article_slug: article_slug(articles_repo, article_id).await?,
+            comment_id: None,
+            kind,
+        });
+    }
+    for (comment_id, kind) in comments_repo.list_comment_reactions_by_user(user_id).await.map_err(db_error)? {
+        let article_id = comments_repo
+            .get_comment_by_id(comment_id)
+            .await
+            .map_err(db_error)?
+            .map(|comment| comment.article_id);
+        let article_slug = match article_id {
+            Some(article_id) => article_slug(articles_repo, article_id).await?,
+            None => None,
+        };
+        reactions.push(ExportedReaction {
+            article_slug,
+            comment_id: Some(comment_id),
+            kind,
+        });
+    }
+
+    let reports = list_my_reports(
+        users_repo,
+        articles_repo,
+        comments_repo,
+        repos.reports_repo.as_ref(),
+        user_id,
+    )
+    .await?
+    .reports;
+    for report in &reports {
+        audit.push(AuditEntry {
+            at: report.created_at,
+            action: "report_filed",
+            subject: None,
+        });
+    }
+
+    let mut series = Vec::new();
+    for owned in repos.series_repo.list_series_by_owner(user_id).await.map_err(db_error)? {
+        let parts = repos
+            .series_repo
+            .list_series_parts(owned.id, Some(user_id))
+            .await
+            .map_err(db_error)?
+            .into_iter()
+            .map(|part| part.slug.as_str().to_owned())
+            .collect();
+        series.push(ExportedSeries {
+            slug: owned.slug.as_str().to_owned(),
+            title: owned.title,
+            description: owned.description,
+            kind: owned.kind,
+            parts,
+        });
+    }
+
+    let mut coauthorships = Vec::new();
+    for coauthor in articles_repo.list_coauthorships(user_id).await.map_err(db_error)? {
+        let article_slug = article_slug(articles_repo, coauthor.article_id).await?;
+        if let Some(at) = coauthor.accepted_at {
+            audit.push(AuditEntry {
+                at,
+                action: "coauthorship_accepted",
+                subject: article_slug.clone(),
+            });
+        }
+        coauthorships.push(ExportedCoauthorship {
+            article_slug,
+            invited_at: coauthor.invited_at,
+            accepted_at: coauthor.accepted_at,
+        });
+    }
+
+    audit.sort_by_key(|entry| entry.at);
+
+    Ok(UserDataExport {
+        generated_at: now,
+        profile: user,
+        articles,
+        comments,
+        favorites,
+        bookmarks,
+        follows,
+        blocks,
+        mutes,
+        followed_tags,
+        reactions,
+        reports,
+        series,
+        coauthorships,
+        sessions: ExportedSessions {
+            active: input.active_sessions,
+        },
+        audit,
+    })
+}
+
+/// Slug of the article, unless it is in the trash or gone
+async fn article_slug<A>(articles_repo: &A, article_id: ArticleId) -> DomainResult<Option<String>>
+where
+    A: ArticlesRepository,
+{
+    let article = articles_repo
+        .get_article_by_id(article_id)
+        .await
+        .map_err(|e| DomainError::Database {
+            message: e.to_string(),
+        })?;
+    Ok(article.map(|article| article.slug.as_str().to_owned()))
+}
+
+/// Render an article as Markdown with YAML front matter
+///
+/// `deleted_at` is set for an article in the trash.
+pub fn article_markdown(article: &Article, deleted_at: Option<DateTime<Utc>>) -> String {
+    let tags = article
+        .tag_list
+        .as_slice()
+        .iter()
+        .map(|tag| yaml_string(tag.as_str()))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let hidden = article
+        .hidden_at
+        .map(|at| format!("hidden_at: {}\n", at.to_rfc3339()))
+        .unwrap_or_default();
+    let deleted = deleted_at
+        .map(|at| format!("deleted_at: {}\n", at.to_rfc3339()))
+        .unwrap_or_default();
+
+    format!(
+        "---\ntitle: {}\nslug: {}\ndescription: {}\ntags: [{}]\nstatus: {}\ncreated_at: {}\nupdated_at: {}\nfavorites_count: {}\n{}{}---\n\n{}\n",
+        yaml_string(&article.title),
+        yaml_string(article.slug.as_str()),
+        yaml_string(&article.description),
+        tags,
+        article.status.as_str(),
+        article.created_at.to_rfc3339(),
+        article.updated_at.to_rfc3339(),
+        article.favorites_count,
+        hidden,
+        deleted,
+        article.body.trim_end(),
+    )
+}
+
+/// Double-quoted YAML scalar
+fn yaml_string(value: &str) -> String {
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for ch in value.chars() {
+        match ch {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            _ => quoted.push(ch),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::Duration;
+
+    use super::*;
+    use crate::repositories::{
+        InMemoryArticlesRepository, InMemoryBlobStore, InMemoryCommentsRepository, InMemoryReportsRepository,
+        InMemoryRevisionsRepository, InMemorySeriesRepository, InMemoryTagsRepository, InMemoryUsersRepository,
+    };
+    use crate::{
+        ArticleDraft, Bookmark, Coauthor, Comment, CommentDraft, Email, PasswordHash, Publication, Report, ReportId,
+        ReportReason, ReportTarget, Series, SeriesId, SlugPolicy, Tag, TagList, Username,
+    };
+
+    type Repos = UseCases<InMemoryUsersRepository, InMemoryArticlesRepository, InMemoryCommentsRepository>;
+
+    fn repos() -> Repos {
+        let users_repo = InMemoryUsersRepository::new();
+        let articles_repo = InMemoryArticlesRepository::new(users_repo.clone());
+        UseCases {
+            tags_repo: Arc::new(InMemoryTagsRepository::new(articles_repo.clone())),
+            series_repo: Arc::new(InMemorySeriesRepository::new(articles_repo.clone())),
+            revisions_repo: Arc::new(InMemoryRevisionsRepository::new()),
+            blob_store: Arc::new(InMemoryBlobStore::new()),
+            reports_repo: Arc::new(InMemoryReportsRepository::new()),
+            comments_repo: InMemoryCommentsRepository::new(),
+            users_repo,
+            articles_repo,
+        }
+    }
+
+    fn user(name: &str) -> User {
+        User::new(
+            UserId::random(),
+            Email::parse(format!("{name}@example.com")).unwrap(),
+            Username::new(name).unwrap(),
+            PasswordHash::new("hash").unwrap(),
+            Utc::now(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_export_user_data_collects_everything() {
+        let repos = repos();
+        let Repos {
+            users_repo,
+            articles_repo,
+            comments_repo,
+            ..
+        } = &repos;
+        let now = Utc::now();
+
+        let me = users_repo.create_user(user("me")).await.unwrap();
+        let other = users_repo.create_user(user("other")).await.unwrap();
+        users_repo.follow_user(me.id, other.id).await.unwrap();
+
+        let tags = TagList::new(["rust"]).unwrap();
+        let draft = ArticleDraft::new("My \"first\" post", "desc", "# Hello", tags).unwrap();
+        let mine = Article::publish(ArticleId::random(), me.id, draft, now).unwrap();
+        articles_repo.create_article(mine.clone()).await.unwrap();
+
+        let draft = ArticleDraft::new("Unfinished", "desc", "wip", TagList::default()).unwrap();
+        let mut unfinished = Article::publish(ArticleId::random(), me.id, draft, now).unwrap();
+        unfinished.set_publication(Publication::Draft, now);
+        articles_repo.create_article(unfinished.clone()).await.unwrap();
+        let draft = ArticleDraft::new("Regrets", "desc", "oops", TagList::default()).unwrap();
+        let trashed = Article::publish(ArticleId::random(), me.id, draft, now).unwrap();
+        articles_repo.create_article(trashed.clone()).await.unwrap();
+        articles_repo.delete_article(trashed.id, now).await.unwrap();
+        let draft = ArticleDraft::new("Flagged", "desc", "spam", TagList::default()).unwrap();
+        let hidden = Article::publish(ArticleId::random(), me.id, draft, now).unwrap();
+        articles_repo.create_article(hidden.clone()).await.unwrap();
+        articles_repo.hide_article(hidden.id, now).await.unwrap();
+
+        let draft = ArticleDraft::new("Their post", "desc", "body", TagList::default()).unwrap();
+        let theirs = Article::publish(ArticleId::random(), other.id, draft, now).unwrap();
+        articles_repo.create_article(theirs.clone()).await.unwrap();
+        articles_repo
+            .favorite_article(me.id, theirs.id, now)
+            .await
+            .unwrap();
+        let draft = ArticleDraft::new("Their draft", "desc", "wip", TagList::default()).unwrap();
+        let mut their_draft = Article::publish(ArticleId::random(), other.id, draft, now).unwrap();
+        articles_repo.create_article(their_draft.clone()).await.unwrap();
+        articles_repo.favorite_article(me.id, their_draft.id, now + Duration::seconds(1)).await.unwrap();
+        their_draft.set_publication(Publication::Draft, now);
+        articles_repo.update_article(their_draft.clone()).await.unwrap();
+        articles_repo.favorite_article(me.id, hidden.id, now + Duration::seconds(2)).await.unwrap();
+
+        let comment = Comment::new(
+            CommentId::new(1),
+            theirs.id,
+            me.id,
+            CommentDraft::new("nice").unwrap(),
+            now,
+        );
+        let comment = comments_repo.create_comment(comment).await.unwrap();
+
+        let bookmark = Bookmark::new(me.id, theirs.id, Some("later".into()), Some("rust".into()), now).unwrap();
+        articles_repo.bookmark_article(bookmark).await.unwrap();
+        let troll = users_repo.create_user(user("troll")).await.unwrap();
+        users_repo.block_user(me.id, troll.id).await.unwrap();
+        users_repo.mute_user(me.id, troll.id).await.unwrap();
+        repos.tags_repo.follow_tag(me.id, &Tag::new("rust").unwrap()).await.unwrap();
+        let heart = ReactionKind::new("heart").unwrap();
+        articles_repo.add_article_reaction(theirs.id, me.id, &heart).await.unwrap();
+        comments_repo.add_comment_reaction(comment.id, me.id, &heart).await.unwrap();
+
+        let report = Report::new(
+            ReportId::random(),
+            me.id,
+            ReportTarget::Article(theirs.id),
+            other.id,
+            ReportReason::Spam,
+            None,
+            now,
+        )
+        .unwrap();
+        repos.reports_repo.create_report(report).await.unwrap();
+
+        let series = Series::new(
+            SeriesId::random(),
+            me.id,
+            "Learning Rust",
+            "",
+            SeriesKind::Series,
+            &SlugPolicy::default(),
+            now,
+        )
+        .unwrap();
+        let series = repos.series_repo.create_series(series).await.unwrap();
+        repos.series_repo.set_series_parts(series.id, &[mine.id, unfinished.id]).await.unwrap();
+
+        articles_repo.invite_coauthor(Coauthor::invite(theirs.id, me.id, now)).await.unwrap();
+        articles_repo.accept_coauthor(theirs.id, me.id, now).await.unwrap();
+
+        let export = export_user_data(
+            &repos,
+            me.id,
+            ExportUserDataInput { active_sessions: 2 },
+            now,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(export.profile.id, me.id);
+        assert_eq!(export.articles.len(), 4);
+        assert!(
+            export.articles[0]
+                .markdown
+                .starts_with("---\ntitle: \"My \\\"first\\\" post\"\n")
+        );
+        assert!(export.articles[0].markdown.contains("tags: [\"rust\"]"));
+        assert!(export.articles[0].markdown.ends_with("# Hello\n"));
+        assert_eq!(export.articles[1].slug, unfinished.slug.as_str());
+        assert!(export.articles[1].markdown.contains("status: draft\n"));
+        assert!(!export.articles[1].markdown.contains("deleted_at"));
+        assert_eq!(export.articles[2].slug, hidden.slug.as_str());
+        assert!(
+            export.articles[2]
+                .markdown
+                .contains(&format!("hidden_at: {}\n", now.to_rfc3339()))
+        );
+        assert_eq!(export.articles[3].slug, trashed.slug.as_str());
+        assert!(
+            export.articles[3]
+                .markdown
+                .contains(&format!("deleted_at: {}\n", now.to_rfc3339()))
+        );
+        assert_eq!(export.comments.len(), 1);
+        assert_eq!(
+            export.comments[0].article_slug.as_deref(),
+            Some(theirs.slug.as_str())
+        );
+        let favorites: Vec<_> = export.favorites.iter().map(|f| f.slug.as_str()).collect();
+        assert_eq!(
+            favorites,
+            [theirs.slug.as_str(), their_draft.slug.as_str(), hidden.slug.as_str()]
+        );
+        assert_eq!(export.bookmarks.len(), 1);
+        assert_eq!(export.bookmarks[0].article_slug.as_deref(), Some(theirs.slug.as_str()));
+        assert_eq!(export.bookmarks[0].note.as_deref(), Some("later"));
+        assert_eq!(export.bookmarks[0].folder.as_deref(), Some("rust"));
+        assert_eq!(export.follows, vec!["other".to_string()]);
+        assert_eq!(export.blocks, vec!["troll".to_string()]);
+        assert_eq!(export.mutes, vec!["troll".to_string()]);
+        assert_eq!(export.followed_tags, vec!["rust".to_string()]);
+        assert_eq!(export.reactions.len(), 2);
+        assert_eq!(export.reactions[0].article_slug.as_deref(), Some(theirs.slug.as_str()));
+        assert_eq!(export.reactions[0].comment_id, None);
+        assert_eq!(export.reactions[1].comment_id, Some(comment.id));
+        assert_eq!(export.reactions[1].article_slug.as_deref(), Some(theirs.slug.as_str()));
+        assert_eq!(export.reports.len(), 1);
+        assert_eq!(export.series.len(), 1);
+        assert_eq!(export.series[0].slug, series.slug.as_str());
+        assert_eq!(
+            export.series[0].parts,
+            vec![mine.slug.as_str().to_owned(), unfinished.slug.as_str().to_owned()]
+        );
+        assert_eq!(export.coauthorships.len(), 1);
+        assert_eq!(export.coauthorships[0].article_slug.as_deref(), Some(theirs.slug.as_str()));
+        assert_eq!(export.coauthorships[0].accepted_at, Some(now));
+        assert_eq!(export.sessions.active, 2);
+        assert!(
+            export
+                .audit
+                .iter()
+                .any(|entry| entry.action == "article_published")
+        );
+        assert!(
+            export
+                .audit
+                .iter()
+                .any(|entry| entry.action == "article_drafted")
+        );
+        assert!(
+            export
+                .audit
+                .iter()
+                .any(|entry| entry.action == "article_deleted")
+        );
+        assert!(
+            export
+                .audit
+                .iter()
+                .any(|entry| entry.action == "article_hidden")
+        );
+        assert!(
+            export
+                .audit
+                .iter()
+                .any(|entry| entry.action == "comment_posted")
+        );
+        assert!(export.audit.iter().any(|entry| entry.action == "report_filed"));
+        assert!(
+            export
+                .audit
+                .iter()
+                .any(|entry| entry.action == "coauthorship_accepted")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_export_user_data_unknown_user() {
+        let result = export_user_data(
+            &repos(),
+            UserId::random(),
+            ExportUserDataInput::default(),
+            Utc::now(),
+        )
+        .await;
+
+        assert!(matches!(
+            result,
+            Err(DomainError::NotFound { entity: "user" })
+        ));
+    }
+}
@@ -17,6 +17,8 @@ pub struct LoginUserInput {
 pub struct LoginUserOutput {
     pub user: User,
     pub view: UserView,
+    /// Set when logging in cancelled a pending account deletion
+    pub restored: bool,
 }
 
 /// Login a user with email and password
@@ -24,6 +26,8 @@ pub struct LoginUserOutput {
 /// # Business Rules
 /// - Email must exist in the system
 /// - Password hash must match stored hash
+/// - Suspended accounts cannot log in
+/// - Logging into a deactivated account restores it
 pub async fn login_user<U>(
     users_repo: &U,
     input: LoginUserInput,
@@ -33,7 +37,7 @@ where
 {
     let email = Email::parse(input.email)?;
 
-    let user = users_repo
+    let mut user = users_repo
         .get_user_by_email(email.as_str())
         .await
         .map_err(|_| DomainError::UnauthorizedAction)?
@@ -42,11 +46,23 @@ where
     if user.password_hash != input.password_hash {
         return Err(DomainError::UnauthorizedAction);
     }
+    if user.is_suspended() {
+        return Err(DomainError::Suspended);
+    }
+
+    let restored = user.is_deactivated();
+    if restored {
+        users_repo
+            .reactivate_user(user.id)
+            .await
+            .map_err(|_| DomainError::NotFound { entity: "user" })?;
+        user.reactivate();
+    }
 
     // Token will be added by the API layer
     let view = user.to_view(None);
 
-    Ok(LoginUserOutput { user, view })
+    Ok(LoginUserOutput { user, view, restored })
 }
 
 #[cfg(test)]
@@ -86,6 +102,38 @@ mod tests {
         assert!(result.is_ok());
         let output = result.unwrap();
         assert_eq!(output.user.id, user.id);
+        assert!(!output.restored);
+    }
+
+    #[tokio::test]
+    async fn test_login_user_restores_deactivated_account() {
+        let (users_repo, user) = setup().await;
+        users_repo.deactivate_user(user.id, Utc::now()).await.unwrap();
+        let input = LoginUserInput {
+            email: "test@example.com".to_string(),
+            password_hash: password_hash(),
+        };
+
+        let output = login_user(&users_repo, input).await.unwrap();
+
+        assert!(output.restored);
+        assert!(!output.user.is_deactivated());
+        let stored = users_repo.get_user_by_id(user.id).await.unwrap().unwrap();
+        assert!(!stored.is_deactivated());
+    }
+
+    #[tokio::test]
+    async fn test_login_user_suspended_account() {
+        let (users_repo, user) = setup().await;
+        users_repo.suspend_user(user.id, Utc::now()).await.unwrap();
+        let input = LoginUserInput {
+            email: "test@example.com".to_string(),
+            password_hash: password_hash(),
+        };
+
+        let result = login_user(&users_repo, input).await;
+
+        assert!(matches!(result, Err(DomainError::Suspended)));
     }
 
     #[tokio::test]
@@ -2,12 +2,18 @@
 //!
 //! All business logic for user authentication and management lives here.
 
+mod delete_account;
+mod export_user_data;
 mod get_current_user;
 mod login_user;
+mod purge_deactivated_accounts;
 mod register_user;
 mod update_user;
 
+pub use delete_account::*;
+pub use export_user_data::*;
 pub use get_current_user::*;
 pub use login_user::*;
+pub use purge_deactivated_accounts::*;
 pub use register_user::*;
 pub use update_user::*;
@@ -0,0 +1,295 @@
+//! Purge deactivated accounts use case
+
+use chrono::{DateTime, Utc};
+
+use crate::{
+    AccountDeletionPolicy, DomainError, DomainEvent, DomainResult, UserId,
+    repositories::{ArticlesRepository, UsersRepository},
+};
+
+/// Erase every account whose deletion grace period has elapsed
+///
+/// # Business Rules
+/// - Only accounts deactivated for longer than the grace period are erased
+/// - Authored articles and comments are deleted or handed over to the
+///   deleted-user placeholder, depending on the policy
+/// - Favorites, reactions and follow relationships are always removed
+/// - Each account is erased all at once or not at all; one that fails is
+///   left for the next run and does not stop the others
+/// - One `AccountDeleted` event is returned per erased account and one
+///   `AccountErasureFailed` per account left in place
+pub async fn purge_deactivated_accounts<U, A>(
+    users_repo: &U,
+    articles_repo: &A,
+    policy: &AccountDeletionPolicy,
+    now: DateTime<Utc>,
+) -> DomainResult<Vec<DomainEvent>>
+where
+    U: UsersRepository,
+    A: ArticlesRepository,
+{
+    let expired = users_repo
+        .list_deactivated_users(now - policy.grace_period)
+        .await
+        .map_err(|e| DomainError::Database {
+            message: e.to_string(),
+        })?;
+
+    let mut events = Vec::with_capacity(expired.len());
+    for user in expired {
+        if user.id == UserId::deleted_user() {
+            continue;
+        }
+        let event = match articles_repo
+            .erase_account(user.id, policy.authored_content, now)
+            .await
+        {
+            Ok(()) => DomainEvent::AccountDeleted {
+                user_id: user.id,
+                authored_content: policy.authored_content,
+            },
+            Err(e) => DomainEvent::AccountErasureFailed {
+                user_id: user.id,
+                message: format!("{e:#}"),
+            },
+        };
+        events.push(event);
+    }
+
+    Ok(events)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::{
+        CommentsRepository, InMemoryArticlesRepository, InMemoryCommentsRepository,
+        InMemoryUsersRepository,
+    };
+    use crate::{
+        Article, ArticleDraft, ArticleId, AuthoredContentPolicy, Comment, CommentDraft, CommentId,
+        Email, PasswordHash, ReactionKind, TagList, User, Username,
+    };
+    use chrono::Duration;
+
+    struct Fixture {
+        users_repo: InMemoryUsersRepository,
+        articles_repo: InMemoryArticlesRepository,
+        comments_repo: InMemoryCommentsRepository,
+        leaving: User,
+        staying: User,
+        article: Article,
+    }
+
+    async fn setup(deactivated_at: DateTime<Utc>) -> Fixture {
+        let users_repo = InMemoryUsersRepository::new();
+        let comments_repo = InMemoryCommentsRepository::new();
+        let articles_repo = InMemoryArticlesRepository::new(users_repo.clone()).with_comments(comments_repo.clone());
+        let now = Utc::now();
+
+        let leaving = User::new(
+            UserId::random(),
+            Email::parse("leaving@example.com").unwrap(),
+            Username::new("leaving").unwrap(),
+            PasswordHash::new("hash").unwrap(),
+            now,
+        );
+        let staying = User::new(
+            UserId::random(),
+            Email::parse("staying@example.com").unwrap(),
+            Username::new("staying").unwrap(),
+            PasswordHash::new("hash").unwrap(),
+            now,
+        );
+        users_repo.create_user(leaving.clone()).await.unwrap();
+        users_repo.create_user(staying.clone()).await.unwrap();
+        users_repo
+            .follow_user(staying.id, leaving.id)
+            .await
+            .unwrap();
+
+        let draft = ArticleDraft::new("Farewell", "desc", "body", TagList::default()).unwrap();
+        let article = Article::publish(ArticleId::random(), leaving.id, draft, now).unwrap();
+        articles_repo.create_article(article.clone()).await.unwrap();
+        articles_repo
+            .favorite_article(leaving.id, article.id, now)
+            .await
+            .unwrap();
+
+        let comment = Comment::new(
+            CommentId::new(1),
+            article.id,
+            leaving.id,
+            CommentDraft::new("so long").unwrap(),
+            now,
+        );
+        let comment = comments_repo.create_comment(comment).await.unwrap();
+        let heart = ReactionKind::new("heart").unwrap();
+        articles_repo
+            .add_article_reaction(article.id, leaving.id, &heart)
+            .await
+            .unwrap();
+        comments_repo
+            .add_comment_reaction(comment.id, leaving.id, &heart)
+            .await
+            .unwrap();
+
+        users_repo
+            .deactivate_user(leaving.id, deactivated_at)
+            .await
+            .unwrap();
+
+        Fixture {
+            users_repo,
+            articles_repo,
+            comments_repo,
+            leaving,
+            staying,
+            article,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_purge_deletes_authored_content() {
+        let now = Utc::now();
+        let policy = AccountDeletionPolicy::new(Duration::days(30), AuthoredContentPolicy::Delete);
+        let f = setup(now - Duration::days(31)).await;
+
+        let events = purge_deactivated_accounts(
+            &f.users_repo,
+            &f.articles_repo,
+            &policy,
+            now,
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(
+            events,
+            vec![DomainEvent::AccountDeleted {
+                user_id: f.leaving.id,
+                authored_content: AuthoredContentPolicy::Delete,
+            }]
+        );
+        assert!(
+            f.users_repo
+                .get_user_by_id(f.leaving.id)
+                .await
+                .unwrap()
+                .is_none()
+        );
+        assert!(
+            f.articles_repo
+                .get_article_by_id(f.article.id)
+                .await
+                .unwrap()
+                .is_none()
+        );
+        assert!(
+            f.comments_repo
+                .get_comments_by_article(f.article.id)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        assert!(
+            !f.users_repo
+                .is_following(f.staying.id, f.leaving.id)
+                .await
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_purge_anonymises_authored_content() {
+        let now = Utc::now();
+        let policy =
+            AccountDeletionPolicy::new(Duration::days(30), AuthoredContentPolicy::Anonymise);
+        let f = setup(now - Duration::days(31)).await;
+
+        purge_deactivated_accounts(
+            &f.users_repo,
+            &f.articles_repo,
+            &policy,
+            now,
+        )
+        .await
+        .unwrap();
+
+        assert!(
+            f.users_repo
+                .get_user_by_id(f.leaving.id)
+                .await
+                .unwrap()
+                .is_none()
+        );
+        let article = f
+            .articles_repo
+            .get_article_by_id(f.article.id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(article.author_id, UserId::deleted_user());
+        assert_eq!(article.favorites_count, 0);
+        let comments = f
+            .comments_repo
+            .get_comments_by_article(f.article.id)
+            .await
+            .unwrap();
+        assert_eq!(comments[0].author_id, UserId::deleted_user());
+        // Reactions go with the account, even on content that stays
+        assert!(
+            f.articles_repo
+                .article_reactions(f.article.id, None)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        assert!(
+            f.comments_repo
+                .comment_reactions(&[comments[0].id], None)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        assert!(
+            f.users_repo
+                .get_user_by_id(UserId::deleted_user())
+                .await
+                .unwrap()
+                .is_some()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_purge_keeps_accounts_within_grace_period() {
+        let now = Utc::now();
+        let policy = AccountDeletionPolicy::default();
+        let f = setup(now - Duration::days(1)).await;
+
+        let events = purge_deactivated_accounts(
+            &f.users_repo,
+            &f.articles_repo,
+            &policy,
+            now,
+        )
+        .await
+        .unwrap();
+
+        assert!(events.is_empty());
+        assert!(
+            f.users_repo
+                .get_user_by_id(f.leaving.id)
+                .await
+                .unwrap()
+                .is_some()
+        );
+        assert!(
+            f.articles_repo
+                .get_article_by_id(f.article.id)
+                .await
+                .unwrap()
+                .is_some()
+        );
+    }
+}
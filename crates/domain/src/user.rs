@@ -3,6 +3,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::errors::{DomainError, DomainResult};
 use crate::identifiers::UserId;
+use crate::avatar::Avatar;
 use crate::profile::Profile;
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -23,18 +24,38 @@ impl Email {
     }
 }
 
+/// Name of the placeholder account anonymised content is reassigned to
+const DELETED_USERNAME: &str = "deleted-user";
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Username(String);
 
 impl Username {
+    /// Rejects blank names and the name reserved for the deleted-user
+    /// placeholder
     pub fn new(value: impl Into<String>) -> DomainResult<Self> {
         let trimmed = value.into().trim().to_owned();
-        if trimmed.is_empty() {
+        if trimmed.is_empty() || trimmed.eq_ignore_ascii_case(DELETED_USERNAME) {
             return Err(DomainError::InvalidUsername);
         }
         Ok(Self(trimmed))
     }
 
+    /// A username read back from storage, where the placeholder's reserved
+    /// name is allowed
+    pub fn stored(value: impl Into<String>) -> DomainResult<Self> {
+        let value = value.into();
+        if value == DELETED_USERNAME {
+            return Ok(Self::deleted());
+        }
+        Self::new(value)
+    }
+
+    /// The deleted-user placeholder's name
+    pub fn deleted() -> Self {
+        Self(DELETED_USERNAME.to_owned())
+    }
+
     pub fn as_str(&self) -> &str {
         &self.0
     }
@@ -129,6 +150,14 @@ pub struct User {
     pub password_hash: PasswordHash,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    /// Set when the owner asked for the account to be deleted. The account
+    /// stays restorable until the grace period elapses.
+    #[serde(default)]
+    pub deactivated_at: Option<DateTime<Utc>>,
+    /// Set when a moderator suspended the account, which can't sign in or
+    /// act from then on
+    #[serde(default)]
+    pub suspended_at: Option<DateTime<Utc>>,
 }
 
 impl User {
@@ -148,9 +177,44 @@ impl User {
             password_hash,
             created_at: now,
             updated_at: now,
+            deactivated_at: None,
+            suspended_at: None,
         }
     }
 
+    /// Placeholder author that anonymised content is reassigned to once the
+    /// original author's account has been erased.
+    pub fn deleted_placeholder(now: DateTime<Utc>) -> Self {
+        Self::new(
+            UserId::deleted_user(),
+            Email::parse("deleted-user@invalid").expect("valid placeholder email"),
+            Username::deleted(),
+            PasswordHash::new("!").expect("valid placeholder hash"),
+            now,
+        )
+    }
+
+    pub fn is_deactivated(&self) -> bool {
+        self.deactivated_at.is_some()
+    }
+
+    pub fn deactivate(&mut self, now: DateTime<Utc>) {
+        self.deactivated_at = Some(now);
+    }
+
+    pub fn reactivate(&mut self) {
+        self.deactivated_at = None;
+    }
+
+    pub fn is_suspended(&self) -> bool {
+        self.suspended_at.is_some()
+    }
+
+    /// Moment after which a deactivated account is erased for good.
+    pub fn purge_after(&self, policy: &AccountDeletionPolicy) -> Option<DateTime<Utc>> {
+        self.deactivated_at.map(|at| at + policy.grace_period)
+    }
+
     pub fn apply_update(&mut self, changes: UpdateUserInput, now: DateTime<Utc>) {
         if let Some(email) = changes.email {
             self.email = email;
@@ -185,11 +249,49 @@ impl User {
             token,
             username: self.username.clone(),
             bio: self.bio.clone(),
-            image: self.image.clone(),
+            image: self.image.clone().or_else(|| Some(Avatar::url(&self.username))),
         }
     }
 }
 
+/// What happens to articles and comments when their author's account is erased
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AuthoredContentPolicy {
+    /// Remove everything the user wrote
+    #[default]
+    Delete,
+    /// Keep the content but attribute it to the deleted-user placeholder
+    Anonymise,
+}
+
+/// Rules applied when a user deletes their account
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AccountDeletionPolicy {
+    pub grace_period: chrono::Duration,
+    pub authored_content: AuthoredContentPolicy,
+}
+
+impl AccountDeletionPolicy {
+    pub const DEFAULT_GRACE_PERIOD_DAYS: i64 = 30;
+
+    pub fn new(grace_period: chrono::Duration, authored_content: AuthoredContentPolicy) -> Self {
+        Self {
+            grace_period,
+            authored_content,
+        }
+    }
+}
+
+impl Default for AccountDeletionPolicy {
+    fn default() -> Self {
+        Self::new(
+            chrono::Duration::days(Self::DEFAULT_GRACE_PERIOD_DAYS),
+            AuthoredContentPolicy::default(),
+        )
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RegisterUserInput {
     pub email: Email,
@@ -266,6 +368,16 @@ mod tests {
         );
     }
 
+    #[test]
+    fn username_reserves_placeholder_name() {
+        for reserved in ["deleted-user", " Deleted-User "] {
+            assert_eq!(Username::new(reserved).unwrap_err(), DomainError::InvalidUsername);
+        }
+        assert_eq!(Username::stored("deleted-user").unwrap(), Username::deleted());
+        assert_eq!(Username::stored("alice").unwrap().as_str(), "alice");
+        assert!(Username::stored("").is_err());
+    }
+
     #[test]
     fn plain_password_requires_min_length() {
         assert!(PlainPassword::new("12345678").is_ok());